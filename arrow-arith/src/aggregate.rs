@@ -21,6 +21,8 @@ use arrow_array::cast::*;
 use arrow_array::iterator::ArrayIter;
 use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
+#[cfg(not(feature = "simd"))]
+use arrow_buffer::Buffer;
 use arrow_data::bit_iterator::try_for_each_valid_idx;
 use arrow_data::bit_iterator::BitIndexIterator;
 use arrow_schema::ArrowError;
@@ -41,7 +43,7 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeType,
 {
-    min_max_helper::<T::Native, _, _>(array, |a, b| (is_nan(*a) & !is_nan(*b)) || a > b)
+    min_max_primitive::<T, _>(array, |a, b| (is_nan(*a) & !is_nan(*b)) || a > b)
 }
 
 /// Returns the maximum value in the array, according to the natural order.
@@ -52,7 +54,66 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeType,
 {
-    min_max_helper::<T::Native, _, _>(array, |a, b| (!is_nan(*a) & is_nan(*b)) || a < b)
+    min_max_primitive::<T, _>(array, |a, b| (!is_nan(*a) & is_nan(*b)) || a < b)
+}
+
+/// Helper to compute min/max of a [`PrimitiveArray`]
+#[cfg(all(not(feature = "simd"), feature = "legacy_aggregate"))]
+fn min_max_primitive<T, F>(array: &PrimitiveArray<T>, cmp: F) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    F: Fn(&T::Native, &T::Native) -> bool,
+{
+    min_max_helper::<T::Native, _, _>(array, cmp)
+}
+
+/// Helper to compute min/max of a [`PrimitiveArray`]
+///
+/// The validity bitmap is processed a 64-bit word at a time, chunks of values that are
+/// all valid are reduced without consulting the bitmap, and chunks that are all null
+/// are skipped entirely
+#[cfg(all(not(feature = "simd"), not(feature = "legacy_aggregate")))]
+fn min_max_primitive<T, F>(array: &PrimitiveArray<T>, cmp: F) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    F: Fn(&T::Native, &T::Native) -> bool,
+{
+    let null_count = array.null_count();
+    if null_count == array.len() {
+        return None;
+    }
+
+    let reduce =
+        |acc: T::Native, item: T::Native| if cmp(&acc, &item) { item } else { acc };
+    let values = array.values();
+    let buffer = match array.data().null_buffer() {
+        Some(buffer) if null_count != 0 => buffer,
+        _ => return values.iter().copied().reduce(reduce),
+    };
+
+    let mut acc: Option<T::Native> = None;
+    let mut reduce_masked = |chunk: &[T::Native], mask: u64| {
+        if mask == u64::MAX {
+            let chunk = chunk.iter().copied();
+            acc = acc.into_iter().chain(chunk).reduce(reduce);
+            return;
+        }
+        let mut mask = mask;
+        while mask != 0 {
+            let item = chunk[mask.trailing_zeros() as usize];
+            acc = Some(acc.map_or(item, |acc| reduce(acc, item)));
+            mask &= mask - 1;
+        }
+    };
+
+    let chunks = values.chunks_exact(64);
+    let remainder = chunks.remainder();
+    let bit_chunks = buffer.bit_chunks(array.offset(), array.len());
+    chunks
+        .zip(bit_chunks.iter())
+        .for_each(|(chunk, mask)| reduce_masked(chunk, mask));
+    reduce_masked(remainder, bit_chunks.remainder_bits());
+    acc
 }
 
 /// Returns the minimum value in the boolean array.
@@ -154,6 +215,63 @@ pub fn min_string<T: OffsetSizeTrait>(array: &GenericStringArray<T>) -> Option<&
     min_max_helper::<&str, _, _>(array, |a, b| *a > *b)
 }
 
+/// Returns the maximum value in the fixed size binary array, according to the natural order.
+pub fn max_fixed_size_binary(array: &FixedSizeBinaryArray) -> Option<&[u8]> {
+    min_max_helper::<&[u8], _, _>(array, |a, b| *a < *b)
+}
+
+/// Returns the minimum value in the fixed size binary array, according to the natural order.
+pub fn min_fixed_size_binary(array: &FixedSizeBinaryArray) -> Option<&[u8]> {
+    min_max_helper::<&[u8], _, _>(array, |a, b| *a > *b)
+}
+
+/// Returns the maximum value in an array of bytes, such as a binary, fixed size binary,
+/// or dictionary array with values of either type, according to the natural order.
+///
+/// ```
+/// # use arrow_array::{BinaryArray, DictionaryArray, Int32Array};
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_arith::aggregate::max_binary_array;
+/// let values = BinaryArray::from(vec![b"a".as_ref(), b"c", b"b"]);
+/// let keys = Int32Array::from(vec![Some(0), None, Some(2)]);
+/// let dictionary = DictionaryArray::<Int32Type>::try_new(&keys, &values).unwrap();
+/// let array = dictionary.downcast_dict::<BinaryArray>().unwrap();
+/// assert_eq!(max_binary_array(array), Some(b"b".as_ref()));
+/// ```
+pub fn max_binary_array<'a, A>(array: A) -> Option<&'a [u8]>
+where
+    A: ArrayAccessor<Item = &'a [u8]>,
+{
+    min_max_helper::<&[u8], _, _>(array, |a, b| *a < *b)
+}
+
+/// Returns the minimum value in an array of bytes, such as a binary, fixed size binary,
+/// or dictionary array with values of either type, according to the natural order.
+pub fn min_binary_array<'a, A>(array: A) -> Option<&'a [u8]>
+where
+    A: ArrayAccessor<Item = &'a [u8]>,
+{
+    min_max_helper::<&[u8], _, _>(array, |a, b| *a > *b)
+}
+
+/// Returns the maximum value in a boolean array, or an array of boolean values
+/// such as a dictionary or run array
+pub fn max_boolean_array<A: ArrayAccessor<Item = bool>>(array: A) -> Option<bool> {
+    match array.as_any().downcast_ref::<BooleanArray>() {
+        Some(array) => max_boolean(array),
+        None => min_max_helper::<bool, _, _>(array, |a, b| !*a & *b),
+    }
+}
+
+/// Returns the minimum value in a boolean array, or an array of boolean values
+/// such as a dictionary or run array
+pub fn min_boolean_array<A: ArrayAccessor<Item = bool>>(array: A) -> Option<bool> {
+    match array.as_any().downcast_ref::<BooleanArray>() {
+        Some(array) => min_boolean(array),
+        None => min_max_helper::<bool, _, _>(array, |a, b| *a & !*b),
+    }
+}
+
 /// Returns the sum of values in the array.
 ///
 /// This doesn't detect overflow. Once overflowing, the result will wrap around.
@@ -296,36 +414,81 @@ where
 
             Some(sum)
         }
-        Some(buffer) => {
-            let mut sum = T::default_value();
-            let data_chunks = data.chunks_exact(64);
-            let remainder = data_chunks.remainder();
-
-            let bit_chunks = buffer.bit_chunks(array.offset(), array.len());
-            data_chunks
-                .zip(bit_chunks.iter())
-                .for_each(|(chunk, mask)| {
-                    // index_mask has value 1 << i in the loop
-                    let mut index_mask = 1;
-                    chunk.iter().for_each(|value| {
-                        if (mask & index_mask) != 0 {
-                            sum = sum.add_wrapping(*value);
-                        }
-                        index_mask <<= 1;
-                    });
-                });
-
-            let remainder_bits = bit_chunks.remainder_bits();
+        Some(buffer) => Some(sum_nullable::<T>(data, buffer, array.offset())),
+    }
+}
 
-            remainder.iter().enumerate().for_each(|(i, value)| {
-                if remainder_bits & (1 << i) != 0 {
+/// Returns the wrapping sum of the valid values of `data`, where `buffer` is the
+/// validity bitmap starting at bit `offset`
+#[cfg(all(not(feature = "simd"), feature = "legacy_aggregate"))]
+fn sum_nullable<T>(data: &[T::Native], buffer: &Buffer, offset: usize) -> T::Native
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let mut sum = T::default_value();
+    let data_chunks = data.chunks_exact(64);
+    let remainder = data_chunks.remainder();
+
+    let bit_chunks = buffer.bit_chunks(offset, data.len());
+    data_chunks
+        .zip(bit_chunks.iter())
+        .for_each(|(chunk, mask)| {
+            // index_mask has value 1 << i in the loop
+            let mut index_mask = 1;
+            chunk.iter().for_each(|value| {
+                if (mask & index_mask) != 0 {
                     sum = sum.add_wrapping(*value);
                 }
+                index_mask <<= 1;
             });
+        });
 
-            Some(sum)
+    let remainder_bits = bit_chunks.remainder_bits();
+
+    remainder.iter().enumerate().for_each(|(i, value)| {
+        if remainder_bits & (1 << i) != 0 {
+            sum = sum.add_wrapping(*value);
         }
-    }
+    });
+
+    sum
+}
+
+/// Returns the wrapping sum of the valid values of `data`, where `buffer` is the
+/// validity bitmap starting at bit `offset`
+///
+/// The validity bitmap is processed a 64-bit word at a time. Chunks that are all valid
+/// or all null are handled without consulting individual bits, and the remaining chunks
+/// only visit the set bits of the word, avoiding a branch per value
+#[cfg(all(not(feature = "simd"), not(feature = "legacy_aggregate")))]
+fn sum_nullable<T>(data: &[T::Native], buffer: &Buffer, offset: usize) -> T::Native
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let sum_masked = |sum: T::Native, chunk: &[T::Native], mask: u64| match mask {
+        0 => sum,
+        u64::MAX => chunk.iter().fold(sum, |sum, v| sum.add_wrapping(*v)),
+        mut mask => {
+            let mut sum = sum;
+            while mask != 0 {
+                sum = sum.add_wrapping(chunk[mask.trailing_zeros() as usize]);
+                mask &= mask - 1;
+            }
+            sum
+        }
+    };
+
+    let data_chunks = data.chunks_exact(64);
+    let remainder = data_chunks.remainder();
+    let bit_chunks = buffer.bit_chunks(offset, data.len());
+    let sum = data_chunks
+        .zip(bit_chunks.iter())
+        .fold(T::default_value(), |sum, (chunk, mask)| {
+            sum_masked(sum, chunk, mask)
+        });
+    sum_masked(sum, remainder, bit_chunks.remainder_bits())
 }
 
 /// Returns the sum of values in the primitive array.
@@ -761,6 +924,8 @@ mod tests {
     use super::*;
     use crate::arithmetic::add;
     use arrow_array::types::*;
+    use arrow_buffer::i256;
+    use half::f16;
 
     #[test]
     fn test_primitive_array_sum() {
@@ -768,12 +933,71 @@ mod tests {
         assert_eq!(15, sum(&a).unwrap());
     }
 
+    #[test]
+    fn test_primitive_array_scattered_nulls() {
+        // Contains all valid, all null and mixed 64-value chunks
+        let values: Vec<_> = (0..300_i32)
+            .map(|i| match i {
+                0..=69 => Some(i * 7 % 23 - 11),
+                70..=149 => None,
+                _ => (i % 3 != 0 && i % 5 != 0).then_some(i * 13 % 41 - 20),
+            })
+            .collect();
+        let array = Int32Array::from(values);
+
+        for (offset, len) in [(0, 300), (3, 297), (5, 60), (70, 80), (71, 200), (150, 1)]
+        {
+            let sliced = array.slice(offset, len);
+            let sliced = as_primitive_array::<Int32Type>(sliced.as_ref());
+            let valid: Vec<_> = sliced.iter().flatten().collect();
+            let expected_sum = (!valid.is_empty()).then(|| valid.iter().sum::<i32>());
+            assert_eq!(sum(sliced), expected_sum, "{offset} {len}");
+            assert_eq!(min(sliced), valid.iter().min().copied(), "{offset} {len}");
+            assert_eq!(max(sliced), valid.iter().max().copied(), "{offset} {len}");
+        }
+
+        let values: Vec<_> = (0..130)
+            .map(|i| match i % 4 {
+                0 => None,
+                1 if i == 65 => Some(f64::NAN),
+                _ => Some(i as f64 - 60.),
+            })
+            .collect();
+        let array = Float64Array::from(values);
+        assert_eq!(min(&array), Some(-59.));
+        assert!(max(&array).unwrap().is_nan());
+        let sliced = array.slice(66, 64);
+        let sliced = as_primitive_array::<Float64Type>(sliced.as_ref());
+        assert_eq!(max(sliced), Some(69.));
+    }
+
     #[test]
     fn test_primitive_array_float_sum() {
         let a = Float64Array::from(vec![1.1, 2.2, 3.3, 4.4, 5.5]);
         assert_eq!(16.5, sum(&a).unwrap());
     }
 
+    #[test]
+    fn test_primitive_array_f16_sum_min_max() {
+        let a = Float16Array::from_iter([
+            Some(f16::from_f32(1.5)),
+            None,
+            Some(f16::from_f32(-2.0)),
+            Some(f16::from_f32(4.0)),
+        ]);
+        assert_eq!(Some(f16::from_f32(3.5)), sum(&a));
+        assert_eq!(Some(f16::from_f32(-2.0)), min(&a));
+        assert_eq!(Some(f16::from_f32(4.0)), max(&a));
+    }
+
+    #[test]
+    fn test_primitive_array_duration_min_max() {
+        let a = DurationMicrosecondArray::from(vec![Some(5), None, Some(-2), Some(4)]);
+        assert_eq!(Some(-2), min(&a));
+        assert_eq!(Some(5), max(&a));
+        assert_eq!(Some(7), sum(&a));
+    }
+
     #[test]
     fn test_primitive_array_sum_with_nulls() {
         let a = Int32Array::from(vec![None, Some(2), Some(3), None, Some(5)]);
@@ -786,6 +1010,28 @@ mod tests {
         assert_eq!(None, sum(&a));
     }
 
+    #[test]
+    fn test_decimal256_sum_min_max() {
+        let a: Decimal256Array = [Some(5), None, Some(-12), Some(40)]
+            .into_iter()
+            .map(|v| v.map(i256::from_i128))
+            .collect::<Decimal256Array>()
+            .with_precision_and_scale(40, 2)
+            .unwrap();
+        assert_eq!(Some(i256::from_i128(33)), sum(&a));
+        assert_eq!(Some(i256::from_i128(33)), sum_checked(&a).unwrap());
+        assert_eq!(Some(i256::from_i128(-12)), min(&a));
+        assert_eq!(Some(i256::from_i128(40)), max(&a));
+
+        let a = Decimal256Array::from(vec![i256::MAX, i256::ONE]);
+        assert_eq!(Some(i256::MIN), sum(&a));
+        sum_checked(&a).expect_err("overflow should be detected");
+
+        let a = Decimal256Array::from(vec![None, None]);
+        assert_eq!(None, sum(&a));
+        assert_eq!(None, min(&a));
+    }
+
     #[test]
     fn test_primitive_array_sum_large_64() {
         let a: Int64Array = (1..=100)
@@ -1141,6 +1387,95 @@ mod tests {
         assert_eq!(Some(true), max_boolean(&a));
     }
 
+    #[test]
+    fn test_fixed_size_binary_min_max() {
+        let a = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some(b"bb"), None, Some(b"ab"), Some(b"ba")].into_iter(),
+            2,
+        )
+        .unwrap();
+        assert_eq!(min_fixed_size_binary(&a), Some(b"ab".as_ref()));
+        assert_eq!(max_fixed_size_binary(&a), Some(b"bb".as_ref()));
+        assert_eq!(min_binary_array(&a), Some(b"ab".as_ref()));
+        assert_eq!(max_binary_array(&a), Some(b"bb".as_ref()));
+
+        let a = a.slice(1, 2);
+        let a = a.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+        assert_eq!(min_fixed_size_binary(a), Some(b"ab".as_ref()));
+        assert_eq!(max_fixed_size_binary(a), Some(b"ab".as_ref()));
+
+        let a = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![None::<&[u8]>, None].into_iter(),
+            2,
+        )
+        .unwrap();
+        assert_eq!(min_fixed_size_binary(&a), None);
+        assert_eq!(max_fixed_size_binary(&a), None);
+    }
+
+    #[test]
+    fn test_dictionary_binary_min_max() {
+        let values = BinaryArray::from(vec![b"c".as_ref(), b"a", b"b"]);
+        let keys = Int8Array::from(vec![Some(2), None, Some(0), Some(2)]);
+        let dict = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+        let array = dict.downcast_dict::<BinaryArray>().unwrap();
+        // "a" is not referenced by any key
+        assert_eq!(min_binary_array(array), Some(b"b".as_ref()));
+        assert_eq!(max_binary_array(array), Some(b"c".as_ref()));
+
+        let keys = Int8Array::from(vec![None, None]);
+        let dict = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+        let array = dict.downcast_dict::<BinaryArray>().unwrap();
+        assert_eq!(min_binary_array(array), None);
+        assert_eq!(max_binary_array(array), None);
+
+        let values = FixedSizeBinaryArray::try_from_iter(
+            vec![b"ba".as_ref(), b"aa", b"ab"].into_iter(),
+        )
+        .unwrap();
+        let keys = Int8Array::from(vec![Some(0), Some(2), None]);
+        let dict = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+        let array = dict.downcast_dict::<FixedSizeBinaryArray>().unwrap();
+        assert_eq!(min_binary_array(array), Some(b"ab".as_ref()));
+        assert_eq!(max_binary_array(array), Some(b"ba".as_ref()));
+    }
+
+    #[test]
+    fn test_dictionary_boolean_min_max() {
+        let values = BooleanArray::from(vec![true, false]);
+        let keys = Int8Array::from(vec![Some(0), None, Some(0)]);
+        let dict = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+        let array = dict.downcast_dict::<BooleanArray>().unwrap();
+        assert_eq!(min_boolean_array(array), Some(true));
+        assert_eq!(max_boolean_array(array), Some(true));
+
+        let keys = Int8Array::from(vec![Some(0), Some(1), None]);
+        let dict = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+        let array = dict.downcast_dict::<BooleanArray>().unwrap();
+        assert_eq!(min_boolean_array(array), Some(false));
+        assert_eq!(max_boolean_array(array), Some(true));
+
+        let keys = Int8Array::from(vec![None]);
+        let dict = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+        let array = dict.downcast_dict::<BooleanArray>().unwrap();
+        assert_eq!(min_boolean_array(array), None);
+        assert_eq!(max_boolean_array(array), None);
+
+        let a = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        assert_eq!(min_boolean_array(&a), Some(false));
+        assert_eq!(max_boolean_array(&a), Some(true));
+    }
+
+    #[test]
+    fn test_run_boolean_min_max() {
+        let run_ends = Int32Array::from(vec![2, 5]);
+        let values = BooleanArray::from(vec![true, false]);
+        let run = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+        let array = run.downcast::<BooleanArray>().unwrap();
+        assert_eq!(min_boolean_array(array), Some(false));
+        assert_eq!(max_boolean_array(array), Some(true));
+    }
+
     #[test]
     fn test_sum_dyn() {
         let values = Int8Array::from_iter_values([10_i8, 11, 12, 13, 14, 15, 16, 17]);
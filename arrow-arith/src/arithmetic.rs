@@ -24,8 +24,10 @@
 
 use crate::arity::*;
 use arrow_array::cast::*;
+use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::*;
+use arrow_buffer::ArrowNativeType;
 use arrow_schema::*;
 use num::traits::Pow;
 use std::sync::Arc;
@@ -432,6 +434,10 @@ macro_rules! typed_dict_op {
                 let array = $MATH_OP::<$KT, UInt64Type, _>($LEFT, $RIGHT, $OP)?;
                 Ok(Arc::new(array))
             }
+            (DataType::Float16, DataType::Float16) => {
+                let array = $MATH_OP::<$KT, Float16Type, _>($LEFT, $RIGHT, $OP)?;
+                Ok(Arc::new(array))
+            }
             (DataType::Float32, DataType::Float32) => {
                 let array = $MATH_OP::<$KT, Float32Type, _>($LEFT, $RIGHT, $OP)?;
                 Ok(Arc::new(array))
@@ -736,64 +742,220 @@ where
     try_binary(left, right, |a, b| a.add_checked(b))
 }
 
-/// Perform `left + right` operation on two arrays. If either left or right value is null
-/// then the result is also null.
-///
-/// This doesn't detect overflow. Once overflowing, the result will wrap around.
-/// For an overflow-checking variant, use `add_dyn_checked` instead.
-pub fn add_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef, ArrowError> {
-    match left.data_type() {
-        DataType::Dictionary(_, _) => {
-            typed_dict_math_op!(left, right, |a, b| a.add_wrapping(b), math_op_dict)
+/// Applies `op` to a timestamp array and a duration array of the same [`TimeUnit`],
+/// returning a timestamp array with the timezone of `left`
+fn timestamp_duration_op<F>(
+    left: &dyn Array,
+    right: &dyn Array,
+    op: F,
+) -> Result<ArrayRef, ArrowError>
+where
+    F: Fn(i64, i64) -> Result<i64, ArrowError>,
+{
+    match (left.data_type(), right.data_type()) {
+        (
+            DataType::Timestamp(TimeUnit::Second, tz),
+            DataType::Duration(TimeUnit::Second),
+        ) => {
+            let l = as_primitive_array::<TimestampSecondType>(left);
+            let r = as_primitive_array::<DurationSecondType>(right);
+            let res: TimestampSecondArray = try_binary(l, r, op)?;
+            Ok(Arc::new(res.with_timezone_opt(tz.clone())))
+        }
+        (
+            DataType::Timestamp(TimeUnit::Millisecond, tz),
+            DataType::Duration(TimeUnit::Millisecond),
+        ) => {
+            let l = as_primitive_array::<TimestampMillisecondType>(left);
+            let r = as_primitive_array::<DurationMillisecondType>(right);
+            let res: TimestampMillisecondArray = try_binary(l, r, op)?;
+            Ok(Arc::new(res.with_timezone_opt(tz.clone())))
+        }
+        (
+            DataType::Timestamp(TimeUnit::Microsecond, tz),
+            DataType::Duration(TimeUnit::Microsecond),
+        ) => {
+            let l = as_primitive_array::<TimestampMicrosecondType>(left);
+            let r = as_primitive_array::<DurationMicrosecondType>(right);
+            let res: TimestampMicrosecondArray = try_binary(l, r, op)?;
+            Ok(Arc::new(res.with_timezone_opt(tz.clone())))
         }
-        DataType::Date32 => {
-            let l = as_primitive_array::<Date32Type>(left);
-            match right.data_type() {
-                DataType::Interval(IntervalUnit::YearMonth) => {
+        (
+            DataType::Timestamp(TimeUnit::Nanosecond, tz),
+            DataType::Duration(TimeUnit::Nanosecond),
+        ) => {
+            let l = as_primitive_array::<TimestampNanosecondType>(left);
+            let r = as_primitive_array::<DurationNanosecondType>(right);
+            let res: TimestampNanosecondArray = try_binary(l, r, op)?;
+            Ok(Arc::new(res.with_timezone_opt(tz.clone())))
+        }
+        _ => Err(ArrowError::CastError(format!(
+            "Cannot perform arithmetic operation between array of type {} and array of type {}",
+            left.data_type(), right.data_type()
+        ))),
+    }
+}
+
+/// Adds an interval array to, or if `subtract` subtracts it from, a date array
+fn date_interval_op(
+    left: &dyn Array,
+    right: &dyn Array,
+    subtract: bool,
+) -> Result<ArrayRef, ArrowError> {
+    macro_rules! date_op {
+        ($t:ty) => {{
+            let l = as_primitive_array::<$t>(left);
+            let res = match (right.data_type(), subtract) {
+                (DataType::Interval(IntervalUnit::YearMonth), false) => {
+                    let r = as_primitive_array::<IntervalYearMonthType>(right);
+                    math_op(l, r, <$t>::add_year_months)?
+                }
+                (DataType::Interval(IntervalUnit::YearMonth), true) => {
                     let r = as_primitive_array::<IntervalYearMonthType>(right);
-                    let res = math_op(l, r, Date32Type::add_year_months)?;
-                    Ok(Arc::new(res))
+                    math_op(l, r, <$t>::subtract_year_months)?
                 }
-                DataType::Interval(IntervalUnit::DayTime) => {
+                (DataType::Interval(IntervalUnit::DayTime), false) => {
                     let r = as_primitive_array::<IntervalDayTimeType>(right);
-                    let res = math_op(l, r, Date32Type::add_day_time)?;
-                    Ok(Arc::new(res))
+                    math_op(l, r, <$t>::add_day_time)?
                 }
-                DataType::Interval(IntervalUnit::MonthDayNano) => {
+                (DataType::Interval(IntervalUnit::DayTime), true) => {
+                    let r = as_primitive_array::<IntervalDayTimeType>(right);
+                    math_op(l, r, <$t>::subtract_day_time)?
+                }
+                (DataType::Interval(IntervalUnit::MonthDayNano), false) => {
                     let r = as_primitive_array::<IntervalMonthDayNanoType>(right);
-                    let res = math_op(l, r, Date32Type::add_month_day_nano)?;
-                    Ok(Arc::new(res))
+                    math_op(l, r, <$t>::add_month_day_nano)?
                 }
-                _ => Err(ArrowError::CastError(format!(
+                (DataType::Interval(IntervalUnit::MonthDayNano), true) => {
+                    let r = as_primitive_array::<IntervalMonthDayNanoType>(right);
+                    math_op(l, r, <$t>::subtract_month_day_nano)?
+                }
+                _ => return Err(ArrowError::CastError(format!(
                     "Cannot perform arithmetic operation between array of type {} and array of type {}",
                     left.data_type(), right.data_type()
                 ))),
-            }
+            };
+            Ok(Arc::new(res))
+        }};
+    }
+
+    match left.data_type() {
+        DataType::Date32 => date_op!(Date32Type),
+        DataType::Date64 => date_op!(Date64Type),
+        _ => Err(ArrowError::CastError(format!(
+            "Cannot perform arithmetic operation between array of type {} and array of type {}",
+            left.data_type(), right.data_type()
+        ))),
+    }
+}
+
+/// Applies `op` to a timestamp array and an interval array, returning an error if `op`
+/// returns `None`
+fn timestamp_interval<T, I>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<I>,
+    tz: Tz,
+    op: fn(i64, I::Native, Tz) -> Option<i64>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowTimestampType<Native = i64>,
+    I: ArrowPrimitiveType,
+{
+    try_binary(left, right, |a, b| {
+        op(a, b, tz).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Overflow happened applying interval {b:?} to timestamp {a}"
+            ))
+        })
+    })
+}
+
+/// Adds an interval array to, or if `subtract` subtracts it from, a timestamp array
+///
+/// Months and days are added in the local time of the timezone of `left`, or UTC if it
+/// has no timezone, with the result having the same timezone as `left`
+fn timestamp_interval_op(
+    left: &dyn Array,
+    right: &dyn Array,
+    subtract: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let (unit, tz) = match left.data_type() {
+        DataType::Timestamp(unit, tz) => (unit, tz),
+        _ => {
+            return Err(ArrowError::CastError(format!(
+                "Cannot perform arithmetic operation between array of type {} and array of type {}",
+                left.data_type(), right.data_type()
+            )))
         }
-        DataType::Date64 => {
-            let l = as_primitive_array::<Date64Type>(left);
-            match right.data_type() {
-                DataType::Interval(IntervalUnit::YearMonth) => {
+    };
+    let parsed: Tz = tz.as_deref().unwrap_or("+00:00").parse()?;
+
+    macro_rules! timestamp_op {
+        ($t:ty) => {{
+            let l = as_primitive_array::<$t>(left);
+            let res = match (right.data_type(), subtract) {
+                (DataType::Interval(IntervalUnit::YearMonth), false) => {
                     let r = as_primitive_array::<IntervalYearMonthType>(right);
-                    let res = math_op(l, r, Date64Type::add_year_months)?;
-                    Ok(Arc::new(res))
+                    timestamp_interval(l, r, parsed, <$t>::add_year_months)?
                 }
-                DataType::Interval(IntervalUnit::DayTime) => {
+                (DataType::Interval(IntervalUnit::YearMonth), true) => {
+                    let r = as_primitive_array::<IntervalYearMonthType>(right);
+                    timestamp_interval(l, r, parsed, <$t>::subtract_year_months)?
+                }
+                (DataType::Interval(IntervalUnit::DayTime), false) => {
+                    let r = as_primitive_array::<IntervalDayTimeType>(right);
+                    timestamp_interval(l, r, parsed, <$t>::add_day_time)?
+                }
+                (DataType::Interval(IntervalUnit::DayTime), true) => {
                     let r = as_primitive_array::<IntervalDayTimeType>(right);
-                    let res = math_op(l, r, Date64Type::add_day_time)?;
-                    Ok(Arc::new(res))
+                    timestamp_interval(l, r, parsed, <$t>::subtract_day_time)?
                 }
-                DataType::Interval(IntervalUnit::MonthDayNano) => {
+                (DataType::Interval(IntervalUnit::MonthDayNano), false) => {
                     let r = as_primitive_array::<IntervalMonthDayNanoType>(right);
-                    let res = math_op(l, r, Date64Type::add_month_day_nano)?;
-                    Ok(Arc::new(res))
+                    timestamp_interval(l, r, parsed, <$t>::add_month_day_nano)?
                 }
-                _ => Err(ArrowError::CastError(format!(
+                (DataType::Interval(IntervalUnit::MonthDayNano), true) => {
+                    let r = as_primitive_array::<IntervalMonthDayNanoType>(right);
+                    timestamp_interval(l, r, parsed, <$t>::subtract_month_day_nano)?
+                }
+                _ => return Err(ArrowError::CastError(format!(
                     "Cannot perform arithmetic operation between array of type {} and array of type {}",
                     left.data_type(), right.data_type()
                 ))),
-            }
+            };
+            Ok(Arc::new(res.with_timezone_opt(tz.clone())))
+        }};
+    }
+
+    match unit {
+        TimeUnit::Second => timestamp_op!(TimestampSecondType),
+        TimeUnit::Millisecond => timestamp_op!(TimestampMillisecondType),
+        TimeUnit::Microsecond => timestamp_op!(TimestampMicrosecondType),
+        TimeUnit::Nanosecond => timestamp_op!(TimestampNanosecondType),
+    }
+}
+
+/// Perform `left + right` operation on two arrays. If either left or right value is null
+/// then the result is also null.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `add_dyn_checked` instead.
+pub fn add_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    match left.data_type() {
+        DataType::Dictionary(_, _) => {
+            typed_dict_math_op!(left, right, |a, b| a.add_wrapping(b), math_op_dict)
         }
+        DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Duration(_)) =>
+        {
+            timestamp_duration_op(left, right, |a, b| Ok(a.add_wrapping(b)))
+        }
+        DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Interval(_)) =>
+        {
+            timestamp_interval_op(left, right, false)
+        }
+        DataType::Date32 | DataType::Date64 => date_interval_op(left, right, false),
         _ => {
             downcast_primitive_array!(
                 (left, right) => {
@@ -826,54 +988,17 @@ pub fn add_dyn_checked(
                 math_checked_op_dict
             )
         }
-        DataType::Date32 => {
-            let l = as_primitive_array::<Date32Type>(left);
-            match right.data_type() {
-                DataType::Interval(IntervalUnit::YearMonth) => {
-                    let r = as_primitive_array::<IntervalYearMonthType>(right);
-                    let res = math_op(l, r, Date32Type::add_year_months)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::DayTime) => {
-                    let r = as_primitive_array::<IntervalDayTimeType>(right);
-                    let res = math_op(l, r, Date32Type::add_day_time)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::MonthDayNano) => {
-                    let r = as_primitive_array::<IntervalMonthDayNanoType>(right);
-                    let res = math_op(l, r, Date32Type::add_month_day_nano)?;
-                    Ok(Arc::new(res))
-                }
-                _ => Err(ArrowError::CastError(format!(
-                    "Cannot perform arithmetic operation between array of type {} and array of type {}",
-                    left.data_type(), right.data_type()
-                ))),
-            }
+        DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Duration(_)) =>
+        {
+            timestamp_duration_op(left, right, |a, b| a.add_checked(b))
         }
-        DataType::Date64 => {
-            let l = as_primitive_array::<Date64Type>(left);
-            match right.data_type() {
-                DataType::Interval(IntervalUnit::YearMonth) => {
-                    let r = as_primitive_array::<IntervalYearMonthType>(right);
-                    let res = math_op(l, r, Date64Type::add_year_months)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::DayTime) => {
-                    let r = as_primitive_array::<IntervalDayTimeType>(right);
-                    let res = math_op(l, r, Date64Type::add_day_time)?;
-                    Ok(Arc::new(res))
-                }
-                DataType::Interval(IntervalUnit::MonthDayNano) => {
-                    let r = as_primitive_array::<IntervalMonthDayNanoType>(right);
-                    let res = math_op(l, r, Date64Type::add_month_day_nano)?;
-                    Ok(Arc::new(res))
-                }
-                _ => Err(ArrowError::CastError(format!(
-                    "Cannot perform arithmetic operation between array of type {} and array of type {}",
-                    left.data_type(), right.data_type()
-                ))),
-            }
+        DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Interval(_)) =>
+        {
+            timestamp_interval_op(left, right, false)
         }
+        DataType::Date32 | DataType::Date64 => date_interval_op(left, right, false),
         _ => {
             downcast_primitive_array!(
                 (left, right) => {
@@ -928,6 +1053,7 @@ where
 /// For an overflow-checking variant, use `add_scalar_checked_dyn` instead.
 ///
 /// This returns an `Err` when the input array is not supported for adding operation.
+#[deprecated(note = "Use arrow_arith::numeric::add_wrapping")]
 pub fn add_scalar_dyn<T>(
     array: &dyn Array,
     scalar: T::Native,
@@ -948,6 +1074,7 @@ where
 ///
 /// As this kernel has the branching costs and also prevents LLVM from vectorising it correctly,
 /// it is usually much slower than non-checking variant.
+#[deprecated(note = "Use arrow_arith::numeric::add")]
 pub fn add_scalar_checked_dyn<T>(
     array: &dyn Array,
     scalar: T::Native,
@@ -1002,6 +1129,21 @@ pub fn subtract_dyn(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef, Arr
         DataType::Dictionary(_, _) => {
             typed_dict_math_op!(left, right, |a, b| a.sub_wrapping(b), math_op_dict)
         }
+        DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Duration(_)) =>
+        {
+            timestamp_duration_op(left, right, |a, b| Ok(a.sub_wrapping(b)))
+        }
+        DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Interval(_)) =>
+        {
+            timestamp_interval_op(left, right, true)
+        }
+        DataType::Date32 | DataType::Date64
+            if matches!(right.data_type(), DataType::Interval(_)) =>
+        {
+            date_interval_op(left, right, true)
+        }
         _ => {
             downcast_primitive_array!(
                 (left, right) => {
@@ -1034,6 +1176,21 @@ pub fn subtract_dyn_checked(
                 math_checked_op_dict
             )
         }
+        DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Duration(_)) =>
+        {
+            timestamp_duration_op(left, right, |a, b| a.sub_checked(b))
+        }
+        DataType::Timestamp(_, _)
+            if matches!(right.data_type(), DataType::Interval(_)) =>
+        {
+            timestamp_interval_op(left, right, true)
+        }
+        DataType::Date32 | DataType::Date64
+            if matches!(right.data_type(), DataType::Interval(_)) =>
+        {
+            date_interval_op(left, right, true)
+        }
         _ => {
             downcast_primitive_array!(
                 (left, right) => {
@@ -1086,6 +1243,7 @@ where
 ///
 /// This doesn't detect overflow. Once overflowing, the result will wrap around.
 /// For an overflow-checking variant, use `subtract_scalar_checked_dyn` instead.
+#[deprecated(note = "Use arrow_arith::numeric::sub_wrapping")]
 pub fn subtract_scalar_dyn<T>(
     array: &dyn Array,
     scalar: T::Native,
@@ -1103,6 +1261,7 @@ where
 ///
 /// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
 /// use `subtract_scalar_dyn` instead.
+#[deprecated(note = "Use arrow_arith::numeric::sub")]
 pub fn subtract_scalar_checked_dyn<T>(
     array: &dyn Array,
     scalar: T::Native,
@@ -1141,6 +1300,36 @@ where
     try_unary(array, |value| value.neg_checked())
 }
 
+/// Perform `-` operation on an array. If value is null then the result is also null.
+/// The given array must be a `PrimitiveArray` of type `T`, or a `DictionaryArray` or
+/// `RunArray` with values of type `T`, whose values are negated in place of the
+/// logical array.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `negate_checked_dyn` instead.
+pub fn negate_dyn<T>(array: &dyn Array) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    unary_dyn::<_, T>(array, |value| value.neg_wrapping())
+}
+
+/// Perform `-` operation on an array. If value is null then the result is also null.
+/// The given array must be a `PrimitiveArray` of type `T`, or a `DictionaryArray` or
+/// `RunArray` with values of type `T`, whose values are negated in place of the
+/// logical array.
+///
+/// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
+/// use `negate_dyn` instead.
+pub fn negate_checked_dyn<T>(array: &dyn Array) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    try_unary_dyn::<_, T>(array, |value| value.neg_checked())
+}
+
 /// Raise array with floating point values to the power of a scalar.
 pub fn powf_scalar<T>(
     array: &PrimitiveArray<T>,
@@ -1279,6 +1468,7 @@ where
 ///
 /// This doesn't detect overflow. Once overflowing, the result will wrap around.
 /// For an overflow-checking variant, use `multiply_scalar_checked_dyn` instead.
+#[deprecated(note = "Use arrow_arith::numeric::mul_wrapping")]
 pub fn multiply_scalar_dyn<T>(
     array: &dyn Array,
     scalar: T::Native,
@@ -1296,6 +1486,7 @@ where
 ///
 /// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
 /// use `multiply_scalar_dyn` instead.
+#[deprecated(note = "Use arrow_arith::numeric::mul")]
 pub fn multiply_scalar_checked_dyn<T>(
     array: &dyn Array,
     scalar: T::Native,
@@ -1593,6 +1784,7 @@ where
 /// Modulus every value in an array by a scalar. If any value in the array is null then the
 /// result is also null. If the scalar is zero then the result of this operation will be
 /// `Err(ArrowError::DivideByZero)`.
+#[deprecated(note = "Use arrow_arith::numeric::rem")]
 pub fn modulus_scalar_dyn<T>(
     array: &dyn Array,
     modulo: T::Native,
@@ -1631,6 +1823,7 @@ where
 ///
 /// This doesn't detect overflow. Once overflowing, the result will wrap around.
 /// For an overflow-checking variant, use `divide_scalar_checked_dyn` instead.
+#[deprecated(note = "Use arrow_arith::numeric::div")]
 pub fn divide_scalar_dyn<T>(
     array: &dyn Array,
     divisor: T::Native,
@@ -1652,6 +1845,7 @@ where
 ///
 /// This detects overflow and returns an `Err` for that. For an non-overflow-checking variant,
 /// use `divide_scalar_dyn` instead.
+#[deprecated(note = "Use arrow_arith::numeric::div")]
 pub fn divide_scalar_checked_dyn<T>(
     array: &dyn Array,
     divisor: T::Native,
@@ -1698,7 +1892,441 @@ where
     unary_dyn::<_, T>(array, |value| value.div_wrapping(divisor))
 }
 
+/// The arithmetic operation performed by a decimal kernel
+#[derive(Debug, Clone, Copy)]
+enum DecimalOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+/// Returns the precision and scale of the result of `op` on two decimal arrays
+///
+/// * add/subtract: `scale = max(s1, s2)`, `precision = max(p1 - s1, p2 - s2) + scale + 1`
+/// * multiply: `scale = s1 + s2`, `precision = p1 + p2 + 1`
+/// * divide: `scale = max(6, s1 + p2 + 1)`, `precision = p1 - s1 + s2 + scale`
+///
+/// The precision is capped to `T::MAX_PRECISION`, as is the scale of a division
+fn decimal_result_type<T: DecimalType>(
+    op: DecimalOp,
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<(u8, i8), ArrowError> {
+    let (p1, s1) = (left.precision() as i32, left.scale() as i32);
+    let (p2, s2) = (right.precision() as i32, right.scale() as i32);
+
+    let (precision, scale) = match op {
+        DecimalOp::Add | DecimalOp::Subtract => {
+            let scale = s1.max(s2);
+            ((p1 - s1).max(p2 - s2) + scale + 1, scale)
+        }
+        DecimalOp::Multiply => (p1 + p2 + 1, s1 + s2),
+        DecimalOp::Divide => {
+            let scale = (s1 + p2 + 1).max(6).min(T::MAX_SCALE as i32);
+            (p1 - s1 + s2 + scale, scale)
+        }
+    };
+
+    if scale > T::MAX_SCALE as i32 || scale < i8::MIN as i32 {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot represent the scale {} of the result of {:?} on {} and {}",
+            scale,
+            op,
+            left.data_type(),
+            right.data_type()
+        )));
+    }
+    let precision = precision.clamp(1, T::MAX_PRECISION as i32);
+    Ok((precision as u8, scale as i8))
+}
+
+/// Returns `10^exp` as the native type of `T`
+fn decimal_pow10<T>(exp: i32) -> Result<T::Native, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    T::Native::usize_as(10).pow_checked(exp as u32)
+}
+
+/// Applies `op` to two decimal arrays after rescaling them to the scale of the result
+fn decimal_add_sub<T, F>(
+    op: DecimalOp,
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    f: F,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+    F: Fn(T::Native, T::Native, T::Native, T::Native) -> Result<T::Native, ArrowError>,
+{
+    let (precision, scale) = decimal_result_type(op, left, right)?;
+    let l_mul = decimal_pow10::<T>(scale as i32 - left.scale() as i32)?;
+    let r_mul = decimal_pow10::<T>(scale as i32 - right.scale() as i32)?;
+    try_binary::<_, _, _, T>(left, right, |a, b| f(a, l_mul, b, r_mul))?
+        .with_precision_and_scale(precision, scale)
+}
+
+/// Perform `left + right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null.
+///
+/// The inputs are rescaled to the scale of the result, which is `max(s1, s2)`, with
+/// a precision of `max(p1 - s1, p2 - s2) + scale + 1` capped to `T::MAX_PRECISION`.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `add_decimal_checked` instead.
+pub fn add_decimal<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    decimal_add_sub(DecimalOp::Add, left, right, |a, l_mul, b, r_mul| {
+        Ok(a.mul_wrapping(l_mul).add_wrapping(b.mul_wrapping(r_mul)))
+    })
+}
+
+/// Perform `left + right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null.
+///
+/// This detects overflow, including values exceeding the precision of the result, and
+/// returns an `Err` for that. For an non-overflow-checking variant, use `add_decimal` instead.
+pub fn add_decimal_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let result = decimal_add_sub(DecimalOp::Add, left, right, |a, l_mul, b, r_mul| {
+        a.mul_checked(l_mul)?.add_checked(b.mul_checked(r_mul)?)
+    })?;
+    result.validate_decimal_precision(result.precision())?;
+    Ok(result)
+}
+
+/// Perform `left - right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null.
+///
+/// The inputs are rescaled to the scale of the result, which is `max(s1, s2)`, with
+/// a precision of `max(p1 - s1, p2 - s2) + scale + 1` capped to `T::MAX_PRECISION`.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `subtract_decimal_checked` instead.
+pub fn subtract_decimal<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    decimal_add_sub(DecimalOp::Subtract, left, right, |a, l_mul, b, r_mul| {
+        Ok(a.mul_wrapping(l_mul).sub_wrapping(b.mul_wrapping(r_mul)))
+    })
+}
+
+/// Perform `left - right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null.
+///
+/// This detects overflow, including values exceeding the precision of the result, and
+/// returns an `Err` for that. For an non-overflow-checking variant, use `subtract_decimal`
+/// instead.
+pub fn subtract_decimal_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let result =
+        decimal_add_sub(DecimalOp::Subtract, left, right, |a, l_mul, b, r_mul| {
+            a.mul_checked(l_mul)?.sub_checked(b.mul_checked(r_mul)?)
+        })?;
+    result.validate_decimal_precision(result.precision())?;
+    Ok(result)
+}
+
+/// Perform `left * right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null.
+///
+/// The result has a scale of `s1 + s2` and a precision of `p1 + p2 + 1` capped to
+/// `T::MAX_PRECISION`.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `multiply_decimal_checked` instead.
+pub fn multiply_decimal<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let (precision, scale) = decimal_result_type(DecimalOp::Multiply, left, right)?;
+    binary::<_, _, _, T>(left, right, |a, b| a.mul_wrapping(b))?
+        .with_precision_and_scale(precision, scale)
+}
+
+/// Perform `left * right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null.
+///
+/// This detects overflow, including values exceeding the precision of the result, and
+/// returns an `Err` for that. For an non-overflow-checking variant, use `multiply_decimal`
+/// instead.
+pub fn multiply_decimal_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let (precision, scale) = decimal_result_type(DecimalOp::Multiply, left, right)?;
+    let result = try_binary::<_, _, _, T>(left, right, |a, b| a.mul_checked(b))?
+        .with_precision_and_scale(precision, scale)?;
+    result.validate_decimal_precision(precision)?;
+    Ok(result)
+}
+
+/// Applies a decimal division `f(left * l_mul, right * r_mul)`, where the multipliers
+/// rescale the quotient to the scale of the result
+fn decimal_divide<T, F>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    f: F,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+    F: Fn(T::Native, T::Native, T::Native, T::Native) -> Result<T::Native, ArrowError>,
+{
+    let (precision, scale) = decimal_result_type(DecimalOp::Divide, left, right)?;
+    let exp = scale as i32 - left.scale() as i32 + right.scale() as i32;
+    let (l_mul, r_mul) = match exp >= 0 {
+        true => (decimal_pow10::<T>(exp)?, T::Native::ONE),
+        false => (T::Native::ONE, decimal_pow10::<T>(-exp)?),
+    };
+    try_binary::<_, _, _, T>(left, right, |a, b| {
+        if b.is_zero() {
+            return Err(ArrowError::DivideByZero);
+        }
+        f(a, l_mul, b, r_mul)
+    })?
+    .with_precision_and_scale(precision, scale)
+}
+
+/// Perform `left / right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null. If any right hand value is zero then the result
+/// of this operation will be `Err(ArrowError::DivideByZero)`.
+///
+/// The left hand side is rescaled so that the quotient has the scale of the result, which
+/// is `max(6, s1 + p2 + 1)`, with a precision of `p1 - s1 + s2 + scale`, both capped to the
+/// maximums of `T`.
+///
+/// This doesn't detect overflow. Once overflowing, the result will wrap around.
+/// For an overflow-checking variant, use `divide_decimal_checked` instead.
+pub fn divide_decimal<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    decimal_divide(left, right, |a, l_mul, b, r_mul| {
+        Ok(a.mul_wrapping(l_mul).div_wrapping(b.mul_wrapping(r_mul)))
+    })
+}
+
+/// Perform `left / right` operation on two decimal arrays. If either left or right value
+/// is null then the result is also null. If any right hand value is zero then the result
+/// of this operation will be `Err(ArrowError::DivideByZero)`.
+///
+/// This detects overflow, including values exceeding the precision of the result, and
+/// returns an `Err` for that. For an non-overflow-checking variant, use `divide_decimal`
+/// instead.
+pub fn divide_decimal_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let result = decimal_divide(left, right, |a, l_mul, b, r_mul| {
+        a.mul_checked(l_mul)?.div_checked(b.mul_checked(r_mul)?)
+    })?;
+    result.validate_decimal_precision(result.precision())?;
+    Ok(result)
+}
+
+/// Rescales a decimal array to `precision` and `scale`. If the scale is reduced, values
+/// are rounded half away from zero.
+///
+/// Returns an error if any value cannot be represented with the given precision and scale.
+///
+/// ```
+/// # use arrow_array::Decimal128Array;
+/// # use arrow_arith::arithmetic::rescale_decimal;
+/// let a = Decimal128Array::from(vec![Some(1234), None, Some(-1255)])
+///     .with_precision_and_scale(10, 3)
+///     .unwrap();
+/// let result = rescale_decimal(&a, 5, 2).unwrap();
+/// let expected = Decimal128Array::from(vec![Some(123), None, Some(-126)])
+///     .with_precision_and_scale(5, 2)
+///     .unwrap();
+/// assert_eq!(result, expected);
+/// ```
+pub fn rescale_decimal<T>(
+    array: &PrimitiveArray<T>,
+    precision: u8,
+    scale: i8,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let exp = scale as i32 - array.scale() as i32;
+    let result = if exp >= 0 {
+        let mul = decimal_pow10::<T>(exp)?;
+        array.try_unary::<_, T, _>(|v| v.mul_checked(mul))?
+    } else {
+        let div = decimal_pow10::<T>(-exp)?;
+        let half = div.div_wrapping(T::Native::usize_as(2));
+        let half_neg = half.neg_wrapping();
+        // div is >= 10 and so this cannot overflow
+        array.unary::<_, T>(|v| {
+            let d = v.div_wrapping(div);
+            let r = v.mod_wrapping(div);
+            match v >= T::Native::ZERO {
+                true if r >= half => d.add_wrapping(T::Native::ONE),
+                false if r <= half_neg => d.sub_wrapping(T::Native::ONE),
+                _ => d,
+            }
+        })
+    };
+
+    let result = result.with_precision_and_scale(precision, scale)?;
+    result.validate_decimal_precision(precision)?;
+    Ok(result)
+}
+
+/// Returns the types `left` and `right` are implicitly promoted to before applying an
+/// arithmetic kernel to arrays of different types, or `None` if they cannot be coerced.
+/// Equal types are returned unchanged.
+///
+/// * integers are widened to the smallest integer type that can represent both, mixed
+///   signed and unsigned integers are promoted to a signed type, capped at `Int64`
+/// * floats are widened to the larger float type, anything else combined with a float
+///   is promoted to `Float64`
+/// * integers combined with a decimal are promoted to a decimal with scale 0 and enough
+///   digits to represent any value of the integer type
+/// * `Decimal128` combined with a `Decimal256` is promoted to `Decimal256`
+///
+/// Decimals of differing precision and scale are not coerced to a common type, as the
+/// decimal kernels compute the precision and scale of the result from those of the inputs
+pub fn arithmetic_coercion(
+    left: &DataType,
+    right: &DataType,
+) -> Option<(DataType, DataType)> {
+    use DataType::*;
+    if left == right {
+        return Some((left.clone(), right.clone()));
+    }
+
+    match (left, right) {
+        (Decimal128(_, _), Decimal128(_, _)) | (Decimal256(_, _), Decimal256(_, _)) => {
+            Some((left.clone(), right.clone()))
+        }
+        (Decimal128(p, s), Decimal256(_, _)) => Some((Decimal256(*p, *s), right.clone())),
+        (Decimal256(_, _), Decimal128(p, s)) => Some((left.clone(), Decimal256(*p, *s))),
+        (Decimal128(_, _) | Decimal256(_, _), _) if integer_digits(right).is_some() => {
+            let digits = integer_digits(right)?;
+            let right = match left {
+                Decimal128(_, _) => Decimal128(digits, 0),
+                _ => Decimal256(digits, 0),
+            };
+            Some((left.clone(), right))
+        }
+        (_, Decimal128(_, _) | Decimal256(_, _)) => {
+            arithmetic_coercion(right, left).map(|(r, l)| (l, r))
+        }
+        (Float16 | Float32 | Float64, _) | (_, Float16 | Float32 | Float64) => {
+            if !left.is_numeric() || !right.is_numeric() {
+                return None;
+            }
+            let t = match (left, right) {
+                (Float16, Float32) | (Float32, Float16) => Float32,
+                _ => Float64,
+            };
+            Some((t.clone(), t))
+        }
+        _ => {
+            let (l_signed, l_bits) = integer_width(left)?;
+            let (r_signed, r_bits) = integer_width(right)?;
+            let t = match (l_signed, r_signed) {
+                (true, true) => signed_integer(l_bits.max(r_bits)),
+                (false, false) => match l_bits.max(r_bits) {
+                    8 => UInt8,
+                    16 => UInt16,
+                    32 => UInt32,
+                    _ => UInt64,
+                },
+                (true, false) => signed_integer(l_bits.max(r_bits * 2)),
+                (false, true) => signed_integer(r_bits.max(l_bits * 2)),
+            };
+            Some((t.clone(), t))
+        }
+    }
+}
+
+/// Returns whether `t` is a signed integer type and its width in bits
+fn integer_width(t: &DataType) -> Option<(bool, u8)> {
+    use DataType::*;
+    match t {
+        Int8 => Some((true, 8)),
+        Int16 => Some((true, 16)),
+        Int32 => Some((true, 32)),
+        Int64 => Some((true, 64)),
+        UInt8 => Some((false, 8)),
+        UInt16 => Some((false, 16)),
+        UInt32 => Some((false, 32)),
+        UInt64 => Some((false, 64)),
+        _ => None,
+    }
+}
+
+fn signed_integer(bits: u8) -> DataType {
+    match bits {
+        8 => DataType::Int8,
+        16 => DataType::Int16,
+        32 => DataType::Int32,
+        _ => DataType::Int64,
+    }
+}
+
+/// Returns the number of decimal digits needed to represent any value of integer type `t`
+fn integer_digits(t: &DataType) -> Option<u8> {
+    use DataType::*;
+    match t {
+        Int8 | UInt8 => Some(3),
+        Int16 | UInt16 => Some(5),
+        Int32 | UInt32 => Some(10),
+        Int64 => Some(19),
+        UInt64 => Some(20),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use super::*;
     use arrow_array::builder::{
@@ -2719,6 +3347,34 @@ mod tests {
         err.expect_err("negate_checked should detect overflow");
     }
 
+    #[test]
+    fn test_negate_dyn() {
+        let a = Int32Array::from(vec![Some(1), None, Some(i32::MIN)]);
+        let actual = negate_dyn::<Int32Type>(&a).unwrap();
+        let expected = Int32Array::from(vec![Some(-1), None, Some(i32::MIN)]);
+        assert_eq!(actual.as_primitive::<Int32Type>(), &expected);
+        negate_checked_dyn::<Int32Type>(&a).unwrap_err();
+
+        // only the run values are negated, the run ends are reused
+        let values = Int32Array::from(vec![Some(2), None, Some(-3)]);
+        let run_ends = Int16Array::from(vec![2, 3, 6]);
+        let a = RunArray::try_new(&run_ends, &values).unwrap();
+        let actual = negate_checked_dyn::<Int32Type>(&a).unwrap();
+        let actual = actual.as_run::<Int16Type>();
+        assert_eq!(actual.run_ends(), a.run_ends());
+        let expected = Int32Array::from(vec![Some(-2), None, Some(3)]);
+        assert_eq!(actual.values().as_primitive::<Int32Type>(), &expected);
+
+        let mut builder = PrimitiveDictionaryBuilder::<Int8Type, Int32Type>::new();
+        builder.append(5).unwrap();
+        builder.append(5).unwrap();
+        let a = builder.finish();
+        let actual = negate_dyn::<Int32Type>(&a).unwrap();
+        let actual = actual.as_dictionary::<Int8Type>();
+        let expected = Int32Array::from(vec![-5]);
+        assert_eq!(actual.values().as_primitive::<Int32Type>(), &expected);
+    }
+
     #[test]
     fn test_arithmetic_kernel_should_not_rely_on_padding() {
         let a: UInt8Array = (0..128_u8).into_iter().map(Some).collect();
@@ -3088,6 +3744,60 @@ mod tests {
         assert_eq!(e, r);
     }
 
+    #[test]
+    fn test_decimal256_precision_scale() {
+        let decimal256 = |values: Vec<Option<i128>>, precision, scale| {
+            values
+                .into_iter()
+                .map(|v| v.map(i256::from_i128))
+                .collect::<Decimal256Array>()
+                .with_precision_and_scale(precision, scale)
+                .unwrap()
+        };
+        // 1.23, 2.50, NULL
+        let a = decimal256(vec![Some(123), Some(250), None], 10, 2);
+        // 1.5, -0.5, 1.0
+        let b = decimal256(vec![Some(15), Some(-5), Some(10)], 8, 1);
+
+        let e = decimal256(vec![Some(273), Some(200), None], 11, 2);
+        assert_eq!(add_decimal(&a, &b).unwrap(), e);
+        assert_eq!(add_decimal_checked(&a, &b).unwrap(), e);
+
+        let e = decimal256(vec![Some(-27), Some(300), None], 11, 2);
+        assert_eq!(subtract_decimal(&a, &b).unwrap(), e);
+        assert_eq!(subtract_decimal_checked(&a, &b).unwrap(), e);
+
+        let e = decimal256(vec![Some(1845), Some(-1250), None], 19, 3);
+        assert_eq!(multiply_decimal(&a, &b).unwrap(), e);
+        assert_eq!(multiply_decimal_checked(&a, &b).unwrap(), e);
+
+        let e = decimal256(vec![Some(82000000000), Some(-500000000000), None], 20, 11);
+        assert_eq!(divide_decimal(&a, &b).unwrap(), e);
+        assert_eq!(divide_decimal_checked(&a, &b).unwrap(), e);
+
+        let zero = decimal256(vec![Some(1), Some(0), None], 8, 1);
+        let err = divide_decimal(&a, &zero).unwrap_err();
+        assert!(matches!(err, ArrowError::DivideByZero));
+    }
+
+    #[test]
+    fn test_decimal_checked_overflow() {
+        let a = Decimal128Array::from(vec![5 * 10_i128.pow(37)])
+            .with_precision_and_scale(38, 0)
+            .unwrap();
+
+        // the sum fits in an i128 but exceeds the maximum precision
+        let r = add_decimal(&a, &a).unwrap();
+        assert_eq!(r.value(0), 10_i128.pow(38));
+        let err = add_decimal_checked(&a, &a).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("too large to store in a Decimal128"));
+
+        let err = multiply_decimal_checked(&a, &a).unwrap_err();
+        assert!(err.to_string().contains("Overflow"), "{}", err);
+    }
+
     #[test]
     #[cfg(feature = "dyn_arith_dict")]
     fn test_dictionary_div_dyn_wrapping_overflow() {
@@ -3150,6 +3860,148 @@ mod tests {
         assert_eq!(&expected, &division_by_zero.unwrap());
     }
 
+    #[test]
+    fn test_timestamp_duration_dyn() {
+        let a = TimestampMillisecondArray::from(vec![Some(1000), None, Some(5000)])
+            .with_timezone("+01:00");
+        let b = DurationMillisecondArray::from(vec![Some(500), Some(1), Some(-6000)]);
+
+        let c = add_dyn(&a, &b).unwrap();
+        assert_eq!(
+            c.data_type(),
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string()))
+        );
+        let c = as_primitive_array::<TimestampMillisecondType>(&c);
+        assert_eq!(c.values(), &[1500, 0, -1000][..]);
+        assert!(c.is_null(1));
+
+        let c = subtract_dyn_checked(&a, &b).unwrap();
+        let c = as_primitive_array::<TimestampMillisecondType>(&c);
+        assert_eq!(c.value(0), 500);
+        assert_eq!(c.value(2), 11000);
+        assert!(c.is_null(1));
+
+        let overflow = DurationMillisecondArray::from(vec![i64::MAX, 0, 0]);
+        let err = add_dyn_checked(&a, &overflow).unwrap_err();
+        assert!(err.to_string().contains("Overflow"), "{}", err);
+
+        let b = DurationSecondArray::from(vec![1, 2, 3]);
+        let err = add_dyn(&a, &b).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Cannot perform arithmetic operation"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_duration_dyn() {
+        let a = DurationSecondArray::from(vec![Some(10), None, Some(-3)]);
+        let b = DurationSecondArray::from(vec![Some(5), Some(1), Some(3)]);
+
+        let c = add_dyn(&a, &b).unwrap();
+        let c = as_primitive_array::<DurationSecondType>(&c);
+        assert_eq!(c, &DurationSecondArray::from(vec![Some(15), None, Some(0)]));
+
+        let c = subtract_dyn(&a, &b).unwrap();
+        let c = as_primitive_array::<DurationSecondType>(&c);
+        assert_eq!(c, &DurationSecondArray::from(vec![Some(5), None, Some(-6)]));
+    }
+
+    #[test]
+    fn test_date_interval_subtract() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let a = Date32Array::from(vec![Date32Type::from_naive_date(date(2000, 3, 31))]);
+        let b =
+            IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNanoType::make_value(
+                1, 2, 0,
+            )]);
+        let c = subtract_dyn(&a, &b).unwrap();
+        let c = as_primitive_array::<Date32Type>(&c);
+        assert_eq!(c.value(0), Date32Type::from_naive_date(date(2000, 2, 27)));
+
+        let a = Date64Array::from(vec![Date64Type::from_naive_date(date(2000, 3, 1))]);
+        let b =
+            IntervalYearMonthArray::from(vec![IntervalYearMonthType::make_value(1, 1)]);
+        let c = subtract_dyn_checked(&a, &b).unwrap();
+        let c = as_primitive_array::<Date64Type>(&c);
+        assert_eq!(c.value(0), Date64Type::from_naive_date(date(1999, 2, 1)));
+
+        let b = IntervalDayTimeArray::from(vec![IntervalDayTimeType::make_value(1, 0)]);
+        let c = subtract_dyn(&a, &b).unwrap();
+        let c = as_primitive_array::<Date64Type>(&c);
+        assert_eq!(c.value(0), Date64Type::from_naive_date(date(2000, 2, 29)));
+    }
+
+    #[test]
+    fn test_timestamp_interval_dyn() {
+        let millis = |d, h| {
+            NaiveDate::from_ymd_opt(2023, 1, d)
+                .unwrap()
+                .and_hms_opt(h, 0, 0)
+                .unwrap()
+                .timestamp_millis()
+        };
+        let a = TimestampMillisecondArray::from(vec![Some(millis(30, 22)), None]);
+        let b = IntervalYearMonthArray::from(vec![Some(1), Some(1)]);
+
+        // 2023-01-30T22:00 + 1 month
+        let c = add_dyn(&a, &b).unwrap();
+        assert_eq!(c.data_type(), a.data_type());
+        let c = as_primitive_array::<TimestampMillisecondType>(&c);
+        let feb_28 = millis(30, 22) + 29 * 86_400_000;
+        assert_eq!(
+            c,
+            &TimestampMillisecondArray::from(vec![Some(feb_28), None])
+        );
+
+        // In +05:00 the local time is 2023-01-31T03:00, and so is clamped to the 28th
+        let a = a.with_timezone("+05:00");
+        let c = add_dyn_checked(&a, &b).unwrap();
+        assert_eq!(c.data_type(), a.data_type());
+        let c = as_primitive_array::<TimestampMillisecondType>(&c);
+        assert_eq!(c.value(0), feb_28 - 86_400_000);
+
+        let b = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNanoType::make_value(0, 1, 1_000_000),
+            IntervalMonthDayNanoType::make_value(0, 1, 0),
+        ]);
+        let c = subtract_dyn(&a, &b).unwrap();
+        let c = as_primitive_array::<TimestampMillisecondType>(&c);
+        assert_eq!(c.value(0), millis(29, 22) - 1);
+        assert!(c.is_null(1));
+
+        let b = IntervalDayTimeArray::from(vec![
+            IntervalDayTimeType::make_value(-1, 500),
+            IntervalDayTimeType::make_value(0, 0),
+        ]);
+        let c = subtract_dyn_checked(&a, &b).unwrap();
+        let c = as_primitive_array::<TimestampMillisecondType>(&c);
+        assert_eq!(c.value(0), millis(31, 22) - 500);
+
+        // Subtracting the most negative components does not overflow
+        let a = TimestampSecondArray::from(vec![0]);
+        let b =
+            IntervalMonthDayNanoArray::from(vec![IntervalMonthDayNanoType::make_value(
+                0,
+                0,
+                i64::MIN,
+            )]);
+        let c = subtract_dyn_checked(&a, &b).unwrap();
+        let c = as_primitive_array::<TimestampSecondType>(&c);
+        assert_eq!(c.value(0), i64::MAX / 1_000_000_000);
+
+        let a = TimestampNanosecondArray::from(vec![i64::MAX - 1]);
+        let b = IntervalYearMonthArray::from(vec![12]);
+        let err = add_dyn(&a, &b).unwrap_err().to_string();
+        assert!(err.contains("Overflow happened"), "{}", err);
+
+        let a = TimestampSecondArray::from(vec![0]).with_timezone("invalid");
+        let err = add_dyn(&a, &b).unwrap_err().to_string();
+        assert!(err.contains("invalid"), "{}", err);
+    }
+
     #[test]
     fn test_sum_f16() {
         let a = Float16Array::from_iter_values([
@@ -3172,6 +4024,47 @@ mod tests {
         assert_eq!(c, expected);
     }
 
+    #[test]
+    fn test_f16_dyn() {
+        let a = Float16Array::from_iter([
+            Some(f16::from_f32(1.5)),
+            None,
+            Some(f16::from_f32(-2.0)),
+        ]);
+        let b = Float16Array::from_iter([
+            Some(f16::from_f32(0.5)),
+            Some(f16::from_f32(1.0)),
+            Some(f16::from_f32(4.0)),
+        ]);
+
+        let c = subtract_dyn(&a, &b).unwrap();
+        let c = as_primitive_array::<Float16Type>(&c);
+        let expected = Float16Array::from_iter([
+            Some(f16::from_f32(1.0)),
+            None,
+            Some(f16::from_f32(-6.0)),
+        ]);
+        assert_eq!(c, &expected);
+
+        let c = multiply_dyn(&a, &b).unwrap();
+        let c = as_primitive_array::<Float16Type>(&c);
+        let expected = Float16Array::from_iter([
+            Some(f16::from_f32(0.75)),
+            None,
+            Some(f16::from_f32(-8.0)),
+        ]);
+        assert_eq!(c, &expected);
+
+        let c = divide_scalar_dyn::<Float16Type>(&a, f16::from_f32(2.0)).unwrap();
+        let c = as_primitive_array::<Float16Type>(&c);
+        let expected = Float16Array::from_iter([
+            Some(f16::from_f32(0.75)),
+            None,
+            Some(f16::from_f32(-1.0)),
+        ]);
+        assert_eq!(c, &expected);
+    }
+
     #[test]
     fn test_resize_builder() {
         let mut null_buffer_builder = BooleanBufferBuilder::new(16);
@@ -3362,4 +4255,82 @@ mod tests {
 
         assert_eq!(&expected, &result);
     }
+
+    #[test]
+    fn test_rescale_decimal() {
+        let a = Decimal128Array::from(vec![Some(1235), None, Some(-1235), Some(-1234)])
+            .with_precision_and_scale(6, 2)
+            .unwrap();
+
+        let r = rescale_decimal(&a, 6, 1).unwrap();
+        let expected =
+            Decimal128Array::from(vec![Some(124), None, Some(-124), Some(-123)])
+                .with_precision_and_scale(6, 1)
+                .unwrap();
+        assert_eq!(r, expected);
+
+        let r = rescale_decimal(&a, 8, 4).unwrap();
+        let expected =
+            Decimal128Array::from(vec![Some(123500), None, Some(-123500), Some(-123400)])
+                .with_precision_and_scale(8, 4)
+                .unwrap();
+        assert_eq!(r, expected);
+
+        let r = rescale_decimal(&a, 3, -1).unwrap();
+        let expected = Decimal128Array::from(vec![Some(1), None, Some(-1), Some(-1)])
+            .with_precision_and_scale(3, -1)
+            .unwrap();
+        assert_eq!(r, expected);
+
+        // 12.35 requires 6 digits with a scale of 4
+        let err = rescale_decimal(&a, 5, 4).unwrap_err().to_string();
+        assert!(err.contains("too large to store in a Decimal128"), "{err}");
+
+        let b = Decimal256Array::from(vec![i256::MAX])
+            .with_precision_and_scale(76, 0)
+            .unwrap();
+        let err = rescale_decimal(&b, 76, 1).unwrap_err().to_string();
+        assert!(err.contains("Overflow"), "{err}");
+    }
+
+    #[test]
+    fn test_arithmetic_coercion() {
+        use DataType::*;
+        let cases = [
+            (Int8, Int32, Int32, Int32),
+            (UInt16, UInt8, UInt16, UInt16),
+            (Int8, UInt8, Int16, Int16),
+            (UInt32, Int16, Int64, Int64),
+            (UInt64, Int64, Int64, Int64),
+            (Float16, Float32, Float32, Float32),
+            (Float32, Int32, Float64, Float64),
+            (Decimal128(10, 2), Float32, Float64, Float64),
+            (
+                Decimal128(10, 2),
+                Int32,
+                Decimal128(10, 2),
+                Decimal128(10, 0),
+            ),
+            (
+                UInt64,
+                Decimal256(10, 2),
+                Decimal256(20, 0),
+                Decimal256(10, 2),
+            ),
+            (
+                Decimal128(10, 2),
+                Decimal256(12, 4),
+                Decimal256(10, 2),
+                Decimal256(12, 4),
+            ),
+        ];
+        for (l, r, l_expected, r_expected) in cases {
+            let coerced = arithmetic_coercion(&l, &r);
+            assert_eq!(coerced, Some((l_expected, r_expected)), "{l} {r}");
+        }
+
+        assert_eq!(arithmetic_coercion(&Utf8, &Int32), None);
+        assert_eq!(arithmetic_coercion(&Decimal128(10, 2), &Utf8), None);
+        assert_eq!(arithmetic_coercion(&Boolean, &Float64), None);
+    }
 }
@@ -18,13 +18,15 @@
 //! Defines kernels suitable to perform operations to primitive arrays.
 
 use arrow_array::builder::BufferBuilder;
+use arrow_array::cast::as_run_array;
 use arrow_array::iterator::ArrayIter;
+use arrow_array::types::{Int16Type, Int32Type, Int64Type, RunEndIndexType};
 use arrow_array::*;
 use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_data::bit_iterator::try_for_each_valid_idx;
 use arrow_data::bit_mask::combine_option_bitmap;
 use arrow_data::ArrayData;
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, DataType};
 use std::sync::Arc;
 
 #[inline]
@@ -127,12 +129,63 @@ where
     Ok(Arc::new(array.with_values(&values)))
 }
 
+/// A helper function that applies an infallible unary function to the values of a
+/// run-end encoded array with primitive value type, reusing the existing run ends.
+fn unary_run<R, F, T>(array: &RunArray<R>, op: F) -> Result<ArrayRef, ArrowError>
+where
+    R: RunEndIndexType,
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> T::Native,
+{
+    if !PrimitiveArray::<T>::is_compatible(array.values().data_type()) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Cannot perform unary operation of type {} on run array of value type {}",
+            T::DATA_TYPE,
+            array.values().data_type()
+        )));
+    }
+
+    let run_values = array.values().as_any().downcast_ref().unwrap();
+    let values = unary::<T, F, T>(run_values, op);
+    Ok(Arc::new(array.with_values(&values)))
+}
+
+/// A helper function that applies a fallible unary function to the values of a
+/// run-end encoded array with primitive value type, reusing the existing run ends.
+fn try_unary_run<R, F, T>(array: &RunArray<R>, op: F) -> Result<ArrayRef, ArrowError>
+where
+    R: RunEndIndexType,
+    T: ArrowPrimitiveType,
+    F: Fn(T::Native) -> Result<T::Native, ArrowError>,
+{
+    if !PrimitiveArray::<T>::is_compatible(array.values().data_type()) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Cannot perform unary operation of type {} on run array of value type {}",
+            T::DATA_TYPE,
+            array.values().data_type()
+        )));
+    }
+
+    let run_values = array.values().as_any().downcast_ref().unwrap();
+    let values = try_unary::<T, F, T>(run_values, op)?;
+    Ok(Arc::new(array.with_values(&values)))
+}
+
 /// Applies an infallible unary function to an array with primitive values.
 pub fn unary_dyn<F, T>(array: &dyn Array, op: F) -> Result<ArrayRef, ArrowError>
 where
     T: ArrowPrimitiveType,
     F: Fn(T::Native) -> T::Native,
 {
+    if let DataType::RunEndEncoded(run_ends, _) = array.data_type() {
+        return match run_ends.data_type() {
+            DataType::Int16 => unary_run::<Int16Type, F, T>(as_run_array(array), op),
+            DataType::Int32 => unary_run::<Int32Type, F, T>(as_run_array(array), op),
+            DataType::Int64 => unary_run::<Int64Type, F, T>(as_run_array(array), op),
+            t => unreachable!("Invalid run end type {}", t),
+        };
+    }
+
     downcast_dictionary_array! {
         array => unary_dict::<_, F, T>(array, op),
         t => {
@@ -158,6 +211,15 @@ where
     T: ArrowPrimitiveType,
     F: Fn(T::Native) -> Result<T::Native, ArrowError>,
 {
+    if let DataType::RunEndEncoded(run_ends, _) = array.data_type() {
+        return match run_ends.data_type() {
+            DataType::Int16 => try_unary_run::<Int16Type, F, T>(as_run_array(array), op),
+            DataType::Int32 => try_unary_run::<Int32Type, F, T>(as_run_array(array), op),
+            DataType::Int64 => try_unary_run::<Int64Type, F, T>(as_run_array(array), op),
+            t => unreachable!("Invalid run end type {}", t),
+        };
+    }
+
     downcast_dictionary_array! {
         array => if array.values().data_type() == &T::DATA_TYPE {
             try_unary_dict::<_, F, T>(array, op)
@@ -615,6 +677,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_run_and_unary_dyn() {
+        let run_ends = Int32Array::from(vec![2, 3, 6]);
+        let values = Int64Array::from(vec![Some(5), None, Some(7)]);
+        let run_array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+
+        let result = unary_dyn::<_, Int64Type>(&run_array, |n| n * 2).unwrap();
+        let result = as_run_array::<Int32Type>(&result);
+        assert_eq!(result.len(), 6);
+        assert_eq!(result.run_ends(), &run_ends);
+        assert_eq!(
+            as_primitive_array::<Int64Type>(result.values()),
+            &Int64Array::from(vec![Some(10), None, Some(14)])
+        );
+
+        let sliced = run_array.slice(1, 4);
+        let result =
+            try_unary_dyn::<_, Int64Type>(sliced.as_ref(), |n| Ok(n.wrapping_neg()))
+                .unwrap();
+        let result = as_run_array::<Int32Type>(&result);
+        assert_eq!(result.len(), 4);
+        assert_eq!(result.offset(), 1);
+        assert_eq!(
+            as_primitive_array::<Int64Type>(result.values()),
+            &Int64Array::from(vec![Some(-5), None, Some(-7)])
+        );
+        let decoded: Vec<_> = (0..result.len())
+            .map(|i| {
+                let physical = result.get_physical_index(i).unwrap();
+                let values = as_primitive_array::<Int64Type>(result.values());
+                values.is_valid(physical).then(|| values.value(physical))
+            })
+            .collect();
+        assert_eq!(decoded, vec![Some(-5), None, Some(-7), Some(-7)]);
+
+        let err = unary_dyn::<_, Int32Type>(&run_array, |n| n + 1).unwrap_err();
+        assert!(err.to_string().contains("run array of value type Int64"));
+    }
+
     #[test]
     fn test_binary_mut() {
         let a = Int32Array::from(vec![15, 14, 9, 8, 1]);
@@ -336,6 +336,109 @@ pub fn or_kleene(
     binary_boolean_kernel(left, right, buffer_bin_or, build_null_buffer_for_or_kleene)
 }
 
+/// Logical 'and' of a boolean array and a scalar with Kleene logic, where a `None`
+/// scalar represents null
+///
+/// See [`and_kleene`] for the handling of nulls
+///
+/// # Example
+///
+/// ```rust
+/// # use arrow_array::BooleanArray;
+/// # use arrow_arith::boolean::and_kleene_scalar;
+/// let a = BooleanArray::from(vec![Some(true), Some(false), None]);
+/// let and_a = and_kleene_scalar(&a, None);
+/// assert_eq!(and_a, BooleanArray::from(vec![None, Some(false), None]));
+/// ```
+pub fn and_kleene_scalar(left: &BooleanArray, right: Option<bool>) -> BooleanArray {
+    kleene_scalar(left, right, false)
+}
+
+/// Logical 'or' of a boolean array and a scalar with Kleene logic, where a `None`
+/// scalar represents null
+///
+/// See [`or_kleene`] for the handling of nulls
+///
+/// # Example
+///
+/// ```rust
+/// # use arrow_array::BooleanArray;
+/// # use arrow_arith::boolean::or_kleene_scalar;
+/// let a = BooleanArray::from(vec![Some(true), Some(false), None]);
+/// let or_a = or_kleene_scalar(&a, None);
+/// assert_eq!(or_a, BooleanArray::from(vec![Some(true), None, None]));
+/// ```
+pub fn or_kleene_scalar(left: &BooleanArray, right: Option<bool>) -> BooleanArray {
+    kleene_scalar(left, right, true)
+}
+
+/// Helper function to implement the Kleene scalar kernels, where `dominant` is the value
+/// that determines the result regardless of the other operand, i.e. `false` for `AND`
+/// and `true` for `OR`
+fn kleene_scalar(
+    left: &BooleanArray,
+    right: Option<bool>,
+    dominant: bool,
+) -> BooleanArray {
+    let len = left.len();
+    let offset = left.offset();
+    let data = left.data_ref();
+    let left_values = &data.buffers()[0];
+
+    let (values, null_bit_buffer) = match right {
+        Some(right) if right == dominant => {
+            let len_bytes = ceil(len, 8);
+            let values = match dominant {
+                true => MutableBuffer::new(len_bytes).with_bitset(len_bytes, true),
+                false => MutableBuffer::from_len_zeroed(len_bytes),
+            };
+            (values.into(), None)
+        }
+        Some(_) => (
+            left_values.bit_slice(offset, len),
+            data.null_buffer().map(|b| b.bit_slice(offset, len)),
+        ),
+        None => {
+            // The result is only valid where the left value is valid and dominant, in
+            // which case the result value is the left value
+            let null_bit_buffer = match (data.null_buffer(), dominant) {
+                (None, true) => left_values.bit_slice(offset, len),
+                (None, false) => buffer_unary_not(left_values, offset, len),
+                (Some(nulls), true) => bitwise_bin_op_helper(
+                    nulls,
+                    offset,
+                    left_values,
+                    offset,
+                    len,
+                    |a, b| a & b,
+                ),
+                (Some(nulls), false) => bitwise_bin_op_helper(
+                    nulls,
+                    offset,
+                    left_values,
+                    offset,
+                    len,
+                    |a, b| a & !b,
+                ),
+            };
+            (left_values.bit_slice(offset, len), Some(null_bit_buffer))
+        }
+    };
+
+    let data = unsafe {
+        ArrayData::new_unchecked(
+            DataType::Boolean,
+            len,
+            None,
+            null_bit_buffer,
+            0,
+            vec![values],
+            vec![],
+        )
+    };
+    BooleanArray::from(data)
+}
+
 /// Performs unary `NOT` operation on an arrays. If value is null then the result is also
 /// null.
 /// # Error
@@ -453,6 +556,7 @@ pub fn is_not_null(input: &dyn Array) -> Result<BooleanArray, ArrowError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow_array::cast::as_boolean_array;
     use std::sync::Arc;
 
     #[test]
@@ -676,6 +780,56 @@ mod tests {
         assert_eq!(c, expected);
     }
 
+    #[test]
+    fn test_bool_array_kleene_scalar() {
+        let a = BooleanArray::from(vec![
+            None,
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+            Some(false),
+        ]);
+        let a = a.slice(1, 5);
+        let a = as_boolean_array(a.as_ref());
+
+        let cases = [
+            (None, vec![None, Some(false), None, None, Some(false)]),
+            (
+                Some(true),
+                vec![Some(true), Some(false), None, Some(true), Some(false)],
+            ),
+            (Some(false), vec![Some(false); 5]),
+        ];
+        for (scalar, expected) in cases {
+            let result = and_kleene_scalar(a, scalar);
+            assert_eq!(result, BooleanArray::from(expected), "{scalar:?}");
+        }
+
+        let cases = [
+            (None, vec![Some(true), None, None, Some(true), None]),
+            (Some(true), vec![Some(true); 5]),
+            (
+                Some(false),
+                vec![Some(true), Some(false), None, Some(true), Some(false)],
+            ),
+        ];
+        for (scalar, expected) in cases {
+            let result = or_kleene_scalar(a, scalar);
+            assert_eq!(result, BooleanArray::from(expected), "{scalar:?}");
+        }
+
+        // Scalar kernels agree with the array kernels
+        let b = BooleanArray::from(vec![true, false, true, false, true, false]);
+        let b = b.slice(2, 4);
+        let b = as_boolean_array(b.as_ref());
+        for scalar in [None, Some(true), Some(false)] {
+            let right = BooleanArray::from(vec![scalar; 4]);
+            assert_eq!(and_kleene_scalar(b, scalar), and_kleene(b, &right).unwrap());
+            assert_eq!(or_kleene_scalar(b, scalar), or_kleene(b, &right).unwrap());
+        }
+    }
+
     #[test]
     fn test_bool_array_not() {
         let a = BooleanArray::from(vec![false, true]);
@@ -0,0 +1,416 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to compute a hash for each row of one or more arrays, as used
+//! by hash joins and hash aggregations.
+
+use arrow_array::cast::*;
+use arrow_array::*;
+use arrow_buffer::{i256, ArrowNativeType};
+use arrow_schema::{ArrowError, DataType};
+use half::f16;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+/// Returns a [`UInt64Array`] containing the hash of each row of `columns`
+///
+/// The hashes of the individual columns are combined, in order, into a single hash
+/// per row. Rows that are equal hash to the same value for a given `state`, in
+/// particular:
+///
+/// * All null values of a column hash to the same value
+/// * A [`DictionaryArray`] hashes the same as its logical values
+/// * Sliced arrays hash the same as the equivalent unsliced arrays
+///
+/// Supports primitive, boolean, string and binary arrays, as well as dictionaries,
+/// lists and structs of supported types
+///
+/// ```
+/// # use std::collections::hash_map::RandomState;
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StringArray};
+/// # use arrow_arith::hash::hash;
+/// let state = RandomState::new();
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 1]));
+/// let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "a"]));
+/// let hashes = hash(&[a, b], &state).unwrap();
+/// assert_eq!(hashes.value(0), hashes.value(2));
+/// assert_ne!(hashes.value(0), hashes.value(1));
+/// ```
+pub fn hash<S: BuildHasher>(
+    columns: &[ArrayRef],
+    state: &S,
+) -> Result<UInt64Array, ArrowError> {
+    let first = columns.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "hash requires input of at least one column".to_string(),
+        )
+    })?;
+    if columns.iter().any(|c| c.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "hash columns have different row counts".to_string(),
+        ));
+    }
+
+    let mut hashes = hash_column(first.as_ref(), state)?;
+    for column in columns.iter().skip(1) {
+        let column_hashes = hash_column(column.as_ref(), state)?;
+        hashes
+            .iter_mut()
+            .zip(column_hashes)
+            .for_each(|(hash, column_hash)| *hash = combine_hashes(*hash, column_hash));
+    }
+    Ok(hashes.into())
+}
+
+/// Combines the hash `r` into the hash `l`
+#[inline]
+fn combine_hashes(l: u64, r: u64) -> u64 {
+    l.wrapping_mul(37).wrapping_add(r)
+}
+
+#[inline]
+fn hash_one<S: BuildHasher, T: Hash + ?Sized>(state: &S, value: &T) -> u64 {
+    let mut hasher = state.build_hasher();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The hash of a null value
+fn null_hash<S: BuildHasher>(state: &S) -> u64 {
+    hash_one(state, &())
+}
+
+/// A value that can be hashed, floating point values are hashed by their bit
+/// representation
+trait HashValue {
+    fn hash_value<S: BuildHasher>(&self, state: &S) -> u64;
+}
+
+macro_rules! hash_value {
+    ($($t:ty),+) => {
+        $(impl HashValue for $t {
+            #[inline]
+            fn hash_value<S: BuildHasher>(&self, state: &S) -> u64 {
+                hash_one(state, self)
+            }
+        })+
+    };
+}
+hash_value!(
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    u8,
+    u16,
+    u32,
+    u64,
+    i256,
+    bool,
+    str,
+    [u8]
+);
+
+macro_rules! hash_float {
+    ($($t:ty),+) => {
+        $(impl HashValue for $t {
+            #[inline]
+            fn hash_value<S: BuildHasher>(&self, state: &S) -> u64 {
+                hash_one(state, &self.to_bits())
+            }
+        })+
+    };
+}
+hash_float!(f16, f32, f64);
+
+impl<T: HashValue + ?Sized> HashValue for &T {
+    #[inline]
+    fn hash_value<S: BuildHasher>(&self, state: &S) -> u64 {
+        T::hash_value(self, state)
+    }
+}
+
+/// Returns the hash of each value of the [`ArrayAccessor`] `array`
+fn hash_accessor<A, S>(array: A, state: &S) -> Vec<u64>
+where
+    A: ArrayAccessor,
+    A::Item: HashValue,
+    S: BuildHasher,
+{
+    let null = null_hash(state);
+    (0..array.len())
+        .map(|idx| match array.is_valid(idx) {
+            true => array.value(idx).hash_value(state),
+            false => null,
+        })
+        .collect()
+}
+
+/// Returns the hash of a list of `len` values, whose hashes are `values`
+fn hash_list<S: BuildHasher>(state: &S, len: usize, values: &[u64]) -> u64 {
+    values.iter().fold(hash_one(state, &len), |hash, value| {
+        combine_hashes(hash, *value)
+    })
+}
+
+/// Returns the hash of each row of `array`
+fn hash_column<S: BuildHasher>(
+    array: &dyn Array,
+    state: &S,
+) -> Result<Vec<u64>, ArrowError> {
+    let hashes = downcast_primitive_array! {
+        array => hash_accessor(array, state),
+        DataType::Boolean => hash_accessor(as_boolean_array(array), state),
+        DataType::Utf8 => hash_accessor(as_string_array(array), state),
+        DataType::LargeUtf8 => hash_accessor(as_largestring_array(array), state),
+        DataType::Binary => hash_accessor(as_generic_binary_array::<i32>(array), state),
+        DataType::LargeBinary => {
+            hash_accessor(as_generic_binary_array::<i64>(array), state)
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+            hash_accessor(array, state)
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array! {
+            array => {
+                let values = hash_column(array.values().as_ref(), state)?;
+                let null = null_hash(state);
+                array
+                    .keys()
+                    .iter()
+                    .map(|key| key.map_or(null, |key| values[key.as_usize()]))
+                    .collect()
+            }
+            t => unreachable!("unexpected dictionary type {}", t)
+        },
+        DataType::List(_) => hash_generic_list(as_list_array(array), state)?,
+        DataType::LargeList(_) => hash_generic_list(as_large_list_array(array), state)?,
+        DataType::FixedSizeList(_, _) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = hash_column(array.values().as_ref(), state)?;
+            let null = null_hash(state);
+            let len = array.value_length() as usize;
+            (0..array.len())
+                .map(|idx| match array.is_valid(idx) {
+                    true => {
+                        let start = array.value_offset(idx) as usize;
+                        hash_list(state, len, &values[start..start + len])
+                    }
+                    false => null,
+                })
+                .collect()
+        }
+        DataType::Struct(_) => {
+            let array = as_struct_array(array);
+            let mut hashes = vec![hash_one(state, &array.num_columns()); array.len()];
+            for column in array.columns() {
+                let column_hashes = hash_column(column.as_ref(), state)?;
+                hashes.iter_mut().zip(column_hashes).for_each(|(hash, column_hash)| {
+                    *hash = combine_hashes(*hash, column_hash)
+                });
+            }
+            let null = null_hash(state);
+            hashes
+                .iter_mut()
+                .enumerate()
+                .filter(|(idx, _)| array.is_null(*idx))
+                .for_each(|(_, hash)| *hash = null);
+            hashes
+        }
+        t => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "hash is not supported for {t}"
+            )))
+        }
+    };
+    Ok(hashes)
+}
+
+fn hash_generic_list<O: OffsetSizeTrait, S: BuildHasher>(
+    array: &GenericListArray<O>,
+    state: &S,
+) -> Result<Vec<u64>, ArrowError> {
+    let values = hash_column(array.values().as_ref(), state)?;
+    let null = null_hash(state);
+    Ok(array
+        .value_offsets()
+        .windows(2)
+        .enumerate()
+        .map(|(idx, w)| match array.is_valid(idx) {
+            true => {
+                let (start, end) = (w[0].as_usize(), w[1].as_usize());
+                hash_list(state, end - start, &values[start..end])
+            }
+            false => null,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::types::*;
+    use arrow_buffer::Buffer;
+    use arrow_schema::Field;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::BuildHasherDefault;
+    use std::sync::Arc;
+
+    fn state() -> BuildHasherDefault<DefaultHasher> {
+        BuildHasherDefault::default()
+    }
+
+    fn hash_values(array: ArrayRef) -> Vec<u64> {
+        hash(&[array], &state()).unwrap().values().to_vec()
+    }
+
+    #[test]
+    fn test_hash() {
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(1),
+            None,
+            Some(1),
+            Some(2),
+            None,
+        ]));
+        let hashes = hash_values(array.clone());
+        assert_eq!(hashes[0], hashes[2]);
+        assert_eq!(hashes[1], hashes[4]);
+        assert_ne!(hashes[0], hashes[3]);
+        assert_ne!(hashes[0], hashes[1]);
+
+        // Sliced arrays hash the same
+        assert_eq!(hash_values(array.slice(1, 3)), &hashes[1..4]);
+
+        // Dictionaries hash the same as their logical values
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("b"),
+            Some("a"),
+        ]));
+        let dictionary: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("b"), Some("a")]
+                .into_iter()
+                .collect();
+        let hashes = hash_values(strings);
+        assert_eq!(hash_values(Arc::new(dictionary)), hashes);
+        assert_eq!(hashes[0], hashes[3]);
+        assert_ne!(hashes[0], hashes[2]);
+
+        let floats: ArrayRef = Arc::new(Float64Array::from(vec![1.5, 2.5, 1.5]));
+        let hashes = hash_values(floats);
+        assert_eq!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+
+        let err = hash(&[], &state()).unwrap_err().to_string();
+        assert!(err.contains("at least one column"), "{err}");
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let err = hash(&[a, b], &state()).unwrap_err().to_string();
+        assert!(err.contains("different row counts"), "{err}");
+
+        let union = UnionArray::try_new(
+            &[0],
+            Buffer::from_slice_ref([0_i8]),
+            None,
+            vec![(
+                Field::new("a", DataType::Int32, false),
+                Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+            )],
+        )
+        .unwrap();
+        let err = hash(&[Arc::new(union)], &state()).unwrap_err().to_string();
+        assert!(err.contains("hash is not supported for Union"), "{err}");
+    }
+
+    #[test]
+    fn test_hash_multiple_columns() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 1, 2, 1]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("x"),
+            Some("y"),
+            Some("x"),
+            Some("x"),
+        ]));
+        let hashes = hash(&[a.clone(), b.clone()], &state()).unwrap();
+        assert_eq!(hashes.null_count(), 0);
+        assert_eq!(hashes.value(0), hashes.value(3));
+        assert_ne!(hashes.value(0), hashes.value(1));
+        assert_ne!(hashes.value(0), hashes.value(2));
+
+        // Column order matters
+        let swapped = hash(&[b, a], &state()).unwrap();
+        assert_ne!(hashes.value(0), swapped.value(0));
+    }
+
+    #[test]
+    fn test_hash_nested() {
+        let list: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![]),
+                None,
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(1)]),
+                Some(vec![Some(2), Some(1)]),
+            ]));
+        let hashes = hash_values(list.clone());
+        assert_eq!(hashes[0], hashes[3]);
+        assert_ne!(hashes[0], hashes[4]);
+        assert_ne!(hashes[0], hashes[5]);
+        assert_ne!(hashes[1], hashes[2]);
+        assert_eq!(hash_values(list.slice(2, 4)), &hashes[2..]);
+
+        let fixed: ArrayRef =
+            Arc::new(FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+                vec![
+                    Some(vec![Some(1), Some(2)]),
+                    None,
+                    Some(vec![Some(1), Some(2)]),
+                ],
+                2,
+            ));
+        let fixed_hashes = hash_values(fixed.clone());
+        assert_eq!(fixed_hashes[0], fixed_hashes[2]);
+        assert_eq!(fixed_hashes[0], hashes[0]);
+        assert_eq!(hash_values(fixed.slice(1, 2)), &fixed_hashes[1..]);
+
+        let structs = StructArray::from((
+            vec![
+                (
+                    Field::new("a", DataType::Int32, true),
+                    Arc::new(Int32Array::from(vec![Some(1), Some(2), Some(1), None]))
+                        as ArrayRef,
+                ),
+                (
+                    Field::new("b", DataType::Utf8, true),
+                    Arc::new(StringArray::from(vec!["x", "y", "x", "z"])) as ArrayRef,
+                ),
+            ],
+            Buffer::from([0b00000111]),
+        ));
+        let structs: ArrayRef = Arc::new(structs);
+        let hashes = hash_values(structs.clone());
+        assert_eq!(hashes[0], hashes[2]);
+        assert_ne!(hashes[0], hashes[1]);
+        assert_eq!(hashes[3], null_hash(&state()));
+        assert_eq!(hash_values(structs.slice(1, 3)), &hashes[1..]);
+    }
+}
@@ -15,11 +15,13 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Arrow arithmetic and aggregation kernels
+//! Arrow arithmetic, aggregation and hash kernels
 
 pub mod aggregate;
 pub mod arithmetic;
 pub mod arity;
 pub mod bitwise;
 pub mod boolean;
+pub mod hash;
+pub mod numeric;
 pub mod temporal;
@@ -0,0 +1,408 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Arithmetic kernels for [`Datum`]
+//!
+//! Unlike the kernels in [`arithmetic`](crate::arithmetic), these accept either argument
+//! as a [`Scalar`](arrow_array::Scalar), removing the need for separate `*_scalar` kernels.
+//! The `*_scalar_dyn` kernels in [`arithmetic`](crate::arithmetic) are deprecated in
+//! favour of these.
+//!
+//! Decimal arguments are evaluated with the decimal kernels, such as
+//! [`add_decimal_checked`](crate::arithmetic::add_decimal_checked), and so the result has
+//! the precision and scale documented there
+//!
+//! ```
+//! # use arrow_array::{Int32Array, Scalar};
+//! # use arrow_arith::numeric::sub;
+//! let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+//! let scalar = Scalar::new(Int32Array::from(vec![10]));
+//! let result = sub(&scalar, &array).unwrap();
+//! assert_eq!(result.as_ref(), &Int32Array::from(vec![Some(9), None, Some(7)]));
+//! ```
+
+use crate::arithmetic::*;
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_data::transform::MutableArrayData;
+use arrow_schema::{ArrowError, DataType};
+use std::sync::Arc;
+
+#[derive(Debug, Copy, Clone)]
+enum Op {
+    Add,
+    AddWrapping,
+    Sub,
+    SubWrapping,
+    Mul,
+    MulWrapping,
+    Div,
+    Rem,
+}
+
+impl Op {
+    fn native<T: ArrowNativeTypeOp>(self, l: T, r: T) -> Result<T, ArrowError> {
+        match self {
+            Op::Add => l.add_checked(r),
+            Op::AddWrapping => Ok(l.add_wrapping(r)),
+            Op::Sub => l.sub_checked(r),
+            Op::SubWrapping => Ok(l.sub_wrapping(r)),
+            Op::Mul => l.mul_checked(r),
+            Op::MulWrapping => Ok(l.mul_wrapping(r)),
+            Op::Div => l.div_checked(r),
+            Op::Rem => l.mod_checked(r),
+        }
+    }
+
+    fn dyn_op(self, l: &dyn Array, r: &dyn Array) -> Result<ArrayRef, ArrowError> {
+        match self {
+            Op::Add => add_dyn_checked(l, r),
+            Op::AddWrapping => add_dyn(l, r),
+            Op::Sub => subtract_dyn_checked(l, r),
+            Op::SubWrapping => subtract_dyn(l, r),
+            Op::Mul => multiply_dyn_checked(l, r),
+            Op::MulWrapping => multiply_dyn(l, r),
+            Op::Div => divide_dyn_checked(l, r),
+            Op::Rem => modulus_dyn(l, r),
+        }
+    }
+
+    fn decimal<T>(self, l: &dyn Array, r: &dyn Array) -> Result<ArrayRef, ArrowError>
+    where
+        T: DecimalType,
+        T::Native: ArrowNativeTypeOp,
+    {
+        let (l, r) = (l.as_primitive::<T>(), r.as_primitive::<T>());
+        let result = match self {
+            Op::Add => add_decimal_checked(l, r),
+            Op::AddWrapping => add_decimal(l, r),
+            Op::Sub => subtract_decimal_checked(l, r),
+            Op::SubWrapping => subtract_decimal(l, r),
+            Op::Mul => multiply_decimal_checked(l, r),
+            Op::MulWrapping => multiply_decimal(l, r),
+            Op::Div => divide_decimal_checked(l, r),
+            Op::Rem => Err(ArrowError::ComputeError(format!(
+                "Remainder is not supported for decimal arrays of type {}",
+                l.data_type()
+            ))),
+        }?;
+        Ok(Arc::new(result))
+    }
+}
+
+/// Perform `lhs + rhs`, returning an error on overflow
+pub fn add(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op(Op::Add, lhs, rhs)
+}
+
+/// Perform `lhs + rhs`, wrapping on overflow
+pub fn add_wrapping(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op(Op::AddWrapping, lhs, rhs)
+}
+
+/// Perform `lhs - rhs`, returning an error on overflow
+pub fn sub(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op(Op::Sub, lhs, rhs)
+}
+
+/// Perform `lhs - rhs`, wrapping on overflow
+pub fn sub_wrapping(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op(Op::SubWrapping, lhs, rhs)
+}
+
+/// Perform `lhs * rhs`, returning an error on overflow
+pub fn mul(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op(Op::Mul, lhs, rhs)
+}
+
+/// Perform `lhs * rhs`, wrapping on overflow
+pub fn mul_wrapping(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op(Op::MulWrapping, lhs, rhs)
+}
+
+/// Perform `lhs / rhs`
+///
+/// Returns an error on overflow, or if any `rhs` value is zero
+pub fn div(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op(Op::Div, lhs, rhs)
+}
+
+/// Perform `lhs % rhs`
+///
+/// Returns an error if any `rhs` value is zero
+pub fn rem(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    arithmetic_op(Op::Rem, lhs, rhs)
+}
+
+fn arithmetic_op(
+    op: Op,
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+) -> Result<ArrayRef, ArrowError> {
+    let (l, l_scalar) = lhs.get();
+    let (r, r_scalar) = rhs.get();
+    array_op(op, (l, l_scalar), (r, r_scalar))
+}
+
+fn array_op(
+    op: Op,
+    (l, l_scalar): (&dyn Array, bool),
+    (r, r_scalar): (&dyn Array, bool),
+) -> Result<ArrayRef, ArrowError> {
+    // Apply `op` to the values of a run-end encoded array, reusing its run ends
+    match (l.data_type(), r.data_type()) {
+        (DataType::RunEndEncoded(_, _), _) if r_scalar && !l_scalar => {
+            return run_op(l, |values| array_op(op, (values, false), (r, true)))
+        }
+        (_, DataType::RunEndEncoded(_, _)) if l_scalar && !r_scalar => {
+            return run_op(r, |values| array_op(op, (l, true), (values, false)))
+        }
+        _ => {}
+    }
+
+    if is_decimal(l.data_type()) || is_decimal(r.data_type()) {
+        return decimal_op(op, (l, l_scalar), (r, r_scalar));
+    }
+
+    let (array, scalar, scalar_left) = match (l_scalar, r_scalar) {
+        (false, true) => (l, r, false),
+        (true, false) => (r, l, true),
+        _ => return op.dyn_op(l, r),
+    };
+
+    if array.data_type().is_numeric() && array.data_type() == scalar.data_type() {
+        if scalar.is_null(0) {
+            return Ok(new_null_array(array.data_type(), array.len()));
+        }
+        return downcast_primitive_array!(
+            (array, scalar) => {
+                let result = scalar_op(op, array, scalar.value(0), scalar_left)?;
+                Ok(Arc::new(result) as ArrayRef)
+            }
+            _ => unreachable!()
+        );
+    }
+
+    // Fallback to broadcasting the scalar to the length of the array
+    let scalar = broadcast(scalar, array.len());
+    match scalar_left {
+        true => op.dyn_op(scalar.as_ref(), array),
+        false => op.dyn_op(array, scalar.as_ref()),
+    }
+}
+
+/// Replaces the values of the run-end encoded `array` with `op(values)`
+fn run_op<F>(array: &dyn Array, op: F) -> Result<ArrayRef, ArrowError>
+where
+    F: FnOnce(&dyn Array) -> Result<ArrayRef, ArrowError>,
+{
+    downcast_run_array!(
+        array => {
+            let values = op(array.values().as_ref())?;
+            Ok(Arc::new(array.with_values(values.as_ref())))
+        },
+        t => unreachable!("expected run-end encoded array, got {t}")
+    )
+}
+
+fn is_decimal(t: &DataType) -> bool {
+    matches!(t, DataType::Decimal128(_, _) | DataType::Decimal256(_, _))
+}
+
+/// Applies `op` to decimal arguments, broadcasting a scalar argument to the length
+/// of the array argument, as the decimal kernels rescale their inputs
+fn decimal_op(
+    op: Op,
+    (l, l_scalar): (&dyn Array, bool),
+    (r, r_scalar): (&dyn Array, bool),
+) -> Result<ArrayRef, ArrowError> {
+    let l = match l_scalar && !r_scalar {
+        true => broadcast(l, r.len()),
+        false => make_array(l.data().clone()),
+    };
+    let r = match r_scalar && !l_scalar {
+        true => broadcast(r, l.len()),
+        false => make_array(r.data().clone()),
+    };
+
+    match (l.data_type(), r.data_type()) {
+        (DataType::Decimal128(_, _), DataType::Decimal128(_, _)) => {
+            op.decimal::<Decimal128Type>(l.as_ref(), r.as_ref())
+        }
+        (DataType::Decimal256(_, _), DataType::Decimal256(_, _)) => {
+            op.decimal::<Decimal256Type>(l.as_ref(), r.as_ref())
+        }
+        (l_t, r_t) => Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot perform {op:?} on decimal arguments of type {l_t} and {r_t}"
+        ))),
+    }
+}
+
+fn scalar_op<T: ArrowPrimitiveType>(
+    op: Op,
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+    scalar_left: bool,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    match scalar_left {
+        true => array.try_unary(|v| op.native(scalar, v)),
+        false => array.try_unary(|v| op.native(v, scalar)),
+    }
+}
+
+/// Repeats the single value in `scalar` `len` times
+fn broadcast(scalar: &dyn Array, len: usize) -> ArrayRef {
+    let mut mutable = MutableArrayData::new(vec![scalar.data()], false, len);
+    (0..len).for_each(|_| mutable.extend(0, 0, 1));
+    make_array(mutable.freeze())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_schema::TimeUnit;
+
+    #[test]
+    fn test_primitive_scalar() {
+        let a = Int32Array::from(vec![Some(1), None, Some(i32::MAX)]);
+        let s = Scalar::new(Int32Array::from(vec![1]));
+
+        let r = add_wrapping(&a, &s).unwrap();
+        let expected = Int32Array::from(vec![Some(2), None, Some(i32::MIN)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let err = add(&s, &a).unwrap_err().to_string();
+        assert_eq!(err, "Compute error: Overflow happened on: 1 + 2147483647");
+
+        let r = sub(&s, &a).unwrap();
+        let expected = Int32Array::from(vec![Some(0), None, Some(1 - i32::MAX)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let r = sub(&a, &s).unwrap();
+        let expected = Int32Array::from(vec![Some(0), None, Some(i32::MAX - 1)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let zero = Scalar::new(Int32Array::from(vec![0]));
+        let err = div(&a, &zero).unwrap_err();
+        assert!(matches!(err, ArrowError::DivideByZero));
+        let err = rem(&a, &zero).unwrap_err();
+        assert!(matches!(err, ArrowError::DivideByZero));
+
+        let r = rem(&Scalar::new(Int32Array::from(vec![7])), &a).unwrap();
+        let expected = Int32Array::from(vec![Some(0), None, Some(7)]);
+        assert_eq!(r.as_primitive::<Int32Type>(), &expected);
+
+        let null = Scalar::new(Int32Array::from(vec![None]));
+        let r = mul(&a, &null).unwrap();
+        assert_eq!(r.data_type(), &DataType::Int32);
+        assert_eq!(r.null_count(), 3);
+
+        let r = mul_wrapping(&s, &s).unwrap();
+        assert_eq!(r.as_primitive::<Int32Type>(), &Int32Array::from(vec![1]));
+    }
+
+    #[test]
+    fn test_arrays() {
+        let a = Float64Array::from(vec![1.0, 2.0, 3.0]);
+        let b = Float64Array::from(vec![2.0, 2.0, 2.0]);
+        let r = div(&a, &b).unwrap();
+        let expected = Float64Array::from(vec![0.5, 1.0, 1.5]);
+        assert_eq!(r.as_primitive::<Float64Type>(), &expected);
+
+        let b: &dyn Array = &b;
+        let r = sub_wrapping(&a, &b).unwrap();
+        let expected = Float64Array::from(vec![-1.0, 0.0, 1.0]);
+        assert_eq!(r.as_primitive::<Float64Type>(), &expected);
+    }
+
+    #[test]
+    fn test_timestamp_duration_scalar() {
+        let a = TimestampSecondArray::from(vec![Some(1), None, Some(3)]);
+        let s = Scalar::new(DurationSecondArray::from(vec![10]));
+        let r = add(&a, &s).unwrap();
+        assert_eq!(r.data_type(), &DataType::Timestamp(TimeUnit::Second, None));
+        let expected = TimestampSecondArray::from(vec![Some(11), None, Some(13)]);
+        assert_eq!(r.as_primitive::<TimestampSecondType>(), &expected);
+    }
+
+    #[test]
+    fn test_decimal_scalar() {
+        let a = Decimal128Array::from(vec![Some(1025), None, Some(-50)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let s = Scalar::new(
+            Decimal128Array::from(vec![15])
+                .with_precision_and_scale(5, 1)
+                .unwrap(),
+        );
+
+        let r = add(&a, &s).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(11, 2));
+        let expected = Decimal128Array::from(vec![Some(1175), None, Some(100)])
+            .with_precision_and_scale(11, 2)
+            .unwrap();
+        assert_eq!(r.as_primitive::<Decimal128Type>(), &expected);
+
+        let r = sub(&s, &a).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(11, 2));
+        let expected = Decimal128Array::from(vec![Some(-875), None, Some(200)])
+            .with_precision_and_scale(11, 2)
+            .unwrap();
+        assert_eq!(r.as_primitive::<Decimal128Type>(), &expected);
+
+        let r = mul(&a, &s).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(16, 3));
+        let expected = Decimal128Array::from(vec![Some(15375), None, Some(-750)])
+            .with_precision_and_scale(16, 3)
+            .unwrap();
+        assert_eq!(r.as_primitive::<Decimal128Type>(), &expected);
+
+        let err = rem(&a, &s).unwrap_err().to_string();
+        assert!(err.contains("not supported"), "{err}");
+
+        let b = Decimal256Array::from(vec![arrow_buffer::i256::from_i128(1)]);
+        let err = add(&a, &Scalar::new(b)).unwrap_err().to_string();
+        assert!(err.contains("Cannot perform Add"), "{err}");
+    }
+
+    #[test]
+    fn test_run_end_encoded_scalar() {
+        let values = Int32Array::from(vec![Some(1), None, Some(i32::MAX)]);
+        let run_ends = Int32Array::from(vec![2, 3, 5]);
+        let a = RunArray::try_new(&run_ends, &values).unwrap();
+        let s = Scalar::new(Int32Array::from(vec![1]));
+
+        // only the run values are computed, the run ends are reused
+        let r = sub(&s, &a).unwrap();
+        let r = r.as_run::<Int32Type>();
+        assert_eq!(r.run_ends(), a.run_ends());
+        let expected = Int32Array::from(vec![Some(0), None, Some(1 - i32::MAX)]);
+        assert_eq!(r.values().as_primitive::<Int32Type>(), &expected);
+
+        let r = add_wrapping(&a, &s).unwrap();
+        let r = r.as_run::<Int32Type>();
+        let expected = Int32Array::from(vec![Some(2), None, Some(i32::MIN)]);
+        assert_eq!(r.values().as_primitive::<Int32Type>(), &expected);
+
+        let err = add(&a, &s).unwrap_err().to_string();
+        assert_eq!(err, "Compute error: Overflow happened on: 2147483647 + 1");
+    }
+}
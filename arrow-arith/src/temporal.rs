@@ -17,19 +17,23 @@
 
 //! Defines temporal kernels for time and date related functions.
 
-use chrono::{DateTime, Datelike, NaiveDateTime, NaiveTime, Offset, Timelike};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDateTime, NaiveTime,
+    Offset, TimeZone, Timelike,
+};
 use std::sync::Arc;
 
 use arrow_array::builder::*;
+use arrow_array::cast::AsArray;
 use arrow_array::iterator::ArrayIter;
 use arrow_array::temporal_conversions::{
-    as_datetime, as_datetime_with_timezone, as_time,
+    as_datetime, as_datetime_with_timezone, as_duration, as_time,
 };
 use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
-use arrow_schema::{ArrowError, DataType};
+use arrow_schema::{ArrowError, DataType, TimeUnit};
 
 /// This function takes an `ArrayIter` of input array and an extractor `op` which takes
 /// an input `NaiveTime` and returns time component (e.g. hour) as `i32` value.
@@ -506,6 +510,421 @@ pub fn second_dyn(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
     time_fraction_dyn(array, "second", |t| t.second() as i32)
 }
 
+/// A part of a date, time, timestamp or duration that can be extracted with [`date_part`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DatePart {
+    /// The year
+    Year,
+    /// The quarter of the year, in the range `1..=4`
+    Quarter,
+    /// The month of the year, in the range `1..=12`
+    Month,
+    /// The ISO 8601 week of the year, in the range `1..=53`
+    Week,
+    /// The day of the month, in the range `1..=31`
+    Day,
+    /// The day of the week, with Sunday encoded as `0`, Monday as `1`, etc.
+    DayOfWeekSunday0,
+    /// The day of the week, with Monday encoded as `0`, Tuesday as `1`, etc.
+    DayOfWeekMonday0,
+    /// The day of the year, in the range `1..=366`
+    DayOfYear,
+    /// The hour of the day, in the range `0..=23`
+    Hour,
+    /// The minute of the hour, in the range `0..=59`
+    Minute,
+    /// The second of the minute, in the range `0..=59`
+    Second,
+    /// The millisecond of the second, in the range `0..=999`
+    Millisecond,
+    /// The microsecond of the second, in the range `0..=999_999`
+    Microsecond,
+    /// The nanosecond of the second, in the range `0..=999_999_999`
+    Nanosecond,
+    /// The number of seconds since the UNIX epoch, or since midnight for a time
+    Epoch,
+}
+
+impl std::fmt::Display for DatePart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// Extracts `part` from a temporal array, or a dictionary array with temporal values,
+/// as an array of integers.
+///
+/// This provides a single entry point for the individual kernels such as [`hour_dyn`],
+/// [`year_dyn`] and [`doy_dyn`], and supports the following types:
+///
+/// * Date32, Date64 and Timestamp, with the parts of a timestamp with a timezone
+///   computed in that timezone. All parts are supported.
+/// * Time32 and Time64, for which only [`DatePart::Hour`] and finer parts, along with
+///   [`DatePart::Epoch`], are supported
+/// * Duration, for which [`DatePart::Week`], [`DatePart::Day`], [`DatePart::Hour`] and
+///   finer parts, along with [`DatePart::Epoch`], return the total number of whole
+///   units in the duration
+///
+/// The result is an `Int32Array`, except for [`DatePart::Epoch`] and durations which
+/// return an `Int64Array`. An error is returned if a duration overflows an `i64`.
+///
+/// ```
+/// # use arrow_array::{Int32Array, TimestampSecondArray};
+/// # use arrow_array::cast::as_primitive_array;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_arith::temporal::{date_part, DatePart};
+/// // 2023-02-15T10:20:30
+/// let array = TimestampSecondArray::from(vec![Some(1676456430), None]);
+/// let result = date_part(&array, DatePart::Quarter).unwrap();
+/// assert_eq!(as_primitive_array::<Int32Type>(&result), &Int32Array::from(vec![Some(1), None]));
+///
+/// let array = array.with_timezone("+05:00".to_string());
+/// let result = date_part(&array, DatePart::Hour).unwrap();
+/// assert_eq!(as_primitive_array::<Int32Type>(&result), &Int32Array::from(vec![Some(15), None]));
+/// ```
+pub fn date_part(array: &dyn Array, part: DatePart) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Dictionary(_, _) => {
+            downcast_dictionary_array!(
+                array => {
+                    let values = date_part(array.values(), part)?;
+                    Ok(Arc::new(array.with_values(&values)))
+                }
+                dt => return_compute_error_with!(format!("{part} does not support"), dt),
+            )
+        }
+        DataType::Duration(TimeUnit::Second) => {
+            date_part_primitive(array.as_primitive::<DurationSecondType>(), part)
+        }
+        DataType::Duration(TimeUnit::Millisecond) => {
+            date_part_primitive(array.as_primitive::<DurationMillisecondType>(), part)
+        }
+        DataType::Duration(TimeUnit::Microsecond) => {
+            date_part_primitive(array.as_primitive::<DurationMicrosecondType>(), part)
+        }
+        DataType::Duration(TimeUnit::Nanosecond) => {
+            date_part_primitive(array.as_primitive::<DurationNanosecondType>(), part)
+        }
+        _ => {
+            downcast_temporal_array!(
+                array => date_part_primitive(array, part),
+                dt => return_compute_error_with!(format!("{part} does not support"), dt),
+            )
+        }
+    }
+}
+
+fn date_part_primitive<T>(
+    array: &PrimitiveArray<T>,
+    part: DatePart,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: From<T::Native>,
+{
+    if part == DatePart::Epoch {
+        let op: fn(i64) -> Option<i64> = match array.data_type() {
+            DataType::Time32(_) | DataType::Time64(_) => {
+                |v| as_time::<T>(v).map(|t| t.num_seconds_from_midnight() as i64)
+            }
+            DataType::Duration(_) => |v| as_duration::<T>(v).map(|d| d.num_seconds()),
+            _ => |v| as_datetime::<T>(v).map(|t| t.timestamp()),
+        };
+        let result: Int64Array =
+            array.iter().map(|v| v.and_then(|v| op(v.into()))).collect();
+        return Ok(Arc::new(result));
+    }
+
+    let unsupported = || {
+        Err(ArrowError::ComputeError(format!(
+            "{part} does not support: {:?}",
+            array.data_type()
+        )))
+    };
+
+    let result = match array.data_type() {
+        DataType::Time32(_) | DataType::Time64(_) => {
+            let op: fn(NaiveTime) -> i32 = match part {
+                DatePart::Hour => |t| t.hour() as i32,
+                DatePart::Minute => |t| t.minute() as i32,
+                DatePart::Second => |t| t.second() as i32,
+                DatePart::Millisecond => |t| (t.nanosecond() / 1_000_000) as i32,
+                DatePart::Microsecond => |t| (t.nanosecond() / 1_000) as i32,
+                DatePart::Nanosecond => |t| t.nanosecond() as i32,
+                _ => return unsupported(),
+            };
+            let b = Int32Builder::with_capacity(array.len());
+            as_time_with_op::<_, T, _>(ArrayIter::new(array), b, op)
+        }
+        DataType::Duration(_) => {
+            let op: fn(chrono::Duration) -> Option<i64> = match part {
+                DatePart::Week => |d| Some(d.num_weeks()),
+                DatePart::Day => |d| Some(d.num_days()),
+                DatePart::Hour => |d| Some(d.num_hours()),
+                DatePart::Minute => |d| Some(d.num_minutes()),
+                DatePart::Second => |d| Some(d.num_seconds()),
+                DatePart::Millisecond => |d| Some(d.num_milliseconds()),
+                DatePart::Microsecond => |d| d.num_microseconds(),
+                DatePart::Nanosecond => |d| d.num_nanoseconds(),
+                _ => return unsupported(),
+            };
+            let result: Int64Array = array.try_unary(|v| {
+                as_duration::<T>(v.into()).and_then(op).ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "{part} of duration {v:?} overflows Int64"
+                    ))
+                })
+            })?;
+            return Ok(Arc::new(result));
+        }
+        _ => {
+            let op: fn(NaiveDateTime) -> i32 = match part {
+                DatePart::Year => |t| t.year(),
+                DatePart::Quarter => |t| t.quarter() as i32,
+                DatePart::Month => |t| t.month() as i32,
+                DatePart::Week => |t| t.iso_week().week() as i32,
+                DatePart::Day => |t| t.day() as i32,
+                DatePart::DayOfWeekSunday0 => |t| t.num_days_from_sunday(),
+                DatePart::DayOfWeekMonday0 => |t| t.num_days_from_monday(),
+                DatePart::DayOfYear => |t| t.ordinal() as i32,
+                DatePart::Hour => |t| t.hour() as i32,
+                DatePart::Minute => |t| t.minute() as i32,
+                DatePart::Second => |t| t.second() as i32,
+                DatePart::Millisecond => |t| (t.nanosecond() / 1_000_000) as i32,
+                DatePart::Microsecond => |t| (t.nanosecond() / 1_000) as i32,
+                DatePart::Nanosecond => |t| t.nanosecond() as i32,
+                DatePart::Epoch => unreachable!(),
+            };
+            time_fraction_internal(array, &part.to_string(), op)?
+        }
+    };
+    Ok(Arc::new(result))
+}
+
+/// Truncates each value of a temporal array, or a dictionary array with temporal values,
+/// to the start of the `part` it falls in, e.g. truncating `2023-05-17T10:42:01` to
+/// [`DatePart::Month`] yields `2023-05-01T00:00:00`.
+///
+/// Supports Date32, Date64 and Timestamp arrays, returning an array of the same type.
+/// Timestamps with a timezone are truncated in local time, so truncating to
+/// [`DatePart::Day`] yields local midnight. If this local time is skipped by a
+/// daylight saving transition, the result is the first instant after the transition,
+/// and if it is ambiguous, the earliest instant.
+///
+/// [`DatePart::Week`] truncates to the Monday of the ISO week. The day of week, day of
+/// year and epoch parts are not supported.
+///
+/// ```
+/// # use arrow_array::TimestampSecondArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::TimestampSecondType;
+/// # use arrow_arith::temporal::{date_trunc, DatePart};
+/// // 2023-05-17T10:42:01
+/// let array = TimestampSecondArray::from(vec![Some(1684320121), None]);
+/// let result = date_trunc(&array, DatePart::Quarter).unwrap();
+/// // 2023-04-01T00:00:00
+/// let expected = TimestampSecondArray::from(vec![Some(1680307200), None]);
+/// assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+/// ```
+pub fn date_trunc(array: &dyn Array, part: DatePart) -> Result<ArrayRef, ArrowError> {
+    match part {
+        DatePart::DayOfWeekSunday0
+        | DatePart::DayOfWeekMonday0
+        | DatePart::DayOfYear
+        | DatePart::Epoch => Err(ArrowError::ComputeError(format!(
+            "date_trunc does not support {part}"
+        ))),
+        _ => temporal_map(array, "date_trunc", &|v| truncate(v, part)),
+    }
+}
+
+/// Assigns each value of a temporal array, or a dictionary array with temporal values,
+/// to a bin of width `stride` aligned to `origin`, returning the start of each bin.
+///
+/// Supports Date32, Date64 and Timestamp arrays, returning an array of the same type.
+/// Bins of dates start at midnight of the day they fall in.
+/// Bins are computed on local time for timestamps with a timezone, and so a `stride`
+/// of one day with an `origin` at midnight yields local midnight, even across daylight
+/// saving transitions. Local times are converted back to instants as for [`date_trunc`].
+///
+/// Returns an error if `stride` is not positive.
+///
+/// ```
+/// # use arrow_array::TimestampMillisecondArray;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::TimestampMillisecondType;
+/// # use arrow_arith::temporal::date_bin;
+/// # use chrono::{Duration, NaiveDateTime};
+/// let array = TimestampMillisecondArray::from(vec![0, 1_000, 899_999, 900_000]);
+/// let origin = NaiveDateTime::from_timestamp_opt(300, 0).unwrap();
+/// let result = date_bin(&array, Duration::minutes(15), origin).unwrap();
+/// let expected = TimestampMillisecondArray::from(vec![-600_000, -600_000, 300_000, 300_000]);
+/// assert_eq!(result.as_primitive::<TimestampMillisecondType>(), &expected);
+/// ```
+pub fn date_bin(
+    array: &dyn Array,
+    stride: Duration,
+    origin: NaiveDateTime,
+) -> Result<ArrayRef, ArrowError> {
+    let stride = stride.num_nanoseconds().filter(|s| *s > 0).ok_or_else(|| {
+        ArrowError::ComputeError(format!(
+            "date_bin stride must be positive and fit in an i64 of nanoseconds, got {stride}"
+        ))
+    })? as i128;
+    let origin = naive_to_nanos(origin);
+
+    temporal_map(array, "date_bin", &|v| {
+        let offset = (naive_to_nanos(v) - origin).rem_euclid(stride);
+        let secs = Duration::seconds((offset / 1_000_000_000) as i64);
+        let nanos = Duration::nanoseconds((offset % 1_000_000_000) as i64);
+        v.checked_sub_signed(secs)?.checked_sub_signed(nanos)
+    })
+}
+
+fn naive_to_nanos(v: NaiveDateTime) -> i128 {
+    v.timestamp() as i128 * 1_000_000_000 + v.timestamp_subsec_nanos() as i128
+}
+
+fn truncate(v: NaiveDateTime, part: DatePart) -> Option<NaiveDateTime> {
+    let date = v.date();
+    match part {
+        DatePart::Year => date.with_ordinal(1)?.and_hms_opt(0, 0, 0),
+        DatePart::Quarter => {
+            let month = (date.month0() / 3) * 3 + 1;
+            date.with_day(1)?.with_month(month)?.and_hms_opt(0, 0, 0)
+        }
+        DatePart::Month => date.with_day(1)?.and_hms_opt(0, 0, 0),
+        DatePart::Week => {
+            let days = date.weekday().num_days_from_monday() as i64;
+            date.checked_sub_signed(Duration::days(days))?
+                .and_hms_opt(0, 0, 0)
+        }
+        DatePart::Day => date.and_hms_opt(0, 0, 0),
+        DatePart::Hour => date.and_hms_opt(v.hour(), 0, 0),
+        DatePart::Minute => date.and_hms_opt(v.hour(), v.minute(), 0),
+        DatePart::Second => date.and_hms_opt(v.hour(), v.minute(), v.second()),
+        DatePart::Millisecond => {
+            v.with_nanosecond(v.nanosecond() / 1_000_000 * 1_000_000)
+        }
+        DatePart::Microsecond => v.with_nanosecond(v.nanosecond() / 1_000 * 1_000),
+        _ => Some(v),
+    }
+}
+
+/// Converts a local time in `tz` to UTC, resolving ambiguous local times to the
+/// earliest instant, and local times skipped by a daylight saving transition to the
+/// first instant after the transition
+fn local_to_utc(tz: &Tz, local: &NaiveDateTime) -> Option<NaiveDateTime> {
+    let offset = match tz.offset_from_local_datetime(local) {
+        LocalResult::Single(o) | LocalResult::Ambiguous(o, _) => o,
+        LocalResult::None => return first_after_transition(tz, local),
+    };
+    let offset = Duration::seconds(offset.fix().local_minus_utc() as i64);
+    local.checked_sub_signed(offset)
+}
+
+/// Returns the first instant after the daylight saving transition that skips `local`
+fn first_after_transition(tz: &Tz, local: &NaiveDateTime) -> Option<NaiveDateTime> {
+    let day = Duration::days(1);
+    let before = tz
+        .offset_from_local_datetime(&local.checked_sub_signed(day)?)
+        .earliest()?
+        .fix();
+    let after = tz
+        .offset_from_local_datetime(&local.checked_add_signed(day)?)
+        .latest()?
+        .fix();
+
+    // As `local` is skipped, the transition lies between interpreting it with
+    // the offset after the transition and with the offset before it
+    let utc = |o: FixedOffset| local.timestamp() - o.local_minus_utc() as i64;
+    let (mut lo, mut hi) = (utc(after), utc(before) + 1);
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        let mid_offset = tz
+            .offset_from_utc_datetime(&NaiveDateTime::from_timestamp_opt(mid, 0)?)
+            .fix();
+        match mid_offset == before {
+            true => lo = mid,
+            false => hi = mid,
+        }
+    }
+    NaiveDateTime::from_timestamp_opt(hi, 0)
+}
+
+type NaiveOp<'a> = &'a dyn Fn(NaiveDateTime) -> Option<NaiveDateTime>;
+
+/// Applies `op` to the local time of each value of a date or timestamp array, returning
+/// an array of the same type
+fn temporal_map(
+    array: &dyn Array,
+    name: &str,
+    op: NaiveOp,
+) -> Result<ArrayRef, ArrowError> {
+    let overflow = |v: &dyn std::fmt::Debug| {
+        ArrowError::ComputeError(format!("{name} overflowed for value {v:?}"))
+    };
+
+    match array.data_type() {
+        DataType::Dictionary(_, _) => {
+            downcast_dictionary_array!(
+                array => {
+                    let values = temporal_map(array.values(), name, op)?;
+                    Ok(Arc::new(array.with_values(&values)))
+                }
+                dt => return_compute_error_with!(format!("{name} does not support"), dt),
+            )
+        }
+        DataType::Date32 => {
+            let array = array.as_primitive::<Date32Type>();
+            let result: Date32Array = array.try_unary(|v| {
+                as_datetime::<Date32Type>(v as i64)
+                    .and_then(op)
+                    .map(|d| Date32Type::from_naive_date(d.date()))
+                    .ok_or_else(|| overflow(&v))
+            })?;
+            Ok(Arc::new(result))
+        }
+        DataType::Date64 => {
+            let array = array.as_primitive::<Date64Type>();
+            let result: Date64Array = array.try_unary(|v| {
+                as_datetime::<Date64Type>(v)
+                    .and_then(op)
+                    .map(|d| d.timestamp_millis())
+                    .ok_or_else(|| overflow(&v))
+            })?;
+            Ok(Arc::new(result))
+        }
+        DataType::Timestamp(unit, tz_str) => {
+            let tz = tz_str.as_deref().map(str::parse::<Tz>).transpose()?;
+            macro_rules! timestamp_map {
+                ($t:ty) => {{
+                    let array = array.as_primitive::<$t>();
+                    let result: PrimitiveArray<$t> = array.try_unary(|v| {
+                        let result = match &tz {
+                            None => as_datetime::<$t>(v).and_then(op),
+                            Some(tz) => as_datetime_with_timezone::<$t>(v, *tz)
+                                .and_then(|d| op(d.naive_local()))
+                                .and_then(|d| local_to_utc(tz, &d)),
+                        };
+                        result
+                            .and_then(<$t>::make_value)
+                            .ok_or_else(|| overflow(&v))
+                    })?;
+                    Ok(Arc::new(result.with_timezone_opt(tz_str.clone())))
+                }};
+            }
+            match unit {
+                TimeUnit::Second => timestamp_map!(TimestampSecondType),
+                TimeUnit::Millisecond => timestamp_map!(TimestampMillisecondType),
+                TimeUnit::Microsecond => timestamp_map!(TimestampMicrosecondType),
+                TimeUnit::Nanosecond => timestamp_map!(TimestampNanosecondType),
+            }
+        }
+        dt => return_compute_error_with!(format!("{name} does not support"), dt),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1140,4 +1559,204 @@ mod tests {
         let expected = Arc::new(expected_dict) as ArrayRef;
         assert_eq!(&expected, &b);
     }
+
+    #[test]
+    fn test_date_part() {
+        let get = |array: &dyn Array, part| {
+            let result = date_part(array, part).unwrap();
+            result.as_primitive::<Int32Type>().value(0)
+        };
+
+        // 2023-12-31T23:59:59.123456
+        let a = TimestampMicrosecondArray::from(vec![Some(1704067199123456), None]);
+        assert_eq!(get(&a, DatePart::Year), 2023);
+        assert_eq!(get(&a, DatePart::Quarter), 4);
+        assert_eq!(get(&a, DatePart::Week), 52);
+        assert_eq!(get(&a, DatePart::DayOfWeekSunday0), 0);
+        assert_eq!(get(&a, DatePart::DayOfWeekMonday0), 6);
+        assert_eq!(get(&a, DatePart::DayOfYear), 365);
+        assert_eq!(get(&a, DatePart::Second), 59);
+        assert_eq!(get(&a, DatePart::Millisecond), 123);
+        assert_eq!(get(&a, DatePart::Microsecond), 123456);
+        assert_eq!(get(&a, DatePart::Nanosecond), 123456000);
+        let epoch = date_part(&a, DatePart::Epoch).unwrap();
+        let expected = Int64Array::from(vec![Some(1704067199), None]);
+        assert_eq!(epoch.as_primitive::<Int64Type>(), &expected);
+
+        let a = a.with_timezone("+01:00".to_string());
+        assert_eq!(get(&a, DatePart::Year), 2024);
+        assert_eq!(get(&a, DatePart::Day), 1);
+        assert_eq!(get(&a, DatePart::Hour), 0);
+        let epoch = date_part(&a, DatePart::Epoch).unwrap();
+        assert_eq!(epoch.as_primitive::<Int64Type>(), &expected);
+
+        let a = Date32Array::from(vec![19403]);
+        assert_eq!(get(&a, DatePart::Month), 2);
+        assert_eq!(get(&a, DatePart::Week), 7);
+        assert_eq!(get(&a, DatePart::Hour), 0);
+
+        // 01:02:03.000001
+        let a = Time64MicrosecondArray::from(vec![3723000001]);
+        assert_eq!(get(&a, DatePart::Minute), 2);
+        assert_eq!(get(&a, DatePart::Nanosecond), 1000);
+        let epoch = date_part(&a, DatePart::Epoch).unwrap();
+        assert_eq!(epoch.as_primitive::<Int64Type>().value(0), 3723);
+        let err = date_part(&a, DatePart::Year).unwrap_err().to_string();
+        assert!(err.contains("Year does not support"), "{err}");
+    }
+
+    #[test]
+    fn test_date_part_duration() {
+        let get = |array: &dyn Array, part| {
+            let result = date_part(array, part).unwrap();
+            result.as_primitive::<Int64Type>().value(0)
+        };
+
+        // 1 day, 1 hour, 1 minute, 1 second and 1 millisecond
+        let a = DurationMillisecondArray::from(vec![90061001]);
+        assert_eq!(get(&a, DatePart::Week), 0);
+        assert_eq!(get(&a, DatePart::Day), 1);
+        assert_eq!(get(&a, DatePart::Hour), 25);
+        assert_eq!(get(&a, DatePart::Minute), 1501);
+        assert_eq!(get(&a, DatePart::Second), 90061);
+        assert_eq!(get(&a, DatePart::Millisecond), 90061001);
+        assert_eq!(get(&a, DatePart::Nanosecond), 90061001000000);
+        assert_eq!(get(&a, DatePart::Epoch), 90061);
+
+        let a = DurationNanosecondArray::from(vec![5_000_000_000]);
+        assert_eq!(get(&a, DatePart::Nanosecond), 5_000_000_000);
+
+        let a = DurationSecondArray::from(vec![10_000_000_000]);
+        let err = date_part(&a, DatePart::Nanosecond).unwrap_err().to_string();
+        assert!(err.contains("overflows Int64"), "{err}");
+        let err = date_part(&a, DatePart::Month).unwrap_err().to_string();
+        assert!(err.contains("Month does not support"), "{err}");
+    }
+
+    #[test]
+    fn test_date_part_dictionary() {
+        let keys = Int8Array::from(vec![Some(1_i8), None, Some(0)]);
+        let values = Date64Array::from(vec![Some(1667328721453), None]);
+        let dict = DictionaryArray::try_new(&keys, &values).unwrap();
+
+        let result = date_part(&dict, DatePart::Month).unwrap();
+        let expected =
+            DictionaryArray::try_new(&keys, &Int32Array::from(vec![Some(11), None]))
+                .unwrap();
+        assert_eq!(result.as_dictionary::<Int8Type>(), &expected);
+
+        let err = date_part(&Int32Array::from(vec![1]), DatePart::Year).unwrap_err();
+        assert!(err.to_string().contains("Year does not support"), "{err}");
+    }
+
+    #[test]
+    fn test_date_trunc() {
+        // 2023-05-17T10:42:01.123456
+        let a = TimestampMicrosecondArray::from(vec![Some(1684320121123456), None]);
+        let trunc = |a: &dyn Array, part| {
+            let result = date_trunc(a, part).unwrap();
+            assert_eq!(result.data_type(), a.data_type());
+            result.as_primitive::<TimestampMicrosecondType>().value(0)
+        };
+        assert_eq!(trunc(&a, DatePart::Year), 1672531200000000);
+        assert_eq!(trunc(&a, DatePart::Quarter), 1680307200000000);
+        assert_eq!(trunc(&a, DatePart::Month), 1682899200000000);
+        assert_eq!(trunc(&a, DatePart::Week), 1684108800000000);
+        assert_eq!(trunc(&a, DatePart::Hour), 1684317600000000);
+        assert_eq!(trunc(&a, DatePart::Second), 1684320121000000);
+        assert_eq!(trunc(&a, DatePart::Millisecond), 1684320121123000);
+        assert_eq!(trunc(&a, DatePart::Nanosecond), 1684320121123456);
+        let result = date_trunc(&a, DatePart::Day).unwrap();
+        assert!(result.is_null(1));
+
+        // Truncated to local midnight
+        let a = a.with_timezone("+09:00".to_string());
+        assert_eq!(trunc(&a, DatePart::Day), 1684249200000000);
+        let a = a.with_timezone("-11:00".to_string());
+        assert_eq!(trunc(&a, DatePart::Day), 1684234800000000);
+
+        let a = Date32Array::from(vec![19494]);
+        let result = date_trunc(&a, DatePart::Month).unwrap();
+        assert_eq!(result.as_primitive::<Date32Type>().value(0), 19478);
+
+        let a = Date64Array::from(vec![1684320121123]);
+        let result = date_trunc(&a, DatePart::Hour).unwrap();
+        assert_eq!(result.as_primitive::<Date64Type>().value(0), 1684317600000);
+
+        let keys = Int8Array::from(vec![Some(0_i8), None, Some(0)]);
+        let dict = DictionaryArray::try_new(&keys, &a).unwrap();
+        let result = date_trunc(&dict, DatePart::Day).unwrap();
+        let expected =
+            DictionaryArray::try_new(&keys, &Date64Array::from(vec![1684281600000]))
+                .unwrap();
+        assert_eq!(result.as_dictionary::<Int8Type>(), &expected);
+
+        let err = date_trunc(&a, DatePart::DayOfYear).unwrap_err().to_string();
+        assert!(
+            err.contains("date_trunc does not support DayOfYear"),
+            "{err}"
+        );
+        let a = Int32Array::from(vec![1]);
+        let err = date_trunc(&a, DatePart::Day).unwrap_err().to_string();
+        assert!(err.contains("date_trunc does not support: Int32"), "{err}");
+    }
+
+    #[test]
+    fn test_date_bin() {
+        let epoch = NaiveDateTime::from_timestamp_opt(0, 0).unwrap();
+
+        let a = TimestampNanosecondArray::from(vec![1_500_000_001, -1]);
+        let result = date_bin(&a, Duration::milliseconds(250), epoch).unwrap();
+        let expected = TimestampNanosecondArray::from(vec![1_500_000_000, -250_000_000]);
+        assert_eq!(result.as_primitive::<TimestampNanosecondType>(), &expected);
+
+        // Binned on local time
+        // 2023-05-17T10:42:01
+        let a = TimestampSecondArray::from(vec![Some(1684320121), None])
+            .with_timezone("+09:00".to_string());
+        let result = date_bin(&a, Duration::days(1), epoch).unwrap();
+        assert_eq!(result.data_type(), a.data_type());
+        let expected = TimestampSecondArray::from(vec![Some(1684249200), None])
+            .with_timezone("+09:00".to_string());
+        assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+
+        let err = date_bin(&a, Duration::zero(), epoch).unwrap_err();
+        assert!(err.to_string().contains("stride must be positive"), "{err}");
+
+        // Dates are binned from midnight, with or without a dictionary
+        let a = Date32Array::from(vec![Some(10), None, Some(-1)]);
+        let result = date_bin(&a, Duration::days(7), epoch).unwrap();
+        let expected = Date32Array::from(vec![Some(7), None, Some(-7)]);
+        assert_eq!(result.as_primitive::<Date32Type>(), &expected);
+
+        let keys = Int8Array::from(vec![Some(0_i8), None, Some(2)]);
+        let dict = DictionaryArray::try_new(&keys, &a).unwrap();
+        let result = date_bin(&dict, Duration::days(7), epoch).unwrap();
+        let expected = DictionaryArray::try_new(&keys, &expected).unwrap();
+        assert_eq!(result.as_dictionary::<Int8Type>(), &expected);
+
+        let a = Int32Array::from(vec![1]);
+        let err = date_bin(&a, Duration::days(1), epoch).unwrap_err();
+        assert!(
+            err.to_string().contains("date_bin does not support: Int32"),
+            "{err}"
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_date_bin_dst() {
+        // Bins start at 02:30 local time, which is skipped in America/New_York on
+        // 2023-03-12, and so resolves to the first instant after the transition
+        // 1970-01-01T02:30:00
+        let origin = NaiveDateTime::from_timestamp_opt(9000, 0).unwrap();
+        // 2023-03-12T16:00:00Z, 2023-03-13T16:00:00Z
+        let a = TimestampSecondArray::from(vec![1678636800, 1678723200])
+            .with_timezone("America/New_York".to_string());
+        let result = date_bin(&a, Duration::days(1), origin).unwrap();
+        // 2023-03-12T07:00:00Z (03:00 EDT), 2023-03-13T06:30:00Z (02:30 EDT)
+        let expected = TimestampSecondArray::from(vec![1678604400, 1678689000])
+            .with_timezone("America/New_York".to_string());
+        assert_eq!(result.as_primitive::<TimestampSecondType>(), &expected);
+    }
 }
@@ -15,14 +15,16 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::builder::StringDictionaryBuilder;
+use crate::builder::{BooleanBufferBuilder, StringDictionaryBuilder};
 use crate::iterator::ArrayIter;
 use crate::types::*;
 use crate::{
     make_array, Array, ArrayAccessor, ArrayRef, ArrowPrimitiveType, PrimitiveArray,
     StringArray,
 };
-use arrow_buffer::ArrowNativeType;
+use arrow_buffer::{bit_util, ArrowNativeType, Buffer, MutableBuffer};
+use arrow_data::bit_iterator::BitSliceIterator;
+use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType};
 use std::any::Any;
@@ -527,6 +529,21 @@ impl<T: ArrowPrimitiveType> Array for DictionaryArray<T> {
     fn into_data(self) -> ArrayData {
         self.into()
     }
+
+    fn logical_nulls(&self) -> Option<Buffer> {
+        let value_nulls = match self.values.logical_nulls() {
+            Some(nulls) => nulls,
+            None => return self.keys.logical_nulls(),
+        };
+
+        let mut builder = BooleanBufferBuilder::new(self.len());
+        self.keys.iter().for_each(|key| {
+            let valid =
+                key.map(|k| bit_util::get_bit(value_nulls.as_slice(), k.as_usize()));
+            builder.append(valid.unwrap_or_default())
+        });
+        Some(builder.finish())
+    }
 }
 
 impl<T: ArrowPrimitiveType> std::fmt::Debug for DictionaryArray<T> {
@@ -592,6 +609,121 @@ impl<'a, K: ArrowPrimitiveType, V> TypedDictionaryArray<'a, K, V> {
     }
 }
 
+impl<'a, K: ArrowPrimitiveType, V: Array> TypedDictionaryArray<'a, K, V> {
+    /// Returns a packed bitmask of `self.values().len()` bits, where a set bit indicates
+    /// the corresponding dictionary value is referenced by at least one non-null key
+    ///
+    /// ```
+    /// use arrow_array::{Array, DictionaryArray, Int8Array, StringArray, types::Int8Type};
+    ///
+    /// let keys = Int8Array::from(vec![Some(0), None, Some(2), Some(0)]);
+    /// let values = StringArray::from(vec!["a", "b", "c"]);
+    /// let dictionary = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+    /// let typed = dictionary.downcast_dict::<StringArray>().unwrap();
+    ///
+    /// assert_eq!(typed.occupancy().as_slice(), &[0b101]);
+    /// ```
+    pub fn occupancy(&self) -> Buffer {
+        let mut occupancy = MutableBuffer::new_null(self.values.len());
+        let slice = occupancy.as_slice_mut();
+        self.keys()
+            .iter()
+            .flatten()
+            .for_each(|key| bit_util::set_bit(slice, key.as_usize()));
+        occupancy.into()
+    }
+
+    /// Returns the dictionary values referenced by at least one non-null key, in the
+    /// order they appear in the dictionary
+    ///
+    /// Values are assumed to be unique within the dictionary, as is the case for
+    /// dictionaries produced by the dictionary builders, and so duplicate values
+    /// are not removed
+    ///
+    /// ```
+    /// use arrow_array::{DictionaryArray, Int8Array, StringArray, types::Int8Type};
+    ///
+    /// let keys = Int8Array::from(vec![Some(2), None, Some(0), Some(2)]);
+    /// let values = StringArray::from(vec!["a", "b", "c"]);
+    /// let dictionary = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+    /// let typed = dictionary.downcast_dict::<StringArray>().unwrap();
+    ///
+    /// let unique: StringArray = typed.unique_values();
+    /// assert_eq!(unique, StringArray::from(vec!["a", "c"]));
+    /// ```
+    pub fn unique_values(&self) -> V
+    where
+        V: From<ArrayData>,
+    {
+        let occupancy = self.occupancy();
+        V::from(self.filter_values(&occupancy))
+    }
+
+    /// Returns a [`DictionaryArray`] with the same logical values as this array, but
+    /// with any dictionary values not referenced by a non-null key removed
+    ///
+    /// ```
+    /// use arrow_array::{Array, DictionaryArray, Int8Array, StringArray, types::Int8Type};
+    ///
+    /// let keys = Int8Array::from(vec![Some(2), None, Some(0), Some(2)]);
+    /// let values = StringArray::from(vec!["a", "b", "c"]);
+    /// let dictionary = DictionaryArray::<Int8Type>::try_new(&keys, &values).unwrap();
+    /// let typed = dictionary.downcast_dict::<StringArray>().unwrap();
+    ///
+    /// let normalized = typed.normalize();
+    /// assert_eq!(
+    ///     normalized.keys(),
+    ///     &Int8Array::from(vec![Some(1), None, Some(0), Some(1)])
+    /// );
+    /// assert_eq!(normalized.values().len(), 2);
+    /// ```
+    pub fn normalize(&self) -> DictionaryArray<K> {
+        let occupancy = self.occupancy();
+
+        // Maps each dictionary index to its index in the normalized values
+        let mut next = 0;
+        let mapping: Vec<usize> = (0..self.values.len())
+            .map(|i| {
+                let mapped = next;
+                next += bit_util::get_bit(occupancy.as_slice(), i) as usize;
+                mapped
+            })
+            .collect();
+
+        // Null keys may not be within the bounds of the dictionary
+        let keys = self.keys().unary::<_, K>(|key| {
+            mapping
+                .get(key.as_usize())
+                .map(|k| K::Native::from_usize(*k).unwrap())
+                .unwrap_or_default()
+        });
+        let values = self.filter_values(&occupancy);
+
+        let builder = keys
+            .into_data()
+            .into_builder()
+            .data_type(DataType::Dictionary(
+                Box::new(K::DATA_TYPE),
+                Box::new(values.data_type().clone()),
+            ))
+            .child_data(vec![values]);
+
+        // SAFETY:
+        // Non-null keys were remapped to the index of their value in `values`
+        DictionaryArray::from(unsafe { builder.build_unchecked() })
+    }
+
+    /// Returns the [`ArrayData`] of the dictionary values whose bit is set in `occupancy`
+    fn filter_values(&self, occupancy: &Buffer) -> ArrayData {
+        let values = self.values.data();
+        let len = occupancy.count_set_bits_offset(0, values.len());
+        let mut mutable = MutableArrayData::new(vec![values], false, len);
+        BitSliceIterator::new(occupancy.as_slice(), 0, values.len())
+            .for_each(|(start, end)| mutable.extend(0, start, end));
+        mutable.freeze()
+    }
+}
+
 impl<'a, K: ArrowPrimitiveType, V: Sync> Array for TypedDictionaryArray<'a, K, V> {
     fn as_any(&self) -> &dyn Any {
         self.dictionary
@@ -941,4 +1073,68 @@ mod tests {
         let a = DictionaryArray::<Int32Type>::from_iter(["32"]);
         let _ = DictionaryArray::<Int64Type>::from(a.into_data());
     }
+
+    #[test]
+    fn test_dictionary_logical_nulls() {
+        let values = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let keys = Int8Array::from(vec![Some(0), Some(1), None, Some(2), Some(1)]);
+        let array = DictionaryArray::try_new(&keys, &values).unwrap();
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.logical_null_count(), 3);
+
+        let nulls = array.logical_nulls().unwrap();
+        let validity: Vec<_> = (0..array.len())
+            .map(|i| bit_util::get_bit(nulls.as_slice(), i))
+            .collect();
+        assert_eq!(validity, [true, false, false, true, false]);
+
+        let sliced = array.slice(3, 2);
+        assert_eq!(sliced.logical_null_count(), 1);
+
+        // Without nulls in the values, the logical nulls are those of the keys
+        let values = Int32Array::from(vec![1, 2]);
+        let keys = Int8Array::from(vec![Some(0), None, Some(1)]);
+        let array = DictionaryArray::try_new(&keys, &values).unwrap();
+        assert_eq!(array.logical_nulls(), keys.logical_nulls());
+        assert_eq!(array.logical_null_count(), 1);
+    }
+
+    #[test]
+    fn test_typed_dictionary_normalize() {
+        let values = Int32Array::from(vec![Some(10), None, Some(30), Some(40)]);
+        let keys = Int8Array::from(vec![Some(3), None, Some(1), Some(3), Some(0)]);
+        let dictionary = DictionaryArray::try_new(&keys, &values).unwrap();
+
+        let sliced = dictionary.slice(1, 3);
+        let sliced = sliced
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        let typed = sliced.downcast_dict::<Int32Array>().unwrap();
+        assert_eq!(typed.occupancy().as_slice(), &[0b1010]);
+
+        let unique: Int32Array = typed.unique_values();
+        assert_eq!(unique, Int32Array::from(vec![None, Some(40)]));
+
+        let normalized = typed.normalize();
+        assert_eq!(
+            normalized.keys(),
+            &Int8Array::from(vec![None, Some(0), Some(1)])
+        );
+        let normalized = normalized.downcast_dict::<Int32Array>().unwrap();
+        assert_eq!(normalized.values(), &unique);
+        assert_eq!(
+            normalized.into_iter().collect::<Vec<_>>(),
+            typed.into_iter().collect::<Vec<_>>()
+        );
+
+        let empty = DictionaryArray::<Int8Type>::try_new(
+            &Int8Array::from(vec![None, None]),
+            &values,
+        )
+        .unwrap();
+        let typed = empty.downcast_dict::<Int32Array>().unwrap();
+        assert_eq!(typed.normalize().values().len(), 0);
+        assert_eq!(typed.normalize().len(), 2);
+    }
 }
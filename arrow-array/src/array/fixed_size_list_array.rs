@@ -19,8 +19,9 @@ use crate::builder::{FixedSizeListBuilder, PrimitiveBuilder};
 use crate::{
     make_array, print_long_array, Array, ArrayAccessor, ArrayRef, ArrowPrimitiveType,
 };
+use arrow_buffer::{bit_util, Buffer};
 use arrow_data::ArrayData;
-use arrow_schema::DataType;
+use arrow_schema::{ArrowError, DataType, Field};
 use std::any::Any;
 
 /// A list array where each element is a fixed-size sequence of values with the same
@@ -68,6 +69,146 @@ pub struct FixedSizeListArray {
 }
 
 impl FixedSizeListArray {
+    /// Create a new [`FixedSizeListArray`] with `size` values per list from the
+    /// provided `values` and optional null bitmap `nulls`
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::try_new`] returns an error
+    pub fn new(
+        field: Box<Field>,
+        size: i32,
+        values: ArrayRef,
+        nulls: Option<Buffer>,
+    ) -> Self {
+        Self::try_new(field, size, values, nulls).unwrap()
+    }
+
+    /// Create a new [`FixedSizeListArray`] with `size` values per list from the
+    /// provided `values` and optional null bitmap `nulls`
+    ///
+    /// The length of the returned array is `values.len() / size`, use
+    /// [`Self::try_new_with_len`] to create an array with a `size` of 0
+    ///
+    /// # Errors
+    ///
+    /// Errors if
+    ///
+    /// * `size <= 0`
+    /// * `values.len()` is not a multiple of `size`
+    /// * `values.data_type() != field.data_type()`
+    /// * `!field.is_nullable()` and `values` contains nulls in a non-null list
+    /// * `nulls` is too short for the length of the array
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Array, FixedSizeListArray, Int32Array};
+    /// # use arrow_buffer::Buffer;
+    /// # use arrow_schema::{DataType, Field};
+    /// let field = Box::new(Field::new("item", DataType::Int32, false));
+    /// let values = Arc::new(Int32Array::from(vec![0, 1, 2, 3, 4, 5]));
+    /// // [[0, 1], null, [4, 5]]
+    /// let nulls = Some(Buffer::from([0b101]));
+    /// let list = FixedSizeListArray::try_new(field, 2, values, nulls).unwrap();
+    ///
+    /// assert_eq!(list.len(), 3);
+    /// assert!(list.is_null(1));
+    /// assert_eq!(list.value(2).as_ref(), &Int32Array::from(vec![4, 5]));
+    /// ```
+    pub fn try_new(
+        field: Box<Field>,
+        size: i32,
+        values: ArrayRef,
+        nulls: Option<Buffer>,
+    ) -> Result<Self, ArrowError> {
+        let len = match usize::try_from(size) {
+            Ok(0) => {
+                let msg = "Cannot infer the length of a FixedSizeListArray \
+                           with a size of 0, use try_new_with_len";
+                return Err(ArrowError::InvalidArgumentError(msg.to_string()));
+            }
+            Ok(s) if values.len() % s != 0 => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Incorrect length of values buffer for FixedSizeListArray, \
+                     expected a multiple of {s} got {}",
+                    values.len()
+                )))
+            }
+            Ok(s) => values.len() / s,
+            // Reported by try_new_with_len
+            Err(_) => 0,
+        };
+        Self::try_new_with_len(field, size, values, nulls, len)
+    }
+
+    /// Create a new [`FixedSizeListArray`] of length `len` with `size` values per
+    /// list from the provided `values` and optional null bitmap `nulls`
+    ///
+    /// # Errors
+    ///
+    /// Errors if
+    ///
+    /// * `size < 0`
+    /// * `values.len() != len * size`
+    /// * `values.data_type() != field.data_type()`
+    /// * `!field.is_nullable()` and `values` contains nulls in a non-null list
+    /// * `nulls` is too short for `len`
+    pub fn try_new_with_len(
+        field: Box<Field>,
+        size: i32,
+        values: ArrayRef,
+        nulls: Option<Buffer>,
+        len: usize,
+    ) -> Result<Self, ArrowError> {
+        let s = usize::try_from(size).map_err(|_| {
+            ArrowError::InvalidArgumentError(format!(
+                "Size cannot be negative, got {size}"
+            ))
+        })?;
+
+        if len.checked_mul(s) != Some(values.len()) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Incorrect length of values buffer for FixedSizeListArray, \
+                 expected {len} lists of size {s} got {}",
+                values.len()
+            )));
+        }
+
+        if field.data_type() != values.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "FixedSizeListArray expected data type {} got {} for {:?}",
+                field.data_type(),
+                values.data_type(),
+                field.name()
+            )));
+        }
+
+        // Nulls in the child are permitted where they are masked by a null list
+        let unmasked = !field.is_nullable()
+            && values.null_count() != 0
+            && match nulls.as_ref() {
+                // An undersized bitmap is reported when building the ArrayData
+                Some(n) if n.len() * 8 >= len => (0..values.len())
+                    .any(|i| values.is_null(i) && bit_util::get_bit(n, i / s)),
+                Some(_) => false,
+                None => true,
+            };
+        if unmasked {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Found unmasked nulls for non-nullable FixedSizeListArray field {:?}",
+                field.name()
+            )));
+        }
+
+        let data = ArrayData::builder(DataType::FixedSizeList(field, size))
+            .len(len)
+            .add_child_data(values.data().clone())
+            .null_bit_buffer(nulls)
+            .build()?;
+
+        Ok(Self::from(data))
+    }
+
     /// Returns a reference to the values of this list.
     pub fn values(&self) -> &ArrayRef {
         &self.values
@@ -236,9 +377,9 @@ impl std::fmt::Debug for FixedSizeListArray {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Int32Array;
-    use arrow_buffer::{bit_util, Buffer};
-    use arrow_schema::Field;
+    use crate::{new_empty_array, Int32Array};
+    use arrow_buffer::bit_util;
+    use std::sync::Arc;
 
     #[test]
     fn test_fixed_size_list_array() {
@@ -429,4 +570,112 @@ mod tests {
 
         list_array.value(10);
     }
+
+    #[test]
+    fn test_fixed_size_list_try_new() {
+        let field = Box::new(Field::new("item", DataType::Int32, true));
+        let values: ArrayRef =
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)]));
+
+        let list = FixedSizeListArray::new(field.clone(), 2, values.clone(), None);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.null_count(), 0);
+        assert_eq!(list.value_length(), 2);
+        assert_eq!(
+            list.value(0).as_ref(),
+            &Int32Array::from(vec![Some(1), None])
+        );
+
+        let nulls = Some(Buffer::from([0b10]));
+        let list =
+            FixedSizeListArray::try_new(field.clone(), 2, values.clone(), nulls).unwrap();
+        assert!(list.is_null(0));
+        assert!(list.is_valid(1));
+
+        let err = FixedSizeListArray::try_new(field.clone(), 0, values.clone(), None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot infer the length of a FixedSizeListArray with a size of 0, use try_new_with_len"
+        );
+
+        let empty = new_empty_array(&DataType::Int32);
+        let nulls = Some(Buffer::from([0b101]));
+        let list =
+            FixedSizeListArray::try_new_with_len(field.clone(), 0, empty, nulls, 3)
+                .unwrap();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.null_count(), 1);
+        assert_eq!(list.value(0).len(), 0);
+
+        let err = FixedSizeListArray::try_new_with_len(
+            field.clone(),
+            2,
+            values.clone(),
+            None,
+            3,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Incorrect length of values buffer for FixedSizeListArray, expected 3 lists of size 2 got 4"
+        );
+
+        let err = FixedSizeListArray::try_new(field.clone(), -1, values.clone(), None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Size cannot be negative, got -1"
+        );
+
+        let err = FixedSizeListArray::try_new(field.clone(), 3, values.clone(), None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Incorrect length of values buffer for FixedSizeListArray, expected a multiple of 3 got 4"
+        );
+
+        let other = Box::new(Field::new("item", DataType::Int64, true));
+        let err =
+            FixedSizeListArray::try_new(other, 2, values.clone(), None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: FixedSizeListArray expected data type Int64 got Int32 for \"item\""
+        );
+
+        let non_nullable = Box::new(Field::new("item", DataType::Int32, false));
+        let err = FixedSizeListArray::try_new(non_nullable, 2, values.clone(), None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Found unmasked nulls for non-nullable FixedSizeListArray field \"item\""
+        );
+
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![1; 20]));
+        let nulls = Some(Buffer::from([0b1]));
+        FixedSizeListArray::try_new(field, 2, values, nulls).unwrap_err();
+    }
+
+    #[test]
+    fn test_fixed_size_list_try_new_masked_nulls() {
+        let field = Box::new(Field::new("item", DataType::Int32, false));
+        let values: ArrayRef =
+            Arc::new(Int32Array::from(vec![Some(1), Some(2), None, None]));
+
+        // [[1, 2], null]
+        let nulls = Some(Buffer::from([0b01]));
+        let list =
+            FixedSizeListArray::try_new(field.clone(), 2, values.clone(), nulls).unwrap();
+        assert_eq!(list.len(), 2);
+        assert!(list.is_valid(0));
+        assert!(list.is_null(1));
+
+        // [null, [null, null]]
+        let nulls = Some(Buffer::from([0b10]));
+        let err = FixedSizeListArray::try_new(field, 2, values, nulls).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Found unmasked nulls for non-nullable FixedSizeListArray field \"item\""
+        );
+    }
 }
@@ -20,8 +20,8 @@
 mod binary_array;
 
 use crate::types::*;
-use arrow_buffer::{Buffer, MutableBuffer, ToByteSlice};
-use arrow_data::ArrayData;
+use arrow_buffer::{ArrowNativeType, Buffer, MutableBuffer, ToByteSlice};
+use arrow_data::{equal_range, ArrayData};
 use arrow_schema::{DataType, IntervalUnit, TimeUnit};
 use std::any::Any;
 use std::sync::Arc;
@@ -238,6 +238,43 @@ pub trait Array: std::fmt::Debug + Send + Sync {
         self.data_ref().null_count()
     }
 
+    /// Returns the logical validity of this array as a packed bitmask of `self.len()`
+    /// bits, starting at bit `0`, or `None` if every element is logically valid.
+    ///
+    /// For most arrays this is the null buffer of [`ArrayData`], however, some arrays
+    /// encode element-level nullability elsewhere:
+    ///
+    /// * [`NullArray`] - every element is null
+    /// * [`DictionaryArray`] - an element is null if its key is null, or its key
+    ///   references a null value
+    /// * [`RunArray`] - an element is null if the value of its run is null
+    /// * [`UnionArray`] - an element is null if the child value it selects is null
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use arrow_array::{Array, DictionaryArray, types::Int8Type};
+    ///
+    /// let array: DictionaryArray<Int8Type> = vec![Some("a"), None, Some("a")]
+    ///     .into_iter()
+    ///     .collect();
+    /// let nulls = array.logical_nulls().unwrap();
+    /// assert_eq!(nulls.as_slice()[0] & 0b111, 0b101);
+    /// ```
+    fn logical_nulls(&self) -> Option<Buffer> {
+        self.data_ref()
+            .null_buffer()
+            .map(|b| b.bit_slice(self.offset(), self.len()))
+    }
+
+    /// Returns the number of logically null elements in this array,
+    /// see [`Array::logical_nulls`]
+    fn logical_null_count(&self) -> usize {
+        self.logical_nulls()
+            .map(|b| self.len() - b.count_set_bits_offset(0, self.len()))
+            .unwrap_or_default()
+    }
+
     /// Returns the total number of bytes of memory pointed to by this array.
     /// The buffers store bytes in the Arrow memory format, and include the data as well as the validity map.
     fn get_buffer_memory_size(&self) -> usize {
@@ -308,6 +345,14 @@ impl Array for ArrayRef {
         self.as_ref().null_count()
     }
 
+    fn logical_nulls(&self) -> Option<Buffer> {
+        self.as_ref().logical_nulls()
+    }
+
+    fn logical_null_count(&self) -> usize {
+        self.as_ref().logical_null_count()
+    }
+
     fn get_buffer_memory_size(&self) -> usize {
         self.as_ref().get_buffer_memory_size()
     }
@@ -366,6 +411,14 @@ impl<'a, T: Array> Array for &'a T {
         T::null_count(self)
     }
 
+    fn logical_nulls(&self) -> Option<Buffer> {
+        T::logical_nulls(self)
+    }
+
+    fn logical_null_count(&self) -> usize {
+        T::logical_null_count(self)
+    }
+
     fn get_buffer_memory_size(&self) -> usize {
         T::get_buffer_memory_size(self)
     }
@@ -477,6 +530,50 @@ impl PartialEq for StructArray {
     }
 }
 
+/// Compares a [`RunArray`] with either another run-end encoded array, or an
+/// array of the same logical values, e.g. the result of decoding this [`RunArray`]
+///
+/// ```
+/// use arrow_array::{RunArray, StringArray, types::Int32Type};
+///
+/// let run_array: RunArray<Int32Type> = vec!["a", "a", "b"].into_iter().collect();
+/// assert_eq!(run_array, StringArray::from(vec!["a", "a", "b"]));
+/// assert_ne!(run_array, StringArray::from(vec!["a", "b", "b"]));
+/// ```
+impl<R: RunEndIndexType, T: Array> PartialEq<T> for RunArray<R> {
+    fn eq(&self, other: &T) -> bool {
+        if matches!(other.data_type(), DataType::RunEndEncoded(_, _)) {
+            return self.data().eq(other.data());
+        }
+
+        let len = self.len();
+        if len != other.len() || self.values().data_type() != other.data_type() {
+            return false;
+        }
+        if len == 0 {
+            return true;
+        }
+
+        let offset = self.offset();
+        let run_ends = self.run_ends();
+        let values = self.values().data();
+        let other = other.data();
+        let mut start = 0;
+        for physical in self.get_start_physical_index()..=self.get_end_physical_index() {
+            let end = (run_ends.value(physical).as_usize() - offset).min(len);
+            // The run is equal if its first item is equal to the run's value, and
+            // each following item is equal to the one before it
+            let run_equal = equal_range(values, other, physical, start, 1)
+                && equal_range(other, other, start, start + 1, end - start - 1);
+            if !run_equal {
+                return false;
+            }
+            start = end;
+        }
+        true
+    }
+}
+
 /// Constructs an array using the input `data`.
 /// Returns a reference-counted `Array` instance.
 pub fn make_array(data: ArrayData) -> ArrayRef {
@@ -18,6 +18,7 @@
 //! Contains the `NullArray` type.
 
 use crate::Array;
+use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_data::ArrayData;
 use arrow_schema::DataType;
 use std::any::Any;
@@ -84,6 +85,11 @@ impl Array for NullArray {
     fn null_count(&self) -> usize {
         self.data_ref().len()
     }
+
+    /// All elements of a `NullArray` are always logically null.
+    fn logical_nulls(&self) -> Option<Buffer> {
+        Some(MutableBuffer::new_null(self.len()).into())
+    }
 }
 
 impl From<ArrayData> for NullArray {
@@ -146,4 +152,12 @@ mod tests {
         let array = NullArray::new(1024 * 1024);
         assert_eq!(format!("{array:?}"), "NullArray(1048576)");
     }
+
+    #[test]
+    fn test_null_array_logical_nulls() {
+        let array = NullArray::new(10).slice(2, 5);
+        let nulls = array.logical_nulls().unwrap();
+        assert_eq!(nulls.count_set_bits_offset(0, 5), 0);
+        assert_eq!(array.logical_null_count(), 5);
+    }
 }
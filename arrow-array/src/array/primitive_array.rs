@@ -1067,6 +1067,74 @@ impl<T: ArrowPrimitiveType> From<ArrayData> for PrimitiveArray<T> {
     }
 }
 
+impl PrimitiveArray<IntervalMonthDayNanoType> {
+    /// Creates a [`IntervalMonthDayNanoArray`] from an iterator of optional
+    /// `(months, days, nanoseconds)` tuples
+    ///
+    /// ```
+    /// # use arrow_array::{Array, IntervalMonthDayNanoArray};
+    /// let array = IntervalMonthDayNanoArray::from_parts([Some((1, 2, 3)), None]);
+    /// assert_eq!(array.value_parts(0), (1, 2, 3));
+    /// assert_eq!(array.value_months(0), 1);
+    /// assert_eq!(array.value_days(0), 2);
+    /// assert_eq!(array.value_nanoseconds(0), 3);
+    /// assert!(array.is_null(1));
+    /// assert_eq!(
+    ///     array.value_as_string(0),
+    ///     "0 years 1 mons 2 days 0 hours 0 mins 0.000000003 secs"
+    /// );
+    /// ```
+    pub fn from_parts<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Option<(i32, i32, i64)>>,
+    {
+        iter.into_iter()
+            .map(|v| {
+                v.map(|(months, days, nanos)| {
+                    IntervalMonthDayNanoType::make_value(months, days, nanos)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the `(months, days, nanoseconds)` of the interval at index `i`
+    ///
+    /// # Panics
+    /// Panics if index `i` is out of bounds
+    pub fn value_parts(&self, i: usize) -> (i32, i32, i64) {
+        IntervalMonthDayNanoType::to_parts(self.value(i))
+    }
+
+    /// Returns the number of months of the interval at index `i`
+    ///
+    /// # Panics
+    /// Panics if index `i` is out of bounds
+    pub fn value_months(&self, i: usize) -> i32 {
+        self.value_parts(i).0
+    }
+
+    /// Returns the number of days of the interval at index `i`
+    ///
+    /// # Panics
+    /// Panics if index `i` is out of bounds
+    pub fn value_days(&self, i: usize) -> i32 {
+        self.value_parts(i).1
+    }
+
+    /// Returns the number of nanoseconds of the interval at index `i`
+    ///
+    /// # Panics
+    /// Panics if index `i` is out of bounds
+    pub fn value_nanoseconds(&self, i: usize) -> i64 {
+        self.value_parts(i).2
+    }
+
+    /// Returns [`Self::value`] formatted as a string
+    pub fn value_as_string(&self, i: usize) -> String {
+        IntervalMonthDayNanoType::format_value(self.value(i))
+    }
+}
+
 impl<T: DecimalType + ArrowPrimitiveType> PrimitiveArray<T> {
     /// Returns a Decimal array with the same data as self, with the
     /// specified precision and scale.
@@ -1350,8 +1418,7 @@ mod tests {
         assert_eq!(-5, arr.values()[2]);
 
         // a month_day_nano interval contains months, days and nanoseconds,
-        // but we do not yet have accessors for the values.
-        // TODO: implement month, day, and nanos access method for month_day_nano.
+        // see test_interval_month_day_nano_parts for accessors of these values
         let arr = IntervalMonthDayNanoArray::from(vec![
             Some(100000000000000000000),
             None,
@@ -1367,6 +1434,32 @@ mod tests {
         assert_eq!(-500000000000000000000, arr.values()[2]);
     }
 
+    #[test]
+    fn test_interval_month_day_nano_parts() {
+        let arr = IntervalMonthDayNanoArray::from_parts(vec![
+            Some((1, -2, 3)),
+            None,
+            Some((i32::MIN, i32::MAX, -1_500_000_000)),
+        ]);
+        assert_eq!(3, arr.len());
+        assert_eq!(1, arr.null_count());
+        assert_eq!(arr.value_parts(0), (1, -2, 3));
+        assert_eq!(arr.value_months(2), i32::MIN);
+        assert_eq!(arr.value_days(2), i32::MAX);
+        assert_eq!(arr.value_nanoseconds(2), -1_500_000_000);
+        assert_eq!(
+            arr.value_as_string(2),
+            "0 years -2147483648 mons 2147483647 days 0 hours 0 mins -1.500000000 secs"
+        );
+
+        let sliced = arr.slice(2, 1);
+        let sliced = sliced
+            .as_any()
+            .downcast_ref::<IntervalMonthDayNanoArray>()
+            .unwrap();
+        assert_eq!(sliced.value_parts(0), (i32::MIN, i32::MAX, -1_500_000_000));
+    }
+
     #[test]
     fn test_duration_array_from_vec() {
         let arr = DurationSecondArray::from(vec![Some(1), None, Some(-5)]);
@@ -17,12 +17,12 @@
 
 use std::any::Any;
 
-use arrow_buffer::ArrowNativeType;
+use arrow_buffer::{bit_util, ArrowNativeType, Buffer};
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType, Field};
 
 use crate::{
-    builder::StringRunBuilder,
+    builder::{BooleanBufferBuilder, StringRunBuilder},
     make_array,
     run_iterator::RunArrayIter,
     types::{Int16Type, Int32Type, Int64Type, RunEndIndexType},
@@ -119,10 +119,122 @@ impl<R: RunEndIndexType> RunArray<R> {
     }
 
     /// Returns a reference to values array
+    ///
+    /// Note: any slicing of this array is not applied to the returned array
+    /// and must be handled separately
     pub fn values(&self) -> &ArrayRef {
         &self.values
     }
 
+    /// Returns the physical index of the run containing the given logical index,
+    /// ignoring any slicing of this array
+    #[inline]
+    fn get_zero_offset_physical_index(&self, logical_index: usize) -> Option<usize> {
+        if logical_index >= Self::logical_len(&self.run_ends) {
+            return None;
+        }
+        // Binary search for the first run that ends after the logical index
+        Some(
+            self.run_ends
+                .values()
+                .partition_point(|run_end| run_end.as_usize() <= logical_index),
+        )
+    }
+
+    /// Returns index to the physical array for the given index to the logical array.
+    /// The logical index is relative to the start of this, possibly sliced, array.
+    /// Performs a binary search on the run_ends array for the input index.
+    ///
+    /// Returns `None` if `logical_index >= self.len()`
+    #[inline]
+    pub fn get_physical_index(&self, logical_index: usize) -> Option<usize> {
+        if logical_index >= self.len() {
+            return None;
+        }
+        self.get_zero_offset_physical_index(logical_index + self.offset())
+    }
+
+    /// Returns the physical index at which the array slice starts.
+    ///
+    /// Returns `0` for an empty array
+    pub fn get_start_physical_index(&self) -> usize {
+        if self.offset() == 0 || self.is_empty() {
+            return 0;
+        }
+        self.get_zero_offset_physical_index(self.offset()).unwrap()
+    }
+
+    /// Returns the physical index at which the array slice ends, inclusive.
+    ///
+    /// Returns `0` for an empty array
+    pub fn get_end_physical_index(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        if self.offset() + self.len() == Self::logical_len(&self.run_ends) {
+            return self.run_ends.len() - 1;
+        }
+        self.get_zero_offset_physical_index(self.offset() + self.len() - 1)
+            .unwrap()
+    }
+
+    /// Returns the physical indices of the runs containing the given logical indices,
+    /// which are relative to the start of this, possibly sliced, array.
+    ///
+    /// The logical indices are resolved in a single pass over the run_ends array,
+    /// searching forward from the previously resolved run. Indices that are not sorted
+    /// are sorted first, the returned physical indices are in the order of `logical_indices`.
+    ///
+    /// Returns an error if any logical index is out of bounds.
+    ///
+    /// ```
+    /// # use arrow_array::{Array, Int32RunArray};
+    /// let array: Int32RunArray = vec!["a", "a", "b", "c", "c", "c"].into_iter().collect();
+    /// assert_eq!(array.get_physical_indices(&[0, 1, 3, 5]).unwrap(), vec![0, 0, 2, 2]);
+    /// assert_eq!(array.get_physical_indices(&[5, 2, 0]).unwrap(), vec![2, 1, 0]);
+    /// assert!(array.get_physical_indices(&[6]).is_err());
+    /// ```
+    pub fn get_physical_indices(
+        &self,
+        logical_indices: &[usize],
+    ) -> Result<Vec<usize>, ArrowError> {
+        let len = self.len();
+        let offset = self.offset();
+        let logical_indices = logical_indices
+            .iter()
+            .map(|index| match *index < len {
+                true => Ok(index + offset),
+                false => Err(ArrowError::InvalidArgumentError(format!(
+                    "Logical index {index} is out of bounds for RunArray of length {len}"
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let sorted = logical_indices.windows(2).all(|w| w[0] <= w[1]);
+        let ordered: Vec<usize> = match sorted {
+            true => (0..logical_indices.len()).collect(),
+            false => {
+                let mut ordered: Vec<usize> = (0..logical_indices.len()).collect();
+                ordered.sort_unstable_by_key(|idx| logical_indices[*idx]);
+                ordered
+            }
+        };
+
+        let run_ends = self.run_ends.values();
+        let mut physical_indices = vec![0; logical_indices.len()];
+        let mut physical = self.get_start_physical_index();
+        for idx in ordered {
+            let logical = logical_indices[idx];
+            if run_ends[physical].as_usize() <= logical {
+                // Search forward from the current run
+                physical += run_ends[physical..]
+                    .partition_point(|run_end| run_end.as_usize() <= logical);
+            }
+            physical_indices[idx] = physical;
+        }
+        Ok(physical_indices)
+    }
+
     /// Downcast this [`RunArray`] to a [`TypedRunArray`]
     ///
     /// ```
@@ -143,6 +255,64 @@ impl<R: RunEndIndexType> RunArray<R> {
             values,
         })
     }
+
+    /// Returns a new [`RunArray`] with the same run ends, offset and length as `self`
+    /// but with the provided values array
+    ///
+    /// This can be used to apply an operation to the physical values of a [`RunArray`]
+    /// without expanding it to its logical length
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` has a length different from the current values array
+    ///
+    /// ```
+    /// use arrow_array::{Array, Int32Array, Int64Array, RunArray, types::Int16Type};
+    ///
+    /// let run_ends = [2_i16, 5].into_iter().collect();
+    /// let values = Int32Array::from(vec![1, 2]);
+    /// let array = RunArray::<Int16Type>::try_new(&run_ends, &values).unwrap();
+    ///
+    /// // Perform a widening cast of the run values
+    /// let typed = array.downcast::<Int32Array>().unwrap();
+    /// let values: Int64Array = typed.values().unary(|x| x as i64 * 10);
+    ///
+    /// let new = array.with_values(&values);
+    /// assert_eq!(new.len(), 5);
+    /// assert_eq!(new.run_ends(), array.run_ends());
+    /// let new_typed = new.downcast::<Int64Array>().unwrap();
+    /// assert_eq!(new_typed.values(), &Int64Array::from(vec![10, 20]));
+    /// ```
+    ///
+    pub fn with_values(&self, values: &dyn Array) -> Self {
+        assert_eq!(values.len(), self.values.len());
+
+        let (run_ends_field, values_field) = match self.data.data_type() {
+            DataType::RunEndEncoded(run_ends_field, values_field) => {
+                (run_ends_field.clone(), values_field)
+            }
+            _ => unreachable!(),
+        };
+        // Keep the name of the values field, only changing its type
+        let values_field = Field::new(
+            values_field.name(),
+            values.data_type().clone(),
+            values_field.is_nullable() || values.null_count() > 0,
+        );
+        let builder = self
+            .data
+            .clone()
+            .into_builder()
+            .data_type(DataType::RunEndEncoded(
+                run_ends_field,
+                Box::new(values_field),
+            ))
+            .child_data(vec![self.run_ends.data().clone(), values.data().clone()]);
+
+        // SAFETY:
+        // Run ends were valid before and the values array has the same length
+        Self::from(unsafe { builder.build_unchecked() })
+    }
 }
 
 impl<R: RunEndIndexType> From<ArrayData> for RunArray<R> {
@@ -183,6 +353,23 @@ impl<T: RunEndIndexType> Array for RunArray<T> {
     fn into_data(self) -> ArrayData {
         self.into()
     }
+
+    fn logical_nulls(&self) -> Option<Buffer> {
+        let value_nulls = self.values.logical_nulls()?;
+
+        let mut builder = BooleanBufferBuilder::new(self.len());
+        let start = self.get_start_physical_index();
+        let end = self.get_end_physical_index();
+        let mut logical_start = 0;
+        for (physical, run_end) in self.run_ends.values()[start..=end].iter().enumerate()
+        {
+            let logical_end = (run_end.as_usize() - self.offset()).min(self.len());
+            let valid = bit_util::get_bit(value_nulls.as_slice(), start + physical);
+            builder.append_n(logical_end - logical_start, valid);
+            logical_start = logical_end;
+        }
+        Some(builder.finish())
+    }
 }
 
 impl<R: RunEndIndexType> std::fmt::Debug for RunArray<R> {
@@ -349,35 +536,18 @@ impl<'a, R: RunEndIndexType, V> TypedRunArray<'a, R, V> {
         self.values
     }
 
-    /// Returns index to the physcial array for the given index to the logical array.
+    /// Returns the [`RunArray`] of this [`TypedRunArray`]
+    pub fn run_array(&self) -> &'a RunArray<R> {
+        self.run_array
+    }
+
+    /// Returns index to the physical array for the given index to the logical array.
     /// Performs a binary search on the run_ends array for the input index.
+    ///
+    /// See [`RunArray::get_physical_index`]
     #[inline]
     pub fn get_physical_index(&self, logical_index: usize) -> Option<usize> {
-        if logical_index >= self.run_array.len() {
-            return None;
-        }
-        let mut st: usize = 0;
-        let mut en: usize = self.run_ends().len();
-        while st + 1 < en {
-            let mid: usize = (st + en) / 2;
-            if logical_index
-                < unsafe {
-                    // Safety:
-                    // The value of mid will always be between 1 and len - 1,
-                    // where len is length of run ends array.
-                    // This is based on the fact that `st` starts with 0 and
-                    // `en` starts with len. The condition `st + 1 < en` ensures
-                    // `st` and `en` differs atleast by two. So the value of `mid`
-                    // will never be either `st` or `en`
-                    self.run_ends().value_unchecked(mid - 1).as_usize()
-                }
-            {
-                en = mid
-            } else {
-                st = mid
-            }
-        }
-        Some(st)
+        self.run_array.get_physical_index(logical_index)
     }
 }
 
@@ -447,8 +617,9 @@ mod tests {
 
     use super::*;
     use crate::builder::PrimitiveRunBuilder;
+    use crate::cast::as_run_array;
     use crate::types::{Int16Type, Int32Type, Int8Type, UInt32Type};
-    use crate::{Array, Int16Array, Int32Array, StringArray};
+    use crate::{Array, Int16Array, Int32Array, Int32RunArray, StringArray};
 
     fn build_input_array(approx_size: usize) -> Vec<Option<i32>> {
         // The input array is created by shuffling and repeating
@@ -728,4 +899,191 @@ mod tests {
             };
         }
     }
+
+    #[test]
+    fn test_run_array_slice() {
+        let input_array = build_input_array(100);
+
+        let mut builder =
+            PrimitiveRunBuilder::<Int16Type, Int32Type>::with_capacity(input_array.len());
+        builder.extend(input_array.iter().copied());
+        let run_array = builder.finish();
+
+        for offset in 0..input_array.len() {
+            for length in 0..input_array.len() - offset {
+                let sliced = run_array.slice(offset, length);
+                let sliced = sliced
+                    .as_any()
+                    .downcast_ref::<RunArray<Int16Type>>()
+                    .unwrap();
+                assert_eq!(sliced.len(), length);
+                assert_eq!(sliced.offset(), offset);
+                assert!(sliced.get_physical_index(length).is_none());
+
+                let typed = sliced.downcast::<PrimitiveArray<Int32Type>>().unwrap();
+                let expected = &input_array[offset..offset + length];
+
+                // Accessors respect the offset
+                for (i, inp_val) in expected.iter().enumerate() {
+                    let physical_ix = typed.get_physical_index(i).unwrap();
+                    assert!(physical_ix >= sliced.get_start_physical_index());
+                    assert!(physical_ix <= sliced.get_end_physical_index());
+                    match inp_val {
+                        Some(val) => assert_eq!(*val, typed.value(i)),
+                        None => assert!(typed.values().is_null(physical_ix)),
+                    }
+                }
+
+                // Iterators respect the offset
+                let actual: Vec<_> = typed.into_iter().collect();
+                assert_eq!(expected, actual.as_slice());
+                let mut reversed: Vec<_> = typed.into_iter().rev().collect();
+                reversed.reverse();
+                assert_eq!(expected, reversed.as_slice());
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_array_slice_physical_indices() {
+        let run_ends = Int32Array::from(vec![2, 5, 6, 10]);
+        let values = StringArray::from(vec!["a", "b", "c", "d"]);
+        let array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+        assert_eq!(array.get_start_physical_index(), 0);
+        assert_eq!(array.get_end_physical_index(), 3);
+
+        let sliced = array.slice(3, 4);
+        let sliced = sliced.as_any().downcast_ref::<Int32RunArray>().unwrap();
+        assert_eq!(sliced.get_start_physical_index(), 1);
+        assert_eq!(sliced.get_end_physical_index(), 3);
+        assert_eq!(sliced.get_physical_index(0), Some(1));
+        assert_eq!(sliced.get_physical_index(2), Some(2));
+        assert_eq!(sliced.get_physical_index(3), Some(3));
+        assert_eq!(sliced.get_physical_index(4), None);
+
+        let typed = sliced.downcast::<StringArray>().unwrap();
+        let values: Vec<_> = typed.into_iter().flatten().collect();
+        assert_eq!(values, vec!["b", "b", "c", "d"]);
+
+        // Slicing a slice accumulates the offset
+        let sliced = sliced.slice(1, 1);
+        let sliced = sliced.as_any().downcast_ref::<Int32RunArray>().unwrap();
+        assert_eq!(sliced.offset(), 4);
+        assert_eq!(sliced.get_start_physical_index(), 1);
+        assert_eq!(sliced.get_end_physical_index(), 1);
+
+        let empty = array.slice(10, 0);
+        let empty = empty.as_any().downcast_ref::<Int32RunArray>().unwrap();
+        assert_eq!(empty.get_start_physical_index(), 0);
+        assert_eq!(empty.get_end_physical_index(), 0);
+        let typed = empty.downcast::<StringArray>().unwrap();
+        assert_eq!(typed.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn test_run_array_get_physical_indices() {
+        let run_ends = Int32Array::from(vec![2, 5, 6, 10]);
+        let values = StringArray::from(vec!["a", "b", "c", "d"]);
+        let array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+
+        let logical_indices: Vec<usize> = (0..10).collect();
+        let expected: Vec<usize> = logical_indices
+            .iter()
+            .map(|idx| array.get_physical_index(*idx).unwrap())
+            .collect();
+        assert_eq!(
+            array.get_physical_indices(&logical_indices).unwrap(),
+            expected
+        );
+
+        let logical_indices = [9, 0, 5, 5, 1, 6];
+        assert_eq!(
+            array.get_physical_indices(&logical_indices).unwrap(),
+            vec![3, 0, 2, 2, 0, 3]
+        );
+        assert!(array.get_physical_indices(&[]).unwrap().is_empty());
+
+        let err = array.get_physical_indices(&[0, 10]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Logical index 10 is out of bounds for RunArray of length 10"
+        );
+
+        // Logical indices are relative to the slice
+        let sliced = array.slice(3, 4);
+        let sliced = sliced.as_any().downcast_ref::<Int32RunArray>().unwrap();
+        assert_eq!(
+            sliced.get_physical_indices(&[3, 0, 1, 2]).unwrap(),
+            vec![3, 1, 1, 2]
+        );
+        assert!(sliced.get_physical_indices(&[4]).is_err());
+    }
+
+    #[test]
+    fn test_run_array_sliced_data_validation() {
+        let array: Int32RunArray = vec!["a", "a", "b", "c", "c"].into_iter().collect();
+        let data = array.into_data();
+        data.slice(1, 3).validate_full().unwrap();
+
+        let data = data.into_builder().offset(3).len(3);
+        let err = data.build().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: The offset + length of array should be less or equal to last value in the run_ends array. The last value of run_ends array is 5 and offset + length of array is 6."
+        );
+    }
+
+    #[test]
+    fn test_run_array_eq() {
+        let array: Int32RunArray = vec!["a", "a", "b", "c", "c"].into_iter().collect();
+        let other: Int32RunArray = vec!["a", "a", "b", "c", "c"].into_iter().collect();
+        assert_eq!(array, other);
+
+        let other: Int32RunArray = vec!["a", "a", "b", "c"].into_iter().collect();
+        assert_ne!(array, other);
+
+        let logical = StringArray::from(vec!["a", "a", "b", "c", "c"]);
+        assert_eq!(array, logical);
+        assert_ne!(array, StringArray::from(vec!["a", "b", "b", "c", "c"]));
+        assert_ne!(array, Int32Array::from(vec![1, 1, 2, 3, 3]));
+
+        let sliced = array.slice(1, 3);
+        let sliced = as_run_array::<Int32Type>(sliced.as_ref());
+        assert_eq!(sliced, &StringArray::from(vec!["a", "b", "c"]));
+        assert_eq!(
+            sliced,
+            &vec!["a", "b", "c"].into_iter().collect::<Int32RunArray>()
+        );
+
+        let mut builder = StringRunBuilder::<Int32Type>::new();
+        builder.extend([Some("a"), None, None]);
+        let array = builder.finish();
+        assert_eq!(array, StringArray::from(vec![Some("a"), None, None]));
+        assert_ne!(array, StringArray::from(vec![Some("a"), Some("b"), None]));
+    }
+
+    #[test]
+    fn test_run_array_logical_nulls() {
+        let mut builder = StringRunBuilder::<Int32Type>::new();
+        builder.extend([Some("a"), Some("a"), None, None, None, Some("b")]);
+        let array = builder.finish();
+        assert_eq!(array.null_count(), 0);
+        assert_eq!(array.logical_null_count(), 3);
+
+        let validity = |array: &dyn Array| {
+            let nulls = array.logical_nulls().unwrap();
+            (0..array.len())
+                .map(|i| bit_util::get_bit(nulls.as_slice(), i))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(validity(&array), [true, true, false, false, false, true]);
+
+        let sliced = array.slice(1, 3);
+        assert_eq!(validity(&sliced), [true, false, false]);
+        assert_eq!(sliced.logical_null_count(), 2);
+
+        let array: Int32RunArray = vec!["a", "a", "b"].into_iter().collect();
+        assert!(array.logical_nulls().is_none());
+        assert_eq!(array.logical_null_count(), 0);
+    }
 }
@@ -15,8 +15,9 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::builder::BooleanBufferBuilder;
 use crate::{make_array, Array, ArrayRef};
-use arrow_buffer::Buffer;
+use arrow_buffer::{bit_util, Buffer};
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType, Field, UnionMode};
 /// Contains the `UnionArray` type.
@@ -338,6 +339,31 @@ impl Array for UnionArray {
     fn null_count(&self) -> usize {
         0
     }
+
+    /// Union types have no validity buffer, an element is instead logically null
+    /// if the child value it selects is null.
+    fn logical_nulls(&self) -> Option<Buffer> {
+        let child_nulls: Vec<_> = self
+            .boxed_fields
+            .iter()
+            .map(|c| c.as_ref().and_then(|c| c.logical_nulls()))
+            .collect();
+        if child_nulls.iter().all(Option::is_none) {
+            return None;
+        }
+
+        let mut builder = BooleanBufferBuilder::new(self.len());
+        for i in 0..self.len() {
+            let valid = match &child_nulls[self.type_id(i) as usize] {
+                Some(nulls) => {
+                    bit_util::get_bit(nulls.as_slice(), self.value_offset(i) as usize)
+                }
+                None => true,
+            };
+            builder.append(valid);
+        }
+        Some(builder.finish())
+    }
 }
 
 impl std::fmt::Debug for UnionArray {
@@ -933,6 +959,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_union_logical_nulls() {
+        let validity = |array: &dyn Array| {
+            let nulls = array.logical_nulls().unwrap();
+            (0..array.len())
+                .map(|i| bit_util::get_bit(nulls.as_slice(), i))
+                .collect::<Vec<_>>()
+        };
+
+        let mut builder = UnionBuilder::new_sparse();
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append_null::<Int32Type>("a").unwrap();
+        builder.append::<Float64Type>("c", 3.0).unwrap();
+        builder.append_null::<Float64Type>("c").unwrap();
+        builder.append::<Int32Type>("a", 4).unwrap();
+        let union = builder.build().unwrap();
+        assert_eq!(union.null_count(), 0);
+        assert_eq!(union.logical_null_count(), 2);
+        assert_eq!(validity(&union), [true, false, true, false, true]);
+        assert_eq!(validity(&union.slice(2, 3)), [true, false, true]);
+
+        let mut builder = UnionBuilder::new_dense();
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("c", 3.0).unwrap();
+        builder.append_null::<Float64Type>("c").unwrap();
+        builder.append::<Int32Type>("a", 4).unwrap();
+        let union = builder.build().unwrap();
+        assert_eq!(validity(&union), [true, true, false, true]);
+        assert_eq!(validity(&union.slice(1, 2)), [true, false]);
+
+        let mut builder = UnionBuilder::new_dense();
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("c", 3.0).unwrap();
+        let union = builder.build().unwrap();
+        assert!(union.logical_nulls().is_none());
+    }
+
     #[test]
     fn test_union_array_validaty() {
         let mut builder = UnionBuilder::new_sparse();
@@ -16,8 +16,10 @@
 // under the License.
 
 use crate::builder::null_buffer_builder::NullBufferBuilder;
-use crate::builder::{ArrayBuilder, BooleanBufferBuilder};
-use crate::{ArrayRef, BooleanArray};
+use crate::builder::{
+    downcast_extend, ArrayBuilder, BooleanBufferBuilder, ExtendFromArray,
+};
+use crate::{Array, ArrayRef, BooleanArray};
 use arrow_buffer::Buffer;
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType};
@@ -143,6 +145,15 @@ impl BooleanBuilder {
         }
     }
 
+    /// Appends the values and nulls of `array` into the builder
+    #[inline]
+    pub fn append_array(&mut self, array: &BooleanArray) {
+        let range = array.offset()..array.offset() + array.len();
+        self.values_builder
+            .append_packed_range(range, array.values().as_slice());
+        self.null_buffer_builder.append_validity(array.data());
+    }
+
     /// Builds the [BooleanArray] and reset this builder.
     pub fn finish(&mut self) -> BooleanArray {
         let len = self.len();
@@ -220,6 +231,13 @@ impl Extend<Option<bool>> for BooleanBuilder {
     }
 }
 
+impl ExtendFromArray for BooleanBuilder {
+    fn extend_from_array(&mut self, array: &dyn Array) -> Result<(), ArrowError> {
+        self.append_array(downcast_extend(array, "BooleanBuilder")?);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -326,4 +344,41 @@ mod tests {
             &[false, false, true, false, false, true, true, false]
         )
     }
+
+    #[test]
+    fn test_append_array() {
+        let array = BooleanArray::from(vec![
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+        ]);
+
+        let mut builder = BooleanBuilder::new();
+        builder.append_value(false);
+        builder.append_array(&BooleanArray::from(vec![true, true]));
+        let sliced = array.slice(3, 6);
+        builder.append_array(sliced.as_any().downcast_ref().unwrap());
+        builder.extend_from_array(&array.slice(9, 1)).unwrap();
+
+        let expected = BooleanArray::from(vec![
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(false),
+        ]);
+        assert_eq!(builder.finish(), expected);
+    }
 }
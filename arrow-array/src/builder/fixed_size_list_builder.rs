@@ -20,7 +20,7 @@ use crate::builder::ArrayBuilder;
 use crate::{ArrayRef, FixedSizeListArray};
 use arrow_buffer::Buffer;
 use arrow_data::ArrayData;
-use arrow_schema::{DataType, Field};
+use arrow_schema::{ArrowError, DataType, Field};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -153,20 +153,41 @@ where
         self.null_buffer_builder.append(is_valid);
     }
 
+    /// Returns an error if the child array does not have `value_length` values
+    /// for every list slot
+    fn validate_values_len(&self) -> Result<(), ArrowError> {
+        let len = self.len();
+        let values_len = self.values_builder.len();
+        match len.checked_mul(self.list_len as usize) == Some(values_len) {
+            true => Ok(()),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "Length of the child array ({}) must be the multiple of the value length ({}) and the array length ({}).",
+                values_len, self.list_len, len,
+            ))),
+        }
+    }
+
     /// Builds the [`FixedSizeListBuilder`] and reset this builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the child array does not have `value_length` values for every list
+    /// slot, see [`Self::try_finish`]
     pub fn finish(&mut self) -> FixedSizeListArray {
+        self.try_finish().unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Builds the [`FixedSizeListBuilder`] and reset this builder, returning an error
+    /// if the child array does not have `value_length` values for every list slot
+    ///
+    /// The builder is left unchanged if an error is returned
+    pub fn try_finish(&mut self) -> Result<FixedSizeListArray, ArrowError> {
+        self.validate_values_len()?;
+
         let len = self.len();
         let values_arr = self.values_builder.finish();
         let values_data = values_arr.data();
 
-        assert_eq!(
-            values_data.len(), len * self.list_len as usize,
-            "Length of the child array ({}) must be the multiple of the value length ({}) and the array length ({}).",
-            values_data.len(),
-            self.list_len,
-            len,
-        );
-
         let null_bit_buffer = self.null_buffer_builder.finish();
         let array_data = ArrayData::builder(DataType::FixedSizeList(
             Box::new(Field::new("item", values_data.data_type().clone(), true)),
@@ -178,23 +199,22 @@ where
 
         let array_data = unsafe { array_data.build_unchecked() };
 
-        FixedSizeListArray::from(array_data)
+        Ok(FixedSizeListArray::from(array_data))
     }
 
     /// Builds the [`FixedSizeListBuilder`] without resetting the builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the child array does not have `value_length` values for every list
+    /// slot
     pub fn finish_cloned(&self) -> FixedSizeListArray {
+        self.validate_values_len().unwrap_or_else(|e| panic!("{e}"));
+
         let len = self.len();
         let values_arr = self.values_builder.finish_cloned();
         let values_data = values_arr.data();
 
-        assert_eq!(
-            values_data.len(), len * self.list_len as usize,
-            "Length of the child array ({}) must be the multiple of the value length ({}) and the array length ({}).",
-            values_data.len(),
-            self.list_len,
-            len,
-        );
-
         let null_bit_buffer = self
             .null_buffer_builder
             .as_slice()
@@ -342,4 +362,26 @@ mod tests {
 
         builder.finish();
     }
+
+    #[test]
+    fn test_fixed_size_list_array_builder_try_finish() {
+        let values_builder = Int32Array::builder(5);
+        let mut builder = FixedSizeListBuilder::new(values_builder, 3);
+
+        builder.values().append_slice(&[1, 2, 3, 4]);
+        builder.append(true);
+        let err = builder.try_finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Length of the child array (4) must be the multiple of the value length (3) and the array length (1)."
+        );
+
+        // The builder is unchanged and can be completed
+        builder.values().append_slice(&[5, 6]);
+        builder.append(false);
+        let arr = builder.try_finish().unwrap();
+        assert_eq!(2, arr.len());
+        assert!(arr.is_null(1));
+        assert_eq!(0, builder.len());
+    }
 }
@@ -16,11 +16,14 @@
 // under the License.
 
 use crate::builder::null_buffer_builder::NullBufferBuilder;
-use crate::builder::{ArrayBuilder, BufferBuilder, UInt8BufferBuilder};
+use crate::builder::{
+    downcast_extend, ArrayBuilder, BufferBuilder, ExtendFromArray, UInt8BufferBuilder,
+};
 use crate::types::{ByteArrayType, GenericBinaryType, GenericStringType};
-use crate::{ArrayRef, GenericByteArray, OffsetSizeTrait};
+use crate::{Array, ArrayRef, GenericByteArray, OffsetSizeTrait};
 use arrow_buffer::{ArrowNativeType, Buffer, MutableBuffer};
 use arrow_data::ArrayDataBuilder;
+use arrow_schema::ArrowError;
 use std::any::Any;
 use std::sync::Arc;
 
@@ -115,6 +118,28 @@ impl<T: ByteArrayType> GenericByteBuilder<T> {
         self.offsets_builder.append(self.next_offset());
     }
 
+    /// Appends the values and nulls of `array` into the builder
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting length of [`Self::values_slice`] would exceed `T::Offset::MAX`
+    pub fn append_array(&mut self, array: &GenericByteArray<T>) {
+        let offsets = array.value_offsets();
+        let start = offsets[0].as_usize();
+        let end = offsets[array.len()].as_usize();
+
+        let base = self.value_builder.len();
+        self.offsets_builder.reserve(array.len());
+        offsets[1..].iter().for_each(|offset| {
+            let offset = T::Offset::from_usize(base + offset.as_usize() - start)
+                .expect("byte array offset overflow");
+            self.offsets_builder.append(offset)
+        });
+        self.value_builder
+            .append_slice(&array.value_data()[start..end]);
+        self.null_buffer_builder.append_validity(array.data());
+    }
+
     /// Builds the [`GenericByteArray`] and reset this builder.
     pub fn finish(&mut self) -> GenericByteArray<T> {
         let array_type = T::DATA_TYPE;
@@ -232,9 +257,62 @@ impl<T: ByteArrayType, V: AsRef<T::Native>> Extend<Option<V>> for GenericByteBui
     }
 }
 
+impl<T: ByteArrayType> ExtendFromArray for GenericByteBuilder<T> {
+    fn extend_from_array(&mut self, array: &dyn Array) -> Result<(), ArrowError> {
+        self.append_array(downcast_extend(array, "GenericByteBuilder")?);
+        Ok(())
+    }
+}
+
 ///  Array builder for [`GenericStringArray`][crate::GenericStringArray]
 pub type GenericStringBuilder<O> = GenericByteBuilder<GenericStringType<O>>;
 
+impl<O: OffsetSizeTrait> GenericStringBuilder<O> {
+    /// Appends the [`Display`](std::fmt::Display) representation of `value` into the
+    /// builder, without allocating an intermediate [`String`]
+    ///
+    /// ```
+    /// # use arrow_array::builder::StringBuilder;
+    /// let mut builder = StringBuilder::new();
+    /// builder.append_display(42);
+    /// builder.append_display(format_args!("{}-{}", "a", 1));
+    /// let array = builder.finish();
+    /// assert_eq!(array.value(0), "42");
+    /// assert_eq!(array.value(1), "a-1");
+    /// ```
+    #[inline]
+    pub fn append_display(&mut self, value: impl std::fmt::Display) {
+        std::fmt::Write::write_fmt(self, format_args!("{value}")).unwrap();
+        self.append_value("");
+    }
+}
+
+/// Writes are appended to the value currently being built, which is completed by
+/// the next call to [`GenericStringBuilder::append_value`]
+///
+/// ```
+/// # use std::fmt::Write;
+/// # use arrow_array::builder::StringBuilder;
+/// let mut builder = StringBuilder::new();
+/// write!(builder, "{}-{}", "foo", 1).unwrap();
+/// builder.append_value("");
+/// write!(builder, "bar").unwrap();
+/// builder.append_value("baz");
+/// let array = builder.finish();
+/// assert_eq!(array.value(0), "foo-1");
+/// assert_eq!(array.value(1), "barbaz");
+/// ```
+///
+/// Note that any pending written bytes are not discarded by
+/// [`GenericStringBuilder::append_null`], and so will be part of the null slot
+impl<O: OffsetSizeTrait> std::fmt::Write for GenericStringBuilder<O> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.value_builder.append_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
 ///  Array builder for [`GenericBinaryArray`][crate::GenericBinaryArray]
 pub type GenericBinaryBuilder<O> = GenericByteBuilder<GenericBinaryType<O>>;
 
@@ -243,6 +321,7 @@ mod tests {
     use super::*;
     use crate::array::{Array, OffsetSizeTrait};
     use crate::GenericStringArray;
+    use std::fmt::Write;
 
     fn _test_generic_binary_builder<O: OffsetSizeTrait>() {
         let mut builder = GenericBinaryBuilder::<O>::new();
@@ -443,4 +522,66 @@ mod tests {
         assert_eq!(array.value_offsets(), &[0, 1, 2, 3, 3, 4, 5, 6, 7, 15, 20]);
         assert_eq!(array.value_data(), b"abcabcdcupcakeshello");
     }
+
+    #[test]
+    fn test_write() {
+        let mut builder = GenericStringBuilder::<i64>::new();
+        write!(builder, "foo").unwrap();
+        builder.append_value("");
+        writeln!(builder, "bar").unwrap();
+        builder.append_value("");
+        builder.append_null();
+        builder.append_display(1.5);
+        let (a, b) = ("a", 2);
+        write!(builder, "{a}-{b}").unwrap();
+        builder.append_value("b");
+
+        let array = builder.finish();
+        assert_eq!(array.value_offsets(), &[0, 3, 7, 7, 10, 14]);
+        assert_eq!(array.value_data(), b"foobar\n1.5a-2b");
+        assert!(array.is_null(2));
+    }
+
+    #[test]
+    fn test_append_array() {
+        let array = GenericStringArray::<i32>::from(vec![
+            Some("foo"),
+            None,
+            Some("bar"),
+            Some(""),
+        ]);
+
+        let mut builder = GenericStringBuilder::<i32>::new();
+        builder.append_value("a");
+        builder.append_array(
+            array
+                .slice(1, 3)
+                .as_any()
+                .downcast_ref::<GenericStringArray<i32>>()
+                .unwrap(),
+        );
+        builder.extend_from_array(&array).unwrap();
+
+        let array = builder.finish();
+        assert_eq!(array.value_offsets(), &[0, 1, 1, 4, 4, 7, 7, 10, 10]);
+        assert_eq!(array.value_data(), b"abarfoobar");
+        assert_eq!(
+            array.iter().collect::<Vec<_>>(),
+            vec![
+                Some("a"),
+                None,
+                Some("bar"),
+                Some(""),
+                Some("foo"),
+                None,
+                Some("bar"),
+                Some("")
+            ]
+        );
+
+        let err = builder
+            .extend_from_array(&GenericStringArray::<i64>::from(vec!["a"]))
+            .unwrap_err();
+        assert!(err.to_string().contains("LargeUtf8"), "{}", err);
+    }
 }
@@ -16,11 +16,11 @@
 // under the License.
 
 use crate::builder::null_buffer_builder::NullBufferBuilder;
-use crate::builder::{ArrayBuilder, BufferBuilder};
-use crate::{ArrayRef, GenericListArray, OffsetSizeTrait};
+use crate::builder::{downcast_extend, ArrayBuilder, BufferBuilder, ExtendFromArray};
+use crate::{Array, ArrayRef, GenericListArray, OffsetSizeTrait};
 use arrow_buffer::Buffer;
 use arrow_data::ArrayData;
-use arrow_schema::Field;
+use arrow_schema::{ArrowError, Field};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -182,6 +182,51 @@ where
     }
 }
 
+impl<OffsetSize, T> GenericListBuilder<OffsetSize, T>
+where
+    OffsetSize: OffsetSizeTrait,
+    T: ExtendFromArray,
+{
+    /// Appends the lists of `array`, including nulls, into the builder
+    ///
+    /// Returns an error if the values of `array` cannot be appended to [`Self::values`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of [`Self::values`] exceeds `OffsetSize::MAX`
+    pub fn append_array(
+        &mut self,
+        array: &GenericListArray<OffsetSize>,
+    ) -> Result<(), ArrowError> {
+        let offsets = array.value_offsets();
+        let start = offsets[0].as_usize();
+        let end = offsets[array.len()].as_usize();
+
+        let base = self.values_builder.len();
+        let values = array.values().slice(start, end - start);
+        self.values_builder.extend_from_array(values.as_ref())?;
+
+        self.offsets_builder.reserve(array.len());
+        offsets[1..].iter().for_each(|offset| {
+            let offset = base + offset.as_usize() - start;
+            self.offsets_builder
+                .append(OffsetSize::from_usize(offset).unwrap())
+        });
+        self.null_buffer_builder.append_validity(array.data());
+        Ok(())
+    }
+}
+
+impl<OffsetSize, T> ExtendFromArray for GenericListBuilder<OffsetSize, T>
+where
+    OffsetSize: OffsetSizeTrait,
+    T: ExtendFromArray,
+{
+    fn extend_from_array(&mut self, array: &dyn Array) -> Result<(), ArrowError> {
+        self.append_array(downcast_extend(array, "GenericListBuilder")?)
+    }
+}
+
 impl<O, B, V, E> Extend<Option<V>> for GenericListBuilder<O, B>
 where
     O: OffsetSizeTrait,
@@ -208,7 +253,7 @@ mod tests {
     use crate::builder::{Int32Builder, ListBuilder};
     use crate::cast::as_primitive_array;
     use crate::types::Int32Type;
-    use crate::{Array, Int32Array};
+    use crate::{Array, Int32Array, ListArray};
     use arrow_buffer::Buffer;
     use arrow_schema::DataType;
 
@@ -411,4 +456,55 @@ mod tests {
         assert_eq!(elements.null_count(), 1);
         assert!(elements.is_null(3));
     }
+
+    #[test]
+    fn test_append_array() {
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), None]),
+        ]);
+
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        builder.values().append_value(0);
+        builder.append(true);
+        let sliced = array.slice(1, 3);
+        builder
+            .append_array(sliced.as_any().downcast_ref().unwrap())
+            .unwrap();
+        builder.extend_from_array(&array).unwrap();
+        let list = builder.finish();
+
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(0)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), None]),
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(3), None]),
+        ]);
+        assert_eq!(list, expected);
+        assert_eq!(list.value_offsets(), &[0, 1, 1, 1, 3, 5, 5, 5, 7]);
+
+        // Nested lists
+        let mut builder = ListBuilder::new(ListBuilder::new(Int32Builder::new()));
+        builder.values().append_array(&array).unwrap();
+        builder.append(true);
+        builder.extend_from_array(&builder.finish_cloned()).unwrap();
+        let nested = builder.finish();
+        assert_eq!(nested.len(), 2);
+        assert_eq!(nested.value(1).as_ref(), &array as &dyn Array);
+
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        let err = builder
+            .extend_from_array(&Int32Array::from(vec![1]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot extend GenericListBuilder from array of type Int32"
+        );
+    }
 }
@@ -0,0 +1,394 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use std::borrow::Borrow;
+use std::fmt::Debug;
+use std::{any::Any, sync::Arc};
+
+use crate::{
+    types::{ByteArrayType, RunEndIndexType},
+    ArrayRef, ArrowPrimitiveType, PrimitiveArray, RunArray,
+};
+
+use super::{ArrayBuilder, BooleanBuilder, GenericByteBuilder, PrimitiveBuilder};
+
+use arrow_buffer::ArrowNativeType;
+
+/// A builder of values that can be run-end encoded by [`GenericRunBuilder`]
+pub trait RunValuesBuilder: ArrayBuilder {
+    /// The type of the values appended to this builder
+    type Value: ?Sized + PartialEq + ToOwned;
+
+    /// Appends the value of a new run
+    fn append_run_value(&mut self, value: &Self::Value);
+
+    /// Appends a null for a new run
+    fn append_run_null(&mut self);
+}
+
+impl RunValuesBuilder for BooleanBuilder {
+    type Value = bool;
+
+    fn append_run_value(&mut self, value: &bool) {
+        self.append_value(*value)
+    }
+
+    fn append_run_null(&mut self) {
+        self.append_null()
+    }
+}
+
+impl<T: ArrowPrimitiveType> RunValuesBuilder for PrimitiveBuilder<T> {
+    type Value = T::Native;
+
+    fn append_run_value(&mut self, value: &T::Native) {
+        self.append_value(*value)
+    }
+
+    fn append_run_null(&mut self) {
+        self.append_null()
+    }
+}
+
+impl<T> RunValuesBuilder for GenericByteBuilder<T>
+where
+    T: ByteArrayType,
+    T::Native: PartialEq + ToOwned,
+{
+    type Value = T::Native;
+
+    fn append_run_value(&mut self, value: &T::Native) {
+        self.append_value(value)
+    }
+
+    fn append_run_null(&mut self) {
+        self.append_null()
+    }
+}
+
+/// Array builder for [`RunArray`] over an arbitrary values builder `V`.
+///
+/// Consecutive equal values appended to this builder are encoded as a single run,
+/// with the value of each run appended to the values builder.
+///
+/// # Example:
+///
+/// ```
+/// # use arrow_array::builder::{BooleanBuilder, GenericRunBuilder};
+/// # use arrow_array::cast::as_boolean_array;
+/// # use arrow_array::types::Int16Type;
+/// # use arrow_array::{BooleanArray, Int16Array};
+///
+/// let mut builder = GenericRunBuilder::<Int16Type, BooleanBuilder>::new();
+/// builder.append_value(&true);
+/// builder.append_value(&true);
+/// builder.append_null();
+/// builder.extend([Some(&false), Some(&false), Some(&true)]);
+/// let array = builder.finish();
+///
+/// assert_eq!(array.run_ends(), &Int16Array::from(vec![2, 3, 5, 6]));
+///
+/// // Values are polymorphic and so require a downcast.
+/// let av = as_boolean_array(array.values().as_ref());
+/// assert_eq!(av, &BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]));
+/// ```
+pub struct GenericRunBuilder<R, V>
+where
+    R: RunEndIndexType,
+    V: RunValuesBuilder,
+{
+    run_ends_builder: PrimitiveBuilder<R>,
+    values_builder: V,
+    current_value: Option<<V::Value as ToOwned>::Owned>,
+    current_run_end_index: usize,
+}
+
+impl<R, V> Debug for GenericRunBuilder<R, V>
+where
+    R: RunEndIndexType + Debug,
+    V: RunValuesBuilder + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GenericRunBuilder")
+            .field("run_ends_builder", &self.run_ends_builder)
+            .field("values_builder", &self.values_builder)
+            .field("current_run_end_index", &self.current_run_end_index)
+            .finish()
+    }
+}
+
+impl<R, V> Default for GenericRunBuilder<R, V>
+where
+    R: RunEndIndexType,
+    V: RunValuesBuilder + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R, V> GenericRunBuilder<R, V>
+where
+    R: RunEndIndexType,
+    V: RunValuesBuilder + Default,
+{
+    /// Creates a new `GenericRunBuilder`
+    pub fn new() -> Self {
+        Self::new_with_values_builder(V::default())
+    }
+}
+
+impl<R, V> GenericRunBuilder<R, V>
+where
+    R: RunEndIndexType,
+    V: RunValuesBuilder,
+{
+    /// Creates a new `GenericRunBuilder` appending the values of runs to `values_builder`
+    ///
+    /// This allows providing a values builder with a specific capacity or data type
+    pub fn new_with_values_builder(values_builder: V) -> Self {
+        Self {
+            run_ends_builder: PrimitiveBuilder::new(),
+            values_builder,
+            current_value: None,
+            current_run_end_index: 0,
+        }
+    }
+
+    /// Returns a reference to the values builder
+    pub fn values(&self) -> &V {
+        &self.values_builder
+    }
+
+    /// Appends optional value to the logical array encoded by the RunArray.
+    pub fn append_option(&mut self, value: Option<&V::Value>) {
+        if self.current_run_end_index > 0 {
+            if self.current_value.as_ref().map(Borrow::borrow) == value {
+                self.current_run_end_index += 1;
+                return;
+            }
+            // write the end of the current run
+            let run_end_index = self.run_end_index_as_native();
+            self.run_ends_builder.append_value(run_end_index);
+        }
+
+        match value {
+            Some(value) => self.values_builder.append_run_value(value),
+            None => self.values_builder.append_run_null(),
+        }
+        self.current_value = value.map(ToOwned::to_owned);
+        self.current_run_end_index += 1;
+    }
+
+    /// Appends value to the logical array encoded by the RunArray.
+    pub fn append_value(&mut self, value: &V::Value) {
+        self.append_option(Some(value))
+    }
+
+    /// Appends null to the logical array encoded by the RunArray.
+    pub fn append_null(&mut self) {
+        self.append_option(None)
+    }
+
+    /// Creates the RunArray and resets the builder.
+    /// Panics if RunArray cannot be built.
+    pub fn finish(&mut self) -> RunArray<R> {
+        // write the last run end to the array.
+        if self.current_run_end_index > 0 {
+            let run_end_index = self.run_end_index_as_native();
+            self.run_ends_builder.append_value(run_end_index);
+        }
+
+        // reset the run index to zero.
+        self.current_value = None;
+        self.current_run_end_index = 0;
+
+        let run_ends_array = self.run_ends_builder.finish();
+        let values_array = self.values_builder.finish();
+        RunArray::<R>::try_new(&run_ends_array, values_array.as_ref()).unwrap()
+    }
+
+    /// Creates the RunArray and without resetting the builder.
+    /// Panics if RunArray cannot be built.
+    pub fn finish_cloned(&self) -> RunArray<R> {
+        let run_ends = self.run_ends_builder.values_slice().iter().copied();
+        let run_ends_array = match self.current_run_end_index {
+            0 => PrimitiveArray::<R>::from_iter_values(run_ends),
+            _ => PrimitiveArray::<R>::from_iter_values(
+                run_ends.chain(std::iter::once(self.run_end_index_as_native())),
+            ),
+        };
+        let values_array = self.values_builder.finish_cloned();
+        RunArray::<R>::try_new(&run_ends_array, values_array.as_ref()).unwrap()
+    }
+
+    fn run_end_index_as_native(&self) -> R::Native {
+        R::Native::from_usize(self.current_run_end_index)
+        .unwrap_or_else(|| panic!(
+                "Cannot convert the value {} from `usize` to native form of arrow datatype {}",
+                self.current_run_end_index,
+                R::DATA_TYPE
+        ))
+    }
+}
+
+impl<R, V> ArrayBuilder for GenericRunBuilder<R, V>
+where
+    R: RunEndIndexType,
+    V: RunValuesBuilder,
+    <V::Value as ToOwned>::Owned: Send,
+{
+    /// Returns the builder as a non-mutable `Any` reference.
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Returns the builder as a mutable `Any` reference.
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    /// Returns the boxed builder as a box of `Any`.
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+
+    /// Returns the length of logical array encoded by
+    /// the eventual runs array.
+    fn len(&self) -> usize {
+        self.current_run_end_index
+    }
+
+    /// Returns whether the number of array slots is zero
+    fn is_empty(&self) -> bool {
+        self.current_run_end_index == 0
+    }
+
+    /// Builds the array and reset this builder.
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(self.finish())
+    }
+
+    /// Builds the array without resetting the builder.
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(self.finish_cloned())
+    }
+}
+
+impl<'a, R, V> Extend<Option<&'a V::Value>> for GenericRunBuilder<R, V>
+where
+    R: RunEndIndexType,
+    V: RunValuesBuilder,
+{
+    fn extend<T: IntoIterator<Item = Option<&'a V::Value>>>(&mut self, iter: T) {
+        for elem in iter {
+            self.append_option(elem);
+        }
+    }
+}
+
+/// Array builder for [`RunArray`] that encodes boolean values.
+/// See [`GenericRunBuilder`] for an example.
+pub type BooleanRunBuilder<R> = GenericRunBuilder<R, BooleanBuilder>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::builder::{Int32Builder, StringBuilder, StringRunBuilder};
+    use crate::cast::{as_boolean_array, as_primitive_array, as_string_array};
+    use crate::types::{Decimal128Type, Int16Type, Int32Type, Int64Type};
+    use crate::{Array, BooleanArray, Decimal128Array, Int32Array, StringArray};
+
+    #[test]
+    fn test_boolean_run_builder() {
+        let mut builder = BooleanRunBuilder::<Int16Type>::new();
+        builder.append_value(&true);
+        builder.append_null();
+        builder.append_null();
+        builder.extend([Some(&false), Some(&false), Some(&true), None]);
+        assert_eq!(builder.len(), 7);
+        let array = builder.finish();
+
+        assert_eq!(array.len(), 7);
+        assert_eq!(array.run_ends().values(), &[1, 3, 5, 6, 7]);
+        let values = as_boolean_array(array.values().as_ref());
+        assert_eq!(
+            values,
+            &BooleanArray::from(vec![Some(true), None, Some(false), Some(true), None])
+        );
+        assert!(builder.is_empty());
+    }
+
+    #[test]
+    fn test_primitive_values_builder() {
+        let values_builder = PrimitiveBuilder::<Decimal128Type>::new()
+            .with_data_type(arrow_schema::DataType::Decimal128(10, 2));
+        let mut builder =
+            GenericRunBuilder::<Int64Type, _>::new_with_values_builder(values_builder);
+        builder.extend([Some(&1), Some(&1), Some(&2)]);
+        let array = builder.finish();
+
+        assert_eq!(array.run_ends().values(), &[2, 3]);
+        let values = as_primitive_array::<Decimal128Type>(array.values().as_ref());
+        assert_eq!(
+            values,
+            &Decimal128Array::from(vec![1, 2])
+                .with_precision_and_scale(10, 2)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bytes_values_builder() {
+        let mut builder = GenericRunBuilder::<Int32Type, StringBuilder>::new();
+        builder.extend(["a", "a", "", "", "b"].into_iter().map(Some));
+        builder.append_null();
+        let array = builder.finish();
+
+        // Produces the same runs as `StringRunBuilder`
+        let mut expected = StringRunBuilder::<Int32Type>::new();
+        expected.extend([Some("a"), Some("a"), Some(""), Some(""), Some("b"), None]);
+        let expected = expected.finish();
+
+        assert_eq!(array.run_ends(), expected.run_ends());
+        assert_eq!(
+            as_string_array(array.values().as_ref()),
+            &StringArray::from(vec![Some("a"), Some(""), Some("b"), None])
+        );
+    }
+
+    #[test]
+    fn test_finish_cloned() {
+        let mut builder = GenericRunBuilder::<Int16Type, Int32Builder>::new();
+        builder.extend([Some(&1), Some(&1), None]);
+        let array = builder.finish_cloned();
+        assert_eq!(array.run_ends().values(), &[2, 3]);
+        let values = as_primitive_array::<Int32Type>(array.values().as_ref());
+        assert_eq!(values, &Int32Array::from(vec![Some(1), None]));
+
+        // Appending the last value again extends the existing run
+        builder.extend([None, Some(&2)]);
+        let array = builder.finish();
+        assert_eq!(array.run_ends().values(), &[2, 4, 5]);
+        let values = as_primitive_array::<Int32Type>(array.values().as_ref());
+        assert_eq!(values, &Int32Array::from(vec![Some(1), None, Some(2)]));
+
+        let array = builder.finish_cloned();
+        assert_eq!(array.len(), 0);
+        assert_eq!(array.run_ends().len(), 0);
+    }
+}
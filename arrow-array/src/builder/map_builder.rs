@@ -22,6 +22,7 @@ use arrow_buffer::Buffer;
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType, Field};
 use std::any::Any;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 /// Creates a new `MapBuilder`
@@ -60,6 +61,7 @@ pub struct MapBuilder<K: ArrayBuilder, V: ArrayBuilder> {
     field_names: MapFieldNames,
     key_builder: K,
     value_builder: V,
+    keys_sorted: bool,
 }
 
 /// Contains details of the mapping
@@ -109,9 +111,20 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             field_names: field_names.unwrap_or_default(),
             key_builder,
             value_builder,
+            keys_sorted: false,
         }
     }
 
+    /// Sets whether the keys of each map are sorted, which is recorded in the
+    /// [`DataType::Map`] of the built [`MapArray`]
+    ///
+    /// If `true`, [`Self::append_map`] returns an error for maps whose keys are not
+    /// strictly increasing. Keys appended directly via [`Self::keys`] are not checked
+    pub fn with_keys_sorted(mut self, keys_sorted: bool) -> Self {
+        self.keys_sorted = keys_sorted;
+        self
+    }
+
     /// Returns the key array builder of the map
     pub fn keys(&mut self) -> &mut K {
         &mut self.key_builder
@@ -139,6 +152,55 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
         Ok(())
     }
 
+    /// Appends a valid map containing the `(key, value)` pairs yielded by `entries`
+    ///
+    /// Returns an error if the builder was created [with sorted keys](Self::with_keys_sorted)
+    /// and the keys are not strictly increasing, in which case nothing is appended
+    ///
+    /// ```
+    /// # use arrow_array::builder::{Int32Builder, MapBuilder, StringBuilder};
+    /// # use arrow_array::{Int32Array, StringArray};
+    /// let mut builder = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new())
+    ///     .with_keys_sorted(true);
+    ///
+    /// // Construct `[{"a": 1, "b": null}, {}]`
+    /// builder.append_map([("a", Some(1)), ("b", None)]).unwrap();
+    /// builder.append_map(std::iter::empty::<(&str, Option<i32>)>()).unwrap();
+    /// assert!(builder.append_map([("b", Some(1)), ("a", Some(2))]).is_err());
+    ///
+    /// let array = builder.finish();
+    /// assert_eq!(array.value_offsets(), &[0, 2, 2]);
+    /// assert_eq!(array.keys().as_ref(), &StringArray::from(vec!["a", "b"]));
+    /// assert_eq!(array.values().as_ref(), &Int32Array::from(vec![Some(1), None]));
+    /// ```
+    pub fn append_map<I, KE, VE>(&mut self, entries: I) -> Result<(), ArrowError>
+    where
+        I: IntoIterator<Item = (KE, VE)>,
+        K: Extend<Option<KE>>,
+        V: Extend<VE>,
+        KE: PartialOrd,
+    {
+        // Entries are collected so that nothing is appended if the keys are unsorted
+        let entries: Vec<_> = entries.into_iter().collect();
+        if self.keys_sorted {
+            if let Some(idx) = entries
+                .windows(2)
+                .position(|w| w[0].0.partial_cmp(&w[1].0) != Some(Ordering::Less))
+            {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Map keys must be sorted, found key at index {} not less than key at index {}",
+                    idx,
+                    idx + 1
+                )));
+            }
+        }
+        for (key, value) in entries {
+            self.key_builder.extend(std::iter::once(Some(key)));
+            self.value_builder.extend(std::iter::once(value));
+        }
+        self.append(true)
+    }
+
     /// Builds the [`MapArray`]
     pub fn finish(&mut self) -> MapArray {
         let len = self.len();
@@ -200,7 +262,7 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             struct_array.data_type().clone(),
             false, // always non-nullable
         ));
-        let array_data = ArrayData::builder(DataType::Map(map_field, false)) // TODO: support sorted keys
+        let array_data = ArrayData::builder(DataType::Map(map_field, self.keys_sorted))
             .len(len)
             .add_buffer(offset_buffer)
             .add_child_data(struct_array.into_data())
@@ -246,6 +308,7 @@ impl<K: ArrayBuilder, V: ArrayBuilder> ArrayBuilder for MapBuilder<K, V> {
 #[cfg(test)]
 mod tests {
     use crate::builder::{Int32Builder, StringBuilder};
+    use crate::{Int32Array, StringArray};
 
     use super::*;
 
@@ -262,4 +325,61 @@ mod tests {
 
         builder.finish();
     }
+
+    #[test]
+    fn test_map_builder_append_map() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.append_map([("b", Some(1)), ("a", None)]).unwrap();
+        builder.append(false).unwrap();
+        builder.append_map([("c", Some(3))]).unwrap();
+
+        let array = builder.finish();
+        assert_eq!(
+            array.data_type(),
+            &DataType::Map(
+                Box::new(Field::new(
+                    "entries",
+                    DataType::Struct(vec![
+                        Field::new("keys", DataType::Utf8, false),
+                        Field::new("values", DataType::Int32, true),
+                    ]),
+                    false,
+                )),
+                false,
+            )
+        );
+        assert_eq!(array.value_offsets(), &[0, 2, 2, 3]);
+        assert!(array.is_null(1));
+        assert_eq!(
+            array.keys().as_ref(),
+            &StringArray::from(vec!["b", "a", "c"])
+        );
+        assert_eq!(
+            array.values().as_ref(),
+            &Int32Array::from(vec![Some(1), None, Some(3)])
+        );
+    }
+
+    #[test]
+    fn test_map_builder_keys_sorted() {
+        let mut builder = MapBuilder::new(None, Int32Builder::new(), Int32Builder::new())
+            .with_keys_sorted(true);
+        builder.append_map([(1, Some(10)), (2, Some(20))]).unwrap();
+
+        let err = builder
+            .append_map([(3, Some(30)), (3, Some(31))])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Map keys must be sorted, found key at index 0 not less than key at index 1"
+        );
+        builder.append_map([(5, None)]).unwrap();
+
+        let array = builder.finish();
+        assert!(matches!(array.data_type(), DataType::Map(_, true)));
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.value_offsets(), &[0, 2, 3]);
+        assert_eq!(array.keys().as_ref(), &Int32Array::from(vec![1, 2, 5]));
+    }
 }
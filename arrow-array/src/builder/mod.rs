@@ -47,10 +47,13 @@ mod generic_bytes_dictionary_builder;
 pub use generic_bytes_dictionary_builder::*;
 mod generic_byte_run_builder;
 pub use generic_byte_run_builder::*;
+mod generic_run_builder;
+pub use generic_run_builder::*;
 mod union_builder;
 pub use union_builder::*;
 
-use crate::ArrayRef;
+use crate::{Array, ArrayRef};
+use arrow_schema::ArrowError;
 use std::any::Any;
 
 /// Trait for dealing with different array builders at runtime
@@ -132,6 +135,48 @@ pub trait ArrayBuilder: Any + Send {
     fn into_box_any(self: Box<Self>) -> Box<dyn Any>;
 }
 
+/// Trait for [`ArrayBuilder`]s that can append the contents of an existing [`Array`]
+/// in bulk, which is considerably faster than appending its elements one at a time
+///
+/// ```
+/// # use arrow_array::{Array, Int32Array, ListArray, types::Int32Type};
+/// # use arrow_array::builder::{ExtendFromArray, Int32Builder, ListBuilder};
+/// let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2)]),
+///     None,
+///     Some(vec![Some(3)]),
+/// ]);
+///
+/// let mut builder = ListBuilder::new(Int32Builder::new());
+/// builder.extend_from_array(&list.slice(1, 2)).unwrap();
+/// builder.extend_from_array(&list).unwrap();
+/// assert_eq!(builder.finish().len(), 5);
+///
+/// // The array must be of the type produced by the builder
+/// let err = Int32Builder::new().extend_from_array(&list).unwrap_err();
+/// assert!(err.to_string().contains("Cannot extend PrimitiveBuilder"));
+/// ```
+pub trait ExtendFromArray: ArrayBuilder {
+    /// Appends all elements of `array`, including nulls, into this builder
+    ///
+    /// Returns an error if `array` is not of the type produced by this builder
+    fn extend_from_array(&mut self, array: &dyn Array) -> Result<(), ArrowError>;
+}
+
+/// Downcasts `array` to the array type `A` appended by the builder named `builder`
+fn downcast_extend<'a, A: Array + 'static>(
+    array: &'a dyn Array,
+    builder: &str,
+) -> Result<&'a A, ArrowError> {
+    array.as_any().downcast_ref().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Cannot extend {} from array of type {}",
+            builder,
+            array.data_type()
+        ))
+    })
+}
+
 /// A list array builder with i32 offsets
 pub type ListBuilder<T> = GenericListBuilder<i32, T>;
 /// A list array builder with i64 offsets
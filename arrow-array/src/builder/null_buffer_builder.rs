@@ -17,6 +17,7 @@
 
 use crate::builder::BooleanBufferBuilder;
 use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_data::ArrayData;
 
 /// Builder for creating the null bit buffer.
 /// This builder only materializes the buffer when we append `false`.
@@ -126,6 +127,20 @@ impl NullBufferBuilder {
         }
     }
 
+    /// Appends the validity of the elements of `data` into the builder,
+    /// taking into account the offset of `data`
+    pub fn append_validity(&mut self, data: &ArrayData) {
+        match data.null_buffer() {
+            Some(nulls) if data.null_count() > 0 => {
+                self.materialize_if_needed();
+                let range = data.offset()..data.offset() + data.len();
+                let builder = self.bitmap_builder.as_mut().unwrap();
+                builder.append_packed_range(range, nulls.as_slice())
+            }
+            _ => self.append_n_non_nulls(data.len()),
+        }
+    }
+
     /// Builds the null buffer and resets the builder.
     /// Returns `None` if the builder only contains `true`s.
     pub fn finish(&mut self) -> Option<Buffer> {
@@ -16,12 +16,12 @@
 // under the License.
 
 use crate::builder::null_buffer_builder::NullBufferBuilder;
-use crate::builder::{ArrayBuilder, BufferBuilder};
+use crate::builder::{downcast_extend, ArrayBuilder, BufferBuilder, ExtendFromArray};
 use crate::types::*;
-use crate::{ArrayRef, ArrowPrimitiveType, PrimitiveArray};
+use crate::{Array, ArrayRef, ArrowPrimitiveType, PrimitiveArray};
 use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_data::ArrayData;
-use arrow_schema::DataType;
+use arrow_schema::{ArrowError, DataType};
 use std::any::Any;
 use std::sync::Arc;
 
@@ -253,6 +253,13 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
         self.values_builder.append_slice(values);
     }
 
+    /// Appends the values and nulls of `array` into the builder
+    #[inline]
+    pub fn append_array(&mut self, array: &PrimitiveArray<T>) {
+        self.null_buffer_builder.append_validity(array.data());
+        self.values_builder.append_slice(array.values());
+    }
+
     /// Appends values from a trusted length iterator.
     ///
     /// # Safety
@@ -341,6 +348,13 @@ impl<P: ArrowPrimitiveType> Extend<Option<P::Native>> for PrimitiveBuilder<P> {
     }
 }
 
+impl<T: ArrowPrimitiveType> ExtendFromArray for PrimitiveBuilder<T> {
+    fn extend_from_array(&mut self, array: &dyn Array) -> Result<(), ArrowError> {
+        self.append_array(downcast_extend(array, "PrimitiveBuilder")?);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -351,6 +365,7 @@ mod tests {
     use crate::array::BooleanArray;
     use crate::array::Date32Array;
     use crate::array::Int32Array;
+    use crate::array::Int64Array;
     use crate::array::TimestampSecondArray;
     use crate::builder::Int32Builder;
 
@@ -600,4 +615,38 @@ mod tests {
         let array = builder.finish();
         assert_eq!(array.values(), &[1, 2, 3, 5, 2, 4, 4, 2, 4, 6, 2]);
     }
+
+    #[test]
+    fn test_append_array() {
+        let array = Int32Array::from(vec![Some(1), None, Some(3), Some(4), None]);
+
+        let mut builder = Int32Builder::new();
+        builder.append_value(0);
+        builder.append_array(&Int32Array::from(vec![5, 6]));
+        builder.append_array(array.slice(1, 3).as_any().downcast_ref().unwrap());
+        builder.extend_from_array(&array).unwrap();
+
+        let expected = Int32Array::from(vec![
+            Some(0),
+            Some(5),
+            Some(6),
+            None,
+            Some(3),
+            Some(4),
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            None,
+        ]);
+        assert_eq!(builder.finish(), expected);
+
+        let err = builder
+            .extend_from_array(&Int64Array::from(vec![1]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot extend PrimitiveBuilder from array of type Int64"
+        );
+    }
 }
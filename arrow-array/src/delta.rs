@@ -23,7 +23,7 @@
 // Copied from chronoutil crate
 
 //! Contains utility functions for shifting Date objects.
-use chrono::{Datelike, Months};
+use chrono::{DateTime, Datelike, Duration, LocalResult, Months, Offset, TimeZone};
 use std::cmp::Ordering;
 
 /// Shift a date by the given number of months.
@@ -42,12 +42,60 @@ pub(crate) fn shift_months<
     }
 }
 
+/// Shift a date back by the given number of months.
+pub(crate) fn sub_months<
+    D: Datelike
+        + std::ops::Add<chrono::Months, Output = D>
+        + std::ops::Sub<chrono::Months, Output = D>,
+>(
+    date: D,
+    months: i32,
+) -> D {
+    match months.cmp(&0) {
+        Ordering::Equal => date,
+        Ordering::Greater => date - Months::new(months as u32),
+        Ordering::Less => date + Months::new(months.unsigned_abs()),
+    }
+}
+
+/// Shift a [`DateTime`] by the given number of months and days in its local time,
+/// returning `None` on overflow.
+///
+/// A local time that falls in a daylight saving gap is resolved using the offset of
+/// `datetime`, and an ambiguous local time resolves to the earliest instant
+pub(crate) fn shift_local<Tz: TimeZone>(
+    datetime: DateTime<Tz>,
+    months: i64,
+    days: i64,
+) -> Option<DateTime<Tz>> {
+    let local = datetime.naive_local();
+    let abs_months = Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+    let local = match months.cmp(&0) {
+        Ordering::Equal => local,
+        Ordering::Greater => local.checked_add_months(abs_months)?,
+        Ordering::Less => local.checked_sub_months(abs_months)?,
+    };
+    let local = local.checked_add_signed(Duration::days(days))?;
+
+    let tz = datetime.timezone();
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(d) => Some(d),
+        LocalResult::Ambiguous(earliest, _) => Some(earliest),
+        LocalResult::None => {
+            let offset = datetime.offset().fix().local_minus_utc();
+            let utc = local.checked_sub_signed(Duration::seconds(offset as i64))?;
+            Some(tz.from_utc_datetime(&utc))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use chrono::naive::{NaiveDate, NaiveDateTime, NaiveTime};
 
     use super::*;
+    use crate::timezone::Tz;
 
     #[test]
     fn test_shift_months() {
@@ -236,4 +284,64 @@ mod tests {
         assert_eq!(shift_months(base, 1).time(), o_clock);
         assert_eq!(shift_months(base, 2).time(), o_clock);
     }
+
+    #[test]
+    fn test_shift_local() {
+        let tz: Tz = "+05:00".parse().unwrap();
+        let base = NaiveDate::from_ymd_opt(2020, 1, 31)
+            .unwrap()
+            .and_hms_opt(3, 0, 0)
+            .unwrap();
+        let base = tz.from_local_datetime(&base).unwrap();
+
+        let res = shift_local(base, 1, 0).unwrap();
+        assert_eq!(res.naive_local().to_string(), "2020-02-29 03:00:00");
+        let res = shift_local(base, -2, 3).unwrap();
+        assert_eq!(res.naive_local().to_string(), "2019-12-03 03:00:00");
+        assert!(shift_local(base, i32::MAX as i64, 0).is_none());
+        assert!(shift_local(base, i64::MIN, 0).is_none());
+    }
+
+    #[test]
+    fn test_sub_months() {
+        let base = NaiveDate::from_ymd_opt(2020, 3, 31).unwrap();
+        assert_eq!(sub_months(base, 0), base);
+        assert_eq!(
+            sub_months(base, 1),
+            NaiveDate::from_ymd_opt(2020, 2, 29).unwrap()
+        );
+        assert_eq!(
+            sub_months(base, -13),
+            NaiveDate::from_ymd_opt(2021, 4, 30).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-tz")]
+    fn test_shift_local_dst() {
+        let tz: Tz = "America/New_York".parse().unwrap();
+        let base = NaiveDate::from_ymd_opt(2023, 3, 11)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+        let base = tz.from_local_datetime(&base).unwrap();
+
+        // The wall clock time is preserved across the transition
+        let res = shift_local(base, 0, 2).unwrap();
+        assert_eq!(res.to_rfc3339(), "2023-03-13T02:30:00-04:00");
+
+        // 2023-03-12T02:30 does not exist, and is resolved with the prior offset
+        let res = shift_local(base, 0, 1).unwrap();
+        assert_eq!(res.to_rfc3339(), "2023-03-12T03:30:00-04:00");
+
+        // 2023-11-05T01:30 is ambiguous, and resolves to the earliest instant
+        let base = tz.from_utc_datetime(
+            &NaiveDate::from_ymd_opt(2023, 10, 5)
+                .unwrap()
+                .and_hms_opt(5, 30, 0)
+                .unwrap(),
+        );
+        let res = shift_local(base, 1, 0).unwrap();
+        assert_eq!(res.to_rfc3339(), "2023-11-05T01:30:00-04:00");
+    }
 }
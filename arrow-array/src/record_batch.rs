@@ -18,7 +18,8 @@
 //! A two-dimensional batch of column-oriented data with a defined
 //! [schema](arrow_schema::Schema).
 
-use crate::{new_empty_array, Array, ArrayRef, StructArray};
+use crate::builder::BooleanBufferBuilder;
+use crate::{make_array, new_empty_array, Array, ArrayRef, StructArray};
 use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
 use std::ops::Index;
 use std::sync::Arc;
@@ -408,6 +409,213 @@ impl RecordBatch {
             .map(|array| array.get_array_memory_size())
             .sum()
     }
+
+    /// Flattens nested [`DataType::Struct`] columns into top-level columns, named by joining
+    /// the field names with `separator`.
+    ///
+    /// `max_level` limits the number of struct levels that are flattened, `None` or `Some(0)`
+    /// flattens all levels. Nulls in a struct column are propagated to its flattened children,
+    /// other than children of a type without a null buffer, such as [`DataType::Union`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray, StructArray};
+    /// # use arrow_schema::{DataType, Field};
+    /// let animals: ArrayRef = Arc::new(StringArray::from(vec!["Parrot", "Dog"]));
+    /// let legs: ArrayRef = Arc::new(Int32Array::from(vec![2, 4]));
+    /// let animal = StructArray::from(vec![
+    ///     (Field::new("name", DataType::Utf8, true), animals),
+    ///     (Field::new("legs", DataType::Int32, true), legs),
+    /// ]);
+    /// let batch = RecordBatch::try_from_iter([("animal", Arc::new(animal) as ArrayRef)]).unwrap();
+    ///
+    /// let normalized = batch.normalize(".", None).unwrap();
+    /// let names: Vec<_> = normalized.schema().fields().iter().map(|f| f.name().clone()).collect();
+    /// assert_eq!(names, vec!["animal.name", "animal.legs"]);
+    ///
+    /// let nested = normalized.denormalize(".").unwrap();
+    /// assert_eq!(nested.column(0), batch.column(0));
+    /// ```
+    pub fn normalize(
+        &self,
+        separator: &str,
+        max_level: Option<usize>,
+    ) -> Result<Self, ArrowError> {
+        let max_level = match max_level {
+            None | Some(0) => usize::MAX,
+            Some(level) => level,
+        };
+
+        let mut fields = Vec::with_capacity(self.columns.len());
+        let mut columns = Vec::with_capacity(self.columns.len());
+        for (field, column) in self.schema.fields().iter().zip(&self.columns) {
+            flatten_column(
+                field.name().clone(),
+                field,
+                column.clone(),
+                separator,
+                max_level,
+                &mut fields,
+                &mut columns,
+            )?;
+        }
+
+        let schema = Schema::new(fields).with_metadata(self.schema.metadata().clone());
+        RecordBatch::try_new_with_options(
+            Arc::new(schema),
+            columns,
+            &RecordBatchOptions::new().with_row_count(Some(self.row_count)),
+        )
+    }
+
+    /// The inverse of [`RecordBatch::normalize`], nests columns whose names contain
+    /// `separator` into [`DataType::Struct`] columns.
+    ///
+    /// Columns sharing a prefix are grouped into the same struct, in the order the prefix
+    /// first appears. Returns an error if a column name is also the prefix of another column.
+    pub fn denormalize(&self, separator: &str) -> Result<Self, ArrowError> {
+        let entries = self
+            .schema
+            .fields()
+            .iter()
+            .zip(&self.columns)
+            .map(|(field, column)| {
+                let path = field.name().split(separator).collect();
+                (path, field, column)
+            })
+            .collect();
+
+        let (fields, columns) = nest_columns(entries, separator)?.into_iter().unzip();
+        let schema = Schema::new(fields).with_metadata(self.schema.metadata().clone());
+        RecordBatch::try_new_with_options(
+            Arc::new(schema),
+            columns,
+            &RecordBatchOptions::new().with_row_count(Some(self.row_count)),
+        )
+    }
+}
+
+/// Appends `column` to `fields` and `columns`, recursively flattening struct columns
+/// up to `level` levels deep
+fn flatten_column(
+    name: String,
+    field: &Field,
+    column: ArrayRef,
+    separator: &str,
+    level: usize,
+    fields: &mut Vec<Field>,
+    columns: &mut Vec<ArrayRef>,
+) -> Result<(), ArrowError> {
+    match field.data_type() {
+        DataType::Struct(children) if level > 0 => {
+            let struct_array = column.as_any().downcast_ref::<StructArray>().unwrap();
+            for (child_field, child) in children.iter().zip(struct_array.columns()) {
+                let child = with_parent_nulls(struct_array, child.clone())?;
+                let child_field = child_field
+                    .clone()
+                    .with_nullable(field.is_nullable() || child_field.is_nullable());
+                flatten_column(
+                    format!("{name}{separator}{}", child_field.name()),
+                    &child_field,
+                    child,
+                    separator,
+                    level - 1,
+                    fields,
+                    columns,
+                )?;
+            }
+        }
+        _ => {
+            let field = Field::new(name, field.data_type().clone(), field.is_nullable())
+                .with_metadata(field.metadata().clone());
+            fields.push(field);
+            columns.push(column);
+        }
+    }
+    Ok(())
+}
+
+/// Returns `child` with the nulls of `parent` combined into its null buffer
+///
+/// Types without a null buffer, such as [`DataType::Null`] and [`DataType::Union`],
+/// are returned unchanged
+fn with_parent_nulls(
+    parent: &StructArray,
+    child: ArrayRef,
+) -> Result<ArrayRef, ArrowError> {
+    if parent.null_count() == 0
+        || matches!(
+            child.data_type(),
+            DataType::Null | DataType::Union(_, _, _) | DataType::RunEndEncoded(_, _)
+        )
+    {
+        return Ok(child);
+    }
+
+    let offset = child.offset();
+    let mut nulls = BooleanBufferBuilder::new(offset + child.len());
+    nulls.append_n(offset, false);
+    let mut null_count = 0;
+    for i in 0..child.len() {
+        let valid = parent.is_valid(i) && child.is_valid(i);
+        null_count += !valid as usize;
+        nulls.append(valid);
+    }
+
+    let data = child
+        .data()
+        .clone()
+        .into_builder()
+        .null_count(null_count)
+        .null_bit_buffer(Some(nulls.finish()))
+        .build()?;
+    Ok(make_array(data))
+}
+
+/// A column to nest, with the remaining segments of its name
+type NestEntry<'a> = (Vec<&'a str>, &'a Field, &'a ArrayRef);
+
+/// Groups `entries` by the first segment of their path, recursively nesting groups
+/// with more than one segment into struct columns
+fn nest_columns<'a>(
+    entries: Vec<NestEntry<'a>>,
+    separator: &str,
+) -> Result<Vec<(Field, ArrayRef)>, ArrowError> {
+    let mut groups: Vec<(&str, Vec<NestEntry<'a>>)> = vec![];
+    for (path, field, column) in entries {
+        let (head, tail) = path.split_first().unwrap();
+        match groups.iter_mut().find(|(name, _)| name == head) {
+            Some((_, group)) => group.push((tail.to_vec(), field, column)),
+            None => groups.push((head, vec![(tail.to_vec(), field, column)])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(name, group)| {
+            if let [(path, field, column)] = group.as_slice() {
+                if path.is_empty() {
+                    let field = Field::new(name, field.data_type().clone(), field.is_nullable())
+                        .with_metadata(field.metadata().clone());
+                    return Ok((field, (*column).clone()));
+                }
+            }
+            if let Some((_, field, _)) = group.iter().find(|(path, _, _)| path.is_empty()) {
+                return Err(ArrowError::SchemaError(format!(
+                    "Cannot denormalize column '{}' which is also a prefix of other columns using separator '{separator}'",
+                    field.name()
+                )));
+            }
+
+            let children = nest_columns(group, separator)?;
+            let nullable = children.iter().any(|(field, _)| field.is_nullable());
+            let struct_array = StructArray::from(children);
+            let field = Field::new(name, struct_array.data_type().clone(), nullable);
+            Ok((field, Arc::new(struct_array) as ArrayRef))
+        })
+        .collect()
 }
 
 /// Options that control the behaviour used when creating a [`RecordBatch`].
@@ -495,7 +703,8 @@ impl Index<&str> for RecordBatch {
 mod tests {
     use super::*;
     use crate::{
-        BooleanArray, Int32Array, Int64Array, Int8Array, ListArray, StringArray,
+        BooleanArray, Int32Array, Int64Array, Int8Array, ListArray, NullArray,
+        StringArray, UnionArray,
     };
     use arrow_buffer::{Buffer, ToByteSlice};
     use arrow_data::ArrayDataBuilder;
@@ -982,6 +1191,178 @@ mod tests {
         );
         assert_eq!("Invalid argument error: Column 'a' is declared as non-nullable but contains null values", format!("{}", maybe_batch.err().unwrap()));
     }
+    #[test]
+    fn test_normalize() {
+        let inner = StructArray::from(vec![(
+            Field::new("c", DataType::Int32, false),
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )]);
+        let outer_fields = [
+            Field::new("b", DataType::Utf8, true),
+            Field::new("inner", inner.data_type().clone(), false),
+        ];
+        let outer = StructArray::from((
+            vec![
+                (
+                    outer_fields[0].clone(),
+                    Arc::new(StringArray::from(vec![Some("x"), None, Some("z")]))
+                        as ArrayRef,
+                ),
+                (outer_fields[1].clone(), Arc::new(inner) as ArrayRef),
+            ],
+            Buffer::from([0b00000101]),
+        ));
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("outer", outer.data_type().clone(), true),
+        ])
+        .with_metadata([("k".to_string(), "v".to_string())].into());
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int64Array::from(vec![10, 20, 30])),
+                Arc::new(outer),
+            ],
+        )
+        .unwrap();
+
+        let normalized = batch.normalize(".", None).unwrap();
+        let schema = normalized.schema();
+        assert_eq!(schema.metadata().get("k").unwrap(), "v");
+        let names: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, vec!["a", "outer.b", "outer.inner.c"]);
+        assert!(!schema.field(0).is_nullable());
+        assert!(schema.field(2).is_nullable());
+
+        // Nulls in the struct are propagated to the flattened columns
+        let c = normalized.column(2);
+        assert_eq!(
+            c.as_ref(),
+            &Int32Array::from(vec![Some(1), None, Some(3)]) as &dyn Array
+        );
+        let b = normalized.column(1);
+        assert_eq!(
+            b.as_ref(),
+            &StringArray::from(vec![Some("x"), None, Some("z")]) as &dyn Array
+        );
+
+        let normalized = batch.normalize("_", Some(1)).unwrap();
+        let names: Vec<_> = normalized
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        assert_eq!(names, vec!["a", "outer_b", "outer_inner"]);
+
+        // Sliced struct columns are flattened with the correct nulls
+        let normalized = batch.slice(1, 2).normalize(".", None).unwrap();
+        assert_eq!(normalized.num_rows(), 2);
+        assert_eq!(
+            normalized.column(2).as_ref(),
+            &Int32Array::from(vec![None, Some(3)]) as &dyn Array
+        );
+
+        // Children without a null buffer are returned unchanged
+        let null = Arc::new(NullArray::new(2)) as ArrayRef;
+        let union = UnionArray::try_new(
+            &[0],
+            Buffer::from_slice_ref([0_i8, 0]),
+            None,
+            vec![(
+                Field::new("i", DataType::Int32, false),
+                Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+            )],
+        )
+        .unwrap();
+        let union = Arc::new(union) as ArrayRef;
+        let outer = StructArray::from((
+            vec![
+                (Field::new("n", DataType::Null, true), null.clone()),
+                (
+                    Field::new("u", union.data_type().clone(), false),
+                    union.clone(),
+                ),
+            ],
+            Buffer::from([0b00000001]),
+        ));
+        let batch = RecordBatch::try_from_iter([("s", Arc::new(outer) as ArrayRef)])
+            .unwrap()
+            .normalize(".", None)
+            .unwrap();
+        assert_eq!(batch.column(0), &null);
+        assert_eq!(batch.column(1), &union);
+    }
+
+    #[test]
+    fn test_denormalize() {
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+            (
+                "b.c",
+                Arc::new(StringArray::from(vec!["x", "y"])) as ArrayRef,
+            ),
+            ("d", Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef),
+            ("b.e.f", Arc::new(Int64Array::from(vec![5, 6])) as ArrayRef),
+        ])
+        .unwrap();
+
+        let nested = batch.denormalize(".").unwrap();
+        let names: Vec<_> = nested
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        assert_eq!(names, vec!["a", "b", "d"]);
+
+        let b = nested
+            .column(1)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(b.num_columns(), 2);
+        assert_eq!(b.column_names(), vec!["c", "e"]);
+        assert!(!nested.schema().field(1).is_nullable());
+
+        let round_trip = nested.normalize(".", None).unwrap();
+        let names: Vec<_> = round_trip
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        assert_eq!(names, vec!["a", "b.c", "b.e.f", "d"]);
+        assert_eq!(round_trip.column(2), batch.column(3));
+
+        let batch = RecordBatch::try_from_iter(vec![
+            ("b", Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef),
+            ("b.c", Arc::new(Int32Array::from(vec![3, 4])) as ArrayRef),
+        ])
+        .unwrap();
+        let err = batch.denormalize(".").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Schema error: Cannot denormalize column 'b' which is also a prefix of other columns using separator '.'"
+        );
+
+        // Structs are nullable if any of their children are
+        let schema = Schema::new(vec![
+            Field::new("b.c", DataType::Int32, true),
+            Field::new("b.d", DataType::Int32, false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None])),
+                Arc::new(Int32Array::from(vec![3, 4])),
+            ],
+        )
+        .unwrap();
+        let nested = batch.denormalize(".").unwrap();
+        assert!(nested.schema().field(0).is_nullable());
+    }
+
     #[test]
     fn test_record_batch_options() {
         let options = RecordBatchOptions::new()
@@ -42,10 +42,16 @@ where
     <&'a V as ArrayAccessor>::Item: Default,
 {
     array: TypedRunArray<'a, R, V>,
+    run_ends: &'a [R::Native],
+    // The logical indices are relative to the start of the unsliced run array
     current_logical: usize,
-    current_physical: usize,
     current_end_logical: usize,
+    // The physical index and logical end of the run at the front of the iterator
+    current_physical: usize,
+    current_run_end: usize,
+    // The physical index and logical start of the run at the back of the iterator
     current_end_physical: usize,
+    current_end_run_start: usize,
 }
 
 impl<'a, R, V> RunArrayIter<'a, R, V>
@@ -57,15 +63,48 @@ where
 {
     /// create a new iterator
     pub fn new(array: TypedRunArray<'a, R, V>) -> Self {
-        let logical_len = array.len();
-        let physical_len: usize = array.values().len();
-        RunArrayIter {
+        let run_ends = array.run_ends().values();
+        let current_logical = array.offset();
+        let current_end_logical = array.offset() + array.len();
+        let mut iter = RunArrayIter {
             array,
-            current_logical: 0,
+            run_ends,
+            current_logical,
+            current_end_logical,
             current_physical: 0,
-            current_end_logical: logical_len,
-            current_end_physical: physical_len,
+            current_run_end: 0,
+            current_end_physical: 0,
+            current_end_run_start: 0,
+        };
+        if !array.is_empty() {
+            let run_array = array.run_array();
+            iter.current_physical = run_array.get_start_physical_index();
+            iter.current_run_end = run_ends[iter.current_physical].as_usize();
+            iter.current_end_physical = run_array.get_end_physical_index();
+            iter.current_end_run_start = iter.run_start(iter.current_end_physical);
+        }
+        iter
+    }
+
+    /// Returns the logical start of the run at `physical`
+    #[inline]
+    fn run_start(&self, physical: usize) -> usize {
+        match physical {
+            0 => 0,
+            _ => self.run_ends[physical - 1].as_usize(),
+        }
+    }
+
+    /// Returns the value of the run at `physical`
+    #[inline]
+    fn physical_value(&self, physical: usize) -> Option<<&'a V as ArrayAccessor>::Item> {
+        if self.array.values().is_null(physical) {
+            return None;
         }
+        // Safety:
+        // The physical index is kept within the runs covering the logical indices
+        // `self.current_logical..self.current_end_logical` of the array.
+        unsafe { Some(self.array.values().value_unchecked(physical)) }
     }
 }
 
@@ -83,35 +122,15 @@ where
         if self.current_logical == self.current_end_logical {
             return None;
         }
-        // If current logical index is greater than current run end index then increment
-        // the physical index.
-        if self.current_logical
-            >= self
-                .array
-                .run_ends()
-                .value(self.current_physical)
-                .as_usize()
-        {
-            // As the run_ends is expected to be strictly increasing, there
-            // should be at least one logical entry in one physical entry. Because of this
-            // reason the next value can be accessed by incrementing physical index once.
+        // If current logical index is past the end of the current run then move to the
+        // next run. As the run_ends is expected to be strictly increasing, there should
+        // be at least one logical entry in one physical entry.
+        if self.current_logical >= self.current_run_end {
             self.current_physical += 1;
+            self.current_run_end = self.run_ends[self.current_physical].as_usize();
         }
-        if self.array.values().is_null(self.current_physical) {
-            self.current_logical += 1;
-            Some(None)
-        } else {
-            self.current_logical += 1;
-            // Safety:
-            // The self.current_physical is kept within bounds of self.current_logical.
-            // The self.current_logical will not go out of bounds because of the check
-            // `self.current_logical = self.current_end_logical` above.
-            unsafe {
-                Some(Some(
-                    self.array.values().value_unchecked(self.current_physical),
-                ))
-            }
-        }
+        self.current_logical += 1;
+        Some(self.physical_value(self.current_physical))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -120,6 +139,22 @@ where
             Some(self.current_end_logical - self.current_logical),
         )
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.current_end_logical - self.current_logical {
+            self.current_logical = self.current_end_logical;
+            return None;
+        }
+        self.current_logical += n;
+        if self.current_logical >= self.current_run_end {
+            // Skip over all the runs ending before the new logical index
+            let logical = self.current_logical;
+            self.current_physical += self.run_ends[self.current_physical..]
+                .partition_point(|run_end| run_end.as_usize() <= logical);
+            self.current_run_end = self.run_ends[self.current_physical].as_usize();
+        }
+        self.next()
+    }
 }
 
 impl<'a, R, V> DoubleEndedIterator for RunArrayIter<'a, R, V>
@@ -136,35 +171,13 @@ where
 
         self.current_end_logical -= 1;
 
-        if self.current_end_physical > 0
-            && self.current_end_logical
-                < self
-                    .array
-                    .run_ends()
-                    .value(self.current_end_physical - 1)
-                    .as_usize()
-        {
-            // As the run_ends is expected to be strictly increasing, there
-            // should be at least one logical entry in one physical entry. Because of this
-            // reason the next value can be accessed by decrementing physical index once.
+        // If current logical index is before the start of the current run then move to
+        // the previous run.
+        if self.current_end_logical < self.current_end_run_start {
             self.current_end_physical -= 1;
+            self.current_end_run_start = self.run_start(self.current_end_physical);
         }
-
-        Some(if self.array.values().is_null(self.current_end_physical) {
-            None
-        } else {
-            // Safety:
-            // The check `self.current_end_physical > 0` ensures the value will not underflow.
-            // Also self.current_end_physical starts with array.len() and
-            // decrements based on the bounds of self.current_end_logical.
-            unsafe {
-                Some(
-                    self.array
-                        .values()
-                        .value_unchecked(self.current_end_physical),
-                )
-            }
-        })
+        Some(self.physical_value(self.current_end_physical))
     }
 }
 
@@ -181,7 +194,7 @@ where
 #[cfg(test)]
 mod tests {
     use crate::{
-        array::{Int32Array, StringArray},
+        array::{Array, Int32Array, StringArray},
         builder::PrimitiveRunBuilder,
         types::Int32Type,
         Int64RunArray,
@@ -270,4 +283,76 @@ mod tests {
 
         assert_eq!(expected_vec, result_asref);
     }
+
+    #[test]
+    fn test_nth() {
+        let input_vec = vec![
+            Some(1),
+            Some(1),
+            None,
+            Some(2),
+            Some(2),
+            Some(2),
+            Some(3),
+            Some(4),
+        ];
+        let mut builder = PrimitiveRunBuilder::<Int32Type, Int32Type>::new();
+        builder.extend(input_vec.clone());
+        let ree_array = builder.finish();
+        let ree_array = ree_array.downcast::<Int32Array>().unwrap();
+
+        for n in 0..input_vec.len() + 1 {
+            let mut iter = ree_array.into_iter();
+            assert_eq!(iter.nth(n), input_vec.get(n).copied());
+            let remaining: Vec<_> = iter.collect();
+            assert_eq!(remaining, input_vec[(n + 1).min(input_vec.len())..]);
+        }
+
+        let mut iter = ree_array.into_iter();
+        assert_eq!(iter.nth(1), Some(Some(1)));
+        assert_eq!(iter.nth(4), Some(Some(3)));
+        assert_eq!(iter.next_back(), Some(Some(4)));
+        assert_eq!(iter.nth(1), None);
+    }
+
+    #[test]
+    fn test_sliced_iter() {
+        let input_vec = vec!["a", "a", "b", "c", "c", "c", "d", "d"];
+        let ree_array: Int64RunArray = input_vec.clone().into_iter().collect();
+
+        for offset in 0..input_vec.len() {
+            for len in 0..input_vec.len() - offset {
+                let sliced = ree_array.slice(offset, len);
+                let sliced = sliced.as_any().downcast_ref::<Int64RunArray>().unwrap();
+                let typed = sliced.downcast::<StringArray>().unwrap();
+                let expected: Vec<_> = input_vec[offset..offset + len]
+                    .iter()
+                    .copied()
+                    .map(Some)
+                    .collect();
+
+                let output: Vec<_> = typed.into_iter().collect();
+                assert_eq!(output, expected);
+
+                let mut output: Vec<_> = typed.into_iter().rev().collect();
+                output.reverse();
+                assert_eq!(output, expected);
+
+                // Alternate between the front and the back of the iterator
+                let mut iter = typed.into_iter();
+                let mut front = vec![];
+                let mut back = vec![];
+                while let Some(v) = iter.next() {
+                    front.push(v);
+                    match iter.next_back() {
+                        Some(v) => back.push(v),
+                        None => break,
+                    }
+                }
+                back.reverse();
+                front.extend(back);
+                assert_eq!(front, expected);
+            }
+        }
+    }
 }
@@ -0,0 +1,142 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::Array;
+
+/// A possibly [`Scalar`] [`Array`]
+///
+/// This allows optimised binary kernels where one or more arguments are constant
+///
+/// ```
+/// # use arrow_array::*;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_schema::ArrowError;
+/// #
+/// fn eq(l: &dyn Datum, r: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+///     let (l_array, l_scalar) = l.get();
+///     let (r_array, r_scalar) = r.get();
+///
+///     // Check length parameters
+///     if !l_scalar && !r_scalar && l_array.len() != r_array.len() {
+///         return Err(ArrowError::InvalidArgumentError("Different lengths".to_string()));
+///     }
+///
+///     let l = l_array.as_primitive::<Int32Type>();
+///     let r = r_array.as_primitive::<Int32Type>();
+///     let len = if l_scalar { r.len() } else { l.len() };
+///
+///     // Compute result, reading the value of a scalar from its first index
+///     Ok((0..len)
+///         .map(|i| {
+///             let l_idx = if l_scalar { 0 } else { i };
+///             let r_idx = if r_scalar { 0 } else { i };
+///             match l.is_valid(l_idx) && r.is_valid(r_idx) {
+///                 true => Some(l.value(l_idx) == r.value(r_idx)),
+///                 false => None,
+///             }
+///         })
+///         .collect())
+/// }
+///
+/// let array = Int32Array::from(vec![Some(1), None, Some(2)]);
+/// let scalar = Scalar::new(Int32Array::from(vec![2]));
+/// let result = eq(&array, &scalar).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(false), None, Some(true)]));
+/// ```
+pub trait Datum {
+    /// Returns the value for this [`Datum`] and a boolean indicating if the value is scalar
+    fn get(&self) -> (&dyn Array, bool);
+}
+
+impl<T: Array> Datum for T {
+    fn get(&self) -> (&dyn Array, bool) {
+        (self, false)
+    }
+}
+
+impl Datum for dyn Array {
+    fn get(&self) -> (&dyn Array, bool) {
+        (self, false)
+    }
+}
+
+impl Datum for &dyn Array {
+    fn get(&self) -> (&dyn Array, bool) {
+        (*self, false)
+    }
+}
+
+/// A wrapper around a single value [`Array`] indicating kernels should treat it as a scalar value
+///
+/// See [`Datum`] for more information
+#[derive(Debug, Copy, Clone)]
+pub struct Scalar<T: Array>(T);
+
+impl<T: Array> Scalar<T> {
+    /// Create a new [`Scalar`] from an [`Array`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `array.len() != 1`
+    pub fn new(array: T) -> Self {
+        assert_eq!(array.len(), 1, "Scalar must have length 1");
+        Self(array)
+    }
+
+    /// Returns the inner array
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Array> Datum for Scalar<T> {
+    fn get(&self) -> (&dyn Array, bool) {
+        (&self.0, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArrayRef, Int32Array};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_datum() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let (a, scalar) = array.get();
+        assert_eq!(a.len(), 3);
+        assert!(!scalar);
+
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let s = Scalar::new(array.clone());
+        let (a, scalar) = s.get();
+        assert_eq!(a.len(), 1);
+        assert!(scalar);
+
+        let d: &dyn Array = array.as_ref();
+        let (_, scalar) = Datum::get(&d);
+        assert!(!scalar);
+    }
+
+    #[test]
+    #[should_panic(expected = "Scalar must have length 1")]
+    fn test_scalar_len() {
+        Scalar::new(Int32Array::from(vec![1, 2]));
+    }
+}
@@ -18,7 +18,9 @@
 //! Zero-sized types used to parameterize generic array implementations
 
 use crate::array::ArrowPrimitiveType;
-use crate::delta::shift_months;
+use crate::delta::{shift_local, shift_months, sub_months};
+use crate::temporal_conversions::as_datetime_with_timezone;
+use crate::timezone::Tz;
 use crate::OffsetSizeTrait;
 use arrow_buffer::i256;
 use arrow_data::decimal::{validate_decimal256_precision, validate_decimal_precision};
@@ -27,7 +29,7 @@ use arrow_schema::{
     DECIMAL128_MAX_SCALE, DECIMAL256_MAX_PRECISION, DECIMAL256_MAX_SCALE,
     DECIMAL_DEFAULT_SCALE,
 };
-use chrono::{Duration, NaiveDate};
+use chrono::{Duration, NaiveDate, NaiveDateTime};
 use half::f16;
 use std::marker::PhantomData;
 use std::ops::{Add, Sub};
@@ -290,6 +292,23 @@ impl ArrowTemporalType for DurationNanosecondType {}
 pub trait ArrowTimestampType: ArrowTemporalType {
     /// Returns the `TimeUnit` of this timestamp.
     fn get_time_unit() -> TimeUnit;
+
+    /// Creates a timestamp value from a [`NaiveDateTime`] in UTC, truncating any
+    /// precision finer than this `TimeUnit`, and returning `None` on overflow
+    fn make_value(naive: NaiveDateTime) -> Option<i64> {
+        let secs = naive.timestamp();
+        let nanos = naive.timestamp_subsec_nanos() as i64;
+        match Self::get_time_unit() {
+            TimeUnit::Second => Some(secs),
+            TimeUnit::Millisecond => {
+                secs.checked_mul(1_000)?.checked_add(nanos / 1_000_000)
+            }
+            TimeUnit::Microsecond => {
+                secs.checked_mul(1_000_000)?.checked_add(nanos / 1_000)
+            }
+            TimeUnit::Nanosecond => secs.checked_mul(1_000_000_000)?.checked_add(nanos),
+        }
+    }
 }
 
 impl ArrowTimestampType for TimestampSecondType {
@@ -313,6 +332,160 @@ impl ArrowTimestampType for TimestampNanosecondType {
     }
 }
 
+/// Adds `months`, `days` and `nanos` to `timestamp`, shifting the months and days in
+/// the local time of `tz`
+fn add_interval<T: ArrowTimestampType>(
+    timestamp: i64,
+    months: i32,
+    days: i32,
+    nanos: i64,
+    tz: Tz,
+) -> Option<i64> {
+    let res = as_datetime_with_timezone::<T>(timestamp, tz)?;
+    let res = shift_local(res, months as i64, days as i64)?;
+    let res = res.checked_add_signed(Duration::nanoseconds(nanos))?;
+    T::make_value(res.naive_utc())
+}
+
+/// Subtracts `months`, `days` and `nanos` from `timestamp`, shifting the months and
+/// days in the local time of `tz`
+fn sub_interval<T: ArrowTimestampType>(
+    timestamp: i64,
+    months: i32,
+    days: i32,
+    nanos: i64,
+    tz: Tz,
+) -> Option<i64> {
+    let res = as_datetime_with_timezone::<T>(timestamp, tz)?;
+    let res = shift_local(res, -(months as i64), -(days as i64))?;
+    let res = res.checked_sub_signed(Duration::nanoseconds(nanos))?;
+    T::make_value(res.naive_utc())
+}
+
+macro_rules! timestamp_interval_ops {
+    ($t:ty) => {
+        impl $t {
+            /// Adds the given IntervalYearMonthType to an arrow timestamp, returning
+            /// `None` on overflow
+            ///
+            /// The months are added to the local time in `tz`, clamping the day to the
+            /// last day of the resulting month, e.g. `2023-01-31 + 1 month = 2023-02-28`
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to add
+            /// * `tz` - The timezone in which to perform the operation
+            pub fn add_year_months(
+                timestamp: <$t as ArrowPrimitiveType>::Native,
+                delta: <IntervalYearMonthType as ArrowPrimitiveType>::Native,
+                tz: Tz,
+            ) -> Option<<$t as ArrowPrimitiveType>::Native> {
+                let months = IntervalYearMonthType::to_months(delta);
+                add_interval::<$t>(timestamp, months, 0, 0, tz)
+            }
+
+            /// Adds the given IntervalDayTimeType to an arrow timestamp, returning
+            /// `None` on overflow
+            ///
+            /// The days are added to the local time in `tz`, and so preserve the time of
+            /// day across daylight saving transitions
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to add
+            /// * `tz` - The timezone in which to perform the operation
+            pub fn add_day_time(
+                timestamp: <$t as ArrowPrimitiveType>::Native,
+                delta: <IntervalDayTimeType as ArrowPrimitiveType>::Native,
+                tz: Tz,
+            ) -> Option<<$t as ArrowPrimitiveType>::Native> {
+                let (days, ms) = IntervalDayTimeType::to_parts(delta);
+                let nanos = (ms as i64) * 1_000_000;
+                add_interval::<$t>(timestamp, 0, days, nanos, tz)
+            }
+
+            /// Adds the given IntervalMonthDayNanoType to an arrow timestamp, returning
+            /// `None` on overflow
+            ///
+            /// See [`Self::add_year_months`] and [`Self::add_day_time`] for how the
+            /// months and days are added
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to add
+            /// * `tz` - The timezone in which to perform the operation
+            pub fn add_month_day_nano(
+                timestamp: <$t as ArrowPrimitiveType>::Native,
+                delta: <IntervalMonthDayNanoType as ArrowPrimitiveType>::Native,
+                tz: Tz,
+            ) -> Option<<$t as ArrowPrimitiveType>::Native> {
+                let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(delta);
+                add_interval::<$t>(timestamp, months, days, nanos, tz)
+            }
+
+            /// Subtracts the given IntervalYearMonthType from an arrow timestamp,
+            /// returning `None` on overflow
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to subtract
+            /// * `tz` - The timezone in which to perform the operation
+            pub fn subtract_year_months(
+                timestamp: <$t as ArrowPrimitiveType>::Native,
+                delta: <IntervalYearMonthType as ArrowPrimitiveType>::Native,
+                tz: Tz,
+            ) -> Option<<$t as ArrowPrimitiveType>::Native> {
+                let months = IntervalYearMonthType::to_months(delta);
+                sub_interval::<$t>(timestamp, months, 0, 0, tz)
+            }
+
+            /// Subtracts the given IntervalDayTimeType from an arrow timestamp,
+            /// returning `None` on overflow
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to subtract
+            /// * `tz` - The timezone in which to perform the operation
+            pub fn subtract_day_time(
+                timestamp: <$t as ArrowPrimitiveType>::Native,
+                delta: <IntervalDayTimeType as ArrowPrimitiveType>::Native,
+                tz: Tz,
+            ) -> Option<<$t as ArrowPrimitiveType>::Native> {
+                let (days, ms) = IntervalDayTimeType::to_parts(delta);
+                let nanos = (ms as i64) * 1_000_000;
+                sub_interval::<$t>(timestamp, 0, days, nanos, tz)
+            }
+
+            /// Subtracts the given IntervalMonthDayNanoType from an arrow timestamp,
+            /// returning `None` on overflow
+            ///
+            /// # Arguments
+            ///
+            /// * `timestamp` - The timestamp on which to perform the operation
+            /// * `delta` - The interval to subtract
+            /// * `tz` - The timezone in which to perform the operation
+            pub fn subtract_month_day_nano(
+                timestamp: <$t as ArrowPrimitiveType>::Native,
+                delta: <IntervalMonthDayNanoType as ArrowPrimitiveType>::Native,
+                tz: Tz,
+            ) -> Option<<$t as ArrowPrimitiveType>::Native> {
+                let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(delta);
+                sub_interval::<$t>(timestamp, months, days, nanos, tz)
+            }
+        }
+    };
+}
+
+timestamp_interval_ops!(TimestampSecondType);
+timestamp_interval_ops!(TimestampMillisecondType);
+timestamp_interval_ops!(TimestampMicrosecondType);
+timestamp_interval_ops!(TimestampNanosecondType);
+
 impl IntervalYearMonthType {
     /// Creates a IntervalYearMonthType::Native
     ///
@@ -425,6 +598,48 @@ impl IntervalMonthDayNanoType {
         let nanos = i as i64;
         (months, days, nanos)
     }
+
+    /// Formats a IntervalMonthDayNanoType::Native as a human readable string
+    ///
+    /// ```
+    /// # use arrow_array::types::IntervalMonthDayNanoType;
+    /// let v = IntervalMonthDayNanoType::make_value(1, 2, 3_723_000_000_001);
+    /// assert_eq!(
+    ///     IntervalMonthDayNanoType::format_value(v),
+    ///     "0 years 1 mons 2 days 1 hours 2 mins 3.000000001 secs"
+    /// );
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// * `i` - The IntervalMonthDayNanoType to format
+    pub fn format_value(
+        i: <IntervalMonthDayNanoType as ArrowPrimitiveType>::Native,
+    ) -> String {
+        let (months, days, nanos) = Self::to_parts(i);
+
+        let secs = nanos / 1_000_000_000;
+        let mins = secs / 60;
+        let hours = mins / 60;
+
+        let secs = secs - (mins * 60);
+        let mins = mins - (hours * 60);
+
+        let nanoseconds = nanos % 1_000_000_000;
+
+        let secs_sign = if secs < 0 || nanoseconds < 0 { "-" } else { "" };
+
+        format!(
+            "0 years {} mons {} days {} hours {} mins {}{}.{:09} secs",
+            months,
+            days,
+            hours,
+            mins,
+            secs_sign,
+            secs.abs(),
+            nanoseconds.abs(),
+        )
+    }
 }
 
 impl Date32Type {
@@ -498,6 +713,57 @@ impl Date32Type {
         let res = res.add(Duration::nanoseconds(nanos));
         Date32Type::from_naive_date(res)
     }
+
+    /// Subtract the given IntervalYearMonthType from an arrow Date32Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_year_months(
+        date: <Date32Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalYearMonthType as ArrowPrimitiveType>::Native,
+    ) -> <Date32Type as ArrowPrimitiveType>::Native {
+        let prior = Date32Type::to_naive_date(date);
+        let months = IntervalYearMonthType::to_months(delta);
+        let posterior = sub_months(prior, months);
+        Date32Type::from_naive_date(posterior)
+    }
+
+    /// Subtract the given IntervalDayTimeType from an arrow Date32Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_day_time(
+        date: <Date32Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalDayTimeType as ArrowPrimitiveType>::Native,
+    ) -> <Date32Type as ArrowPrimitiveType>::Native {
+        let (days, ms) = IntervalDayTimeType::to_parts(delta);
+        let res = Date32Type::to_naive_date(date);
+        let res = res.sub(Duration::days(days as i64));
+        let res = res.sub(Duration::milliseconds(ms as i64));
+        Date32Type::from_naive_date(res)
+    }
+
+    /// Subtract the given IntervalMonthDayNanoType from an arrow Date32Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_month_day_nano(
+        date: <Date32Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalMonthDayNanoType as ArrowPrimitiveType>::Native,
+    ) -> <Date32Type as ArrowPrimitiveType>::Native {
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(delta);
+        let res = Date32Type::to_naive_date(date);
+        let res = sub_months(res, months);
+        let res = res.sub(Duration::days(days as i64));
+        let res = res.sub(Duration::nanoseconds(nanos));
+        Date32Type::from_naive_date(res)
+    }
 }
 
 impl Date64Type {
@@ -571,6 +837,57 @@ impl Date64Type {
         let res = res.add(Duration::nanoseconds(nanos));
         Date64Type::from_naive_date(res)
     }
+
+    /// Subtract the given IntervalYearMonthType from an arrow Date64Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_year_months(
+        date: <Date64Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalYearMonthType as ArrowPrimitiveType>::Native,
+    ) -> <Date64Type as ArrowPrimitiveType>::Native {
+        let prior = Date64Type::to_naive_date(date);
+        let months = IntervalYearMonthType::to_months(delta);
+        let posterior = sub_months(prior, months);
+        Date64Type::from_naive_date(posterior)
+    }
+
+    /// Subtract the given IntervalDayTimeType from an arrow Date64Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_day_time(
+        date: <Date64Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalDayTimeType as ArrowPrimitiveType>::Native,
+    ) -> <Date64Type as ArrowPrimitiveType>::Native {
+        let (days, ms) = IntervalDayTimeType::to_parts(delta);
+        let res = Date64Type::to_naive_date(date);
+        let res = res.sub(Duration::days(days as i64));
+        let res = res.sub(Duration::milliseconds(ms as i64));
+        Date64Type::from_naive_date(res)
+    }
+
+    /// Subtract the given IntervalMonthDayNanoType from an arrow Date64Type
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date on which to perform the operation
+    /// * `delta` - The interval to subtract
+    pub fn subtract_month_day_nano(
+        date: <Date64Type as ArrowPrimitiveType>::Native,
+        delta: <IntervalMonthDayNanoType as ArrowPrimitiveType>::Native,
+    ) -> <Date64Type as ArrowPrimitiveType>::Native {
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(delta);
+        let res = Date64Type::to_naive_date(date);
+        let res = sub_months(res, months);
+        let res = res.sub(Duration::days(days as i64));
+        let res = res.sub(Duration::nanoseconds(nanos));
+        Date64Type::from_naive_date(res)
+    }
 }
 
 /// Crate private types for Decimal Arrays
@@ -39,12 +39,16 @@ use chrono::{DateTime, NaiveDateTime, NaiveTime, Timelike};
 use std::sync::Arc;
 
 use crate::display::{array_value_to_string, lexical_to_string};
-use crate::parse::string_to_timestamp_nanos;
+use crate::parse::{
+    parse_decimal_native, parse_formatted_datetime, string_to_timestamp_nanos_formatted,
+    DecimalParseError,
+};
 use arrow_array::{
     builder::*, cast::*, iterator::ArrayIter, temporal_conversions::*, timezone::Tz,
     types::*, *,
 };
-use arrow_buffer::{i256, ArrowNativeType, Buffer, MutableBuffer};
+use arrow_buffer::{bit_util, i256, ArrowNativeType, Buffer, MutableBuffer};
+use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::*;
 use arrow_select::take::take;
@@ -52,18 +56,171 @@ use num::cast::AsPrimitive;
 use num::{NumCast, ToPrimitive};
 
 /// CastOptions provides a way to override the default cast behaviors
+///
+/// **Breaking change:** `CastOptions` previously only contained `safe`, and so struct
+/// literals such as `CastOptions { safe: false }` no longer compile. Instead write
+/// `CastOptions { safe: false, ..Default::default() }`, or use the `with_` methods,
+/// e.g. `CastOptions::default().with_safe(false)`
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CastOptions {
     /// how to handle cast failures, either return NULL (safe=true) or return ERR (safe=false)
     pub safe: bool,
+    /// strptime-style formats used when casting strings to timestamp or date types,
+    /// tried in order before falling back to the default parsing
+    ///
+    /// See [`parse_formatted_datetime`] for how the formats are interpreted
+    pub datetime_formats: Vec<String>,
+    /// how the fields of a struct are matched to the fields of the target struct type
+    pub struct_field_matching: StructFieldMatching,
+    /// strings, compared case-insensitively, that are parsed as `true` when casting
+    /// strings to boolean. If empty `t`, `true`, `y`, `yes`, `on`, `1` and their
+    /// prefixes are used
+    pub true_values: Vec<String>,
+    /// strings, compared case-insensitively, that are parsed as `false` when casting
+    /// strings to boolean. If empty `f`, `false`, `n`, `no`, `off`, `0` and their
+    /// prefixes are used
+    pub false_values: Vec<String>,
+    /// how values are rounded when casting floating point values to decimal
+    pub decimal_rounding: DecimalRoundingMode,
+    /// how invalid UTF-8 is handled when casting binary to string types. If `None`
+    /// invalid values are null if `safe` is true, otherwise they return an error
+    pub invalid_utf8: Option<InvalidUtf8Policy>,
+}
+
+pub const DEFAULT_CAST_OPTIONS: CastOptions = CastOptions {
+    safe: true,
+    datetime_formats: vec![],
+    struct_field_matching: StructFieldMatching::Position,
+    true_values: vec![],
+    false_values: vec![],
+    decimal_rounding: DecimalRoundingMode::HalfUp,
+    invalid_utf8: None,
+};
+
+impl Default for CastOptions {
+    fn default() -> Self {
+        DEFAULT_CAST_OPTIONS
+    }
+}
+
+impl CastOptions {
+    /// Sets whether cast failures return NULL (`true`) or an error (`false`)
+    pub fn with_safe(mut self, safe: bool) -> Self {
+        self.safe = safe;
+        self
+    }
+
+    /// Sets the strptime-style formats used when casting strings to timestamp or date types
+    pub fn with_datetime_formats(mut self, datetime_formats: Vec<String>) -> Self {
+        self.datetime_formats = datetime_formats;
+        self
+    }
+
+    /// Sets how the fields of a struct are matched to the fields of the target struct type
+    pub fn with_struct_field_matching(
+        mut self,
+        struct_field_matching: StructFieldMatching,
+    ) -> Self {
+        self.struct_field_matching = struct_field_matching;
+        self
+    }
+
+    /// Sets the strings parsed as `true` when casting strings to boolean
+    pub fn with_true_values(mut self, true_values: Vec<String>) -> Self {
+        self.true_values = true_values;
+        self
+    }
+
+    /// Sets the strings parsed as `false` when casting strings to boolean
+    pub fn with_false_values(mut self, false_values: Vec<String>) -> Self {
+        self.false_values = false_values;
+        self
+    }
+
+    /// Sets how values are rounded when casting floating point values to decimal
+    pub fn with_decimal_rounding(
+        mut self,
+        decimal_rounding: DecimalRoundingMode,
+    ) -> Self {
+        self.decimal_rounding = decimal_rounding;
+        self
+    }
+
+    /// Sets how invalid UTF-8 is handled when casting binary to string types
+    pub fn with_invalid_utf8(mut self, invalid_utf8: Option<InvalidUtf8Policy>) -> Self {
+        self.invalid_utf8 = invalid_utf8;
+        self
+    }
+
+    /// Returns the [`InvalidUtf8Policy`] to use when casting binary to string types
+    fn invalid_utf8(&self) -> InvalidUtf8Policy {
+        match (self.invalid_utf8, self.safe) {
+            (Some(policy), _) => policy,
+            (None, true) => InvalidUtf8Policy::Null,
+            (None, false) => InvalidUtf8Policy::Error,
+        }
+    }
+}
+
+/// Determines how binary values that are not valid UTF-8 are handled when
+/// casting them to string types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidUtf8Policy {
+    /// Return an error identifying the first invalid value
+    Error,
+    /// Invalid values become null
+    Null,
+    /// Invalid sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`
+    Replace,
+}
+
+/// Determines how the fields of a struct are matched to the fields of the
+/// target type when casting between struct types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructFieldMatching {
+    /// Fields are matched by position, the number of fields must be the same
+    Position,
+    /// Fields are matched by name. Nullable target fields not present in the
+    /// source are filled with nulls, source fields not present in the target
+    /// return an error
+    Name,
+    /// As [`StructFieldMatching::Name`], but source fields not present in the
+    /// target are dropped
+    NameDropExtra,
+}
+
+/// Determines how floating point values are rounded to the scale of the
+/// target type when casting them to decimal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalRoundingMode {
+    /// Round half away from zero, e.g. 2.5 becomes 3 and -2.5 becomes -3
+    HalfUp,
+    /// Round half to even, e.g. 2.5 becomes 2 and 3.5 becomes 4
+    HalfEven,
+    /// Round towards zero, e.g. 2.9 becomes 2 and -2.9 becomes -2
+    Truncate,
 }
 
-pub const DEFAULT_CAST_OPTIONS: CastOptions = CastOptions { safe: true };
+impl DecimalRoundingMode {
+    fn round(self, v: f64) -> f64 {
+        match self {
+            Self::HalfUp => v.round(),
+            Self::HalfEven => match (v - v.trunc()).abs() == 0.5 {
+                true => 2.0 * (v / 2.0).round(),
+                false => v.round(),
+            },
+            Self::Truncate => v.trunc(),
+        }
+    }
+}
 
 /// Return true if a value of type `from_type` can be cast into a
 /// value of `to_type`. Note that such as cast may be lossy.
 ///
 /// If this function returns true to stay consistent with the `cast` kernel below.
+///
+/// Casts between struct types are checked for the default [`CastOptions`], that
+/// match the fields by position.
 pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
     use self::DataType::*;
     if from_type == to_type {
@@ -107,6 +264,13 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Dictionary(_, from_value_type), Dictionary(_, to_value_type)) => {
             can_cast_types(from_value_type, to_value_type)
         }
+        (RunEndEncoded(_, from_values), Dictionary(_, to_value_type)) => {
+            can_cast_types(from_values.data_type(), to_value_type)
+        }
+        (Dictionary(_, from_value_type), RunEndEncoded(run_ends, to_values)) => {
+            run_ends.data_type().is_run_ends_type()
+                && can_cast_types(from_value_type, to_values.data_type())
+        }
         (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
         (LargeList(list_from), LargeList(list_to)) => {
@@ -116,10 +280,20 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             can_cast_types(list_from.data_type(), list_to.data_type())
         }
         (List(list_from), LargeList(list_to)) => {
-            list_from.data_type() == list_to.data_type()
+            can_cast_types(list_from.data_type(), list_to.data_type())
         }
         (LargeList(list_from), List(list_to)) => {
-            list_from.data_type() == list_to.data_type()
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
+        (FixedSizeList(list_from, _), List(list_to) | LargeList(list_to)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
+        (List(list_from) | LargeList(list_from), FixedSizeList(list_to, _)) => {
+            can_cast_types(list_from.data_type(), list_to.data_type())
+        }
+        (FixedSizeList(list_from, size_from), FixedSizeList(list_to, size_to)) => {
+            size_from == size_to
+                && can_cast_types(list_from.data_type(), list_to.data_type())
         }
         (List(list_from) | LargeList(list_from), Utf8 | LargeUtf8) => can_cast_types(list_from.data_type(), to_type),
         (List(_), _) => false,
@@ -145,10 +319,22 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         // Utf8 to decimal
         (Utf8 | LargeUtf8, Decimal128(_, _)) => true,
         (Utf8 | LargeUtf8, Decimal256(_, _)) => true,
+        // decimal to Utf8
+        (Decimal128(_, _) | Decimal256(_, _), Utf8 | LargeUtf8) => true,
         (Decimal128(_, _), _) => false,
         (_, Decimal128(_, _)) => false,
         (Decimal256(_, _), _) => false,
         (_, Decimal256(_, _)) => false,
+        (Struct(from_fields), Struct(to_fields)) => {
+            // The default cast matches fields by position
+            from_fields.len() == to_fields.len()
+                && from_fields.iter().zip(to_fields).all(|(from, to)| {
+                    can_cast_types(from.data_type(), to.data_type())
+                })
+        }
+        (Map(from_entries, _), Map(to_entries, _)) => {
+            can_cast_types(from_entries.data_type(), to_entries.data_type())
+        }
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (_, Boolean) => DataType::is_numeric(from_type) || from_type == &Utf8 || from_type == &LargeUtf8,
@@ -190,52 +376,56 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         // start numeric casts
         (
             UInt8,
-            UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
+            UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             UInt16,
-            UInt8 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
+            UInt8 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             UInt32,
-            UInt8 | UInt16 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
+            UInt8 | UInt16 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             UInt64,
-            UInt8 | UInt16 | UInt32 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
+            UInt8 | UInt16 | UInt32 | Int8 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             Int8,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int16 | Int32 | Int64 | Float32 | Float64,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int16 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             Int16,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int32 | Int64 | Float32 | Float64,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int32 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             Int32,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int64 | Float32 | Float64,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int64 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             Int64,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Float32 | Float64,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Float16 | Float32 | Float64,
         ) => true,
 
         (
             Float32,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float64,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float16 | Float64,
         ) => true,
 
         (
             Float64,
-            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float16 | Float32,
+        ) => true,
+        (
+            Float16,
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64,
         ) => true,
         // end numeric casts
 
@@ -270,6 +460,15 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Time64(TimeUnit::Nanosecond)) => true,
         (Int64, Duration(_)) => true,
         (Duration(_), Int64) => true,
+        (Duration(_), Interval(IntervalUnit::MonthDayNano)) => true,
+        (Interval(IntervalUnit::MonthDayNano), Duration(_)) => true,
+        (Duration(_), Interval(IntervalUnit::DayTime)) => true,
+        (Interval(IntervalUnit::DayTime), Duration(_)) => true,
+        (
+            Interval(IntervalUnit::YearMonth | IntervalUnit::DayTime),
+            Interval(IntervalUnit::MonthDayNano),
+        ) => true,
+        (Interval(IntervalUnit::MonthDayNano), Interval(IntervalUnit::DayTime)) => true,
         (Interval(from_type), Int64) => {
             match from_type {
                 IntervalUnit::YearMonth => true,
@@ -306,6 +505,14 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
 /// * List to List: the underlying data type is cast
+/// * List, LargeList and FixedSizeList: the container is converted and the underlying data
+///   type is cast. List values whose length does not match the FixedSizeList size return null
+///   or error
+/// * Struct to Struct: each field is cast to the field at the same position, the number
+///   of fields must match. Fields may instead be matched by name, see [`StructFieldMatching`]
+/// * Map to Map: the keys and values are cast
+/// * Dictionary to Dictionary: the keys and values are cast, if the keys do not fit in
+///   the target key type the dictionary is re-encoded with only the referenced values
 /// * Primitive to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
@@ -313,10 +520,11 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 /// * Temporal to/from backing primitive: zero-copy with data type change
 /// * Casting from `float32/float64` to `Decimal(precision, scale)` rounds to the `scale` decimals
 ///   (i.e. casting 6.4999 to Decimal(10, 1) becomes 6.5). This is the breaking change from `26.0.0`.
-///   It used to truncate it instead of round (i.e. outputs 6.4 instead)
+///   It used to truncate it instead of round (i.e. outputs 6.4 instead). Other rounding modes
+///   may be selected with [`CastOptions::decimal_rounding`]
 ///
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * To or from `StructArray`, other than between structs
 /// * List to primitive
 /// * Interval and duration
 pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef, ArrowError> {
@@ -372,16 +580,21 @@ where
     <T as ArrowPrimitiveType>::Native: AsPrimitive<f64>,
 {
     let mul = 10_f64.powi(scale as i32);
+    let rounding = cast_options.decimal_rounding;
 
     if cast_options.safe {
         array
-            .unary_opt::<_, Decimal128Type>(|v| (mul * v.as_()).round().to_i128())
+            .unary_opt::<_, Decimal128Type>(|v| {
+                rounding.round(mul * v.as_()).to_i128().filter(|v| {
+                    Decimal128Type::validate_decimal_precision(*v, precision).is_ok()
+                })
+            })
             .with_precision_and_scale(precision, scale)
             .map(|a| Arc::new(a) as ArrayRef)
     } else {
         array
             .try_unary::<_, Decimal128Type, _>(|v| {
-                (mul * v.as_()).round().to_i128().ok_or_else(|| {
+                let value = rounding.round(mul * v.as_()).to_i128().ok_or_else(|| {
                     ArrowError::CastError(format!(
                         "Cannot cast to {}({}, {}). Overflowing on {:?}",
                         Decimal128Type::PREFIX,
@@ -389,7 +602,9 @@ where
                         scale,
                         v
                     ))
-                })
+                })?;
+                Decimal128Type::validate_decimal_precision(value, precision)
+                    .map(|_| value)
             })?
             .with_precision_and_scale(precision, scale)
             .map(|a| Arc::new(a) as ArrayRef)
@@ -406,24 +621,32 @@ where
     <T as ArrowPrimitiveType>::Native: AsPrimitive<f64>,
 {
     let mul = 10_f64.powi(scale as i32);
+    let rounding = cast_options.decimal_rounding;
 
     if cast_options.safe {
         array
-            .unary_opt::<_, Decimal256Type>(|v| i256::from_f64((v.as_() * mul).round()))
+            .unary_opt::<_, Decimal256Type>(|v| {
+                i256::from_f64(rounding.round(v.as_() * mul)).filter(|v| {
+                    Decimal256Type::validate_decimal_precision(*v, precision).is_ok()
+                })
+            })
             .with_precision_and_scale(precision, scale)
             .map(|a| Arc::new(a) as ArrayRef)
     } else {
         array
             .try_unary::<_, Decimal256Type, _>(|v| {
-                i256::from_f64((v.as_() * mul).round()).ok_or_else(|| {
-                    ArrowError::CastError(format!(
-                        "Cannot cast to {}({}, {}). Overflowing on {:?}",
-                        Decimal256Type::PREFIX,
-                        precision,
-                        scale,
-                        v
-                    ))
-                })
+                let value =
+                    i256::from_f64(rounding.round(v.as_() * mul)).ok_or_else(|| {
+                        ArrowError::CastError(format!(
+                            "Cannot cast to {}({}, {}). Overflowing on {:?}",
+                            Decimal256Type::PREFIX,
+                            precision,
+                            scale,
+                            v
+                        ))
+                    })?;
+                Decimal256Type::validate_decimal_precision(value, precision)
+                    .map(|_| value)
             })?
             .with_precision_and_scale(precision, scale)
             .map(|a| Arc::new(a) as ArrayRef)
@@ -590,6 +813,14 @@ fn as_time_res_with_timezone<T: ArrowPrimitiveType>(
 ///   in integer casts return null
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
 /// * List to List: the underlying data type is cast
+/// * List, LargeList and FixedSizeList: the container is converted and the underlying data
+///   type is cast. List values whose length does not match the FixedSizeList size return null
+///   or error
+/// * Struct to Struct: each field is cast to the field at the same position, the number
+///   of fields must match. Fields may instead be matched by name, see [`StructFieldMatching`]
+/// * Map to Map: the keys and values are cast
+/// * Dictionary to Dictionary: the keys and values are cast, if the keys do not fit in
+///   the target key type the dictionary is re-encoded with only the referenced values
 /// * Primitive to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
@@ -597,12 +828,75 @@ fn as_time_res_with_timezone<T: ArrowPrimitiveType>(
 /// * Temporal to/from backing primitive: zero-copy with data type change
 ///
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * To or from `StructArray`, other than between structs
 /// * List to primitive
+///
+/// If a value cannot be cast, e.g. because `cast_options.safe` is false, the returned
+/// error reports its row index and value
 pub fn cast_with_options(
     array: &ArrayRef,
     to_type: &DataType,
     cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    cast_internal(array, to_type, cast_options)
+        .map_err(|e| with_row_index(e, array, to_type, cast_options))
+}
+
+/// Returns the error for the first row of `array` that cannot be cast to `to_type`, with
+/// its row index and value added to the message
+///
+/// The row is found with a binary search for the shortest prefix of `array` that fails
+/// to cast. If the failure is not caused by a single row, `error` is returned as is
+fn with_row_index(
+    error: ArrowError,
+    array: &ArrayRef,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> ArrowError {
+    let cast_error = |offset: usize, len: usize| {
+        cast_internal(&array.slice(offset, len), to_type, cast_options).err()
+    };
+    if cast_error(0, 0).is_some() {
+        return error;
+    }
+
+    // Casting the first `valid` rows succeeds, casting the first `invalid` rows fails
+    let (mut valid, mut invalid) = (0, array.len());
+    while invalid - valid > 1 {
+        let mid = valid + (invalid - valid) / 2;
+        match cast_error(0, mid) {
+            Some(_) => invalid = mid,
+            None => valid = mid,
+        }
+    }
+    let row = invalid - 1;
+    let error = match cast_error(row, 1) {
+        Some(error) => error,
+        None => return error,
+    };
+
+    let value = match array_value_to_string(array, row) {
+        Ok(value) => format!("'{value}'"),
+        Err(_) => "value".to_string(),
+    };
+    let message = |message: String| {
+        format!("Cannot cast {value} at row {row} to {to_type}: {message}")
+    };
+    match error {
+        ArrowError::CastError(m) => ArrowError::CastError(message(m)),
+        ArrowError::ComputeError(m) => ArrowError::ComputeError(message(m)),
+        ArrowError::InvalidArgumentError(m) => {
+            ArrowError::InvalidArgumentError(message(m))
+        }
+        ArrowError::ParseError(m) => ArrowError::ParseError(message(m)),
+        error => error,
+    }
+}
+
+fn cast_internal(
+    array: &ArrayRef,
+    to_type: &DataType,
+    cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     use DataType::*;
     let from_type = array.data_type();
@@ -644,6 +938,27 @@ pub fn cast_with_options(
             | Map(_, _)
             | Dictionary(_, _),
         ) => Ok(new_null_array(to_type, array.len())),
+        (RunEndEncoded(run_ends, _), Dictionary(_, _)) => match run_ends.data_type() {
+            Int16 => run_end_encoded_to_dictionary::<Int16Type>(array, to_type, cast_options),
+            Int32 => run_end_encoded_to_dictionary::<Int32Type>(array, to_type, cast_options),
+            Int64 => run_end_encoded_to_dictionary::<Int64Type>(array, to_type, cast_options),
+            _ => Err(ArrowError::CastError(format!(
+                "Casting from run end encoded type {from_type:?} to {to_type:?} not supported",
+            ))),
+        },
+        (Dictionary(index_type, _), RunEndEncoded(_, _)) => match **index_type {
+            Int8 => dictionary_to_run_end_encoded::<Int8Type>(array, to_type, cast_options),
+            Int16 => dictionary_to_run_end_encoded::<Int16Type>(array, to_type, cast_options),
+            Int32 => dictionary_to_run_end_encoded::<Int32Type>(array, to_type, cast_options),
+            Int64 => dictionary_to_run_end_encoded::<Int64Type>(array, to_type, cast_options),
+            UInt8 => dictionary_to_run_end_encoded::<UInt8Type>(array, to_type, cast_options),
+            UInt16 => dictionary_to_run_end_encoded::<UInt16Type>(array, to_type, cast_options),
+            UInt32 => dictionary_to_run_end_encoded::<UInt32Type>(array, to_type, cast_options),
+            UInt64 => dictionary_to_run_end_encoded::<UInt64Type>(array, to_type, cast_options),
+            _ => Err(ArrowError::CastError(format!(
+                "Casting from dictionary type {from_type:?} to {to_type:?} not supported",
+            ))),
+        },
         (Dictionary(index_type, _), _) => match **index_type {
             Int8 => dictionary_cast::<Int8Type>(array, to_type, cast_options),
             Int16 => dictionary_cast::<Int16Type>(array, to_type, cast_options),
@@ -677,23 +992,38 @@ pub fn cast_with_options(
             cast_list_inner::<i64>(array, to, to_type, cast_options)
         }
         (List(list_from), LargeList(list_to)) => {
-            if list_to.data_type() != list_from.data_type() {
-                Err(ArrowError::CastError(
-                    "cannot cast list to large-list with different child data".into(),
-                ))
+            let array = cast_list_container::<i32, i64>(&**array, cast_options)?;
+            if list_to.data_type() == list_from.data_type() {
+                Ok(array)
             } else {
-                cast_list_container::<i32, i64>(&**array, cast_options)
+                cast_list_inner::<i64>(&array, list_to, to_type, cast_options)
             }
         }
         (LargeList(list_from), List(list_to)) => {
-            if list_to.data_type() != list_from.data_type() {
-                Err(ArrowError::CastError(
-                    "cannot cast large-list to list with different child data".into(),
-                ))
+            let array = cast_list_container::<i64, i32>(&**array, cast_options)?;
+            if list_to.data_type() == list_from.data_type() {
+                Ok(array)
             } else {
-                cast_list_container::<i64, i32>(&**array, cast_options)
+                cast_list_inner::<i32>(&array, list_to, to_type, cast_options)
             }
         }
+        (FixedSizeList(_, _), List(ref to)) => {
+            cast_fixed_size_list_to_list::<i32>(array, to, to_type, cast_options)
+        }
+        (FixedSizeList(_, _), LargeList(ref to)) => {
+            cast_fixed_size_list_to_list::<i64>(array, to, to_type, cast_options)
+        }
+        (List(_), FixedSizeList(ref to, size)) => {
+            cast_list_to_fixed_size_list::<i32>(array, to, *size, cast_options)
+        }
+        (LargeList(_), FixedSizeList(ref to, size)) => {
+            cast_list_to_fixed_size_list::<i64>(array, to, *size, cast_options)
+        }
+        (FixedSizeList(_, size_from), FixedSizeList(ref to, size_to))
+            if size_from == size_to =>
+        {
+            cast_fixed_size_list_inner(array, to, to_type, cast_options)
+        }
         (List(_) | LargeList(_), _) => match to_type {
             Utf8 => cast_list_to_string!(array, i32),
             LargeUtf8 => cast_list_to_string!(array, i64),
@@ -804,6 +1134,8 @@ pub fn cast_with_options(
                         x as f64 / 10_f64.powi(*scale as i32)
                     })
                 }
+                Utf8 => cast_decimal_to_string::<Decimal128Type, i32>(array),
+                LargeUtf8 => cast_decimal_to_string::<Decimal128Type, i64>(array),
                 Null => Ok(new_null_array(to_type, array.len())),
                 _ => Err(ArrowError::CastError(format!(
                     "Casting from {from_type:?} to {to_type:?} not supported"
@@ -871,6 +1203,8 @@ pub fn cast_with_options(
                         x.to_f64().unwrap() / 10_f64.powi(*scale as i32)
                     })
                 }
+                Utf8 => cast_decimal_to_string::<Decimal256Type, i32>(array),
+                LargeUtf8 => cast_decimal_to_string::<Decimal256Type, i64>(array),
                 Null => Ok(new_null_array(to_type, array.len())),
                 _ => Err(ArrowError::CastError(format!(
                     "Casting from {from_type:?} to {to_type:?} not supported"
@@ -1055,6 +1389,12 @@ pub fn cast_with_options(
                 ))),
             }
         }
+        (Struct(_), Struct(ref to_fields)) => {
+            cast_struct_inner(array, to_fields, to_type, cast_options)
+        }
+        (Map(_, _), Map(ref to_entries, to_sorted)) => {
+            cast_map_inner(array, to_entries, *to_sorted, cast_options)
+        }
         (Struct(_), _) => Err(ArrowError::CastError(
             "Cannot cast from struct to other types".to_string(),
         )),
@@ -1538,12 +1878,74 @@ pub fn cast_with_options(
         (Float64, Float32) => {
             cast_numeric_arrays::<Float64Type, Float32Type>(array, cast_options)
         }
+
+        (UInt8, Float16) => {
+            cast_numeric_arrays::<UInt8Type, Float16Type>(array, cast_options)
+        }
+        (UInt16, Float16) => {
+            cast_numeric_arrays::<UInt16Type, Float16Type>(array, cast_options)
+        }
+        (UInt32, Float16) => {
+            cast_numeric_arrays::<UInt32Type, Float16Type>(array, cast_options)
+        }
+        (UInt64, Float16) => {
+            cast_numeric_arrays::<UInt64Type, Float16Type>(array, cast_options)
+        }
+        (Int8, Float16) => {
+            cast_numeric_arrays::<Int8Type, Float16Type>(array, cast_options)
+        }
+        (Int16, Float16) => {
+            cast_numeric_arrays::<Int16Type, Float16Type>(array, cast_options)
+        }
+        (Int32, Float16) => {
+            cast_numeric_arrays::<Int32Type, Float16Type>(array, cast_options)
+        }
+        (Int64, Float16) => {
+            cast_numeric_arrays::<Int64Type, Float16Type>(array, cast_options)
+        }
+        (Float32, Float16) => {
+            cast_numeric_arrays::<Float32Type, Float16Type>(array, cast_options)
+        }
+        (Float64, Float16) => {
+            cast_numeric_arrays::<Float64Type, Float16Type>(array, cast_options)
+        }
+
+        (Float16, UInt8) => {
+            cast_numeric_arrays::<Float16Type, UInt8Type>(array, cast_options)
+        }
+        (Float16, UInt16) => {
+            cast_numeric_arrays::<Float16Type, UInt16Type>(array, cast_options)
+        }
+        (Float16, UInt32) => {
+            cast_numeric_arrays::<Float16Type, UInt32Type>(array, cast_options)
+        }
+        (Float16, UInt64) => {
+            cast_numeric_arrays::<Float16Type, UInt64Type>(array, cast_options)
+        }
+        (Float16, Int8) => {
+            cast_numeric_arrays::<Float16Type, Int8Type>(array, cast_options)
+        }
+        (Float16, Int16) => {
+            cast_numeric_arrays::<Float16Type, Int16Type>(array, cast_options)
+        }
+        (Float16, Int32) => {
+            cast_numeric_arrays::<Float16Type, Int32Type>(array, cast_options)
+        }
+        (Float16, Int64) => {
+            cast_numeric_arrays::<Float16Type, Int64Type>(array, cast_options)
+        }
+        (Float16, Float32) => {
+            cast_numeric_arrays::<Float16Type, Float32Type>(array, cast_options)
+        }
+        (Float16, Float64) => {
+            cast_numeric_arrays::<Float16Type, Float64Type>(array, cast_options)
+        }
         // end numeric casts
 
         // temporal casts
         (Int32, Date32) => cast_reinterpret_arrays::<Int32Type, Date32Type>(array),
-        (Int32, Date64) => cast_with_options(
-            &cast_with_options(array, &Date32, cast_options)?,
+        (Int32, Date64) => cast_internal(
+            &cast_internal(array, &Date32, cast_options)?,
             &Date64,
             cast_options,
         ),
@@ -1555,8 +1957,8 @@ pub fn cast_with_options(
         }
         // No support for microsecond/nanosecond with i32
         (Date32, Int32) => cast_reinterpret_arrays::<Date32Type, Int32Type>(array),
-        (Date32, Int64) => cast_with_options(
-            &cast_with_options(array, &Int32, cast_options)?,
+        (Date32, Int64) => cast_internal(
+            &cast_internal(array, &Int32, cast_options)?,
             &Int64,
             cast_options,
         ),
@@ -1567,8 +1969,8 @@ pub fn cast_with_options(
             cast_reinterpret_arrays::<Time32MillisecondType, Int32Type>(array)
         }
         (Int64, Date64) => cast_reinterpret_arrays::<Int64Type, Date64Type>(array),
-        (Int64, Date32) => cast_with_options(
-            &cast_with_options(array, &Int32, cast_options)?,
+        (Int64, Date32) => cast_internal(
+            &cast_internal(array, &Int32, cast_options)?,
             &Date32,
             cast_options,
         ),
@@ -1581,8 +1983,8 @@ pub fn cast_with_options(
         }
 
         (Date64, Int64) => cast_reinterpret_arrays::<Date64Type, Int64Type>(array),
-        (Date64, Int32) => cast_with_options(
-            &cast_with_options(array, &Int64, cast_options)?,
+        (Date64, Int32) => cast_internal(
+            &cast_internal(array, &Int64, cast_options)?,
             &Int32,
             cast_options,
         ),
@@ -1681,7 +2083,7 @@ pub fn cast_with_options(
         )),
 
         (Timestamp(from_unit, _), Timestamp(to_unit, to_tz)) => {
-            let array = cast_with_options(array, &Int64, cast_options)?;
+            let array = cast_internal(array, &Int64, cast_options)?;
             let time_array = as_primitive_array::<Int64Type>(array.as_ref());
             let from_size = time_unit_multiple(from_unit);
             let to_size = time_unit_multiple(to_unit);
@@ -1701,7 +2103,7 @@ pub fn cast_with_options(
             ))
         }
         (Timestamp(from_unit, _), Date32) => {
-            let array = cast_with_options(array, &Int64, cast_options)?;
+            let array = cast_internal(array, &Int64, cast_options)?;
             let time_array = as_primitive_array::<Int64Type>(array.as_ref());
             let from_size = time_unit_multiple(from_unit) * SECONDS_IN_DAY;
 
@@ -1982,6 +2384,69 @@ pub fn cast_with_options(
         (Int64, Interval(IntervalUnit::DayTime)) => {
             cast_reinterpret_arrays::<Int64Type, IntervalDayTimeType>(array)
         }
+        (Duration(TimeUnit::Second), Interval(IntervalUnit::MonthDayNano)) => {
+            cast_duration_to_interval::<DurationSecondType>(array, cast_options)
+        }
+        (Duration(TimeUnit::Millisecond), Interval(IntervalUnit::MonthDayNano)) => {
+            cast_duration_to_interval::<DurationMillisecondType>(array, cast_options)
+        }
+        (Duration(TimeUnit::Microsecond), Interval(IntervalUnit::MonthDayNano)) => {
+            cast_duration_to_interval::<DurationMicrosecondType>(array, cast_options)
+        }
+        (Duration(TimeUnit::Nanosecond), Interval(IntervalUnit::MonthDayNano)) => {
+            cast_duration_to_interval::<DurationNanosecondType>(array, cast_options)
+        }
+        (Interval(IntervalUnit::MonthDayNano), Duration(TimeUnit::Second)) => {
+            cast_interval_to_duration::<DurationSecondType>(array, cast_options)
+        }
+        (Interval(IntervalUnit::MonthDayNano), Duration(TimeUnit::Millisecond)) => {
+            cast_interval_to_duration::<DurationMillisecondType>(array, cast_options)
+        }
+        (Interval(IntervalUnit::MonthDayNano), Duration(TimeUnit::Microsecond)) => {
+            cast_interval_to_duration::<DurationMicrosecondType>(array, cast_options)
+        }
+        (Interval(IntervalUnit::MonthDayNano), Duration(TimeUnit::Nanosecond)) => {
+            cast_interval_to_duration::<DurationNanosecondType>(array, cast_options)
+        }
+        (Interval(IntervalUnit::YearMonth), Interval(IntervalUnit::MonthDayNano)) => {
+            let array = as_primitive_array::<IntervalYearMonthType>(array);
+            let array: IntervalMonthDayNanoArray =
+                array.unary(|v| IntervalMonthDayNanoType::make_value(v, 0, 0));
+            Ok(Arc::new(array))
+        }
+        (Interval(IntervalUnit::DayTime), Interval(IntervalUnit::MonthDayNano)) => {
+            Ok(Arc::new(cast_interval_day_time_to_month_day_nano(array)))
+        }
+        (Interval(IntervalUnit::MonthDayNano), Interval(IntervalUnit::DayTime)) => {
+            cast_interval_month_day_nano_to_day_time(array, cast_options)
+        }
+        (Interval(IntervalUnit::DayTime), Duration(TimeUnit::Second)) => {
+            cast_interval_day_time_to_duration::<DurationSecondType>(array, cast_options)
+        }
+        (Interval(IntervalUnit::DayTime), Duration(TimeUnit::Millisecond)) => {
+            cast_interval_day_time_to_duration::<DurationMillisecondType>(
+                array,
+                cast_options,
+            )
+        }
+        (Interval(IntervalUnit::DayTime), Duration(TimeUnit::Microsecond)) => {
+            cast_interval_day_time_to_duration::<DurationMicrosecondType>(
+                array,
+                cast_options,
+            )
+        }
+        (Interval(IntervalUnit::DayTime), Duration(TimeUnit::Nanosecond)) => {
+            cast_interval_day_time_to_duration::<DurationNanosecondType>(
+                array,
+                cast_options,
+            )
+        }
+        (Duration(_), Interval(IntervalUnit::DayTime)) => {
+            // Casting via IntervalMonthDayNano is lossless
+            let month_day_nano = Interval(IntervalUnit::MonthDayNano);
+            let array = cast_internal(array, &month_day_nano, cast_options)?;
+            cast_interval_month_day_nano_to_day_time(array.as_ref(), cast_options)
+        }
         (_, _) => Err(ArrowError::CastError(format!(
             "Casting from {from_type:?} to {to_type:?} not supported",
         ))),
@@ -1998,6 +2463,160 @@ const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
     }
 }
 
+/// Get the number of nanoseconds in a single unit of the duration type `D`
+fn duration_nanos<D: ArrowPrimitiveType>() -> i64 {
+    match D::DATA_TYPE {
+        DataType::Duration(unit) => NANOSECONDS / time_unit_multiple(&unit),
+        _ => unreachable!("{} is not a duration type", D::DATA_TYPE),
+    }
+}
+
+/// Cast a duration array to an [`IntervalMonthDayNanoArray`]
+///
+/// Values whose nanoseconds overflow an `i64` are null if `cast_options.safe`,
+/// otherwise an error is returned
+fn cast_duration_to_interval<D: ArrowPrimitiveType<Native = i64>>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = as_primitive_array::<D>(array);
+    let scale = duration_nanos::<D>();
+    let convert = |v: i64| {
+        v.checked_mul(scale)
+            .map(|nanos| IntervalMonthDayNanoType::make_value(0, 0, nanos))
+    };
+
+    let array: IntervalMonthDayNanoArray = if cast_options.safe {
+        array.unary_opt(convert)
+    } else {
+        array.try_unary(|v| {
+            convert(v).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Can't cast value {:?} to type {}",
+                    v,
+                    DataType::Interval(IntervalUnit::MonthDayNano)
+                ))
+            })
+        })?
+    };
+    Ok(Arc::new(array))
+}
+
+/// Cast an [`IntervalMonthDayNanoArray`] to a duration array
+///
+/// Only intervals without a month or day component, and whose nanoseconds are a
+/// multiple of the duration unit, can be represented losslessly. Other values are
+/// null if `cast_options.safe`, otherwise an error is returned
+fn cast_interval_to_duration<D: ArrowPrimitiveType<Native = i64>>(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = as_primitive_array::<IntervalMonthDayNanoType>(array);
+    let scale = duration_nanos::<D>();
+    let convert = |v: i128| {
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(v);
+        (months == 0 && days == 0 && nanos % scale == 0).then_some(nanos / scale)
+    };
+
+    let array: PrimitiveArray<D> = if cast_options.safe {
+        array.unary_opt(convert)
+    } else {
+        array.try_unary(|v| {
+            convert(v).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Can't cast value {} to type {}",
+                    IntervalMonthDayNanoType::format_value(v),
+                    D::DATA_TYPE
+                ))
+            })
+        })?
+    };
+    Ok(Arc::new(array))
+}
+
+/// Cast an [`IntervalDayTimeArray`] to a duration array
+///
+/// Only intervals without a day component, and whose milliseconds are a whole
+/// number of `D`'s unit, can be represented losslessly. Other values are null if
+/// `cast_options.safe`, otherwise an error is returned
+fn cast_interval_day_time_to_duration<D: ArrowPrimitiveType<Native = i64>>(
+    array: &ArrayRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let intervals = as_primitive_array::<IntervalDayTimeType>(array);
+    let scale = duration_nanos::<D>();
+    let convert = |v: i64| {
+        let (days, millis) = IntervalDayTimeType::to_parts(v);
+        let nanos = millis as i64 * 1_000_000;
+        (days == 0 && nanos % scale == 0).then_some(nanos / scale)
+    };
+
+    let durations: PrimitiveArray<D> = if cast_options.safe {
+        intervals.unary_opt(convert)
+    } else {
+        intervals
+            .iter()
+            .enumerate()
+            .map(|(row, v)| match v.map(convert) {
+                Some(None) => Err(ArrowError::CastError(format!(
+                    "Can't cast value {} to type {}",
+                    array_value_to_string(array, row)?,
+                    D::DATA_TYPE
+                ))),
+                v => Ok(v.flatten()),
+            })
+            .collect::<Result<_, _>>()?
+    };
+    Ok(Arc::new(durations))
+}
+
+/// Cast an [`IntervalDayTimeArray`] to an [`IntervalMonthDayNanoArray`], which is
+/// always lossless
+fn cast_interval_day_time_to_month_day_nano(
+    array: &dyn Array,
+) -> IntervalMonthDayNanoArray {
+    let array = as_primitive_array::<IntervalDayTimeType>(array);
+    array.unary(|v| {
+        let (days, millis) = IntervalDayTimeType::to_parts(v);
+        IntervalMonthDayNanoType::make_value(0, days, millis as i64 * 1_000_000)
+    })
+}
+
+/// Cast an [`IntervalMonthDayNanoArray`] to an [`IntervalDayTimeArray`]
+///
+/// Only intervals without a month component, and whose nanoseconds are a whole
+/// number of milliseconds that fits in an `i32`, can be represented losslessly.
+/// Other values are null if `cast_options.safe`, otherwise an error is returned
+fn cast_interval_month_day_nano_to_day_time(
+    array: &dyn Array,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let array = as_primitive_array::<IntervalMonthDayNanoType>(array);
+    let convert = |v: i128| {
+        let (months, days, nanos) = IntervalMonthDayNanoType::to_parts(v);
+        if months != 0 || nanos % 1_000_000 != 0 {
+            return None;
+        }
+        let millis = i32::try_from(nanos / 1_000_000).ok()?;
+        Some(IntervalDayTimeType::make_value(days, millis))
+    };
+
+    let array: IntervalDayTimeArray = if cast_options.safe {
+        array.unary_opt(convert)
+    } else {
+        array.try_unary(|v| {
+            convert(v).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Can't cast value {} to type {}",
+                    IntervalMonthDayNanoType::format_value(v),
+                    DataType::Interval(IntervalUnit::DayTime)
+                ))
+            })
+        })?
+    };
+    Ok(Arc::new(array))
+}
+
 /// A utility trait that provides checked conversions between
 /// decimal types inspired by [`NumCast`]
 trait DecimalCast: Sized {
@@ -2319,6 +2938,19 @@ where
     )))
 }
 
+/// Cast decimal types to Utf8
+fn cast_decimal_to_string<T, OffsetSize>(array: &ArrayRef) -> Result<ArrayRef, ArrowError>
+where
+    T: DecimalType,
+    OffsetSize: OffsetSizeTrait,
+{
+    let array = as_primitive_array::<T>(array);
+    let strings: GenericStringArray<OffsetSize> = (0..array.len())
+        .map(|idx| array.is_valid(idx).then(|| array.value_as_string(idx)))
+        .collect();
+    Ok(Arc::new(strings))
+}
+
 fn numeric_to_string_cast<T, OffsetSize>(
     from: &PrimitiveArray<T>,
 ) -> GenericStringArray<OffsetSize>
@@ -2390,6 +3022,21 @@ where
     }
 }
 
+/// Parses a date from `s` with the first of `formats` that matches, falling back to
+/// parsing `s` as `%Y-%m-%d`
+fn parse_date(s: &str, formats: &[String]) -> Option<chrono::NaiveDate> {
+    match parse_formatted_datetime(s, formats) {
+        Some(datetime) => Some(datetime.date()),
+        None => s.parse().ok(),
+    }
+}
+
+/// Parses a datetime from `s` with the first of `formats` that matches, falling back to
+/// parsing `s` as `%Y-%m-%dT%H:%M:%S%.f`
+fn parse_datetime(s: &str, formats: &[String]) -> Option<chrono::NaiveDateTime> {
+    parse_formatted_datetime(s, formats).or_else(|| s.parse().ok())
+}
+
 /// Casts generic string arrays to Date32Array
 fn cast_string_to_date32<Offset: OffsetSizeTrait>(
     array: &dyn Array,
@@ -2404,9 +3051,8 @@ fn cast_string_to_date32<Offset: OffsetSizeTrait>(
     let array = if cast_options.safe {
         let iter = string_array.iter().map(|v| {
             v.and_then(|v| {
-                v.parse::<chrono::NaiveDate>()
+                parse_date(v, &cast_options.datetime_formats)
                     .map(|date| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
-                    .ok()
             })
         });
 
@@ -2420,9 +3066,9 @@ fn cast_string_to_date32<Offset: OffsetSizeTrait>(
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    v.parse::<chrono::NaiveDate>()
+                    parse_date(v, &cast_options.datetime_formats)
                         .map(|date| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
-                        .map_err(|_| {
+                        .ok_or_else(|| {
                             ArrowError::CastError(format!(
                                 "Cannot cast string '{}' to value of {:?} type",
                                 v,
@@ -2457,9 +3103,8 @@ fn cast_string_to_date64<Offset: OffsetSizeTrait>(
     let array = if cast_options.safe {
         let iter = string_array.iter().map(|v| {
             v.and_then(|v| {
-                v.parse::<chrono::NaiveDateTime>()
+                parse_datetime(v, &cast_options.datetime_formats)
                     .map(|datetime| datetime.timestamp_millis())
-                    .ok()
             })
         });
 
@@ -2473,9 +3118,9 @@ fn cast_string_to_date64<Offset: OffsetSizeTrait>(
             .iter()
             .map(|v| {
                 v.map(|v| {
-                    v.parse::<chrono::NaiveDateTime>()
+                    parse_datetime(v, &cast_options.datetime_formats)
                         .map(|datetime| datetime.timestamp_millis())
-                        .map_err(|_| {
+                        .ok_or_else(|| {
                             ArrowError::CastError(format!(
                                 "Cannot cast string '{}' to value of {:?} type",
                                 v,
@@ -2762,11 +3407,12 @@ fn cast_string_to_timestamp_ns<Offset: OffsetSizeTrait>(
         .as_any()
         .downcast_ref::<GenericStringArray<Offset>>()
         .unwrap();
+    let formats = &cast_options.datetime_formats;
 
     let array = if cast_options.safe {
-        let iter = string_array
-            .iter()
-            .map(|v| v.and_then(|v| string_to_timestamp_nanos(v).ok()));
+        let iter = string_array.iter().map(|v| {
+            v.and_then(|v| string_to_timestamp_nanos_formatted(v, formats).ok())
+        });
         // Benefit:
         //     20% performance improvement
         // Soundness:
@@ -2775,7 +3421,10 @@ fn cast_string_to_timestamp_ns<Offset: OffsetSizeTrait>(
     } else {
         let vec = string_array
             .iter()
-            .map(|v| v.map(string_to_timestamp_nanos).transpose())
+            .map(|v| {
+                v.map(|v| string_to_timestamp_nanos_formatted(v, formats))
+                    .transpose()
+            })
             .collect::<Result<Vec<Option<i64>>, _>>()?;
 
         // Benefit:
@@ -2788,6 +3437,12 @@ fn cast_string_to_timestamp_ns<Offset: OffsetSizeTrait>(
     Ok(Arc::new(array) as ArrayRef)
 }
 
+const DEFAULT_TRUE_VALUES: &[&str] =
+    &["t", "tr", "tru", "true", "y", "ye", "yes", "on", "1"];
+const DEFAULT_FALSE_VALUES: &[&str] = &[
+    "f", "fa", "fal", "fals", "false", "n", "no", "of", "off", "0",
+];
+
 /// Casts Utf8 to Boolean
 fn cast_utf8_to_boolean<OffsetSize>(
     from: &ArrayRef,
@@ -2801,21 +3456,35 @@ where
         .downcast_ref::<GenericStringArray<OffsetSize>>()
         .unwrap();
 
+    let is_true = |value: &str| match cast_options.true_values.is_empty() {
+        true => DEFAULT_TRUE_VALUES
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(value)),
+        false => cast_options
+            .true_values
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(value)),
+    };
+    let is_false = |value: &str| match cast_options.false_values.is_empty() {
+        true => DEFAULT_FALSE_VALUES
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(value)),
+        false => cast_options
+            .false_values
+            .iter()
+            .any(|v| v.eq_ignore_ascii_case(value)),
+    };
+
     let output_array = array
         .iter()
-        .map(|value| match value {
-            Some(value) => match value.to_ascii_lowercase().trim() {
-                "t" | "tr" | "tru" | "true" | "y" | "ye" | "yes" | "on" | "1" => {
-                    Ok(Some(true))
-                }
-                "f" | "fa" | "fal" | "fals" | "false" | "n" | "no" | "of" | "off"
-                | "0" => Ok(Some(false)),
-                invalid_value => match cast_options.safe {
-                    true => Ok(None),
-                    false => Err(ArrowError::CastError(format!(
-                        "Cannot cast value '{invalid_value}' to value of Boolean type",
-                    ))),
-                },
+        .map(|value| match value.map(str::trim) {
+            Some(value) if is_true(value) => Ok(Some(true)),
+            Some(value) if is_false(value) => Ok(Some(false)),
+            Some(invalid_value) => match cast_options.safe {
+                true => Ok(None),
+                false => Err(ArrowError::CastError(format!(
+                    "Cannot cast value '{invalid_value}' to value of Boolean type",
+                ))),
             },
             None => Ok(None),
         })
@@ -2825,87 +3494,34 @@ where
 }
 
 /// Parses given string to specified decimal native (i128/i256) based on given
-/// scale. Returns an `Err` if it cannot parse given string.
+/// scale, rounding half away from zero if it has more decimal places than `scale`
+///
+/// Accepts an optional sign, and an optional exponent e.g. `-1.5e-3`. Returns a
+/// [`ArrowError::ParseError`] if it cannot parse given string, or a
+/// [`ArrowError::CastError`] if the value overflows the decimal native type
 fn parse_string_to_decimal_native<T: DecimalType>(
     value_str: &str,
-    scale: usize,
+    scale: i8,
 ) -> Result<T::Native, ArrowError>
 where
     T::Native: DecimalCast + ArrowNativeTypeOp,
 {
     let value_str = value_str.trim();
-    let parts: Vec<&str> = value_str.split('.').collect();
-    if parts.len() > 2 {
-        return Err(ArrowError::InvalidArgumentError(format!(
-            "Invalid decimal format: {value_str:?}"
-        )));
+    // An empty string is parsed as zero
+    if value_str.is_empty() {
+        return Ok(T::Native::usize_as(0));
     }
-
-    let integers = parts[0].trim_start_matches('0');
-    let decimals = if parts.len() == 2 { parts[1] } else { "" };
-
-    // Adjust decimal based on scale
-    let number_decimals = if decimals.len() > scale {
-        let decimal_number = i256::from_string(decimals).ok_or_else(|| {
-            ArrowError::InvalidArgumentError(format!(
-                "Cannot parse decimal format: {value_str}"
-            ))
-        })?;
-
-        let div =
-            i256::from_i128(10_i128).pow_checked((decimals.len() - scale) as u32)?;
-
-        let half = div.div_wrapping(i256::from_i128(2));
-        let half_neg = half.neg_wrapping();
-
-        let d = decimal_number.div_wrapping(div);
-        let r = decimal_number.mod_wrapping(div);
-
-        // Round result
-        let adjusted = match decimal_number >= i256::ZERO {
-            true if r >= half => d.add_wrapping(i256::ONE),
-            false if r <= half_neg => d.sub_wrapping(i256::ONE),
-            _ => d,
-        };
-
-        let integers = if !integers.is_empty() {
-            i256::from_string(integers)
-                .ok_or_else(|| {
-                    ArrowError::InvalidArgumentError(format!(
-                        "Cannot parse decimal format: {value_str}"
-                    ))
-                })
-                .map(|v| {
-                    v.mul_wrapping(i256::from_i128(10_i128).pow_wrapping(scale as u32))
-                })?
-        } else {
-            i256::ZERO
-        };
-
-        format!("{}", integers.add_wrapping(adjusted))
-    } else {
-        let padding = if scale > decimals.len() { scale } else { 0 };
-
-        let decimals = format!("{decimals:0<padding$}");
-        format!("{integers}{decimals}")
-    };
-
-    let value = i256::from_string(number_decimals.as_str()).ok_or_else(|| {
-        ArrowError::InvalidArgumentError(format!(
-            "Cannot convert {} to {}: Overflow",
-            value_str,
-            T::PREFIX
-        ))
-    })?;
-
-    T::Native::from_decimal(value).ok_or_else(|| {
-        ArrowError::InvalidArgumentError(format!(
-            "Cannot convert {} to {}",
-            value_str,
-            T::PREFIX
-        ))
-    })
-}
+    parse_decimal_native::<T>(value_str, scale, true).map_err(|e| match e {
+        DecimalParseError::Invalid => {
+            ArrowError::ParseError(format!("Cannot parse decimal format: {value_str}"))
+        }
+        DecimalParseError::Overflow => ArrowError::CastError(format!(
+            "Cannot convert {} to {}: Overflow",
+            value_str,
+            T::PREFIX
+        )),
+    })
+}
 
 fn string_to_decimal_cast<T, Offset: OffsetSizeTrait>(
     from: &GenericStringArray<Offset>,
@@ -2917,10 +3533,28 @@ where
     T: DecimalType,
     T::Native: DecimalCast + ArrowNativeTypeOp,
 {
+    let parse = |v: &str| {
+        let value = parse_string_to_decimal_native::<T>(v, scale);
+        let value = value.map_err(|e| match e {
+            ArrowError::ParseError(_) => ArrowError::CastError(format!(
+                "Cannot cast string '{}' to value of {:?} type",
+                v,
+                T::DATA_TYPE,
+            )),
+            e => e,
+        })?;
+        T::validate_decimal_precision(value, precision).map_err(|_| {
+            ArrowError::CastError(format!(
+                "Cannot cast string '{}' to value of {:?} type: Overflow",
+                v,
+                T::TYPE_CONSTRUCTOR(precision, scale),
+            ))
+        })?;
+        Ok(value)
+    };
+
     if cast_options.safe {
-        let iter = from.iter().map(|v| {
-            v.and_then(|v| parse_string_to_decimal_native::<T>(v, scale as usize).ok())
-        });
+        let iter = from.iter().map(|v| v.and_then(|v| parse(v).ok()));
         // Benefit:
         //     20% performance improvement
         // Soundness:
@@ -2932,19 +3566,8 @@ where
     } else {
         let vec = from
             .iter()
-            .map(|v| {
-                v.map(|v| {
-                    parse_string_to_decimal_native::<T>(v, scale as usize).map_err(|_| {
-                        ArrowError::CastError(format!(
-                            "Cannot cast string '{}' to value of {:?} type",
-                            v,
-                            T::DATA_TYPE,
-                        ))
-                    })
-                })
-                .transpose()
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+            .map(|v| v.map(parse).transpose())
+            .collect::<Result<Vec<_>, ArrowError>>()?;
         // Benefit:
         //     20% performance improvement
         // Soundness:
@@ -3090,21 +3713,32 @@ fn dictionary_cast<K: ArrowDictionaryKeyType>(
 
             let keys_array: ArrayRef =
                 Arc::new(PrimitiveArray::<K>::from(dict_array.keys().data().clone()));
-            let values_array = dict_array.values();
-            let cast_keys = cast_with_options(&keys_array, to_index_type, cast_options)?;
-            let cast_values =
-                cast_with_options(values_array, to_value_type, cast_options)?;
+            let mut values_array = dict_array.values().clone();
 
             // Failure to cast keys (because they don't fit in the
-            // target type) results in NULL values;
+            // target type) results in NULL values
+            let safe_options = CastOptions {
+                safe: true,
+                ..cast_options.clone()
+            };
+            let mut cast_keys = cast_internal(&keys_array, to_index_type, &safe_options)?;
             if cast_keys.null_count() > keys_array.null_count() {
-                return Err(ArrowError::ComputeError(format!(
-                    "Could not convert {} dictionary indexes from {:?} to {:?}",
-                    cast_keys.null_count() - keys_array.null_count(),
-                    keys_array.data_type(),
-                    to_index_type
-                )));
+                // Re-encode the dictionary with only the referenced values, which
+                // may allow the keys to fit in the target type
+                let (compact_keys, compact_values) = compact_dictionary(dict_array)?;
+                cast_keys = cast_internal(&compact_keys, to_index_type, &safe_options)?;
+                values_array = compact_values;
+
+                if cast_keys.null_count() > keys_array.null_count() {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Could not convert {} dictionary indexes from {:?} to {:?}",
+                        cast_keys.null_count() - keys_array.null_count(),
+                        keys_array.data_type(),
+                        to_index_type
+                    )));
+                }
             }
+            let cast_values = cast_internal(&values_array, to_value_type, cast_options)?;
 
             // keys are data, child_data is values (dictionary)
             let data = unsafe {
@@ -3146,6 +3780,29 @@ fn dictionary_cast<K: ArrowDictionaryKeyType>(
     }
 }
 
+/// Re-encodes `dict_array` so that its values only contain those referenced by a key,
+/// returning the new keys as a `UInt64Array` and the new values
+fn compact_dictionary<K: ArrowDictionaryKeyType>(
+    dict_array: &DictionaryArray<K>,
+) -> Result<(ArrayRef, ArrayRef), ArrowError> {
+    let mut remap: Vec<Option<u64>> = vec![None; dict_array.values().len()];
+    let mut used = Vec::new();
+    let keys: UInt64Array = dict_array
+        .keys()
+        .iter()
+        .map(|key| {
+            key.map(|key| {
+                *remap[key.as_usize()].get_or_insert_with(|| {
+                    used.push(key.as_usize() as u64);
+                    used.len() as u64 - 1
+                })
+            })
+        })
+        .collect();
+    let values = take(dict_array.values(), &UInt64Array::from(used), None)?;
+    Ok((Arc::new(keys), values))
+}
+
 // Unpack a dictionary where the keys are of type <K> into a flattened array of type to_type
 fn unpack_dictionary<K>(
     array: &ArrayRef,
@@ -3166,12 +3823,12 @@ where
 
     // attempt to cast the dict values to the target type
     // use the take kernel to expand out the dictionary
-    let cast_dict_values = cast_with_options(dict_array.values(), to_type, cast_options)?;
+    let cast_dict_values = cast_internal(dict_array.values(), to_type, cast_options)?;
 
     // Note take requires first casting the indices to u32
     let keys_array: ArrayRef =
         Arc::new(PrimitiveArray::<K>::from(dict_array.keys().data().clone()));
-    let indices = cast_with_options(&keys_array, &DataType::UInt32, cast_options)?;
+    let indices = cast_internal(&keys_array, &DataType::UInt32, cast_options)?;
     let u32_indices =
         indices
             .as_any()
@@ -3275,7 +3932,7 @@ where
     V: ArrowPrimitiveType,
 {
     // attempt to cast the source array values to the target value type (the dictionary values type)
-    let cast_values = cast_with_options(array, dict_value_type, cast_options)?;
+    let cast_values = cast_internal(array, dict_value_type, cast_options)?;
     let values = cast_values
         .as_any()
         .downcast_ref::<PrimitiveArray<V>>()
@@ -3305,7 +3962,7 @@ where
     K: ArrowDictionaryKeyType,
     T: ByteArrayType,
 {
-    let cast_values = cast_with_options(array, &T::DATA_TYPE, cast_options)?;
+    let cast_values = cast_internal(array, &T::DATA_TYPE, cast_options)?;
     let values = cast_values
         .as_any()
         .downcast_ref::<GenericByteArray<T>>()
@@ -3324,6 +3981,222 @@ where
     Ok(Arc::new(b.finish()))
 }
 
+/// Converts a [`RunArray`] into a [`DictionaryArray`] with one dictionary value per
+/// run, without expanding the values of the runs.
+///
+/// Only the runs covered by a sliced [`RunArray`] are included in the dictionary, and
+/// runs of null values are encoded as null keys. Returns an error if the number of runs
+/// cannot be represented by the key type `K`.
+///
+/// ```
+/// # use arrow_array::{Array, DictionaryArray, Int32RunArray, StringArray};
+/// # use arrow_array::types::{Int32Type, Int8Type};
+/// # use arrow_cast::run_array_to_dictionary;
+/// let array: Int32RunArray = vec!["a", "a", "b", "b", "b", "a"].into_iter().collect();
+/// let dictionary = run_array_to_dictionary::<Int32Type, Int8Type>(&array).unwrap();
+/// let keys: Vec<_> = dictionary.keys().values().to_vec();
+/// assert_eq!(keys, vec![0, 0, 1, 1, 1, 2]);
+/// let values = dictionary.values().as_any().downcast_ref::<StringArray>().unwrap();
+/// assert_eq!(values, &StringArray::from(vec!["a", "b", "a"]));
+/// ```
+pub fn run_array_to_dictionary<R, K>(
+    array: &RunArray<R>,
+) -> Result<DictionaryArray<K>, ArrowError>
+where
+    R: RunEndIndexType,
+    K: ArrowDictionaryKeyType,
+{
+    let (start, num_runs) = match array.is_empty() {
+        true => (0, 0),
+        false => {
+            let start = array.get_start_physical_index();
+            (start, array.get_end_physical_index() - start + 1)
+        }
+    };
+    let values = array.values().slice(start, num_runs);
+
+    // Run ends are relative to the start of the unsliced array
+    let logical_start = array.offset();
+    let logical_end = logical_start + array.len();
+    let run_ends = &array.run_ends().values()[start..start + num_runs];
+
+    let mut keys = PrimitiveBuilder::<K>::with_capacity(array.len());
+    let mut run_start = logical_start;
+    for (idx, run_end) in run_ends.iter().enumerate() {
+        let key = K::Native::from_usize(idx).ok_or_else(|| {
+            ArrowError::CastError(format!(
+                "Cannot represent {} runs with dictionary key type {:?}",
+                num_runs,
+                K::DATA_TYPE
+            ))
+        })?;
+        let run_end = run_end.as_usize().min(logical_end);
+        let run_len = run_end - run_start;
+        // Runs of null values are encoded as null keys
+        match values.is_null(idx) {
+            true => keys.append_nulls(run_len),
+            false => (0..run_len).for_each(|_| keys.append_value(key)),
+        }
+        run_start = run_end;
+    }
+
+    DictionaryArray::try_new(&keys.finish(), &values)
+}
+
+/// Converts a [`DictionaryArray`] into a [`RunArray`] with one run for each sequence
+/// of consecutive equal keys, without expanding the dictionary.
+///
+/// Null keys are encoded as runs of null values. Returns an error if the length of the
+/// array cannot be represented by the run end type `R`.
+///
+/// ```
+/// # use arrow_array::{Array, DictionaryArray, Int32Array, StringArray};
+/// # use arrow_array::types::{Int16Type, Int8Type};
+/// # use arrow_cast::dictionary_to_run_array;
+/// let dictionary: DictionaryArray<Int8Type> =
+///     vec![Some("a"), Some("a"), None, Some("b"), Some("b")].into_iter().collect();
+/// let run_array = dictionary_to_run_array::<Int8Type, Int16Type>(&dictionary).unwrap();
+/// assert_eq!(run_array.run_ends().values(), &[2, 3, 5]);
+/// let values = run_array.values().as_any().downcast_ref::<StringArray>().unwrap();
+/// assert_eq!(values, &StringArray::from(vec![Some("a"), None, Some("b")]));
+/// ```
+pub fn dictionary_to_run_array<K, R>(
+    array: &DictionaryArray<K>,
+) -> Result<RunArray<R>, ArrowError>
+where
+    K: ArrowDictionaryKeyType,
+    R: RunEndIndexType,
+{
+    let keys = array.keys();
+    let len = keys.len();
+    let run_end_err = || {
+        ArrowError::ComputeError(format!(
+            "Cannot represent array of length {} with run end type {:?}",
+            len,
+            R::DATA_TYPE
+        ))
+    };
+
+    // Encode runs of equal keys, and then look up the values of the runs
+    let mut run_ends = BufferBuilder::<R::Native>::new(1);
+    let mut run_keys = UInt64Builder::new();
+    let mut current = None;
+    for (idx, key) in keys.iter().enumerate() {
+        if idx > 0 && key == current {
+            continue;
+        }
+        if idx > 0 {
+            run_ends.append(R::Native::from_usize(idx).ok_or_else(run_end_err)?);
+        }
+        run_keys.append_option(key.map(|k| k.as_usize() as u64));
+        current = key;
+    }
+    if len > 0 {
+        run_ends.append(R::Native::from_usize(len).ok_or_else(run_end_err)?);
+    }
+
+    let run_ends = PrimitiveArray::<R>::from(unsafe {
+        ArrayData::new_unchecked(
+            R::DATA_TYPE,
+            run_ends.len(),
+            None,
+            None,
+            0,
+            vec![run_ends.finish()],
+            vec![],
+        )
+    });
+    let values = take(array.values(), &run_keys.finish(), None)?;
+    RunArray::try_new(&run_ends, &values)
+}
+
+/// Casts a [`RunArray`] to a dictionary type, see [`run_array_to_dictionary`]
+fn run_end_encoded_to_dictionary<R: RunEndIndexType>(
+    array: &ArrayRef,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    use DataType::*;
+
+    let run_array = array
+        .as_any()
+        .downcast_ref::<RunArray<R>>()
+        .ok_or_else(|| {
+            ArrowError::ComputeError(
+                "Internal Error: Cannot cast run end encoded array to RunArray of expected type".to_string(),
+            )
+        })?;
+
+    let index_type = match to_type {
+        Dictionary(index_type, _) => index_type,
+        _ => unreachable!(),
+    };
+    let dictionary: ArrayRef = match **index_type {
+        Int8 => Arc::new(run_array_to_dictionary::<R, Int8Type>(run_array)?),
+        Int16 => Arc::new(run_array_to_dictionary::<R, Int16Type>(run_array)?),
+        Int32 => Arc::new(run_array_to_dictionary::<R, Int32Type>(run_array)?),
+        Int64 => Arc::new(run_array_to_dictionary::<R, Int64Type>(run_array)?),
+        UInt8 => Arc::new(run_array_to_dictionary::<R, UInt8Type>(run_array)?),
+        UInt16 => Arc::new(run_array_to_dictionary::<R, UInt16Type>(run_array)?),
+        UInt32 => Arc::new(run_array_to_dictionary::<R, UInt32Type>(run_array)?),
+        UInt64 => Arc::new(run_array_to_dictionary::<R, UInt64Type>(run_array)?),
+        _ => {
+            return Err(ArrowError::CastError(format!(
+                "Unsupported type {index_type:?} for dictionary index"
+            )));
+        }
+    };
+
+    // Cast the dictionary values if required
+    cast_internal(&dictionary, to_type, cast_options)
+}
+
+/// Casts a [`DictionaryArray`] to a run end encoded type, see [`dictionary_to_run_array`]
+fn dictionary_to_run_end_encoded<K: ArrowDictionaryKeyType>(
+    array: &ArrayRef,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    use DataType::*;
+
+    let (run_ends, values) = match to_type {
+        RunEndEncoded(run_ends, values) => (run_ends, values),
+        _ => unreachable!(),
+    };
+
+    // Cast the dictionary values first, so runs are computed on the keys only
+    let dictionary_type =
+        Dictionary(Box::new(K::DATA_TYPE), Box::new(values.data_type().clone()));
+    let dictionary = cast_internal(array, &dictionary_type, cast_options)?;
+    let dictionary = dictionary
+        .as_any()
+        .downcast_ref::<DictionaryArray<K>>()
+        .ok_or_else(|| {
+            ArrowError::ComputeError(
+                "Internal Error: Cannot cast dictionary to DictionaryArray of expected type".to_string(),
+            )
+        })?;
+
+    let run_array = match run_ends.data_type() {
+        Int16 => dictionary_to_run_array::<K, Int16Type>(dictionary)?.into_data(),
+        Int32 => dictionary_to_run_array::<K, Int32Type>(dictionary)?.into_data(),
+        Int64 => dictionary_to_run_array::<K, Int64Type>(dictionary)?.into_data(),
+        _ => {
+            return Err(ArrowError::CastError(format!(
+                "Unsupported type {:?} for run ends",
+                run_ends.data_type()
+            )));
+        }
+    };
+
+    // Use the field names and nullability of `to_type`
+    let data = run_array
+        .into_builder()
+        .data_type(to_type.clone())
+        .build()?;
+    Ok(make_array(data))
+}
+
 /// Helper function that takes a primitive array and casts to a (generic) list array.
 fn cast_primitive_to_list<OffsetSize: OffsetSizeTrait + NumCast>(
     array: &ArrayRef,
@@ -3332,7 +4205,7 @@ fn cast_primitive_to_list<OffsetSize: OffsetSizeTrait + NumCast>(
     cast_options: &CastOptions,
 ) -> Result<ArrayRef, ArrowError> {
     // cast primitive to list's primitive
-    let cast_array = cast_with_options(array, to.data_type(), cast_options)?;
+    let cast_array = cast_internal(array, to.data_type(), cast_options)?;
     // create offsets, where if array.len() = 2, we have [0,1,2]
     // Safety:
     // Length of range can be trusted.
@@ -3373,7 +4246,7 @@ fn cast_list_inner<OffsetSize: OffsetSizeTrait>(
 ) -> Result<ArrayRef, ArrowError> {
     let data = array.data_ref();
     let underlying_array = make_array(data.child_data()[0].clone());
-    let cast_array = cast_with_options(&underlying_array, to.data_type(), cast_options)?;
+    let cast_array = cast_internal(&underlying_array, to.data_type(), cast_options)?;
     let array_data = unsafe {
         ArrayData::new_unchecked(
             to_type.clone(),
@@ -3392,6 +4265,284 @@ fn cast_list_inner<OffsetSize: OffsetSizeTrait>(
     Ok(Arc::new(list) as ArrayRef)
 }
 
+/// Helper function that casts the children of a `StructArray` to the fields in
+/// `to_fields`, matched according to [`CastOptions::struct_field_matching`], reusing
+/// the validity of the struct.
+fn cast_struct_inner(
+    array: &ArrayRef,
+    to_fields: &[Field],
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let data = array.data_ref();
+    let from_fields = match data.data_type() {
+        DataType::Struct(fields) => fields,
+        _ => unreachable!("Struct array's data type is not struct!"),
+    };
+
+    // The children of a struct are already sliced to the range of the parent
+    let cast_child = |child: &ArrayData, field: &Field| {
+        let child = make_array(child.clone());
+        Ok(cast_internal(&child, field.data_type(), cast_options)?.into_data())
+    };
+
+    let child_data = match cast_options.struct_field_matching {
+        StructFieldMatching::Position => {
+            if from_fields.len() != to_fields.len() {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast struct with {} fields to struct with {} fields",
+                    from_fields.len(),
+                    to_fields.len()
+                )));
+            }
+            data.child_data()
+                .iter()
+                .zip(to_fields)
+                .map(|(child, field)| cast_child(child, field))
+                .collect::<Result<Vec<_>, ArrowError>>()?
+        }
+        StructFieldMatching::Name | StructFieldMatching::NameDropExtra => {
+            let duplicate = |fields: &[Field]| {
+                fields.iter().enumerate().find_map(|(idx, field)| {
+                    fields[..idx]
+                        .iter()
+                        .any(|f| f.name() == field.name())
+                        .then(|| field.name().clone())
+                })
+            };
+            if let Some(name) = duplicate(from_fields).or_else(|| duplicate(to_fields)) {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast struct by name: field '{name}' is not unique"
+                )));
+            }
+            if cast_options.struct_field_matching == StructFieldMatching::Name {
+                if let Some(extra) = from_fields
+                    .iter()
+                    .find(|from| !to_fields.iter().any(|to| to.name() == from.name()))
+                {
+                    return Err(ArrowError::CastError(format!(
+                        "Cannot cast struct: field '{}' is not present in the target type",
+                        extra.name()
+                    )));
+                }
+            }
+            to_fields
+                .iter()
+                .map(|field| {
+                    match from_fields.iter().position(|from| from.name() == field.name())
+                    {
+                        Some(idx) => cast_child(&data.child_data()[idx], field),
+                        None if field.is_nullable() => {
+                            Ok(new_null_array(field.data_type(), array.len()).into_data())
+                        }
+                        None => Err(ArrowError::CastError(format!(
+                            "Cannot cast struct: non-nullable field '{}' is not present in the source type",
+                            field.name()
+                        ))),
+                    }
+                })
+                .collect::<Result<Vec<_>, ArrowError>>()?
+        }
+    };
+
+    // Values that fail to cast may be null, so validate the non-nullable fields
+    let array_data = ArrayData::try_new(
+        to_type.clone(),
+        array.len(),
+        data.null_buffer().cloned(),
+        array.offset(),
+        vec![],
+        child_data,
+    )?;
+    Ok(Arc::new(StructArray::from(array_data)) as ArrayRef)
+}
+
+/// Helper function that casts the entries of a `MapArray`, reusing the offsets and
+/// validity of the map.
+///
+/// The result is only marked as sorted if `to_sorted` is set, and the source is sorted
+/// with keys of the same type, as casting the keys may change their order
+fn cast_map_inner(
+    array: &ArrayRef,
+    to_entries: &Field,
+    to_sorted: bool,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let data = array.data_ref();
+    let (from_entries, from_sorted) = match data.data_type() {
+        DataType::Map(entries, sorted) => (entries, *sorted),
+        _ => unreachable!("Map array's data type is not map!"),
+    };
+    let key_type = |entries: &Field| match entries.data_type() {
+        DataType::Struct(fields) => fields.first().map(|f| f.data_type().clone()),
+        _ => None,
+    };
+    let sorted =
+        to_sorted && from_sorted && key_type(from_entries) == key_type(to_entries);
+
+    let entries = make_array(data.child_data()[0].clone());
+    let cast_entries = cast_internal(&entries, to_entries.data_type(), cast_options)?;
+    let array_data = ArrayData::try_new(
+        DataType::Map(Box::new(to_entries.clone()), sorted),
+        array.len(),
+        data.null_buffer().cloned(),
+        array.offset(),
+        // reuse offset buffer
+        data.buffers().to_vec(),
+        vec![cast_entries.into_data()],
+    )?;
+    Ok(Arc::new(MapArray::from(array_data)) as ArrayRef)
+}
+
+/// Helper function to cast a `FixedSizeListArray` to a `GenericListArray`, reusing the
+/// child values and only computing new offsets.
+fn cast_fixed_size_list_to_list<OffsetSize: OffsetSizeTrait>(
+    array: &ArrayRef,
+    to: &Field,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let size = list.value_length() as usize;
+    let len = array.len() * size;
+
+    if OffsetSize::from_usize(len).is_none() {
+        return Err(ArrowError::ComputeError(
+            "FixedSizeList too large to cast to List".into(),
+        ));
+    }
+
+    let offsets = (0..=array.len()).map(|i| OffsetSize::from_usize(i * size).unwrap());
+    // SAFETY: a range produces a trusted length iterator
+    let offsets = unsafe { Buffer::from_trusted_len_iter(offsets) };
+
+    // Only cast the values referenced by the slice of `array`
+    let values = list.values().slice(array.offset() * size, len);
+    let values = if values.data_type() == to.data_type() {
+        values
+    } else {
+        cast_internal(&values, to.data_type(), cast_options)?
+    };
+
+    let builder = ArrayData::builder(to_type.clone())
+        .len(array.len())
+        .add_buffer(offsets)
+        .add_child_data(values.into_data())
+        .null_bit_buffer(
+            array
+                .data_ref()
+                .null_buffer()
+                .map(|b| b.bit_slice(array.offset(), array.len())),
+        );
+
+    // SAFETY: the offsets are monotonically increasing and bounded by the child length
+    let array_data = unsafe { builder.build_unchecked() };
+    Ok(make_array(array_data))
+}
+
+/// Helper function to cast a `GenericListArray` to a `FixedSizeListArray` of `size`.
+///
+/// The child values are reused if every list has length `size`, otherwise they are copied.
+/// Lists of a different length are null if `cast_options.safe` is true, or return an error.
+fn cast_list_to_fixed_size_list<OffsetSize: OffsetSizeTrait>(
+    array: &ArrayRef,
+    to: &Field,
+    size: i32,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = as_generic_list_array::<OffsetSize>(array);
+    let offsets = list.value_offsets();
+    let values = list.values();
+    let len = size as usize;
+
+    let fixed = offsets.windows(2).all(|w| (w[1] - w[0]).as_usize() == len);
+    let (values, nulls) = if fixed {
+        let nulls = list
+            .data_ref()
+            .null_buffer()
+            .map(|b| b.bit_slice(list.offset(), list.len()));
+        (values.slice(offsets[0].as_usize(), list.len() * len), nulls)
+    } else {
+        let mut mutable =
+            MutableArrayData::new(vec![values.data_ref()], true, list.len() * len);
+        let mut nulls = BooleanBufferBuilder::new(list.len());
+
+        for (idx, w) in offsets.windows(2).enumerate() {
+            let (start, end) = (w[0].as_usize(), w[1].as_usize());
+            if list.is_valid(idx) && end - start == len {
+                mutable.extend(0, start, end);
+                nulls.append(true);
+            } else if list.is_null(idx) || cast_options.safe {
+                mutable.extend_nulls(len);
+                nulls.append(false);
+            } else {
+                return Err(ArrowError::CastError(format!(
+                    "Cannot cast to FixedSizeList({size}): value has length {}",
+                    end - start
+                )));
+            }
+        }
+        (make_array(mutable.freeze()), Some(nulls.finish()))
+    };
+
+    let values = if values.data_type() == to.data_type() {
+        values
+    } else {
+        cast_internal(&values, to.data_type(), cast_options)?
+    };
+
+    // Nulls in the child are only permitted where the list slot itself is null
+    if !to.is_nullable() && values.null_count() != 0 {
+        let is_valid = |idx: usize| match &nulls {
+            Some(nulls) => bit_util::get_bit(nulls.as_slice(), idx),
+            None => true,
+        };
+        if (0..values.len()).any(|i| values.is_null(i) && is_valid(i / len)) {
+            return Err(ArrowError::CastError(format!(
+                "Cannot cast to FixedSizeList({size}) with non-nullable child: value contains nulls"
+            )));
+        }
+    }
+
+    let builder = ArrayData::builder(DataType::FixedSizeList(Box::new(to.clone()), size))
+        .len(list.len())
+        .add_child_data(values.into_data())
+        .null_bit_buffer(nulls);
+
+    // SAFETY: the child contains exactly `size` values for each slot
+    let array_data = unsafe { builder.build_unchecked() };
+    Ok(make_array(array_data))
+}
+
+/// Helper function that takes a `FixedSizeListArray` and casts the inner datatype.
+fn cast_fixed_size_list_inner(
+    array: &ArrayRef,
+    to: &Field,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let list = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let size = list.value_length() as usize;
+    let values = list
+        .values()
+        .slice(array.offset() * size, array.len() * size);
+    let values = cast_internal(&values, to.data_type(), cast_options)?;
+
+    let builder = ArrayData::builder(to_type.clone())
+        .len(array.len())
+        .add_child_data(values.into_data())
+        .null_bit_buffer(
+            array
+                .data_ref()
+                .null_buffer()
+                .map(|b| b.bit_slice(array.offset(), array.len())),
+        );
+
+    // SAFETY: casting preserves the length of the child values
+    let array_data = unsafe { builder.build_unchecked() };
+    Ok(make_array(array_data))
+}
+
 /// A specified helper to cast from `GenericBinaryArray` to `GenericStringArray` when they have same
 /// offset size so re-encoding offset is unnecessary.
 fn cast_binary_to_string<O>(
@@ -3406,50 +4557,57 @@ where
         .downcast_ref::<GenericByteArray<GenericBinaryType<O>>>()
         .unwrap();
 
-    if !cast_options.safe {
-        let offsets = array.value_offsets();
-        let values = array.value_data();
-
-        // We only need to validate that all values are valid UTF-8
-        let validated = std::str::from_utf8(values)
-            .map_err(|_| ArrowError::CastError("Invalid UTF-8 sequence".to_string()))?;
-        // Checks if the offsets are valid but does not re-encode
-        for offset in offsets.iter() {
-            if !validated.is_char_boundary(offset.as_usize()) {
-                return Err(ArrowError::CastError("Invalid UTF-8 sequence".to_string()));
+    match cast_options.invalid_utf8() {
+        InvalidUtf8Policy::Replace => {
+            return Ok(cast_binary_to_string_lossy::<O, O>(array))
+        }
+        InvalidUtf8Policy::Null => {}
+        InvalidUtf8Policy::Error => {
+            let offsets = array.value_offsets();
+            let start = offsets[0].as_usize();
+            let values = &array.value_data()[start..offsets[array.len()].as_usize()];
+
+            // We only need to validate that the values of the array are valid UTF-8
+            let validated =
+                std::str::from_utf8(values).map_err(|_| invalid_utf8_error(array))?;
+            // Checks if the offsets are valid but does not re-encode
+            for offset in offsets.iter() {
+                if !validated.is_char_boundary(offset.as_usize() - start) {
+                    return Err(invalid_utf8_error(array));
+                }
             }
+
+            let builder = array
+                .into_data()
+                .into_builder()
+                .data_type(GenericStringArray::<O>::DATA_TYPE);
+            // SAFETY:
+            // Validated UTF-8 above
+            return Ok(Arc::new(GenericStringArray::<O>::from(unsafe {
+                builder.build_unchecked()
+            })));
         }
+    }
 
-        let builder = array
-            .into_data()
-            .into_builder()
-            .data_type(GenericStringArray::<O>::DATA_TYPE);
-        // SAFETY:
-        // Validated UTF-8 above
-        Ok(Arc::new(GenericStringArray::<O>::from(unsafe {
-            builder.build_unchecked()
-        })))
-    } else {
-        let mut null_builder = BooleanBufferBuilder::new(array.len());
-        array.iter().for_each(|maybe_value| {
-            null_builder.append(
-                maybe_value
-                    .and_then(|value| std::str::from_utf8(value).ok())
-                    .is_some(),
-            );
-        });
+    let mut null_builder = BooleanBufferBuilder::new(array.len());
+    array.iter().for_each(|maybe_value| {
+        null_builder.append(
+            maybe_value
+                .and_then(|value| std::str::from_utf8(value).ok())
+                .is_some(),
+        );
+    });
 
-        let builder = array
-            .into_data()
-            .into_builder()
-            .null_bit_buffer(Some(null_builder.finish()))
-            .data_type(GenericStringArray::<O>::DATA_TYPE);
-        // SAFETY:
-        // Validated UTF-8 above
-        Ok(Arc::new(GenericStringArray::<O>::from(unsafe {
-            builder.build_unchecked()
-        })))
-    }
+    let builder = array
+        .into_data()
+        .into_builder()
+        .null_bit_buffer(Some(null_builder.finish()))
+        .data_type(GenericStringArray::<O>::DATA_TYPE);
+    // SAFETY:
+    // Validated UTF-8 above
+    Ok(Arc::new(GenericStringArray::<O>::from(unsafe {
+        builder.build_unchecked()
+    })))
 }
 
 /// Helper function to cast from `GenericBinaryArray` to `GenericStringArray`. This function performs
@@ -3468,62 +4626,91 @@ where
         .downcast_ref::<GenericByteArray<GenericBinaryType<I>>>()
         .unwrap();
 
-    if !cast_options.safe {
-        let offsets = array.value_offsets();
-        let values = array.value_data();
-
-        // We only need to validate that all values are valid UTF-8
-        let validated = std::str::from_utf8(values)
-            .map_err(|_| ArrowError::CastError("Invalid UTF-8 sequence".to_string()))?;
-
-        let mut offset_builder = BufferBuilder::<O>::new(offsets.len());
-        // Checks if the offset is a valid char boundary and re-encode the offset
-        offsets
-            .iter()
-            .try_for_each::<_, Result<_, ArrowError>>(|offset| {
-                if !validated.is_char_boundary(offset.as_usize()) {
-                    return Err(ArrowError::CastError(
-                        "Invalid UTF-8 sequence".to_string(),
-                    ));
-                }
-
-                let offset = <O as NumCast>::from(*offset).ok_or_else(|| {
-                    ArrowError::ComputeError(format!(
-                        "{}Binary array too large to cast to {}String array",
-                        I::PREFIX,
-                        O::PREFIX
-                    ))
-                })?;
-                offset_builder.append(offset);
-                Ok(())
-            })?;
-
-        let offset_buffer = offset_builder.finish();
-
-        let builder = ArrayData::builder(GenericStringArray::<O>::DATA_TYPE)
-            .len(array.len())
-            .add_buffer(offset_buffer)
-            .add_buffer(array.data().buffers()[1].clone())
-            .null_count(array.null_count())
-            .null_bit_buffer(array.data().null_buffer().cloned());
-
-        // SAFETY:
-        // Validated UTF-8 above
-        Ok(Arc::new(GenericStringArray::<O>::from(unsafe {
-            builder.build_unchecked()
-        })))
-    } else {
-        Ok(Arc::new(
+    match cast_options.invalid_utf8() {
+        InvalidUtf8Policy::Replace => Ok(cast_binary_to_string_lossy::<I, O>(array)),
+        InvalidUtf8Policy::Null => Ok(Arc::new(
             array
                 .iter()
                 .map(|maybe_value| {
                     maybe_value.and_then(|value| std::str::from_utf8(value).ok())
                 })
                 .collect::<GenericByteArray<GenericStringType<O>>>(),
-        ))
+        )),
+        InvalidUtf8Policy::Error => {
+            let offsets = array.value_offsets();
+            let start = offsets[0].as_usize();
+            let values = &array.value_data()[start..offsets[array.len()].as_usize()];
+
+            // We only need to validate that the values of the array are valid UTF-8
+            let validated =
+                std::str::from_utf8(values).map_err(|_| invalid_utf8_error(array))?;
+
+            let mut offset_builder = BufferBuilder::<O>::new(offsets.len());
+            // Checks if the offset is a valid char boundary and re-encode the offset
+            offsets
+                .iter()
+                .try_for_each::<_, Result<_, ArrowError>>(|offset| {
+                    if !validated.is_char_boundary(offset.as_usize() - start) {
+                        return Err(invalid_utf8_error(array));
+                    }
+
+                    let offset = <O as NumCast>::from(*offset).ok_or_else(|| {
+                        ArrowError::ComputeError(format!(
+                            "{}Binary array too large to cast to {}String array",
+                            I::PREFIX,
+                            O::PREFIX
+                        ))
+                    })?;
+                    offset_builder.append(offset);
+                    Ok(())
+                })?;
+
+            let offset_buffer = offset_builder.finish();
+
+            let builder = ArrayData::builder(GenericStringArray::<O>::DATA_TYPE)
+                .len(array.len())
+                .add_buffer(offset_buffer)
+                .add_buffer(array.data().buffers()[1].clone())
+                .null_count(array.null_count())
+                .null_bit_buffer(array.data().null_buffer().cloned());
+
+            // SAFETY:
+            // Validated UTF-8 above
+            Ok(Arc::new(GenericStringArray::<O>::from(unsafe {
+                builder.build_unchecked()
+            })))
+        }
     }
 }
 
+/// Helper function to cast from `GenericBinaryArray` to `GenericStringArray`, replacing
+/// invalid UTF-8 sequences with `U+FFFD REPLACEMENT CHARACTER`
+fn cast_binary_to_string_lossy<I, O>(array: &GenericBinaryArray<I>) -> ArrayRef
+where
+    I: OffsetSizeTrait,
+    O: OffsetSizeTrait,
+{
+    Arc::new(
+        array
+            .iter()
+            .map(|maybe_value| maybe_value.map(String::from_utf8_lossy))
+            .collect::<GenericStringArray<O>>(),
+    )
+}
+
+/// Returns an error describing the first value of `array` that is not valid UTF-8
+fn invalid_utf8_error<O: OffsetSizeTrait>(array: &GenericBinaryArray<O>) -> ArrowError {
+    array
+        .iter()
+        .find_map(|value| {
+            let err = std::str::from_utf8(value?).err()?;
+            Some(ArrowError::CastError(format!(
+                "Invalid UTF-8 sequence: {err}"
+            )))
+        })
+        .unwrap_or_else(|| ArrowError::CastError("Invalid UTF-8 sequence".to_string()))
+}
+
 /// Helper function to cast from one `ByteArrayType` to another and vice versa.
 /// If the target one (e.g., `LargeUtf8`) is too large for the source array it will return an Error.
 fn cast_byte_container<FROM, TO, N: ?Sized>(
@@ -3676,7 +4863,10 @@ mod tests {
                 }
             }
 
-            let cast_option = CastOptions { safe: false };
+            let cast_option = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let casted_array_with_option =
                 cast_with_options($INPUT_ARRAY, $OUTPUT_TYPE, &cast_option).unwrap();
             let result_array = casted_array_with_option
@@ -3889,9 +5079,15 @@ mod tests {
         let array = vec![Some(i128::MAX)];
         let input_decimal_array = create_decimal_array(array, 38, 3).unwrap();
         let array = Arc::new(input_decimal_array) as ArrayRef;
-        let result =
-            cast_with_options(&array, &output_type, &CastOptions { safe: false });
-        assert_eq!("Cast error: Cannot cast to Decimal128(38, 38). Overflowing on 170141183460469231731687303715884105727",
+        let result = cast_with_options(
+            &array,
+            &output_type,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!("Cast error: Cannot cast '17014118346046923173168730371588410.572' at row 0 to Decimal128(38, 38): Cannot cast to Decimal128(38, 38). Overflowing on 170141183460469231731687303715884105727",
                    result.unwrap_err().to_string());
     }
 
@@ -3904,9 +5100,15 @@ mod tests {
         let array = vec![Some(i128::MAX)];
         let input_decimal_array = create_decimal_array(array, 38, 3).unwrap();
         let array = Arc::new(input_decimal_array) as ArrayRef;
-        let result =
-            cast_with_options(&array, &output_type, &CastOptions { safe: false });
-        assert_eq!("Cast error: Cannot cast to Decimal256(76, 76). Overflowing on 170141183460469231731687303715884105727",
+        let result = cast_with_options(
+            &array,
+            &output_type,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!("Cast error: Cannot cast '17014118346046923173168730371588410.572' at row 0 to Decimal256(76, 76): Cannot cast to Decimal256(76, 76). Overflowing on 170141183460469231731687303715884105727",
                    result.unwrap_err().to_string());
     }
 
@@ -3939,9 +5141,15 @@ mod tests {
         let array = vec![Some(i256::from_i128(i128::MAX))];
         let input_decimal_array = create_decimal256_array(array, 76, 5).unwrap();
         let array = Arc::new(input_decimal_array) as ArrayRef;
-        let result =
-            cast_with_options(&array, &output_type, &CastOptions { safe: false });
-        assert_eq!("Cast error: Cannot cast to Decimal128(38, 7). Overflowing on 170141183460469231731687303715884105727",
+        let result = cast_with_options(
+            &array,
+            &output_type,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!("Cast error: Cannot cast '1701411834604692317316873037158841.05727' at row 0 to Decimal128(38, 7): Cannot cast to Decimal128(38, 7). Overflowing on 170141183460469231731687303715884105727",
                    result.unwrap_err().to_string());
     }
 
@@ -3953,9 +5161,15 @@ mod tests {
         let array = vec![Some(i256::from_i128(i128::MAX))];
         let input_decimal_array = create_decimal256_array(array, 76, 5).unwrap();
         let array = Arc::new(input_decimal_array) as ArrayRef;
-        let result =
-            cast_with_options(&array, &output_type, &CastOptions { safe: false });
-        assert_eq!("Cast error: Cannot cast to Decimal256(76, 55). Overflowing on 170141183460469231731687303715884105727",
+        let result = cast_with_options(
+            &array,
+            &output_type,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!("Cast error: Cannot cast '1701411834604692317316873037158841.05727' at row 0 to Decimal256(76, 55): Cannot cast to Decimal256(76, 55). Overflowing on 170141183460469231731687303715884105727",
                    result.unwrap_err().to_string());
     }
 
@@ -4104,15 +5318,27 @@ mod tests {
         let value_array: Vec<Option<i128>> = vec![Some(51300)];
         let decimal_array = create_decimal_array(value_array, 38, 2).unwrap();
         let array = Arc::new(decimal_array) as ArrayRef;
-        let casted_array =
-            cast_with_options(&array, &DataType::UInt8, &CastOptions { safe: false });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::UInt8,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
         assert_eq!(
-            "Cast error: value of 513 is out of range UInt8".to_string(),
+            "Cast error: Cannot cast '513.00' at row 0 to UInt8: value of 513 is out of range UInt8".to_string(),
             casted_array.unwrap_err().to_string()
         );
 
-        let casted_array =
-            cast_with_options(&array, &DataType::UInt8, &CastOptions { safe: true });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::UInt8,
+            &CastOptions {
+                safe: true,
+                ..Default::default()
+            },
+        );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
 
@@ -4120,15 +5346,27 @@ mod tests {
         let value_array: Vec<Option<i128>> = vec![Some(24400)];
         let decimal_array = create_decimal_array(value_array, 38, 2).unwrap();
         let array = Arc::new(decimal_array) as ArrayRef;
-        let casted_array =
-            cast_with_options(&array, &DataType::Int8, &CastOptions { safe: false });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Int8,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
         assert_eq!(
-            "Cast error: value of 244 is out of range Int8".to_string(),
+            "Cast error: Cannot cast '244.00' at row 0 to Int8: value of 244 is out of range Int8".to_string(),
             casted_array.unwrap_err().to_string()
         );
 
-        let casted_array =
-            cast_with_options(&array, &DataType::Int8, &CastOptions { safe: true });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Int8,
+            &CastOptions {
+                safe: true,
+                ..Default::default()
+            },
+        );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
 
@@ -4288,15 +5526,27 @@ mod tests {
         let value_array: Vec<Option<i256>> = vec![Some(i256::from_i128(24400))];
         let decimal_array = create_decimal256_array(value_array, 38, 2).unwrap();
         let array = Arc::new(decimal_array) as ArrayRef;
-        let casted_array =
-            cast_with_options(&array, &DataType::Int8, &CastOptions { safe: false });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Int8,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
         assert_eq!(
-            "Cast error: value of 244 is out of range Int8".to_string(),
+            "Cast error: Cannot cast '244.00' at row 0 to Int8: value of 244 is out of range Int8".to_string(),
             casted_array.unwrap_err().to_string()
         );
 
-        let casted_array =
-            cast_with_options(&array, &DataType::Int8, &CastOptions { safe: true });
+        let casted_array = cast_with_options(
+            &array,
+            &DataType::Int8,
+            &CastOptions {
+                safe: true,
+                ..Default::default()
+            },
+        );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
 
@@ -4702,6 +5952,33 @@ mod tests {
         assert_eq!(9.0, c.value(4));
     }
 
+    #[test]
+    fn test_cast_f16() {
+        let a = Int32Array::from(vec![Some(5), None, Some(-7), Some(100000)]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Float16).unwrap();
+        assert_eq!(b.data_type(), &DataType::Float16);
+        assert!(b.is_null(1));
+
+        // 100000 overflows f16 to infinity
+        let c = cast(&b, &DataType::Float64).unwrap();
+        let c = c.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(5.0, c.value(0));
+        assert_eq!(-7.0, c.value(2));
+        assert!(c.value(3).is_infinite());
+
+        let c = cast(&b, &DataType::Int8).unwrap();
+        let c = c.as_any().downcast_ref::<Int8Array>().unwrap();
+        assert_eq!(c, &Int8Array::from(vec![Some(5), None, Some(-7), None]));
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&b, &DataType::Int8, &options).unwrap_err();
+        assert!(err.to_string().contains("Can't cast value"), "{}", err);
+    }
+
     #[test]
     fn test_cast_i32_to_u8() {
         let a = Int32Array::from(vec![-5, 6, -7, 8, 100000000]);
@@ -4722,7 +5999,10 @@ mod tests {
         let a = Int32Array::from(vec![-5, 6, -7, 8, 100000000]);
         let array = Arc::new(a) as ArrayRef;
         // overflow with the error
-        let cast_option = CastOptions { safe: false };
+        let cast_option = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
         let result = cast_with_options(&array, &DataType::UInt8, &cast_option);
         assert!(result.is_err());
         result.unwrap();
@@ -4854,14 +6134,20 @@ mod tests {
     fn test_cast_with_options_utf8_to_i32() {
         let a = StringArray::from(vec!["5", "6", "seven", "8", "9.1"]);
         let array = Arc::new(a) as ArrayRef;
-        let result =
-            cast_with_options(&array, &DataType::Int32, &CastOptions { safe: false });
+        let result = cast_with_options(
+            &array,
+            &DataType::Int32,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
         match result {
             Ok(_) => panic!("expected error"),
             Err(e) => {
                 assert!(
                     e.to_string().contains(
-                        "Cast error: Cannot cast string 'seven' to value of Int32 type",
+                        "Cast error: Cannot cast 'seven' at row 2 to Int32: Cannot cast string 'seven' to value of Int32 type",
                     ),
                     "Error: {e}"
                 )
@@ -4869,6 +6155,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cast_error_row_index() {
+        let array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("1"),
+            None,
+            Some("2"),
+            Some("abc"),
+        ]));
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &DataType::Int32, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 'abc' at row 3 to Int32: Cannot cast string 'abc' to value of Int32 type"
+        );
+
+        // Row index is relative to the start of a sliced array
+        let sliced = array.slice(2, 2);
+        let err = cast_with_options(&sliced, &DataType::Int32, &options).unwrap_err();
+        assert!(err.to_string().contains("'abc' at row 1 to Int32"), "{err}");
+
+        // Overflow errors report the row of the first value that overflows
+        let array: ArrayRef = Arc::new(Int64Array::from(vec![1, 2, 300, 400]));
+        let err = cast_with_options(&array, &DataType::Int8, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast '300' at row 2 to Int8: Can't cast value 300 to type Int8"
+        );
+
+        let to_type = DataType::Decimal128(38, 37);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("'300' at row 2 to Decimal128(38, 37)"));
+    }
+
     #[test]
     fn test_cast_utf8_to_bool() {
         let strings = Arc::new(StringArray::from(vec![
@@ -4880,18 +6204,62 @@ mod tests {
         assert_eq!(*as_boolean_array(&casted), expected);
     }
 
+    #[test]
+    fn test_cast_utf8_to_bool_custom_values() {
+        let strings = Arc::new(StringArray::from(vec![
+            Some("Oui"),
+            Some("NON"),
+            Some(" oui "),
+            Some("true"),
+            Some("0"),
+            None,
+        ])) as ArrayRef;
+        let options = CastOptions {
+            true_values: vec!["oui".to_string()],
+            false_values: vec!["non".to_string(), "0".to_string()],
+            ..Default::default()
+        };
+        let casted = cast_with_options(&strings, &DataType::Boolean, &options).unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+            None,
+        ]);
+        assert_eq!(*as_boolean_array(&casted), expected);
+
+        let options = CastOptions {
+            safe: false,
+            ..options
+        };
+        let err = cast_with_options(&strings, &DataType::Boolean, &options).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Cannot cast value 'true' to value of Boolean type"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_cast_with_options_utf8_to_bool() {
         let strings = Arc::new(StringArray::from(vec![
             "true", "false", "invalid", " Y ", "",
         ])) as ArrayRef;
-        let casted =
-            cast_with_options(&strings, &DataType::Boolean, &CastOptions { safe: false });
+        let casted = cast_with_options(
+            &strings,
+            &DataType::Boolean,
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
+        );
         match casted {
             Ok(_) => panic!("expected error"),
             Err(e) => {
                 assert!(e.to_string().contains(
-                    "Cast error: Cannot cast value 'invalid' to value of Boolean type"
+                    "Cast error: Cannot cast 'invalid' at row 2 to Boolean: Cannot cast value 'invalid' to value of Boolean type"
                 ))
             }
         }
@@ -5067,11 +6435,14 @@ mod tests {
             assert!(c.is_null(1));
             assert!(c.is_null(2));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
                 err.to_string(),
-                "Cast error: Error parsing 'Not a valid date' as timestamp"
+                "Cast error: Cannot cast 'Not a valid date' at row 1 to Timestamp(Nanosecond, None): Error parsing 'Not a valid date' as timestamp"
             );
         }
     }
@@ -5096,12 +6467,71 @@ mod tests {
             assert!(c.is_null(1));
             assert!(c.is_null(2));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid date' to value of Date32 type");
+            assert_eq!(err.to_string(), "Cast error: Cannot cast 'Not a valid date' at row 1 to Date32: Cannot cast string 'Not a valid date' to value of Date32 type");
         }
     }
 
+    #[test]
+    fn test_cast_string_to_temporal_with_formats() {
+        let array = Arc::new(StringArray::from(vec![
+            Some("25/12/2018 10:30"),
+            Some("25/12/2018"),
+            Some("2018-12-25T10:30:00+02:00"),
+            Some("25/12/2018 10:30 +0100"),
+            Some("Not a valid date"),
+            None,
+        ])) as ArrayRef;
+        let options = CastOptions {
+            datetime_formats: vec![
+                "%d/%m/%Y %H:%M %z".to_string(),
+                "%d/%m/%Y %H:%M".to_string(),
+                "%d/%m/%Y".to_string(),
+            ],
+            ..Default::default()
+        };
+
+        let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(c.value(0), 1545733800000000000);
+        assert_eq!(c.value(1), 1545696000000000000);
+        assert_eq!(c.value(2), 1545726600000000000);
+        assert_eq!(c.value(3), 1545730200000000000);
+        assert!(c.is_null(4));
+        assert!(c.is_null(5));
+
+        let b = cast_with_options(&array, &DataType::Date32, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(c.value(0), 17890);
+        assert_eq!(c.value(1), 17890);
+        assert!(c.is_null(2));
+        assert!(c.is_null(4));
+
+        let b = cast_with_options(&array, &DataType::Date64, &options).unwrap();
+        let c = b.as_any().downcast_ref::<Date64Array>().unwrap();
+        assert_eq!(c.value(0), 1545733800000);
+        assert_eq!(c.value(1), 1545696000000);
+        assert!(c.is_null(4));
+
+        let options = CastOptions {
+            safe: false,
+            ..options
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 'Not a valid date' at row 4 to Timestamp(Nanosecond, None): Error parsing 'Not a valid date' as timestamp"
+        );
+    }
+
     #[test]
     fn test_cast_string_to_time32second() {
         let a1 = Arc::new(StringArray::from(vec![
@@ -5128,9 +6558,12 @@ mod tests {
             assert!(c.is_null(3));
             assert!(c.is_null(4));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
+            assert_eq!(err.to_string(), "Cast error: Cannot cast '08:08:61.091323414' at row 2 to Time32(Second): Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
         }
     }
 
@@ -5160,9 +6593,12 @@ mod tests {
             assert!(c.is_null(3));
             assert!(c.is_null(4));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
+            assert_eq!(err.to_string(), "Cast error: Cannot cast '08:08:61.091323414' at row 2 to Time32(Millisecond): Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
         }
     }
 
@@ -5186,9 +6622,12 @@ mod tests {
             assert!(c.is_null(1));
             assert!(c.is_null(2));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
+            assert_eq!(err.to_string(), "Cast error: Cannot cast 'Not a valid time' at row 1 to Time64(Microsecond): Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
         }
     }
 
@@ -5212,9 +6651,12 @@ mod tests {
             assert!(c.is_null(1));
             assert!(c.is_null(2));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
+            assert_eq!(err.to_string(), "Cast error: Cannot cast 'Not a valid time' at row 1 to Time64(Nanosecond): Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
         }
     }
 
@@ -5238,9 +6680,12 @@ mod tests {
             assert!(c.is_null(1));
             assert!(c.is_null(2));
 
-            let options = CastOptions { safe: false };
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
-            assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid date' to value of Date64 type");
+            assert_eq!(err.to_string(), "Cast error: Cannot cast 'Not a valid date' at row 1 to Date64: Cannot cast string 'Not a valid date' to value of Date64 type");
         }
     }
 
@@ -5272,6 +6717,63 @@ mod tests {
         assert!(down_cast.is_null(2));
     }
 
+    #[test]
+    fn test_cast_binary_to_string_invalid_utf8() {
+        let data: Vec<Option<&[u8]>> =
+            vec![Some(b"foo"), None, Some(b"ba\xffr"), Some(b"\xe2\x82\xac")];
+        let binary = Arc::new(BinaryArray::from(data.clone())) as ArrayRef;
+        let large_binary = Arc::new(LargeBinaryArray::from(data)) as ArrayRef;
+
+        for array in [&binary, &large_binary] {
+            for to_type in [DataType::Utf8, DataType::LargeUtf8] {
+                // Invalid values are null by default
+                let result = cast(array, &to_type).unwrap();
+                assert_eq!(array_to_strings(&result), vec!["foo", "null", "null", "€"]);
+
+                let options = CastOptions {
+                    invalid_utf8: Some(InvalidUtf8Policy::Replace),
+                    ..Default::default()
+                };
+                let result = cast_with_options(array, &to_type, &options).unwrap();
+                assert_eq!(result.data_type(), &to_type);
+                assert_eq!(
+                    array_to_strings(&result),
+                    vec!["foo", "null", "ba\u{FFFD}r", "€"]
+                );
+
+                let options = CastOptions {
+                    invalid_utf8: Some(InvalidUtf8Policy::Error),
+                    ..Default::default()
+                };
+                let err = cast_with_options(array, &to_type, &options).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    format!("Cast error: Cannot cast '6261ff72' at row 2 to {to_type}: Invalid UTF-8 sequence: invalid utf-8 sequence of 1 bytes from index 2")
+                );
+
+                // Without a policy, `safe: false` returns the same error
+                let options = CastOptions {
+                    safe: false,
+                    ..Default::default()
+                };
+                let err = cast_with_options(array, &to_type, &options).unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    format!("Cast error: Cannot cast '6261ff72' at row 2 to {to_type}: Invalid UTF-8 sequence: invalid utf-8 sequence of 1 bytes from index 2")
+                );
+
+                // An explicit policy takes precedence over `safe`
+                let options = CastOptions {
+                    safe: false,
+                    invalid_utf8: Some(InvalidUtf8Policy::Null),
+                    ..Default::default()
+                };
+                let result = cast_with_options(array, &to_type, &options).unwrap();
+                assert_eq!(array_to_strings(&result), vec!["foo", "null", "null", "€"]);
+            }
+        }
+    }
+
     #[test]
     fn test_cast_date32_to_int32() {
         let a = Date32Array::from(vec![10000, 17890]);
@@ -5622,6 +7124,163 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cast_duration_interval() {
+        let array = DurationMillisecondArray::from(vec![
+            Some(1500),
+            None,
+            Some(-3),
+            Some(i64::MAX),
+        ]);
+        let array = Arc::new(array) as ArrayRef;
+        let to_type = DataType::Interval(IntervalUnit::MonthDayNano);
+        assert!(can_cast_types(array.data_type(), &to_type));
+
+        let interval = cast(&array, &to_type).unwrap();
+        let interval = as_primitive_array::<IntervalMonthDayNanoType>(&interval);
+        assert_eq!(
+            interval,
+            &IntervalMonthDayNanoArray::from(vec![
+                Some(IntervalMonthDayNanoType::make_value(0, 0, 1_500_000_000)),
+                None,
+                Some(IntervalMonthDayNanoType::make_value(0, 0, -3_000_000)),
+                None,
+            ])
+        );
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast 'P106751991167DT25975.807S' at row 3 to Interval(MonthDayNano): Can't cast value 9223372036854775807 to type Interval(MonthDayNano)"
+        );
+
+        // Round trip back to a duration
+        let interval = Arc::new(interval.clone()) as ArrayRef;
+        let to_type = DataType::Duration(TimeUnit::Millisecond);
+        let duration = cast(&interval, &to_type).unwrap();
+        let duration = as_primitive_array::<DurationMillisecondType>(&duration);
+        assert_eq!(
+            duration,
+            &DurationMillisecondArray::from(vec![Some(1500), None, Some(-3), None])
+        );
+
+        // Intervals with months, days or sub-unit nanoseconds are lossy
+        let interval = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNanoType::make_value(0, 0, 2_000_000_000),
+            IntervalMonthDayNanoType::make_value(1, 0, 0),
+            IntervalMonthDayNanoType::make_value(0, 1, 0),
+            IntervalMonthDayNanoType::make_value(0, 0, 1),
+        ]);
+        let interval = Arc::new(interval) as ArrayRef;
+        let to_type = DataType::Duration(TimeUnit::Second);
+        assert!(can_cast_types(interval.data_type(), &to_type));
+
+        let duration = cast(&interval, &to_type).unwrap();
+        let duration = as_primitive_array::<DurationSecondType>(&duration);
+        assert_eq!(
+            duration,
+            &DurationSecondArray::from(vec![Some(2), None, None, None])
+        );
+
+        let err = cast_with_options(&interval, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast '0 years 1 mons 0 days 0 hours 0 mins 0.000000000 secs' at row 1 to Duration(Second): Can't cast value 0 years 1 mons 0 days 0 hours 0 mins 0.000000000 secs to type Duration(Second)"
+        );
+    }
+
+    #[test]
+    fn test_cast_interval_units() {
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let month_day_nano = DataType::Interval(IntervalUnit::MonthDayNano);
+        let day_time = DataType::Interval(IntervalUnit::DayTime);
+
+        let array = IntervalYearMonthArray::from(vec![Some(14), None, Some(-1)]);
+        let array = Arc::new(array) as ArrayRef;
+        assert!(can_cast_types(array.data_type(), &month_day_nano));
+        let interval = cast(&array, &month_day_nano).unwrap();
+        assert_eq!(
+            as_primitive_array::<IntervalMonthDayNanoType>(&interval),
+            &IntervalMonthDayNanoArray::from(vec![
+                Some(IntervalMonthDayNanoType::make_value(14, 0, 0)),
+                None,
+                Some(IntervalMonthDayNanoType::make_value(-1, 0, 0)),
+            ])
+        );
+
+        let array = IntervalDayTimeArray::from(vec![
+            Some(IntervalDayTimeType::make_value(1, -1500)),
+            None,
+            Some(IntervalDayTimeType::make_value(0, 3000)),
+        ]);
+        let array = Arc::new(array) as ArrayRef;
+        assert!(can_cast_types(array.data_type(), &month_day_nano));
+        let interval = cast(&array, &month_day_nano).unwrap();
+        assert_eq!(
+            as_primitive_array::<IntervalMonthDayNanoType>(&interval),
+            &IntervalMonthDayNanoArray::from(vec![
+                Some(IntervalMonthDayNanoType::make_value(0, 1, -1_500_000_000)),
+                None,
+                Some(IntervalMonthDayNanoType::make_value(0, 0, 3_000_000_000)),
+            ])
+        );
+
+        // Round trip back to IntervalDayTime
+        assert!(can_cast_types(interval.data_type(), &day_time));
+        let round_trip = cast_with_options(&interval, &day_time, &options).unwrap();
+        assert_eq!(&round_trip, &array);
+
+        // IntervalDayTime to duration errors on day components
+        let to_type = DataType::Duration(TimeUnit::Second);
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let duration = cast(&array, &to_type).unwrap();
+        assert_eq!(
+            as_primitive_array::<DurationSecondType>(&duration),
+            &DurationSecondArray::from(vec![None, None, Some(3)])
+        );
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast '0 years 0 mons 1 days 0 hours 0 mins -1.500 secs' at row 0 to Duration(Second): Can't cast value 0 years 0 mons 1 days 0 hours 0 mins -1.500 secs to type Duration(Second)"
+        );
+
+        let array = DurationMicrosecondArray::from(vec![Some(2000), Some(1), None]);
+        let array = Arc::new(array) as ArrayRef;
+        assert!(can_cast_types(array.data_type(), &day_time));
+        let interval = cast(&array, &day_time).unwrap();
+        assert_eq!(
+            as_primitive_array::<IntervalDayTimeType>(&interval),
+            &IntervalDayTimeArray::from(vec![
+                Some(IntervalDayTimeType::make_value(0, 2)),
+                None,
+                None
+            ])
+        );
+
+        // IntervalMonthDayNano to IntervalDayTime errors on month components,
+        // sub-millisecond nanoseconds and milliseconds overflowing an i32
+        let interval = IntervalMonthDayNanoArray::from(vec![
+            IntervalMonthDayNanoType::make_value(1, 0, 0),
+            IntervalMonthDayNanoType::make_value(0, 0, 1),
+            IntervalMonthDayNanoType::make_value(0, 0, i64::MAX / 1_000_000 * 1_000_000),
+        ]);
+        let interval = Arc::new(interval) as ArrayRef;
+        let result = cast(&interval, &day_time).unwrap();
+        assert_eq!(result.null_count(), 3);
+        let err = cast_with_options(&interval, &day_time, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast '0 years 1 mons 0 days 0 hours 0 mins 0.000000000 secs' at row 0 to Interval(DayTime): Can't cast value 0 years 1 mons 0 days 0 hours 0 mins 0.000000000 secs to type Interval(DayTime)"
+        );
+    }
+
     #[test]
     fn test_cast_to_strings() {
         let a = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
@@ -6788,29 +8447,218 @@ mod tests {
             actual_error.contains(expected_error),
             "did not find expected error '{actual_error}' in actual error '{expected_error}'"
         );
-    }
+    }
+
+    #[test]
+    fn test_cast_dict_to_dict_compacts_values() {
+        use DataType::*;
+
+        // 300 values, of which only 3 are referenced
+        let values: ArrayRef = Arc::new(StringArray::from_iter_values(
+            (0..300).map(|i| format!("val{i}")),
+        ));
+        let keys = Int32Array::from(vec![Some(299), None, Some(150), Some(299), Some(7)]);
+        let array: ArrayRef =
+            Arc::new(DictionaryArray::<Int32Type>::try_new(&keys, &values).unwrap());
+
+        let cast_type = Dictionary(Box::new(Int8), Box::new(LargeUtf8));
+        assert!(can_cast_types(array.data_type(), &cast_type));
+        let cast_array = cast(&array, &cast_type).unwrap();
+        assert_eq!(cast_array.data_type(), &cast_type);
+        let dict = as_dictionary_array::<Int8Type>(&cast_array);
+        assert_eq!(dict.values().len(), 3);
+        assert_eq!(
+            dict.keys(),
+            &Int8Array::from(vec![Some(0), None, Some(1), Some(0), Some(2)])
+        );
+        assert_eq!(
+            array_to_strings(&cast_array),
+            vec!["val299", "null", "val150", "val299", "val7"]
+        );
+
+        // Keys that fit are not re-encoded
+        let cast_type = Dictionary(Box::new(UInt16), Box::new(Utf8));
+        let cast_array = cast(&array, &cast_type).unwrap();
+        let dict = as_dictionary_array::<UInt16Type>(&cast_array);
+        assert_eq!(dict.values().len(), 300);
+        assert_eq!(
+            array_to_strings(&cast_array),
+            vec!["val299", "null", "val150", "val299", "val7"]
+        );
+    }
+
+    #[test]
+    fn test_cast_primitive_dict() {
+        // FROM a dictionary with of INT32 values
+        use DataType::*;
+
+        let mut builder = PrimitiveDictionaryBuilder::<Int8Type, Int32Type>::new();
+        builder.append(1).unwrap();
+        builder.append_null();
+        builder.append(3).unwrap();
+        let array: ArrayRef = Arc::new(builder.finish());
+
+        let expected = vec!["1", "null", "3"];
+
+        // Test casting TO PrimitiveArray, different dictionary type
+        let cast_array = cast(&array, &Utf8).expect("cast to UTF-8 failed");
+        assert_eq!(array_to_strings(&cast_array), expected);
+        assert_eq!(cast_array.data_type(), &Utf8);
+
+        let cast_array = cast(&array, &Int64).expect("cast to int64 failed");
+        assert_eq!(array_to_strings(&cast_array), expected);
+        assert_eq!(cast_array.data_type(), &Int64);
+    }
+
+    #[test]
+    fn test_cast_run_end_encoded_to_dict() {
+        use DataType::*;
+
+        let run_ends = Int32Array::from(vec![2, 3, 6, 7]);
+        let values = Int32Array::from(vec![Some(1), None, Some(3), Some(1)]);
+        let run_array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+        let array: ArrayRef = Arc::new(run_array);
+
+        let cast_type = Dictionary(Box::new(UInt8), Box::new(Int32));
+        assert!(can_cast_types(array.data_type(), &cast_type));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(
+            array_to_strings(&cast_array),
+            vec!["1", "1", "null", "3", "3", "3", "1"]
+        );
+        let dict = as_dictionary_array::<UInt8Type>(&cast_array);
+        assert_eq!(
+            dict.keys(),
+            &UInt8Array::from(vec![
+                Some(0),
+                Some(0),
+                None,
+                Some(2),
+                Some(2),
+                Some(2),
+                Some(3)
+            ])
+        );
+        assert_eq!(dict.values().len(), 4);
+
+        // Casting the dictionary values
+        let cast_type = Dictionary(Box::new(Int16), Box::new(Utf8));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        assert_eq!(
+            array_to_strings(&cast_array),
+            vec!["1", "1", "null", "3", "3", "3", "1"]
+        );
+
+        // Only the runs within the slice are included
+        let sliced = array.slice(1, 4);
+        let cast_type = Dictionary(Box::new(Int32), Box::new(Int32));
+        let cast_array = cast(&sliced, &cast_type).expect("cast failed");
+        assert_eq!(array_to_strings(&cast_array), vec!["1", "null", "3", "3"]);
+        let dict = as_dictionary_array::<Int32Type>(&cast_array);
+        assert_eq!(
+            dict.keys(),
+            &Int32Array::from(vec![Some(0), None, Some(2), Some(2)])
+        );
+        assert_eq!(dict.values().len(), 3);
+
+        let empty = cast(&array.slice(7, 0), &cast_type).expect("cast failed");
+        assert_eq!(empty.len(), 0);
+    }
+
+    #[test]
+    fn test_cast_run_end_encoded_to_dict_key_overflow() {
+        let mut builder = PrimitiveRunBuilder::<Int32Type, Int32Type>::new();
+        for i in 0..200 {
+            builder.append_value(i);
+        }
+        let run_array = builder.finish();
+        let err = run_array_to_dictionary::<Int32Type, Int8Type>(&run_array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot represent 200 runs with dictionary key type Int8"
+        );
+    }
+
+    #[test]
+    fn test_cast_dict_to_run_end_encoded() {
+        use DataType::*;
+
+        let dict: DictionaryArray<Int8Type> = vec![
+            Some("a"),
+            Some("a"),
+            None,
+            None,
+            Some("b"),
+            Some("a"),
+            Some("a"),
+        ]
+        .into_iter()
+        .collect();
+        let array: ArrayRef = Arc::new(dict);
+
+        let cast_type = RunEndEncoded(
+            Box::new(Field::new("run_ends", Int16, false)),
+            Box::new(Field::new("values", Utf8, true)),
+        );
+        assert!(can_cast_types(array.data_type(), &cast_type));
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        let run_array = cast_array
+            .as_any()
+            .downcast_ref::<RunArray<Int16Type>>()
+            .unwrap();
+        assert_eq!(run_array.run_ends().values(), &[2, 4, 5, 7]);
+        let values = as_string_array(run_array.values());
+        assert_eq!(
+            values,
+            &StringArray::from(vec![Some("a"), None, Some("b"), Some("a")])
+        );
 
-    #[test]
-    fn test_cast_primitive_dict() {
-        // FROM a dictionary with of INT32 values
-        use DataType::*;
+        // Round trip back to a dictionary
+        let dict_type = Dictionary(Box::new(Int8), Box::new(Utf8));
+        let round_trip = cast(&cast_array, &dict_type).expect("cast failed");
+        assert_eq!(array_to_strings(&round_trip), array_to_strings(&array));
 
-        let mut builder = PrimitiveDictionaryBuilder::<Int8Type, Int32Type>::new();
-        builder.append(1).unwrap();
-        builder.append_null();
-        builder.append(3).unwrap();
-        let array: ArrayRef = Arc::new(builder.finish());
+        // Casting the run values
+        let cast_type = RunEndEncoded(
+            Box::new(Field::new("run_ends", Int64, false)),
+            Box::new(Field::new("values", LargeUtf8, true)),
+        );
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
 
-        let expected = vec!["1", "null", "3"];
+        // The output uses the fields of the cast type
+        let cast_type = RunEndEncoded(
+            Box::new(Field::new("ends", Int32, false)),
+            Box::new(Field::new("vals", Utf8, true)),
+        );
+        let cast_array = cast(&array, &cast_type).expect("cast failed");
+        assert_eq!(cast_array.data_type(), &cast_type);
+        let run_array = cast_array
+            .as_any()
+            .downcast_ref::<RunArray<Int32Type>>()
+            .unwrap();
+        assert_eq!(run_array.run_ends().values(), &[2, 4, 5, 7]);
 
-        // Test casting TO PrimitiveArray, different dictionary type
-        let cast_array = cast(&array, &Utf8).expect("cast to UTF-8 failed");
-        assert_eq!(array_to_strings(&cast_array), expected);
-        assert_eq!(cast_array.data_type(), &Utf8);
+        let cast_type = RunEndEncoded(
+            Box::new(Field::new("run_ends", Int8, false)),
+            Box::new(Field::new("values", Utf8, true)),
+        );
+        assert!(!can_cast_types(array.data_type(), &cast_type));
+    }
 
-        let cast_array = cast(&array, &Int64).expect("cast to int64 failed");
-        assert_eq!(array_to_strings(&cast_array), expected);
-        assert_eq!(cast_array.data_type(), &Int64);
+    #[test]
+    fn test_cast_dict_to_run_end_encoded_overflow() {
+        let keys = Int32Array::from_iter_values((0..40000).map(|i| i % 2));
+        let values = Int32Array::from(vec![1, 2]);
+        let dict = DictionaryArray::<Int32Type>::try_new(&keys, &values).unwrap();
+        let err = dictionary_to_run_array::<Int32Type, Int16Type>(&dict).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot represent array of length 40000 with run end type Int16"
+        );
     }
 
     #[test]
@@ -6905,6 +8753,275 @@ mod tests {
         typed_test!(Date64Array, Date64, Date64Type);
     }
 
+    #[test]
+    fn test_cast_struct_to_struct() {
+        let a = Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)]));
+        let b = Arc::new(StringArray::from(vec![
+            Some("2023-01-01T00:00:00"),
+            Some("2023-01-02T00:00:00"),
+            None,
+            Some("2023-01-04T00:00:00"),
+        ]));
+        let array = StructArray::from((
+            vec![
+                (Field::new("a", DataType::Int32, true), a as ArrayRef),
+                (Field::new("b", DataType::Utf8, true), b as ArrayRef),
+            ],
+            Buffer::from([0b00001011]),
+        ));
+        let array = Arc::new(array) as ArrayRef;
+
+        let to_type = DataType::Struct(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Timestamp(TimeUnit::Nanosecond, None), true),
+        ]);
+        assert!(can_cast_types(array.data_type(), &to_type));
+
+        let result = cast(&array, &to_type).unwrap();
+        assert_eq!(result.data_type(), &to_type);
+        let result = as_struct_array(&result);
+        assert!(result.is_null(2));
+        assert_eq!(
+            as_primitive_array::<Int64Type>(result.column(0)),
+            &Int64Array::from(vec![Some(1), None, Some(3), Some(4)])
+        );
+        assert_eq!(
+            as_primitive_array::<TimestampNanosecondType>(result.column(1)),
+            &TimestampNanosecondArray::from(vec![
+                Some(1672531200000000000),
+                Some(1672617600000000000),
+                None,
+                Some(1672790400000000000)
+            ])
+        );
+
+        // Slices of the struct retain their offset
+        let sliced = array.slice(1, 3);
+        let result = cast(&sliced, &to_type).unwrap();
+        let result = as_struct_array(&result);
+        assert_eq!(result.len(), 3);
+        assert!(result.is_valid(0));
+        assert!(result.is_null(1));
+        assert_eq!(
+            as_primitive_array::<Int64Type>(result.column(0)),
+            &Int64Array::from(vec![None, Some(3), Some(4)])
+        );
+
+        // The number of fields must match when matching by position
+        let to_type = DataType::Struct(vec![Field::new("c", DataType::Int64, true)]);
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        let err = cast(&array, &to_type).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast struct with 2 fields to struct with 1 fields"
+        );
+
+        let to_type = DataType::Struct(vec![Field::new("c", DataType::Int64, false)]);
+        assert!(!can_cast_types(array.data_type(), &to_type));
+
+        // Values that fail to cast cannot be null in a non-nullable field
+        let b = Arc::new(StringArray::from(vec!["1", "x"])) as ArrayRef;
+        let array = StructArray::from(vec![(Field::new("b", DataType::Utf8, false), b)]);
+        let array = Arc::new(array) as ArrayRef;
+        let to_type = DataType::Struct(vec![Field::new("b", DataType::Int32, false)]);
+        let err = cast(&array, &to_type).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot cast '{\"b\": \"x\"}' at row 1 to Struct([Field { name: \"b\", data_type: Int32, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: {} }]): non-nullable child of type Int32 contains nulls not present in parent Struct([Field { name: \"b\", data_type: Int32, nullable: false, dict_id: 0, dict_is_ordered: false, metadata: {} }])"
+        );
+    }
+
+    #[test]
+    fn test_cast_struct_to_struct_by_name() {
+        let a = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+        let array = StructArray::from(vec![
+            (Field::new("a", DataType::Int32, true), a as ArrayRef),
+            (Field::new("b", DataType::Utf8, false), b as ArrayRef),
+        ]);
+        let array = Arc::new(array) as ArrayRef;
+
+        let to_type = DataType::Struct(vec![
+            Field::new("c", DataType::Float64, true),
+            Field::new("b", DataType::LargeUtf8, false),
+            Field::new("a", DataType::Int64, true),
+        ]);
+        let options = CastOptions {
+            struct_field_matching: StructFieldMatching::Name,
+            ..Default::default()
+        };
+        // can_cast_types only considers the default matching by position
+        assert!(!can_cast_types(array.data_type(), &to_type));
+        let result = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(result.data_type(), &to_type);
+        let result = as_struct_array(&result);
+        assert_eq!(result.column(0).null_count(), 3);
+        assert_eq!(
+            result
+                .column(1)
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .unwrap(),
+            &LargeStringArray::from(vec!["x", "y", "z"])
+        );
+        assert_eq!(
+            as_primitive_array::<Int64Type>(result.column(2)),
+            &Int64Array::from(vec![Some(1), None, Some(3)])
+        );
+
+        // Missing fields must be nullable
+        let to_type = DataType::Struct(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, false),
+            Field::new("c", DataType::Float64, false),
+        ]);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast struct: non-nullable field 'c' is not present in the source type"
+        );
+
+        // Extra source fields are an error unless they are dropped
+        let to_type = DataType::Struct(vec![Field::new("a", DataType::Int64, true)]);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast struct: field 'b' is not present in the target type"
+        );
+
+        let options = CastOptions {
+            struct_field_matching: StructFieldMatching::NameDropExtra,
+            ..Default::default()
+        };
+        let result = cast_with_options(&array, &to_type, &options).unwrap();
+        let result = as_struct_array(&result);
+        assert_eq!(result.num_columns(), 1);
+        assert_eq!(
+            as_primitive_array::<Int64Type>(result.column(0)),
+            &Int64Array::from(vec![Some(1), None, Some(3)])
+        );
+
+        // Fields cannot be matched by a name that is not unique
+        let a = Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef;
+        let array = StructArray::from(vec![
+            (Field::new("a", DataType::Int32, false), a.clone()),
+            (Field::new("a", DataType::Int32, false), a),
+        ]);
+        let array = Arc::new(array) as ArrayRef;
+        let to_type = DataType::Struct(vec![Field::new("a", DataType::Int64, false)]);
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast struct by name: field 'a' is not unique"
+        );
+    }
+
+    #[test]
+    fn test_cast_map_to_map() {
+        let values = Int32Array::from(vec![Some(1), None, Some(3), Some(4), Some(5)]);
+        let map = MapArray::new_from_strings(
+            ["a", "b", "c", "d", "e"].into_iter(),
+            &values,
+            &[0, 2, 2, 5],
+        )
+        .unwrap();
+        let array = Arc::new(map) as ArrayRef;
+
+        let to_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("keys", DataType::LargeUtf8, false),
+                    Field::new("values", DataType::Float64, true),
+                ]),
+                true,
+            )),
+            false,
+        );
+        assert!(can_cast_types(array.data_type(), &to_type));
+
+        let result = cast(&array, &to_type).unwrap();
+        assert_eq!(result.data_type(), &to_type);
+        let result = result.as_any().downcast_ref::<MapArray>().unwrap();
+        assert_eq!(result.value_offsets(), &[0, 2, 2, 5]);
+        assert_eq!(
+            result
+                .keys()
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .unwrap(),
+            &LargeStringArray::from(vec!["a", "b", "c", "d", "e"])
+        );
+        assert_eq!(
+            as_primitive_array::<Float64Type>(result.values()),
+            &Float64Array::from(vec![Some(1.0), None, Some(3.0), Some(4.0), Some(5.0)])
+        );
+
+        let sliced = array.slice(2, 1);
+        let result = cast(&sliced, &to_type).unwrap();
+        let result = result.as_any().downcast_ref::<MapArray>().unwrap();
+        assert_eq!(result.len(), 1);
+        let entry = result.value(0);
+        let entry = as_struct_array(&entry);
+        assert_eq!(
+            as_primitive_array::<Float64Type>(entry.column(1)),
+            &Float64Array::from(vec![3.0, 4.0, 5.0])
+        );
+
+        // Keys that fail to cast cannot be null
+        let to_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("keys", DataType::Int32, false),
+                    Field::new("values", DataType::Int32, true),
+                ]),
+                true,
+            )),
+            false,
+        );
+        assert!(cast(&array, &to_type).is_err());
+
+        // Sorted maps are only kept sorted if the type of the keys is unchanged
+        let entries = match array.data_type() {
+            DataType::Map(entries, _) => entries.clone(),
+            _ => unreachable!(),
+        };
+        let sorted_type = DataType::Map(entries.clone(), true);
+        let data = array.data().clone().into_builder().data_type(sorted_type);
+        let sorted = make_array(data.build().unwrap());
+
+        let to_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("keys", DataType::Utf8, false),
+                    Field::new("values", DataType::Int64, true),
+                ]),
+                true,
+            )),
+            true,
+        );
+        let result = cast(&sorted, &to_type).unwrap();
+        assert_eq!(result.data_type(), &to_type);
+
+        let to_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("keys", DataType::LargeUtf8, false),
+                    Field::new("values", DataType::Int64, true),
+                ]),
+                true,
+            )),
+            true,
+        );
+        let result = cast(&sorted, &to_type).unwrap();
+        assert!(matches!(result.data_type(), DataType::Map(_, false)));
+        let result = cast(&array, &DataType::Map(entries, true)).unwrap();
+        assert!(matches!(result.data_type(), DataType::Map(_, false)));
+    }
+
     fn cast_from_null_to_other(data_type: &DataType) {
         // Cast from null to data_type
         {
@@ -7083,6 +9200,174 @@ mod tests {
         assert_eq!(&expected.value(2), &actual.value(2));
     }
 
+    #[test]
+    fn test_cast_list_container_inner() {
+        let array = Arc::new(make_list_array()) as ArrayRef;
+        let to_type =
+            DataType::LargeList(Box::new(Field::new("item", DataType::Int64, true)));
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let cast_array = cast(&array, &to_type).unwrap();
+        assert_eq!(cast_array.data_type(), &to_type);
+        let list = as_large_list_array(&cast_array);
+        assert_eq!(
+            list.value(2).as_ref(),
+            &Int64Array::from(vec![6, 7]) as &dyn Array
+        );
+
+        let to_type = DataType::List(Box::new(Field::new("item", DataType::Utf8, true)));
+        let cast_array = cast(&cast_array, &to_type).unwrap();
+        let list = as_list_array(&cast_array);
+        assert_eq!(
+            list.value(0).as_ref(),
+            &StringArray::from(vec!["0", "1", "2"]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn test_cast_fixed_size_list_to_list() {
+        let values = Int32Array::from(vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        let field = Box::new(Field::new("item", DataType::Int32, true));
+        let list_data = ArrayData::builder(DataType::FixedSizeList(field, 2))
+            .len(4)
+            .add_child_data(values.into_data())
+            .null_bit_buffer(Some(Buffer::from([0b00001011])))
+            .build()
+            .unwrap();
+        let array = Arc::new(FixedSizeListArray::from(list_data)) as ArrayRef;
+        let array = array.slice(1, 3);
+
+        let to_type = DataType::List(Box::new(Field::new("item", DataType::Int64, true)));
+        assert!(can_cast_types(array.data_type(), &to_type));
+        let cast_array = cast(&array, &to_type).unwrap();
+        let list = as_list_array(&cast_array);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.value_offsets(), &[0, 2, 4, 6]);
+        assert_eq!(list.values().len(), 6);
+        assert!(list.is_valid(0));
+        assert!(list.is_null(1));
+        assert!(list.is_valid(2));
+        assert_eq!(
+            list.value(2).as_ref(),
+            &Int64Array::from(vec![6, 7]) as &dyn Array
+        );
+
+        let to_type =
+            DataType::LargeList(Box::new(Field::new("item", DataType::Int32, true)));
+        let cast_array = cast(&array, &to_type).unwrap();
+        let list = as_large_list_array(&cast_array);
+        assert_eq!(list.value_offsets(), &[0, 2, 4, 6]);
+        assert_eq!(
+            list.value(0).as_ref(),
+            &Int32Array::from(vec![2, 3]) as &dyn Array
+        );
+        assert_eq!(list.null_count(), 1);
+    }
+
+    #[test]
+    fn test_cast_list_to_fixed_size_list() {
+        let array = Arc::new(make_list_array()) as ArrayRef;
+        let field = Box::new(Field::new("item", DataType::Int64, true));
+        let to_type = DataType::FixedSizeList(field.clone(), 3);
+        assert!(can_cast_types(array.data_type(), &to_type));
+
+        // The last list has length 2
+        let cast_array = cast(&array, &to_type).unwrap();
+        let list = cast_array
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(list.data_type(), &to_type);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.null_count(), 1);
+        assert!(list.is_null(2));
+        assert_eq!(
+            list.value(1).as_ref(),
+            &Int64Array::from(vec![3, 4, 5]) as &dyn Array
+        );
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast '[6, 7]' at row 2 to FixedSizeList(Field { name: \"item\", data_type: Int64, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} }, 3): Cannot cast to FixedSizeList(3): value has length 2"
+        );
+
+        // All lists have length 3, values are reused
+        let sliced = array.slice(0, 2);
+        let cast_array = cast_with_options(&sliced, &to_type, &options).unwrap();
+        let list = cast_array
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(list.null_count(), 0);
+        assert_eq!(
+            list.values().as_ref(),
+            &Int64Array::from(vec![0, 1, 2, 3, 4, 5]) as &dyn Array
+        );
+
+        // Round trip back to a list
+        let to_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+        let round_trip = cast(&cast_array, &to_type).unwrap();
+        assert_eq!(round_trip.as_ref(), sliced.as_ref());
+
+        // FixedSizeList to FixedSizeList of the same size
+        let to_type = DataType::FixedSizeList(
+            Box::new(Field::new("item", DataType::Utf8, true)),
+            3,
+        );
+        let cast_array = cast(&cast_array, &to_type).unwrap();
+        let list = cast_array
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(
+            list.value(0).as_ref(),
+            &StringArray::from(vec!["0", "1", "2"]) as &dyn Array
+        );
+        let to_type = DataType::FixedSizeList(field, 2);
+        assert!(!can_cast_types(cast_array.data_type(), &to_type));
+
+        // Lists replaced by nulls only mask nulls in a non-nullable child
+        let to_type = DataType::FixedSizeList(
+            Box::new(Field::new("item", DataType::Int64, false)),
+            3,
+        );
+        let cast_array = cast(&array, &to_type).unwrap();
+        assert_eq!(cast_array.data_type(), &to_type);
+        assert_eq!(cast_array.null_count(), 1);
+        assert!(cast_array.is_null(2));
+        cast_array.data().validate_full().unwrap();
+        let cast_array = cast(&sliced, &to_type).unwrap();
+        assert_eq!(cast_array.data_type(), &to_type);
+
+        // A null list of a different length is masked as well
+        let array = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(0), Some(1), Some(2)]),
+            None,
+        ])) as ArrayRef;
+        let cast_array = cast(&array, &to_type).unwrap();
+        assert!(cast_array.is_null(1));
+        cast_array.data().validate_full().unwrap();
+
+        // Nulls within a valid list cannot be cast to a non-nullable child
+        let array = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(0), None, Some(2)]),
+            None,
+        ])) as ArrayRef;
+        let err = cast(&array, &to_type).unwrap_err().to_string();
+        assert!(
+            err.starts_with("Cast error: Cannot cast '[0, , 2]' at row 0"),
+            "{err}"
+        );
+        assert!(
+            err.ends_with("non-nullable child: value contains nulls"),
+            "{err}"
+        );
+    }
+
     fn make_list_array() -> ListArray {
         // Construct a value array
         let value_data = ArrayData::builder(DataType::Int32)
@@ -7272,7 +9557,10 @@ mod tests {
         let casted_array = cast_with_options(
             &array,
             &DataType::Decimal128(38, 30),
-            &CastOptions { safe: true },
+            &CastOptions {
+                safe: true,
+                ..Default::default()
+            },
         );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
@@ -7280,7 +9568,10 @@ mod tests {
         let casted_array = cast_with_options(
             &array,
             &DataType::Decimal128(38, 30),
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
         );
         assert!(casted_array.is_err());
     }
@@ -7292,7 +9583,10 @@ mod tests {
         let casted_array = cast_with_options(
             &array,
             &DataType::Decimal256(76, 76),
-            &CastOptions { safe: true },
+            &CastOptions {
+                safe: true,
+                ..Default::default()
+            },
         );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
@@ -7300,11 +9594,78 @@ mod tests {
         let casted_array = cast_with_options(
             &array,
             &DataType::Decimal256(76, 76),
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
         );
         assert!(casted_array.is_err());
     }
 
+    #[test]
+    fn test_cast_floating_point_to_decimal_rounding() {
+        let array = Arc::new(Float64Array::from(vec![
+            Some(1.25),
+            Some(1.35),
+            Some(-1.25),
+            Some(-1.29),
+            Some(999.99),
+            None,
+        ])) as ArrayRef;
+
+        for (rounding, expected) in [
+            (
+                DecimalRoundingMode::HalfUp,
+                vec![Some(13), Some(14), Some(-13), Some(-13), None, None],
+            ),
+            (
+                DecimalRoundingMode::HalfEven,
+                vec![Some(12), Some(14), Some(-12), Some(-13), None, None],
+            ),
+            (
+                DecimalRoundingMode::Truncate,
+                vec![Some(12), Some(13), Some(-12), Some(-12), Some(9999), None],
+            ),
+        ] {
+            let options = CastOptions {
+                decimal_rounding: rounding,
+                ..Default::default()
+            };
+
+            let result =
+                cast_with_options(&array, &DataType::Decimal128(4, 1), &options).unwrap();
+            let result = as_primitive_array::<Decimal128Type>(&result);
+            assert_eq!(result.iter().collect::<Vec<_>>(), expected, "{rounding:?}");
+
+            let result =
+                cast_with_options(&array, &DataType::Decimal256(4, 1), &options).unwrap();
+            let result = as_primitive_array::<Decimal256Type>(&result);
+            let expected: Vec<_> =
+                expected.iter().map(|v| v.map(i256::from_i128)).collect();
+            assert_eq!(result.iter().collect::<Vec<_>>(), expected, "{rounding:?}");
+        }
+
+        // Values exceeding the precision are an error rather than wrapping
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err =
+            cast_with_options(&array, &DataType::Decimal128(4, 1), &options).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("10000 is too large to store in a Decimal128 of precision 4"),
+            "{err}"
+        );
+        let err =
+            cast_with_options(&array, &DataType::Decimal256(4, 1), &options).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("10000 is too large to store in a Decimal256 of precision 4"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_cast_floating_point_to_decimal128_overflow() {
         let array = Float64Array::from(vec![f64::MAX]);
@@ -7312,7 +9673,10 @@ mod tests {
         let casted_array = cast_with_options(
             &array,
             &DataType::Decimal128(38, 30),
-            &CastOptions { safe: true },
+            &CastOptions {
+                safe: true,
+                ..Default::default()
+            },
         );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
@@ -7320,10 +9684,14 @@ mod tests {
         let casted_array = cast_with_options(
             &array,
             &DataType::Decimal128(38, 30),
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
         );
         let err = casted_array.unwrap_err().to_string();
-        let expected_error = "Cast error: Cannot cast to Decimal128(38, 30)";
+        let expected_error =
+            "at row 0 to Decimal128(38, 30): Cannot cast to Decimal128(38, 30)";
         assert!(
             err.contains(expected_error),
             "did not find expected error '{expected_error}' in actual error '{err}'"
@@ -7337,7 +9705,10 @@ mod tests {
         let casted_array = cast_with_options(
             &array,
             &DataType::Decimal256(76, 50),
-            &CastOptions { safe: true },
+            &CastOptions {
+                safe: true,
+                ..Default::default()
+            },
         );
         assert!(casted_array.is_ok());
         assert!(casted_array.unwrap().is_null(0));
@@ -7345,10 +9716,14 @@ mod tests {
         let casted_array = cast_with_options(
             &array,
             &DataType::Decimal256(76, 50),
-            &CastOptions { safe: false },
+            &CastOptions {
+                safe: false,
+                ..Default::default()
+            },
         );
         let err = casted_array.unwrap_err().to_string();
-        let expected_error = "Cast error: Cannot cast to Decimal256(76, 50)";
+        let expected_error =
+            "at row 0 to Decimal256(76, 50): Cannot cast to Decimal256(76, 50)";
         assert!(
             err.contains(expected_error),
             "did not find expected error '{expected_error}' in actual error '{err}'"
@@ -7563,6 +9938,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_string_to_decimal_exponent() {
+        let cases = [
+            ("1.5e2", "150.00"),
+            ("1.5E-2", "0.02"),
+            ("-1.5e-2", "-0.02"),
+            ("+12.345e1", "123.45"),
+            ("-1.55", "-1.55"),
+            ("-1.555", "-1.56"),
+            ("-0.555", "-0.56"),
+            ("0.004", "0.00"),
+            ("0.005", "0.01"),
+            ("5e-3", "0.01"),
+            ("5e-4", "0.00"),
+            ("0e100", "0.00"),
+        ];
+        for (input, expected) in cases {
+            let value = parse_string_to_decimal_native::<Decimal128Type>(input, 2);
+            let value = Decimal128Type::format_decimal(value.unwrap(), 38, 2);
+            assert_eq!(value, expected, "{input}");
+        }
+
+        let value = parse_string_to_decimal_native::<Decimal256Type>("1e70", 2).unwrap();
+        assert_eq!(value, i256::from_i128(10).pow_wrapping(72));
+
+        for input in ["e5", "-", "1e", "1e1.5", "1.5e+-2"] {
+            let err = parse_string_to_decimal_native::<Decimal128Type>(input, 2);
+            assert!(matches!(err, Err(ArrowError::ParseError(_))), "{input}");
+        }
+        let err = parse_string_to_decimal_native::<Decimal128Type>("1e40", 2)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Cast error: Cannot convert 1e40 to Decimal128: Overflow"
+        );
+    }
+
+    #[test]
+    fn test_cast_utf8_to_decimal_precision_overflow() {
+        let array =
+            Arc::new(StringArray::from(vec!["123.45", "1234.5", "-999.995"])) as ArrayRef;
+        let output_type = DataType::Decimal128(5, 2);
+        let casted_array = cast(&array, &output_type).unwrap();
+        let decimal_arr = as_primitive_array::<Decimal128Type>(&casted_array);
+        assert_eq!("123.45", decimal_arr.value_as_string(0));
+        assert!(decimal_arr.is_null(1));
+        assert!(decimal_arr.is_null(2));
+
+        let options = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
+        let err = cast_with_options(&array, &output_type, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Cannot cast '1234.5' at row 1 to Decimal128(5, 2): Cannot cast string '1234.5' to value of Decimal128(5, 2) type: Overflow"
+        );
+    }
+
+    #[test]
+    fn test_cast_decimal_to_utf8() {
+        let array =
+            create_decimal_array(vec![Some(12345), None, Some(-5)], 10, 3).unwrap();
+        let array = Arc::new(array) as ArrayRef;
+        assert!(can_cast_types(array.data_type(), &DataType::Utf8));
+        let expected = vec![Some("12.345"), None, Some("-0.005")];
+
+        let casted = cast(&array, &DataType::Utf8).unwrap();
+        assert_eq!(
+            as_string_array(&casted),
+            &StringArray::from(expected.clone())
+        );
+
+        let values = vec![
+            Some(i256::from_i128(12345)),
+            None,
+            Some(i256::from_i128(-5)),
+        ];
+        let array = create_decimal256_array(values, 40, 3).unwrap();
+        let array = Arc::new(array) as ArrayRef;
+        let casted = cast(&array, &DataType::LargeUtf8).unwrap();
+        assert_eq!(
+            as_largestring_array(&casted),
+            &LargeStringArray::from(expected)
+        );
+
+        // Round trip
+        let casted = cast(&casted, &DataType::Decimal256(40, 3)).unwrap();
+        assert_eq!(&casted, &array);
+    }
+
     fn test_cast_string_to_decimal(array: ArrayRef) {
         // Decimal128
         let output_type = DataType::Decimal128(38, 2);
@@ -7671,7 +10138,10 @@ mod tests {
         let output_type = DataType::Decimal128(38, 2);
         let str_array = StringArray::from(vec!["4.4.5"]);
         let array = Arc::new(str_array) as ArrayRef;
-        let option = CastOptions { safe: false };
+        let option = CastOptions {
+            safe: false,
+            ..Default::default()
+        };
         let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
         assert!(casted_err
             .to_string()
@@ -108,34 +108,7 @@ macro_rules! make_string_interval_month_day_nano {
             .downcast_ref::<array::IntervalMonthDayNanoArray>()
             .unwrap();
 
-        let value: u128 = array.value($row) as u128;
-
-        let months_part: i32 =
-            ((value & 0xFFFFFFFF000000000000000000000000) >> 96) as i32;
-        let days_part: i32 = ((value & 0xFFFFFFFF0000000000000000) >> 64) as i32;
-        let nanoseconds_part: i64 = (value & 0xFFFFFFFFFFFFFFFF) as i64;
-
-        let secs = nanoseconds_part / 1_000_000_000;
-        let mins = secs / 60;
-        let hours = mins / 60;
-
-        let secs = secs - (mins * 60);
-        let mins = mins - (hours * 60);
-
-        let nanoseconds = nanoseconds_part % 1_000_000_000;
-
-        let secs_sign = if secs < 0 || nanoseconds < 0 { "-" } else { "" };
-
-        Ok(format!(
-            "0 years {} mons {} days {} hours {} mins {}{}.{:09} secs",
-            months_part,
-            days_part,
-            hours,
-            mins,
-            secs_sign,
-            secs.abs(),
-            nanoseconds.abs(),
-        ))
+        Ok(array.value_as_string($row))
     }};
 }
 
@@ -482,6 +455,10 @@ fn array_value_to_string_internal(
         DataType::Float32 => make_string!(array::Float32Array, column, row_idx),
         DataType::Float64 => make_string!(array::Float64Array, column, row_idx),
         DataType::Decimal128(..) => make_string_from_decimal(column, row_idx),
+        DataType::Decimal256(..) => {
+            let array = column.as_any().downcast_ref::<Decimal256Array>().unwrap();
+            Ok(array.value_as_string(row_idx))
+        }
         DataType::Timestamp(unit, tz_string_opt) if *unit == TimeUnit::Second => {
             handle_string_datetime!(
                 array::TimestampSecondArray,
@@ -16,7 +16,8 @@
 // under the License.
 
 use arrow_array::types::*;
-use arrow_array::ArrowPrimitiveType;
+use arrow_array::{ArrowNativeTypeOp, ArrowPrimitiveType};
+use arrow_buffer::ArrowNativeType;
 use arrow_schema::ArrowError;
 use chrono::prelude::*;
 
@@ -70,10 +71,33 @@ use chrono::prelude::*;
 /// `1997-01-31T09:26:56.123-05:00`
 #[inline]
 pub fn string_to_timestamp_nanos(s: &str) -> Result<i64, ArrowError> {
+    to_timestamp_nanos(string_to_datetime(&Utc, s)?.naive_utc())
+}
+
+/// Accepts a string in the formats accepted by [`string_to_timestamp_nanos`], or
+/// without seconds such as `1997-01-31 09:26`, and converts it to a [`DateTime`]
+///
+/// Strings with an explicit timezone offset are converted to `timezone`, and strings
+/// without are interpreted as local times in `timezone`. Ambiguous local times resolve
+/// to the earliest instant, and local times that do not exist in `timezone`, for
+/// example because of a daylight saving transition, return an error
+///
+/// ```
+/// # use arrow_cast::parse::string_to_datetime;
+/// # use chrono::{FixedOffset, TimeZone, Utc};
+/// let tz = FixedOffset::east_opt(2 * 3600).unwrap();
+/// let expected = Utc.with_ymd_and_hms(2023, 3, 15, 8, 30, 0).unwrap();
+/// assert_eq!(string_to_datetime(&tz, "2023-03-15 10:30").unwrap(), expected);
+/// assert_eq!(string_to_datetime(&tz, "2023-03-15T08:30:00Z").unwrap(), expected);
+/// ```
+pub fn string_to_datetime<T: TimeZone>(
+    timezone: &T,
+    s: &str,
+) -> Result<DateTime<T>, ArrowError> {
     // Fast path:  RFC3339 timestamp (with a T)
     // Example: 2020-09-08T13:42:29.190855Z
     if let Ok(ts) = DateTime::parse_from_rfc3339(s) {
-        return Ok(ts.timestamp_nanos());
+        return Ok(ts.with_timezone(timezone));
     }
 
     // Implement quasi-RFC3339 support by trying to parse the
@@ -84,49 +108,38 @@ pub fn string_to_timestamp_nanos(s: &str) -> Result<i64, ArrowError> {
     // timezone offset, using ' ' as a separator
     // Example: 2020-09-08 13:42:29.190855-05:00
     if let Ok(ts) = DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f%:z") {
-        return to_timestamp_nanos(ts.naive_utc());
+        return Ok(ts.with_timezone(timezone));
     }
 
     // with an explicit Z, using ' ' as a separator
     // Example: 2020-09-08 13:42:29Z
     if let Ok(ts) = Utc.datetime_from_str(s, "%Y-%m-%d %H:%M:%S%.fZ") {
-        return to_timestamp_nanos(ts.naive_utc());
+        return Ok(ts.with_timezone(timezone));
     }
 
     // Support timestamps without an explicit timezone offset, again
     // to be compatible with what Apache Spark SQL does.
-
-    // without a timezone specifier as a local time, using T as a separator
-    // Example: 2020-09-08T13:42:29.190855
-    if let Ok(ts) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f") {
-        return to_timestamp_nanos(ts);
-    }
-
-    // without a timezone specifier as a local time, using T as a
-    // separator, no fractional seconds
-    // Example: 2020-09-08T13:42:29
-    if let Ok(ts) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S") {
-        return Ok(ts.timestamp_nanos());
-    }
-
-    // without a timezone specifier as a local time, using ' ' as a separator
-    // Example: 2020-09-08 13:42:29.190855
-    if let Ok(ts) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f") {
-        return to_timestamp_nanos(ts);
-    }
-
-    // without a timezone specifier as a local time, using ' ' as a
-    // separator, no fractional seconds
-    // Example: 2020-09-08 13:42:29
-    if let Ok(ts) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
-        return Ok(ts.timestamp_nanos());
+    //
+    // Examples: 2020-09-08T13:42:29.190855, 2020-09-08 13:42:29, 2020-09-08 13:42
+    const NAIVE_FORMATS: [&str; 6] = [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ];
+    for format in NAIVE_FORMATS {
+        if let Ok(ts) = NaiveDateTime::parse_from_str(s, format) {
+            return localize(timezone, ts);
+        }
     }
 
     // without a timezone specifier as a local time, only date
     // Example: 2020-09-08
     if let Ok(dt) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
         if let Some(ts) = dt.and_hms_opt(0, 0, 0) {
-            return Ok(ts.timestamp_nanos());
+            return localize(timezone, ts);
         }
     }
 
@@ -140,6 +153,87 @@ pub fn string_to_timestamp_nanos(s: &str) -> Result<i64, ArrowError> {
     )))
 }
 
+/// Interprets `naive` as a local time in `timezone`
+fn localize<T: TimeZone>(
+    timezone: &T,
+    naive: NaiveDateTime,
+) -> Result<DateTime<T>, ArrowError> {
+    timezone
+        .from_local_datetime(&naive)
+        .earliest()
+        .ok_or_else(|| {
+            ArrowError::ParseError(format!("{naive} does not exist in the timezone"))
+        })
+}
+
+/// Parses `s` using the first of the strptime-style `formats` that matches, returning
+/// `None` if no format matches
+fn parse_formatted<T: TimeZone>(
+    timezone: &T,
+    s: &str,
+    formats: &[String],
+) -> Option<Result<DateTime<T>, ArrowError>> {
+    formats.iter().find_map(|format| {
+        if let Ok(dt) = DateTime::parse_from_str(s, format) {
+            return Some(Ok(dt.with_timezone(timezone)));
+        }
+        NaiveDateTime::parse_from_str(s, format)
+            .ok()
+            .or_else(|| {
+                NaiveDate::parse_from_str(s, format)
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+            })
+            .map(|dt| localize(timezone, dt))
+    })
+}
+
+/// Parses `s` using the first of the strptime-style `formats` that matches, returning
+/// the corresponding UTC [`NaiveDateTime`], or `None` if no format matches
+///
+/// Formats containing an offset specifier such as `%z` are converted to UTC, and
+/// formats without a time component are interpreted as midnight
+///
+/// ```
+/// # use arrow_cast::parse::parse_formatted_datetime;
+/// # use chrono::NaiveDate;
+/// let formats = vec!["%d/%m/%Y %H:%M".to_string(), "%d/%m/%Y".to_string()];
+/// let expected = NaiveDate::from_ymd_opt(2023, 2, 1).unwrap().and_hms_opt(0, 0, 0);
+/// assert_eq!(parse_formatted_datetime("01/02/2023", &formats), expected);
+/// assert_eq!(parse_formatted_datetime("2023-02-01", &formats), None);
+/// ```
+pub fn parse_formatted_datetime(s: &str, formats: &[String]) -> Option<NaiveDateTime> {
+    parse_formatted(&Utc, s, formats)
+        .and_then(Result::ok)
+        .map(|dt| dt.naive_utc())
+}
+
+/// Converts a string to a [`DateTime`], first trying each of the strptime-style
+/// `formats` in order, see [`parse_formatted_datetime`], and falling back to
+/// [`string_to_datetime`] if none match
+///
+/// Values without an explicit offset are interpreted as local times in `timezone`
+pub fn string_to_datetime_formatted<T: TimeZone>(
+    timezone: &T,
+    s: &str,
+    formats: &[String],
+) -> Result<DateTime<T>, ArrowError> {
+    match parse_formatted(timezone, s, formats) {
+        Some(dt) => dt,
+        None => string_to_datetime(timezone, s),
+    }
+}
+
+/// Converts a string to a nanosecond precision timestamp, first trying each of the
+/// strptime-style `formats` in order, see [`parse_formatted_datetime`], and falling
+/// back to [`string_to_timestamp_nanos`] if none match
+pub fn string_to_timestamp_nanos_formatted(
+    s: &str,
+    formats: &[String],
+) -> Result<i64, ArrowError> {
+    to_timestamp_nanos(string_to_datetime_formatted(&Utc, s, formats)?.naive_utc())
+}
+
 /// Defensive check to prevent chrono-rs panics when nanosecond conversion happens on non-supported dates
 #[inline]
 fn to_timestamp_nanos(dt: NaiveDateTime) -> Result<i64, ArrowError> {
@@ -432,9 +526,130 @@ impl Parser for Date64Type {
     }
 }
 
+/// Parse the string representation of a decimal, such as `-123.45` or `1.5e-3`, to
+/// the native type of `T` with the provided `precision` and `scale`
+///
+/// Digits beyond `scale` are truncated, and a negative `scale` truncates the least
+/// significant digits of the integer part. Returns an error if the string is not
+/// a decimal, or the value does not fit in `precision` digits
+pub fn parse_decimal<T: DecimalType>(
+    s: &str,
+    precision: u8,
+    scale: i8,
+) -> Result<T::Native, ArrowError>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    let result = parse_decimal_native::<T>(s, scale, false).map_err(|e| match e {
+        DecimalParseError::Invalid => ArrowError::ParseError(format!(
+            "can't parse the string value {s} to decimal"
+        )),
+        DecimalParseError::Overflow => ArrowError::ParseError(format!(
+            "parse decimal overflow: {s} is too large to store in a {}({precision}, {scale})",
+            T::PREFIX
+        )),
+    })?;
+
+    match T::validate_decimal_precision(result, precision) {
+        Ok(_) => Ok(result),
+        Err(e) => Err(ArrowError::ParseError(format!(
+            "parse decimal overflow: {e}"
+        ))),
+    }
+}
+
+/// The reason [`parse_decimal_native`] could not parse a string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DecimalParseError {
+    /// The string is not a decimal
+    Invalid,
+    /// The value does not fit in the native type
+    Overflow,
+}
+
+/// Parse a string of an optional sign, digits with an optional decimal point, and an
+/// optional exponent, such as `-1.5e-3`, to the native type of `T` in units of
+/// `10^-scale`
+///
+/// Digits beyond `scale` are rounded half away from zero if `round` is true, and
+/// truncated otherwise
+pub(crate) fn parse_decimal_native<T: DecimalType>(
+    s: &str,
+    scale: i8,
+    round: bool,
+) -> Result<T::Native, DecimalParseError>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    let (negative, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(idx) => {
+            let exponent = unsigned[idx + 1..]
+                .parse::<i32>()
+                .map_err(|_| DecimalParseError::Invalid)?;
+            (&unsigned[..idx], exponent)
+        }
+        None => (unsigned, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if integer.len() + fraction.len() == 0 || !is_digits(integer) || !is_digits(fraction)
+    {
+        return Err(DecimalParseError::Invalid);
+    }
+
+    // The value is `digits * 10^shift` in units of the scale
+    let num_digits = integer.len() + fraction.len();
+    let shift = scale as i64 + exponent as i64 - fraction.len() as i64;
+    // The number of least significant digits dropped by a negative shift
+    let dropped = (-shift).max(0).min(num_digits as i64 + 1) as usize;
+    let kept = num_digits.saturating_sub(dropped);
+
+    let mut digits = integer.bytes().chain(fraction.bytes());
+    let ten = T::Native::usize_as(10);
+    let mut result = T::Native::usize_as(0);
+    for digit in digits.by_ref().take(kept) {
+        result = result
+            .mul_checked(ten)
+            .and_then(|r| r.add_checked(T::Native::usize_as((digit - b'0') as usize)))
+            .map_err(|_| DecimalParseError::Overflow)?;
+    }
+
+    if shift > 0 && !result.is_zero() {
+        // 10^77 exceeds the range of every decimal type
+        if shift > 77 {
+            return Err(DecimalParseError::Overflow);
+        }
+        for _ in 0..shift {
+            result = result
+                .mul_checked(ten)
+                .map_err(|_| DecimalParseError::Overflow)?;
+        }
+    }
+
+    // If all digits are dropped, the first dropped digit is an implicit leading zero
+    let round_up =
+        round && dropped <= num_digits && matches!(digits.next(), Some(b'5'..=b'9'));
+    if round_up {
+        result = result
+            .add_checked(T::Native::usize_as(1))
+            .map_err(|_| DecimalParseError::Overflow)?;
+    }
+
+    match negative {
+        true => Ok(result.neg_wrapping()),
+        false => Ok(result),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow_buffer::i256;
 
     #[test]
     fn string_to_timestamp_timezone() {
@@ -478,6 +693,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn string_to_datetime_timezone() {
+        let tz = FixedOffset::west_opt(5 * 3600).unwrap();
+        let expected = Utc.with_ymd_and_hms(2023, 3, 15, 15, 30, 0).unwrap();
+
+        // Values without an offset are local times in the timezone
+        for s in [
+            "2023-03-15T10:30:00",
+            "2023-03-15 10:30:00.000",
+            "2023-03-15 10:30",
+            "2023-03-15T10:30",
+        ] {
+            assert_eq!(string_to_datetime(&tz, s).unwrap(), expected, "{s}");
+        }
+        let midnight = Utc.with_ymd_and_hms(2023, 3, 15, 5, 0, 0).unwrap();
+        assert_eq!(string_to_datetime(&tz, "2023-03-15").unwrap(), midnight);
+
+        // Values with an offset are converted
+        for s in ["2023-03-15T15:30:00Z", "2023-03-15 17:30:00+02:00"] {
+            let dt = string_to_datetime(&tz, s).unwrap();
+            assert_eq!(dt, expected, "{s}");
+            assert_eq!(dt.offset(), &tz);
+        }
+
+        // Formats are tried first
+        let formats = vec!["%d/%m/%Y %H:%M".to_string()];
+        let dt = string_to_datetime_formatted(&tz, "15/03/2023 10:30", &formats);
+        assert_eq!(dt.unwrap(), expected);
+        let dt = string_to_datetime_formatted(&tz, "2023-03-15 10:30", &formats);
+        assert_eq!(dt.unwrap(), expected);
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function: mktime
     fn string_to_timestamp_no_timezone() {
@@ -864,4 +1111,57 @@ mod tests {
             .map_err(|e| assert!(e.to_string().ends_with(ERR_NANOSECONDS_NOT_SUPPORTED)))
             .unwrap_err();
     }
+
+    #[test]
+    fn test_parse_decimal() {
+        let tests = [
+            ("123.123", 123123_i128),
+            ("123.1234", 123123),
+            ("123.1", 123100),
+            ("123", 123000),
+            ("-123.123", -123123),
+            ("-0.1", -100),
+            (".1", 100),
+            ("5.", 5000),
+            ("+1", 1000),
+            ("1e2", 100000),
+            ("1.5E-2", 15),
+            ("-12.5e-1", -1250),
+            ("1.2345e+2", 123450),
+            ("1e-4", 0),
+            ("0e400", 0),
+        ];
+        for (s, expected) in tests {
+            let result = parse_decimal::<Decimal128Type>(s, 20, 3).unwrap();
+            assert_eq!(result, expected, "{s}");
+        }
+
+        let result = parse_decimal::<Decimal256Type>("-12345.678", 40, 2).unwrap();
+        assert_eq!(result, i256::from_i128(-1234567));
+
+        let result = parse_decimal::<Decimal128Type>("-12345.678", 5, -2).unwrap();
+        assert_eq!(result, -123);
+
+        for s in [
+            "", "-", ".", "1.2.3", "e3", "1e", "1e1.5", "abc", "1-", "+-1",
+        ] {
+            let err = parse_decimal::<Decimal128Type>(s, 10, 3).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Parser error: can't parse the string value {s} to decimal")
+            );
+        }
+
+        let err = parse_decimal::<Decimal128Type>("123456.1", 5, 2).unwrap_err();
+        assert!(err.to_string().contains("parse decimal overflow"), "{err}");
+        let err = parse_decimal::<Decimal128Type>(&"9".repeat(50), 38, 0).unwrap_err();
+        assert!(err.to_string().contains("is too large to store"), "{err}");
+        let err = parse_decimal::<Decimal128Type>("1e100", 38, 0).unwrap_err();
+        assert!(err.to_string().contains("is too large to store"), "{err}");
+
+        // digits are not lost to floating point precision
+        let result =
+            parse_decimal::<Decimal128Type>("123456789012345678901.5", 38, 1).unwrap();
+        assert_eq!(result, 1234567890123456789015);
+    }
 }
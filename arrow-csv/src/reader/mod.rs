@@ -44,23 +44,24 @@ mod records;
 
 use lazy_static::lazy_static;
 use regex::{Regex, RegexSet};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fs::File;
 use std::io::{BufRead, BufReader as StdBufReader, Read, Seek, SeekFrom};
+use std::ops::Range;
 use std::sync::Arc;
 
-use arrow_array::builder::Decimal128Builder;
+use arrow_array::builder::PrimitiveBuilder;
+use arrow_array::timezone::Tz;
 use arrow_array::types::*;
 use arrow_array::*;
-use arrow_cast::parse::Parser;
+use arrow_cast::parse::{parse_formatted_datetime, string_to_datetime_formatted, Parser};
 use arrow_schema::*;
 
 use crate::map_csv_error;
 use crate::reader::records::{RecordDecoder, StringRecords};
-use arrow_data::decimal::validate_decimal_precision;
+use chrono::Utc;
 use csv::StringRecord;
-use std::ops::Neg;
 
 lazy_static! {
     static ref REGEX_SET: RegexSet = RegexSet::new([
@@ -106,6 +107,95 @@ fn infer_field_schema(string: &str, datetime_re: Option<Regex>) -> DataType {
     }
 }
 
+/// The data types inferred from the values of a column
+#[derive(Debug, Default, Clone)]
+struct InferredDataType {
+    /// The data types of the values
+    types: HashSet<DataType>,
+    /// The maximum number of digits before the decimal point of the numeric values
+    integer_digits: usize,
+    /// The maximum number of digits after the decimal point of the numeric values
+    fraction_digits: usize,
+    /// Whether a numeric value cannot be read as a decimal, such as one with an exponent
+    non_decimal: bool,
+}
+
+impl InferredDataType {
+    /// Record a value of `string` that has the given `data_type`
+    fn update(&mut self, data_type: DataType, string: &str) {
+        if matches!(data_type, DataType::Int64 | DataType::Float64) {
+            if PARSE_DECIMAL_RE.is_match(string) {
+                let digits = string.trim_start_matches('-');
+                let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+                let integer = integer.trim_start_matches('0');
+                self.integer_digits = self.integer_digits.max(integer.len());
+                self.fraction_digits = self.fraction_digits.max(fraction.len());
+            } else {
+                self.non_decimal = true;
+            }
+        }
+        self.types.insert(data_type);
+    }
+
+    /// Returns the data type of the column, if `infer_decimals` columns of
+    /// floating point values are read as decimals where they fit in a [`DataType::Decimal128`]
+    fn data_type(&self, infer_decimals: bool) -> DataType {
+        let is_float = self.types.contains(&DataType::Float64)
+            && self
+                .types
+                .iter()
+                .all(|t| matches!(t, DataType::Int64 | DataType::Float64));
+        if infer_decimals && is_float && !self.non_decimal {
+            let precision = (self.integer_digits + self.fraction_digits).max(1);
+            if precision <= DECIMAL128_MAX_PRECISION as usize {
+                return DataType::Decimal128(precision as u8, self.fraction_digits as i8);
+            }
+        }
+
+        // determine data type based on possible types
+        // if there are incompatible types, use DataType::Utf8
+        match self.types.len() {
+            1 => self.types.iter().next().unwrap().clone(),
+            // we have an integer and double, fall down to double
+            2 if is_float => DataType::Float64,
+            // default to Utf8 for conflicting datatypes (e.g bool and int)
+            _ => DataType::Utf8,
+        }
+    }
+}
+
+/// The strings that are read as null, for all columns or for individual columns
+#[derive(Debug, Default, Clone)]
+struct NullValues {
+    /// Strings read as null in all columns, if `None` only empty fields are null
+    all: Option<HashSet<String>>,
+    /// Strings read as null in the column with the given index, overriding `all`
+    columns: HashMap<usize, HashSet<String>>,
+}
+
+impl NullValues {
+    fn get(&self, col_idx: usize) -> Option<&HashSet<String>> {
+        self.columns.get(&col_idx).or(self.all.as_ref())
+    }
+
+    /// Returns true if `value` in the column at `col_idx` is null
+    fn is_null(&self, col_idx: usize, value: &str) -> bool {
+        match self.get(col_idx) {
+            Some(values) => values.contains(value),
+            None => value.is_empty(),
+        }
+    }
+
+    /// Returns true if `value` in the string column at `col_idx` is null
+    ///
+    /// Unless null values are configured, empty fields are read as empty strings
+    fn is_string_null(&self, col_idx: usize, value: &str) -> bool {
+        self.get(col_idx)
+            .map(|values| values.contains(value))
+            .unwrap_or_default()
+    }
+}
+
 /// This is a collection of options for csv reader when the builder pattern cannot be used
 /// and the parameters need to be passed around
 #[derive(Debug, Default, Clone)]
@@ -115,8 +205,26 @@ struct ReaderOptions {
     escape: Option<u8>,
     quote: Option<u8>,
     terminator: Option<u8>,
+    comment: Option<u8>,
+    skip_rows: usize,
+    ragged_rows: RaggedRows,
     max_read_records: Option<usize>,
     datetime_re: Option<Regex>,
+    datetime_formats: Vec<String>,
+    infer_decimals: bool,
+    null_values: NullValues,
+}
+
+/// How a CSV reader handles records with a different number of fields to the schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RaggedRows {
+    /// Return an error
+    #[default]
+    Error,
+    /// Fill the missing trailing fields of records with too few fields with nulls
+    PadWithNulls,
+    /// Ignore the trailing fields of records with too many fields
+    IgnoreExtra,
 }
 
 /// Infer the schema of a CSV file by reading through the first n records of the file,
@@ -187,6 +295,8 @@ fn build_csv_reader<R: Read>(
     escape: Option<u8>,
     quote: Option<u8>,
     terminator: Option<u8>,
+    comment: Option<u8>,
+    flexible: bool,
 ) -> csv::Reader<R> {
     let mut reader_builder = csv::ReaderBuilder::new();
     reader_builder.has_headers(has_header);
@@ -201,6 +311,8 @@ fn build_csv_reader<R: Read>(
     if let Some(t) = terminator {
         reader_builder.terminator(csv::Terminator::Any(t));
     }
+    reader_builder.comment(comment);
+    reader_builder.flexible(flexible);
     reader_builder.from_reader(reader)
 }
 
@@ -208,6 +320,16 @@ fn infer_reader_schema_with_csv_options<R: Read>(
     reader: R,
     roptions: ReaderOptions,
 ) -> Result<(Schema, usize), ArrowError> {
+    let mut reader = StdBufReader::new(reader);
+    let line_terminator = roptions.terminator.unwrap_or(b'\n');
+    let mut line = vec![];
+    for _ in 0..roptions.skip_rows {
+        line.clear();
+        if reader.read_until(line_terminator, &mut line)? == 0 {
+            break;
+        }
+    }
+
     let mut csv_reader = build_csv_reader(
         reader,
         roptions.has_header,
@@ -215,6 +337,8 @@ fn infer_reader_schema_with_csv_options<R: Read>(
         roptions.escape,
         roptions.quote,
         roptions.terminator,
+        roptions.comment,
+        roptions.ragged_rows != RaggedRows::Error,
     );
 
     // get or create header names
@@ -231,7 +355,7 @@ fn infer_reader_schema_with_csv_options<R: Read>(
 
     let header_length = headers.len();
     // keep track of inferred field types
-    let mut column_types: Vec<HashSet<DataType>> = vec![HashSet::new(); header_length];
+    let mut column_types = vec![InferredDataType::default(); header_length];
 
     let mut records_count = 0;
     let mut fields = vec![];
@@ -248,40 +372,26 @@ fn infer_reader_schema_with_csv_options<R: Read>(
         // they could be nullable
         for (i, column_type) in column_types.iter_mut().enumerate().take(header_length) {
             if let Some(string) = record.get(i) {
-                if !string.is_empty() {
-                    column_type
-                        .insert(infer_field_schema(string, roptions.datetime_re.clone()));
+                if !roptions.null_values.is_null(i, string) {
+                    // values matching a user provided datetime format are timestamps
+                    let data_type =
+                        if parse_formatted_datetime(string, &roptions.datetime_formats)
+                            .is_some()
+                        {
+                            DataType::Timestamp(TimeUnit::Nanosecond, None)
+                        } else {
+                            infer_field_schema(string, roptions.datetime_re.clone())
+                        };
+                    column_type.update(data_type, string);
                 }
             }
         }
     }
 
     // build schema from inference results
-    for i in 0..header_length {
-        let possibilities = &column_types[i];
-        let field_name = &headers[i];
-
-        // determine data type based on possible types
-        // if there are incompatible types, use DataType::Utf8
-        match possibilities.len() {
-            1 => {
-                for dtype in possibilities.iter() {
-                    fields.push(Field::new(field_name, dtype.clone(), true));
-                }
-            }
-            2 => {
-                if possibilities.contains(&DataType::Int64)
-                    && possibilities.contains(&DataType::Float64)
-                {
-                    // we have an integer and double, fall down to double
-                    fields.push(Field::new(field_name, DataType::Float64, true));
-                } else {
-                    // default to Utf8 for conflicting datatypes (e.g bool and int)
-                    fields.push(Field::new(field_name, DataType::Utf8, true));
-                }
-            }
-            _ => fields.push(Field::new(field_name, DataType::Utf8, true)),
-        }
+    for (column_type, field_name) in column_types.iter().zip(&headers) {
+        let data_type = column_type.data_type(roptions.infer_decimals);
+        fields.push(Field::new(field_name, data_type, true));
     }
 
     Ok((Schema::new(fields), records_count))
@@ -496,6 +606,37 @@ impl<R: BufRead> Iterator for BufReader<R> {
 ///     Ok(std::iter::from_fn(move || next().transpose()))
 /// }
 /// ```
+///
+/// As the decoder never blocks waiting for more data, it can equally be driven by
+/// an asynchronous source, such as a `Stream` of `Bytes`. [`Self::capacity`] can be
+/// used to determine when enough records have been buffered to yield a batch
+///
+/// ```
+/// # use arrow_array::RecordBatch;
+/// # use arrow_csv::reader::Decoder;
+/// # use arrow_schema::ArrowError;
+/// #
+/// fn decode_chunks<'a>(
+///     decoder: &mut Decoder,
+///     chunks: impl IntoIterator<Item = &'a [u8]>,
+/// ) -> Result<Vec<RecordBatch>, ArrowError> {
+///     let mut batches = vec![];
+///     for mut chunk in chunks {
+///         while !chunk.is_empty() {
+///             let decoded = decoder.decode(chunk)?;
+///             chunk = &chunk[decoded..];
+///
+///             // A full batch has been buffered
+///             if decoder.capacity() == 0 {
+///                 batches.extend(decoder.flush()?);
+///             }
+///         }
+///     }
+///     // Flush any remaining records once the source is exhausted
+///     batches.extend(decoder.flush()?);
+///     Ok(batches)
+/// }
+/// ```
 #[derive(Debug)]
 pub struct Decoder {
     /// Explicit schema for the CSV file
@@ -507,6 +648,12 @@ pub struct Decoder {
     /// Number of records per batch
     batch_size: usize,
 
+    /// Lines to skip before decoding any records
+    skip_lines: usize,
+
+    /// The byte terminating the lines in `skip_lines`
+    line_terminator: u8,
+
     /// Rows to skip
     to_skip: usize,
 
@@ -523,6 +670,15 @@ pub struct Decoder {
     ///
     /// For format refer to [chrono docs](https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html)
     datetime_format: Option<String>,
+
+    /// Additional datetime formats used to parse timestamp values
+    datetime_formats: Vec<String>,
+
+    /// The timezone of timestamp values without an explicit offset
+    default_timezone: Option<Tz>,
+
+    /// The strings read as null
+    null_values: NullValues,
 }
 
 impl Decoder {
@@ -536,6 +692,20 @@ impl Decoder {
     /// integration with arbitrary byte streams, such as that yielded by [`BufRead`] or
     /// network sources such as object storage
     pub fn decode(&mut self, buf: &[u8]) -> Result<usize, ArrowError> {
+        if self.skip_lines != 0 {
+            let mut read = 0;
+            while self.skip_lines != 0 {
+                match buf[read..].iter().position(|b| *b == self.line_terminator) {
+                    Some(idx) => {
+                        read += idx + 1;
+                        self.skip_lines -= 1;
+                    }
+                    None => return Ok(buf.len()),
+                }
+            }
+            return Ok(read);
+        }
+
         if self.to_skip != 0 {
             // Skip in units of `to_read` to avoid over-allocating buffers
             let to_skip = self.to_skip.min(self.batch_size);
@@ -545,12 +715,17 @@ impl Decoder {
             return Ok(bytes);
         }
 
-        let to_read =
-            self.batch_size.min(self.end - self.line_number) - self.record_decoder.len();
+        let to_read = self.capacity();
         let (_, bytes) = self.record_decoder.decode(buf, to_read)?;
         Ok(bytes)
     }
 
+    /// Returns the number of records that can be decoded before a call to
+    /// [`Self::flush`] is required to yield a [`RecordBatch`]
+    pub fn capacity(&self) -> usize {
+        self.batch_size.min(self.end - self.line_number) - self.record_decoder.len()
+    }
+
     /// Flushes the currently buffered data to a [`RecordBatch`]
     ///
     /// This should only be called after [`Self::decode`] has returned `Ok(0)`,
@@ -570,12 +745,27 @@ impl Decoder {
             self.projection.as_ref(),
             self.line_number,
             self.datetime_format.as_deref(),
+            &self.datetime_formats,
+            self.default_timezone.as_ref(),
+            &self.null_values,
         )?;
         self.line_number += rows.len();
         Ok(Some(batch))
     }
 }
 
+/// Returns for each of `rows` if its field `col_idx` was added by
+/// [`RaggedRows::PadWithNulls`], and should therefore be read as null
+fn padded_fields(rows: &StringRecords<'_>, col_idx: usize) -> Vec<bool> {
+    let mut padded = vec![false; rows.len()];
+    for (row, num_fields) in rows.padded_rows() {
+        if *num_fields <= col_idx {
+            padded[*row] = true;
+        }
+    }
+    padded
+}
+
 /// Parses a slice of [`StringRecords`] into a [RecordBatch]
 fn parse(
     rows: &StringRecords<'_>,
@@ -584,6 +774,9 @@ fn parse(
     projection: Option<&Vec<usize>>,
     line_number: usize,
     datetime_format: Option<&str>,
+    datetime_formats: &[String],
+    default_timezone: Option<&Tz>,
+    null_values: &NullValues,
 ) -> Result<RecordBatch, ArrowError> {
     let projection: Vec<usize> = match projection {
         Some(v) => v.clone(),
@@ -596,86 +789,200 @@ fn parse(
             let i = *i;
             let field = &fields[i];
             match field.data_type() {
-                DataType::Boolean => build_boolean_array(line_number, rows, i),
-                DataType::Decimal128(precision, scale) => {
-                    build_decimal_array(line_number, rows, i, *precision, *scale)
-                }
-                DataType::Int8 => {
-                    build_primitive_array::<Int8Type>(line_number, rows, i, None)
-                }
-                DataType::Int16 => {
-                    build_primitive_array::<Int16Type>(line_number, rows, i, None)
-                }
-                DataType::Int32 => {
-                    build_primitive_array::<Int32Type>(line_number, rows, i, None)
-                }
-                DataType::Int64 => {
-                    build_primitive_array::<Int64Type>(line_number, rows, i, None)
-                }
-                DataType::UInt8 => {
-                    build_primitive_array::<UInt8Type>(line_number, rows, i, None)
-                }
-                DataType::UInt16 => {
-                    build_primitive_array::<UInt16Type>(line_number, rows, i, None)
-                }
-                DataType::UInt32 => {
-                    build_primitive_array::<UInt32Type>(line_number, rows, i, None)
-                }
-                DataType::UInt64 => {
-                    build_primitive_array::<UInt64Type>(line_number, rows, i, None)
+                DataType::Boolean => {
+                    build_boolean_array(line_number, rows, i, null_values)
                 }
-                DataType::Float32 => {
-                    build_primitive_array::<Float32Type>(line_number, rows, i, None)
-                }
-                DataType::Float64 => {
-                    build_primitive_array::<Float64Type>(line_number, rows, i, None)
+                DataType::Decimal128(precision, scale) => {
+                    build_decimal_array::<Decimal128Type>(
+                        line_number,
+                        rows,
+                        i,
+                        *precision,
+                        *scale,
+                        null_values,
+                    )
                 }
-                DataType::Date32 => {
-                    build_primitive_array::<Date32Type>(line_number, rows, i, None)
+                DataType::Decimal256(precision, scale) => {
+                    build_decimal_array::<Decimal256Type>(
+                        line_number,
+                        rows,
+                        i,
+                        *precision,
+                        *scale,
+                        null_values,
+                    )
                 }
+                DataType::Int8 => build_primitive_array::<Int8Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
+                DataType::Int16 => build_primitive_array::<Int16Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
+                DataType::Int32 => build_primitive_array::<Int32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
+                DataType::Int64 => build_primitive_array::<Int64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
+                DataType::UInt8 => build_primitive_array::<UInt8Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
+                DataType::UInt16 => build_primitive_array::<UInt16Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
+                DataType::UInt32 => build_primitive_array::<UInt32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
+                DataType::UInt64 => build_primitive_array::<UInt64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
+                DataType::Float32 => build_primitive_array::<Float32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
+                DataType::Float64 => build_primitive_array::<Float64Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
+                DataType::Date32 => build_primitive_array::<Date32Type>(
+                    line_number,
+                    rows,
+                    i,
+                    None,
+                    null_values,
+                ),
                 DataType::Date64 => build_primitive_array::<Date64Type>(
                     line_number,
                     rows,
                     i,
                     datetime_format,
+                    null_values,
                 ),
                 DataType::Time32(TimeUnit::Second) => {
-                    build_primitive_array::<Time32SecondType>(line_number, rows, i, None)
+                    build_primitive_array::<Time32SecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        None,
+                        null_values,
+                    )
                 }
-                DataType::Time32(TimeUnit::Millisecond) => build_primitive_array::<
-                    Time32MillisecondType,
-                >(
-                    line_number, rows, i, None
-                ),
-                DataType::Time64(TimeUnit::Microsecond) => build_primitive_array::<
-                    Time64MicrosecondType,
-                >(
-                    line_number, rows, i, None
-                ),
-                DataType::Time64(TimeUnit::Nanosecond) => build_primitive_array::<
-                    Time64NanosecondType,
-                >(
-                    line_number, rows, i, None
-                ),
-                DataType::Timestamp(TimeUnit::Microsecond, _) => {
-                    build_primitive_array::<TimestampMicrosecondType>(
+                DataType::Time32(TimeUnit::Millisecond) => {
+                    build_primitive_array::<Time32MillisecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        None,
+                        null_values,
+                    )
+                }
+                DataType::Time64(TimeUnit::Microsecond) => {
+                    build_primitive_array::<Time64MicrosecondType>(
                         line_number,
                         rows,
                         i,
                         None,
+                        null_values,
                     )
                 }
-                DataType::Timestamp(TimeUnit::Nanosecond, _) => {
-                    build_primitive_array::<TimestampNanosecondType>(
+                DataType::Time64(TimeUnit::Nanosecond) => {
+                    build_primitive_array::<Time64NanosecondType>(
                         line_number,
                         rows,
                         i,
                         None,
+                        null_values,
+                    )
+                }
+                DataType::Timestamp(TimeUnit::Second, tz) => {
+                    build_timestamp_array::<TimestampSecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        tz.clone(),
+                        datetime_formats,
+                        default_timezone,
+                        null_values,
+                    )
+                }
+                DataType::Timestamp(TimeUnit::Millisecond, tz) => {
+                    build_timestamp_array::<TimestampMillisecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        tz.clone(),
+                        datetime_formats,
+                        default_timezone,
+                        null_values,
+                    )
+                }
+                DataType::Timestamp(TimeUnit::Microsecond, tz) => {
+                    build_timestamp_array::<TimestampMicrosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        tz.clone(),
+                        datetime_formats,
+                        default_timezone,
+                        null_values,
+                    )
+                }
+                DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
+                    build_timestamp_array::<TimestampNanosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        tz.clone(),
+                        datetime_formats,
+                        default_timezone,
+                        null_values,
                     )
                 }
                 DataType::Utf8 => Ok(Arc::new(
                     rows.iter()
-                        .map(|row| Some(row.get(i)))
+                        .zip(padded_fields(rows, i))
+                        .map(|(row, padded)| {
+                            let s = row.get(i);
+                            (!padded && !null_values.is_string_null(i, s)).then_some(s)
+                        })
                         .collect::<StringArray>(),
                 ) as ArrayRef),
                 DataType::Dictionary(key_type, value_type)
@@ -684,42 +991,82 @@ fn parse(
                     match key_type.as_ref() {
                         DataType::Int8 => Ok(Arc::new(
                             rows.iter()
-                                .map(|row| row.get(i))
+                                .zip(padded_fields(rows, i))
+                                .map(|(row, padded)| {
+                                    let s = row.get(i);
+                                    (!padded && !null_values.is_string_null(i, s))
+                                        .then_some(s)
+                                })
                                 .collect::<DictionaryArray<Int8Type>>(),
                         ) as ArrayRef),
                         DataType::Int16 => Ok(Arc::new(
                             rows.iter()
-                                .map(|row| row.get(i))
+                                .zip(padded_fields(rows, i))
+                                .map(|(row, padded)| {
+                                    let s = row.get(i);
+                                    (!padded && !null_values.is_string_null(i, s))
+                                        .then_some(s)
+                                })
                                 .collect::<DictionaryArray<Int16Type>>(),
                         ) as ArrayRef),
                         DataType::Int32 => Ok(Arc::new(
                             rows.iter()
-                                .map(|row| row.get(i))
+                                .zip(padded_fields(rows, i))
+                                .map(|(row, padded)| {
+                                    let s = row.get(i);
+                                    (!padded && !null_values.is_string_null(i, s))
+                                        .then_some(s)
+                                })
                                 .collect::<DictionaryArray<Int32Type>>(),
                         ) as ArrayRef),
                         DataType::Int64 => Ok(Arc::new(
                             rows.iter()
-                                .map(|row| row.get(i))
+                                .zip(padded_fields(rows, i))
+                                .map(|(row, padded)| {
+                                    let s = row.get(i);
+                                    (!padded && !null_values.is_string_null(i, s))
+                                        .then_some(s)
+                                })
                                 .collect::<DictionaryArray<Int64Type>>(),
                         ) as ArrayRef),
                         DataType::UInt8 => Ok(Arc::new(
                             rows.iter()
-                                .map(|row| row.get(i))
+                                .zip(padded_fields(rows, i))
+                                .map(|(row, padded)| {
+                                    let s = row.get(i);
+                                    (!padded && !null_values.is_string_null(i, s))
+                                        .then_some(s)
+                                })
                                 .collect::<DictionaryArray<UInt8Type>>(),
                         ) as ArrayRef),
                         DataType::UInt16 => Ok(Arc::new(
                             rows.iter()
-                                .map(|row| row.get(i))
+                                .zip(padded_fields(rows, i))
+                                .map(|(row, padded)| {
+                                    let s = row.get(i);
+                                    (!padded && !null_values.is_string_null(i, s))
+                                        .then_some(s)
+                                })
                                 .collect::<DictionaryArray<UInt16Type>>(),
                         ) as ArrayRef),
                         DataType::UInt32 => Ok(Arc::new(
                             rows.iter()
-                                .map(|row| row.get(i))
+                                .zip(padded_fields(rows, i))
+                                .map(|(row, padded)| {
+                                    let s = row.get(i);
+                                    (!padded && !null_values.is_string_null(i, s))
+                                        .then_some(s)
+                                })
                                 .collect::<DictionaryArray<UInt32Type>>(),
                         ) as ArrayRef),
                         DataType::UInt64 => Ok(Arc::new(
                             rows.iter()
-                                .map(|row| row.get(i))
+                                .zip(padded_fields(rows, i))
+                                .map(|(row, padded)| {
+                                    let s = row.get(i);
+                                    (!padded && !null_values.is_string_null(i, s))
+                                        .then_some(s)
+                                })
                                 .collect::<DictionaryArray<UInt64Type>>(),
                         ) as ArrayRef),
                         _ => Err(ArrowError::ParseError(format!(
@@ -771,30 +1118,27 @@ fn parse_bool(string: &str) -> Option<bool> {
 }
 
 // parse the column string to an Arrow Array
-fn build_decimal_array(
+fn build_decimal_array<T: DecimalType>(
     _line_number: usize,
     rows: &StringRecords<'_>,
     col_idx: usize,
     precision: u8,
     scale: i8,
-) -> Result<ArrayRef, ArrowError> {
-    let mut decimal_builder = Decimal128Builder::with_capacity(rows.len());
-    for row in rows.iter() {
+    null_values: &NullValues,
+) -> Result<ArrayRef, ArrowError>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    let mut decimal_builder = PrimitiveBuilder::<T>::with_capacity(rows.len());
+    for (row, padded) in rows.iter().zip(padded_fields(rows, col_idx)) {
         let s = row.get(col_idx);
-        if s.is_empty() {
+        if padded || null_values.is_null(col_idx, s) {
             // append null
             decimal_builder.append_null();
         } else {
-            let decimal_value: Result<i128, _> =
-                parse_decimal_with_parameter(s, precision, scale);
-            match decimal_value {
-                Ok(v) => {
-                    decimal_builder.append_value(v);
-                }
-                Err(e) => {
-                    return Err(e);
-                }
-            }
+            let decimal_value =
+                arrow_cast::parse::parse_decimal::<T>(s, precision, scale)?;
+            decimal_builder.append_value(decimal_value);
         }
     }
     Ok(Arc::new(
@@ -804,72 +1148,6 @@ fn build_decimal_array(
     ))
 }
 
-// Parse the string format decimal value to i128 format and checking the precision and scale.
-// The result i128 value can't be out of bounds.
-fn parse_decimal_with_parameter(
-    s: &str,
-    precision: u8,
-    scale: i8,
-) -> Result<i128, ArrowError> {
-    if PARSE_DECIMAL_RE.is_match(s) {
-        let mut offset = s.len();
-        let len = s.len();
-        let mut base = 1;
-        let scale_usize = usize::from(scale as u8);
-
-        // handle the value after the '.' and meet the scale
-        let delimiter_position = s.find('.');
-        match delimiter_position {
-            None => {
-                // there is no '.'
-                base = 10_i128.pow(scale as u32);
-            }
-            Some(mid) => {
-                // there is the '.'
-                if len - mid >= scale_usize + 1 {
-                    // If the string value is "123.12345" and the scale is 2, we should just remain '.12' and drop the '345' value.
-                    offset -= len - mid - 1 - scale_usize;
-                } else {
-                    // If the string value is "123.12" and the scale is 4, we should append '00' to the tail.
-                    base = 10_i128.pow((scale_usize + 1 + mid - len) as u32);
-                }
-            }
-        };
-
-        // each byte is digit、'-' or '.'
-        let bytes = s.as_bytes();
-        let mut negative = false;
-        let mut result: i128 = 0;
-
-        bytes[0..offset].iter().rev().for_each(|&byte| match byte {
-            b'-' => {
-                negative = true;
-            }
-            b'0'..=b'9' => {
-                result += i128::from(byte - b'0') * base;
-                base *= 10;
-            }
-            // because of the PARSE_DECIMAL_RE, bytes just contains digit、'-' and '.'.
-            _ => {}
-        });
-
-        if negative {
-            result = result.neg();
-        }
-
-        match validate_decimal_precision(result, precision) {
-            Ok(_) => Ok(result),
-            Err(e) => Err(ArrowError::ParseError(format!(
-                "parse decimal overflow: {e}"
-            ))),
-        }
-    } else {
-        Err(ArrowError::ParseError(format!(
-            "can't parse the string value {s} to decimal"
-        )))
-    }
-}
-
 // Parse the string format decimal value to i128 format without checking the precision and scale.
 // Like "125.12" to 12512_i128.
 #[cfg(test)]
@@ -903,7 +1181,7 @@ fn parse_decimal(s: &str) -> Result<i128, ArrowError> {
             offset -= 1;
         }
         if negative {
-            Ok(result.neg())
+            Ok(-result)
         } else {
             Ok(result)
         }
@@ -920,12 +1198,14 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
     rows: &StringRecords<'_>,
     col_idx: usize,
     format: Option<&str>,
+    null_values: &NullValues,
 ) -> Result<ArrayRef, ArrowError> {
+    let padded = padded_fields(rows, col_idx);
     rows.iter()
         .enumerate()
         .map(|(row_index, row)| {
             let s = row.get(col_idx);
-            if s.is_empty() {
+            if padded[row_index] || null_values.is_null(col_idx, s) {
                 return Ok(None);
             }
 
@@ -948,17 +1228,72 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
         .map(|e| Arc::new(e) as ArrayRef)
 }
 
+// parses a specific column (col_idx) into a timestamp Arrow Array, trying the
+// datetime formats before the default timestamp formats
+fn build_timestamp_array<T: ArrowTimestampType<Native = i64>>(
+    line_number: usize,
+    rows: &StringRecords<'_>,
+    col_idx: usize,
+    timezone: Option<String>,
+    datetime_formats: &[String],
+    default_timezone: Option<&Tz>,
+    null_values: &NullValues,
+) -> Result<ArrayRef, ArrowError> {
+    // values without an explicit offset are local times in the default timezone,
+    // falling back to the timezone of the column, and then to UTC
+    let local_timezone = match (default_timezone, &timezone) {
+        (Some(tz), _) => Some(*tz),
+        (None, Some(tz)) => Some(tz.parse::<Tz>()?),
+        (None, None) => None,
+    };
+    let padded = padded_fields(rows, col_idx);
+    rows.iter()
+        .enumerate()
+        .map(|(row_index, row)| {
+            let s = row.get(col_idx);
+            if padded[row_index] || null_values.is_null(col_idx, s) {
+                return Ok(None);
+            }
+
+            let parsed = match &local_timezone {
+                Some(tz) => string_to_datetime_formatted(tz, s, datetime_formats)
+                    .map(|datetime| datetime.naive_utc()),
+                None => string_to_datetime_formatted(&Utc, s, datetime_formats)
+                    .map(|datetime| datetime.naive_utc()),
+            };
+            let detail = match parsed {
+                Ok(datetime) => match T::make_value(datetime) {
+                    Some(e) => return Ok(Some(e)),
+                    None => String::new(),
+                },
+                Err(ArrowError::ParseError(e)) => format!(": {e}"),
+                Err(_) => String::new(),
+            };
+            Err(ArrowError::ParseError(format!(
+                "Error while parsing value {} for column {} at line {}{}",
+                s,
+                col_idx,
+                line_number + row_index,
+                detail
+            )))
+        })
+        .collect::<Result<PrimitiveArray<T>, ArrowError>>()
+        .map(|e| Arc::new(e.with_timezone_opt(timezone)) as ArrayRef)
+}
+
 // parses a specific column (col_idx) into an Arrow Array.
 fn build_boolean_array(
     line_number: usize,
     rows: &StringRecords<'_>,
     col_idx: usize,
+    null_values: &NullValues,
 ) -> Result<ArrayRef, ArrowError> {
+    let padded = padded_fields(rows, col_idx);
     rows.iter()
         .enumerate()
         .map(|(row_index, row)| {
             let s = row.get(col_idx);
-            if s.is_empty() {
+            if padded[row_index] || null_values.is_null(col_idx, s) {
                 return Ok(None);
             }
             let parsed = parse_bool(s);
@@ -978,7 +1313,7 @@ fn build_boolean_array(
 }
 
 /// CSV file reader builder
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReaderBuilder {
     /// Optional schema for the CSV file
     ///
@@ -998,6 +1333,10 @@ pub struct ReaderBuilder {
     quote: Option<u8>,
     /// An optional record terminator. Defaults CRLF
     terminator: Option<u8>,
+    /// An optional comment character, lines starting with it are ignored
+    comment: Option<u8>,
+    /// The number of leading lines to skip, before any header. Defaults 0
+    skip_rows: usize,
     /// Optional maximum number of records to read during schema inference
     ///
     /// If a number is not provided, all the records are read.
@@ -1014,6 +1353,16 @@ pub struct ReaderBuilder {
     datetime_re: Option<Regex>,
     /// DateTime format to be used while parsing datetime format
     datetime_format: Option<String>,
+    /// Additional datetime formats used to infer and parse timestamps
+    datetime_formats: Vec<String>,
+    /// The timezone of timestamp values without an explicit offset
+    default_timezone: Option<Tz>,
+    /// Whether to infer decimals rather than floating point numbers
+    infer_decimals: bool,
+    /// The strings read as null
+    null_values: NullValues,
+    /// How to handle records with an unexpected number of fields
+    ragged_rows: RaggedRows,
 }
 
 impl Default for ReaderBuilder {
@@ -1025,12 +1374,19 @@ impl Default for ReaderBuilder {
             escape: None,
             quote: None,
             terminator: None,
+            comment: None,
+            skip_rows: 0,
             max_records: None,
             batch_size: 1024,
             bounds: None,
             projection: None,
             datetime_re: None,
             datetime_format: None,
+            datetime_formats: vec![],
+            default_timezone: None,
+            infer_decimals: false,
+            null_values: NullValues::default(),
+            ragged_rows: RaggedRows::default(),
         }
     }
 }
@@ -1090,6 +1446,100 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set the strings read as null in all columns, such as `"NA"` or `"null"`
+    ///
+    /// By default only empty fields are read as null, except in string columns where
+    /// they are read as empty strings. Setting null values replaces this default, so
+    /// include `""` to also read empty fields as null.
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::Array;
+    /// # use arrow_csv::ReaderBuilder;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// let schema = Schema::new(vec![
+    ///     Field::new("a", DataType::Int32, true),
+    ///     Field::new("b", DataType::Utf8, true),
+    /// ]);
+    /// let csv = "1,x\nNA,y\n,z\n4,w\n";
+    /// let mut reader = ReaderBuilder::new()
+    ///     .with_schema(Arc::new(schema))
+    ///     .with_null_values(["NA", ""])
+    ///     .build_buffered(std::io::Cursor::new(csv))
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.column(0).null_count(), 2);
+    /// ```
+    pub fn with_null_values<I, S>(mut self, null_values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.null_values.all = Some(null_values.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the strings read as null in the column at `column_index`, overriding
+    /// those set with [`Self::with_null_values`] for this column
+    ///
+    /// `column_index` is the zero-based index of the column in the file, before
+    /// any projection is applied
+    pub fn with_column_null_values<I, S>(
+        mut self,
+        column_index: usize,
+        null_values: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.null_values.columns.insert(
+            column_index,
+            null_values.into_iter().map(Into::into).collect(),
+        );
+        self
+    }
+
+    /// Set how to handle records with a different number of fields to the schema,
+    /// defaults to [`RaggedRows::Error`]
+    pub fn with_ragged_rows(mut self, ragged_rows: RaggedRows) -> Self {
+        self.ragged_rows = ragged_rows;
+        self
+    }
+
+    /// Set additional strptime-style datetime formats, such as `"%d/%m/%Y %H:%M"`
+    ///
+    /// During schema inference, values matching one of the formats are inferred to be
+    /// timestamps, and timestamp columns are parsed by trying each format in turn before
+    /// the default RFC3339-like formats. Formats without a time component are read as
+    /// midnight, and formats with an offset are converted to UTC.
+    pub fn with_datetime_formats(mut self, datetime_formats: Vec<String>) -> Self {
+        self.datetime_formats = datetime_formats;
+        self
+    }
+
+    /// Set the timezone of timestamp values without an explicit offset, such as
+    /// `2023-03-15T10:30:00`, defaults to the timezone of the timestamp column they
+    /// are read into, or UTC if it has none
+    ///
+    /// Values with an explicit offset, such as `2023-03-15T10:30:00+02:00`, are
+    /// unaffected. All values are normalized to UTC
+    pub fn with_default_timezone(mut self, timezone: Tz) -> Self {
+        self.default_timezone = Some(timezone);
+        self
+    }
+
+    /// Set whether schema inference reads floating point numbers as decimals
+    ///
+    /// If true, a column of numbers without exponents is inferred to be a
+    /// [`DataType::Decimal128`] with the precision and scale needed to represent
+    /// all its values exactly, falling back to [`DataType::Float64`] if they do not
+    /// fit. Defaults to false.
+    pub fn with_decimal_inference(mut self, infer_decimals: bool) -> Self {
+        self.infer_decimals = infer_decimals;
+        self
+    }
+
     /// Set the CSV file's column delimiter as a byte character
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
         self.delimiter = Some(delimiter);
@@ -1111,6 +1561,21 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set a comment character, records starting with it are ignored
+    pub fn with_comment(mut self, comment: u8) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Set the number of leading lines to skip, before the header if any
+    ///
+    /// Skipped lines are not parsed as CSV, and so may contain arbitrary content
+    /// such as a preamble describing the file
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
     /// Set the CSV reader to infer the schema of the file
     pub fn infer_schema(mut self, max_records: Option<usize>) -> Self {
         // remove any schema that is set
@@ -1161,26 +1626,245 @@ impl ReaderBuilder {
                 escape: self.escape,
                 quote: self.quote,
                 terminator: self.terminator,
+                comment: self.comment,
+                skip_rows: self.skip_rows,
+                ragged_rows: self.ragged_rows,
                 datetime_re: self.datetime_re.take(),
+                datetime_formats: self.datetime_formats.clone(),
+                infer_decimals: self.infer_decimals,
+                null_values: self.null_values.clone(),
             };
             let (inferred_schema, _) =
                 infer_file_schema_with_csv_options(&mut reader, roptions)?;
             self.schema = Some(Arc::new(inferred_schema))
         }
 
-        Ok(BufReader {
-            reader,
-            decoder: self.build_decoder(),
-        })
+        Ok(BufReader {
+            reader,
+            decoder: self.build_decoder(),
+        })
+    }
+
+    /// Builds a decoder that can be used to decode CSV from an arbitrary byte stream
+    ///
+    /// # Panics
+    ///
+    /// This method panics if no schema provided
+    pub fn build_decoder(self) -> Decoder {
+        let delimiter = self.build_record_reader();
+        let schema = self.schema.expect("schema should be provided");
+        let record_decoder =
+            RecordDecoder::new(delimiter, schema.fields().len(), self.ragged_rows);
+
+        let header = self.has_header as usize;
+
+        let (start, end) = match self.bounds {
+            Some((start, end)) => (start + header, end + header),
+            None => (header, usize::MAX),
+        };
+
+        Decoder {
+            schema,
+            skip_lines: self.skip_rows,
+            line_terminator: self.terminator.unwrap_or(b'\n'),
+            to_skip: start,
+            record_decoder,
+            line_number: start,
+            end,
+            projection: self.projection,
+            datetime_format: self.datetime_format,
+            datetime_formats: self.datetime_formats,
+            default_timezone: self.default_timezone,
+            batch_size: self.batch_size,
+            null_values: self.null_values,
+        }
+    }
+
+    /// Reads all of `reader` into [`RecordBatch`]es of up to `batch_size` records,
+    /// parsing them on up to `num_threads` threads
+    ///
+    /// The whole input is read into memory, and split into chunks of `batch_size`
+    /// records by a sequential scan for record terminators that tracks quoting but
+    /// does not parse fields. The chunks are then parsed on at most `num_threads`
+    /// threads, spawned for the duration of the call. The returned batches are in
+    /// the same order as the records in the input
+    ///
+    /// As memory use is proportional to the size of the input, large inputs should
+    /// be read in portions that end on a record boundary, or with [`Reader`]
+    ///
+    /// Returns an error if no schema was provided
+    pub fn read_parallel<R: Read>(
+        self,
+        mut reader: R,
+        num_threads: usize,
+    ) -> Result<Vec<RecordBatch>, ArrowError> {
+        if self.schema.is_none() {
+            return Err(ArrowError::CsvError(
+                "A schema must be provided to read CSV in parallel".to_string(),
+            ));
+        }
+
+        let mut data = vec![];
+        reader.read_to_end(&mut data)?;
+        let data: Arc<[u8]> = data.into();
+        let chunks = self.record_chunks(&data);
+
+        // Each chunk is decoded independently, starting from its first record
+        let builder = Self {
+            has_header: false,
+            skip_rows: 0,
+            bounds: None,
+            ..self
+        };
+
+        let num_threads = num_threads.clamp(1, chunks.len().max(1));
+        let handles: Vec<_> = (0..num_threads)
+            .map(|thread| {
+                let builder = builder.clone();
+                let data = Arc::clone(&data);
+                let chunks: Vec<_> = chunks
+                    .iter()
+                    .skip(thread)
+                    .step_by(num_threads)
+                    .cloned()
+                    .collect();
+                std::thread::spawn(move || {
+                    chunks
+                        .into_iter()
+                        .map(|(range, line_number)| {
+                            let mut decoder = builder.clone().build_decoder();
+                            decoder.line_number = line_number;
+                            let mut buf = &data[range];
+                            loop {
+                                let decoded = decoder.decode(buf)?;
+                                if decoded == 0 {
+                                    break;
+                                }
+                                buf = &buf[decoded..];
+                            }
+                            decoder.flush()
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut results = handles
+            .into_iter()
+            .map(|handle| {
+                handle.join().map_err(|_| {
+                    ArrowError::CsvError("CSV parsing thread panicked".to_string())
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Chunks were assigned to threads round-robin
+        let mut batches = Vec::with_capacity(chunks.len());
+        for idx in 0..chunks.len() {
+            let result = std::mem::replace(
+                &mut results[idx % num_threads][idx / num_threads],
+                Ok(None),
+            );
+            batches.extend(result?);
+        }
+        Ok(batches)
+    }
+
+    /// Splits `data` into the byte ranges of chunks of up to `batch_size` records,
+    /// along with the index of their first record, honoring the configured
+    /// skipped rows, header and bounds
+    fn record_chunks(&self, data: &[u8]) -> Vec<(Range<usize>, usize)> {
+        let line_terminator = self.terminator.unwrap_or(b'\n');
+        let mut offset = 0;
+        for _ in 0..self.skip_rows {
+            match data[offset..].iter().position(|b| *b == line_terminator) {
+                Some(idx) => offset += idx + 1,
+                None => offset = data.len(),
+            }
+        }
+
+        let header = self.has_header as usize;
+        let (start, end) = match self.bounds {
+            Some((start, end)) => (start + header, end + header),
+            None => (header, usize::MAX),
+        };
+
+        let mut chunks: Vec<(Range<usize>, usize)> = vec![];
+        let mut record = 0;
+        while record < end {
+            let record_start = offset;
+            match self.next_record_end(&data[offset..]) {
+                Some(len) => offset += len,
+                None => break,
+            }
+
+            if record >= start {
+                match chunks.last_mut() {
+                    Some((range, first)) if record - *first < self.batch_size.max(1) => {
+                        range.end = offset
+                    }
+                    _ => chunks.push((record_start..offset, record)),
+                }
+            }
+            record += 1;
+        }
+        chunks
     }
 
-    /// Builds a decoder that can be used to decode CSV from an arbitrary byte stream
-    ///
-    /// # Panics
+    /// Returns the length of the next record of `data` including its terminator, or
+    /// `None` if `data` contains no further records
     ///
-    /// This method panics if no schema provided
-    pub fn build_decoder(self) -> Decoder {
-        let schema = self.schema.expect("schema should be provided");
+    /// Follows the record boundaries of [`csv_core::Reader`], including quoted
+    /// terminators and skipped empty and comment lines, without parsing fields
+    fn next_record_end(&self, data: &[u8]) -> Option<usize> {
+        #[derive(Clone, Copy)]
+        enum State {
+            StartRecord,
+            StartField,
+            InField,
+            InQuotedField,
+            InEscapedQuote,
+            InDoubleEscapedQuote,
+            InComment,
+        }
+
+        let delimiter = self.delimiter.unwrap_or(b',');
+        let quote = self.quote.unwrap_or(b'"');
+        let is_terminator = |c: u8| match self.terminator {
+            Some(t) => c == t,
+            None => c == b'\r' || c == b'\n',
+        };
+
+        let mut state = State::StartRecord;
+        for (idx, c) in data.iter().copied().enumerate() {
+            state = match state {
+                State::StartRecord if is_terminator(c) => State::StartRecord,
+                State::StartRecord if self.comment == Some(c) => State::InComment,
+                State::InComment if c == b'\n' => State::StartRecord,
+                State::InComment => State::InComment,
+                State::InQuotedField if c == quote => State::InDoubleEscapedQuote,
+                State::InQuotedField if self.escape == Some(c) => State::InEscapedQuote,
+                State::InQuotedField | State::InEscapedQuote => State::InQuotedField,
+                // A quote only starts a quoted field at the start of a field, and a
+                // doubled quote is an escaped quote within a quoted field
+                State::StartRecord | State::StartField | State::InDoubleEscapedQuote
+                    if c == quote =>
+                {
+                    State::InQuotedField
+                }
+                _ if c == delimiter => State::StartField,
+                _ if is_terminator(c) => return Some(idx + 1),
+                _ => State::InField,
+            };
+        }
+        match state {
+            State::StartRecord | State::InComment => None,
+            _ => Some(data.len()),
+        }
+    }
+
+    /// Creates a [`csv_core::Reader`] with the configured dialect
+    fn build_record_reader(&self) -> csv_core::Reader {
         let mut reader_builder = csv_core::ReaderBuilder::new();
         reader_builder.escape(self.escape);
 
@@ -1193,26 +1877,8 @@ impl ReaderBuilder {
         if let Some(t) = self.terminator {
             reader_builder.terminator(csv_core::Terminator::Any(t));
         }
-        let delimiter = reader_builder.build();
-        let record_decoder = RecordDecoder::new(delimiter, schema.fields().len());
-
-        let header = self.has_header as usize;
-
-        let (start, end) = match self.bounds {
-            Some((start, end)) => (start + header, end + header),
-            None => (header, usize::MAX),
-        };
-
-        Decoder {
-            schema,
-            to_skip: start,
-            record_decoder,
-            line_number: start,
-            end,
-            projection: self.projection,
-            datetime_format: self.datetime_format,
-            batch_size: self.batch_size,
-        }
+        reader_builder.comment(self.comment);
+        reader_builder.build()
     }
 }
 
@@ -1223,7 +1889,8 @@ mod tests {
     use std::io::{Cursor, Write};
     use tempfile::NamedTempFile;
 
-    use arrow_array::cast::as_boolean_array;
+    use arrow_array::cast::{as_boolean_array, as_primitive_array};
+    use arrow_buffer::i256;
     use chrono::prelude::*;
 
     #[test]
@@ -1335,6 +2002,33 @@ mod tests {
         assert_eq!("-50.760000", lat.value_as_string(9));
     }
 
+    #[test]
+    fn test_csv_reader_with_decimal256() {
+        let schema = Schema::new(vec![
+            Field::new("city", DataType::Utf8, false),
+            Field::new("lat", DataType::Decimal256(50, 10), false),
+            Field::new("lng", DataType::Decimal256(20, 6), false),
+        ]);
+
+        let file = File::open("test/data/decimal_test.csv").unwrap();
+
+        let mut csv = ReaderBuilder::new()
+            .with_schema(Arc::new(schema))
+            .build(file)
+            .unwrap();
+        let batch = csv.next().unwrap().unwrap();
+
+        let lat = as_primitive_array::<Decimal256Type>(batch.column(1));
+        assert_eq!(lat.precision(), 50);
+        assert_eq!(lat.scale(), 10);
+        assert_eq!(lat.value(0), i256::from_i128(576534840000));
+        assert_eq!(lat.value(9), i256::from_i128(-507600000000));
+
+        let lng = as_primitive_array::<Decimal256Type>(batch.column(2));
+        assert_eq!(lng.value_as_string(0), "-3.335724");
+        assert_eq!(lng.value_as_string(5), "0.290472");
+    }
+
     #[test]
     fn test_csv_from_buf_reader() {
         let schema = Schema::new(vec![
@@ -1614,6 +2308,239 @@ mod tests {
         assert!(!batch.column(1).is_null(4));
     }
 
+    #[test]
+    fn test_null_values() {
+        let csv = "a,b,c,d\n1,NA,x,true\nnull,2.5,NA,-\n,-,,false\n";
+
+        // null values are used to infer the schema
+        let mut reader = ReaderBuilder::new()
+            .has_header(true)
+            .infer_schema(None)
+            .with_null_values(["NA", "null", ""])
+            .with_column_null_values(3, ["-"])
+            .build(Cursor::new(csv))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let schema = batch.schema();
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(2).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(3).data_type(), &DataType::Boolean);
+
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(a, &Int64Array::from(vec![Some(1), None, None]));
+        let c = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(c, &StringArray::from(vec![Some("x"), None, None]));
+        // the column null values replace those for all columns
+        let d = as_boolean_array(batch.column(3));
+        assert_eq!(d, &BooleanArray::from(vec![Some(true), None, Some(false)]));
+
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Float64, true),
+            Field::new(
+                "c",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                true,
+            ),
+            Field::new("d", DataType::Utf8, true),
+        ]);
+        let mut reader = ReaderBuilder::new()
+            .has_header(true)
+            .with_schema(Arc::new(schema))
+            .with_null_values(["NA", "null", "-", ""])
+            .build(Cursor::new(csv))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(b, &Float64Array::from(vec![None, Some(2.5), None]));
+        let c = batch.column(2);
+        assert_eq!(c.null_count(), 2);
+        assert!(c.is_valid(0));
+        let d = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            d,
+            &StringArray::from(vec![Some("true"), None, Some("false")])
+        );
+
+        // without null values, only empty fields of non-string columns are null
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Utf8, true),
+            Field::new("d", DataType::Utf8, true),
+        ]);
+        let mut reader = ReaderBuilder::new()
+            .has_header(true)
+            .with_schema(Arc::new(schema))
+            .build(Cursor::new(csv))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(a, &StringArray::from(vec!["1", "null", ""]));
+    }
+
+    #[test]
+    fn test_infer_custom_datetime_formats() {
+        let csv = "a,b\n01/02/2023 10:30,2023-02-01T10:30:00.000\n15/03/2023,\n";
+        let mut reader = ReaderBuilder::new()
+            .has_header(true)
+            .infer_schema(None)
+            .with_datetime_formats(vec![
+                "%d/%m/%Y %H:%M".to_string(),
+                "%d/%m/%Y".to_string(),
+            ])
+            .build(Cursor::new(csv))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let ts = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        assert_eq!(batch.schema().field(0).data_type(), &ts);
+        assert_eq!(batch.schema().field(1).data_type(), &ts);
+
+        let a = as_primitive_array::<TimestampNanosecondType>(batch.column(0));
+        let first = NaiveDate::from_ymd_opt(2023, 2, 1)
+            .unwrap()
+            .and_hms_opt(10, 30, 0)
+            .unwrap();
+        assert_eq!(a.value(0), first.timestamp_nanos());
+        let expected = NaiveDate::from_ymd_opt(2023, 3, 15)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        assert_eq!(a.value(1), expected.timestamp_nanos());
+        let b = as_primitive_array::<TimestampNanosecondType>(batch.column(1));
+        assert_eq!(a.value(0), b.value(0));
+        assert!(b.is_null(1));
+
+        // the formats are also used to parse columns of other time units
+        let schema = Schema::new(vec![
+            Field::new(
+                "a",
+                DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string())),
+                true,
+            ),
+            Field::new("b", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+        ]);
+        let mut reader = ReaderBuilder::new()
+            .has_header(true)
+            .with_schema(Arc::new(schema.clone()))
+            .with_datetime_formats(vec![
+                "%d/%m/%Y %H:%M".to_string(),
+                "%d/%m/%Y".to_string(),
+            ])
+            .build(Cursor::new(csv))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.schema().as_ref(), &schema);
+        // values without an offset are local times in the timezone of the column
+        let a = as_primitive_array::<TimestampSecondType>(batch.column(0));
+        assert_eq!(a.value(1), expected.timestamp() - 3600);
+        let b = as_primitive_array::<TimestampMillisecondType>(batch.column(1));
+        assert_eq!(b.value(0), first.timestamp_millis());
+    }
+
+    #[test]
+    fn test_default_timezone() {
+        let csv = "a,b\n\
+                   2023-03-15T10:30:00,2023-03-15T10:30:00+02:00\n\
+                   2023-03-15 10:30:00.5,2023-03-15 10:30:00Z\n\
+                   2023-03-15,15/03/2023 10:30\n\
+                   2023-03-15 10:30,2023-03-15 10:30\n";
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(
+                "a",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string())),
+                true,
+            ),
+            Field::new("b", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+        ]));
+        let mut reader = ReaderBuilder::new()
+            .has_header(true)
+            .with_schema(schema.clone())
+            .with_datetime_formats(vec!["%d/%m/%Y %H:%M".to_string()])
+            .with_default_timezone("-05:00".parse().unwrap())
+            .build(Cursor::new(csv))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.schema(), schema);
+
+        let utc = |s: &str| {
+            NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f")
+                .unwrap()
+                .timestamp_millis()
+        };
+        let a = as_primitive_array::<TimestampMillisecondType>(batch.column(0));
+        assert_eq!(
+            a.values(),
+            &[
+                utc("2023-03-15 15:30:00"),
+                utc("2023-03-15 15:30:00.5"),
+                utc("2023-03-15 05:00:00"),
+                utc("2023-03-15 15:30:00")
+            ]
+        );
+        let b = as_primitive_array::<TimestampMillisecondType>(batch.column(1));
+        assert_eq!(
+            b.values(),
+            &[
+                utc("2023-03-15 08:30:00"),
+                utc("2023-03-15 10:30:00"),
+                utc("2023-03-15 15:30:00"),
+                utc("2023-03-15 15:30:00")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_infer_decimals() {
+        let csv = "a,b,c,d\n1.5,1.25,1e3,1\n-20,0.125,2.5,2\n";
+        let builder = || ReaderBuilder::new().has_header(true).infer_schema(None);
+
+        let reader = builder().build(Cursor::new(csv)).unwrap();
+        let schema = reader.schema();
+        assert_eq!(schema.field(0).data_type(), &DataType::Float64);
+        assert_eq!(schema.field(1).data_type(), &DataType::Float64);
+
+        let mut reader = builder()
+            .with_decimal_inference(true)
+            .build(Cursor::new(csv))
+            .unwrap();
+        let schema = reader.schema();
+        assert_eq!(schema.field(0).data_type(), &DataType::Decimal128(3, 1));
+        assert_eq!(schema.field(1).data_type(), &DataType::Decimal128(4, 3));
+        // numbers with exponents are not read as decimals
+        assert_eq!(schema.field(2).data_type(), &DataType::Float64);
+        assert_eq!(schema.field(3).data_type(), &DataType::Int64);
+
+        let batch = reader.next().unwrap().unwrap();
+        let a = as_primitive_array::<Decimal128Type>(batch.column(0));
+        assert_eq!(a.value(0), 15);
+        assert_eq!(a.value(1), -200);
+        let b = as_primitive_array::<Decimal128Type>(batch.column(1));
+        assert_eq!(b.value(0), 1250);
+        assert_eq!(b.value(1), 125);
+    }
+
     #[test]
     fn test_parse_invalid_csv() {
         let file = File::open("test/data/various_types_invalid.csv").unwrap();
@@ -1778,20 +2705,33 @@ mod tests {
             ("-123.", -123000i128),
         ];
         for (s, i) in tests {
-            let result = parse_decimal_with_parameter(s, 20, 3);
+            let result = arrow_cast::parse::parse_decimal::<Decimal128Type>(s, 20, 3);
             assert_eq!(i, result.unwrap())
         }
         let can_not_parse_tests = ["123,123", ".", "123.123.123"];
         for s in can_not_parse_tests {
-            let result = parse_decimal_with_parameter(s, 20, 3);
+            let result = arrow_cast::parse::parse_decimal::<Decimal128Type>(s, 20, 3);
             assert_eq!(
                 format!("Parser error: can't parse the string value {s} to decimal"),
                 result.unwrap_err().to_string()
             );
         }
+        let result = arrow_cast::parse::parse_decimal::<Decimal256Type>(
+            "-123456789012345678901234567890123456789.0123",
+            50,
+            2,
+        );
+        assert_eq!(
+            result.unwrap(),
+            i256::from_string("-12345678901234567890123456789012345678901").unwrap()
+        );
+        let result =
+            arrow_cast::parse::parse_decimal::<Decimal128Type>("-12345.678", 5, -2);
+        assert_eq!(result.unwrap(), -123);
+
         let overflow_parse_tests = ["12345678", "12345678.9", "99999999.99"];
         for s in overflow_parse_tests {
-            let result = parse_decimal_with_parameter(s, 10, 3);
+            let result = arrow_cast::parse::parse_decimal::<Decimal128Type>(s, 10, 3);
             let expected = "Parser error: parse decimal overflow";
             let actual = result.unwrap_err().to_string();
 
@@ -1800,6 +2740,13 @@ mod tests {
                 "actual: '{actual}', expected: '{expected}'"
             );
         }
+        // values exceeding the native type are reported as overflow rather than panicking
+        let result =
+            arrow_cast::parse::parse_decimal::<Decimal128Type>(&"9".repeat(50), 38, 0);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("parse decimal overflow"));
     }
 
     #[test]
@@ -2232,6 +3179,287 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_decoder_chunks() {
+        let csv = "a,b\n1,\"hello\nworld\"\n2,foo\n3,\"bar,baz\"\n4,\n5,qux\n";
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+
+        let expected = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5])),
+                Arc::new(StringArray::from(vec![
+                    Some("hello\nworld"),
+                    Some("foo"),
+                    Some("bar,baz"),
+                    Some(""),
+                    Some("qux"),
+                ])),
+            ],
+        )
+        .unwrap();
+
+        for chunk_size in [1, 2, 5, 7, csv.len()] {
+            let mut decoder = ReaderBuilder::new()
+                .with_schema(schema.clone())
+                .has_header(true)
+                .with_batch_size(2)
+                .build_decoder();
+
+            let mut batches = vec![];
+            for mut chunk in csv.as_bytes().chunks(chunk_size) {
+                while !chunk.is_empty() {
+                    let decoded = decoder.decode(chunk).unwrap();
+                    chunk = &chunk[decoded..];
+                    if decoder.capacity() == 0 {
+                        batches.extend(decoder.flush().unwrap());
+                    }
+                }
+            }
+            batches.extend(decoder.flush().unwrap());
+            assert_eq!(decoder.capacity(), 2);
+
+            let rows: Vec<_> = batches.iter().map(|b| b.num_rows()).collect();
+            assert_eq!(rows, vec![2, 2, 1]);
+            for (i, batch) in batches.iter().enumerate() {
+                assert_eq!(batch, &expected.slice(i * 2, batch.num_rows()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_comments_and_skip_rows() {
+        let csv = "exported by some tool, version 1\n\
+                   #a comment, with \"an unterminated quote\n\
+                   a,b\n\
+                   1,foo\n\
+                   #2,bar\n\
+                   3,baz\n";
+
+        for buffer_capacity in [1, 3, 1024] {
+            let builder = ReaderBuilder::new()
+                .has_header(true)
+                .with_skip_rows(2)
+                .with_comment(b'#');
+            let buffered =
+                std::io::BufReader::with_capacity(buffer_capacity, Cursor::new(csv));
+            let mut reader = builder.build_buffered(buffered).unwrap();
+
+            let schema = reader.schema();
+            assert_eq!(schema.field(0).name(), "a");
+            assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+            assert_eq!(schema.field(1).name(), "b");
+            assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+
+            let batch = reader.next().unwrap().unwrap();
+            let a = as_primitive_array::<Int64Type>(batch.column(0));
+            assert_eq!(a.values(), &[1, 3]);
+            let b = batch
+                .column(1)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            assert_eq!(b.value(0), "foo");
+            assert_eq!(b.value(1), "baz");
+            assert!(reader.next().is_none());
+        }
+
+        // skipping more lines than present yields no records
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema)
+            .with_skip_rows(10)
+            .build(Cursor::new("1\n2\n"))
+            .unwrap();
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let csv = "a,b,c\n1,foo,x\n2\n3,bar\n";
+        let mut reader = ReaderBuilder::new()
+            .has_header(true)
+            .with_ragged_rows(RaggedRows::PadWithNulls)
+            .build(Cursor::new(csv))
+            .unwrap();
+
+        let schema = reader.schema();
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(2).data_type(), &DataType::Utf8);
+
+        let batch = reader.next().unwrap().unwrap();
+        let a = as_primitive_array::<Int64Type>(batch.column(0));
+        assert_eq!(a.values(), &[1, 2, 3]);
+        assert_eq!(a.null_count(), 0);
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            &[Some("foo"), None, Some("bar")]
+        );
+        let c = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(c.iter().collect::<Vec<_>>(), &[Some("x"), None, None]);
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let csv = "1,foo,x,y\n2,bar\n3,baz,z\n";
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema.clone())
+            .with_ragged_rows(RaggedRows::IgnoreExtra)
+            .build(Cursor::new(csv))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            &[Some("foo"), Some("bar"), Some("baz")]
+        );
+
+        // padding a non-nullable column is an error
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema)
+            .with_ragged_rows(RaggedRows::PadWithNulls)
+            .build(Cursor::new("1,foo\n2\n"))
+            .unwrap();
+        assert!(reader.next().unwrap().is_err());
+
+        // padded fields are null even if the empty string is not a null value
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Int64, true),
+        ]));
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema)
+            .with_ragged_rows(RaggedRows::PadWithNulls)
+            .with_null_values(["NA"])
+            .build(Cursor::new("x,1\ny\nNA,NA\n"))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(a.iter().collect::<Vec<_>>(), &[Some("x"), Some("y"), None]);
+        let b = as_primitive_array::<Int64Type>(batch.column(1));
+        assert_eq!(b.iter().collect::<Vec<_>>(), &[Some(1), None, None]);
+    }
+
+    #[test]
+    fn test_read_parallel() {
+        let tests = [
+            ("test/data/uk_cities.csv", false, None),
+            ("test/data/various_types.csv", true, None),
+            ("test/data/decimal_test.csv", false, Some((2, 8))),
+        ];
+
+        for (path, has_header, bounds) in tests {
+            let file = File::open(path).unwrap();
+            let builder = ReaderBuilder::new()
+                .has_header(has_header)
+                .with_delimiter(if path.contains("various") { b'|' } else { b',' });
+            let builder = match bounds {
+                Some((start, end)) => builder.with_bounds(start, end),
+                None => builder,
+            };
+            let schema = builder.clone().build(file).unwrap().schema();
+
+            for batch_size in [1, 3, 1024] {
+                let builder = builder
+                    .clone()
+                    .with_schema(schema.clone())
+                    .with_batch_size(batch_size);
+                let expected = builder
+                    .clone()
+                    .build(File::open(path).unwrap())
+                    .unwrap()
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap();
+
+                for num_threads in [1, 2, 5] {
+                    let actual = builder
+                        .clone()
+                        .read_parallel(File::open(path).unwrap(), num_threads)
+                        .unwrap();
+                    assert_eq!(expected, actual);
+                }
+            }
+        }
+
+        // quoted fields may contain line terminators
+        let csv = "a,b\n1,\"x\ny\"\n2,z\n3,\"\"";
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let batches = ReaderBuilder::new()
+            .with_schema(schema)
+            .has_header(true)
+            .with_batch_size(1)
+            .read_parallel(Cursor::new(csv), 2)
+            .unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .map(|batch| {
+                let b = batch
+                    .column(1)
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap();
+                b.value(0).to_string()
+            })
+            .collect();
+        assert_eq!(values, vec!["x\ny", "z", ""]);
+
+        // quotes only start a quoted field at the start of a field, and empty and
+        // comment lines are skipped
+        let csv = "1,a\"b\r\n\r\n#2,\"\n2,\"c,\"\"\nd\"\"\"\r\n3,\\\"\n\n#4\n4,e";
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let builder = ReaderBuilder::new()
+            .with_schema(schema)
+            .with_comment(b'#')
+            .with_escape(b'\\')
+            .with_batch_size(1);
+        let expected = builder
+            .clone()
+            .build(Cursor::new(csv))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(expected.len(), 4);
+        let actual = builder.read_parallel(Cursor::new(csv), 3).unwrap();
+        assert_eq!(expected, actual);
+
+        let err = ReaderBuilder::new()
+            .read_parallel(Cursor::new(csv), 2)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Csv error: A schema must be provided to read CSV in parallel"
+        );
+    }
+
     fn err_test(csv: &[u8], expected: &str) {
         let schema = Arc::new(Schema::new(vec![
             Field::new("text1", DataType::Utf8, false),
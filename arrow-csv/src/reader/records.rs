@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::reader::RaggedRows;
 use arrow_schema::ArrowError;
 use csv_core::{ReadRecordResult, Reader};
 
@@ -32,6 +33,12 @@ pub struct RecordDecoder {
     /// The expected number of fields per row
     num_columns: usize,
 
+    /// How to handle records with an unexpected number of fields
+    ragged_rows: RaggedRows,
+
+    /// The buffered rows padded with nulls, and their original number of fields
+    padded_rows: Vec<(usize, usize)>,
+
     /// The current line number
     line_number: usize,
 
@@ -59,10 +66,12 @@ pub struct RecordDecoder {
 }
 
 impl RecordDecoder {
-    pub fn new(delimiter: Reader, num_columns: usize) -> Self {
+    pub fn new(delimiter: Reader, num_columns: usize, ragged_rows: RaggedRows) -> Self {
         Self {
             delimiter,
             num_columns,
+            ragged_rows,
+            padded_rows: vec![],
             line_number: 1,
             offsets: vec![],
             offsets_len: 1, // The first offset is always 0
@@ -123,10 +132,41 @@ impl RecordDecoder {
                     }
                     // Need to allocate more capacity
                     ReadRecordResult::OutputFull => break,
+                    ReadRecordResult::OutputEndsFull
+                        if self.ragged_rows == RaggedRows::IgnoreExtra =>
+                    {
+                        // Allocate space for the extra fields, these are truncated once
+                        // the end of the record is reached
+                        self.offsets
+                            .resize(self.offsets.len() + self.num_columns, 0);
+                    }
                     ReadRecordResult::OutputEndsFull => {
                         return Err(ArrowError::CsvError(format!("incorrect number of fields for line {}, expected {} got more than {}", self.line_number, self.num_columns, self.current_field)));
                     }
                     ReadRecordResult::Record => {
+                        if self.current_field < self.num_columns
+                            && self.ragged_rows == RaggedRows::PadWithNulls
+                        {
+                            // Pad with empty fields, later converted to nulls
+                            let end = self.offsets[self.offsets_len - 1];
+                            let missing = self.num_columns - self.current_field;
+                            self.offsets[self.offsets_len..self.offsets_len + missing]
+                                .fill(end);
+                            self.offsets_len += missing;
+                            self.padded_rows.push((self.num_rows, self.current_field));
+                            self.current_field = self.num_columns;
+                        } else if self.current_field > self.num_columns
+                            && self.ragged_rows == RaggedRows::IgnoreExtra
+                        {
+                            // Discard the extra fields and their data, ends are relative
+                            // to the start of the record
+                            let row_start =
+                                self.data_len - self.offsets[self.offsets_len - 1];
+                            self.offsets_len -= self.current_field - self.num_columns;
+                            self.data_len =
+                                row_start + self.offsets[self.offsets_len - 1];
+                            self.current_field = self.num_columns;
+                        }
                         if self.current_field != self.num_columns {
                             return Err(ArrowError::CsvError(format!("incorrect number of fields for line {}, expected {} got {}", self.line_number, self.num_columns, self.current_field)));
                         }
@@ -168,6 +208,7 @@ impl RecordDecoder {
         self.offsets_len = 1;
         self.data_len = 0;
         self.num_rows = 0;
+        self.padded_rows.clear();
     }
 
     /// Flushes the current contents of the reader
@@ -212,6 +253,7 @@ impl RecordDecoder {
 
         let offsets = &self.offsets[..self.offsets_len];
         let num_rows = self.num_rows;
+        let padded_rows = std::mem::take(&mut self.padded_rows);
 
         // Reset state
         self.offsets_len = 1;
@@ -223,6 +265,7 @@ impl RecordDecoder {
             num_columns: self.num_columns,
             offsets,
             data,
+            padded_rows,
         })
     }
 }
@@ -234,6 +277,7 @@ pub struct StringRecords<'a> {
     num_rows: usize,
     offsets: &'a [usize],
     data: &'a str,
+    padded_rows: Vec<(usize, usize)>,
 }
 
 impl<'a> StringRecords<'a> {
@@ -252,6 +296,11 @@ impl<'a> StringRecords<'a> {
     pub fn iter(&self) -> impl Iterator<Item = StringRecord<'a>> + '_ {
         (0..self.num_rows).map(|x| self.get(x))
     }
+
+    /// Returns the rows padded with nulls, and their original number of fields
+    pub fn padded_rows(&self) -> &[(usize, usize)] {
+        &self.padded_rows
+    }
 }
 
 /// A single parsed, UTF-8 CSV record
@@ -275,6 +324,7 @@ impl<'a> StringRecord<'a> {
 #[cfg(test)]
 mod tests {
     use crate::reader::records::RecordDecoder;
+    use crate::reader::RaggedRows;
     use csv_core::Reader;
     use std::io::{BufRead, BufReader, Cursor};
 
@@ -297,7 +347,7 @@ mod tests {
         .into_iter();
 
         let mut reader = BufReader::with_capacity(3, Cursor::new(csv.as_bytes()));
-        let mut decoder = RecordDecoder::new(Reader::new(), 3);
+        let mut decoder = RecordDecoder::new(Reader::new(), 3, RaggedRows::Error);
 
         loop {
             let to_read = 3;
@@ -331,7 +381,7 @@ mod tests {
     #[test]
     fn test_invalid_fields() {
         let csv = "a,b\nb,c\na\n";
-        let mut decoder = RecordDecoder::new(Reader::new(), 2);
+        let mut decoder = RecordDecoder::new(Reader::new(), 2, RaggedRows::Error);
         let err = decoder.decode(csv.as_bytes(), 4).unwrap_err().to_string();
 
         let expected =
@@ -340,7 +390,7 @@ mod tests {
         assert_eq!(err, expected);
 
         // Test with initial skip
-        let mut decoder = RecordDecoder::new(Reader::new(), 2);
+        let mut decoder = RecordDecoder::new(Reader::new(), 2, RaggedRows::Error);
         let (skipped, bytes) = decoder.decode(csv.as_bytes(), 1).unwrap();
         assert_eq!(skipped, 1);
         decoder.clear();
@@ -350,10 +400,64 @@ mod tests {
         assert_eq!(err, expected);
     }
 
+    #[test]
+    fn test_ragged_rows() {
+        let csv = "a,b,c\nd\ne,f,g,h,i\nj,k\n";
+
+        let mut decoder = RecordDecoder::new(Reader::new(), 3, RaggedRows::PadWithNulls);
+        let err = decoder.decode(csv.as_bytes(), 4).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Csv error: incorrect number of fields for line 3, expected 3 got 5"
+        );
+
+        let mut decoder = RecordDecoder::new(Reader::new(), 3, RaggedRows::IgnoreExtra);
+        let err = decoder.decode(csv.as_bytes(), 4).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Csv error: incorrect number of fields for line 2, expected 3 got 1"
+        );
+
+        let csv = "a,b,c\nd\nj,k\n";
+        let mut decoder = RecordDecoder::new(Reader::new(), 3, RaggedRows::PadWithNulls);
+        let (read, _) = decoder.decode(csv.as_bytes(), 4).unwrap();
+        assert_eq!(read, 3);
+        let rows = decoder.flush().unwrap();
+        let actual: Vec<Vec<_>> = rows
+            .iter()
+            .map(|row| (0..3).map(|i| row.get(i)).collect())
+            .collect();
+        assert_eq!(
+            actual,
+            vec![vec!["a", "b", "c"], vec!["d", "", ""], vec!["j", "k", ""]]
+        );
+        assert_eq!(rows.padded_rows(), &[(1, 1), (2, 2)]);
+
+        let csv = "a,b,c,d,e\nf,g\nh,i,j,k\nl,m,n,o,p,q,r,s\n";
+        let mut decoder = RecordDecoder::new(Reader::new(), 2, RaggedRows::IgnoreExtra);
+        let (read, _) = decoder.decode(csv.as_bytes(), 4).unwrap();
+        assert_eq!(read, 4);
+        let rows = decoder.flush().unwrap();
+        let actual: Vec<Vec<_>> = rows
+            .iter()
+            .map(|row| (0..2).map(|i| row.get(i)).collect())
+            .collect();
+        assert_eq!(
+            actual,
+            vec![
+                vec!["a", "b"],
+                vec!["f", "g"],
+                vec!["h", "i"],
+                vec!["l", "m"]
+            ]
+        );
+        assert!(rows.padded_rows().is_empty());
+    }
+
     #[test]
     fn test_skip_insufficient_rows() {
         let csv = "a\nv\n";
-        let mut decoder = RecordDecoder::new(Reader::new(), 1);
+        let mut decoder = RecordDecoder::new(Reader::new(), 1, RaggedRows::Error);
         let (read, bytes) = decoder.decode(csv.as_bytes(), 3).unwrap();
         assert_eq!(read, 2);
         assert_eq!(bytes, csv.len());
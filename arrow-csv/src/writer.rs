@@ -70,6 +70,8 @@ use arrow_cast::display::{
 };
 use arrow_schema::*;
 use std::io::Write;
+#[cfg(feature = "json")]
+use std::sync::Arc;
 
 use crate::map_csv_error;
 
@@ -88,11 +90,78 @@ where
     lexical_to_string(c.value(i))
 }
 
+/// Encodes the values of a nested array, such as a list or struct, as JSON strings
+#[cfg(feature = "json")]
+fn nested_to_json(array: &ArrayRef) -> Result<ArrayRef, ArrowError> {
+    let field = Field::new("value", array.data_type().clone(), true);
+    let schema = Arc::new(Schema::new(vec![field]));
+    let batch = RecordBatch::try_new(schema, vec![array.clone()])?;
+    let rows = arrow_json::writer::record_batches_to_json_rows(&[batch])?;
+    let values: StringArray = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            array.is_valid(i).then(|| {
+                row.get("value")
+                    .map_or_else(|| "null".to_string(), |v| v.to_string())
+            })
+        })
+        .collect();
+    Ok(Arc::new(values))
+}
+
+/// The quoting policy of a CSV [`Writer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuoteStyle {
+    /// Quote all fields
+    Always,
+    /// Only quote fields that contain a delimiter, a quote or a line terminator
+    #[default]
+    Necessary,
+    /// Quote all fields that are not numbers
+    NonNumeric,
+    /// Never quote fields, even if this produces CSV that cannot be read back
+    Never,
+}
+
+/// The line terminator written after each record by a CSV [`Writer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineTerminator {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+    /// An arbitrary byte
+    Byte(u8),
+}
+
+impl From<LineTerminator> for csv::Terminator {
+    fn from(terminator: LineTerminator) -> Self {
+        match terminator {
+            LineTerminator::Lf => csv::Terminator::Any(b'\n'),
+            LineTerminator::CrLf => csv::Terminator::CRLF,
+            LineTerminator::Byte(byte) => csv::Terminator::Any(byte),
+        }
+    }
+}
+
 /// A CSV writer
 #[derive(Debug)]
 pub struct Writer<W: Write> {
-    /// The object to write to
+    /// The object to write to, fields are quoted before being handed to it
+    /// so that null values are only quoted when they would otherwise be blank records
     writer: csv::Writer<W>,
+    /// The column delimiter
+    delimiter: u8,
+    /// The quote character
+    quote: u8,
+    /// The escape character for quotes, `None` if quotes are doubled
+    escape: Option<u8>,
+    /// The quoting policy for non-null fields
+    quote_style: QuoteStyle,
+    /// The line terminator
+    line_terminator: LineTerminator,
     /// Whether file should be written with headers. Defaults to `true`
     has_headers: bool,
     /// The date format for date arrays
@@ -111,6 +180,9 @@ pub struct Writer<W: Write> {
     beginning: bool,
     /// The value to represent null entries
     null_value: String,
+    /// Whether to write nested arrays as JSON strings
+    #[cfg(feature = "json")]
+    nested_as_json: bool,
 }
 
 impl<W: Write> Writer<W> {
@@ -118,9 +190,17 @@ impl<W: Write> Writer<W> {
     pub fn new(writer: W) -> Self {
         let delimiter = b',';
         let mut builder = csv::WriterBuilder::new();
-        let writer = builder.delimiter(delimiter).from_writer(writer);
+        let writer = builder
+            .delimiter(delimiter)
+            .quote_style(csv::QuoteStyle::Never)
+            .from_writer(writer);
         Writer {
             writer,
+            delimiter,
+            quote: b'"',
+            escape: None,
+            quote_style: QuoteStyle::default(),
+            line_terminator: LineTerminator::default(),
             has_headers: true,
             date_format: Some(DEFAULT_DATE_FORMAT.to_string()),
             datetime_format: Some(DEFAULT_TIMESTAMP_FORMAT.to_string()),
@@ -129,6 +209,8 @@ impl<W: Write> Writer<W> {
             timestamp_tz_format: Some(DEFAULT_TIMESTAMP_TZ_FORMAT.to_string()),
             beginning: true,
             null_value: DEFAULT_NULL_VALUE.to_string(),
+            #[cfg(feature = "json")]
+            nested_as_json: false,
         }
     }
 
@@ -137,14 +219,19 @@ impl<W: Write> Writer<W> {
         &self,
         batch: &[ArrayRef],
         row_index: usize,
-        buffer: &mut [String],
+        buffer: &mut [Vec<u8>],
     ) -> Result<(), ArrowError> {
         // TODO: it'd be more efficient if we could create `record: Vec<&[u8]>
+        let only_field = buffer.len() == 1;
         for (col_index, item) in buffer.iter_mut().enumerate() {
             let col = &batch[col_index];
             if col.is_null(row_index) {
-                // write the configured null value
-                *item = self.null_value.clone();
+                // write the configured null value, which is only quoted if the
+                // record would otherwise be an empty line that readers skip
+                *item = match only_field && self.null_value.is_empty() {
+                    true => vec![self.quote; 2],
+                    false => self.null_value.clone().into_bytes(),
+                };
                 continue;
             }
             let string = match col.data_type() {
@@ -227,29 +314,68 @@ impl<W: Write> Writer<W> {
                     array_value_to_string(col, row_index)?.to_string()
                 }
                 t => {
-                    // List and Struct arrays not supported by the writer, any
-                    // other type needs to be implemented
+                    // List and Struct arrays are only supported when written as JSON,
+                    // any other type needs to be implemented
                     return Err(ArrowError::CsvError(format!(
                         "CSV Writer does not support {t:?} data type"
                     )));
                 }
             };
-            *item = string;
+            *item = self.quote(string, only_field);
         }
         Ok(())
     }
 
+    /// Quote `field` according to the configured [`QuoteStyle`], `only_field`
+    /// is true if it is the only field of its record
+    fn quote(&self, field: String, only_field: bool) -> Vec<u8> {
+        // an empty record would otherwise be indistinguishable from an empty line
+        if only_field && field.is_empty() && self.quote_style != QuoteStyle::Never {
+            return vec![self.quote; 2];
+        }
+        let quote = match self.quote_style {
+            QuoteStyle::Always => true,
+            QuoteStyle::Never => false,
+            QuoteStyle::NonNumeric => {
+                field.parse::<f64>().is_err() && field.parse::<i128>().is_err()
+            }
+            QuoteStyle::Necessary => field.bytes().any(|b| self.requires_quotes(b)),
+        };
+        if !quote {
+            return field.into_bytes();
+        }
+        let mut quoted = Vec::with_capacity(field.len() + 2);
+        quoted.push(self.quote);
+        for b in field.into_bytes() {
+            if b == self.quote || Some(b) == self.escape {
+                quoted.push(self.escape.unwrap_or(self.quote));
+            }
+            quoted.push(b);
+        }
+        quoted.push(self.quote);
+        quoted
+    }
+
+    /// Returns true if a field containing `b` must be quoted to be read back
+    fn requires_quotes(&self, b: u8) -> bool {
+        let terminator = match self.line_terminator {
+            LineTerminator::Lf | LineTerminator::CrLf => b == b'\n' || b == b'\r',
+            LineTerminator::Byte(t) => t == b,
+        };
+        terminator || b == self.delimiter || b == self.quote || Some(b) == self.escape
+    }
+
     /// Write a vector of record batches to a writable object
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
         let num_columns = batch.num_columns();
         if self.beginning {
             if self.has_headers {
-                let mut headers: Vec<String> = Vec::with_capacity(num_columns);
-                batch
+                let headers: Vec<Vec<u8>> = batch
                     .schema()
                     .fields()
                     .iter()
-                    .for_each(|field| headers.push(field.name().to_string()));
+                    .map(|field| self.quote(field.name().to_string(), num_columns == 1))
+                    .collect();
                 self.writer
                     .write_record(&headers[..])
                     .map_err(map_csv_error)?;
@@ -257,19 +383,27 @@ impl<W: Write> Writer<W> {
             self.beginning = false;
         }
 
-        let columns: Vec<_> = batch
+        let columns = batch
             .columns()
             .iter()
             .map(|array| match array.data_type() {
                 DataType::Dictionary(_, value_type) => {
                     arrow_cast::cast(array, value_type)
-                        .expect("cannot cast dictionary to underlying values")
                 }
-                _ => array.clone(),
+                #[cfg(feature = "json")]
+                DataType::List(_)
+                | DataType::LargeList(_)
+                | DataType::Struct(_)
+                | DataType::Map(_, _)
+                    if self.nested_as_json =>
+                {
+                    nested_to_json(array)
+                }
+                _ => Ok(array.clone()),
             })
-            .collect();
+            .collect::<Result<Vec<_>, ArrowError>>()?;
 
-        let mut buffer = vec!["".to_string(); batch.num_columns()];
+        let mut buffer = vec![vec![]; batch.num_columns()];
 
         for row_index in 0..batch.num_rows() {
             self.convert(columns.as_slice(), row_index, &mut buffer)?;
@@ -286,6 +420,15 @@ impl<W: Write> Writer<W> {
 pub struct WriterBuilder {
     /// Optional column delimiter. Defaults to `b','`
     delimiter: Option<u8>,
+    /// Optional quote character. Defaults to `b'"'`
+    quote: Option<u8>,
+    /// Optional escape character for quotes within quoted fields. Defaults to
+    /// `None`, which escapes quotes by doubling them
+    escape: Option<u8>,
+    /// The quoting policy. Defaults to [`QuoteStyle::Necessary`]
+    quote_style: QuoteStyle,
+    /// The line terminator. Defaults to [`LineTerminator::Lf`]
+    line_terminator: LineTerminator,
     /// Whether to write column names as file headers. Defaults to `true`
     has_headers: bool,
     /// Optional date format for date arrays
@@ -300,6 +443,9 @@ pub struct WriterBuilder {
     time_format: Option<String>,
     /// Optional value to represent null
     null_value: Option<String>,
+    /// Whether to write nested arrays as JSON strings. Defaults to `false`
+    #[cfg(feature = "json")]
+    nested_as_json: bool,
 }
 
 impl Default for WriterBuilder {
@@ -307,12 +453,18 @@ impl Default for WriterBuilder {
         Self {
             has_headers: true,
             delimiter: None,
+            quote: None,
+            escape: None,
+            quote_style: QuoteStyle::default(),
+            line_terminator: LineTerminator::default(),
             date_format: Some(DEFAULT_DATE_FORMAT.to_string()),
             datetime_format: Some(DEFAULT_TIMESTAMP_FORMAT.to_string()),
             time_format: Some(DEFAULT_TIME_FORMAT.to_string()),
             timestamp_format: Some(DEFAULT_TIMESTAMP_FORMAT.to_string()),
             timestamp_tz_format: Some(DEFAULT_TIMESTAMP_TZ_FORMAT.to_string()),
             null_value: Some(DEFAULT_NULL_VALUE.to_string()),
+            #[cfg(feature = "json")]
+            nested_as_json: false,
         }
     }
 }
@@ -354,6 +506,34 @@ impl WriterBuilder {
         self
     }
 
+    /// Set the CSV file's quote character as a byte character
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    /// Set the CSV file's escape character as a byte character
+    ///
+    /// By default quotes within quoted fields are escaped by doubling them,
+    /// setting an escape character instead prefixes them with `escape`
+    pub fn with_escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Set the policy for quoting fields, defaults to [`QuoteStyle::Necessary`]
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Set the line terminator written after each record, defaults to
+    /// [`LineTerminator::Lf`]
+    pub fn with_line_terminator(mut self, line_terminator: LineTerminator) -> Self {
+        self.line_terminator = line_terminator;
+        self
+    }
+
     /// Set the CSV file's date format
     pub fn with_date_format(mut self, format: String) -> Self {
         self.date_format = Some(format);
@@ -378,12 +558,22 @@ impl WriterBuilder {
         self
     }
 
-    /// Set the value to represent null in output
+    /// Set the value to represent null in output, defaults to an empty string
     pub fn with_null(mut self, null_value: String) -> Self {
         self.null_value = Some(null_value);
         self
     }
 
+    /// Set whether to write list, struct and map columns as JSON strings,
+    /// rather than returning an error
+    ///
+    /// Requires the `json` feature
+    #[cfg(feature = "json")]
+    pub fn with_nested_as_json(mut self, nested_as_json: bool) -> Self {
+        self.nested_as_json = nested_as_json;
+        self
+    }
+
     /// Use RFC3339 format for date/time/timestamps by clearing all
     /// date/time specific formats.
     pub fn with_rfc3339(mut self, use_rfc3339: bool) -> Self {
@@ -401,9 +591,18 @@ impl WriterBuilder {
     pub fn build<W: Write>(self, writer: W) -> Writer<W> {
         let delimiter = self.delimiter.unwrap_or(b',');
         let mut builder = csv::WriterBuilder::new();
-        let writer = builder.delimiter(delimiter).from_writer(writer);
+        let writer = builder
+            .delimiter(delimiter)
+            .quote_style(csv::QuoteStyle::Never)
+            .terminator(self.line_terminator.into())
+            .from_writer(writer);
         Writer {
             writer,
+            delimiter,
+            quote: self.quote.unwrap_or(b'"'),
+            escape: self.escape,
+            quote_style: self.quote_style,
+            line_terminator: self.line_terminator,
             has_headers: self.has_headers,
             date_format: self.date_format,
             datetime_format: self.datetime_format,
@@ -414,6 +613,8 @@ impl WriterBuilder {
             null_value: self
                 .null_value
                 .unwrap_or_else(|| DEFAULT_NULL_VALUE.to_string()),
+            #[cfg(feature = "json")]
+            nested_as_json: self.nested_as_json,
         }
     }
 }
@@ -422,7 +623,7 @@ impl WriterBuilder {
 mod tests {
     use super::*;
 
-    use crate::Reader;
+    use crate::{Reader, ReaderBuilder};
     use std::io::{Cursor, Read, Seek};
     use std::sync::Arc;
 
@@ -570,6 +771,159 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555000000,23:46:03,foo
         );
     }
 
+    #[test]
+    fn test_write_csv_dialect() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new("c2", DataType::Int32, true),
+        ]);
+        let c1 = StringArray::from(vec![Some("a 'quoted' value"), None, Some("b;c")]);
+        let c2 = Int32Array::from(vec![Some(1), Some(2), None]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(c1), Arc::new(c2)])
+                .unwrap();
+
+        let write = |builder: WriterBuilder| {
+            let mut buf = Vec::new();
+            let mut writer = builder.build(&mut buf);
+            writer.write(&batch).unwrap();
+            drop(writer);
+            String::from_utf8(buf).unwrap()
+        };
+
+        let options = || {
+            WriterBuilder::new()
+                .with_delimiter(b';')
+                .with_quote(b'\'')
+                .with_null("\\N".to_string())
+        };
+
+        assert_eq!(
+            write(options()),
+            "c1;c2\n'a ''quoted'' value';1\n\\N;2\n'b;c';\\N\n"
+        );
+        assert_eq!(
+            write(
+                options()
+                    .with_escape(b'\\')
+                    .with_line_terminator(LineTerminator::CrLf)
+            ),
+            "c1;c2\r\n'a \\'quoted\\' value';1\r\n\\N;2\r\n'b;c';\\N\r\n"
+        );
+        assert_eq!(
+            write(options().with_quote_style(QuoteStyle::Always)),
+            "'c1';'c2'\n'a ''quoted'' value';'1'\n\\N;'2'\n'b;c';\\N\n"
+        );
+        assert_eq!(
+            write(options().with_quote_style(QuoteStyle::NonNumeric)),
+            "'c1';'c2'\n'a ''quoted'' value';1\n\\N;2\n'b;c';\\N\n"
+        );
+        assert_eq!(
+            write(
+                options()
+                    .with_quote_style(QuoteStyle::Never)
+                    .with_line_terminator(LineTerminator::Byte(b'|'))
+            ),
+            "c1;c2|a 'quoted' value;1|\\N;2|b;c;\\N|"
+        );
+    }
+
+    #[test]
+    fn test_write_csv_round_trip() {
+        let round_trip = |batch: &RecordBatch, builder: WriterBuilder, escape: u8| {
+            let mut buf: Cursor<Vec<u8>> = Default::default();
+            {
+                let mut writer = builder.build(&mut buf);
+                writer.write(batch).unwrap();
+            }
+            buf.set_position(0);
+
+            let mut reader = ReaderBuilder::new()
+                .with_schema(batch.schema())
+                .has_header(true)
+                .with_escape(escape)
+                .build(buf)
+                .unwrap();
+            let read = reader.next().unwrap().unwrap();
+            assert!(reader.next().is_none());
+            read
+        };
+
+        // a single null field must not be written as an empty line
+        let schema = Schema::new(vec![Field::new("c1", DataType::Int32, true)]);
+        let c1 = Int32Array::from(vec![Some(1), None, Some(3), None]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(c1)]).unwrap();
+        assert_eq!(round_trip(&batch, WriterBuilder::new(), b'\\'), batch);
+
+        // the escape character must itself be escaped
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new("c2", DataType::Int32, true),
+        ]);
+        let c1 = StringArray::from(vec![
+            Some(r#"a "quoted" value"#),
+            Some(r#"C:\path\"#),
+            Some(r#"\"mixed\""#),
+        ]);
+        let c2 = Int32Array::from(vec![Some(1), Some(2), None]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(c1), Arc::new(c2)])
+                .unwrap();
+        let builder = WriterBuilder::new().with_escape(b'\\');
+        assert_eq!(round_trip(&batch, builder, b'\\'), batch);
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_write_nested_as_json() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None, Some(3)]),
+            None,
+            Some(vec![]),
+        ]);
+        let structs = StructArray::from(vec![
+            (
+                Field::new("a", DataType::Utf8, true),
+                Arc::new(StringArray::from(vec![Some("x,y"), None, Some("z")]))
+                    as ArrayRef,
+            ),
+            (
+                Field::new("b", DataType::Boolean, false),
+                Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+            ),
+        ]);
+        let schema = Schema::new(vec![
+            Field::new("list", list.data_type().clone(), true),
+            Field::new("struct", structs.data_type().clone(), false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(list), Arc::new(structs)],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer = WriterBuilder::new().build(&mut buf);
+        let err = writer.write(&batch).unwrap_err().to_string();
+        assert!(err.contains("CSV Writer does not support List"), "{err}");
+
+        let mut buf = Vec::new();
+        let mut writer = WriterBuilder::new()
+            .with_nested_as_json(true)
+            .with_null("NULL".to_string())
+            .build(&mut buf);
+        writer.write(&batch).unwrap();
+        drop(writer);
+
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "list,struct\n\
+             \"[1,null,3]\",\"{\"\"a\"\":\"\"x,y\"\",\"\"b\"\":true}\"\n\
+             NULL,\"{\"\"b\"\":false}\"\n\
+             [],\"{\"\"a\"\":\"\"z\"\",\"\"b\"\":true}\"\n"
+        );
+    }
+
     #[test]
     fn test_conversion_consistency() {
         // test if we can serialize and deserialize whilst retaining the same type information/ precision
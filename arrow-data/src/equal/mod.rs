@@ -31,6 +31,7 @@ mod fixed_list;
 mod list;
 mod null;
 mod primitive;
+mod run;
 mod structure;
 mod union;
 mod utils;
@@ -46,6 +47,7 @@ use fixed_list::fixed_list_equal;
 use list::list_equal;
 use null::null_equal;
 use primitive::primitive_equal;
+use run::run_equal;
 use structure::struct_equal;
 use union::union_equal;
 use variable_size::variable_sized_equal;
@@ -137,11 +139,21 @@ fn equal_values(
         },
         DataType::Float16 => primitive_equal::<f16>(lhs, rhs, lhs_start, rhs_start, len),
         DataType::Map(_, _) => list_equal::<i32>(lhs, rhs, lhs_start, rhs_start, len),
-        DataType::RunEndEncoded(_, _) => todo!(),
+        DataType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+            DataType::Int16 => run_equal::<i16>(lhs, rhs, lhs_start, rhs_start, len),
+            DataType::Int32 => run_equal::<i32>(lhs, rhs, lhs_start, rhs_start, len),
+            DataType::Int64 => run_equal::<i64>(lhs, rhs, lhs_start, rhs_start, len),
+            _ => unreachable!(),
+        },
     }
 }
 
-fn equal_range(
+/// Logically compares `len` items of `lhs` starting at `lhs_start` with `len` items
+/// of `rhs` starting at `rhs_start`, see [`equal`] for how items are compared
+///
+/// Both ranges must be within the bounds of their [ArrayData], and their data types
+/// must be equal
+pub fn equal_range(
     lhs: &ArrayData,
     rhs: &ArrayData,
     lhs_start: usize,
@@ -0,0 +1,74 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::data::ArrayData;
+use arrow_buffer::ArrowNativeType;
+
+use super::equal_range;
+
+/// Returns the physical index of the run containing the logical index `logical`
+#[inline]
+fn physical_index<T: ArrowNativeType>(run_ends: &[T], logical: usize) -> usize {
+    run_ends.partition_point(|end| end.as_usize() <= logical)
+}
+
+/// Compares two run-end encoded arrays by walking their runs in lockstep,
+/// comparing the value of each pair of overlapping runs only once.
+pub(super) fn run_equal<T: ArrowNativeType>(
+    lhs: &ArrayData,
+    rhs: &ArrayData,
+    lhs_start: usize,
+    rhs_start: usize,
+    len: usize,
+) -> bool {
+    let lhs_run_ends = lhs.child_data()[0].buffer::<T>(0);
+    let rhs_run_ends = rhs.child_data()[0].buffer::<T>(0);
+
+    let lhs_values = &lhs.child_data()[1];
+    let rhs_values = &rhs.child_data()[1];
+
+    let mut lhs_pos = lhs.offset() + lhs_start;
+    let mut rhs_pos = rhs.offset() + rhs_start;
+    let mut remaining = len;
+
+    let mut lhs_physical = physical_index(lhs_run_ends, lhs_pos);
+    let mut rhs_physical = physical_index(rhs_run_ends, rhs_pos);
+
+    while remaining > 0 {
+        let lhs_run_end = lhs_run_ends[lhs_physical].as_usize();
+        let rhs_run_end = rhs_run_ends[rhs_physical].as_usize();
+
+        if !equal_range(lhs_values, rhs_values, lhs_physical, rhs_physical, 1) {
+            return false;
+        }
+
+        let step = (lhs_run_end - lhs_pos)
+            .min(rhs_run_end - rhs_pos)
+            .min(remaining);
+        lhs_pos += step;
+        rhs_pos += step;
+        remaining -= step;
+
+        if lhs_pos == lhs_run_end {
+            lhs_physical += 1;
+        }
+        if rhs_pos == rhs_run_end {
+            rhs_physical += 1;
+        }
+    }
+    true
+}
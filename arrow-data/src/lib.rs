@@ -23,6 +23,7 @@ mod data;
 pub use data::*;
 
 mod equal;
+pub use equal::equal_range;
 pub mod transform;
 
 pub mod bit_iterator;
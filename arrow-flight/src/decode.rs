@@ -19,7 +19,7 @@ use crate::{utils::flight_data_to_arrow_batch, FlightData};
 use arrow_array::{ArrayRef, RecordBatch};
 use arrow_schema::{Schema, SchemaRef};
 use bytes::Bytes;
-use futures::{ready, stream::BoxStream, Stream, StreamExt};
+use futures::{ready, stream::BoxStream, Stream, StreamExt, TryStreamExt};
 use std::{
     collections::HashMap, convert::TryFrom, fmt::Debug, pin::Pin, sync::Arc, task::Poll,
 };
@@ -225,6 +225,25 @@ impl FlightDataDecoder {
         self.state.as_ref().map(|state| &state.schema)
     }
 
+    /// Consume self and return a [`Stream`] of the decoded [`RecordBatch`]es paired
+    /// with the [`FlightData::app_metadata`] of the message they were decoded from,
+    /// skipping all other messages.
+    ///
+    /// This is the counterpart of
+    /// [`FlightDataEncoderBuilder::build_with_app_metadata`](crate::encode::FlightDataEncoderBuilder::build_with_app_metadata)
+    pub fn into_batches_with_app_metadata(
+        self,
+    ) -> BoxStream<'static, Result<(RecordBatch, Bytes)>> {
+        self.try_filter_map(|data| async move {
+            let app_metadata = data.app_metadata();
+            Ok(match data.payload {
+                DecodedPayload::RecordBatch(batch) => Some((batch, app_metadata)),
+                DecodedPayload::None | DecodedPayload::Schema(_) => None,
+            })
+        })
+        .boxed()
+    }
+
     /// Extracts flight data from the next message, updating decoding
     /// state as necessary.
     fn extract_message(&mut self, data: FlightData) -> Result<Option<DecodedFlightData>> {
@@ -22,7 +22,7 @@ use arrow_array::{ArrayRef, RecordBatch};
 use arrow_ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
 use arrow_schema::{DataType, Field, Schema, SchemaRef};
 use bytes::Bytes;
-use futures::{ready, stream::BoxStream, Stream, StreamExt};
+use futures::{ready, stream::BoxStream, Stream, StreamExt, TryStreamExt};
 
 /// Creates a [`Stream`](futures::Stream) of [`FlightData`]s from a
 /// `Stream` of [`Result`]<[`RecordBatch`], [`FlightError`]>.
@@ -31,9 +31,11 @@ use futures::{ready, stream::BoxStream, Stream, StreamExt};
 /// Arrow Flight implementation;
 ///
 /// # Caveats
-///   1. [`DictionaryArray`](arrow_array::array::DictionaryArray)s
+///   1. By default [`DictionaryArray`](arrow_array::array::DictionaryArray)s
 ///   are converted to their underlying types prior to transport, due to
-///   <https://github.com/apache/arrow-rs/issues/3389>.
+///   <https://github.com/apache/arrow-rs/issues/3389>. See
+///   [`FlightDataEncoderBuilder::with_dictionary_handling`] to send them as
+///   dictionaries instead.
 ///
 /// # Example
 /// ```no_run
@@ -72,6 +74,26 @@ pub struct FlightDataEncoderBuilder {
     app_metadata: Bytes,
     /// Optional schema, if known before data.
     schema: Option<SchemaRef>,
+    /// How dictionary arrays are sent
+    dictionary_handling: DictionaryHandling,
+}
+
+/// Determines how [`DictionaryArray`](arrow_array::array::DictionaryArray)s are
+/// encoded by a [`FlightDataEncoder`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DictionaryHandling {
+    /// Convert dictionary arrays to their underlying value type before sending,
+    /// so the receiver does not need to track dictionaries. This is the default.
+    ///
+    /// This can increase the size of the data sent, but is supported by all clients.
+    #[default]
+    Hydrate,
+    /// Send dictionary arrays as dictionaries, preceding each `RecordBatch` message
+    /// with a `DictionaryBatch` message when its dictionary was not sent before.
+    ///
+    /// A dictionary that differs from the one previously sent for the same field
+    /// replaces it, and is sent again in full.
+    Resend,
 }
 
 /// Default target size for encoded [`FlightData`].
@@ -87,6 +109,7 @@ impl Default for FlightDataEncoderBuilder {
             options: IpcWriteOptions::default(),
             app_metadata: Bytes::new(),
             schema: None,
+            dictionary_handling: DictionaryHandling::Hydrate,
         }
     }
 }
@@ -134,17 +157,67 @@ impl FlightDataEncoderBuilder {
         self
     }
 
+    /// Set how [`DictionaryArray`](arrow_array::array::DictionaryArray)s are sent,
+    /// defaults to [`DictionaryHandling::Hydrate`]
+    pub fn with_dictionary_handling(
+        mut self,
+        dictionary_handling: DictionaryHandling,
+    ) -> Self {
+        self.dictionary_handling = dictionary_handling;
+        self
+    }
+
     /// Return a [`Stream`](futures::Stream) of [`FlightData`],
     /// consuming self. More details on [`FlightDataEncoder`]
     pub fn build<S>(self, input: S) -> FlightDataEncoder
     where
         S: Stream<Item = Result<RecordBatch>> + Send + 'static,
+    {
+        self.build_with_app_metadata(input.map_ok(|batch| (batch, Bytes::new())))
+    }
+
+    /// Return a [`Stream`](futures::Stream) of [`FlightData`] from a stream of
+    /// [`RecordBatch`]es paired with application specific metadata, consuming self.
+    ///
+    /// The metadata of each batch is sent in the [`FlightData::app_metadata`] field
+    /// of the first `RecordBatch` message encoding that batch, and can be read back
+    /// with [`FlightDataDecoder::into_batches_with_app_metadata`]. This allows a
+    /// `DoExchange` endpoint to pass per-batch metadata through in both directions.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use arrow_array::RecordBatch;
+    /// # use arrow_flight::FlightData;
+    /// # use bytes::Bytes;
+    /// # use futures::{Stream, StreamExt, TryStreamExt};
+    /// # use tonic::{Request, Status, Streaming};
+    /// use arrow_flight::decode::FlightDataDecoder;
+    /// use arrow_flight::encode::FlightDataEncoderBuilder;
+    ///
+    /// // Echo the batches, and their metadata, sent to a DoExchange endpoint
+    /// fn do_exchange(
+    ///     request: Request<Streaming<FlightData>>,
+    /// ) -> impl Stream<Item = Result<FlightData, Status>> {
+    ///     let input = FlightDataDecoder::new(request.into_inner().map_err(Into::into))
+    ///         .into_batches_with_app_metadata();
+    ///
+    ///     FlightDataEncoderBuilder::new()
+    ///         .build_with_app_metadata(input)
+    ///         .map_err(Into::into)
+    /// }
+    /// ```
+    ///
+    /// [`FlightDataDecoder::into_batches_with_app_metadata`]: crate::decode::FlightDataDecoder::into_batches_with_app_metadata
+    pub fn build_with_app_metadata<S>(self, input: S) -> FlightDataEncoder
+    where
+        S: Stream<Item = Result<(RecordBatch, Bytes)>> + Send + 'static,
     {
         let Self {
             max_flight_data_size,
             options,
             app_metadata,
             schema,
+            dictionary_handling,
         } = self;
 
         FlightDataEncoder::new(
@@ -153,6 +226,7 @@ impl FlightDataEncoderBuilder {
             max_flight_data_size,
             options,
             app_metadata,
+            dictionary_handling,
         )
     }
 }
@@ -161,8 +235,8 @@ impl FlightDataEncoderBuilder {
 ///
 /// See [`FlightDataEncoderBuilder`] for details and example.
 pub struct FlightDataEncoder {
-    /// Input stream
-    inner: BoxStream<'static, Result<RecordBatch>>,
+    /// Input stream of batches and their app_metadata
+    inner: BoxStream<'static, Result<(RecordBatch, Bytes)>>,
     /// schema, set after the first batch
     schema: Option<SchemaRef>,
     /// Target maximum size of flight data
@@ -176,24 +250,31 @@ pub struct FlightDataEncoder {
     queue: VecDeque<FlightData>,
     /// Is this stream done (inner is empty or errored)
     done: bool,
+    /// How dictionary arrays are sent
+    dictionary_handling: DictionaryHandling,
 }
 
 impl FlightDataEncoder {
     fn new(
-        inner: BoxStream<'static, Result<RecordBatch>>,
+        inner: BoxStream<'static, Result<(RecordBatch, Bytes)>>,
         schema: Option<SchemaRef>,
         max_flight_data_size: usize,
         options: IpcWriteOptions,
         app_metadata: Bytes,
+        dictionary_handling: DictionaryHandling,
     ) -> Self {
         let mut encoder = Self {
             inner,
             schema: None,
             max_flight_data_size,
-            encoder: FlightIpcEncoder::new(options),
+            encoder: FlightIpcEncoder::new(
+                options,
+                dictionary_handling != DictionaryHandling::Resend,
+            ),
             app_metadata: Some(app_metadata),
             queue: VecDeque::new(),
             done: false,
+            dictionary_handling,
         };
 
         // If schema is known up front, enqueue it immediately
@@ -220,7 +301,10 @@ impl FlightDataEncoder {
     fn encode_schema(&mut self, schema: &SchemaRef) -> SchemaRef {
         // The first message is the schema message, and all
         // batches have the same schema
-        let schema = Arc::new(prepare_schema_for_flight(schema));
+        let schema = match self.dictionary_handling {
+            DictionaryHandling::Hydrate => Arc::new(prepare_schema_for_flight(schema)),
+            DictionaryHandling::Resend => Arc::new(assign_dictionary_ids(schema)),
+        };
         let mut schema_flight_data = self.encoder.encode_schema(&schema);
 
         // attach any metadata requested
@@ -233,8 +317,9 @@ impl FlightDataEncoder {
         schema
     }
 
-    /// Encodes batch into one or more `FlightData` messages in self.queue
-    fn encode_batch(&mut self, batch: RecordBatch) -> Result<()> {
+    /// Encodes batch into one or more `FlightData` messages in self.queue,
+    /// attaching `app_metadata` to the first `RecordBatch` message
+    fn encode_batch(&mut self, batch: RecordBatch, app_metadata: Bytes) -> Result<()> {
         let schema = match &self.schema {
             Some(schema) => schema.clone(),
             // encode the schema if this is the first time we have seen it
@@ -242,11 +327,20 @@ impl FlightDataEncoder {
         };
 
         // encode the batch
-        let batch = prepare_batch_for_flight(&batch, schema)?;
+        let batch = match self.dictionary_handling {
+            DictionaryHandling::Hydrate => prepare_batch_for_flight(&batch, schema)?,
+            DictionaryHandling::Resend => {
+                RecordBatch::try_new(schema, batch.columns().to_vec())?
+            }
+        };
 
+        let mut app_metadata = Some(app_metadata);
         for batch in split_batch_for_grpc_response(batch, self.max_flight_data_size) {
-            let (flight_dictionaries, flight_batch) =
+            let (flight_dictionaries, mut flight_batch) =
                 self.encoder.encode_batch(&batch)?;
+            if let Some(app_metadata) = app_metadata.take() {
+                flight_batch.app_metadata = app_metadata;
+            }
 
             self.queue_messages(flight_dictionaries);
             self.queue_message(flight_batch);
@@ -290,9 +384,9 @@ impl Stream for FlightDataEncoder {
                     self.queue.clear();
                     return Poll::Ready(Some(Err(e)));
                 }
-                Some(Ok(batch)) => {
+                Some(Ok((batch, app_metadata))) => {
                     // had data, encode into the queue
-                    if let Err(e) = self.encode_batch(batch) {
+                    if let Err(e) = self.encode_batch(batch, app_metadata) {
                         self.done = true;
                         self.queue.clear();
                         return Poll::Ready(Some(Err(e)));
@@ -326,6 +420,73 @@ fn prepare_schema_for_flight(schema: &Schema) -> Schema {
     Schema::new(fields)
 }
 
+/// Assigns a distinct `dict_id` to each dictionary field of an arrow Schema,
+/// including those nested within other fields, so their dictionaries are
+/// tracked separately when sent over the Arrow Flight protocol
+fn assign_dictionary_ids(schema: &Schema) -> Schema {
+    let mut next_id = 0;
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| assign_field_dictionary_ids(field, &mut next_id))
+        .collect();
+
+    Schema::new_with_metadata(fields, schema.metadata().clone())
+}
+
+/// Assigns `dict_id`s taken from `next_id` to `field` and any of its children
+/// that are dictionaries
+fn assign_field_dictionary_ids(field: &Field, next_id: &mut i64) -> Field {
+    let data_type = match field.data_type() {
+        DataType::List(child) => {
+            DataType::List(Box::new(assign_field_dictionary_ids(child, next_id)))
+        }
+        DataType::LargeList(child) => {
+            DataType::LargeList(Box::new(assign_field_dictionary_ids(child, next_id)))
+        }
+        DataType::FixedSizeList(child, size) => DataType::FixedSizeList(
+            Box::new(assign_field_dictionary_ids(child, next_id)),
+            *size,
+        ),
+        DataType::Map(child, sorted) => DataType::Map(
+            Box::new(assign_field_dictionary_ids(child, next_id)),
+            *sorted,
+        ),
+        DataType::Struct(children) => DataType::Struct(
+            children
+                .iter()
+                .map(|child| assign_field_dictionary_ids(child, next_id))
+                .collect(),
+        ),
+        DataType::Union(children, type_ids, mode) => DataType::Union(
+            children
+                .iter()
+                .map(|child| assign_field_dictionary_ids(child, next_id))
+                .collect(),
+            type_ids.clone(),
+            mode.clone(),
+        ),
+        data_type => data_type.clone(),
+    };
+
+    match data_type {
+        DataType::Dictionary(_, _) => {
+            let dict_id = *next_id;
+            *next_id += 1;
+            Field::new_dict(
+                field.name(),
+                data_type,
+                field.is_nullable(),
+                dict_id,
+                field.dict_is_ordered().unwrap_or_default(),
+            )
+            .with_metadata(field.metadata().clone())
+        }
+        data_type => Field::new(field.name(), data_type, field.is_nullable())
+            .with_metadata(field.metadata().clone()),
+    }
+}
+
 /// Split [`RecordBatch`] so it hopefully fits into a gRPC response.
 ///
 /// Data is zero-copy sliced into batches.
@@ -372,8 +533,7 @@ struct FlightIpcEncoder {
 }
 
 impl FlightIpcEncoder {
-    fn new(options: IpcWriteOptions) -> Self {
-        let error_on_replacement = true;
+    fn new(options: IpcWriteOptions, error_on_replacement: bool) -> Self {
         Self {
             options,
             data_gen: IpcDataGenerator::default(),
@@ -21,6 +21,8 @@ use bytes::Bytes;
 use std::collections::HashMap;
 use std::time::Duration;
 
+use crate::decode::FlightRecordBatchStream;
+use crate::error::FlightError;
 use crate::flight_service_client::FlightServiceClient;
 use crate::sql::server::{CLOSE_PREPARED_STATEMENT, CREATE_PREPARED_STATEMENT};
 use crate::sql::{
@@ -29,9 +31,10 @@ use crate::sql::{
     CommandGetCrossReference, CommandGetDbSchemas, CommandGetExportedKeys,
     CommandGetImportedKeys, CommandGetPrimaryKeys, CommandGetSqlInfo,
     CommandGetTableTypes, CommandGetTables, CommandPreparedStatementQuery,
-    CommandStatementQuery, CommandStatementUpdate, DoPutUpdateResult, ProstMessageExt,
-    SqlInfo,
+    CommandPreparedStatementUpdate, CommandStatementQuery, CommandStatementUpdate,
+    DoPutUpdateResult, ProstMessageExt, SqlInfo,
 };
+use crate::utils::batches_to_flight_data;
 use crate::{
     Action, FlightData, FlightDescriptor, FlightInfo, HandshakeRequest,
     HandshakeResponse, IpcMessage, Ticket,
@@ -47,7 +50,6 @@ use prost::Message;
 #[cfg(feature = "tls")]
 use tonic::transport::{Certificate, ClientTlsConfig, Identity};
 use tonic::transport::{Channel, Endpoint};
-use tonic::Streaming;
 
 /// A FlightSQLServiceClient is an endpoint for retrieving or storing Arrow data
 /// by FlightSQL protocol.
@@ -246,17 +248,22 @@ impl FlightSqlServiceClient {
         self.get_flight_info_for_command(request).await
     }
 
-    /// Given a flight ticket, request to be sent the stream. Returns record batch stream reader
+    /// Given a flight ticket, request to be sent the stream. Returns a stream of the
+    /// [`RecordBatch`]es in the response
     pub async fn do_get(
         &mut self,
         ticket: Ticket,
-    ) -> Result<Streaming<FlightData>, ArrowError> {
-        Ok(self
+    ) -> Result<FlightRecordBatchStream, ArrowError> {
+        let response_stream = self
             .flight_client
             .do_get(ticket)
             .await
             .map_err(status_to_arrow_error)?
-            .into_inner())
+            .into_inner()
+            .map_err(FlightError::Tonic);
+        Ok(FlightRecordBatchStream::new_from_flight_data(
+            response_stream,
+        ))
     }
 
     /// Request a list of tables.
@@ -397,12 +404,28 @@ impl PreparedStatement<Channel> {
         }
     }
 
-    /// Executes the prepared statement query on the server.
+    /// Executes the prepared statement query on the server, binding the parameters
+    /// set with [`Self::set_parameters`] first, if any.
     pub async fn execute(&mut self) -> Result<FlightInfo, ArrowError> {
         let cmd = CommandPreparedStatementQuery {
             prepared_statement_handle: self.handle.clone(),
         };
         let descriptor = FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec());
+        if self.parameter_binding.is_some() {
+            let flight_data = self.flight_data_with_parameters(descriptor.clone())?;
+            let mut result = self
+                .flight_client
+                .do_put(stream::iter(flight_data))
+                .await
+                .map_err(status_to_arrow_error)?
+                .into_inner();
+            while result
+                .message()
+                .await
+                .map_err(status_to_arrow_error)?
+                .is_some()
+            {}
+        }
         let result = self
             .flight_client
             .get_flight_info(descriptor)
@@ -412,18 +435,17 @@ impl PreparedStatement<Channel> {
         Ok(result)
     }
 
-    /// Executes the prepared statement update query on the server.
+    /// Executes the prepared statement update query on the server, with the
+    /// parameters set with [`Self::set_parameters`], if any.
     pub async fn execute_update(&mut self) -> Result<i64, ArrowError> {
-        let cmd = CommandPreparedStatementQuery {
+        let cmd = CommandPreparedStatementUpdate {
             prepared_statement_handle: self.handle.clone(),
         };
         let descriptor = FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec());
+        let flight_data = self.flight_data_with_parameters(descriptor)?;
         let mut result = self
             .flight_client
-            .do_put(stream::iter(vec![FlightData {
-                flight_descriptor: Some(descriptor),
-                ..Default::default()
-            }]))
+            .do_put(stream::iter(flight_data))
             .await
             .map_err(status_to_arrow_error)?
             .into_inner();
@@ -438,6 +460,23 @@ impl PreparedStatement<Channel> {
         Ok(result.record_count)
     }
 
+    /// Returns the `FlightData` to send with `descriptor` in a `do_put` request,
+    /// the bound parameters, if any, are encoded after the descriptor's schema message
+    fn flight_data_with_parameters(
+        &self,
+        descriptor: FlightDescriptor,
+    ) -> Result<Vec<FlightData>, ArrowError> {
+        let mut flight_data = match &self.parameter_binding {
+            Some(batch) => batches_to_flight_data(
+                batch.schema().as_ref().clone(),
+                vec![batch.clone()],
+            )?,
+            None => vec![FlightData::default()],
+        };
+        flight_data[0].flight_descriptor = Some(descriptor);
+        Ok(flight_data)
+    }
+
     /// Retrieve the parameter schema from the query.
     pub fn parameter_schema(&self) -> Result<&Schema, ArrowError> {
         Ok(&self.parameter_schema)
@@ -449,10 +488,28 @@ impl PreparedStatement<Channel> {
     }
 
     /// Set a RecordBatch that contains the parameters that will be bind.
+    ///
+    /// Each row of `parameter_binding` is a set of parameters, which are sent to the
+    /// server on the next [`Self::execute`] or [`Self::execute_update`]. Returns an
+    /// error if its columns do not match the [`Self::parameter_schema`] of the
+    /// statement, when the server provided one.
     pub fn set_parameters(
         &mut self,
         parameter_binding: RecordBatch,
     ) -> Result<(), ArrowError> {
+        let expected = self.parameter_schema.fields();
+        let actual = parameter_binding.schema();
+        let matches = expected.len() == actual.fields().len()
+            && expected
+                .iter()
+                .zip(actual.fields())
+                .all(|(expected, actual)| expected.data_type() == actual.data_type());
+        if !expected.is_empty() && !matches {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Parameters {actual:?} do not match the parameter schema {:?}",
+                self.parameter_schema
+            )));
+        }
         self.parameter_binding = Some(parameter_binding);
         Ok(())
     }
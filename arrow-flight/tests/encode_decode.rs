@@ -20,12 +20,15 @@
 use std::sync::Arc;
 
 use arrow::{compute::concat_batches, datatypes::Int32Type};
-use arrow_array::{ArrayRef, DictionaryArray, Float64Array, RecordBatch, UInt8Array};
+use arrow_array::{
+    Array, ArrayRef, DictionaryArray, Float64Array, RecordBatch, StructArray, UInt8Array,
+};
 use arrow_flight::{
     decode::{DecodedPayload, FlightDataDecoder, FlightRecordBatchStream},
-    encode::FlightDataEncoderBuilder,
+    encode::{DictionaryHandling, FlightDataEncoderBuilder},
     error::FlightError,
 };
+use arrow_ipc::{root_as_message, MessageHeader};
 use arrow_schema::{DataType, Field, Schema, SchemaRef};
 use bytes::Bytes;
 use futures::{StreamExt, TryStreamExt};
@@ -96,6 +99,83 @@ async fn test_dictionary_many() {
     .await;
 }
 
+#[tokio::test]
+async fn test_dictionary_resend() {
+    let input = vec![
+        make_dictionary_batch(5),
+        make_dictionary_batch(9),
+        make_dictionary_batch(5),
+    ];
+    let encoder = FlightDataEncoderBuilder::default()
+        .with_dictionary_handling(DictionaryHandling::Resend);
+    roundtrip_with_encoder(encoder, input.clone(), input).await;
+}
+
+#[tokio::test]
+async fn test_dictionary_resend_multiple_columns() {
+    let a = make_dictionary_batch(5).column(0).clone();
+    let b: DictionaryArray<Int32Type> =
+        vec!["x", "y", "x", "z", "y"].into_iter().collect();
+    let b = Arc::new(b) as ArrayRef;
+    let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
+    let input = vec![batch.clone(), batch.slice(1, 3), batch];
+
+    let encoder = FlightDataEncoderBuilder::default()
+        .with_dictionary_handling(DictionaryHandling::Resend);
+    roundtrip_with_encoder(encoder, input.clone(), input).await;
+}
+
+#[tokio::test]
+async fn test_dictionary_resend_nested() {
+    let a = make_dictionary_batch(5).column(0).clone();
+    let b: DictionaryArray<Int32Type> =
+        vec!["x", "y", "x", "z", "y"].into_iter().collect();
+    let b = Arc::new(StructArray::from(vec![(
+        Field::new("b", b.data_type().clone(), false),
+        Arc::new(b) as ArrayRef,
+    )])) as ArrayRef;
+    let batch = RecordBatch::try_from_iter(vec![("a", a), ("s", b)]).unwrap();
+    let input = vec![batch.clone(), batch.slice(1, 3), batch];
+
+    let encoder = FlightDataEncoderBuilder::default()
+        .with_dictionary_handling(DictionaryHandling::Resend);
+    roundtrip_with_encoder(encoder, input.clone(), input).await;
+}
+
+#[tokio::test]
+async fn test_dictionary_resend_only_when_replaced() {
+    let batch = make_dictionary_batch(6);
+    let input = vec![
+        batch.clone(),
+        // shares the dictionary of the previous batch
+        batch.slice(2, 3),
+        // different dictionary
+        make_dictionary_batch(9),
+    ];
+    let input_stream = futures::stream::iter(input).map(Ok);
+
+    let encode_stream = FlightDataEncoderBuilder::default()
+        .with_dictionary_handling(DictionaryHandling::Resend)
+        .build(input_stream);
+    let messages: Vec<_> = encode_stream.try_collect().await.expect("encode fails");
+
+    let header_types: Vec<_> = messages
+        .iter()
+        .map(|data| root_as_message(&data.data_header).unwrap().header_type())
+        .collect();
+    assert_eq!(
+        header_types,
+        vec![
+            MessageHeader::Schema,
+            MessageHeader::DictionaryBatch,
+            MessageHeader::RecordBatch,
+            MessageHeader::RecordBatch,
+            MessageHeader::DictionaryBatch,
+            MessageHeader::RecordBatch,
+        ]
+    );
+}
+
 #[tokio::test]
 async fn test_zero_batches_no_schema() {
     let stream = FlightDataEncoderBuilder::default().build(futures::stream::iter(vec![]));
@@ -181,6 +261,55 @@ async fn test_app_metadata() {
     assert!(matches!(message2.payload, DecodedPayload::RecordBatch(_)));
 }
 
+#[tokio::test]
+async fn test_batch_app_metadata() {
+    let input = vec![
+        (make_primative_batch(5), Bytes::from("first")),
+        (make_primative_batch(3), Bytes::new()),
+        (make_primative_batch(7), Bytes::from("third")),
+    ];
+    let input_stream = futures::stream::iter(input.clone()).map(Ok);
+
+    let encode_stream = FlightDataEncoderBuilder::default()
+        .with_metadata(Bytes::from("schema"))
+        .build_with_app_metadata(input_stream);
+
+    let output: Vec<_> = FlightDataDecoder::new(encode_stream)
+        .into_batches_with_app_metadata()
+        .try_collect()
+        .await
+        .expect("encode / decode");
+
+    let metadata: Vec<_> = output.iter().map(|(_, m)| m.clone()).collect();
+    assert_eq!(
+        metadata,
+        vec![Bytes::from("first"), Bytes::new(), Bytes::from("third")]
+    );
+    for ((output, _), (input, _)) in output.iter().zip(&input) {
+        assert_eq!(output, input);
+    }
+}
+
+#[tokio::test]
+async fn test_batch_app_metadata_split() {
+    // metadata is only sent with the first message of a split batch
+    let input_stream =
+        futures::stream::iter(vec![Ok((make_primative_batch(3), Bytes::from("m")))]);
+
+    let encode_stream = FlightDataEncoderBuilder::default()
+        .with_max_flight_data_size(1)
+        .build_with_app_metadata(input_stream);
+
+    let output: Vec<_> = FlightDataDecoder::new(encode_stream)
+        .into_batches_with_app_metadata()
+        .try_collect()
+        .await
+        .expect("encode / decode");
+
+    let metadata: Vec<_> = output.into_iter().map(|(_, m)| m).collect();
+    assert_eq!(metadata, vec![Bytes::from("m"), Bytes::new(), Bytes::new()]);
+}
+
 #[tokio::test]
 async fn test_max_message_size() {
     let input_batch_stream = futures::stream::iter(vec![Ok(make_primative_batch(5))]);
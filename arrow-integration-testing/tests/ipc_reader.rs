@@ -55,28 +55,13 @@ fn read_0_1_7() {
     });
 }
 
-#[test]
-#[should_panic(expected = "Big Endian is not supported for Decimal!")]
-fn read_1_0_0_bigendian_decimal_should_panic() {
-    let testdata = arrow_test_data();
-    verify_arrow_file(&testdata, "1.0.0-bigendian", "generated_decimal");
-}
-
-#[test]
-#[should_panic(
-    expected = "Last offset 687865856 of Utf8 is larger than values length 41"
-)]
-fn read_1_0_0_bigendian_dictionary_should_panic() {
-    // The offsets are not translated for big-endian files
-    // https://github.com/apache/arrow-rs/issues/859
-    let testdata = arrow_test_data();
-    verify_arrow_file(&testdata, "1.0.0-bigendian", "generated_dictionary");
-}
-
 #[test]
 fn read_1_0_0_bigendian() {
     let testdata = arrow_test_data();
+    let version = "1.0.0-bigendian";
     let paths = vec![
+        "generated_decimal",
+        "generated_dictionary",
         "generated_interval",
         "generated_datetime",
         "generated_map",
@@ -88,19 +73,8 @@ fn read_1_0_0_bigendian() {
         "generated_primitive",
     ];
     paths.iter().for_each(|path| {
-        let file = File::open(format!(
-            "{testdata}/arrow-ipc-stream/integration/1.0.0-bigendian/{path}.arrow_file"
-        ))
-        .unwrap();
-
-        FileReader::try_new(file, None).unwrap();
-
-        // While the the reader doesn't error but the values are not
-        // read correctly on little endian platforms so verifing the
-        // contents fails
-        //
-        // https://github.com/apache/arrow-rs/issues/3459
-        //verify_arrow_file(&testdata, "1.0.0-bigendian", path);
+        verify_arrow_file(&testdata, version, path);
+        verify_arrow_stream(&testdata, version, path);
     });
 }
 
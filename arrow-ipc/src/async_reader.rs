@@ -0,0 +1,250 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Asynchronous reading of the Arrow IPC streaming format
+//!
+//! ```
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! # use arrow_ipc::async_reader::AsyncStreamReader;
+//! # use arrow_ipc::writer::StreamWriter;
+//! # use futures::TryStreamExt;
+//! # let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+//! # let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+//! # let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+//! # writer.write(&batch).unwrap();
+//! # writer.finish().unwrap();
+//! # let buffer = writer.into_inner().unwrap();
+//! // Any tokio::io::AsyncRead, such as a TcpStream or a File
+//! let reader = AsyncStreamReader::try_new(buffer.as_slice(), None).await.unwrap();
+//! let batches: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+//! assert_eq!(batches, vec![batch]);
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use futures::Stream;
+use tokio::io::{AsyncRead, AsyncReadExt, BufReader};
+
+use arrow_array::RecordBatch;
+use arrow_buffer::MutableBuffer;
+use arrow_schema::{ArrowError, SchemaRef};
+
+use crate::reader::{parse_message, StreamDecoder, StreamMessage};
+use crate::CONTINUATION_MARKER;
+
+/// Arrow Stream reader for an [`AsyncRead`]
+///
+/// Messages are decoded as in [`StreamReader`](crate::reader::StreamReader)
+pub struct AsyncStreamReader<R> {
+    /// Buffered stream reader
+    reader: BufReader<R>,
+
+    /// Decodes the messages of the stream
+    decoder: StreamDecoder,
+
+    /// An indicator of whether the stream is complete.
+    ///
+    /// This value is set to `true` the first time [`Self::next_batch`] returns `None`.
+    finished: bool,
+}
+
+impl<R> fmt::Debug for AsyncStreamReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncStreamReader<R>")
+            .field("reader", &"BufReader<..>")
+            .field("decoder", &self.decoder)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncStreamReader<R> {
+    /// Try to create a new async stream reader
+    ///
+    /// The first message in the stream is the schema, the reader will fail if it does not
+    /// encounter a schema.
+    pub async fn try_new(
+        reader: R,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let mut reader = BufReader::new(reader);
+        let meta_len = read_meta_len(&mut reader).await?;
+
+        let mut meta_buffer = vec![0; meta_len];
+        reader.read_exact(&mut meta_buffer).await?;
+
+        let decoder = StreamDecoder::try_new(&meta_buffer, projection)?;
+        Ok(Self {
+            reader,
+            decoder,
+            finished: false,
+        })
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema()
+    }
+
+    /// Check if the stream is finished
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Return the custom metadata of the most recently read record batch message,
+    /// which is empty if the message has none
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        self.decoder.custom_metadata()
+    }
+
+    /// Read the next [`RecordBatch`] from the stream, returning `None` once it is exhausted
+    pub async fn next_batch(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        while !self.finished {
+            let meta_len = match read_meta_len(&mut self.reader).await {
+                Ok(meta_len) => meta_len,
+                // Handle EOF without the "0xFFFFFFFF 0x00000000"
+                // valid according to:
+                // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => 0,
+                Err(e) => return Err(e.into()),
+            };
+
+            if meta_len == 0 {
+                // the stream has ended, mark the reader as finished
+                self.finished = true;
+                break;
+            }
+
+            let mut meta_buffer = vec![0; meta_len];
+            self.reader.read_exact(&mut meta_buffer).await?;
+            let message = parse_message(&meta_buffer)?;
+
+            // read the block that makes up the message body into a buffer
+            let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
+            self.reader.read_exact(&mut buf).await?;
+
+            match self.decoder.decode_message(message, buf.into())? {
+                StreamMessage::RecordBatch(batch) => return Ok(Some(batch)),
+                // read the next message until we encounter a RecordBatch
+                StreamMessage::Dictionary => continue,
+                StreamMessage::End => break,
+            }
+        }
+        Ok(None)
+    }
+
+    /// Convert this reader into a [`Stream`] of [`RecordBatch`]
+    pub fn into_stream(self) -> impl Stream<Item = Result<RecordBatch, ArrowError>> {
+        futures::stream::try_unfold(self, |mut reader| async move {
+            Ok(reader.next_batch().await?.map(|batch| (batch, reader)))
+        })
+    }
+}
+
+/// Read the length of the next message's metadata, skipping any continuation marker
+async fn read_meta_len<R: AsyncRead + Unpin>(
+    reader: &mut BufReader<R>,
+) -> std::io::Result<usize> {
+    let mut meta_size: [u8; 4] = [0; 4];
+    reader.read_exact(&mut meta_size).await?;
+    // If a continuation marker is encountered, skip over it and read
+    // the size from the next four bytes.
+    if meta_size == CONTINUATION_MARKER {
+        reader.read_exact(&mut meta_size).await?;
+    }
+    Ok(i32::from_le_bytes(meta_size) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::TryStreamExt;
+
+    use crate::async_writer::test_batches;
+    use crate::writer::StreamWriter;
+
+    fn write_stream(batches: &[RecordBatch]) -> Vec<u8> {
+        let mut writer = StreamWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader() {
+        let batches = test_batches();
+        let buffer = write_stream(&batches);
+
+        let reader = AsyncStreamReader::try_new(buffer.as_slice(), None)
+            .await
+            .unwrap();
+        assert_eq!(reader.schema(), batches[0].schema());
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        assert_eq!(read, batches);
+
+        // Stream without the end of stream marker
+        let truncated = &buffer[..buffer.len() - 8];
+        let mut reader = AsyncStreamReader::try_new(truncated, None).await.unwrap();
+        for batch in &batches {
+            assert_eq!(&reader.next_batch().await.unwrap().unwrap(), batch);
+        }
+        assert!(reader.next_batch().await.unwrap().is_none());
+        assert!(reader.is_finished());
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader_custom_metadata() {
+        let batches = test_batches();
+        let metadata = HashMap::from([("key".to_string(), "value".to_string())]);
+        let mut writer = StreamWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        writer.write_with_metadata(&batches[0], &metadata).unwrap();
+        writer.write(&batches[1]).unwrap();
+        let buffer = writer.into_inner().unwrap();
+
+        let mut reader = AsyncStreamReader::try_new(buffer.as_slice(), None)
+            .await
+            .unwrap();
+        assert_eq!(reader.next_batch().await.unwrap().unwrap(), batches[0]);
+        assert_eq!(reader.custom_metadata(), &metadata);
+        assert_eq!(reader.next_batch().await.unwrap().unwrap(), batches[1]);
+        assert!(reader.custom_metadata().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader_projection() {
+        let batches = test_batches();
+        let buffer = write_stream(&batches);
+
+        let reader = AsyncStreamReader::try_new(buffer.as_slice(), Some(vec![2, 1]))
+            .await
+            .unwrap();
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        let expected: Vec<_> = batches
+            .iter()
+            .map(|b| b.project(&[2, 1]).unwrap())
+            .collect();
+        assert_eq!(read, expected);
+    }
+}
@@ -0,0 +1,449 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Asynchronous writing of the Arrow IPC stream and file formats
+//!
+//! Record batches are encoded as in [`StreamWriter`](crate::writer::StreamWriter) and
+//! [`FileWriter`](crate::writer::FileWriter), and buffered in memory until the buffer
+//! is full or [`AsyncStreamWriter::flush`] is called. Flushing after every batch
+//! minimises latency, while flushing less often allows batches to be coalesced into
+//! fewer, larger writes.
+//!
+//! ```
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+//! # use arrow_ipc::async_writer::AsyncStreamWriter;
+//! # let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+//! # let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+//! // Any tokio::io::AsyncWrite, such as a TcpStream or a File
+//! let mut writer = AsyncStreamWriter::try_new(vec![], &batch.schema()).await.unwrap();
+//! writer.write(&batch).await.unwrap();
+//! // Send the batch to the underlying writer
+//! writer.flush().await.unwrap();
+//! let buffer = writer.into_inner().await.unwrap();
+//! # assert!(!buffer.is_empty());
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use tokio::io::{AsyncWrite, AsyncWriteExt, BufWriter};
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, Schema};
+
+use crate::writer::{
+    encode_footer, write_continuation, write_message, DictionaryTracker, EncodedData,
+    IpcDataGenerator, IpcWriteOptions,
+};
+
+/// Arrow Stream writer for an [`AsyncWrite`]
+pub struct AsyncStreamWriter<W> {
+    /// The object to write to
+    writer: BufWriter<W>,
+    /// IPC write options
+    write_options: IpcWriteOptions,
+    /// Whether the end of stream marker has been written, and the writer is finished
+    finished: bool,
+    /// Keeps track of dictionaries that have been written
+    dictionary_tracker: DictionaryTracker,
+
+    data_gen: IpcDataGenerator,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncStreamWriter<W> {
+    /// Try create a new writer, with the schema written as part of the header
+    pub async fn try_new(writer: W, schema: &Schema) -> Result<Self, ArrowError> {
+        let write_options = IpcWriteOptions::default();
+        Self::try_new_with_options(writer, schema, write_options).await
+    }
+
+    /// Try create a new writer with IpcWriteOptions
+    pub async fn try_new_with_options(
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        let data_gen = IpcDataGenerator::default();
+        let mut writer = BufWriter::new(writer);
+        // write the schema
+        let encoded_message = data_gen.schema_to_bytes(schema, &write_options);
+        write_encoded(&mut writer, encoded_message, &write_options).await?;
+        Ok(Self {
+            writer,
+            write_options,
+            finished: false,
+            dictionary_tracker: DictionaryTracker::new(false),
+            data_gen,
+        })
+    }
+
+    /// Write a record batch to the stream
+    ///
+    /// The encoded batch may be buffered, call [`Self::flush`] to send it to the
+    /// underlying writer
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, &HashMap::new()).await
+    }
+
+    /// Write a record batch to the stream, attaching `custom_metadata` to its
+    /// record batch message
+    ///
+    /// The encoded batch may be buffered, call [`Self::flush`] to send it to the
+    /// underlying writer
+    pub async fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
+        if self.finished {
+            return Err(ArrowError::IoError(
+                "Cannot write record batch to stream writer as it is closed".to_string(),
+            ));
+        }
+
+        let (encoded_dictionaries, encoded_messages) =
+            self.data_gen.encoded_batches_with_metadata(
+                batch,
+                &mut self.dictionary_tracker,
+                &self.write_options,
+                custom_metadata,
+            )?;
+
+        for encoded_dictionary in encoded_dictionaries {
+            write_encoded(&mut self.writer, encoded_dictionary, &self.write_options)
+                .await?;
+        }
+        for encoded_message in encoded_messages {
+            write_encoded(&mut self.writer, encoded_message, &self.write_options).await?;
+        }
+        Ok(())
+    }
+
+    /// Flush any buffered data to the underlying writer, and flush the writer
+    pub async fn flush(&mut self) -> Result<(), ArrowError> {
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Write continuation bytes, flush, and mark the stream as done
+    pub async fn finish(&mut self) -> Result<(), ArrowError> {
+        if self.finished {
+            return Err(ArrowError::IoError(
+                "Cannot write footer to stream writer as it is closed".to_string(),
+            ));
+        }
+
+        let mut buffer = vec![];
+        write_continuation(&mut buffer, &self.write_options, 0)?;
+        self.writer.write_all(&buffer).await?;
+        self.writer.flush().await?;
+        self.finished = true;
+
+        Ok(())
+    }
+
+    /// Finish the stream if necessary, returning the underlying writer
+    pub async fn into_inner(mut self) -> Result<W, ArrowError> {
+        if !self.finished {
+            self.finish().await?;
+        }
+        Ok(self.writer.into_inner())
+    }
+}
+
+/// Arrow File writer for an [`AsyncWrite`]
+pub struct AsyncFileWriter<W> {
+    /// The object to write to
+    writer: BufWriter<W>,
+    /// IPC write options
+    write_options: IpcWriteOptions,
+    /// A reference to the schema, used in validating record batches
+    schema: Schema,
+    /// The number of bytes between each block of bytes, as an offset for random access
+    block_offsets: usize,
+    /// Dictionary blocks that will be written as part of the IPC footer
+    dictionary_blocks: Vec<crate::Block>,
+    /// Record blocks that will be written as part of the IPC footer
+    record_blocks: Vec<crate::Block>,
+    /// Whether the writer footer has been written, and the writer is finished
+    finished: bool,
+    /// Keeps track of dictionaries that have been written
+    dictionary_tracker: DictionaryTracker,
+
+    data_gen: IpcDataGenerator,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncFileWriter<W> {
+    /// Try create a new writer, with the schema written as part of the header
+    pub async fn try_new(writer: W, schema: &Schema) -> Result<Self, ArrowError> {
+        let write_options = IpcWriteOptions::default();
+        Self::try_new_with_options(writer, schema, write_options).await
+    }
+
+    /// Try create a new writer with IpcWriteOptions
+    pub async fn try_new_with_options(
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        let data_gen = IpcDataGenerator::default();
+        let mut writer = BufWriter::new(writer);
+        // write magic to header aligned on 8 byte boundary
+        let header_size = super::ARROW_MAGIC.len() + 2;
+        writer.write_all(&super::ARROW_MAGIC[..]).await?;
+        writer.write_all(&[0, 0]).await?;
+        // write the schema, set the written bytes to the schema + header
+        let encoded_message = data_gen.schema_to_bytes(schema, &write_options);
+        let (meta, data) =
+            write_encoded(&mut writer, encoded_message, &write_options).await?;
+        Ok(Self {
+            writer,
+            write_options,
+            schema: schema.clone(),
+            block_offsets: meta + data + header_size,
+            dictionary_blocks: vec![],
+            record_blocks: vec![],
+            finished: false,
+            dictionary_tracker: DictionaryTracker::new(true),
+            data_gen,
+        })
+    }
+
+    /// Write a record batch to the file
+    ///
+    /// The encoded batch may be buffered, call [`Self::flush`] to send it to the
+    /// underlying writer
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, &HashMap::new()).await
+    }
+
+    /// Write a record batch to the file, attaching `custom_metadata` to its
+    /// record batch message
+    ///
+    /// The encoded batch may be buffered, call [`Self::flush`] to send it to the
+    /// underlying writer
+    pub async fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
+        if self.finished {
+            return Err(ArrowError::IoError(
+                "Cannot write record batch to file writer as it is closed".to_string(),
+            ));
+        }
+
+        let (encoded_dictionaries, encoded_messages) =
+            self.data_gen.encoded_batches_with_metadata(
+                batch,
+                &mut self.dictionary_tracker,
+                &self.write_options,
+                custom_metadata,
+            )?;
+
+        for encoded_dictionary in encoded_dictionaries {
+            let (meta, data) =
+                write_encoded(&mut self.writer, encoded_dictionary, &self.write_options)
+                    .await?;
+
+            let block =
+                crate::Block::new(self.block_offsets as i64, meta as i32, data as i64);
+            self.dictionary_blocks.push(block);
+            self.block_offsets += meta + data;
+        }
+
+        for encoded_message in encoded_messages {
+            let (meta, data) =
+                write_encoded(&mut self.writer, encoded_message, &self.write_options)
+                    .await?;
+            // add a record block for the footer
+            let block =
+                crate::Block::new(self.block_offsets as i64, meta as i32, data as i64);
+            self.record_blocks.push(block);
+            self.block_offsets += meta + data;
+        }
+        Ok(())
+    }
+
+    /// Flush any buffered data to the underlying writer, and flush the writer
+    pub async fn flush(&mut self) -> Result<(), ArrowError> {
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Write footer and closing tag, flush, and mark the writer as done
+    pub async fn finish(&mut self) -> Result<(), ArrowError> {
+        if self.finished {
+            return Err(ArrowError::IoError(
+                "Cannot write footer to file writer as it is closed".to_string(),
+            ));
+        }
+
+        // write EOS
+        let mut buffer = vec![];
+        write_continuation(&mut buffer, &self.write_options, 0)?;
+        buffer.extend(encode_footer(
+            &self.schema,
+            &self.dictionary_blocks,
+            &self.record_blocks,
+            &self.write_options,
+        ));
+        self.writer.write_all(&buffer).await?;
+        self.writer.flush().await?;
+        self.finished = true;
+
+        Ok(())
+    }
+
+    /// Finish the file if necessary, returning the underlying writer
+    pub async fn into_inner(mut self) -> Result<W, ArrowError> {
+        if !self.finished {
+            self.finish().await?;
+        }
+        Ok(self.writer.into_inner())
+    }
+}
+
+/// Encode a message with [`write_message`] and write it to `writer`, returning
+/// metadata and buffer data lengths written
+async fn write_encoded<W: AsyncWrite + Unpin>(
+    writer: &mut BufWriter<W>,
+    encoded: EncodedData,
+    write_options: &IpcWriteOptions,
+) -> Result<(usize, usize), ArrowError> {
+    let mut buffer =
+        Vec::with_capacity(encoded.ipc_message.len() + encoded.arrow_data.len() + 16);
+    let written = write_message(&mut buffer, encoded, write_options)?;
+    writer.write_all(&buffer).await?;
+    Ok(written)
+}
+
+/// Returns record batches with a dictionary column, shared by the async tests
+#[cfg(test)]
+pub(crate) fn test_batches() -> Vec<RecordBatch> {
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, DictionaryArray, Int32Array, StringArray};
+    use std::sync::Arc;
+
+    (0..3)
+        .map(|i| {
+            let a: ArrayRef = Arc::new(Int32Array::from(vec![i, i + 1, i + 2]));
+            let b: ArrayRef = Arc::new(
+                vec!["x", "y", "x"]
+                    .into_iter()
+                    .collect::<DictionaryArray<Int32Type>>(),
+            );
+            let c: ArrayRef =
+                Arc::new(StringArray::from(vec![Some("foo"), None, Some("bar")]));
+            RecordBatch::try_from_iter([("a", a), ("b", b), ("c", c)]).unwrap()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use crate::reader::{FileReader, StreamReader};
+
+    /// An [`AsyncWrite`] that records the data it has received
+    #[derive(Default)]
+    struct RecordingWriter {
+        data: Vec<u8>,
+        flushes: usize,
+    }
+
+    impl AsyncWrite for RecordingWriter {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<std::io::Result<usize>> {
+            self.data.extend_from_slice(buf);
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            self.flushes += 1;
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_writer() {
+        let batches = test_batches();
+        let schema = batches[0].schema();
+
+        let mut writer = AsyncStreamWriter::try_new(RecordingWriter::default(), &schema)
+            .await
+            .unwrap();
+        for batch in &batches {
+            writer.write(batch).await.unwrap();
+            // nothing is sent to the underlying writer until flushed
+            assert!(writer.writer.get_ref().data.is_empty());
+        }
+        writer.flush().await.unwrap();
+        assert!(!writer.writer.get_ref().data.is_empty());
+        assert_eq!(writer.writer.get_ref().flushes, 1);
+
+        let inner = writer.into_inner().await.unwrap();
+        let reader = StreamReader::try_new(inner.data.as_slice(), None).unwrap();
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(read, batches);
+    }
+
+    #[tokio::test]
+    async fn test_async_file_writer() {
+        let batches = test_batches();
+        let schema = batches[0].schema();
+
+        let mut writer = AsyncFileWriter::try_new(vec![], &schema).await.unwrap();
+        for batch in &batches {
+            writer.write(batch).await.unwrap();
+            writer.flush().await.unwrap();
+        }
+        let buffer = writer.into_inner().await.unwrap();
+
+        let reader = FileReader::try_new(Cursor::new(buffer), None).unwrap();
+        assert_eq!(reader.num_batches(), batches.len());
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(read, batches);
+
+        // write after finish
+        let mut writer = AsyncFileWriter::try_new(vec![], &schema).await.unwrap();
+        writer.finish().await.unwrap();
+        let err = writer.write(&batches[0]).await.unwrap_err();
+        assert!(err.to_string().contains("closed"), "{err}");
+    }
+}
@@ -23,6 +23,7 @@ use flatbuffers::{
 };
 use std::collections::HashMap;
 
+use crate::endian::NATIVE_ENDIANNESS;
 use crate::{size_prefixed_root_as_message, CONTINUATION_MARKER};
 use DataType::*;
 
@@ -40,6 +41,15 @@ pub fn schema_to_fb(schema: &Schema) -> FlatBufferBuilder {
 pub fn schema_to_fb_offset<'a>(
     fbb: &mut FlatBufferBuilder<'a>,
     schema: &Schema,
+) -> WIPOffset<crate::Schema<'a>> {
+    schema_to_fb_offset_with_endianness(fbb, schema, NATIVE_ENDIANNESS)
+}
+
+/// Serialize a schema in IPC format, declaring data of the given endianness
+pub(crate) fn schema_to_fb_offset_with_endianness<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    schema: &Schema,
+    endianness: crate::Endianness,
 ) -> WIPOffset<crate::Schema<'a>> {
     let fields = schema
         .fields()
@@ -49,25 +59,13 @@ pub fn schema_to_fb_offset<'a>(
     let fb_field_list = fbb.create_vector(&fields);
 
     let fb_metadata_list = if !schema.metadata().is_empty() {
-        let custom_metadata = schema
-            .metadata()
-            .iter()
-            .map(|(k, v)| {
-                let fb_key_name = fbb.create_string(k);
-                let fb_val_name = fbb.create_string(v);
-
-                let mut kv_builder = crate::KeyValueBuilder::new(fbb);
-                kv_builder.add_key(fb_key_name);
-                kv_builder.add_value(fb_val_name);
-                kv_builder.finish()
-            })
-            .collect::<Vec<_>>();
-        Some(fbb.create_vector(&custom_metadata))
+        Some(metadata_to_fb(fbb, schema.metadata()))
     } else {
         None
     };
 
     let mut builder = crate::SchemaBuilder::new(fbb);
+    builder.add_endianness(endianness);
     builder.add_fields(fb_field_list);
     if let Some(fb_metadata_list) = fb_metadata_list {
         builder.add_custom_metadata(fb_metadata_list);
@@ -75,6 +73,37 @@ pub fn schema_to_fb_offset<'a>(
     builder.finish()
 }
 
+/// Serialize key-value metadata in IPC format
+pub(crate) fn metadata_to_fb<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    metadata: &HashMap<String, String>,
+) -> WIPOffset<Vector<'a, ForwardsUOffset<crate::KeyValue<'a>>>> {
+    let custom_metadata = metadata
+        .iter()
+        .map(|(k, v)| {
+            let fb_key_name = fbb.create_string(k);
+            let fb_val_name = fbb.create_string(v);
+
+            let mut kv_builder = crate::KeyValueBuilder::new(fbb);
+            kv_builder.add_key(fb_key_name);
+            kv_builder.add_value(fb_val_name);
+            kv_builder.finish()
+        })
+        .collect::<Vec<_>>();
+    fbb.create_vector(&custom_metadata)
+}
+
+/// Deserialize key-value metadata from IPC format, skipping entries without a
+/// key or value
+pub(crate) fn fb_to_metadata(
+    fb: Option<Vector<'_, ForwardsUOffset<crate::KeyValue<'_>>>>,
+) -> HashMap<String, String> {
+    fb.into_iter()
+        .flatten()
+        .filter_map(|kv| Some((kv.key()?.to_string(), kv.value()?.to_string())))
+        .collect()
+}
+
 /// Convert an IPC Field to Arrow Field
 impl<'a> From<crate::Field<'a>> for Field {
     fn from(field: crate::Field) -> Field {
@@ -114,12 +143,6 @@ pub fn fb_to_schema(fb: crate::Schema) -> Schema {
     let len = c_fields.len();
     for i in 0..len {
         let c_field: crate::Field = c_fields.get(i);
-        match c_field.type_type() {
-            crate::Type::Decimal if fb.endianness() == crate::Endianness::Big => {
-                unimplemented!("Big Endian is not supported for Decimal!")
-            }
-            _ => (),
-        };
         fields.push(c_field.into());
     }
 
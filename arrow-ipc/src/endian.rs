@@ -0,0 +1,141 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion of IPC buffers between little and big endian byte order
+
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_schema::{DataType, IntervalUnit, UnionMode};
+
+/// The endianness of the platform
+pub(crate) const NATIVE_ENDIANNESS: crate::Endianness = if cfg!(target_endian = "big") {
+    crate::Endianness::Big
+} else {
+    crate::Endianness::Little
+};
+
+/// How the values of a buffer are byte swapped
+enum Swap {
+    /// Reverse the bytes of each value of this width
+    Width(usize),
+    /// Reverse the bytes of the months, days and nanoseconds of each value
+    MonthDayNano,
+}
+
+/// Returns how to swap the buffer at `index` of an array of `data_type`, where
+/// `index` does not count the validity buffer
+fn buffer_swap(data_type: &DataType, index: usize) -> Option<Swap> {
+    use DataType::*;
+    match (data_type, index) {
+        (Int16 | UInt16 | Float16, 0) => Some(Swap::Width(2)),
+        (
+            Int32
+            | UInt32
+            | Float32
+            | Date32
+            | Time32(_)
+            | Interval(IntervalUnit::YearMonth)
+            | Interval(IntervalUnit::DayTime),
+            0,
+        ) => Some(Swap::Width(4)),
+        (
+            Int64 | UInt64 | Float64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_),
+            0,
+        ) => Some(Swap::Width(8)),
+        (Interval(IntervalUnit::MonthDayNano), 0) => Some(Swap::MonthDayNano),
+        (Decimal128(_, _), 0) => Some(Swap::Width(16)),
+        (Decimal256(_, _), 0) => Some(Swap::Width(32)),
+        // offsets
+        (Utf8 | Binary | List(_) | Map(_, _), 0) => Some(Swap::Width(4)),
+        (LargeUtf8 | LargeBinary | LargeList(_), 0) => Some(Swap::Width(8)),
+        (Union(_, _, UnionMode::Dense), 1) => Some(Swap::Width(4)),
+        // keys
+        (Dictionary(key_type, _), 0) => buffer_swap(key_type, 0),
+        _ => None,
+    }
+}
+
+/// Reverses the byte order of the values in the buffer at `index` of an array of
+/// `data_type`, where `index` does not count the validity buffer
+///
+/// Returns `None` if the buffer does not contain multi-byte values
+pub(crate) fn swap_buffer(
+    data_type: &DataType,
+    index: usize,
+    buffer: &[u8],
+) -> Option<Buffer> {
+    let swap = buffer_swap(data_type, index)?;
+    let mut swapped = MutableBuffer::from_len_zeroed(buffer.len());
+    swapped.as_slice_mut().copy_from_slice(buffer);
+    match swap {
+        Swap::Width(width) => swapped
+            .as_slice_mut()
+            .chunks_exact_mut(width)
+            .for_each(|value| value.reverse()),
+        Swap::MonthDayNano => {
+            swapped
+                .as_slice_mut()
+                .chunks_exact_mut(16)
+                .for_each(|value| {
+                    value[..4].reverse();
+                    value[4..8].reverse();
+                    value[8..].reverse();
+                })
+        }
+    }
+    Some(swapped.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_buffer::i256;
+
+    #[test]
+    fn test_swap_buffer() {
+        let values = Buffer::from_slice_ref([1_i32, -2, 3]);
+        let swapped = swap_buffer(&DataType::Int32, 0, &values).unwrap();
+        assert_eq!(
+            swapped.typed_data::<i32>(),
+            &[1_i32.to_be(), (-2_i32).to_be(), 3_i32.to_be()]
+        );
+        assert_eq!(swap_buffer(&DataType::Int32, 0, &swapped).unwrap(), values);
+
+        let values = Buffer::from_slice_ref([i256::from_i128(-5)]);
+        let swapped = swap_buffer(&DataType::Decimal256(40, 2), 0, &values).unwrap();
+        let expected: Vec<u8> = values.iter().rev().copied().collect();
+        assert_eq!(swapped.as_slice(), expected);
+
+        // month, day and nanoseconds are swapped individually
+        let value: i128 = (5_i128 << 64) | (3_i128 << 32) | 2;
+        let values = Buffer::from_slice_ref([value]);
+        let swapped =
+            swap_buffer(&DataType::Interval(IntervalUnit::MonthDayNano), 0, &values)
+                .unwrap();
+        let mut expected = vec![0, 0, 0, 2, 0, 0, 0, 3];
+        expected.extend_from_slice(&5_i64.to_be_bytes());
+        assert_eq!(swapped.as_slice(), expected);
+
+        let dictionary = DataType::Dictionary(
+            Box::new(DataType::Int16),
+            Box::new(DataType::LargeUtf8),
+        );
+        assert!(swap_buffer(&dictionary, 0, &[0, 1]).is_some());
+        assert!(swap_buffer(&DataType::Utf8, 1, b"abcd").is_none());
+        assert!(swap_buffer(&DataType::Int8, 0, &[1, 2]).is_none());
+    }
+}
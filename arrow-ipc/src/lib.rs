@@ -21,9 +21,16 @@
 
 pub mod convert;
 pub mod reader;
+pub mod tensor;
 pub mod writer;
 
+#[cfg(feature = "async")]
+pub mod async_reader;
+#[cfg(feature = "async")]
+pub mod async_writer;
+
 mod compression;
+mod endian;
 
 #[allow(clippy::redundant_closure)]
 #[allow(clippy::needless_lifetimes)]
@@ -24,15 +24,18 @@ use arrow_buffer::i256;
 use std::collections::HashMap;
 use std::fmt;
 use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::ops::Range;
 use std::sync::Arc;
 
 use arrow_array::*;
 use arrow_buffer::{Buffer, MutableBuffer};
 use arrow_cast::cast;
+use arrow_data::transform::MutableArrayData;
 use arrow_data::ArrayData;
 use arrow_schema::*;
 
 use crate::compression::CompressionCodec;
+use crate::endian::{swap_buffer, NATIVE_ENDIANNESS};
 use crate::CONTINUATION_MARKER;
 use DataType::*;
 
@@ -79,8 +82,14 @@ fn create_array(
     mut buffer_index: usize,
     compression_codec: &Option<CompressionCodec>,
     metadata: &crate::MetadataVersion,
+    swap_endianness: bool,
 ) -> Result<(ArrayRef, usize, usize), ArrowError> {
     let data_type = field.data_type();
+    // convert the data buffer at `index`, excluding the validity buffer, to native endianness
+    let to_native = |index: usize, buffer: Buffer| match swap_endianness {
+        true => swap_buffer(data_type, index, &buffer).unwrap_or(buffer),
+        false => buffer,
+    };
     let array = match data_type {
         Utf8 | Binary | LargeBinary | LargeUtf8 => {
             let array = create_primitive_array(
@@ -88,7 +97,14 @@ fn create_array(
                 data_type,
                 &[
                     read_buffer(buffers.get(buffer_index), data, compression_codec)?,
-                    read_buffer(buffers.get(buffer_index + 1), data, compression_codec)?,
+                    to_native(
+                        0,
+                        read_buffer(
+                            buffers.get(buffer_index + 1),
+                            data,
+                            compression_codec,
+                        )?,
+                    ),
                     read_buffer(buffers.get(buffer_index + 2), data, compression_codec)?,
                 ],
             )?;
@@ -113,7 +129,10 @@ fn create_array(
             let list_node = nodes.get(node_index);
             let list_buffers = [
                 read_buffer(buffers.get(buffer_index), data, compression_codec)?,
-                read_buffer(buffers.get(buffer_index + 1), data, compression_codec)?,
+                to_native(
+                    0,
+                    read_buffer(buffers.get(buffer_index + 1), data, compression_codec)?,
+                ),
             ];
             node_index += 1;
             buffer_index += 2;
@@ -127,6 +146,7 @@ fn create_array(
                 buffer_index,
                 compression_codec,
                 metadata,
+                swap_endianness,
             )?;
             node_index = triple.1;
             buffer_index = triple.2;
@@ -152,6 +172,7 @@ fn create_array(
                 buffer_index,
                 compression_codec,
                 metadata,
+                swap_endianness,
             )?;
             node_index = triple.1;
             buffer_index = triple.2;
@@ -180,6 +201,7 @@ fn create_array(
                     buffer_index,
                     compression_codec,
                     metadata,
+                    swap_endianness,
                 )?;
                 node_index = triple.1;
                 buffer_index = triple.2;
@@ -199,7 +221,10 @@ fn create_array(
             let index_node = nodes.get(node_index);
             let index_buffers = [
                 read_buffer(buffers.get(buffer_index), data, compression_codec)?,
-                read_buffer(buffers.get(buffer_index + 1), data, compression_codec)?,
+                to_native(
+                    0,
+                    read_buffer(buffers.get(buffer_index + 1), data, compression_codec)?,
+                ),
             ];
 
             let dict_id = field.dict_id().ok_or_else(|| {
@@ -245,7 +270,7 @@ fn create_array(
                     let buffer =
                         read_buffer(buffers.get(buffer_index), data, compression_codec)?;
                     buffer_index += 1;
-                    Some(buffer[..len * 4].into())
+                    Some(to_native(1, buffer[..len * 4].into()))
                 }
                 UnionMode::Sparse => None,
             };
@@ -263,6 +288,7 @@ fn create_array(
                     buffer_index,
                     compression_codec,
                     metadata,
+                    swap_endianness,
                 )?;
 
                 node_index = triple.1;
@@ -307,7 +333,14 @@ fn create_array(
                 data_type,
                 &[
                     read_buffer(buffers.get(buffer_index), data, compression_codec)?,
-                    read_buffer(buffers.get(buffer_index + 1), data, compression_codec)?,
+                    to_native(
+                        0,
+                        read_buffer(
+                            buffers.get(buffer_index + 1),
+                            data,
+                            compression_codec,
+                        )?,
+                    ),
                 ],
             )?;
             node_index += 1;
@@ -395,6 +428,47 @@ fn skip_field(
     Ok((node_index, buffer_index))
 }
 
+/// Returns the ranges of the message body containing the buffers of the fields in
+/// `projection`, merging adjacent ranges.
+fn projected_body_ranges(
+    batch: crate::RecordBatch,
+    schema: &Schema,
+    projection: &[usize],
+) -> Result<Vec<Range<usize>>, ArrowError> {
+    let buffers = batch.buffers().ok_or_else(|| {
+        ArrowError::IoError("Unable to get buffers from IPC RecordBatch".to_string())
+    })?;
+
+    let mut ranges: Vec<Range<usize>> = vec![];
+    let mut node_index = 0;
+    let mut buffer_index = 0;
+    for (idx, field) in schema.fields().iter().enumerate() {
+        let start_buffer = buffer_index;
+        (node_index, buffer_index) =
+            skip_field(field.data_type(), node_index, buffer_index)?;
+        if !projection.contains(&idx) {
+            continue;
+        }
+        if buffer_index > buffers.len() {
+            return Err(ArrowError::IoError(format!(
+                "Invalid data for schema. {} refers to buffer index {} but only {} in schema",
+                field,
+                buffer_index,
+                buffers.len()
+            )));
+        }
+        for buffer in (start_buffer..buffer_index).map(|i| buffers.get(i)) {
+            let start = buffer.offset() as usize;
+            let end = start + buffer.length() as usize;
+            match ranges.last_mut() {
+                Some(last) if last.end == start => last.end = end,
+                _ => ranges.push(start..end),
+            }
+        }
+    }
+    Ok(ranges)
+}
+
 /// Reads the correct number of buffers based on data type and null_count, and creates a
 /// primitive array ref
 fn create_primitive_array(
@@ -582,6 +656,28 @@ pub fn read_record_batch(
     dictionaries_by_id: &HashMap<i64, ArrayRef>,
     projection: Option<&[usize]>,
     metadata: &crate::MetadataVersion,
+) -> Result<RecordBatch, ArrowError> {
+    read_record_batch_impl(
+        buf,
+        batch,
+        schema,
+        dictionaries_by_id,
+        projection,
+        metadata,
+        false,
+    )
+}
+
+/// Creates a record batch as [`read_record_batch`], converting its buffers to
+/// native endianness if `swap_endianness` is true
+fn read_record_batch_impl(
+    buf: &Buffer,
+    batch: crate::RecordBatch,
+    schema: SchemaRef,
+    dictionaries_by_id: &HashMap<i64, ArrayRef>,
+    projection: Option<&[usize]>,
+    metadata: &crate::MetadataVersion,
+    swap_endianness: bool,
 ) -> Result<RecordBatch, ArrowError> {
     let buffers = batch.buffers().ok_or_else(|| {
         ArrowError::IoError("Unable to get buffers from IPC RecordBatch".to_string())
@@ -603,6 +699,7 @@ pub fn read_record_batch(
 
     if let Some(projection) = projection {
         // project fields
+        let mut projected = vec![None; schema.fields().len()];
         for (idx, field) in schema.fields().iter().enumerate() {
             // Create array for projected field
             if projection.contains(&idx) {
@@ -616,10 +713,11 @@ pub fn read_record_batch(
                     buffer_index,
                     &compression_codec,
                     metadata,
+                    swap_endianness,
                 )?;
                 node_index = triple.1;
                 buffer_index = triple.2;
-                arrays.push(triple.0);
+                projected[idx] = Some(triple.0);
             } else {
                 // Skip field.
                 // This must be called to advance `node_index` and `buffer_index`.
@@ -629,11 +727,13 @@ pub fn read_record_batch(
             }
         }
 
-        RecordBatch::try_new_with_options(
-            Arc::new(schema.project(projection)?),
-            arrays,
-            &options,
-        )
+        // The projection may reorder fields
+        let schema = schema.project(projection)?;
+        for idx in projection {
+            arrays.push(projected[*idx].clone().unwrap());
+        }
+
+        RecordBatch::try_new_with_options(Arc::new(schema), arrays, &options)
     } else {
         // keep track of index as lists require more than one node
         for field in schema.fields() {
@@ -647,6 +747,7 @@ pub fn read_record_batch(
                 buffer_index,
                 &compression_codec,
                 metadata,
+                swap_endianness,
             )?;
             node_index = triple.1;
             buffer_index = triple.2;
@@ -665,12 +766,19 @@ pub fn read_dictionary(
     dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
     metadata: &crate::MetadataVersion,
 ) -> Result<(), ArrowError> {
-    if batch.isDelta() {
-        return Err(ArrowError::IoError(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
+    read_dictionary_impl(buf, batch, schema, dictionaries_by_id, metadata, false)
+}
 
+/// Reads a dictionary as [`read_dictionary`], converting its buffers to native
+/// endianness if `swap_endianness` is true
+fn read_dictionary_impl(
+    buf: &Buffer,
+    batch: crate::DictionaryBatch,
+    schema: &Schema,
+    dictionaries_by_id: &mut HashMap<i64, ArrayRef>,
+    metadata: &crate::MetadataVersion,
+    swap_endianness: bool,
+) -> Result<(), ArrowError> {
     let id = batch.id();
     let fields_using_this_dictionary = schema.fields_with_dict_id(id);
     let first_field = fields_using_this_dictionary.first().ok_or_else(|| {
@@ -688,13 +796,14 @@ pub fn read_dictionary(
                 metadata: HashMap::new(),
             };
             // Read a single column
-            let record_batch = read_record_batch(
+            let record_batch = read_record_batch_impl(
                 buf,
                 batch.data().unwrap(),
                 Arc::new(schema),
                 dictionaries_by_id,
                 None,
                 metadata,
+                swap_endianness,
             )?;
             Some(record_batch.column(0).clone())
         }
@@ -704,21 +813,218 @@ pub fn read_dictionary(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    // A delta dictionary batch appends its values to the existing dictionary
+    let dictionary_values = match batch.isDelta() {
+        true => {
+            let existing = dictionaries_by_id.get(&id).ok_or_else(|| {
+                ArrowError::IoError(format!(
+                    "Delta dictionary batch for dictionary id {id} without an existing dictionary"
+                ))
+            })?;
+            append_dictionary_values(existing.data(), dictionary_values.data())?
+        }
+        false => dictionary_values,
+    };
+
     // We don't currently record the isOrdered field. This could be general
     // attributes of arrays.
     // Add (possibly multiple) array refs to the dictionaries array.
-    dictionaries_by_id.insert(id, dictionary_values.clone());
+    dictionaries_by_id.insert(id, dictionary_values);
 
     Ok(())
 }
 
+/// Appends the values of a delta dictionary batch to the `existing` values of the
+/// dictionary
+///
+/// Returns an error if the values contain nested dictionaries whose concatenated
+/// values cannot be represented by their key type
+fn append_dictionary_values(
+    existing: &ArrayData,
+    delta: &ArrayData,
+) -> Result<ArrayRef, ArrowError> {
+    check_nested_dictionary_keys(&[existing, delta])?;
+    let mut mutable =
+        MutableArrayData::new(vec![existing, delta], false, existing.len() + delta.len());
+    mutable.extend(0, 0, existing.len());
+    mutable.extend(1, 0, delta.len());
+    Ok(make_array(mutable.freeze()))
+}
+
+/// Checks that concatenating `arrays` with [`MutableArrayData`] does not overflow the
+/// keys of any of the dictionaries they contain, which would panic
+fn check_nested_dictionary_keys(arrays: &[&ArrayData]) -> Result<(), ArrowError> {
+    let children: Vec<Vec<&ArrayData>> = (0..arrays[0].child_data().len())
+        .map(|i| arrays.iter().map(|array| &array.child_data()[i]).collect())
+        .collect();
+    if let Dictionary(key_type, _) = arrays[0].data_type() {
+        // dictionaries are only concatenated if their values differ
+        let values = &children[0];
+        if values.windows(2).all(|v| v[0].ptr_eq(v[1])) {
+            return Ok(());
+        }
+        let len: usize = values.iter().map(|v| v.len()).sum();
+        let max_key = match key_type.as_ref() {
+            Int8 => i8::MAX as usize,
+            Int16 => i16::MAX as usize,
+            Int32 => i32::MAX as usize,
+            UInt8 => u8::MAX as usize,
+            UInt16 => u16::MAX as usize,
+            UInt32 => usize::try_from(u32::MAX).unwrap_or(usize::MAX),
+            _ => usize::MAX,
+        };
+        if len > 0 && len - 1 > max_key {
+            return Err(ArrowError::DictionaryKeyOverflowError);
+        }
+    }
+    children
+        .iter()
+        .try_for_each(|child| check_nested_dictionary_keys(child))
+}
+
+/// Decodes the messages of an IPC file, shared by [`FileReader`] and
+/// [`BufferFileReader`]
+#[derive(Debug)]
+struct FileDecoder {
+    /// The schema that is read from the file footer
+    schema: SchemaRef,
+
+    /// Optional dictionaries for each schema field.
+    ///
+    /// Dictionaries may be appended to in the streaming format.
+    dictionaries_by_id: HashMap<i64, ArrayRef>,
+
+    /// Metadata version
+    metadata_version: crate::MetadataVersion,
+
+    /// Optional projection and projected_schema
+    projection: Option<(Vec<usize>, Schema)>,
+
+    /// Whether the data is not of native endianness, and must be byte swapped
+    swap_endianness: bool,
+}
+
+impl FileDecoder {
+    /// Create a decoder from the footer of a file, returning it along with the
+    /// blocks of the file's record batches
+    ///
+    /// The dictionaries of the file must then be read with [`Self::read_dictionary`]
+    fn try_new(
+        footer: crate::Footer<'_>,
+        projection: Option<Vec<usize>>,
+    ) -> Result<(Self, Vec<crate::Block>), ArrowError> {
+        let blocks = footer
+            .recordBatches()
+            .ok_or_else(|| {
+                ArrowError::IoError(
+                    "Unable to get record batches from IPC Footer".to_string(),
+                )
+            })?
+            .iter()
+            .copied()
+            .collect();
+
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::IoError("Unable to get schema from IPC Footer".to_string())
+        })?;
+        let schema = crate::convert::fb_to_schema(ipc_schema);
+        let swap_endianness = ipc_schema.endianness() != NATIVE_ENDIANNESS;
+
+        let projection = match projection {
+            Some(projection_indices) => {
+                let schema = schema.project(&projection_indices)?;
+                Some((projection_indices, schema))
+            }
+            _ => None,
+        };
+
+        let decoder = Self {
+            schema: Arc::new(schema),
+            dictionaries_by_id: HashMap::new(),
+            metadata_version: footer.version(),
+            projection,
+            swap_endianness,
+        };
+        Ok((decoder, blocks))
+    }
+
+    /// Read the dictionary batch of a dictionary block, given its message and body
+    fn read_dictionary(
+        &mut self,
+        message: crate::Message<'_>,
+        body: &Buffer,
+    ) -> Result<(), ArrowError> {
+        match message.header_type() {
+            crate::MessageHeader::DictionaryBatch => {
+                let batch = message.header_as_dictionary_batch().unwrap();
+                read_dictionary_impl(
+                    body,
+                    batch,
+                    &self.schema,
+                    &mut self.dictionaries_by_id,
+                    &message.version(),
+                    self.swap_endianness,
+                )
+            }
+            t => Err(ArrowError::IoError(format!(
+                "Expecting DictionaryBatch in dictionary blocks, found {t:?}."
+            ))),
+        }
+    }
+
+    /// Read the record batch of a record batch block, given its message and a
+    /// function that reads the body of the record batch message
+    fn read_record_batch(
+        &self,
+        message: crate::Message<'_>,
+        read_body: impl FnOnce(crate::RecordBatch<'_>) -> Result<Buffer, ArrowError>,
+    ) -> Result<Option<RecordBatch>, ArrowError> {
+        // some old test data's footer metadata is not set, so we account for that
+        if self.metadata_version != crate::MetadataVersion::V1
+            && message.version() != self.metadata_version
+        {
+            return Err(ArrowError::IoError(
+                "Could not read IPC message as metadata versions mismatch".to_string(),
+            ));
+        }
+
+        match message.header_type() {
+            crate::MessageHeader::Schema => Err(ArrowError::IoError(
+                "Not expecting a schema when messages are read".to_string(),
+            )),
+            crate::MessageHeader::RecordBatch => {
+                let batch = message.header_as_record_batch().ok_or_else(|| {
+                    ArrowError::IoError(
+                        "Unable to read IPC message as record batch".to_string(),
+                    )
+                })?;
+                let body = read_body(batch)?;
+                read_record_batch_impl(
+                    &body,
+                    batch,
+                    self.schema.clone(),
+                    &self.dictionaries_by_id,
+                    self.projection.as_ref().map(|x| x.0.as_ref()),
+                    &message.version(),
+                    self.swap_endianness,
+                )
+                .map(Some)
+            }
+            crate::MessageHeader::NONE => Ok(None),
+            t => Err(ArrowError::IoError(format!(
+                "Reading types other than record batches not yet supported, unable to read {t:?}"
+            ))),
+        }
+    }
+}
+
 /// Arrow File reader
 pub struct FileReader<R: Read + Seek> {
     /// Buffered file reader that supports reading and seeking
     reader: BufReader<R>,
 
-    /// The schema that is read from the file header
-    schema: SchemaRef,
+    /// Decodes the messages of the file
+    decoder: FileDecoder,
 
     /// The blocks in the file
     ///
@@ -730,30 +1036,20 @@ pub struct FileReader<R: Read + Seek> {
 
     /// The total number of blocks, which may contain record batches and other types
     total_blocks: usize,
-
-    /// Optional dictionaries for each schema field.
-    ///
-    /// Dictionaries may be appended to in the streaming format.
-    dictionaries_by_id: HashMap<i64, ArrayRef>,
-
-    /// Metadata version
-    metadata_version: crate::MetadataVersion,
-
-    /// Optional projection and projected_schema
-    projection: Option<(Vec<usize>, Schema)>,
 }
 
 impl<R: Read + Seek> fmt::Debug for FileReader<R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
         f.debug_struct("FileReader<R>")
             .field("reader", &"BufReader<..>")
-            .field("schema", &self.schema)
+            .field("schema", &self.decoder.schema)
             .field("blocks", &self.blocks)
             .field("current_block", &self.current_block)
             .field("total_blocks", &self.total_blocks)
-            .field("dictionaries_by_id", &self.dictionaries_by_id)
-            .field("metadata_version", &self.metadata_version)
-            .field("projection", &self.projection)
+            .field("dictionaries_by_id", &self.decoder.dictionaries_by_id)
+            .field("metadata_version", &self.decoder.metadata_version)
+            .field("projection", &self.decoder.projection)
+            .field("swap_endianness", &self.decoder.swap_endianness)
             .finish()
     }
 }
@@ -761,6 +1057,9 @@ impl<R: Read + Seek> fmt::Debug for FileReader<R> {
 impl<R: Read + Seek> FileReader<R> {
     /// Try to create a new file reader
     ///
+    /// If `projection` is provided, only the buffers of the projected fields are read
+    /// from `reader` for each record batch
+    ///
     /// Returns errors if the file does not meet the Arrow Format header and footer
     /// requirements
     pub fn try_new(
@@ -798,19 +1097,9 @@ impl<R: Read + Seek> FileReader<R> {
             ArrowError::IoError(format!("Unable to get root as footer: {err:?}"))
         })?;
 
-        let blocks = footer.recordBatches().ok_or_else(|| {
-            ArrowError::IoError(
-                "Unable to get record batches from IPC Footer".to_string(),
-            )
-        })?;
-
+        let (mut decoder, blocks) = FileDecoder::try_new(footer, projection)?;
         let total_blocks = blocks.len();
 
-        let ipc_schema = footer.schema().unwrap();
-        let schema = crate::convert::fb_to_schema(ipc_schema);
-
-        // Create an array of optional dictionary value arrays, one per field.
-        let mut dictionaries_by_id = HashMap::new();
         if let Some(dictionaries) = footer.dictionaries() {
             for block in dictionaries {
                 // read length from end of offset
@@ -825,55 +1114,26 @@ impl<R: Read + Seek> FileReader<R> {
 
                 reader.read_exact(&mut block_data)?;
 
-                let message = crate::root_as_message(&block_data[..]).map_err(|err| {
-                    ArrowError::IoError(format!("Unable to get root as message: {err:?}"))
-                })?;
+                let message = parse_message(&block_data)?;
 
-                match message.header_type() {
-                    crate::MessageHeader::DictionaryBatch => {
-                        let batch = message.header_as_dictionary_batch().unwrap();
-
-                        // read the block that makes up the dictionary batch into a buffer
-                        let mut buf =
-                            MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
-                        reader.seek(SeekFrom::Start(
-                            block.offset() as u64 + block.metaDataLength() as u64,
-                        ))?;
-                        reader.read_exact(&mut buf)?;
-
-                        read_dictionary(
-                            &buf.into(),
-                            batch,
-                            &schema,
-                            &mut dictionaries_by_id,
-                            &message.version(),
-                        )?;
-                    }
-                    t => {
-                        return Err(ArrowError::IoError(format!(
-                            "Expecting DictionaryBatch in dictionary blocks, found {t:?}."
-                        )));
-                    }
-                }
+                // read the block that makes up the dictionary batch into a buffer
+                let mut buf =
+                    MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
+                reader.seek(SeekFrom::Start(
+                    block.offset() as u64 + block.metaDataLength() as u64,
+                ))?;
+                reader.read_exact(&mut buf)?;
+
+                decoder.read_dictionary(message, &buf.into())?;
             }
         }
-        let projection = match projection {
-            Some(projection_indices) => {
-                let schema = schema.project(&projection_indices)?;
-                Some((projection_indices, schema))
-            }
-            _ => None,
-        };
 
         Ok(Self {
             reader,
-            schema: Arc::new(schema),
-            blocks: blocks.iter().copied().collect(),
+            decoder,
+            blocks,
             current_block: 0,
             total_blocks,
-            dictionaries_by_id,
-            metadata_version: footer.version(),
-            projection,
         })
     }
 
@@ -884,7 +1144,7 @@ impl<R: Read + Seek> FileReader<R> {
 
     /// Return the schema of the file
     pub fn schema(&self) -> SchemaRef {
-        self.schema.clone()
+        self.decoder.schema.clone()
     }
 
     /// Read a specific record batch
@@ -902,10 +1162,55 @@ impl<R: Read + Seek> FileReader<R> {
         }
     }
 
+    /// Return the blocks of the record batches in the file, as listed in its footer
+    ///
+    /// Each block records the offset and length of a record batch message, allowing
+    /// batches to be located without reading the file from the start
+    pub fn blocks(&self) -> &[crate::Block] {
+        &self.blocks
+    }
+
+    /// Read the record batch at `index`, without changing the position of the iterator
+    pub fn read_batch(&mut self, index: usize) -> Result<RecordBatch, ArrowError> {
+        let block = *self.blocks.get(index).ok_or_else(|| {
+            ArrowError::IoError(format!(
+                "Cannot read batch at index {} from {} total batches",
+                index, self.total_blocks
+            ))
+        })?;
+        self.read_block(&block)?.ok_or_else(|| {
+            ArrowError::IoError(format!("Block {index} does not contain a record batch"))
+        })
+    }
+
+    /// Return the custom metadata of the record batch message at `index`, which is
+    /// empty if the message has none
+    pub fn batch_custom_metadata(
+        &mut self,
+        index: usize,
+    ) -> Result<HashMap<String, String>, ArrowError> {
+        let block = *self.blocks.get(index).ok_or_else(|| {
+            ArrowError::IoError(format!(
+                "Cannot read batch at index {} from {} total batches",
+                index, self.total_blocks
+            ))
+        })?;
+        let block_data = self.read_block_metadata(&block)?;
+        let message = parse_message(&block_data)?;
+        Ok(crate::convert::fb_to_metadata(message.custom_metadata()))
+    }
+
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         let block = self.blocks[self.current_block];
         self.current_block += 1;
+        self.read_block(&block)
+    }
 
+    /// Read the flatbuffer metadata of the message in `block`
+    fn read_block_metadata(
+        &mut self,
+        block: &crate::Block,
+    ) -> Result<Vec<u8>, ArrowError> {
         // read length
         self.reader.seek(SeekFrom::Start(block.offset() as u64))?;
         let mut meta_buf = [0; 4];
@@ -918,53 +1223,46 @@ impl<R: Read + Seek> FileReader<R> {
 
         let mut block_data = vec![0; meta_len as usize];
         self.reader.read_exact(&mut block_data)?;
+        Ok(block_data)
+    }
+
+    fn read_block(
+        &mut self,
+        block: &crate::Block,
+    ) -> Result<Option<RecordBatch>, ArrowError> {
+        let block_data = self.read_block_metadata(block)?;
         let message = crate::root_as_message(&block_data[..]).map_err(|err| {
             ArrowError::IoError(format!("Unable to get root as footer: {err:?}"))
         })?;
 
-        // some old test data's footer metadata is not set, so we account for that
-        if self.metadata_version != crate::MetadataVersion::V1
-            && message.version() != self.metadata_version
-        {
-            return Err(ArrowError::IoError(
-                "Could not read IPC message as metadata versions mismatch".to_string(),
-            ));
-        }
-
-        match message.header_type() {
-            crate::MessageHeader::Schema => Err(ArrowError::IoError(
-                "Not expecting a schema when messages are read".to_string(),
-            )),
-            crate::MessageHeader::RecordBatch => {
-                let batch = message.header_as_record_batch().ok_or_else(|| {
-                    ArrowError::IoError(
-                        "Unable to read IPC message as record batch".to_string(),
-                    )
-                })?;
-                // read the block that makes up the record batch into a buffer
-                let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
-                self.reader.seek(SeekFrom::Start(
-                    block.offset() as u64 + block.metaDataLength() as u64,
-                ))?;
-                self.reader.read_exact(&mut buf)?;
-
-                read_record_batch(
-                    &buf.into(),
-                    batch,
-                    self.schema(),
-                    &self.dictionaries_by_id,
-                    self.projection.as_ref().map(|x| x.0.as_ref()),
-                    &message.version()
-
-                ).map(Some)
-            }
-            crate::MessageHeader::NONE => {
-                Ok(None)
+        let reader = &mut self.reader;
+        let decoder = &self.decoder;
+        decoder.read_record_batch(message, |batch| {
+            // read the block that makes up the record batch into a buffer
+            let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
+            let body_offset = block.offset() as u64 + block.metaDataLength() as u64;
+            match &decoder.projection {
+                Some((projection, _)) => {
+                    // only read the buffers of the projected fields
+                    let ranges = projected_body_ranges(batch, &decoder.schema, projection)?;
+                    for range in ranges {
+                        let region = buf.get_mut(range.clone()).ok_or_else(|| {
+                            ArrowError::IoError(format!(
+                                "Buffer range {range:?} exceeds IPC message body of length {}",
+                                message.bodyLength()
+                            ))
+                        })?;
+                        reader.seek(SeekFrom::Start(body_offset + range.start as u64))?;
+                        reader.read_exact(region)?;
+                    }
+                }
+                None => {
+                    reader.seek(SeekFrom::Start(body_offset))?;
+                    reader.read_exact(&mut buf)?;
+                }
             }
-            t => Err(ArrowError::IoError(format!(
-                "Reading types other than record batches not yet supported, unable to read {t:?}"
-            ))),
-        }
+            Ok(buf.into())
+        })
     }
 }
 
@@ -983,81 +1281,306 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
 
 impl<R: Read + Seek> RecordBatchReader for FileReader<R> {
     fn schema(&self) -> SchemaRef {
-        self.schema.clone()
+        self.decoder.schema.clone()
     }
 }
 
-/// Arrow Stream reader
-pub struct StreamReader<R: Read> {
-    /// Buffered stream reader
-    reader: BufReader<R>,
-
-    /// The schema that is read from the stream's first message
-    schema: SchemaRef,
+/// Arrow File reader for an IPC file that is already in memory
+///
+/// Unlike [`FileReader`], which copies each message body out of its reader, the
+/// arrays of the returned record batches reference `buffer` directly. This makes
+/// loading a memory mapped file, wrapped in a [`Buffer`] with
+/// [`Buffer::from_custom_allocation`], essentially free.
+///
+/// The footer and the bounds of every block are validated once, when the reader
+/// is created. A message body is only copied if it is not aligned to 8 bytes,
+/// or if it is compressed.
+pub struct BufferFileReader {
+    /// The IPC file
+    buffer: Buffer,
 
-    /// Optional dictionaries for each schema field.
-    ///
-    /// Dictionaries may be appended to in the streaming format.
-    dictionaries_by_id: HashMap<i64, ArrayRef>,
+    /// Decodes the messages of the file
+    decoder: FileDecoder,
 
-    /// An indicator of whether the stream is complete.
-    ///
-    /// This value is set to `true` the first time the reader's `next()` returns `None`.
-    finished: bool,
+    /// The record batch blocks in the file
+    blocks: Vec<crate::Block>,
 
-    /// Optional projection
-    projection: Option<(Vec<usize>, Schema)>,
+    /// A counter to keep track of the current block that should be read
+    current_block: usize,
 }
 
-impl<R: Read> fmt::Debug for StreamReader<R> {
+impl fmt::Debug for BufferFileReader {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
-        f.debug_struct("StreamReader<R>")
-            .field("reader", &"BufReader<..>")
-            .field("schema", &self.schema)
-            .field("dictionaries_by_id", &self.dictionaries_by_id)
-            .field("finished", &self.finished)
-            .field("projection", &self.projection)
+        f.debug_struct("BufferFileReader")
+            .field(
+                "buffer",
+                &format_args!("Buffer({} bytes)", self.buffer.len()),
+            )
+            .field("schema", &self.decoder.schema)
+            .field("blocks", &self.blocks)
+            .field("current_block", &self.current_block)
+            .field("dictionaries_by_id", &self.decoder.dictionaries_by_id)
+            .field("metadata_version", &self.decoder.metadata_version)
+            .field("projection", &self.decoder.projection)
+            .field("swap_endianness", &self.decoder.swap_endianness)
             .finish()
     }
 }
 
-impl<R: Read> StreamReader<R> {
-    /// Try to create a new stream reader
+impl BufferFileReader {
+    /// Try to create a new reader for the IPC file contained in `buffer`
     ///
-    /// The first message in the stream is the schema, the reader will fail if it does not
-    /// encounter a schema.
-    /// To check if the reader is done, use `is_finished(self)`
+    /// Returns errors if the file does not meet the Arrow Format header and footer
+    /// requirements, or if any of its blocks lie outside of `buffer`
     pub fn try_new(
-        reader: R,
+        buffer: Buffer,
         projection: Option<Vec<usize>>,
     ) -> Result<Self, ArrowError> {
-        let mut reader = BufReader::new(reader);
-        // determine metadata length
-        let mut meta_size: [u8; 4] = [0; 4];
-        reader.read_exact(&mut meta_size)?;
-        let meta_len = {
-            // If a continuation marker is encountered, skip over it and read
-            // the size from the next four bytes.
-            if meta_size == CONTINUATION_MARKER {
-                reader.read_exact(&mut meta_size)?;
-            }
-            i32::from_le_bytes(meta_size)
-        };
+        let magic_len = super::ARROW_MAGIC.len();
+        let len = buffer.len();
+        if len < 2 * magic_len + 4 || buffer[..magic_len] != super::ARROW_MAGIC {
+            return Err(ArrowError::IoError(
+                "Arrow file does not contain correct header".to_string(),
+            ));
+        }
+        if buffer[len - magic_len..] != super::ARROW_MAGIC {
+            return Err(ArrowError::IoError(
+                "Arrow file does not contain correct footer".to_string(),
+            ));
+        }
 
-        let mut meta_buffer = vec![0; meta_len as usize];
-        reader.read_exact(&mut meta_buffer)?;
+        // read footer
+        let footer_end = len - magic_len - 4;
+        let footer_len =
+            i32::from_le_bytes(buffer[footer_end..footer_end + 4].try_into().unwrap());
+        let footer_start = usize::try_from(footer_len)
+            .ok()
+            .and_then(|footer_len| footer_end.checked_sub(footer_len))
+            .ok_or_else(|| {
+                ArrowError::IoError(format!("Invalid IPC footer length {footer_len}"))
+            })?;
+        let footer =
+            crate::root_as_footer(&buffer[footer_start..footer_end]).map_err(|err| {
+                ArrowError::IoError(format!("Unable to get root as footer: {err:?}"))
+            })?;
+
+        let (mut decoder, blocks) = FileDecoder::try_new(footer, projection)?;
+
+        // validate the blocks up front, so that reading them cannot go out of bounds,
+        // the dictionary blocks are validated as they are read
+        for block in &blocks {
+            block_message(&buffer, block, footer_start)?;
+        }
+        for block in footer.dictionaries().into_iter().flatten() {
+            let (message, body) = block_message(&buffer, block, footer_start)?;
+            decoder.read_dictionary(message, &body)?;
+        }
+
+        Ok(Self {
+            decoder,
+            blocks,
+            current_block: 0,
+            buffer,
+        })
+    }
 
-        let message = crate::root_as_message(meta_buffer.as_slice()).map_err(|err| {
-            ArrowError::IoError(format!("Unable to get root as message: {err:?}"))
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+
+    /// Read a specific record batch
+    ///
+    /// Sets the current block to the index, allowing random reads
+    pub fn set_index(&mut self, index: usize) -> Result<(), ArrowError> {
+        if index >= self.blocks.len() {
+            Err(ArrowError::IoError(format!(
+                "Cannot set batch to index {} from {} total batches",
+                index,
+                self.blocks.len()
+            )))
+        } else {
+            self.current_block = index;
+            Ok(())
+        }
+    }
+
+    /// Return the blocks of the record batches in the file, as listed in its footer
+    ///
+    /// Each block records the offset and length of a record batch message, allowing
+    /// batches to be located without reading the file from the start
+    pub fn blocks(&self) -> &[crate::Block] {
+        &self.blocks
+    }
+
+    /// Read the record batch at `index`, without changing the position of the iterator
+    pub fn read_batch(&self, index: usize) -> Result<RecordBatch, ArrowError> {
+        let block = self.blocks.get(index).ok_or_else(|| {
+            ArrowError::IoError(format!(
+                "Cannot read batch at index {} from {} total batches",
+                index,
+                self.blocks.len()
+            ))
+        })?;
+        self.read_block(block)?.ok_or_else(|| {
+            ArrowError::IoError(format!("Block {index} does not contain a record batch"))
+        })
+    }
+
+    /// Return the custom metadata of the record batch message at `index`, which is
+    /// empty if the message has none
+    pub fn batch_custom_metadata(
+        &self,
+        index: usize,
+    ) -> Result<HashMap<String, String>, ArrowError> {
+        let block = self.blocks.get(index).ok_or_else(|| {
+            ArrowError::IoError(format!(
+                "Cannot read batch at index {} from {} total batches",
+                index,
+                self.blocks.len()
+            ))
         })?;
+        let (message, _) = block_message(&self.buffer, block, self.buffer.len())?;
+        Ok(crate::convert::fb_to_metadata(message.custom_metadata()))
+    }
+
+    fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let block = self.blocks[self.current_block];
+        self.current_block += 1;
+        self.read_block(&block)
+    }
+
+    fn read_block(
+        &self,
+        block: &crate::Block,
+    ) -> Result<Option<RecordBatch>, ArrowError> {
+        let (message, body) = block_message(&self.buffer, block, self.buffer.len())?;
+        self.decoder.read_record_batch(message, |_| Ok(body))
+    }
+}
+
+impl Iterator for BufferFileReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_block < self.blocks.len() {
+            self.maybe_next().transpose()
+        } else {
+            None
+        }
+    }
+}
+
+impl RecordBatchReader for BufferFileReader {
+    fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+}
+
+/// Returns the message of an IPC file block within `buffer`, and its body
+///
+/// The body is a slice of `buffer`, unless it is not aligned to 8 bytes, in which
+/// case it is copied. Returns an error if the block is not contained in `buffer[..end]`
+fn block_message<'a>(
+    buffer: &'a Buffer,
+    block: &crate::Block,
+    end: usize,
+) -> Result<(crate::Message<'a>, Buffer), ArrowError> {
+    let out_of_bounds = || {
+        ArrowError::IoError(format!(
+            "IPC block at offset {} exceeds file of length {end}",
+            block.offset()
+        ))
+    };
+    let checked_range = |start: usize, len: usize| {
+        start
+            .checked_add(len)
+            .filter(|stop| *stop <= end)
+            .map(|stop| start..stop)
+            .ok_or_else(out_of_bounds)
+    };
+
+    let offset = usize::try_from(block.offset()).map_err(|_| out_of_bounds())?;
+    let meta_len =
+        usize::try_from(block.metaDataLength()).map_err(|_| out_of_bounds())?;
+    let meta = &buffer[checked_range(offset, meta_len)?];
+
+    // skip the optional continuation marker and the message length
+    let prefix_len = match meta.get(..4) {
+        Some(prefix) if prefix == CONTINUATION_MARKER => 8,
+        _ => 4,
+    };
+    let message = parse_message(meta.get(prefix_len..).ok_or_else(out_of_bounds)?)?;
+
+    let body_len = usize::try_from(message.bodyLength()).map_err(|_| out_of_bounds())?;
+    let body_range = checked_range(offset + meta_len, body_len)?;
+    let body = buffer.slice_with_length(body_range.start, body_len);
+    let body = match body.as_ptr().align_offset(8) {
+        0 => body,
+        _ => Buffer::from_slice_ref(body.as_slice()),
+    };
+    Ok((message, body))
+}
+
+/// Parses the flatbuffer metadata of an IPC message
+pub(crate) fn parse_message(
+    meta_buffer: &[u8],
+) -> Result<crate::Message<'_>, ArrowError> {
+    crate::root_as_message(meta_buffer).map_err(|err| {
+        ArrowError::IoError(format!("Unable to get root as message: {err:?}"))
+    })
+}
+
+/// The result of decoding an IPC stream message with [`StreamDecoder::decode_message`]
+pub(crate) enum StreamMessage {
+    /// A record batch was decoded
+    RecordBatch(RecordBatch),
+    /// A dictionary batch was decoded, and the dictionaries updated
+    Dictionary,
+    /// The message indicates the end of the stream
+    End,
+}
+
+/// Decodes the messages of an IPC stream, shared by the synchronous [`StreamReader`]
+/// and the asynchronous stream reader
+#[derive(Debug)]
+pub(crate) struct StreamDecoder {
+    /// The schema that is read from the stream's first message
+    schema: SchemaRef,
+
+    /// Optional dictionaries for each schema field.
+    ///
+    /// Dictionaries may be appended to in the streaming format.
+    dictionaries_by_id: HashMap<i64, ArrayRef>,
+
+    /// Optional projection
+    projection: Option<(Vec<usize>, Schema)>,
+
+    /// Whether the data is not of native endianness, and must be byte swapped
+    swap_endianness: bool,
+
+    /// The custom metadata of the most recently decoded record batch message
+    custom_metadata: HashMap<String, String>,
+}
+
+impl StreamDecoder {
+    /// Create a decoder from the metadata of the schema message that starts a stream
+    pub(crate) fn try_new(
+        meta_buffer: &[u8],
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let message = parse_message(meta_buffer)?;
         // message header is a Schema, so read it
         let ipc_schema: crate::Schema = message.header_as_schema().ok_or_else(|| {
             ArrowError::IoError("Unable to read IPC message as schema".to_string())
         })?;
         let schema = crate::convert::fb_to_schema(ipc_schema);
-
-        // Create an array of optional dictionary value arrays, one per field.
-        let dictionaries_by_id = HashMap::new();
+        let swap_endianness = ipc_schema.endianness() != NATIVE_ENDIANNESS;
 
         let projection = match projection {
             Some(projection_indices) => {
@@ -1067,24 +1590,154 @@ impl<R: Read> StreamReader<R> {
             _ => None,
         };
         Ok(Self {
-            reader,
             schema: Arc::new(schema),
-            finished: false,
-            dictionaries_by_id,
+            // Create an array of optional dictionary value arrays, one per field.
+            dictionaries_by_id: HashMap::new(),
             projection,
+            swap_endianness,
+            custom_metadata: HashMap::new(),
         })
     }
 
     /// Return the schema of the stream
-    pub fn schema(&self) -> SchemaRef {
+    pub(crate) fn schema(&self) -> SchemaRef {
         self.schema.clone()
     }
 
+    /// Return the custom metadata of the most recently decoded record batch message
+    pub(crate) fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.custom_metadata
+    }
+
+    /// Decode a message following the schema, given its metadata and body
+    pub(crate) fn decode_message(
+        &mut self,
+        message: crate::Message<'_>,
+        body: Buffer,
+    ) -> Result<StreamMessage, ArrowError> {
+        match message.header_type() {
+            crate::MessageHeader::Schema => Err(ArrowError::IoError(
+                "Not expecting a schema when messages are read".to_string(),
+            )),
+            crate::MessageHeader::RecordBatch => {
+                let batch = message.header_as_record_batch().ok_or_else(|| {
+                    ArrowError::IoError(
+                        "Unable to read IPC message as record batch".to_string(),
+                    )
+                })?;
+                let record_batch = read_record_batch_impl(
+                    &body,
+                    batch,
+                    self.schema(),
+                    &self.dictionaries_by_id,
+                    self.projection.as_ref().map(|x| x.0.as_ref()),
+                    &message.version(),
+                    self.swap_endianness,
+                )?;
+                self.custom_metadata =
+                    crate::convert::fb_to_metadata(message.custom_metadata());
+                Ok(StreamMessage::RecordBatch(record_batch))
+            }
+            crate::MessageHeader::DictionaryBatch => {
+                let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                    ArrowError::IoError(
+                        "Unable to read IPC message as dictionary batch".to_string(),
+                    )
+                })?;
+                read_dictionary_impl(
+                    &body,
+                    batch,
+                    &self.schema,
+                    &mut self.dictionaries_by_id,
+                    &message.version(),
+                    self.swap_endianness,
+                )?;
+                Ok(StreamMessage::Dictionary)
+            }
+            crate::MessageHeader::NONE => Ok(StreamMessage::End),
+            t => Err(ArrowError::IoError(format!(
+                "Reading types other than record batches not yet supported, unable to read {t:?} "
+            ))),
+        }
+    }
+}
+
+/// Arrow Stream reader
+pub struct StreamReader<R: Read> {
+    /// Buffered stream reader
+    reader: BufReader<R>,
+
+    /// Decodes the messages of the stream
+    decoder: StreamDecoder,
+
+    /// An indicator of whether the stream is complete.
+    ///
+    /// This value is set to `true` the first time the reader's `next()` returns `None`.
+    finished: bool,
+}
+
+impl<R: Read> fmt::Debug for StreamReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
+        f.debug_struct("StreamReader<R>")
+            .field("reader", &"BufReader<..>")
+            .field("schema", &self.decoder.schema)
+            .field("dictionaries_by_id", &self.decoder.dictionaries_by_id)
+            .field("finished", &self.finished)
+            .field("projection", &self.decoder.projection)
+            .finish()
+    }
+}
+
+impl<R: Read> StreamReader<R> {
+    /// Try to create a new stream reader
+    ///
+    /// The first message in the stream is the schema, the reader will fail if it does not
+    /// encounter a schema.
+    /// To check if the reader is done, use `is_finished(self)`
+    pub fn try_new(
+        reader: R,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let mut reader = BufReader::new(reader);
+        // determine metadata length
+        let mut meta_size: [u8; 4] = [0; 4];
+        reader.read_exact(&mut meta_size)?;
+        let meta_len = {
+            // If a continuation marker is encountered, skip over it and read
+            // the size from the next four bytes.
+            if meta_size == CONTINUATION_MARKER {
+                reader.read_exact(&mut meta_size)?;
+            }
+            i32::from_le_bytes(meta_size)
+        };
+
+        let mut meta_buffer = vec![0; meta_len as usize];
+        reader.read_exact(&mut meta_buffer)?;
+
+        let decoder = StreamDecoder::try_new(&meta_buffer, projection)?;
+        Ok(Self {
+            reader,
+            decoder,
+            finished: false,
+        })
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema()
+    }
+
     /// Check if the stream is finished
     pub fn is_finished(&self) -> bool {
         self.finished
     }
 
+    /// Return the custom metadata of the most recently read record batch message,
+    /// which is empty if the message has none
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        self.decoder.custom_metadata()
+    }
+
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         if self.finished {
             return Ok(None);
@@ -1124,51 +1777,17 @@ impl<R: Read> StreamReader<R> {
 
         let mut meta_buffer = vec![0; meta_len as usize];
         self.reader.read_exact(&mut meta_buffer)?;
+        let message = parse_message(&meta_buffer)?;
 
-        let vecs = &meta_buffer.to_vec();
-        let message = crate::root_as_message(vecs).map_err(|err| {
-            ArrowError::IoError(format!("Unable to get root as message: {err:?}"))
-        })?;
-
-        match message.header_type() {
-            crate::MessageHeader::Schema => Err(ArrowError::IoError(
-                "Not expecting a schema when messages are read".to_string(),
-            )),
-            crate::MessageHeader::RecordBatch => {
-                let batch = message.header_as_record_batch().ok_or_else(|| {
-                    ArrowError::IoError(
-                        "Unable to read IPC message as record batch".to_string(),
-                    )
-                })?;
-                // read the block that makes up the record batch into a buffer
-                let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
-                self.reader.read_exact(&mut buf)?;
+        // read the block that makes up the message body into a buffer
+        let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
+        self.reader.read_exact(&mut buf)?;
 
-                read_record_batch(&buf.into(), batch, self.schema(), &self.dictionaries_by_id, self.projection.as_ref().map(|x| x.0.as_ref()), &message.version()).map(Some)
-            }
-            crate::MessageHeader::DictionaryBatch => {
-                let batch = message.header_as_dictionary_batch().ok_or_else(|| {
-                    ArrowError::IoError(
-                        "Unable to read IPC message as dictionary batch".to_string(),
-                    )
-                })?;
-                // read the block that makes up the dictionary batch into a buffer
-                let mut buf = MutableBuffer::from_len_zeroed(message.bodyLength() as usize);
-                self.reader.read_exact(&mut buf)?;
-
-                read_dictionary(
-                    &buf.into(), batch, &self.schema, &mut self.dictionaries_by_id, &message.version()
-                )?;
-
-                // read the next message until we encounter a RecordBatch
-                self.maybe_next()
-            }
-            crate::MessageHeader::NONE => {
-                Ok(None)
-            }
-            t => Err(ArrowError::IoError(
-                format!("Reading types other than record batches not yet supported, unable to read {t:?} ")
-            )),
+        match self.decoder.decode_message(message, buf.into())? {
+            StreamMessage::RecordBatch(batch) => Ok(Some(batch)),
+            // read the next message until we encounter a RecordBatch
+            StreamMessage::Dictionary => self.maybe_next(),
+            StreamMessage::End => Ok(None),
         }
     }
 }
@@ -1183,7 +1802,7 @@ impl<R: Read> Iterator for StreamReader<R> {
 
 impl<R: Read> RecordBatchReader for StreamReader<R> {
     fn schema(&self) -> SchemaRef {
-        self.schema.clone()
+        self.decoder.schema()
     }
 }
 
@@ -1422,6 +2041,84 @@ mod tests {
         });
     }
 
+    /// A [`Read`] + [`Seek`] implementation that counts the bytes read
+    struct CountingReader<R> {
+        inner: R,
+        bytes_read: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl<R: Read> Read for CountingReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let read = self.inner.read(buf)?;
+            self.bytes_read
+                .fetch_add(read, std::sync::atomic::Ordering::Relaxed);
+            Ok(read)
+        }
+    }
+
+    impl<R: Seek> Seek for CountingReader<R> {
+        fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn test_projection_file_reader_reads_projected_buffers() {
+        let a = Int64Array::from_iter_values(0..100_000);
+        let b = StringArray::from_iter_values((0..100_000).map(|i| format!("v{i}")));
+        let c = Float64Array::from_iter_values((0..100_000).map(|i| i as f64));
+        let batch = RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+            ("c", Arc::new(c) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::FileWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let bytes_read = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let reader = CountingReader {
+            inner: std::io::Cursor::new(&buf),
+            bytes_read: bytes_read.clone(),
+        };
+        let mut reader = FileReader::try_new(reader, Some(vec![2, 0])).unwrap();
+        let read = reader.next().unwrap().unwrap();
+        assert!(reader.next().is_none());
+
+        assert_eq!(read.schema().fields()[0].name(), "c");
+        assert_eq!(read.schema().fields()[1].name(), "a");
+        assert_eq!(read.column(0).as_ref(), batch.column(2).as_ref());
+        assert_eq!(read.column(1).as_ref(), batch.column(0).as_ref());
+
+        // The string column is not read
+        let bytes_read = bytes_read.load(std::sync::atomic::Ordering::Relaxed);
+        assert!(
+            bytes_read < buf.len() * 3 / 4,
+            "read {bytes_read} of {} bytes",
+            buf.len()
+        );
+
+        // Projection order is also respected by the stream reader
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::StreamWriter::try_new(&mut buf, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader =
+            StreamReader::try_new(std::io::Cursor::new(buf), Some(vec![1, 0])).unwrap();
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read.schema().fields()[0].name(), "b");
+        assert_eq!(read.column(0).as_ref(), batch.column(1).as_ref());
+        assert_eq!(read.column(1).as_ref(), batch.column(0).as_ref());
+    }
+
     fn roundtrip_ipc(rb: &RecordBatch) -> RecordBatch {
         let mut buf = Vec::new();
         let mut writer =
@@ -1449,6 +2146,317 @@ mod tests {
         reader.next().unwrap().unwrap()
     }
 
+    #[test]
+    fn test_buffer_file_reader_zero_copy() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new(
+                "b",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                true,
+            ),
+        ]));
+        let batches: Vec<_> = (0..2)
+            .map(|i| {
+                let a = Int32Array::from(vec![Some(i), None, Some(i + 2)]);
+                let b: DictionaryArray<Int32Type> =
+                    vec!["x", "y", "x"].into_iter().collect();
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)])
+                    .unwrap()
+            })
+            .collect();
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::FileWriter::try_new(&mut buf, &schema).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let buffer = Buffer::from_slice_ref(&buf);
+        let reader = BufferFileReader::try_new(buffer.clone(), None).unwrap();
+        assert_eq!(reader.num_batches(), 2);
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(read, batches);
+
+        // the values reference the file buffer
+        let file_range =
+            buffer.as_ptr() as usize..buffer.as_ptr() as usize + buffer.len();
+        for batch in &read {
+            let values = batch.column(0).data().buffers()[0].as_ptr() as usize;
+            assert!(file_range.contains(&values));
+        }
+
+        let mut reader = BufferFileReader::try_new(buffer, Some(vec![1])).unwrap();
+        reader.set_index(1).unwrap();
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read, batches[1].project(&[1]).unwrap());
+        assert!(reader.next().is_none());
+
+        // a misaligned file is copied rather than referenced
+        let mut padded = vec![0_u8];
+        padded.extend_from_slice(&buf);
+        let buffer = Buffer::from_slice_ref(&padded).slice(1);
+        let reader = BufferFileReader::try_new(buffer, None).unwrap();
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(read, batches);
+
+        let truncated = Buffer::from_slice_ref(&buf[..buf.len() - 1]);
+        let err = BufferFileReader::try_new(truncated, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Arrow file does not contain correct footer"
+        );
+    }
+
+    #[test]
+    fn test_file_reader_read_batch() {
+        let batches: Vec<_> = (0..3)
+            .map(|i| {
+                let a: ArrayRef = Arc::new(Int32Array::from(vec![i; i as usize + 1]));
+                RecordBatch::try_from_iter([("a", a)]).unwrap()
+            })
+            .collect();
+
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::FileWriter::try_new(&mut buf, &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let mut reader = FileReader::try_new(std::io::Cursor::new(&buf), None).unwrap();
+        let blocks = reader.blocks();
+        assert_eq!(blocks.len(), 3);
+        assert!(blocks.windows(2).all(|w| w[0].offset() < w[1].offset()));
+
+        assert_eq!(reader.read_batch(2).unwrap(), batches[2]);
+        assert_eq!(reader.read_batch(0).unwrap(), batches[0]);
+        let err = reader.read_batch(3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Cannot read batch at index 3 from 3 total batches"
+        );
+        // random access does not affect iteration
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(read, batches);
+
+        let reader =
+            BufferFileReader::try_new(Buffer::from_slice_ref(&buf), None).unwrap();
+        assert_eq!(reader.blocks().len(), 3);
+        assert_eq!(reader.read_batch(1).unwrap(), batches[1]);
+    }
+
+    #[test]
+    fn test_roundtrip_non_native_endianness() {
+        let non_native = match NATIVE_ENDIANNESS {
+            crate::Endianness::Little => crate::Endianness::Big,
+            _ => crate::Endianness::Little,
+        };
+
+        let mut union = UnionBuilder::new_dense();
+        union.append::<Int32Type>("a", 0x01020304).unwrap();
+        union.append::<Float64Type>("b", 2.5).unwrap();
+        union.append_null::<Int32Type>("a").unwrap();
+        let list = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(-2)]),
+            None,
+            Some(vec![]),
+        ]);
+        let dict: DictionaryArray<Int16Type> = vec!["x", "y", "x"].into_iter().collect();
+        let columns: Vec<(&str, ArrayRef)> = vec![
+            ("i32", Arc::new(Int32Array::from(vec![0x01020304, -1, 3]))),
+            (
+                "u16",
+                Arc::new(UInt16Array::from(vec![Some(1), None, Some(300)])),
+            ),
+            (
+                "f64",
+                Arc::new(Float64Array::from(vec![1.5, -0.0, f64::MAX])),
+            ),
+            (
+                "decimal",
+                Arc::new(
+                    Decimal128Array::from(vec![123_i128, -456, 789])
+                        .with_precision_and_scale(10, 2)
+                        .unwrap(),
+                ),
+            ),
+            (
+                "interval",
+                Arc::new(IntervalMonthDayNanoArray::from(vec![
+                    IntervalMonthDayNanoType::make_value(1, 2, 3),
+                    IntervalMonthDayNanoType::make_value(-1, 0, 1 << 40),
+                    IntervalMonthDayNanoType::make_value(0, 5, 0),
+                ])),
+            ),
+            ("utf8", Arc::new(StringArray::from(vec!["a", "bc", "def"]))),
+            (
+                "large_utf8",
+                Arc::new(LargeStringArray::from(vec!["gh", "", "i"])),
+            ),
+            ("list", Arc::new(list)),
+            ("dict", Arc::new(dict)),
+            ("union", Arc::new(union.build().unwrap())),
+        ];
+        let batch = RecordBatch::try_from_iter(columns).unwrap();
+
+        let options =
+            crate::writer::IpcWriteOptions::default().with_endianness(non_native);
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::FileWriter::try_new_with_options(
+            &mut buf,
+            &batch.schema(),
+            options.clone(),
+        )
+        .unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        // the data is written in non-native byte order
+        let value = 0x01020304_i32.swap_bytes().to_ne_bytes();
+        assert!(buf.windows(4).any(|w| w == value));
+
+        let reader = FileReader::try_new(std::io::Cursor::new(&buf), None).unwrap();
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(read, vec![batch.clone()]);
+
+        let reader =
+            BufferFileReader::try_new(Buffer::from_slice_ref(&buf), None).unwrap();
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(read, vec![batch.clone()]);
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::StreamWriter::try_new_with_options(
+            &mut buf,
+            &batch.schema(),
+            options,
+        )
+        .unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let reader = StreamReader::try_new(buf.as_slice(), None).unwrap();
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(read, vec![batch]);
+    }
+
+    #[test]
+    fn test_roundtrip_delta_dictionary() {
+        let first: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let second: DictionaryArray<Int32Type> =
+            vec!["a", "b", "c", "c"].into_iter().collect();
+        let third: DictionaryArray<Int32Type> = vec!["x", "y"].into_iter().collect();
+        let batches: Vec<_> = [first, second, third]
+            .into_iter()
+            .map(|array| {
+                RecordBatch::try_from_iter(vec![("dict", Arc::new(array) as ArrayRef)])
+                    .unwrap()
+            })
+            .collect();
+        let schema = batches[0].schema();
+        let options = crate::writer::IpcWriteOptions::default()
+            .with_dictionary_handling(crate::writer::DictionaryHandling::Delta);
+
+        // The second dictionary extends the first, the third replaces it
+        let data_gen = crate::writer::IpcDataGenerator::default();
+        let mut tracker = crate::writer::DictionaryTracker::new(false);
+        let is_delta: Vec<Vec<bool>> = batches
+            .iter()
+            .map(|batch| {
+                let (dictionaries, _) = data_gen
+                    .encoded_batch(batch, &mut tracker, &options)
+                    .unwrap();
+                dictionaries
+                    .iter()
+                    .map(|encoded| {
+                        let message =
+                            crate::root_as_message(&encoded.ipc_message).unwrap();
+                        message.header_as_dictionary_batch().unwrap().isDelta()
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(is_delta, vec![vec![false], vec![true], vec![false]]);
+
+        let mut buf = Vec::new();
+        let mut writer = crate::writer::StreamWriter::try_new_with_options(
+            &mut buf,
+            &schema,
+            options.clone(),
+        )
+        .unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let reader = StreamReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+        let read: Vec<_> = reader.map(|batch| batch.unwrap()).collect();
+        assert_eq!(read, batches);
+
+        // Delta dictionaries are also supported by the file format
+        let mut buf = Vec::new();
+        let mut writer =
+            crate::writer::FileWriter::try_new_with_options(&mut buf, &schema, options)
+                .unwrap();
+        for batch in &batches[..2] {
+            writer.write(batch).unwrap();
+        }
+        writer.finish().unwrap();
+        drop(writer);
+
+        let reader = FileReader::try_new(std::io::Cursor::new(buf), None).unwrap();
+        let read: Vec<_> = reader.map(|batch| batch.unwrap()).collect();
+        assert_eq!(read, &batches[..2]);
+    }
+
+    #[test]
+    fn test_append_nested_dictionary_values() {
+        let nested = |values: Vec<String>| -> ArrayRef {
+            let inner: DictionaryArray<Int8Type> =
+                values.iter().map(String::as_str).collect();
+            let keys = Int32Array::from_iter_values(0..values.len() as i32);
+            Arc::new(
+                DictionaryArray::<Int32Type>::try_new(
+                    &keys,
+                    &(Arc::new(inner) as ArrayRef),
+                )
+                .unwrap(),
+            )
+        };
+        let strings = |range: std::ops::Range<usize>| -> Vec<String> {
+            range.map(|i| i.to_string()).collect()
+        };
+
+        let existing = nested(strings(0..100));
+        let delta = nested(strings(100..128));
+        let appended = append_dictionary_values(existing.data(), delta.data()).unwrap();
+        let appended = arrow_array::cast::as_dictionary_array::<Int32Type>(&appended);
+        let inner = arrow_array::cast::as_dictionary_array::<Int8Type>(appended.values());
+        let values: Vec<_> = inner
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .map(|v| v.unwrap().to_string())
+            .collect();
+        assert_eq!(values, strings(0..128));
+
+        // the concatenated nested dictionary cannot be represented with Int8 keys
+        let delta = nested(strings(100..129));
+        let err = append_dictionary_values(existing.data(), delta.data()).unwrap_err();
+        assert!(
+            matches!(err, ArrowError::DictionaryKeyOverflowError),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_roundtrip_nested_dict() {
         let inner: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
@@ -0,0 +1,1152 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading and writing of the IPC `Tensor` and `SparseTensor` messages
+//!
+//! A tensor is written as a single encapsulated message, as by pyarrow's
+//! `write_tensor` and `read_tensor`. Only tensors of integer and floating point
+//! values are supported.
+//!
+//! ```
+//! # use arrow_buffer::Buffer;
+//! # use arrow_ipc::tensor::{read_tensor, write_tensor, IpcTensor};
+//! # use arrow_ipc::writer::IpcWriteOptions;
+//! # use arrow_schema::DataType;
+//! // A 2x3 matrix of 32-bit floats, in row-major order
+//! let values = Buffer::from_slice_ref([1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0]);
+//! let tensor = IpcTensor::try_new(DataType::Float32, values, vec![2, 3], None, None).unwrap();
+//!
+//! let mut buffer = vec![];
+//! write_tensor(&mut buffer, &tensor, &IpcWriteOptions::default()).unwrap();
+//! let read = read_tensor(&mut buffer.as_slice()).unwrap();
+//! assert_eq!(read, tensor);
+//! ```
+
+use std::io::{Read, Write};
+
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+use arrow_buffer::Buffer;
+use arrow_schema::{ArrowError, DataType};
+
+use crate::convert::get_fb_field_type;
+use crate::endian::NATIVE_ENDIANNESS;
+use crate::reader::parse_message;
+use crate::writer::{write_message, EncodedData, IpcWriteOptions};
+use crate::CONTINUATION_MARKER;
+
+/// A dense tensor, as exchanged in an IPC `Tensor` message
+#[derive(Debug, Clone)]
+pub struct IpcTensor {
+    data_type: DataType,
+    buffer: Buffer,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    names: Option<Vec<String>>,
+}
+
+impl IpcTensor {
+    /// Create a new tensor of `data_type` values stored in `buffer`
+    ///
+    /// `strides` are in bytes, and default to the strides of a row-major tensor.
+    /// Returns an error if the values are not integers or floating point numbers,
+    /// or if `buffer` is too small for the shape and strides
+    pub fn try_new(
+        data_type: DataType,
+        buffer: Buffer,
+        shape: Vec<usize>,
+        strides: Option<Vec<usize>>,
+        names: Option<Vec<String>>,
+    ) -> Result<Self, ArrowError> {
+        let width = value_width(&data_type)?;
+        let strides = match strides {
+            Some(strides) if strides.len() != shape.len() => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Tensor has {} strides for {} dimensions",
+                    strides.len(),
+                    shape.len()
+                )))
+            }
+            Some(strides) => strides,
+            None => row_major_strides(&shape, width)?,
+        };
+        check_names(&names, &shape)?;
+
+        // the offset of the last value, if the tensor is not empty
+        let required = match shape.contains(&0) {
+            true => Some(0),
+            false => shape
+                .iter()
+                .zip(&strides)
+                .try_fold(width, |acc, (dim, stride)| {
+                    (dim - 1).checked_mul(*stride)?.checked_add(acc)
+                }),
+        };
+        let required = required.ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Tensor of shape {shape:?} with strides {strides:?} overflows usize"
+            ))
+        })?;
+        if buffer.len() < required {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Tensor buffer of {} bytes is too small for {} bytes of values",
+                buffer.len(),
+                required
+            )));
+        }
+
+        Ok(Self {
+            data_type,
+            buffer,
+            shape,
+            strides,
+            names,
+        })
+    }
+
+    /// The type of the tensor's values
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The buffer containing the tensor's values
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// The size of each of the tensor's dimensions
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// The number of bytes between consecutive values of each dimension
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    /// The names of the tensor's dimensions, if any
+    pub fn names(&self) -> Option<&[String]> {
+        self.names.as_deref()
+    }
+}
+
+/// The axis that is compressed in a [`SparseIndex::Csx`] index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressedAxis {
+    /// Compressed sparse row (CSR) format
+    Row,
+    /// Compressed sparse column (CSC) format
+    Column,
+}
+
+/// The location of the non-zero values of an [`IpcSparseTensor`]
+#[derive(Debug, Clone)]
+pub enum SparseIndex {
+    /// Coordinate (COO) format, a row-major `[non_zero_length, ndim]` matrix holding
+    /// the coordinates of each value
+    Coo {
+        /// The integer type of the coordinates
+        indices_type: DataType,
+        /// The coordinates of the values
+        indices: Buffer,
+        /// Whether the coordinates are sorted and unique
+        is_canonical: bool,
+    },
+    /// Compressed sparse row (CSR) or column (CSC) format of a matrix
+    Csx {
+        /// The compressed axis
+        compressed_axis: CompressedAxis,
+        /// The integer type of `indptr`
+        indptr_type: DataType,
+        /// The offsets into `indices` of each row (or column)
+        indptr: Buffer,
+        /// The integer type of `indices`
+        indices_type: DataType,
+        /// The column (or row) of each value
+        indices: Buffer,
+    },
+}
+
+/// A sparse tensor, as exchanged in an IPC `SparseTensor` message
+#[derive(Debug, Clone)]
+pub struct IpcSparseTensor {
+    data_type: DataType,
+    data: Buffer,
+    shape: Vec<usize>,
+    names: Option<Vec<String>>,
+    non_zero_length: usize,
+    index: SparseIndex,
+}
+
+impl IpcSparseTensor {
+    /// Create a new sparse tensor, with `non_zero_length` values of `data_type`
+    /// stored contiguously in `data`, at the locations described by `index`
+    ///
+    /// Returns an error if the values are not integers or floating point numbers,
+    /// if the index is not of integers, or if any buffer is too small
+    pub fn try_new(
+        data_type: DataType,
+        data: Buffer,
+        shape: Vec<usize>,
+        names: Option<Vec<String>>,
+        non_zero_length: usize,
+        index: SparseIndex,
+    ) -> Result<Self, ArrowError> {
+        let width = value_width(&data_type)?;
+        check_buffer_len("data", &data, &[non_zero_length, width])?;
+        check_names(&names, &shape)?;
+
+        match &index {
+            SparseIndex::Coo {
+                indices_type,
+                indices,
+                ..
+            } => {
+                let width = index_width(indices_type)?;
+                check_buffer_len(
+                    "indices",
+                    indices,
+                    &[non_zero_length, shape.len(), width],
+                )?;
+            }
+            SparseIndex::Csx {
+                compressed_axis,
+                indptr_type,
+                indptr,
+                indices_type,
+                indices,
+            } => {
+                if shape.len() != 2 {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Compressed sparse index requires a matrix, got {} dimensions",
+                        shape.len()
+                    )));
+                }
+                let axis_len = match compressed_axis {
+                    CompressedAxis::Row => shape[0],
+                    CompressedAxis::Column => shape[1],
+                };
+                let width = index_width(indptr_type)?;
+                let indptr_len = axis_len.checked_add(1).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Sparse tensor of shape {shape:?} overflows usize"
+                    ))
+                })?;
+                check_buffer_len("indptr", indptr, &[indptr_len, width])?;
+                let width = index_width(indices_type)?;
+                check_buffer_len("indices", indices, &[non_zero_length, width])?;
+            }
+        }
+
+        Ok(Self {
+            data_type,
+            data,
+            shape,
+            names,
+            non_zero_length,
+            index,
+        })
+    }
+
+    /// The type of the tensor's values
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The buffer containing the tensor's non-zero values
+    pub fn data(&self) -> &Buffer {
+        &self.data
+    }
+
+    /// The size of each of the tensor's dimensions
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// The names of the tensor's dimensions, if any
+    pub fn names(&self) -> Option<&[String]> {
+        self.names.as_deref()
+    }
+
+    /// The number of non-zero values
+    pub fn non_zero_length(&self) -> usize {
+        self.non_zero_length
+    }
+
+    /// The location of the non-zero values
+    pub fn index(&self) -> &SparseIndex {
+        &self.index
+    }
+}
+
+// Buffers are compared by their contents, rather than by their allocations
+
+impl PartialEq for IpcTensor {
+    fn eq(&self, other: &Self) -> bool {
+        self.data_type == other.data_type
+            && self.buffer.as_slice() == other.buffer.as_slice()
+            && self.shape == other.shape
+            && self.strides == other.strides
+            && self.names == other.names
+    }
+}
+
+impl PartialEq for SparseIndex {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::Coo {
+                    indices_type,
+                    indices,
+                    is_canonical,
+                },
+                Self::Coo {
+                    indices_type: other_indices_type,
+                    indices: other_indices,
+                    is_canonical: other_is_canonical,
+                },
+            ) => {
+                indices_type == other_indices_type
+                    && indices.as_slice() == other_indices.as_slice()
+                    && is_canonical == other_is_canonical
+            }
+            (
+                Self::Csx {
+                    compressed_axis,
+                    indptr_type,
+                    indptr,
+                    indices_type,
+                    indices,
+                },
+                Self::Csx {
+                    compressed_axis: other_compressed_axis,
+                    indptr_type: other_indptr_type,
+                    indptr: other_indptr,
+                    indices_type: other_indices_type,
+                    indices: other_indices,
+                },
+            ) => {
+                compressed_axis == other_compressed_axis
+                    && indptr_type == other_indptr_type
+                    && indptr.as_slice() == other_indptr.as_slice()
+                    && indices_type == other_indices_type
+                    && indices.as_slice() == other_indices.as_slice()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq for IpcSparseTensor {
+    fn eq(&self, other: &Self) -> bool {
+        self.data_type == other.data_type
+            && self.data.as_slice() == other.data.as_slice()
+            && self.shape == other.shape
+            && self.names == other.names
+            && self.non_zero_length == other.non_zero_length
+            && self.index == other.index
+    }
+}
+
+/// Write `tensor` to `writer` as an IPC `Tensor` message
+///
+/// Returns an error if `write_options` specifies a non-native endianness
+pub fn write_tensor<W: Write>(
+    writer: W,
+    tensor: &IpcTensor,
+    write_options: &IpcWriteOptions,
+) -> Result<(), ArrowError> {
+    let mut fbb = FlatBufferBuilder::new();
+    let mut body = Body::default();
+    let data = body.append(&tensor.buffer);
+
+    let field_type = get_fb_field_type(&tensor.data_type, &mut fbb);
+    let shape = build_shape(&mut fbb, &tensor.shape, &tensor.names);
+    let strides: Vec<i64> = tensor.strides.iter().map(|s| *s as i64).collect();
+    let strides = fbb.create_vector(&strides);
+
+    let root = crate::Tensor::create(
+        &mut fbb,
+        &crate::TensorArgs {
+            type_type: field_type.type_type,
+            type_: Some(field_type.type_),
+            shape: Some(shape),
+            strides: Some(strides),
+            data: Some(&data),
+        },
+    );
+    write_encoded(
+        writer,
+        fbb,
+        crate::MessageHeader::Tensor,
+        root.as_union_value(),
+        body,
+        write_options,
+    )
+}
+
+/// Write `tensor` to `writer` as an IPC `SparseTensor` message
+///
+/// Returns an error if `write_options` specifies a non-native endianness
+pub fn write_sparse_tensor<W: Write>(
+    writer: W,
+    tensor: &IpcSparseTensor,
+    write_options: &IpcWriteOptions,
+) -> Result<(), ArrowError> {
+    let mut fbb = FlatBufferBuilder::new();
+    let mut body = Body::default();
+
+    let (index_type, index) = match &tensor.index {
+        SparseIndex::Coo {
+            indices_type,
+            indices,
+            is_canonical,
+        } => {
+            let width = index_width(indices_type)? as i64;
+            let indices_buffer = body.append(indices);
+            let indices_type = build_int(&mut fbb, indices_type);
+            let strides = fbb.create_vector(&[tensor.shape.len() as i64 * width, width]);
+            let index = crate::SparseTensorIndexCOO::create(
+                &mut fbb,
+                &crate::SparseTensorIndexCOOArgs {
+                    indicesType: Some(indices_type),
+                    indicesStrides: Some(strides),
+                    indicesBuffer: Some(&indices_buffer),
+                    isCanonical: *is_canonical,
+                },
+            );
+            (
+                crate::SparseTensorIndex::SparseTensorIndexCOO,
+                index.as_union_value(),
+            )
+        }
+        SparseIndex::Csx {
+            compressed_axis,
+            indptr_type,
+            indptr,
+            indices_type,
+            indices,
+        } => {
+            let indptr_buffer = body.append(indptr);
+            let indices_buffer = body.append(indices);
+            let indptr_type = build_int(&mut fbb, indptr_type);
+            let indices_type = build_int(&mut fbb, indices_type);
+            let compressed_axis = match compressed_axis {
+                CompressedAxis::Row => crate::SparseMatrixCompressedAxis::Row,
+                CompressedAxis::Column => crate::SparseMatrixCompressedAxis::Column,
+            };
+            let index = crate::SparseMatrixIndexCSX::create(
+                &mut fbb,
+                &crate::SparseMatrixIndexCSXArgs {
+                    compressedAxis: compressed_axis,
+                    indptrType: Some(indptr_type),
+                    indptrBuffer: Some(&indptr_buffer),
+                    indicesType: Some(indices_type),
+                    indicesBuffer: Some(&indices_buffer),
+                },
+            );
+            (
+                crate::SparseTensorIndex::SparseMatrixIndexCSX,
+                index.as_union_value(),
+            )
+        }
+    };
+    let data = body.append(&tensor.data);
+
+    let field_type = get_fb_field_type(&tensor.data_type, &mut fbb);
+    let shape = build_shape(&mut fbb, &tensor.shape, &tensor.names);
+
+    let root = crate::SparseTensor::create(
+        &mut fbb,
+        &crate::SparseTensorArgs {
+            type_type: field_type.type_type,
+            type_: Some(field_type.type_),
+            shape: Some(shape),
+            non_zero_length: tensor.non_zero_length as i64,
+            sparseIndex_type: index_type,
+            sparseIndex: Some(index),
+            data: Some(&data),
+        },
+    );
+    write_encoded(
+        writer,
+        fbb,
+        crate::MessageHeader::SparseTensor,
+        root.as_union_value(),
+        body,
+        write_options,
+    )
+}
+
+/// Read an [`IpcTensor`] from an IPC `Tensor` message in `reader`
+pub fn read_tensor<R: Read>(reader: &mut R) -> Result<IpcTensor, ArrowError> {
+    let (meta, body) = read_encapsulated_message(reader)?;
+    let message = parse_message(&meta)?;
+    let tensor = message.header_as_tensor().ok_or_else(|| {
+        ArrowError::IoError(format!(
+            "Expected a Tensor message, found {:?}",
+            message.header_type()
+        ))
+    })?;
+
+    let data_type = fb_to_data_type(tensor.type_type(), tensor.type_as_int(), || {
+        tensor.type_as_floating_point()
+    })?;
+    let (shape, names) = read_shape(tensor.shape())?;
+    let strides = tensor
+        .strides()
+        .map(|strides| {
+            strides
+                .iter()
+                .map(|s| {
+                    usize::try_from(s).map_err(|_| {
+                        ArrowError::IoError(format!("Invalid tensor stride {s}"))
+                    })
+                })
+                .collect::<Result<_, _>>()
+        })
+        .transpose()?;
+    let buffer = body_buffer(&body, tensor.data())?;
+    IpcTensor::try_new(data_type, buffer, shape, strides, names)
+}
+
+/// Read an [`IpcSparseTensor`] from an IPC `SparseTensor` message in `reader`
+pub fn read_sparse_tensor<R: Read>(
+    reader: &mut R,
+) -> Result<IpcSparseTensor, ArrowError> {
+    let (meta, body) = read_encapsulated_message(reader)?;
+    let message = parse_message(&meta)?;
+    let tensor = message.header_as_sparse_tensor().ok_or_else(|| {
+        ArrowError::IoError(format!(
+            "Expected a SparseTensor message, found {:?}",
+            message.header_type()
+        ))
+    })?;
+
+    let data_type = fb_to_data_type(tensor.type_type(), tensor.type_as_int(), || {
+        tensor.type_as_floating_point()
+    })?;
+    let (shape, names) = read_shape(tensor.shape())?;
+
+    let index = match tensor.sparseIndex_type() {
+        crate::SparseTensorIndex::SparseTensorIndexCOO => {
+            let index = tensor.sparseIndex_as_sparse_tensor_index_coo().unwrap();
+            let indices_type = fb_to_int(index.indicesType())?;
+            let width = index_width(&indices_type)? as i64;
+            if let Some(strides) = index.indicesStrides() {
+                let row_major = [shape.len() as i64 * width, width];
+                if strides.iter().ne(row_major) {
+                    return Err(ArrowError::IoError(
+                        "Only row-major sparse tensor COO indices are supported"
+                            .to_string(),
+                    ));
+                }
+            }
+            SparseIndex::Coo {
+                indices_type,
+                indices: body_buffer(&body, index.indicesBuffer())?,
+                is_canonical: index.isCanonical(),
+            }
+        }
+        crate::SparseTensorIndex::SparseMatrixIndexCSX => {
+            let index = tensor.sparseIndex_as_sparse_matrix_index_csx().unwrap();
+            let compressed_axis = match index.compressedAxis() {
+                crate::SparseMatrixCompressedAxis::Row => CompressedAxis::Row,
+                crate::SparseMatrixCompressedAxis::Column => CompressedAxis::Column,
+                axis => {
+                    return Err(ArrowError::IoError(format!(
+                        "Unknown sparse matrix compressed axis {axis:?}"
+                    )))
+                }
+            };
+            SparseIndex::Csx {
+                compressed_axis,
+                indptr_type: fb_to_int(index.indptrType())?,
+                indptr: body_buffer(&body, index.indptrBuffer())?,
+                indices_type: fb_to_int(index.indicesType())?,
+                indices: body_buffer(&body, index.indicesBuffer())?,
+            }
+        }
+        t => {
+            return Err(ArrowError::IoError(format!(
+                "Sparse tensor index {t:?} is not supported"
+            )))
+        }
+    };
+
+    let non_zero_length = usize::try_from(tensor.non_zero_length()).map_err(|_| {
+        ArrowError::IoError(format!(
+            "Invalid sparse tensor non-zero length {}",
+            tensor.non_zero_length()
+        ))
+    })?;
+    let data = body_buffer(&body, tensor.data())?;
+    IpcSparseTensor::try_new(data_type, data, shape, names, non_zero_length, index)
+}
+
+/// The body of a tensor message, in which each buffer is padded to 8 bytes
+#[derive(Default)]
+struct Body {
+    data: Vec<u8>,
+}
+
+impl Body {
+    /// Append `buffer` to the body, returning its location
+    fn append(&mut self, buffer: &Buffer) -> crate::Buffer {
+        let offset = self.data.len();
+        self.data.extend_from_slice(buffer.as_slice());
+        let padded_len = (self.data.len() + 7) & !7;
+        self.data.resize(padded_len, 0);
+        crate::Buffer::new(offset as i64, buffer.len() as i64)
+    }
+}
+
+fn write_encoded<W: Write>(
+    writer: W,
+    mut fbb: FlatBufferBuilder,
+    header_type: crate::MessageHeader,
+    header: WIPOffset<flatbuffers::UnionWIPOffset>,
+    body: Body,
+    write_options: &IpcWriteOptions,
+) -> Result<(), ArrowError> {
+    // Unlike a schema, tensor messages do not record the byte order of their data
+    if write_options.endianness != NATIVE_ENDIANNESS {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Tensors can only be written in the native endianness {NATIVE_ENDIANNESS:?}, got {:?}",
+            write_options.endianness
+        )));
+    }
+
+    let mut message = crate::MessageBuilder::new(&mut fbb);
+    message.add_version(write_options.metadata_version);
+    message.add_header_type(header_type);
+    message.add_bodyLength(body.data.len() as i64);
+    message.add_header(header);
+    let root = message.finish();
+    fbb.finish(root, None);
+
+    let encoded = EncodedData {
+        ipc_message: fbb.finished_data().to_vec(),
+        arrow_data: body.data,
+    };
+    write_message(writer, encoded, write_options)?;
+    Ok(())
+}
+
+/// Read the metadata and body of an encapsulated IPC message
+fn read_encapsulated_message<R: Read>(
+    reader: &mut R,
+) -> Result<(Vec<u8>, Buffer), ArrowError> {
+    let mut meta_size: [u8; 4] = [0; 4];
+    reader.read_exact(&mut meta_size)?;
+    // If a continuation marker is encountered, skip over it and read
+    // the size from the next four bytes.
+    if meta_size == CONTINUATION_MARKER {
+        reader.read_exact(&mut meta_size)?;
+    }
+    let meta_len = i32::from_le_bytes(meta_size);
+    let meta = read_len(reader, "metadata", meta_len as i64)?;
+    let body_len = parse_message(&meta)?.bodyLength();
+    let body = read_len(reader, "body", body_len)?;
+    Ok((meta, body.into()))
+}
+
+/// Read `len` bytes of the `name` of a message from `reader`, returning an error
+/// if `len` is negative or `reader` ends before `len` bytes
+///
+/// The bytes are read incrementally, rather than allocating `len` bytes up front,
+/// so that a corrupt length does not result in an arbitrarily large allocation
+fn read_len<R: Read>(
+    reader: &mut R,
+    name: &str,
+    len: i64,
+) -> Result<Vec<u8>, ArrowError> {
+    let len = u64::try_from(len).map_err(|_| {
+        ArrowError::IoError(format!("Invalid message {name} length {len}"))
+    })?;
+    let mut buf = vec![];
+    reader.take(len).read_to_end(&mut buf)?;
+    if buf.len() as u64 != len {
+        return Err(ArrowError::IoError(format!(
+            "Expected message {name} of {len} bytes, found {}",
+            buf.len()
+        )));
+    }
+    Ok(buf)
+}
+
+/// Returns the part of `body` described by `buffer`
+fn body_buffer(body: &Buffer, buffer: &crate::Buffer) -> Result<Buffer, ArrowError> {
+    let (offset, length) = (buffer.offset() as usize, buffer.length() as usize);
+    match offset.checked_add(length) {
+        Some(end) if end <= body.len() => Ok(body.slice_with_length(offset, length)),
+        _ => Err(ArrowError::IoError(format!(
+            "Buffer at offset {offset} of length {length} exceeds message body of length {}",
+            body.len()
+        ))),
+    }
+}
+
+fn build_shape<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    shape: &[usize],
+    names: &Option<Vec<String>>,
+) -> WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<crate::TensorDim<'a>>>>
+{
+    let dims: Vec<_> = shape
+        .iter()
+        .enumerate()
+        .map(|(i, size)| {
+            let name = names.as_ref().map(|names| fbb.create_string(&names[i]));
+            crate::TensorDim::create(
+                fbb,
+                &crate::TensorDimArgs {
+                    size_: *size as i64,
+                    name,
+                },
+            )
+        })
+        .collect();
+    fbb.create_vector(&dims)
+}
+
+fn read_shape(
+    dims: flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<crate::TensorDim<'_>>>,
+) -> Result<(Vec<usize>, Option<Vec<String>>), ArrowError> {
+    let shape = dims
+        .iter()
+        .map(|dim| {
+            usize::try_from(dim.size_()).map_err(|_| {
+                ArrowError::IoError(format!("Invalid tensor dimension {}", dim.size_()))
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    // unnamed dimensions of a tensor with some named dimensions have empty names
+    let names = dims.iter().any(|dim| dim.name().is_some()).then(|| {
+        dims.iter()
+            .map(|dim| dim.name().unwrap_or_default().to_string())
+            .collect()
+    });
+    Ok((shape, names))
+}
+
+fn build_int<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    data_type: &DataType,
+) -> WIPOffset<crate::Int<'a>> {
+    let (bit_width, is_signed) = match data_type {
+        DataType::Int8 => (8, true),
+        DataType::Int16 => (16, true),
+        DataType::Int32 => (32, true),
+        DataType::Int64 => (64, true),
+        DataType::UInt8 => (8, false),
+        DataType::UInt16 => (16, false),
+        DataType::UInt32 => (32, false),
+        DataType::UInt64 => (64, false),
+        t => unreachable!("index type {t} is validated to be an integer"),
+    };
+    let mut builder = crate::IntBuilder::new(fbb);
+    builder.add_bitWidth(bit_width);
+    builder.add_is_signed(is_signed);
+    builder.finish()
+}
+
+fn fb_to_int(int: crate::Int) -> Result<DataType, ArrowError> {
+    match (int.bitWidth(), int.is_signed()) {
+        (8, true) => Ok(DataType::Int8),
+        (16, true) => Ok(DataType::Int16),
+        (32, true) => Ok(DataType::Int32),
+        (64, true) => Ok(DataType::Int64),
+        (8, false) => Ok(DataType::UInt8),
+        (16, false) => Ok(DataType::UInt16),
+        (32, false) => Ok(DataType::UInt32),
+        (64, false) => Ok(DataType::UInt64),
+        (width, _) => Err(ArrowError::IoError(format!(
+            "Unsupported integer bit width {width} in IPC tensor"
+        ))),
+    }
+}
+
+fn fb_to_data_type<'a>(
+    type_type: crate::Type,
+    int: Option<crate::Int<'a>>,
+    float: impl FnOnce() -> Option<crate::FloatingPoint<'a>>,
+) -> Result<DataType, ArrowError> {
+    match (type_type, int) {
+        (crate::Type::Int, Some(int)) => fb_to_int(int),
+        (crate::Type::FloatingPoint, _) => match float().map(|f| f.precision()) {
+            Some(crate::Precision::HALF) => Ok(DataType::Float16),
+            Some(crate::Precision::SINGLE) => Ok(DataType::Float32),
+            Some(crate::Precision::DOUBLE) => Ok(DataType::Float64),
+            p => Err(ArrowError::IoError(format!(
+                "Unsupported floating point precision {p:?} in IPC tensor"
+            ))),
+        },
+        (t, _) => Err(ArrowError::IoError(format!(
+            "Unsupported IPC tensor value type {t:?}"
+        ))),
+    }
+}
+
+/// Returns the byte width of a tensor value type
+fn value_width(data_type: &DataType) -> Result<usize, ArrowError> {
+    match data_type {
+        DataType::Float16 => Ok(2),
+        DataType::Float32 => Ok(4),
+        DataType::Float64 => Ok(8),
+        _ => index_width(data_type).map_err(|_| {
+            ArrowError::InvalidArgumentError(format!(
+                "Tensor values must be integers or floating point numbers, got {data_type}"
+            ))
+        }),
+    }
+}
+
+/// Returns the byte width of a sparse tensor index type
+fn index_width(data_type: &DataType) -> Result<usize, ArrowError> {
+    match data_type {
+        DataType::Int8 | DataType::UInt8 => Ok(1),
+        DataType::Int16 | DataType::UInt16 => Ok(2),
+        DataType::Int32 | DataType::UInt32 => Ok(4),
+        DataType::Int64 | DataType::UInt64 => Ok(8),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "Sparse tensor indices must be integers, got {data_type}"
+        ))),
+    }
+}
+
+fn row_major_strides(shape: &[usize], width: usize) -> Result<Vec<usize>, ArrowError> {
+    let mut strides = vec![width; shape.len()];
+    for i in (0..shape.len().saturating_sub(1)).rev() {
+        strides[i] = strides[i + 1].checked_mul(shape[i + 1]).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Tensor of shape {shape:?} overflows usize"
+            ))
+        })?;
+    }
+    Ok(strides)
+}
+
+fn check_names(names: &Option<Vec<String>>, shape: &[usize]) -> Result<(), ArrowError> {
+    match names {
+        Some(names) if names.len() != shape.len() => {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "Tensor has {} names for {} dimensions",
+                names.len(),
+                shape.len()
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks that `buffer` holds at least the product of `sizes` bytes
+fn check_buffer_len(
+    name: &str,
+    buffer: &Buffer,
+    sizes: &[usize],
+) -> Result<(), ArrowError> {
+    let required = sizes
+        .iter()
+        .try_fold(1_usize, |acc, size| acc.checked_mul(*size))
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Sparse tensor {name} buffer size {sizes:?} overflows usize"
+            ))
+        })?;
+    if buffer.len() < required {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Sparse tensor {name} buffer of {} bytes is too small for {required} bytes",
+            buffer.len()
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::MetadataVersion;
+
+    #[test]
+    fn test_tensor_roundtrip() {
+        let values = Buffer::from_slice_ref([1_i64, 2, 3, 4, 5, 6]);
+        let tensor = IpcTensor::try_new(
+            DataType::Int64,
+            values,
+            vec![2, 3],
+            None,
+            Some(vec!["x".to_string(), "y".to_string()]),
+        )
+        .unwrap();
+        assert_eq!(tensor.strides(), &[24, 8]);
+
+        for options in [
+            IpcWriteOptions::default(),
+            IpcWriteOptions::try_new(8, true, MetadataVersion::V4).unwrap(),
+        ] {
+            let mut buffer = vec![];
+            write_tensor(&mut buffer, &tensor, &options).unwrap();
+            let read = read_tensor(&mut buffer.as_slice()).unwrap();
+            assert_eq!(read, tensor);
+        }
+
+        // column-major strides
+        let values = Buffer::from_slice_ref([1.0_f64, 4.0, 2.0, 5.0, 3.0, 6.0]);
+        let tensor = IpcTensor::try_new(
+            DataType::Float64,
+            values,
+            vec![2, 3],
+            Some(vec![8, 16]),
+            None,
+        )
+        .unwrap();
+        let mut buffer = vec![];
+        write_tensor(&mut buffer, &tensor, &IpcWriteOptions::default()).unwrap();
+        let read = read_tensor(&mut buffer.as_slice()).unwrap();
+        assert_eq!(read, tensor);
+        assert_eq!(read.names(), None);
+
+        let err = read_sparse_tensor(&mut buffer.as_slice()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Expected a SparseTensor message, found Tensor"
+        );
+
+        let endianness = match NATIVE_ENDIANNESS {
+            crate::Endianness::Little => crate::Endianness::Big,
+            _ => crate::Endianness::Little,
+        };
+        let options = IpcWriteOptions::default().with_endianness(endianness);
+        let err = write_tensor(vec![], &tensor, &options).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("Invalid argument error: Tensors can only be written in the native endianness"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_tensor_invalid() {
+        let values = Buffer::from_slice_ref([1_i32, 2, 3]);
+        let err =
+            IpcTensor::try_new(DataType::Int32, values.clone(), vec![2, 2], None, None)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Tensor buffer of 12 bytes is too small for 16 bytes of values"
+        );
+
+        let err = IpcTensor::try_new(DataType::Utf8, values.clone(), vec![3], None, None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Tensor values must be integers or floating point numbers, got Utf8"
+        );
+
+        let err = IpcTensor::try_new(
+            DataType::Int32,
+            values.clone(),
+            vec![3, 1],
+            Some(vec![usize::MAX, 4]),
+            None,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("overflows usize"), "{err}");
+
+        let err =
+            IpcTensor::try_new(DataType::Int32, values, vec![2, usize::MAX], None, None)
+                .unwrap_err();
+        assert!(err.to_string().contains("overflows usize"), "{err}");
+    }
+
+    #[test]
+    fn test_read_tensor_invalid() {
+        // A tensor with a negative stride
+        let mut fbb = FlatBufferBuilder::new();
+        let mut body = Body::default();
+        let data = body.append(&Buffer::from_slice_ref([1_i32, 2]));
+        let field_type = get_fb_field_type(&DataType::Int32, &mut fbb);
+        let shape = build_shape(&mut fbb, &[2], &None);
+        let strides = fbb.create_vector(&[-4_i64]);
+        let root = crate::Tensor::create(
+            &mut fbb,
+            &crate::TensorArgs {
+                type_type: field_type.type_type,
+                type_: Some(field_type.type_),
+                shape: Some(shape),
+                strides: Some(strides),
+                data: Some(&data),
+            },
+        );
+        let mut buffer = vec![];
+        let options = IpcWriteOptions::default();
+        let header = root.as_union_value();
+        write_encoded(
+            &mut buffer,
+            fbb,
+            crate::MessageHeader::Tensor,
+            header,
+            body,
+            &options,
+        )
+        .unwrap();
+        let err = read_tensor(&mut buffer.as_slice()).unwrap_err();
+        assert_eq!(err.to_string(), "Io error: Invalid tensor stride -4");
+
+        // A negative metadata length
+        let buffer = [255, 255, 255, 255, 0, 0, 0, 128];
+        let err = read_tensor(&mut buffer.as_slice()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Invalid message metadata length -2147483648"
+        );
+
+        // A metadata length exceeding the input
+        let buffer = [255, 255, 255, 255, 255, 255, 255, 127, 0, 0];
+        let err = read_tensor(&mut buffer.as_slice()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Expected message metadata of 2147483647 bytes, found 2"
+        );
+    }
+
+    #[test]
+    fn test_read_tensor_partial_names() {
+        let mut fbb = FlatBufferBuilder::new();
+        let mut body = Body::default();
+        let data = body.append(&Buffer::from_slice_ref([1_i32, 2, 3, 4, 5, 6]));
+        let field_type = get_fb_field_type(&DataType::Int32, &mut fbb);
+        let name = fbb.create_string("rows");
+        let dims = [(2, Some(name)), (3, None)].map(|(size_, name)| {
+            crate::TensorDim::create(&mut fbb, &crate::TensorDimArgs { size_, name })
+        });
+        let shape = fbb.create_vector(&dims);
+        let root = crate::Tensor::create(
+            &mut fbb,
+            &crate::TensorArgs {
+                type_type: field_type.type_type,
+                type_: Some(field_type.type_),
+                shape: Some(shape),
+                strides: None,
+                data: Some(&data),
+            },
+        );
+        let mut buffer = vec![];
+        let header = root.as_union_value();
+        let options = IpcWriteOptions::default();
+        write_encoded(
+            &mut buffer,
+            fbb,
+            crate::MessageHeader::Tensor,
+            header,
+            body,
+            &options,
+        )
+        .unwrap();
+
+        let tensor = read_tensor(&mut buffer.as_slice()).unwrap();
+        assert_eq!(tensor.shape(), &[2, 3]);
+        assert_eq!(tensor.names().unwrap(), &["rows", ""]);
+    }
+
+    #[test]
+    fn test_sparse_tensor_roundtrip() {
+        // [[0, 1.5, 0], [2.5, 0, 3.5]]
+        let data = Buffer::from_slice_ref([1.5_f32, 2.5, 3.5]);
+        let shape = vec![2, 3];
+
+        let coo = SparseIndex::Coo {
+            indices_type: DataType::Int64,
+            indices: Buffer::from_slice_ref([0_i64, 1, 1, 0, 1, 2]),
+            is_canonical: true,
+        };
+        let csr = SparseIndex::Csx {
+            compressed_axis: CompressedAxis::Row,
+            indptr_type: DataType::Int32,
+            indptr: Buffer::from_slice_ref([0_i32, 1, 3]),
+            indices_type: DataType::Int32,
+            indices: Buffer::from_slice_ref([1_i32, 0, 2]),
+        };
+        let csc = SparseIndex::Csx {
+            compressed_axis: CompressedAxis::Column,
+            indptr_type: DataType::Int64,
+            indptr: Buffer::from_slice_ref([0_i64, 1, 2, 3]),
+            indices_type: DataType::Int16,
+            indices: Buffer::from_slice_ref([1_i16, 0, 1]),
+        };
+
+        for index in [coo, csr, csc] {
+            let tensor = IpcSparseTensor::try_new(
+                DataType::Float32,
+                data.clone(),
+                shape.clone(),
+                None,
+                3,
+                index,
+            )
+            .unwrap();
+            let mut buffer = vec![];
+            write_sparse_tensor(&mut buffer, &tensor, &IpcWriteOptions::default())
+                .unwrap();
+            let read = read_sparse_tensor(&mut buffer.as_slice()).unwrap();
+            assert_eq!(read, tensor);
+        }
+
+        let err = IpcSparseTensor::try_new(
+            DataType::Float32,
+            data,
+            vec![2, 3, 1],
+            None,
+            3,
+            SparseIndex::Csx {
+                compressed_axis: CompressedAxis::Row,
+                indptr_type: DataType::Int32,
+                indptr: Buffer::from_slice_ref([0_i32, 1, 3]),
+                indices_type: DataType::Int32,
+                indices: Buffer::from_slice_ref([1_i32, 0, 2]),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Compressed sparse index requires a matrix, got 3 dimensions"
+        );
+
+        let err = IpcSparseTensor::try_new(
+            DataType::Float32,
+            Buffer::from_slice_ref([1.5_f32]),
+            vec![1, 1],
+            None,
+            usize::MAX / 2,
+            SparseIndex::Coo {
+                indices_type: DataType::Int64,
+                indices: Buffer::from_slice_ref([0_i64, 0]),
+                is_canonical: true,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Invalid argument error: Sparse tensor data buffer size [{}, 4] overflows usize",
+                usize::MAX / 2
+            )
+        );
+    }
+}
@@ -35,14 +35,17 @@ use arrow_data::{layout, ArrayData, BufferSpec};
 use arrow_schema::*;
 
 use crate::compression::CompressionCodec;
+use crate::endian::{swap_buffer, NATIVE_ENDIANNESS};
 use crate::CONTINUATION_MARKER;
 
 /// IPC write options used to control the behaviour of the [`IpcDataGenerator`]
 #[derive(Debug, Clone)]
 pub struct IpcWriteOptions {
-    /// Write padding after memory buffers to this multiple of bytes.
+    /// Write padding after message metadata to this multiple of bytes.
     /// Generally 8 or 64, defaults to 64
     alignment: usize,
+    /// Write padding after memory buffers to this multiple of bytes, defaults to 8
+    buffer_alignment: usize,
     /// The legacy format is for releases before 0.15.0, and uses metadata V4
     write_legacy_ipc_format: bool,
     /// The metadata version to write. The Rust IPC writer supports V4+
@@ -53,10 +56,17 @@ pub struct IpcWriteOptions {
     ///
     /// version 2.0.0: V4, with legacy format enabled
     /// version 4.0.0: V5
-    metadata_version: crate::MetadataVersion,
+    pub(crate) metadata_version: crate::MetadataVersion,
     /// Compression, if desired. Will result in a runtime error
     /// if the corresponding feature is not enabled
     batch_compression_type: Option<crate::CompressionType>,
+    /// How dictionaries that change between batches are written
+    dictionary_handling: DictionaryHandling,
+    /// The byte order to write data in, defaults to the native endianness
+    pub(crate) endianness: crate::Endianness,
+    /// The maximum encoded size of a record batch message, larger batches are
+    /// split into multiple messages
+    max_message_size: Option<usize>,
 }
 
 impl IpcWriteOptions {
@@ -79,17 +89,70 @@ impl IpcWriteOptions {
         }
         Ok(self)
     }
+
+    /// Configures how dictionaries that change between batches are written,
+    /// see [`DictionaryHandling`]
+    pub fn with_dictionary_handling(
+        mut self,
+        dictionary_handling: DictionaryHandling,
+    ) -> Self {
+        self.dictionary_handling = dictionary_handling;
+        self
+    }
+
+    /// Configures the byte order that data is written in, by default the native
+    /// endianness of the platform
+    ///
+    /// Writing a non-native endianness requires the data to be byte swapped
+    pub fn with_endianness(mut self, endianness: crate::Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+
+    /// Configures the maximum size in bytes of a record batch message as written by
+    /// [`write_message`], including its length prefix, metadata, body and padding
+    ///
+    /// Record batches whose encoded form exceeds this size are split into multiple
+    /// record batch messages with fewer rows, for example to stay within the frame
+    /// limits of a transport. Dictionary messages are not split, and a batch is never
+    /// split into messages of less than one row, so a single row that exceeds the
+    /// limit is still written as one message.
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = Some(max_message_size);
+        self
+    }
+
+    /// Configures the multiple of bytes that message metadata is padded to,
+    /// generally 8 or 64, like the `alignment` of [`Self::try_new`]
+    ///
+    /// Defaults to 64. Returns an error if `alignment` is not a non-zero multiple of 8
+    pub fn try_with_alignment(mut self, alignment: usize) -> Result<Self, ArrowError> {
+        validate_alignment(alignment)?;
+        self.alignment = alignment;
+        Ok(self)
+    }
+
+    /// Configures the multiple of bytes that the buffers of a message body are
+    /// padded to, generally 8 or 64
+    ///
+    /// Defaults to 8. Returns an error if `buffer_alignment` is not a non-zero
+    /// multiple of 8
+    pub fn try_with_buffer_alignment(
+        mut self,
+        buffer_alignment: usize,
+    ) -> Result<Self, ArrowError> {
+        validate_alignment(buffer_alignment)?;
+        self.buffer_alignment = buffer_alignment;
+        Ok(self)
+    }
+
     /// Try create IpcWriteOptions, checking for incompatible settings
     pub fn try_new(
         alignment: usize,
         write_legacy_ipc_format: bool,
         metadata_version: crate::MetadataVersion,
     ) -> Result<Self, ArrowError> {
-        if alignment == 0 || alignment % 8 != 0 {
-            return Err(ArrowError::InvalidArgumentError(
-                "Alignment should be greater than 0 and be a multiple of 8".to_string(),
-            ));
-        }
+        validate_alignment(alignment)?;
         match metadata_version {
             crate::MetadataVersion::V1
             | crate::MetadataVersion::V2
@@ -98,9 +161,13 @@ impl IpcWriteOptions {
             )),
             crate::MetadataVersion::V4 => Ok(Self {
                 alignment,
+                buffer_alignment: 8,
                 write_legacy_ipc_format,
                 metadata_version,
                 batch_compression_type: None,
+                dictionary_handling: DictionaryHandling::Resend,
+                endianness: NATIVE_ENDIANNESS,
+                max_message_size: None,
             }),
             crate::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -111,9 +178,13 @@ impl IpcWriteOptions {
                 } else {
                     Ok(Self {
                         alignment,
+                        buffer_alignment: 8,
                         write_legacy_ipc_format,
                         metadata_version,
                         batch_compression_type: None,
+                        dictionary_handling: DictionaryHandling::Resend,
+                        endianness: NATIVE_ENDIANNESS,
+                        max_message_size: None,
                     })
                 }
             }
@@ -124,17 +195,40 @@ impl IpcWriteOptions {
     }
 }
 
+fn validate_alignment(alignment: usize) -> Result<(), ArrowError> {
+    if alignment == 0 || alignment % 8 != 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "Alignment should be greater than 0 and be a multiple of 8".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 impl Default for IpcWriteOptions {
     fn default() -> Self {
         Self {
             alignment: 64,
+            buffer_alignment: 8,
             write_legacy_ipc_format: false,
             metadata_version: crate::MetadataVersion::V5,
             batch_compression_type: None,
+            dictionary_handling: DictionaryHandling::Resend,
+            endianness: NATIVE_ENDIANNESS,
+            max_message_size: None,
         }
     }
 }
 
+/// Determines how a dictionary that changes between record batches is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DictionaryHandling {
+    /// Write the full dictionary whenever it changes
+    Resend,
+    /// If the new dictionary extends the previously written dictionary, only write
+    /// the new values as a delta dictionary batch, otherwise write the full dictionary
+    Delta,
+}
+
 #[derive(Debug, Default)]
 /// Handles low level details of encoding [`Array`] and [`Schema`] into the
 /// [Arrow IPC Format].
@@ -178,7 +272,11 @@ impl IpcDataGenerator {
     ) -> EncodedData {
         let mut fbb = FlatBufferBuilder::new();
         let schema = {
-            let fb = crate::convert::schema_to_fb_offset(&mut fbb, schema);
+            let fb = crate::convert::schema_to_fb_offset_with_endianness(
+                &mut fbb,
+                schema,
+                write_options.endianness,
+            );
             fb.as_union_value()
         };
 
@@ -198,14 +296,17 @@ impl IpcDataGenerator {
         }
     }
 
+    /// Encodes the dictionaries nested within `column`, taking the `dict_id`s
+    /// from the fields of `data_type` rather than from the column itself
     fn _encode_dictionaries(
         &self,
+        data_type: &DataType,
         column: &ArrayRef,
         encoded_dictionaries: &mut Vec<EncodedData>,
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
     ) -> Result<(), ArrowError> {
-        match column.data_type() {
+        match data_type {
             DataType::Struct(fields) => {
                 let s = as_struct_array(column);
                 for (field, column) in fields.iter().zip(s.columns()) {
@@ -306,8 +407,8 @@ impl IpcDataGenerator {
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
     ) -> Result<(), ArrowError> {
-        match column.data_type() {
-            DataType::Dictionary(_key_type, _value_type) => {
+        match field.data_type() {
+            DataType::Dictionary(_key_type, value_type) => {
                 let dict_id = field
                     .dict_id()
                     .expect("All Dictionary types have `dict_id`");
@@ -317,23 +418,41 @@ impl IpcDataGenerator {
                 let values = make_array(dict_data.child_data()[0].clone());
 
                 self._encode_dictionaries(
+                    value_type,
                     &values,
                     encoded_dictionaries,
                     dictionary_tracker,
                     write_options,
                 )?;
 
-                let emit = dictionary_tracker.insert(dict_id, column)?;
+                let update = dictionary_tracker.insert_column(
+                    dict_id,
+                    column,
+                    write_options.dictionary_handling,
+                )?;
 
-                if emit {
-                    encoded_dictionaries.push(self.dictionary_batch_to_bytes(
-                        dict_id,
-                        dict_values,
-                        write_options,
-                    )?);
+                match update {
+                    DictionaryUpdate::Unchanged => {}
+                    DictionaryUpdate::New | DictionaryUpdate::Replaced => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            dict_values,
+                            false,
+                            write_options,
+                        )?);
+                    }
+                    DictionaryUpdate::Delta(delta) => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            &delta,
+                            true,
+                            write_options,
+                        )?);
+                    }
                 }
             }
-            _ => self._encode_dictionaries(
+            data_type => self._encode_dictionaries(
+                data_type,
                 column,
                 encoded_dictionaries,
                 dictionary_tracker,
@@ -353,6 +472,58 @@ impl IpcDataGenerator {
         dictionary_tracker: &mut DictionaryTracker,
         write_options: &IpcWriteOptions,
     ) -> Result<(Vec<EncodedData>, EncodedData), ArrowError> {
+        let encoded_dictionaries =
+            self.encoded_dictionaries(batch, dictionary_tracker, write_options)?;
+        let encoded_message =
+            self.record_batch_to_bytes(batch, write_options, &HashMap::new())?;
+        Ok((encoded_dictionaries, encoded_message))
+    }
+
+    /// Encodes a batch like [`Self::encoded_batch`], splitting the record batch into
+    /// multiple record batch messages if its encoded size exceeds the
+    /// [maximum message size](IpcWriteOptions::with_max_message_size)
+    pub fn encoded_batches(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+    ) -> Result<(Vec<EncodedData>, Vec<EncodedData>), ArrowError> {
+        self.encoded_batches_with_metadata(
+            batch,
+            dictionary_tracker,
+            write_options,
+            &HashMap::new(),
+        )
+    }
+
+    /// Encodes a batch like [`Self::encoded_batches`], attaching `custom_metadata`
+    /// to each of its record batch messages
+    pub fn encoded_batches_with_metadata(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(Vec<EncodedData>, Vec<EncodedData>), ArrowError> {
+        let encoded_dictionaries =
+            self.encoded_dictionaries(batch, dictionary_tracker, write_options)?;
+        let mut encoded_messages = vec![];
+        self.split_record_batch_to_bytes(
+            batch,
+            write_options,
+            custom_metadata,
+            &mut encoded_messages,
+        )?;
+        Ok((encoded_dictionaries, encoded_messages))
+    }
+
+    /// Encodes the dictionaries of a batch that have not yet been sent
+    fn encoded_dictionaries(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+    ) -> Result<Vec<EncodedData>, ArrowError> {
         let schema = batch.schema();
         let mut encoded_dictionaries = Vec::with_capacity(schema.all_fields().len());
 
@@ -367,8 +538,118 @@ impl IpcDataGenerator {
             )?;
         }
 
-        let encoded_message = self.record_batch_to_bytes(batch, write_options)?;
-        Ok((encoded_dictionaries, encoded_message))
+        Ok(encoded_dictionaries)
+    }
+
+    /// Encodes a `RecordBatch` into `encoded_messages`, splitting it into slices
+    /// until each message is within the maximum message size
+    ///
+    /// The batch is split up front if the memory its arrays refer to exceeds the
+    /// maximum message size, so that an oversized batch is not encoded as a whole
+    fn split_record_batch_to_bytes(
+        &self,
+        batch: &RecordBatch,
+        write_options: &IpcWriteOptions,
+        custom_metadata: &HashMap<String, String>,
+        encoded_messages: &mut Vec<EncodedData>,
+    ) -> Result<(), ArrowError> {
+        let max_size = match write_options.max_message_size {
+            Some(max_size) => max_size,
+            None => {
+                let encoded =
+                    self.record_batch_to_bytes(batch, write_options, custom_metadata)?;
+                encoded_messages.push(encoded);
+                return Ok(());
+            }
+        };
+
+        // Types without a slice size, such as unions, are measured by encoding them
+        let estimated_size: Option<usize> = batch
+            .columns()
+            .iter()
+            .map(|column| column.data().get_slice_memory_size().ok())
+            .sum();
+        match estimated_size {
+            Some(size) if size > max_size => self.encode_splits(
+                batch,
+                size,
+                max_size,
+                write_options,
+                custom_metadata,
+                encoded_messages,
+            ),
+            _ => self.encode_checked(
+                batch,
+                max_size,
+                write_options,
+                custom_metadata,
+                encoded_messages,
+            ),
+        }
+    }
+
+    /// Encodes `batch`, splitting it again if its framed message exceeds `max_size`
+    fn encode_checked(
+        &self,
+        batch: &RecordBatch,
+        max_size: usize,
+        write_options: &IpcWriteOptions,
+        custom_metadata: &HashMap<String, String>,
+        encoded_messages: &mut Vec<EncodedData>,
+    ) -> Result<(), ArrowError> {
+        let encoded =
+            self.record_batch_to_bytes(batch, write_options, custom_metadata)?;
+        let size = framed_metadata_len(encoded.ipc_message.len(), write_options)
+            + encoded.arrow_data.len();
+        match size > max_size {
+            true => self.encode_splits(
+                batch,
+                size,
+                max_size,
+                write_options,
+                custom_metadata,
+                encoded_messages,
+            ),
+            false => {
+                encoded_messages.push(encoded);
+                Ok(())
+            }
+        }
+    }
+
+    /// Encodes `batch` of `size` bytes as slices that are each expected to be
+    /// within `max_size`, a batch of a single row is encoded as is
+    fn encode_splits(
+        &self,
+        batch: &RecordBatch,
+        size: usize,
+        max_size: usize,
+        write_options: &IpcWriteOptions,
+        custom_metadata: &HashMap<String, String>,
+        encoded_messages: &mut Vec<EncodedData>,
+    ) -> Result<(), ArrowError> {
+        let num_rows = batch.num_rows();
+        if num_rows <= 1 {
+            let encoded =
+                self.record_batch_to_bytes(batch, write_options, custom_metadata)?;
+            encoded_messages.push(encoded);
+            return Ok(());
+        }
+        let num_splits = (size / max_size.max(1) + 1).min(num_rows);
+        let rows_per_split = (num_rows + num_splits - 1) / num_splits;
+        let mut offset = 0;
+        while offset < num_rows {
+            let length = rows_per_split.min(num_rows - offset);
+            self.encode_checked(
+                &batch.slice(offset, length),
+                max_size,
+                write_options,
+                custom_metadata,
+                encoded_messages,
+            )?;
+            offset += length;
+        }
+        Ok(())
     }
 
     /// Write a `RecordBatch` into two sets of bytes, one for the header (crate::Message) and the
@@ -377,6 +658,7 @@ impl IpcDataGenerator {
         &self,
         batch: &RecordBatch,
         write_options: &IpcWriteOptions,
+        custom_metadata: &HashMap<String, String>,
     ) -> Result<EncodedData, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
 
@@ -414,7 +696,7 @@ impl IpcDataGenerator {
         }
         // pad the tail of body data
         let len = arrow_data.len();
-        let pad_len = pad_to_8(len as u32);
+        let pad_len = pad_to_alignment(write_options.buffer_alignment, len);
         arrow_data.extend_from_slice(&vec![0u8; pad_len][..]);
 
         // write data
@@ -431,12 +713,17 @@ impl IpcDataGenerator {
             let b = batch_builder.finish();
             b.as_union_value()
         };
+        let custom_metadata = (!custom_metadata.is_empty())
+            .then(|| crate::convert::metadata_to_fb(&mut fbb, custom_metadata));
         // create an crate::Message
         let mut message = crate::MessageBuilder::new(&mut fbb);
         message.add_version(write_options.metadata_version);
         message.add_header_type(crate::MessageHeader::RecordBatch);
         message.add_bodyLength(arrow_data.len() as i64);
         message.add_header(root);
+        if let Some(custom_metadata) = custom_metadata {
+            message.add_custom_metadata(custom_metadata);
+        }
         let root = message.finish();
         fbb.finish(root, None);
         let finished_data = fbb.finished_data();
@@ -448,11 +735,12 @@ impl IpcDataGenerator {
     }
 
     /// Write dictionary values into two sets of bytes, one for the header (crate::Message) and the
-    /// other for the data
+    /// other for the data. If `is_delta` the values are appended to the existing dictionary
     fn dictionary_batch_to_bytes(
         &self,
         dict_id: i64,
         array_data: &ArrayData,
+        is_delta: bool,
         write_options: &IpcWriteOptions,
     ) -> Result<EncodedData, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
@@ -489,7 +777,7 @@ impl IpcDataGenerator {
 
         // pad the tail of body data
         let len = arrow_data.len();
-        let pad_len = pad_to_8(len as u32);
+        let pad_len = pad_to_alignment(write_options.buffer_alignment, len);
         arrow_data.extend_from_slice(&vec![0u8; pad_len][..]);
 
         // write data
@@ -511,6 +799,7 @@ impl IpcDataGenerator {
             let mut batch_builder = crate::DictionaryBatchBuilder::new(&mut fbb);
             batch_builder.add_id(dict_id);
             batch_builder.add_data(root);
+            batch_builder.add_isDelta(is_delta);
             batch_builder.finish().as_union_value()
         };
 
@@ -556,6 +845,8 @@ impl DictionaryTracker {
     ///
     /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
     ///   that the dictionary was not actually inserted (because it's already been seen).
+    ///   Dictionaries are compared by value, so a dictionary equal to the one previously
+    ///   written is not emitted again, even if it does not share its buffers.
     /// * If this ID has been written already but with different data, and this tracker is
     ///   configured to return an error, return an error.
     /// * If the tracker has not been configured to error on replacement or this dictionary
@@ -566,21 +857,47 @@ impl DictionaryTracker {
         dict_id: i64,
         column: &ArrayRef,
     ) -> Result<bool, ArrowError> {
+        let update = self.insert_column(dict_id, column, DictionaryHandling::Resend)?;
+        Ok(update != DictionaryUpdate::Unchanged)
+    }
+
+    /// Keep track of the dictionary with the given ID and values, returning how it
+    /// differs from the dictionary previously written with this ID. Behaves as
+    /// [`Self::insert`], except that with [`DictionaryHandling::Delta`] a dictionary
+    /// that extends the previously written one returns [`DictionaryUpdate::Delta`],
+    /// which is not considered a replacement.
+    pub fn insert_column(
+        &mut self,
+        dict_id: i64,
+        column: &ArrayRef,
+        dictionary_handling: DictionaryHandling,
+    ) -> Result<DictionaryUpdate, ArrowError> {
         let dict_data = column.data();
         let dict_values = &dict_data.child_data()[0];
 
         // If a dictionary with this id was already emitted, check if it was the same.
         if let Some(last) = self.written.get(&dict_id) {
-            if ArrayData::ptr_eq(&last.data().child_data()[0], dict_values) {
+            let last_values = &last.data().child_data()[0];
+            if ArrayData::ptr_eq(last_values, dict_values) {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
+                return Ok(DictionaryUpdate::Unchanged);
+            }
+            if *last_values == *dict_values {
+                // Logically the same dictionary values => no need to emit it again,
+                // track this column so later batches sharing it take the fast path
+                self.written.insert(dict_id, column.clone());
+                return Ok(DictionaryUpdate::Unchanged);
+            }
+            if dictionary_handling == DictionaryHandling::Delta
+                && dict_values.len() > last_values.len()
+                && dict_values.slice(0, last_values.len()) == *last_values
+            {
+                let delta = dict_values
+                    .slice(last_values.len(), dict_values.len() - last_values.len());
+                self.written.insert(dict_id, column.clone());
+                return Ok(DictionaryUpdate::Delta(delta));
             }
             if self.error_on_replacement {
-                // If error on replacement perform a logical comparison
-                if last.data().child_data()[0] == *dict_values {
-                    // Same dictionary values => no need to emit it again
-                    return Ok(false);
-                }
                 return Err(ArrowError::InvalidArgumentError(
                     "Dictionary replacement detected when writing IPC file format. \
                      Arrow IPC files only support a single dictionary for a given field \
@@ -588,13 +905,29 @@ impl DictionaryTracker {
                         .to_string(),
                 ));
             }
+            self.written.insert(dict_id, column.clone());
+            return Ok(DictionaryUpdate::Replaced);
         }
 
         self.written.insert(dict_id, column.clone());
-        Ok(true)
+        Ok(DictionaryUpdate::New)
     }
 }
 
+/// Describes how a dictionary differs from the dictionary previously written with
+/// the same ID, as returned by [`DictionaryTracker::insert_column`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DictionaryUpdate {
+    /// The dictionary is the same as the one previously written
+    Unchanged,
+    /// No dictionary has been written with this ID
+    New,
+    /// The dictionary replaces the one previously written
+    Replaced,
+    /// The dictionary extends the one previously written with these values
+    Delta(ArrayData),
+}
+
 pub struct FileWriter<W: Write> {
     /// The object to write to
     writer: BufWriter<W>,
@@ -654,17 +987,29 @@ impl<W: Write> FileWriter<W> {
 
     /// Write a record batch to the file
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, &HashMap::new())
+    }
+
+    /// Write a record batch to the file, attaching `custom_metadata` to its
+    /// record batch message
+    pub fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IoError(
                 "Cannot write record batch to file writer as it is closed".to_string(),
             ));
         }
 
-        let (encoded_dictionaries, encoded_message) = self.data_gen.encoded_batch(
-            batch,
-            &mut self.dictionary_tracker,
-            &self.write_options,
-        )?;
+        let (encoded_dictionaries, encoded_messages) =
+            self.data_gen.encoded_batches_with_metadata(
+                batch,
+                &mut self.dictionary_tracker,
+                &self.write_options,
+                custom_metadata,
+            )?;
 
         for encoded_dictionary in encoded_dictionaries {
             let (meta, data) =
@@ -676,16 +1021,18 @@ impl<W: Write> FileWriter<W> {
             self.block_offsets += meta + data;
         }
 
-        let (meta, data) =
-            write_message(&mut self.writer, encoded_message, &self.write_options)?;
-        // add a record block for the footer
-        let block = crate::Block::new(
-            self.block_offsets as i64,
-            meta as i32, // TODO: is this still applicable?
-            data as i64,
-        );
-        self.record_blocks.push(block);
-        self.block_offsets += meta + data;
+        for encoded_message in encoded_messages {
+            let (meta, data) =
+                write_message(&mut self.writer, encoded_message, &self.write_options)?;
+            // add a record block for the footer
+            let block = crate::Block::new(
+                self.block_offsets as i64,
+                meta as i32, // TODO: is this still applicable?
+                data as i64,
+            );
+            self.record_blocks.push(block);
+            self.block_offsets += meta + data;
+        }
         Ok(())
     }
 
@@ -700,25 +1047,13 @@ impl<W: Write> FileWriter<W> {
         // write EOS
         write_continuation(&mut self.writer, &self.write_options, 0)?;
 
-        let mut fbb = FlatBufferBuilder::new();
-        let dictionaries = fbb.create_vector(&self.dictionary_blocks);
-        let record_batches = fbb.create_vector(&self.record_blocks);
-        let schema = crate::convert::schema_to_fb_offset(&mut fbb, &self.schema);
-
-        let root = {
-            let mut footer_builder = crate::FooterBuilder::new(&mut fbb);
-            footer_builder.add_version(self.write_options.metadata_version);
-            footer_builder.add_schema(schema);
-            footer_builder.add_dictionaries(dictionaries);
-            footer_builder.add_recordBatches(record_batches);
-            footer_builder.finish()
-        };
-        fbb.finish(root, None);
-        let footer_data = fbb.finished_data();
-        self.writer.write_all(footer_data)?;
-        self.writer
-            .write_all(&(footer_data.len() as i32).to_le_bytes())?;
-        self.writer.write_all(&super::ARROW_MAGIC)?;
+        let footer = encode_footer(
+            &self.schema,
+            &self.dictionary_blocks,
+            &self.record_blocks,
+            &self.write_options,
+        );
+        self.writer.write_all(&footer)?;
         self.writer.flush()?;
         self.finished = true;
 
@@ -779,22 +1114,39 @@ impl<W: Write> StreamWriter<W> {
 
     /// Write a record batch to the stream
     pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write_with_metadata(batch, &HashMap::new())
+    }
+
+    /// Write a record batch to the stream, attaching `custom_metadata` to its
+    /// record batch message
+    pub fn write_with_metadata(
+        &mut self,
+        batch: &RecordBatch,
+        custom_metadata: &HashMap<String, String>,
+    ) -> Result<(), ArrowError> {
         if self.finished {
             return Err(ArrowError::IoError(
                 "Cannot write record batch to stream writer as it is closed".to_string(),
             ));
         }
 
-        let (encoded_dictionaries, encoded_message) = self
+        let (encoded_dictionaries, encoded_messages) = self
             .data_gen
-            .encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)
+            .encoded_batches_with_metadata(
+                batch,
+                &mut self.dictionary_tracker,
+                &self.write_options,
+                custom_metadata,
+            )
             .expect("StreamWriter is configured to not error on dictionary replacement");
 
         for encoded_dictionary in encoded_dictionaries {
             write_message(&mut self.writer, encoded_dictionary, &self.write_options)?;
         }
 
-        write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        for encoded_message in encoded_messages {
+            write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        }
         Ok(())
     }
 
@@ -860,6 +1212,40 @@ impl<W: Write> StreamWriter<W> {
     }
 }
 
+/// Encode the footer of an IPC file, followed by its length and the closing magic
+pub(crate) fn encode_footer(
+    schema: &Schema,
+    dictionary_blocks: &[crate::Block],
+    record_blocks: &[crate::Block],
+    write_options: &IpcWriteOptions,
+) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+    let dictionaries = fbb.create_vector(dictionary_blocks);
+    let record_batches = fbb.create_vector(record_blocks);
+    let schema = crate::convert::schema_to_fb_offset_with_endianness(
+        &mut fbb,
+        schema,
+        write_options.endianness,
+    );
+
+    let root = {
+        let mut footer_builder = crate::FooterBuilder::new(&mut fbb);
+        footer_builder.add_version(write_options.metadata_version);
+        footer_builder.add_schema(schema);
+        footer_builder.add_dictionaries(dictionaries);
+        footer_builder.add_recordBatches(record_batches);
+        footer_builder.finish()
+    };
+    fbb.finish(root, None);
+    let footer_data = fbb.finished_data();
+
+    let mut footer = Vec::with_capacity(footer_data.len() + 4 + super::ARROW_MAGIC.len());
+    footer.extend_from_slice(footer_data);
+    footer.extend_from_slice(&(footer_data.len() as i32).to_le_bytes());
+    footer.extend_from_slice(&super::ARROW_MAGIC);
+    footer
+}
+
 /// Stores the encoded data, which is an crate::Message, and optional Arrow data
 pub struct EncodedData {
     /// An encoded crate::Message
@@ -880,15 +1266,10 @@ pub fn write_message<W: Write>(
         ));
     }
 
-    let a = write_options.alignment - 1;
     let buffer = encoded.ipc_message;
     let flatbuf_size = buffer.len();
-    let prefix_size = if write_options.write_legacy_ipc_format {
-        4
-    } else {
-        8
-    };
-    let aligned_size = (flatbuf_size + prefix_size + a) & !a;
+    let prefix_size = prefix_len(write_options);
+    let aligned_size = framed_metadata_len(flatbuf_size, write_options);
     let padding_bytes = aligned_size - flatbuf_size - prefix_size;
 
     write_continuation(
@@ -914,6 +1295,21 @@ pub fn write_message<W: Write>(
     Ok((aligned_size, body_len))
 }
 
+/// The length of the continuation marker and length prefix written before a message
+fn prefix_len(write_options: &IpcWriteOptions) -> usize {
+    match write_options.write_legacy_ipc_format {
+        true => 4,
+        false => 8,
+    }
+}
+
+/// The number of bytes [`write_message`] writes for message metadata of
+/// `flatbuf_size` bytes, including the length prefix and padding
+fn framed_metadata_len(flatbuf_size: usize, write_options: &IpcWriteOptions) -> usize {
+    let len = flatbuf_size + prefix_len(write_options);
+    len + pad_to_alignment(write_options.alignment, len)
+}
+
 fn write_body_buffers<W: Write>(mut writer: W, data: &[u8]) -> Result<usize, ArrowError> {
     let len = data.len() as u32;
     let pad_len = pad_to_8(len) as u32;
@@ -931,7 +1327,7 @@ fn write_body_buffers<W: Write>(mut writer: W, data: &[u8]) -> Result<usize, Arr
 
 /// Write a record batch to the writer, writing the message size before the message
 /// if the record batch is being written to a stream
-fn write_continuation<W: Write>(
+pub(crate) fn write_continuation<W: Write>(
     mut writer: W,
     write_options: &IpcWriteOptions,
     total_len: i32,
@@ -1126,6 +1522,7 @@ fn write_array_data(
             arrow_data,
             offset,
             compression_codec,
+            write_options.buffer_alignment,
         )?;
     }
 
@@ -1159,12 +1556,14 @@ fn write_array_data(
                     )
                 };
 
+            let swapped = swap_for_write(data_type, 0, &new_offsets, write_options);
             offset = write_buffer(
-                new_offsets.as_slice(),
+                swapped.as_deref().unwrap_or(&new_offsets),
                 buffers,
                 arrow_data,
                 offset,
                 compression_codec,
+                write_options.buffer_alignment,
             )?;
 
             let total_bytes = get_binary_buffer_len(array_data);
@@ -1178,15 +1577,18 @@ fn write_array_data(
                 arrow_data,
                 offset,
                 compression_codec,
+                write_options.buffer_alignment,
             )?;
         } else {
-            for buffer in array_data.buffers() {
+            for (index, buffer) in array_data.buffers().iter().enumerate() {
+                let swapped = swap_for_write(data_type, index, buffer, write_options);
                 offset = write_buffer(
-                    buffer.as_slice(),
+                    swapped.as_deref().unwrap_or(buffer),
                     buffers,
                     arrow_data,
                     offset,
                     compression_codec,
+                    write_options.buffer_alignment,
                 )?;
             }
         }
@@ -1211,20 +1613,24 @@ fn write_array_data(
             let buffer_length = min(min_length, buffer.len() - byte_offset);
             let buffer_slice =
                 &buffer.as_slice()[byte_offset..(byte_offset + buffer_length)];
+            let swapped = swap_for_write(data_type, 0, buffer_slice, write_options);
             offset = write_buffer(
-                buffer_slice,
+                swapped.as_deref().unwrap_or(buffer_slice),
                 buffers,
                 arrow_data,
                 offset,
                 compression_codec,
+                write_options.buffer_alignment,
             )?;
         } else {
+            let swapped = swap_for_write(data_type, 0, buffer, write_options);
             offset = write_buffer(
-                buffer.as_slice(),
+                swapped.as_deref().unwrap_or(buffer),
                 buffers,
                 arrow_data,
                 offset,
                 compression_codec,
+                write_options.buffer_alignment,
             )?;
         }
     } else if matches!(data_type, DataType::Boolean) {
@@ -1234,11 +1640,25 @@ fn write_array_data(
 
         let buffer = &array_data.buffers()[0];
         let buffer = buffer.bit_slice(array_data.offset(), array_data.len());
-        offset = write_buffer(&buffer, buffers, arrow_data, offset, compression_codec)?;
+        offset = write_buffer(
+            &buffer,
+            buffers,
+            arrow_data,
+            offset,
+            compression_codec,
+            write_options.buffer_alignment,
+        )?;
     } else {
-        for buffer in array_data.buffers() {
-            offset =
-                write_buffer(buffer, buffers, arrow_data, offset, compression_codec)?;
+        for (index, buffer) in array_data.buffers().iter().enumerate() {
+            let swapped = swap_for_write(data_type, index, buffer, write_options);
+            offset = write_buffer(
+                swapped.as_deref().unwrap_or(buffer),
+                buffers,
+                arrow_data,
+                offset,
+                compression_codec,
+                write_options.buffer_alignment,
+            )?;
         }
     }
 
@@ -1263,6 +1683,20 @@ fn write_array_data(
     Ok(offset)
 }
 
+/// Byte swaps the data buffer at `index` of an array of `data_type`, excluding its
+/// validity buffer, if it is written in non-native endianness
+fn swap_for_write(
+    data_type: &DataType,
+    index: usize,
+    buffer: &[u8],
+    write_options: &IpcWriteOptions,
+) -> Option<Buffer> {
+    if write_options.endianness == NATIVE_ENDIANNESS {
+        return None;
+    }
+    swap_buffer(data_type, index, buffer)
+}
+
 /// Write a buffer into `arrow_data`, a vector of bytes, and adds its
 /// [`crate::Buffer`] to `buffers`. Returns the new offset in `arrow_data`
 ///
@@ -1281,6 +1715,7 @@ fn write_buffer(
     arrow_data: &mut Vec<u8>,         // output stream
     offset: i64,                      // current output stream offset
     compression_codec: Option<CompressionCodec>,
+    alignment: usize, // the multiple of bytes to pad the buffer to
 ) -> Result<i64, ArrowError> {
     let len: i64 = match compression_codec {
         Some(compressor) => compressor.compress_to_vec(buffer, arrow_data)?,
@@ -1298,8 +1733,8 @@ fn write_buffer(
 
     // make new index entry
     buffers.push(crate::Buffer::new(offset, len));
-    // padding and make offset 8 bytes aligned
-    let pad_len = pad_to_8(len as u32) as i64;
+    // padding and make offset aligned
+    let pad_len = pad_to_alignment(alignment, len as usize) as i64;
     arrow_data.extend_from_slice(&vec![0u8; pad_len as usize][..]);
 
     Ok(offset + len + pad_len)
@@ -1311,6 +1746,12 @@ fn pad_to_8(len: u32) -> usize {
     (((len + 7) & !7) - len) as usize
 }
 
+/// Return the number of bytes needed to pad `len` to a multiple of `alignment`
+#[inline]
+fn pad_to_alignment(alignment: usize, len: usize) -> usize {
+    (alignment - len % alignment) % alignment
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1895,6 +2336,202 @@ mod tests {
         assert_eq!(record_batch_slice, deserialized_batch);
     }
 
+    #[test]
+    fn test_stream_writer_tracks_dictionaries_by_value() {
+        let batch = |values: Vec<&str>| {
+            let dict: DictionaryArray<Int32Type> = values.into_iter().collect();
+            RecordBatch::try_from_iter([("a", Arc::new(dict) as ArrayRef)]).unwrap()
+        };
+
+        let data_gen = IpcDataGenerator::default();
+        let options = IpcWriteOptions::default();
+        let mut tracker = DictionaryTracker::new(false);
+        let mut num_dictionaries = |batch: &RecordBatch| {
+            let (dictionaries, _) = data_gen
+                .encoded_batch(batch, &mut tracker, &options)
+                .unwrap();
+            dictionaries.len()
+        };
+
+        assert_eq!(num_dictionaries(&batch(vec!["a", "b", "a"])), 1);
+        // an identical dictionary in different buffers is not resent
+        assert_eq!(num_dictionaries(&batch(vec!["a", "b", "b"])), 0);
+        // a changed dictionary is sent as a replacement
+        assert_eq!(num_dictionaries(&batch(vec!["c", "a"])), 1);
+        assert_eq!(num_dictionaries(&batch(vec!["c", "a"])), 0);
+
+        let batches = vec![
+            batch(vec!["a", "b"]),
+            batch(vec!["a", "b"]),
+            batch(vec!["c"]),
+            batch(vec!["a", "b"]),
+        ];
+        let mut writer = StreamWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let buffer = writer.into_inner().unwrap();
+        let reader = StreamReader::try_new(buffer.as_slice(), None).unwrap();
+        let read: Vec<_> = reader.map(|b| b.unwrap()).collect();
+        assert_eq!(read, batches);
+    }
+
+    #[test]
+    fn test_write_splits_batches_exceeding_max_message_size() {
+        let ints = Int32Array::from_iter_values(0..1000);
+        let strings = StringArray::from_iter_values((0..1000).map(|i| format!("v{i}")));
+        let batch = RecordBatch::try_from_iter([
+            ("ints", Arc::new(ints) as ArrayRef),
+            ("strings", Arc::new(strings) as ArrayRef),
+        ])
+        .unwrap();
+
+        let max_message_size = 2048;
+        let options = IpcWriteOptions::default().with_max_message_size(max_message_size);
+
+        let data_gen = IpcDataGenerator::default();
+        let mut tracker = DictionaryTracker::new(false);
+        let (_, encoded) = data_gen
+            .encoded_batches(&batch, &mut tracker, &options)
+            .unwrap();
+        assert!(encoded.len() > 1);
+        for message in &encoded {
+            let mut framed = vec![];
+            let message = EncodedData {
+                ipc_message: message.ipc_message.clone(),
+                arrow_data: message.arrow_data.clone(),
+            };
+            write_message(&mut framed, message, &options).unwrap();
+            assert!(framed.len() <= max_message_size);
+        }
+
+        // the split batches read back as consecutive slices of the original
+        let assert_slices = |read: Vec<RecordBatch>| {
+            assert_eq!(read.len(), encoded.len());
+            let mut offset = 0;
+            for read_batch in read {
+                assert_eq!(read_batch, batch.slice(offset, read_batch.num_rows()));
+                offset += read_batch.num_rows();
+            }
+            assert_eq!(offset, batch.num_rows());
+        };
+
+        let mut writer =
+            StreamWriter::try_new_with_options(vec![], &batch.schema(), options.clone())
+                .unwrap();
+        writer.write(&batch).unwrap();
+        let buffer = writer.into_inner().unwrap();
+        let reader = StreamReader::try_new(buffer.as_slice(), None).unwrap();
+        assert_slices(reader.map(|b| b.unwrap()).collect());
+
+        let mut writer =
+            FileWriter::try_new_with_options(vec![], &batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        let buffer = writer.into_inner().unwrap();
+        let reader = FileReader::try_new(Cursor::new(buffer), None).unwrap();
+        assert_slices(reader.map(|b| b.unwrap()).collect());
+
+        // a single row is never split
+        let options = IpcWriteOptions::default().with_max_message_size(1);
+        let (_, encoded) = data_gen
+            .encoded_batches(&batch.slice(0, 3), &mut tracker, &options)
+            .unwrap();
+        assert_eq!(encoded.len(), 3);
+    }
+
+    #[test]
+    fn test_write_custom_message_metadata() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let batch = RecordBatch::try_from_iter([("a", a)]).unwrap();
+        let metadata = HashMap::from([("key".to_string(), "value".to_string())]);
+
+        let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write_with_metadata(&batch, &metadata).unwrap();
+        writer.write(&batch).unwrap();
+        let buffer = writer.into_inner().unwrap();
+
+        let mut reader = StreamReader::try_new(buffer.as_slice(), None).unwrap();
+        assert!(reader.custom_metadata().is_empty());
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert_eq!(reader.custom_metadata(), &metadata);
+        assert_eq!(reader.next().unwrap().unwrap(), batch);
+        assert!(reader.custom_metadata().is_empty());
+
+        let mut writer = FileWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write_with_metadata(&batch, &metadata).unwrap();
+        let buffer = writer.into_inner().unwrap();
+
+        let mut reader = FileReader::try_new(Cursor::new(buffer.clone()), None).unwrap();
+        assert!(reader.batch_custom_metadata(0).unwrap().is_empty());
+        assert_eq!(reader.batch_custom_metadata(1).unwrap(), metadata);
+        assert!(reader.batch_custom_metadata(2).is_err());
+        assert_eq!(reader.read_batch(1).unwrap(), batch);
+
+        let reader =
+            BufferFileReader::try_new(Buffer::from_slice_ref(&buffer), None).unwrap();
+        assert!(reader.batch_custom_metadata(0).unwrap().is_empty());
+        assert_eq!(reader.batch_custom_metadata(1).unwrap(), metadata);
+    }
+
+    #[test]
+    fn test_write_buffer_alignment() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "bc", "d"]));
+        let batch = RecordBatch::try_from_iter([("a", a), ("b", b)]).unwrap();
+
+        // buffers are padded to 8 bytes by default
+        let options = IpcWriteOptions::default();
+        let (_, encoded) = IpcDataGenerator::default()
+            .encoded_batch(&batch, &mut DictionaryTracker::new(false), &options)
+            .unwrap();
+        assert_eq!(encoded.arrow_data.len(), 56);
+
+        // the metadata alignment does not change the buffer alignment
+        let options = IpcWriteOptions::default().try_with_alignment(8).unwrap();
+        let (_, encoded) = IpcDataGenerator::default()
+            .encoded_batch(&batch, &mut DictionaryTracker::new(false), &options)
+            .unwrap();
+        assert_eq!(encoded.arrow_data.len(), 56);
+
+        for alignment in [8, 24, 64] {
+            let options = IpcWriteOptions::default()
+                .try_with_alignment(alignment)
+                .unwrap()
+                .try_with_buffer_alignment(alignment)
+                .unwrap();
+            let data_gen = IpcDataGenerator::default();
+            let mut tracker = DictionaryTracker::new(false);
+            let (_, encoded) = data_gen
+                .encoded_batch(&batch, &mut tracker, &options)
+                .unwrap();
+            assert_eq!(encoded.arrow_data.len() % alignment, 0);
+
+            let message = crate::root_as_message(&encoded.ipc_message).unwrap();
+            let buffers = message.header_as_record_batch().unwrap().buffers().unwrap();
+            for buffer in buffers {
+                assert_eq!(buffer.offset() as usize % alignment, 0);
+            }
+
+            let mut writer =
+                FileWriter::try_new_with_options(vec![], &batch.schema(), options)
+                    .unwrap();
+            writer.write(&batch).unwrap();
+            let buffer = writer.into_inner().unwrap();
+            let mut reader = FileReader::try_new(Cursor::new(buffer), None).unwrap();
+            for block in reader.blocks() {
+                assert_eq!(block.offset() as usize % 8, 0);
+                assert_eq!(block.metaDataLength() as usize % alignment, 0);
+            }
+            assert_eq!(reader.next().unwrap().unwrap(), batch);
+        }
+
+        let options = IpcWriteOptions::default();
+        assert!(options.clone().try_with_alignment(0).is_err());
+        assert!(options.clone().try_with_alignment(4).is_err());
+        assert!(options.try_with_buffer_alignment(12).is_err());
+    }
+
     #[test]
     fn test_stream_writer_writes_array_slice() {
         let array = UInt32Array::from(vec![Some(1), Some(2), Some(3)]);
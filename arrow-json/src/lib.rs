@@ -18,6 +18,16 @@
 //! Transfer data between the Arrow memory format and JSON
 //! line-delimited records. See the module level documentation for the
 //! [`reader`] and [`writer`] for usage examples.
+//!
+//! There are two JSON readers:
+//!
+//! * [`RawReader`] and the push-based [`RawDecoder`], built by [`RawReaderBuilder`],
+//!   decode bytes directly into arrays without intermediate [`serde_json::Value`]s.
+//!   They require a schema
+//! * [`Reader`], built by [`ReaderBuilder`], can additionally infer the schema and
+//!   apply projections. It decodes with a [`RawDecoder`], unless format strings are
+//!   provided or it is created with [`Reader::new`], in which case each record is
+//!   first parsed into a [`serde_json::Value`]
 
 #![deny(rustdoc::broken_intra_doc_links)]
 #![warn(missing_docs)]
@@ -27,9 +37,13 @@ pub mod writer;
 
 mod raw;
 
+#[cfg(feature = "async")]
+pub use self::raw::AsyncRawReader;
 pub use self::raw::{RawDecoder, RawReader, RawReaderBuilder};
 pub use self::reader::{Reader, ReaderBuilder};
-pub use self::writer::{ArrayWriter, LineDelimitedWriter, Writer};
+pub use self::writer::{
+    ArrayWriter, BinaryEncoding, Encoder, LineDelimitedWriter, Writer, WriterBuilder,
+};
 use half::f16;
 use serde_json::{Number, Value};
 
@@ -0,0 +1,167 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! An asynchronous JSON reader, see [`AsyncRawReader`]
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::ready;
+use futures::stream::Stream;
+use tokio::io::AsyncBufRead;
+
+use crate::raw::RawDecoder;
+
+/// Reads JSON data with a known schema from an [`AsyncBufRead`] into arrow [`RecordBatch`]
+///
+/// This is the asynchronous counterpart of [`RawReader`], yielding a [`Stream`] of
+/// [`RecordBatch`] decoded using a [`RawDecoder`]. Lines consisting solely of ASCII
+/// whitespace are ignored
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_json::RawReaderBuilder;
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use futures::TryStreamExt;
+/// #
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+/// let data: &[u8] = b"{\"a\": 1}\n{\"a\": 2}\n";
+///
+/// let stream = RawReaderBuilder::new(schema).build_async(data).unwrap();
+/// let batches: Vec<_> = stream.try_collect().await.unwrap();
+/// assert_eq!(batches[0].num_rows(), 2);
+/// # }
+/// ```
+///
+/// [`RawReader`]: crate::RawReader
+pub struct AsyncRawReader<R> {
+    reader: R,
+    decoder: RawDecoder,
+}
+
+impl<R> std::fmt::Debug for AsyncRawReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncRawReader")
+            .field("decoder", &self.decoder)
+            .finish()
+    }
+}
+
+impl<R> AsyncRawReader<R> {
+    pub(crate) fn new(reader: R, decoder: RawDecoder) -> Self {
+        Self { reader, decoder }
+    }
+
+    /// Returns the schema of the [`RecordBatch`] yielded by this stream
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> AsyncRawReader<R> {
+    /// Polls the next [`RecordBatch`] returning `Ok(None)` if EOF
+    fn poll_read(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<RecordBatch>, ArrowError>> {
+        loop {
+            let buf = ready!(Pin::new(&mut self.reader).poll_fill_buf(cx))?;
+            if buf.is_empty() {
+                break;
+            }
+            let read = buf.len();
+
+            let decoded = self.decoder.decode(buf)?;
+            Pin::new(&mut self.reader).consume(decoded);
+            if decoded != read {
+                break;
+            }
+        }
+        Poll::Ready(self.decoder.flush())
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Stream for AsyncRawReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.poll_read(cx).map(Result::transpose)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RawReaderBuilder;
+    use arrow_schema::{DataType, Field, Schema};
+    use futures::TryStreamExt;
+    use std::io::Cursor;
+    use std::sync::Arc;
+    use tokio::io::BufReader;
+
+    #[tokio::test]
+    async fn test_async_reader() {
+        let buf = r#"
+        {"a": 1, "b": "foo"}
+        {"a": 2, "b": null}
+
+        {"a": null, "b": "bar"}
+        {"a": 4}
+        {"a": 5, "b": "baz"}
+        "#;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+
+        let expected = RawReaderBuilder::new(schema.clone())
+            .with_batch_size(2)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(expected.len(), 3);
+
+        // Test with different buffer sizes to test for boundary conditions
+        for capacity in [1, 3, 7, 1024] {
+            let reader = BufReader::with_capacity(capacity, buf.as_bytes());
+            let stream = RawReaderBuilder::new(schema.clone())
+                .with_batch_size(2)
+                .build_async(reader)
+                .unwrap();
+            assert_eq!(stream.schema(), schema);
+
+            let batches: Vec<_> = stream.try_collect().await.unwrap();
+            assert_eq!(batches, expected);
+        }
+
+        let stream = RawReaderBuilder::new(schema)
+            .build_async(&b"{\"a\": \"foo\"}"[..])
+            .unwrap();
+        let err = stream.try_collect::<Vec<_>>().await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: failed to parse \"foo\" as Int64"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use arrow_array::make_array;
+use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, DataType};
+
+use crate::raw::tape::Tape;
+use crate::raw::{make_decoder, ArrayDecoder};
+
+/// Decodes the values of a dictionary, before encoding them as a dictionary
+pub struct DictionaryArrayDecoder {
+    data_type: DataType,
+    decoder: Box<dyn ArrayDecoder>,
+}
+
+impl DictionaryArrayDecoder {
+    pub fn new(
+        data_type: DataType,
+        coerce_mixed: bool,
+        is_nullable: bool,
+    ) -> Result<Self, ArrowError> {
+        let decoder = match &data_type {
+            DataType::Dictionary(key_type, value_type)
+                if key_type.is_dictionary_key_type() =>
+            {
+                make_decoder(value_type.as_ref().clone(), coerce_mixed, is_nullable)?
+            }
+            d => {
+                return Err(ArrowError::JsonError(format!(
+                    "{d} is not supported by JSON"
+                )))
+            }
+        };
+        Ok(Self { data_type, decoder })
+    }
+}
+
+impl ArrayDecoder for DictionaryArrayDecoder {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError> {
+        let values = make_array(self.decoder.decode(tape, pos)?);
+        Ok(arrow_cast::cast(&values, &self.data_type)?.data().clone())
+    }
+}
@@ -31,13 +31,18 @@ pub struct ListArrayDecoder<O> {
 }
 
 impl<O: OffsetSizeTrait> ListArrayDecoder<O> {
-    pub fn new(data_type: DataType, is_nullable: bool) -> Result<Self, ArrowError> {
+    pub fn new(
+        data_type: DataType,
+        coerce_mixed: bool,
+        is_nullable: bool,
+    ) -> Result<Self, ArrowError> {
         let field = match &data_type {
             DataType::List(f) if !O::IS_LARGE => f,
             DataType::LargeList(f) if O::IS_LARGE => f,
             _ => unreachable!(),
         };
-        let decoder = make_decoder(field.data_type().clone(), field.is_nullable())?;
+        let decoder =
+            make_decoder(field.data_type().clone(), coerce_mixed, field.is_nullable())?;
 
         Ok(Self {
             data_type,
@@ -100,7 +105,7 @@ impl<O: OffsetSizeTrait> ArrayDecoder for ListArrayDecoder<O> {
             offsets.append(offset)
         }
 
-        let child_data = self.decoder.decode(tape, &child_pos).unwrap();
+        let child_data = self.decoder.decode(tape, &child_pos)?;
 
         let data = ArrayDataBuilder::new(self.data_type.clone())
             .len(pos.len())
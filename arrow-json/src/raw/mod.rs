@@ -20,27 +20,43 @@
 //! [`Reader`]: crate::reader::Reader
 
 use crate::raw::boolean_array::BooleanArrayDecoder;
+use crate::raw::decimal_array::DecimalArrayDecoder;
+use crate::raw::dictionary_array::DictionaryArrayDecoder;
 use crate::raw::list_array::ListArrayDecoder;
+use crate::raw::map_array::MapArrayDecoder;
+use crate::raw::null_array::NullArrayDecoder;
 use crate::raw::primitive_array::PrimitiveArrayDecoder;
 use crate::raw::string_array::StringArrayDecoder;
 use crate::raw::struct_array::StructArrayDecoder;
 use crate::raw::tape::{Tape, TapeDecoder, TapeElement};
 use arrow_array::types::*;
-use arrow_array::{downcast_integer, make_array, RecordBatch, RecordBatchReader};
+use arrow_array::{
+    downcast_integer, make_array, RecordBatch, RecordBatchOptions, RecordBatchReader,
+};
 use arrow_data::ArrayData;
-use arrow_schema::{ArrowError, DataType, SchemaRef};
+use arrow_schema::{ArrowError, DataType, SchemaRef, TimeUnit};
 use std::io::BufRead;
 
+#[cfg(feature = "async")]
+mod async_reader;
 mod boolean_array;
+mod decimal_array;
+mod dictionary_array;
 mod list_array;
+mod map_array;
+mod null_array;
 mod primitive_array;
 mod string_array;
 mod struct_array;
 mod tape;
 
+#[cfg(feature = "async")]
+pub use async_reader::AsyncRawReader;
+
 /// A builder for [`RawReader`] and [`RawDecoder`]
 pub struct RawReaderBuilder {
     batch_size: usize,
+    coerce_mixed: bool,
 
     schema: SchemaRef,
 }
@@ -56,6 +72,7 @@ impl RawReaderBuilder {
     pub fn new(schema: SchemaRef) -> Self {
         Self {
             batch_size: 1024,
+            coerce_mixed: false,
             schema,
         }
     }
@@ -65,6 +82,22 @@ impl RawReaderBuilder {
         Self { batch_size, ..self }
     }
 
+    /// Sets whether `Utf8` and `LargeUtf8` columns accept values of any JSON type,
+    /// defaults to `false`
+    ///
+    /// When enabled, values other than strings, such as numbers, booleans, objects
+    /// and lists, are stored as their JSON text, e.g. `{"a":1}`. This allows loading
+    /// fields with inconsistent types, such as inferred with [`TypeWidening::Utf8`],
+    /// which would otherwise return an error
+    ///
+    /// [`TypeWidening::Utf8`]: crate::reader::TypeWidening::Utf8
+    pub fn with_coerce_mixed(self, coerce_mixed: bool) -> Self {
+        Self {
+            coerce_mixed,
+            ..self
+        }
+    }
+
     /// Create a [`RawReader`] with the provided [`BufRead`]
     pub fn build<R: BufRead>(self, reader: R) -> Result<RawReader<R>, ArrowError> {
         Ok(RawReader {
@@ -73,9 +106,24 @@ impl RawReaderBuilder {
         })
     }
 
+    /// Create an [`AsyncRawReader`] with the provided [`AsyncBufRead`]
+    ///
+    /// [`AsyncBufRead`]: tokio::io::AsyncBufRead
+    #[cfg(feature = "async")]
+    pub fn build_async<R>(self, reader: R) -> Result<AsyncRawReader<R>, ArrowError>
+    where
+        R: tokio::io::AsyncBufRead + Unpin,
+    {
+        Ok(AsyncRawReader::new(reader, self.build_decoder()?))
+    }
+
     /// Create a [`RawDecoder`]
     pub fn build_decoder(self) -> Result<RawDecoder, ArrowError> {
-        let decoder = make_decoder(DataType::Struct(self.schema.fields.clone()), false)?;
+        let decoder = make_decoder(
+            DataType::Struct(self.schema.fields.clone()),
+            self.coerce_mixed,
+            false,
+        )?;
         let num_fields = self.schema.all_fields().len();
 
         Ok(RawDecoder {
@@ -199,6 +247,11 @@ impl std::fmt::Debug for RawDecoder {
 }
 
 impl RawDecoder {
+    /// Returns the schema of the decoded [`RecordBatch`]
+    pub(crate) fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
     /// Read JSON objects from `buf`, returning the number of bytes read
     ///
     /// This method returns once `batch_size` objects have been parsed since the
@@ -250,7 +303,10 @@ impl RawDecoder {
             .map(|x| make_array(x.clone()))
             .collect();
 
-        let batch = RecordBatch::try_new(self.schema.clone(), columns)?;
+        // The row count is required to decode a schema without fields
+        let options = RecordBatchOptions::new().with_row_count(Some(pos.len()));
+        let batch =
+            RecordBatch::try_new_with_options(self.schema.clone(), columns, &options)?;
         Ok(Some(batch))
     }
 }
@@ -268,18 +324,34 @@ macro_rules! primitive_decoder {
 
 fn make_decoder(
     data_type: DataType,
+    coerce_mixed: bool,
     is_nullable: bool,
 ) -> Result<Box<dyn ArrayDecoder>, ArrowError> {
     downcast_integer! {
         data_type => (primitive_decoder, data_type),
         DataType::Float32 => primitive_decoder!(Float32Type, data_type),
         DataType::Float64 => primitive_decoder!(Float64Type, data_type),
+        DataType::Timestamp(TimeUnit::Second, _) => primitive_decoder!(TimestampSecondType, data_type),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => primitive_decoder!(TimestampMillisecondType, data_type),
+        DataType::Timestamp(TimeUnit::Microsecond, _) => primitive_decoder!(TimestampMicrosecondType, data_type),
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => primitive_decoder!(TimestampNanosecondType, data_type),
+        DataType::Date32 => primitive_decoder!(Date32Type, data_type),
+        DataType::Date64 => primitive_decoder!(Date64Type, data_type),
+        DataType::Time32(TimeUnit::Second) => primitive_decoder!(Time32SecondType, data_type),
+        DataType::Time32(TimeUnit::Millisecond) => primitive_decoder!(Time32MillisecondType, data_type),
+        DataType::Time64(TimeUnit::Microsecond) => primitive_decoder!(Time64MicrosecondType, data_type),
+        DataType::Time64(TimeUnit::Nanosecond) => primitive_decoder!(Time64NanosecondType, data_type),
+        DataType::Decimal128(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal128Type>::new(p, s))),
+        DataType::Decimal256(p, s) => Ok(Box::new(DecimalArrayDecoder::<Decimal256Type>::new(p, s))),
+        DataType::Null => Ok(Box::<NullArrayDecoder>::default()),
         DataType::Boolean => Ok(Box::<BooleanArrayDecoder>::default()),
-        DataType::Utf8 => Ok(Box::<StringArrayDecoder::<i32>>::default()),
-        DataType::LargeUtf8 => Ok(Box::<StringArrayDecoder::<i64>>::default()),
-        DataType::List(_) => Ok(Box::new(ListArrayDecoder::<i32>::new(data_type, is_nullable)?)),
-        DataType::LargeList(_) => Ok(Box::new(ListArrayDecoder::<i64>::new(data_type, is_nullable)?)),
-        DataType::Struct(_) => Ok(Box::new(StructArrayDecoder::new(data_type, is_nullable)?)),
+        DataType::Utf8 => Ok(Box::new(StringArrayDecoder::<i32>::new(coerce_mixed))),
+        DataType::LargeUtf8 => Ok(Box::new(StringArrayDecoder::<i64>::new(coerce_mixed))),
+        DataType::List(_) => Ok(Box::new(ListArrayDecoder::<i32>::new(data_type, coerce_mixed, is_nullable)?)),
+        DataType::LargeList(_) => Ok(Box::new(ListArrayDecoder::<i64>::new(data_type, coerce_mixed, is_nullable)?)),
+        DataType::Struct(_) => Ok(Box::new(StructArrayDecoder::new(data_type, coerce_mixed, is_nullable)?)),
+        DataType::Map(_, _) => Ok(Box::new(MapArrayDecoder::new(data_type, coerce_mixed, is_nullable)?)),
+        DataType::Dictionary(_, _) => Ok(Box::new(DictionaryArrayDecoder::new(data_type, coerce_mixed, is_nullable)?)),
         DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => {
             Err(ArrowError::JsonError(format!("{data_type} is not supported by JSON")))
         }
@@ -294,15 +366,15 @@ fn tape_error(d: TapeElement, expected: &str) -> ArrowError {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::reader::infer_json_schema;
-    use crate::ReaderBuilder;
+    use crate::reader::{infer_json_schema, DecoderOptions, Reader};
     use arrow_array::cast::{
-        as_boolean_array, as_largestring_array, as_list_array, as_primitive_array,
-        as_string_array, as_struct_array,
+        as_boolean_array, as_dictionary_array, as_largestring_array, as_list_array,
+        as_primitive_array, as_string_array, as_struct_array,
     };
     use arrow_array::types::Int32Type;
-    use arrow_array::Array;
-    use arrow_schema::{DataType, Field, Schema};
+    use arrow_array::{Array, LargeStringArray, MapArray, StringArray};
+    use arrow_buffer::i256;
+    use arrow_schema::{DataType, Field, Schema, TimeUnit};
     use std::fs::File;
     use std::io::{BufReader, Cursor, Seek};
     use std::sync::Arc;
@@ -377,6 +449,28 @@ mod tests {
         assert!(!col3.is_null(1));
     }
 
+    #[test]
+    fn test_large_integers() {
+        let buf = r#"
+        {"a": 18446744073709551615, "b": 9223372036854775807}
+        {"a": 9223372036854775808, "b": -9223372036854775808}
+        "#;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::UInt64, true),
+            Field::new("b", DataType::Int64, true),
+        ]));
+
+        let batches = do_read(buf, 1024, schema);
+        assert_eq!(batches.len(), 1);
+
+        let col1 = as_primitive_array::<UInt64Type>(batches[0].column(0));
+        assert_eq!(col1.values(), &[u64::MAX, 1 << 63]);
+
+        let col2 = as_primitive_array::<Int64Type>(batches[0].column(1));
+        assert_eq!(col2.values(), &[i64::MAX, i64::MIN]);
+    }
+
     #[test]
     fn test_string() {
         let buf = r#"
@@ -417,8 +511,8 @@ mod tests {
     fn test_complex() {
         let buf = r#"
            {"list": [], "nested": {"a": 1, "b": 2}, "nested_list": {"list2": [{"c": 3}, {"c": 4}]}}
-           {"list": [5, 6], "nested": {"a": 7}, "nested_list": {"list2": []}}
-           {"list": null, "nested": {"a": null}}
+           {"list": [5, 6], "nested": {"a": 7, "b": 8}, "nested_list": {"list2": []}}
+           {"list": null, "nested": null}
         "#;
 
         let schema = Arc::new(Schema::new(vec![
@@ -450,7 +544,7 @@ mod tests {
             ),
         ]));
 
-        let batches = do_read(buf, 1024, schema);
+        let batches = do_read(buf, 1024, schema.clone());
         assert_eq!(batches.len(), 1);
 
         let list = as_list_array(batches[0].column(0).as_ref());
@@ -467,10 +561,10 @@ mod tests {
         assert!(list.is_null(2));
 
         let b = as_primitive_array::<Int32Type>(nested.column(1).as_ref());
-        assert_eq!(b.null_count(), 2);
+        assert_eq!(b.null_count(), 1);
         assert_eq!(b.len(), 3);
         assert_eq!(b.value(0), 2);
-        assert!(b.is_null(1));
+        assert_eq!(b.value(1), 8);
         assert!(b.is_null(2));
 
         let nested_list = as_struct_array(batches[0].column(2).as_ref());
@@ -483,6 +577,32 @@ mod tests {
 
         let c = as_primitive_array::<Int32Type>(list2_values.column(0));
         assert_eq!(c.values(), &[3, 4]);
+
+        // nested is not null, so its non-nullable children may not be null
+        let buf = r#"{"nested": {"a": 7}}"#;
+        let err = RawReaderBuilder::new(schema.clone())
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Encountered unmasked nulls in non-nullable StructArray child: b"
+        );
+
+        // the elements of list2 are not nullable, so neither are their children
+        let buf = r#"{"nested_list": {"list2": [{"c": 3}, {}]}}"#;
+        let err = RawReaderBuilder::new(schema)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Encountered unmasked nulls in non-nullable StructArray child: c"
+        );
     }
 
     #[test]
@@ -504,7 +624,7 @@ mod tests {
                     "list2",
                     DataType::List(Box::new(Field::new(
                         "element",
-                        DataType::Struct(vec![Field::new("d", DataType::Int32, false)]),
+                        DataType::Struct(vec![Field::new("d", DataType::Int32, true)]),
                         false,
                     ))),
                     true,
@@ -541,6 +661,280 @@ mod tests {
         assert!(c.is_null(1));
     }
 
+    #[test]
+    fn test_nested_nulls() {
+        let buf = r#"
+           {"outer": {"inner": {"a": 1}, "list": [{"b": 1}, null, {"b": null}], "map": {"x": 1, "y": null}}}
+           {"outer": {"inner": {}, "list": null, "map": null}}
+           {"outer": {"inner": {"a": null}, "list": [], "map": {}}}
+           {"outer": null}
+           {}
+        "#;
+
+        let map_type = DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("keys", DataType::Utf8, false),
+                    Field::new("values", DataType::Int32, true),
+                ]),
+                false,
+            )),
+            false,
+        );
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "outer",
+            DataType::Struct(vec![
+                Field::new(
+                    "inner",
+                    DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+                    false,
+                ),
+                Field::new(
+                    "list",
+                    DataType::List(Box::new(Field::new(
+                        "element",
+                        DataType::Struct(vec![Field::new("b", DataType::Int32, true)]),
+                        true,
+                    ))),
+                    true,
+                ),
+                Field::new("map", map_type.clone(), true),
+            ]),
+            true,
+        )]));
+
+        let batches = do_read(buf, 1024, schema.clone());
+        assert_eq!(batches.len(), 1);
+
+        let outer = as_struct_array(batches[0].column(0).as_ref());
+        assert_eq!(outer.len(), 5);
+        assert_eq!(outer.null_count(), 2);
+        assert!(outer.is_null(3));
+        assert!(outer.is_null(4));
+
+        // inner is not nullable, but is permitted to be null when outer is
+        let inner = as_struct_array(outer.column(0).as_ref());
+        assert_eq!(inner.null_count(), 2);
+        assert!(inner.is_valid(0));
+        assert!(inner.is_valid(1));
+        assert!(inner.is_valid(2));
+        assert!(inner.is_null(3));
+        let a = as_primitive_array::<Int32Type>(inner.column(0).as_ref());
+        assert_eq!(a.value(0), 1);
+        assert!(a.is_null(1));
+        assert!(a.is_null(2));
+
+        let list = as_list_array(outer.column(1).as_ref());
+        assert_eq!(list.value_offsets(), &[0, 3, 3, 3, 3, 3]);
+        assert_eq!(list.null_count(), 3);
+        assert!(list.is_null(1));
+        assert!(list.is_valid(2));
+        let elements = as_struct_array(list.values().as_ref());
+        assert_eq!(elements.null_count(), 1);
+        assert!(elements.is_null(1));
+        let b = as_primitive_array::<Int32Type>(elements.column(0).as_ref());
+        assert_eq!(b.value(0), 1);
+        assert!(b.is_null(2));
+
+        let map = batches[0].column(0).data().child_data()[2].clone();
+        assert_eq!(map.data_type(), &map_type);
+        let map = MapArray::from(map);
+        assert_eq!(map.value_offsets(), &[0, 2, 2, 2, 2, 2]);
+        assert_eq!(map.null_count(), 3);
+        assert!(map.is_null(1));
+        assert!(map.is_valid(2));
+        let keys = as_string_array(map.keys().as_ref());
+        assert_eq!(keys.value(0), "x");
+        assert_eq!(keys.value(1), "y");
+        let values = as_primitive_array::<Int32Type>(map.values().as_ref());
+        assert_eq!(values.value(0), 1);
+        assert!(values.is_null(1));
+
+        // inner may not be null where outer is not
+        let buf = r#"{"outer": {"inner": null}}"#;
+        let err = RawReaderBuilder::new(schema)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Encountered unmasked nulls in non-nullable StructArray child: inner"
+        );
+    }
+
+    #[test]
+    fn test_temporal_null_dictionary() {
+        let buf = r#"
+        {"a": "2022-01-01T00:00:00Z", "b": "2021-12-31", "c": "12:30:00", "d": null, "e": "foo"}
+        {"a": 1672531200000000001, "b": 19000, "c": null, "d": null, "e": "bar"}
+        {"a": null, "b": null, "c": 45000, "e": "foo"}
+        "#;
+
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Timestamp(TimeUnit::Nanosecond, None), true),
+            Field::new("b", DataType::Date32, true),
+            Field::new("c", DataType::Time32(TimeUnit::Second), true),
+            Field::new("d", DataType::Null, true),
+            Field::new("e", dict_type.clone(), true),
+        ]));
+
+        let batches = do_read(buf, 1024, schema);
+        assert_eq!(batches.len(), 1);
+
+        let a = as_primitive_array::<TimestampNanosecondType>(batches[0].column(0));
+        assert_eq!(a.value(0), 1640995200000000000);
+        assert_eq!(a.value(1), 1672531200000000001);
+        assert!(a.is_null(2));
+
+        let b = as_primitive_array::<Date32Type>(batches[0].column(1));
+        assert_eq!(b.value(0), 18992);
+        assert_eq!(b.value(1), 19000);
+        assert!(b.is_null(2));
+
+        let c = as_primitive_array::<Time32SecondType>(batches[0].column(2));
+        assert_eq!(c.value(0), 45000);
+        assert!(c.is_null(1));
+        assert_eq!(c.value(2), 45000);
+
+        let d = batches[0].column(3);
+        assert_eq!(d.data_type(), &DataType::Null);
+        assert_eq!(d.len(), 3);
+
+        let e = batches[0].column(4);
+        assert_eq!(e.data_type(), &dict_type);
+        let e = as_dictionary_array::<Int32Type>(e);
+        assert_eq!(e.values().len(), 2);
+        assert_eq!(e.keys().values(), &[0, 1, 0]);
+
+        let buf = r#"{"d": 1}"#;
+        let schema = Arc::new(Schema::new(vec![Field::new("d", DataType::Null, true)]));
+        let err = RawReaderBuilder::new(schema)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Json error: expected null got number");
+    }
+
+    #[test]
+    fn test_decimals() {
+        let buf = r#"
+        {"a": 1, "b": "-1.5"}
+        {"a": 123.456, "b": null}
+        {"a": "0.1", "b": "12345678901234567890123.45"}
+        {"a": null}
+        {"a": 1e2, "b": 123456789012345678901.5}
+        {"a": -1.5E-1, "b": "1.25e3"}
+        "#;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Decimal128(10, 2), true),
+            Field::new("b", DataType::Decimal256(40, 2), true),
+        ]));
+
+        let batches = do_read(buf, 1024, schema);
+        assert_eq!(batches.len(), 1);
+
+        let a = as_primitive_array::<Decimal128Type>(batches[0].column(0));
+        assert_eq!(a.data_type(), &DataType::Decimal128(10, 2));
+        assert_eq!(a.value(0), 100);
+        assert_eq!(a.value(1), 12345);
+        assert_eq!(a.value(2), 10);
+        assert!(a.is_null(3));
+        assert_eq!(a.value(4), 10000);
+        assert_eq!(a.value(5), -15);
+
+        let b = as_primitive_array::<Decimal256Type>(batches[0].column(1));
+        assert_eq!(b.data_type(), &DataType::Decimal256(40, 2));
+        assert_eq!(b.value(0), i256::from_i128(-150));
+        assert!(b.is_null(1));
+        assert_eq!(b.value_as_string(2), "12345678901234567890123.45");
+        assert!(b.is_null(3));
+        assert_eq!(b.value_as_string(4), "123456789012345678901.50");
+        assert_eq!(b.value_as_string(5), "1250.00");
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::Decimal128(3, 2),
+            true,
+        )]));
+        for (buf, expected) in [
+            (r#"{"a": 12.3}"#, "parse decimal overflow"),
+            (
+                r#"{"a": "foo"}"#,
+                "can't parse the string value foo to decimal",
+            ),
+            (r#"{"a": true}"#, "expected decimal got true"),
+        ] {
+            let err = RawReaderBuilder::new(schema.clone())
+                .build(Cursor::new(buf.as_bytes()))
+                .unwrap()
+                .next()
+                .unwrap()
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains(expected), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_coerce_mixed() {
+        let buf = r#"
+        {"a": "foo", "b": 1}
+        {"a": 1.50, "b": [1, "x\"y", null]}
+        {"a": true, "b": {"c": {"d": false}, "e": []}}
+        {"a": null, "b": "bar"}
+        "#;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::LargeUtf8, true),
+        ]));
+
+        let err = RawReaderBuilder::new(schema.clone())
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Json error: expected string got number");
+
+        let a = StringArray::from(vec![Some("foo"), Some("1.50"), Some("true"), None]);
+        let b = LargeStringArray::from(vec![
+            Some("1"),
+            Some(r#"[1,"x\"y",null]"#),
+            Some(r#"{"c":{"d":false},"e":[]}"#),
+            Some("bar"),
+        ]);
+
+        for batch_size in [1, 3, 100] {
+            let batches = RawReaderBuilder::new(schema.clone())
+                .with_batch_size(batch_size)
+                .with_coerce_mixed(true)
+                .build(Cursor::new(buf.as_bytes()))
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(batches.len(), (4 + batch_size - 1) / batch_size);
+
+            let mut offset = 0;
+            for batch in &batches {
+                let len = batch.num_rows();
+                assert_eq!(batch.column(0).as_ref(), a.slice(offset, len).as_ref());
+                assert_eq!(batch.column(1).as_ref(), b.slice(offset, len).as_ref());
+                offset += len;
+            }
+            assert_eq!(offset, 4);
+        }
+    }
+
     #[test]
     fn integration_test() {
         let files = [
@@ -554,10 +948,7 @@ mod tests {
             let schema = Arc::new(infer_json_schema(&mut f, None).unwrap());
 
             f.rewind().unwrap();
-            let a = ReaderBuilder::new()
-                .with_schema(schema.clone())
-                .build(&mut f)
-                .unwrap();
+            let a = Reader::new(&mut f, schema.clone(), DecoderOptions::new());
             let a_result = a.into_iter().collect::<Result<Vec<_>, _>>().unwrap();
 
             f.rewind().unwrap();
@@ -67,9 +67,20 @@ where
                 }
                 TapeElement::Number(idx) => {
                     let s = tape.get_string(idx);
-                    let value = lexical_core::parse::<f64>(s.as_bytes())
+                    // Parse integers directly to avoid losing precision beyond 2^53
+                    let value = lexical_core::parse::<i64>(s.as_bytes())
                         .ok()
                         .and_then(NumCast::from)
+                        .or_else(|| {
+                            lexical_core::parse::<u64>(s.as_bytes())
+                                .ok()
+                                .and_then(NumCast::from)
+                        })
+                        .or_else(|| {
+                            lexical_core::parse::<f64>(s.as_bytes())
+                                .ok()
+                                .and_then(NumCast::from)
+                        })
                         .ok_or_else(|| {
                             ArrowError::JsonError(format!(
                                 "failed to parse {s} as {}",
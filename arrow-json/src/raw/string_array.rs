@@ -24,13 +24,26 @@ use std::marker::PhantomData;
 use crate::raw::tape::{Tape, TapeElement};
 use crate::raw::{tape_error, ArrayDecoder};
 
-#[derive(Default)]
 pub struct StringArrayDecoder<O: OffsetSizeTrait> {
+    coerce_mixed: bool,
     phantom: PhantomData<O>,
 }
 
+impl<O: OffsetSizeTrait> StringArrayDecoder<O> {
+    pub fn new(coerce_mixed: bool) -> Self {
+        Self {
+            coerce_mixed,
+            phantom: Default::default(),
+        }
+    }
+}
+
 impl<O: OffsetSizeTrait> ArrayDecoder for StringArrayDecoder<O> {
     fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData, ArrowError> {
+        // The JSON text of coerced values, and the end offset of each value
+        let mut coerced = String::new();
+        let mut coerced_ends = vec![];
+
         let mut data_capacity = 0;
         for p in pos {
             match tape.get(*p) {
@@ -38,6 +51,12 @@ impl<O: OffsetSizeTrait> ArrayDecoder for StringArrayDecoder<O> {
                     data_capacity += tape.get_string(idx).len();
                 }
                 TapeElement::Null => {}
+                _ if self.coerce_mixed => {
+                    let start = coerced.len();
+                    tape.serialize(&mut coerced, *p);
+                    data_capacity += coerced.len() - start;
+                    coerced_ends.push(coerced.len());
+                }
                 d => return Err(tape_error(d, "string")),
             }
         }
@@ -52,13 +71,19 @@ impl<O: OffsetSizeTrait> ArrayDecoder for StringArrayDecoder<O> {
         let mut builder =
             GenericStringBuilder::<O>::with_capacity(pos.len(), data_capacity);
 
+        let mut coerced_start = 0;
+        let mut coerced_ends = coerced_ends.into_iter();
         for p in pos {
             match tape.get(*p) {
                 TapeElement::String(idx) => {
                     builder.append_value(tape.get_string(idx));
                 }
                 TapeElement::Null => builder.append_null(),
-                _ => unreachable!(),
+                _ => {
+                    let end = coerced_ends.next().unwrap();
+                    builder.append_value(&coerced[coerced_start..end]);
+                    coerced_start = end;
+                }
             }
         }
 
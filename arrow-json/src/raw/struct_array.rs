@@ -28,10 +28,20 @@ pub struct StructArrayDecoder {
 }
 
 impl StructArrayDecoder {
-    pub fn new(data_type: DataType, is_nullable: bool) -> Result<Self, ArrowError> {
+    pub fn new(
+        data_type: DataType,
+        coerce_mixed: bool,
+        is_nullable: bool,
+    ) -> Result<Self, ArrowError> {
         let decoders = struct_fields(&data_type)
             .iter()
-            .map(|f| make_decoder(f.data_type().clone(), f.is_nullable()))
+            .map(|f| {
+                // A null struct has null children, so a nullable struct must
+                // permit nulls in its children regardless of their nullability,
+                // these are checked to be masked by the struct after decoding
+                let nullable = f.is_nullable() || is_nullable;
+                make_decoder(f.data_type().clone(), coerce_mixed, nullable)
+            })
             .collect::<Result<Vec<_>, ArrowError>>()?;
 
         Ok(Self {
@@ -109,6 +119,24 @@ impl ArrayDecoder for StructArrayDecoder {
             .iter()
             .for_each(|x| assert_eq!(x.len(), pos.len()));
 
+        for (child, field) in child_data.iter().zip(fields) {
+            if field.is_nullable() || child.null_count() == 0 {
+                continue;
+            }
+            let unmasked = match nulls.as_ref() {
+                Some(nulls) => {
+                    (0..pos.len()).any(|i| child.is_null(i) && nulls.get_bit(i))
+                }
+                None => true,
+            };
+            if unmasked {
+                return Err(ArrowError::JsonError(format!(
+                    "Encountered unmasked nulls in non-nullable StructArray child: {}",
+                    field.name()
+                )));
+            }
+        }
+
         let data = ArrayDataBuilder::new(self.data_type.clone())
             .len(pos.len())
             .null_count(null_count)
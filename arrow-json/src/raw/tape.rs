@@ -114,6 +114,64 @@ impl<'a> Tape<'a> {
     pub fn num_rows(&self) -> usize {
         self.num_rows
     }
+
+    /// Serializes the element at `idx`, including any children, as JSON text to `out`
+    ///
+    /// Returns the index of the next element
+    pub fn serialize(&self, out: &mut String, idx: u32) -> u32 {
+        match self.get(idx) {
+            TapeElement::StartObject(end) => {
+                out.push('{');
+                let mut cur_idx = idx + 1;
+                while cur_idx < end {
+                    if cur_idx != idx + 1 {
+                        out.push(',');
+                    }
+                    cur_idx = self.serialize(out, cur_idx);
+                    out.push(':');
+                    cur_idx = self.serialize(out, cur_idx);
+                }
+                out.push('}');
+                end + 1
+            }
+            TapeElement::StartList(end) => {
+                out.push('[');
+                let mut cur_idx = idx + 1;
+                while cur_idx < end {
+                    if cur_idx != idx + 1 {
+                        out.push(',');
+                    }
+                    cur_idx = self.serialize(out, cur_idx);
+                }
+                out.push(']');
+                end + 1
+            }
+            TapeElement::String(s) => {
+                // Serializing a str cannot fail
+                out.push_str(&serde_json::to_string(self.get_string(s)).unwrap());
+                idx + 1
+            }
+            TapeElement::Number(n) => {
+                out.push_str(self.get_string(n));
+                idx + 1
+            }
+            TapeElement::True => {
+                out.push_str("true");
+                idx + 1
+            }
+            TapeElement::False => {
+                out.push_str("false");
+                idx + 1
+            }
+            TapeElement::Null => {
+                out.push_str("null");
+                idx + 1
+            }
+            TapeElement::EndObject(_) | TapeElement::EndList(_) => {
+                unreachable!("corrupt tape")
+            }
+        }
+    }
 }
 
 /// States based on <https://www.json.org/json-en.html>
@@ -45,6 +45,11 @@
 //!
 //! let batch = json.next().unwrap().unwrap();
 //! ```
+//!
+//! A [`Reader`] created with [`Reader::new`] first parses values into
+//! [`serde_json::Value`], which stores numbers that are not integers as `f64`. It
+//! therefore only reads integers and quoted numbers into a decimal column, use
+//! [`ReaderBuilder`] or [`RawReader`](crate::RawReader) to read other numbers exactly.
 
 use std::io::{BufRead, BufReader, Read, Seek};
 use std::sync::Arc;
@@ -58,10 +63,90 @@ use arrow_array::builder::*;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{bit_util, Buffer, MutableBuffer};
-use arrow_cast::parse::Parser;
+use arrow_cast::parse::{parse_decimal, Parser};
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::*;
 
+use crate::raw::{RawDecoder, RawReaderBuilder};
+
+/// How to widen conflicting types found during JSON schema inference
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TypeWidening {
+    /// Widen `Int64` and `Float64` to `Float64`, lists and scalars to a list of a
+    /// compatible scalar, and all other conflicting scalars to `Utf8`
+    ///
+    /// Objects that conflict with any other type are an error
+    #[default]
+    Standard,
+    /// Widen `Int64` and `Float64` to `Float64`, and all other conflicting types,
+    /// including objects and lists, to `Utf8`
+    Utf8,
+    /// Widen `Int64` and `Float64` to `Float64`, any other conflict is an error
+    Strict,
+}
+
+/// Options for JSON schema inference
+///
+/// # Example
+///
+/// ```
+/// use std::fs::File;
+/// use std::io::BufReader;
+/// use arrow_json::reader::{infer_json_schema_with_options, InferenceOptions, TypeWidening};
+///
+/// let mut reader =
+///     BufReader::new(File::open("test/data/mixed_arrays.json").unwrap());
+/// let options = InferenceOptions::new()
+///     .with_max_read_records(100)
+///     .with_max_depth(1)
+///     .with_type_widening(TypeWidening::Utf8);
+/// let inferred_schema = infer_json_schema_with_options(&mut reader, &options).unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InferenceOptions {
+    /// The maximum number of records to read, defaults to all records
+    max_read_records: Option<usize>,
+    /// The maximum nesting depth of `Struct` and `List` types, defaults to unlimited
+    max_depth: Option<usize>,
+    /// How to widen conflicting types, defaults to [`TypeWidening::Standard`]
+    type_widening: TypeWidening,
+}
+
+impl InferenceOptions {
+    /// Creates a new `InferenceOptions` with the default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of records to read to infer the schema
+    pub fn with_max_read_records(mut self, max_read_records: usize) -> Self {
+        self.max_read_records = Some(max_read_records);
+        self
+    }
+
+    /// Set the maximum nesting depth of `Struct` and `List` types
+    ///
+    /// The values of top-level fields have a depth of 1, and the children of an
+    /// object or array have a depth one greater than their parent. Objects and
+    /// arrays deeper than `max_depth` are inferred as `Utf8`, so a `max_depth`
+    /// of 0 infers a flat schema.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Set how to widen conflicting types
+    pub fn with_type_widening(mut self, type_widening: TypeWidening) -> Self {
+        self.type_widening = type_widening;
+        self
+    }
+
+    /// Returns true if objects and arrays at `depth` should be inferred as `Utf8`
+    fn exceeds_max_depth(&self, depth: usize) -> bool {
+        self.max_depth.map(|max| depth > max).unwrap_or(false)
+    }
+}
+
 #[derive(Debug, Clone)]
 enum InferredType {
     Scalar(HashSet<DataType>),
@@ -71,10 +156,19 @@ enum InferredType {
 }
 
 impl InferredType {
-    fn merge(&mut self, other: InferredType) -> Result<(), ArrowError> {
+    /// Returns an [`InferredType`] that will be inferred as `Utf8`
+    fn utf8() -> Self {
+        InferredType::Scalar(HashSet::from([DataType::Utf8]))
+    }
+
+    fn merge(
+        &mut self,
+        other: InferredType,
+        widening: TypeWidening,
+    ) -> Result<(), ArrowError> {
         match (self, other) {
             (InferredType::Array(s), InferredType::Array(o)) => {
-                s.merge(*o)?;
+                s.merge(*o, widening)?;
             }
             (InferredType::Scalar(self_hs), InferredType::Scalar(other_hs)) => {
                 other_hs.into_iter().for_each(|v| {
@@ -83,7 +177,10 @@ impl InferredType {
             }
             (InferredType::Object(self_map), InferredType::Object(other_map)) => {
                 for (k, v) in other_map {
-                    self_map.entry(k).or_insert(InferredType::Any).merge(v)?;
+                    self_map
+                        .entry(k)
+                        .or_insert(InferredType::Any)
+                        .merge(v, widening)?;
                 }
             }
             (s @ InferredType::Any, v) => {
@@ -94,13 +191,18 @@ impl InferredType {
             (
                 InferredType::Array(self_inner_type),
                 other_scalar @ InferredType::Scalar(_),
-            ) => {
-                self_inner_type.merge(other_scalar)?;
+            ) if widening == TypeWidening::Standard => {
+                self_inner_type.merge(other_scalar, widening)?;
             }
-            (s @ InferredType::Scalar(_), InferredType::Array(mut other_inner_type)) => {
-                other_inner_type.merge(s.clone())?;
+            (s @ InferredType::Scalar(_), InferredType::Array(mut other_inner_type))
+                if widening == TypeWidening::Standard =>
+            {
+                other_inner_type.merge(s.clone(), widening)?;
                 *s = InferredType::Array(other_inner_type);
             }
+            (s, _) if widening == TypeWidening::Utf8 => {
+                *s = InferredType::utf8();
+            }
             // incompatible types
             (s, o) => {
                 return Err(ArrowError::JsonError(format!(
@@ -118,40 +220,60 @@ impl InferredType {
 /// * `Int64` and `Float64` should be `Float64`
 /// * Lists and scalars are coerced to a list of a compatible scalar
 /// * All other types are coerced to `Utf8`
-fn coerce_data_type(dt: Vec<&DataType>) -> DataType {
+///
+/// With [`TypeWidening::Utf8`] lists and scalars are also coerced to `Utf8`, and with
+/// [`TypeWidening::Strict`] all but `Int64` and `Float64` are an error
+fn coerce_data_type(
+    dt: Vec<&DataType>,
+    widening: TypeWidening,
+) -> Result<DataType, ArrowError> {
     let mut dt_iter = dt.into_iter().cloned();
     let dt_init = dt_iter.next().unwrap_or(DataType::Utf8);
 
-    dt_iter.fold(dt_init, |l, r| match (l, r) {
-        (DataType::Boolean, DataType::Boolean) => DataType::Boolean,
-        (DataType::Int64, DataType::Int64) => DataType::Int64,
-        (DataType::Float64, DataType::Float64)
-        | (DataType::Float64, DataType::Int64)
-        | (DataType::Int64, DataType::Float64) => DataType::Float64,
-        (DataType::List(l), DataType::List(r)) => DataType::List(Box::new(Field::new(
-            "item",
-            coerce_data_type(vec![l.data_type(), r.data_type()]),
-            true,
-        ))),
-        // coerce scalar and scalar array into scalar array
-        (DataType::List(e), not_list) | (not_list, DataType::List(e)) => {
-            DataType::List(Box::new(Field::new(
-                "item",
-                coerce_data_type(vec![e.data_type(), &not_list]),
-                true,
-            )))
-        }
-        _ => DataType::Utf8,
+    dt_iter.try_fold(dt_init, |l, r| {
+        Ok(match (l, r) {
+            (DataType::Boolean, DataType::Boolean) => DataType::Boolean,
+            (DataType::Int64, DataType::Int64) => DataType::Int64,
+            (DataType::Float64, DataType::Float64)
+            | (DataType::Float64, DataType::Int64)
+            | (DataType::Int64, DataType::Float64) => DataType::Float64,
+            (DataType::List(l), DataType::List(r)) => {
+                DataType::List(Box::new(Field::new(
+                    "item",
+                    coerce_data_type(vec![l.data_type(), r.data_type()], widening)?,
+                    true,
+                )))
+            }
+            // coerce scalar and scalar array into scalar array
+            (DataType::List(e), not_list) | (not_list, DataType::List(e))
+                if widening == TypeWidening::Standard =>
+            {
+                DataType::List(Box::new(Field::new(
+                    "item",
+                    coerce_data_type(vec![e.data_type(), &not_list], widening)?,
+                    true,
+                )))
+            }
+            (l, r) if widening == TypeWidening::Strict && l != r => {
+                return Err(ArrowError::JsonError(format!(
+                    "Incompatible type found during schema inference: {l:?} v.s. {r:?}",
+                )))
+            }
+            _ => DataType::Utf8,
+        })
     })
 }
 
-fn generate_datatype(t: &InferredType) -> Result<DataType, ArrowError> {
+fn generate_datatype(
+    t: &InferredType,
+    widening: TypeWidening,
+) -> Result<DataType, ArrowError> {
     Ok(match t {
-        InferredType::Scalar(hs) => coerce_data_type(hs.iter().collect()),
-        InferredType::Object(spec) => DataType::Struct(generate_fields(spec)?),
+        InferredType::Scalar(hs) => coerce_data_type(hs.iter().collect(), widening)?,
+        InferredType::Object(spec) => DataType::Struct(generate_fields(spec, widening)?),
         InferredType::Array(ele_type) => DataType::List(Box::new(Field::new(
             "item",
-            generate_datatype(ele_type)?,
+            generate_datatype(ele_type, widening)?,
             true,
         ))),
         InferredType::Any => DataType::Null,
@@ -160,15 +282,19 @@ fn generate_datatype(t: &InferredType) -> Result<DataType, ArrowError> {
 
 fn generate_fields(
     spec: &HashMap<String, InferredType>,
+    widening: TypeWidening,
 ) -> Result<Vec<Field>, ArrowError> {
     spec.iter()
-        .map(|(k, types)| Ok(Field::new(k, generate_datatype(types)?, true)))
+        .map(|(k, types)| Ok(Field::new(k, generate_datatype(types, widening)?, true)))
         .collect()
 }
 
 /// Generate schema from JSON field names and inferred data types
-fn generate_schema(spec: HashMap<String, InferredType>) -> Result<Schema, ArrowError> {
-    Ok(Schema::new(generate_fields(&spec)?))
+fn generate_schema(
+    spec: HashMap<String, InferredType>,
+    widening: TypeWidening,
+) -> Result<Schema, ArrowError> {
+    Ok(Schema::new(generate_fields(&spec, widening)?))
 }
 
 /// JSON file reader that produces a serde_json::Value iterator from a Read trait
@@ -311,10 +437,24 @@ pub fn infer_json_schema<R: Read>(
     infer_json_schema_from_iterator(ValueIter::new(reader, max_read_records))
 }
 
+/// Infer the fields of a JSON file according to the provided [`InferenceOptions`]
+///
+/// Like [`infer_json_schema`], this function will not seek back to the start of the `reader`.
+pub fn infer_json_schema_with_options<R: Read>(
+    reader: &mut BufReader<R>,
+    options: &InferenceOptions,
+) -> Result<Schema, ArrowError> {
+    infer_json_schema_from_iterator_with_options(
+        ValueIter::new(reader, options.max_read_records),
+        options,
+    )
+}
+
 fn set_object_scalar_field_type(
     field_types: &mut HashMap<String, InferredType>,
     key: &str,
     ftype: DataType,
+    widening: TypeWidening,
 ) -> Result<(), ArrowError> {
     if !field_types.contains_key(key) {
         field_types.insert(key.to_string(), InferredType::Scalar(HashSet::new()));
@@ -330,7 +470,11 @@ fn set_object_scalar_field_type(
         scalar_array @ InferredType::Array(_) => {
             let mut hs = HashSet::new();
             hs.insert(ftype);
-            scalar_array.merge(InferredType::Scalar(hs))?;
+            scalar_array.merge(InferredType::Scalar(hs), widening)?;
+            Ok(())
+        }
+        t if widening == TypeWidening::Utf8 => {
+            *t = InferredType::utf8();
             Ok(())
         }
         t => Err(ArrowError::JsonError(format!(
@@ -339,7 +483,10 @@ fn set_object_scalar_field_type(
     }
 }
 
-fn infer_scalar_array_type(array: &[Value]) -> Result<InferredType, ArrowError> {
+fn infer_scalar_array_type(
+    array: &[Value],
+    widening: TypeWidening,
+) -> Result<InferredType, ArrowError> {
     let mut hs = HashSet::new();
 
     for v in array {
@@ -358,6 +505,9 @@ fn infer_scalar_array_type(array: &[Value]) -> Result<InferredType, ArrowError>
             Value::String(_) => {
                 hs.insert(DataType::Utf8);
             }
+            Value::Array(_) | Value::Object(_) if widening == TypeWidening::Utf8 => {
+                hs.insert(DataType::Utf8);
+            }
             Value::Array(_) | Value::Object(_) => {
                 return Err(ArrowError::JsonError(format!(
                     "Expected scalar value for scalar array, got: {v:?}"
@@ -369,14 +519,22 @@ fn infer_scalar_array_type(array: &[Value]) -> Result<InferredType, ArrowError>
     Ok(InferredType::Scalar(hs))
 }
 
-fn infer_nested_array_type(array: &[Value]) -> Result<InferredType, ArrowError> {
+/// Infers the type of the arrays in `array`, where `depth` is the depth of these arrays
+fn infer_nested_array_type(
+    array: &[Value],
+    options: &InferenceOptions,
+    depth: usize,
+) -> Result<InferredType, ArrowError> {
+    let widening = options.type_widening;
     let mut inner_ele_type = InferredType::Any;
 
     for v in array {
         match v {
             Value::Array(inner_array) => {
-                inner_ele_type.merge(infer_array_element_type(inner_array)?)?;
+                let ele_type = infer_array_element_type(inner_array, options, depth + 1)?;
+                inner_ele_type.merge(ele_type, widening)?;
             }
+            _ if widening == TypeWidening::Utf8 => return Ok(InferredType::utf8()),
             x => {
                 return Err(ArrowError::JsonError(format!(
                     "Got non array element in nested array: {x:?}"
@@ -388,13 +546,26 @@ fn infer_nested_array_type(array: &[Value]) -> Result<InferredType, ArrowError>
     Ok(InferredType::Array(Box::new(inner_ele_type)))
 }
 
-fn infer_struct_array_type(array: &[Value]) -> Result<InferredType, ArrowError> {
+/// Infers the type of the objects in `array`, where `depth` is the depth of these objects
+fn infer_struct_array_type(
+    array: &[Value],
+    options: &InferenceOptions,
+    depth: usize,
+) -> Result<InferredType, ArrowError> {
     let mut field_types = HashMap::new();
 
     for v in array {
         match v {
             Value::Object(map) => {
-                collect_field_types_from_object(&mut field_types, map)?;
+                collect_field_types_from_object(
+                    &mut field_types,
+                    map,
+                    options,
+                    depth + 1,
+                )?;
+            }
+            _ if options.type_widening == TypeWidening::Utf8 => {
+                return Ok(InferredType::utf8())
             }
             _ => {
                 return Err(ArrowError::JsonError(format!(
@@ -407,25 +578,40 @@ fn infer_struct_array_type(array: &[Value]) -> Result<InferredType, ArrowError>
     Ok(InferredType::Object(field_types))
 }
 
-fn infer_array_element_type(array: &[Value]) -> Result<InferredType, ArrowError> {
+/// Infers the element type of `array`, where `depth` is the depth of its elements
+fn infer_array_element_type(
+    array: &[Value],
+    options: &InferenceOptions,
+    depth: usize,
+) -> Result<InferredType, ArrowError> {
     match array.iter().take(1).next() {
         None => Ok(InferredType::Any), // empty array, return any type that can be updated later
+        Some(Value::Array(_) | Value::Object(_)) if options.exceeds_max_depth(depth) => {
+            Ok(InferredType::utf8())
+        }
         Some(a) => match a {
-            Value::Array(_) => infer_nested_array_type(array),
-            Value::Object(_) => infer_struct_array_type(array),
-            _ => infer_scalar_array_type(array),
+            Value::Array(_) => infer_nested_array_type(array, options, depth),
+            Value::Object(_) => infer_struct_array_type(array, options, depth),
+            _ => infer_scalar_array_type(array, options.type_widening),
         },
     }
 }
 
+/// Collects the field types of `map`, where `depth` is the depth of its values
 fn collect_field_types_from_object(
     field_types: &mut HashMap<String, InferredType>,
     map: &JsonMap<String, Value>,
+    options: &InferenceOptions,
+    depth: usize,
 ) -> Result<(), ArrowError> {
+    let widening = options.type_widening;
     for (k, v) in map {
         match v {
+            Value::Array(_) | Value::Object(_) if options.exceeds_max_depth(depth) => {
+                set_object_scalar_field_type(field_types, k, DataType::Utf8, widening)?;
+            }
             Value::Array(array) => {
-                let ele_type = infer_array_element_type(array)?;
+                let ele_type = infer_array_element_type(array, options, depth + 1)?;
 
                 if !field_types.contains_key(k) {
                     match ele_type {
@@ -458,14 +644,19 @@ fn collect_field_types_from_object(
 
                 match field_types.get_mut(k).unwrap() {
                     InferredType::Array(inner_type) => {
-                        inner_type.merge(ele_type)?;
+                        inner_type.merge(ele_type, widening)?;
                     }
                     // in case of column contains both scalar type and scalar array type, we
                     // convert type of this column to scalar array.
-                    field_type @ InferredType::Scalar(_) => {
-                        field_type.merge(ele_type)?;
+                    field_type @ InferredType::Scalar(_)
+                        if widening == TypeWidening::Standard =>
+                    {
+                        field_type.merge(ele_type, widening)?;
                         *field_type = InferredType::Array(Box::new(field_type.clone()));
                     }
+                    t if widening == TypeWidening::Utf8 => {
+                        *t = InferredType::utf8();
+                    }
                     t => {
                         return Err(ArrowError::JsonError(format!(
                             "Expected array json type, found: {t:?}",
@@ -474,7 +665,12 @@ fn collect_field_types_from_object(
                 }
             }
             Value::Bool(_) => {
-                set_object_scalar_field_type(field_types, k, DataType::Boolean)?;
+                set_object_scalar_field_type(
+                    field_types,
+                    k,
+                    DataType::Boolean,
+                    widening,
+                )?;
             }
             Value::Null => {
                 // do nothing, we treat json as nullable by default when
@@ -482,14 +678,24 @@ fn collect_field_types_from_object(
             }
             Value::Number(n) => {
                 if n.is_f64() {
-                    set_object_scalar_field_type(field_types, k, DataType::Float64)?;
+                    set_object_scalar_field_type(
+                        field_types,
+                        k,
+                        DataType::Float64,
+                        widening,
+                    )?;
                 } else {
                     // default to i64
-                    set_object_scalar_field_type(field_types, k, DataType::Int64)?;
+                    set_object_scalar_field_type(
+                        field_types,
+                        k,
+                        DataType::Int64,
+                        widening,
+                    )?;
                 }
             }
             Value::String(_) => {
-                set_object_scalar_field_type(field_types, k, DataType::Utf8)?;
+                set_object_scalar_field_type(field_types, k, DataType::Utf8, widening)?;
             }
             Value::Object(inner_map) => {
                 if !field_types.contains_key(k) {
@@ -498,7 +704,15 @@ fn collect_field_types_from_object(
                 }
                 match field_types.get_mut(k).unwrap() {
                     InferredType::Object(inner_field_types) => {
-                        collect_field_types_from_object(inner_field_types, inner_map)?;
+                        collect_field_types_from_object(
+                            inner_field_types,
+                            inner_map,
+                            options,
+                            depth + 1,
+                        )?;
+                    }
+                    t if widening == TypeWidening::Utf8 => {
+                        *t = InferredType::utf8();
                     }
                     t => {
                         return Err(ArrowError::JsonError(format!(
@@ -527,15 +741,30 @@ fn collect_field_types_from_object(
 /// interpreted as Strings. We should match Spark's behavior once we added more JSON parsing
 /// kernels in the future.
 pub fn infer_json_schema_from_iterator<I>(value_iter: I) -> Result<Schema, ArrowError>
+where
+    I: Iterator<Item = Result<Value, ArrowError>>,
+{
+    infer_json_schema_from_iterator_with_options(value_iter, &InferenceOptions::default())
+}
+
+/// Infer the fields of a JSON file by reading items from the JSON Value Iterator according
+/// to the provided [`InferenceOptions`]
+///
+/// See [`InferenceOptions`] and [`TypeWidening`] for how the inferred types may be configured
+pub fn infer_json_schema_from_iterator_with_options<I>(
+    value_iter: I,
+    options: &InferenceOptions,
+) -> Result<Schema, ArrowError>
 where
     I: Iterator<Item = Result<Value, ArrowError>>,
 {
     let mut field_types: HashMap<String, InferredType> = HashMap::new();
+    let max_read_records = options.max_read_records.unwrap_or(usize::MAX);
 
-    for record in value_iter {
+    for record in value_iter.take(max_read_records) {
         match record? {
             Value::Object(map) => {
-                collect_field_types_from_object(&mut field_types, &map)?;
+                collect_field_types_from_object(&mut field_types, &map, options, 1)?;
             }
             value => {
                 return Err(ArrowError::JsonError(format!(
@@ -545,7 +774,7 @@ where
         };
     }
 
-    generate_schema(field_types)
+    generate_schema(field_types, options.type_widening)
 }
 
 /// JSON values to Arrow record batch decoder.
@@ -598,6 +827,8 @@ pub struct DecoderOptions {
     projection: Option<Vec<String>>,
     /// optional HashMap of column name to its format string
     format_strings: Option<HashMap<String, String>>,
+    /// Whether `Utf8` columns store values of other JSON types as their JSON text
+    coerce_mixed: bool,
 }
 
 impl Default for DecoderOptions {
@@ -606,6 +837,7 @@ impl Default for DecoderOptions {
             batch_size: 1024,
             projection: None,
             format_strings: None,
+            coerce_mixed: false,
         }
     }
 }
@@ -636,6 +868,19 @@ impl DecoderOptions {
         self.format_strings = Some(format_strings);
         self
     }
+
+    /// Sets whether `Utf8` columns accept values of any JSON type, defaults to `false`
+    ///
+    /// When enabled, values other than strings, such as numbers, booleans, objects
+    /// and lists, are stored as their JSON text, e.g. `{"a":1}`, otherwise they
+    /// are read as null. This allows loading fields inferred with
+    /// [`TypeWidening::Utf8`], and matches [`RawReaderBuilder::with_coerce_mixed`]
+    ///
+    /// [`RawReaderBuilder::with_coerce_mixed`]: crate::raw::RawReaderBuilder::with_coerce_mixed
+    pub fn with_coerce_mixed(mut self, coerce_mixed: bool) -> Self {
+        self.coerce_mixed = coerce_mixed;
+        self
+    }
 }
 
 impl Decoder {
@@ -1015,6 +1260,50 @@ impl Decoder {
         ))
     }
 
+    /// Build a decimal array from JSON integers or numeric strings, returning an error
+    /// for values that are not decimals or do not fit in `precision` and `scale`
+    ///
+    /// Other numbers have already been rounded to the nearest `f64` by `serde_json`,
+    /// and so return an error instead of silently losing precision
+    fn build_decimal_array<T: DecimalType>(
+        &self,
+        rows: &[Value],
+        col_name: &str,
+        precision: u8,
+        scale: i8,
+    ) -> Result<ArrayRef, ArrowError>
+    where
+        T::Native: ArrowNativeTypeOp,
+    {
+        let mut builder = PrimitiveBuilder::<T>::with_capacity(rows.len());
+        for row in rows {
+            let value = match row.get(col_name) {
+                Some(Value::String(s)) => Some(parse_decimal::<T>(s, precision, scale)?),
+                Some(Value::Number(n)) if n.is_i64() || n.is_u64() => {
+                    Some(parse_decimal::<T>(&n.to_string(), precision, scale)?)
+                }
+                Some(Value::Number(n)) => {
+                    return Err(ArrowError::JsonError(format!(
+                        "cannot read {n} for column {col_name} as a decimal without \
+                         losing precision, quote it or use ReaderBuilder"
+                    )))
+                }
+                Some(Value::Null) | None => None,
+                Some(v) => {
+                    return Err(ArrowError::JsonError(format!(
+                        "expected decimal got {v} for column {col_name}"
+                    )))
+                }
+            };
+            builder.append_option(value);
+        }
+        Ok(Arc::new(
+            builder
+                .finish()
+                .with_precision_and_scale(precision, scale)?,
+        ))
+    }
+
     /// Build a nested GenericListArray from a list of unnested `Value`s
     fn build_nested_list_array<OffsetSize: OffsetSizeTrait>(
         &self,
@@ -1203,6 +1492,20 @@ impl Decoder {
                         Ok(Arc::new(NullArray::new(rows.len())) as ArrayRef)
                     }
                     DataType::Boolean => self.build_boolean_array(rows, field.name()),
+                    DataType::Decimal128(p, s) => self
+                        .build_decimal_array::<Decimal128Type>(
+                            rows,
+                            field.name(),
+                            *p,
+                            *s,
+                        ),
+                    DataType::Decimal256(p, s) => self
+                        .build_decimal_array::<Decimal256Type>(
+                            rows,
+                            field.name(),
+                            *p,
+                            *s,
+                        ),
                     DataType::Float64 => {
                         self.build_primitive_array::<Float64Type>(rows, field.name())
                     }
@@ -1292,14 +1595,25 @@ impl Decoder {
                             "TimeUnit {t:?} not supported with Time32"
                         ))),
                     },
-                    DataType::Utf8 => Ok(Arc::new(
-                        rows.iter()
-                            .map(|row| {
-                                let maybe_value = row.get(field.name());
-                                maybe_value.and_then(|value| value.as_str())
-                            })
-                            .collect::<StringArray>(),
-                    ) as ArrayRef),
+                    DataType::Utf8 => {
+                        let array: StringArray = match self.options.coerce_mixed {
+                            true => rows
+                                .iter()
+                                .map(|row| {
+                                    let maybe_value = row.get(field.name());
+                                    maybe_value.and_then(json_value_as_string)
+                                })
+                                .collect(),
+                            false => rows
+                                .iter()
+                                .map(|row| {
+                                    let maybe_value = row.get(field.name());
+                                    maybe_value.and_then(|value| value.as_str())
+                                })
+                                .collect(),
+                        };
+                        Ok(Arc::new(array) as ArrayRef)
+                    }
                     DataType::Binary => Ok(Arc::new(
                         rows.iter()
                             .map(|row| {
@@ -1598,16 +1912,24 @@ fn flatten_json_string_values(values: &[Value]) -> Vec<Option<String>> {
 }
 /// JSON file reader
 ///
-/// Note: Consider instead using [`RawReader`] which is faster and will
-/// eventually replace this implementation as part of [#3610]
+/// A [`Reader`] created with [`Reader::new`] or [`Reader::from_buf_reader`] decodes
+/// each record into a [`Value`] with [`Decoder`]. One created with
+/// [`ReaderBuilder::build`] instead decodes the bytes directly with the faster
+/// [`RawDecoder`], see [#3610]
 ///
-/// [`RawReader`]: crate::raw::RawReader
+/// [`RawDecoder`]: crate::raw::RawDecoder
 /// [#3610]: https://github.com/apache/arrow-rs/issues/3610
 #[derive(Debug)]
 pub struct Reader<R: Read> {
     reader: BufReader<R>,
-    /// JSON value decoder
-    decoder: Decoder,
+    decoder: ReaderDecoder,
+}
+
+/// The decoder used by a [`Reader`]
+#[derive(Debug)]
+enum ReaderDecoder {
+    Value(Decoder),
+    Raw(RawDecoder),
 }
 
 impl<R: Read> Reader<R> {
@@ -1629,32 +1951,47 @@ impl<R: Read> Reader<R> {
     ) -> Self {
         Self {
             reader,
-            decoder: Decoder::new(schema, options),
+            decoder: ReaderDecoder::Value(Decoder::new(schema, options)),
         }
     }
 
     /// Returns the schema of the reader, useful for getting the schema without reading
     /// record batches
     pub fn schema(&self) -> SchemaRef {
-        self.decoder.schema()
+        match &self.decoder {
+            ReaderDecoder::Value(decoder) => decoder.schema(),
+            ReaderDecoder::Raw(decoder) => decoder.schema(),
+        }
     }
 
     /// Read the next batch of records
     #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
-        self.decoder
-            .next_batch(&mut ValueIter::new(&mut self.reader, None))
+        let decoder = match &mut self.decoder {
+            ReaderDecoder::Value(decoder) => {
+                return decoder.next_batch(&mut ValueIter::new(&mut self.reader, None))
+            }
+            ReaderDecoder::Raw(decoder) => decoder,
+        };
+
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                break;
+            }
+            let read = buf.len();
+
+            let decoded = decoder.decode(buf)?;
+            self.reader.consume(decoded);
+            if decoded != read {
+                break;
+            }
+        }
+        decoder.flush()
     }
 }
 
 /// JSON file reader builder
-///
-/// Note: Consider instead using [`RawReaderBuilder`] which is faster and will
-/// eventually replace this implementation as part of [#3610]
-///
-/// [`RawReaderBuilder`]: crate::raw::RawReaderBuilder
-/// [#3610]: https://github.com/apache/arrow-rs/issues/3610
-///
 #[derive(Debug, Default)]
 pub struct ReaderBuilder {
     /// Optional schema for the JSON file
@@ -1722,6 +2059,11 @@ impl ReaderBuilder {
     }
 
     /// Set the decoder's format Strings param
+    ///
+    /// Format strings are only supported by the [`Value`] based [`Decoder`], which
+    /// the built [`Reader`] then uses in place of the [`RawDecoder`]
+    ///
+    /// [`RawDecoder`]: crate::raw::RawDecoder
     pub fn with_format_strings(
         mut self,
         format_strings: HashMap<String, String>,
@@ -1730,7 +2072,20 @@ impl ReaderBuilder {
         self
     }
 
+    /// Sets whether `Utf8` columns accept values of any JSON type, defaults to `false`
+    ///
+    /// See [`DecoderOptions::with_coerce_mixed`]
+    pub fn with_coerce_mixed(mut self, coerce_mixed: bool) -> Self {
+        self.options = self.options.with_coerce_mixed(coerce_mixed);
+        self
+    }
+
     /// Create a new `Reader` from the `ReaderBuilder`
+    ///
+    /// The returned [`Reader`] decodes JSON with the [`RawDecoder`], unless format
+    /// strings are provided
+    ///
+    /// [`RawDecoder`]: crate::raw::RawDecoder
     pub fn build<R>(self, source: R) -> Result<Reader<R>, ArrowError>
     where
         R: Read + Seek,
@@ -1746,7 +2101,30 @@ impl ReaderBuilder {
             )?),
         };
 
-        Ok(Reader::from_buf_reader(buf_reader, schema, self.options))
+        if self.options.format_strings.is_some() {
+            return Ok(Reader::from_buf_reader(buf_reader, schema, self.options));
+        }
+
+        let schema = match &self.options.projection {
+            Some(projection) => Arc::new(
+                schema.project(
+                    &projection
+                        .iter()
+                        .filter_map(|name| schema.index_of(name).ok())
+                        .collect::<Vec<_>>(),
+                )?,
+            ),
+            None => schema,
+        };
+        let decoder = RawReaderBuilder::new(schema)
+            .with_batch_size(self.options.batch_size)
+            .with_coerce_mixed(self.options.coerce_mixed)
+            .build_decoder()?;
+
+        Ok(Reader {
+            reader: buf_reader,
+            decoder: ReaderDecoder::Raw(decoder),
+        })
     }
 }
 
@@ -2102,7 +2480,7 @@ mod tests {
         let mut reader = builder.build(Cursor::new(b"}")).unwrap();
         assert_eq!(
             reader.next().err().unwrap().to_string(),
-            "Json error: Not valid JSON: expected value at line 1 column 1",
+            "Json error: Encountered unexpected '}' whilst trimming leading whitespace",
         );
     }
 
@@ -2112,41 +2490,47 @@ mod tests {
 
         assert_eq!(
             List(Box::new(Field::new("item", Float64, true))),
-            coerce_data_type(vec![
-                &Float64,
-                &List(Box::new(Field::new("item", Float64, true)))
-            ])
+            coerce_data_type(
+                vec![&Float64, &List(Box::new(Field::new("item", Float64, true)))],
+                TypeWidening::Standard
+            )
+            .unwrap()
         );
         assert_eq!(
             List(Box::new(Field::new("item", Float64, true))),
-            coerce_data_type(vec![
-                &Float64,
-                &List(Box::new(Field::new("item", Int64, true)))
-            ])
+            coerce_data_type(
+                vec![&Float64, &List(Box::new(Field::new("item", Int64, true)))],
+                TypeWidening::Standard
+            )
+            .unwrap()
         );
         assert_eq!(
             List(Box::new(Field::new("item", Int64, true))),
-            coerce_data_type(vec![
-                &Int64,
-                &List(Box::new(Field::new("item", Int64, true)))
-            ])
+            coerce_data_type(
+                vec![&Int64, &List(Box::new(Field::new("item", Int64, true)))],
+                TypeWidening::Standard
+            )
+            .unwrap()
         );
         // boolean and number are incompatible, return utf8
         assert_eq!(
             List(Box::new(Field::new("item", Utf8, true))),
-            coerce_data_type(vec![
-                &Boolean,
-                &List(Box::new(Field::new("item", Float64, true)))
-            ])
+            coerce_data_type(
+                vec![&Boolean, &List(Box::new(Field::new("item", Float64, true)))],
+                TypeWidening::Standard
+            )
+            .unwrap()
         );
     }
 
     #[test]
     fn test_mixed_json_arrays() {
-        let builder = ReaderBuilder::new().infer_schema(None).with_batch_size(64);
-        let mut reader: Reader<File> = builder
-            .build::<File>(File::open("test/data/mixed_arrays.json").unwrap())
-            .unwrap();
+        // Scalars are read as lists of one element by the Value based decoder
+        let mut reader =
+            BufReader::new(File::open("test/data/mixed_arrays.json").unwrap());
+        let schema = infer_json_schema_from_seekable(&mut reader, None).unwrap();
+        let options = DecoderOptions::new().with_batch_size(64);
+        let mut reader = Reader::from_buf_reader(reader, Arc::new(schema), options);
         let batch = reader.next().unwrap().unwrap();
 
         let mut file = File::open("test/data/mixed_arrays.json.gz").unwrap();
@@ -2251,10 +2635,12 @@ mod tests {
             true,
         );
         let schema = Arc::new(Schema::new(vec![a_field.clone()]));
-        let builder = ReaderBuilder::new().with_schema(schema).with_batch_size(64);
-        let mut reader: Reader<File> = builder
-            .build::<File>(File::open("test/data/nested_structs.json").unwrap())
-            .unwrap();
+        // Values that are not objects are read as null by the Value based decoder
+        let mut reader: Reader<File> = Reader::new(
+            File::open("test/data/nested_structs.json").unwrap(),
+            schema,
+            DecoderOptions::new().with_batch_size(64),
+        );
 
         // build expected output
         let d = StringArray::from(vec![Some("text"), None, Some("text"), None]);
@@ -2411,15 +2797,15 @@ mod tests {
             true,
         );
         let schema = Arc::new(Schema::new(vec![account_field, stocks_field.clone()]));
-        let builder = ReaderBuilder::new().with_schema(schema).with_batch_size(64);
-        // Note: account 456 has 'long' twice, to show that the JSON reader will overwrite
-        // existing keys. This thus guarantees unique keys for the map
+        // Note: account 456 has 'long' twice, to show that the Value based decoder will
+        // overwrite existing keys. This thus guarantees unique keys for the map
         let json_content = r#"
         {"account": 123, "stocks":{"long": ["$AAA", "$BBB"], "short": ["$CCC", "$D"]}}
         {"account": 456, "stocks":{"long": null, "long": ["$AAA", "$CCC", "$D"], "short": null}}
         {"account": 789, "stocks":{"hedged": ["$YYY"], "long": null, "short": ["$D"]}}
         "#;
-        let mut reader = builder.build(Cursor::new(json_content)).unwrap();
+        let options = DecoderOptions::new().with_batch_size(64);
+        let mut reader = Reader::new(Cursor::new(json_content), schema, options);
 
         // build expected output
         let expected_accounts = UInt16Array::from(vec![123, 456, 789]);
@@ -3009,6 +3395,245 @@ mod tests {
         assert_eq!(inferred_schema, schema);
     }
 
+    #[test]
+    fn test_json_infer_schema_with_options() {
+        let records = || {
+            vec![
+                Ok(serde_json::json!({"a": 1, "b": {"c": {"d": 1}, "e": [[1]]}})),
+                Ok(serde_json::json!({"a": 1.5, "b": {"c": null, "e": []}, "f": 1})),
+                Ok(serde_json::json!({"a": 2, "f": {"g": true}})),
+            ]
+            .into_iter()
+        };
+
+        // objects conflicting with scalars are an error by default
+        let err = infer_json_schema_from_iterator(records()).unwrap_err();
+        assert!(
+            err.to_string().contains("Expected object json type"),
+            "{err}"
+        );
+
+        let options = InferenceOptions::new().with_max_read_records(2);
+        let schema =
+            infer_json_schema_from_iterator_with_options(records(), &options).unwrap();
+        let list = |dt| DataType::List(Box::new(Field::new("item", dt, true)));
+        let b = DataType::Struct(vec![
+            Field::new(
+                "c",
+                DataType::Struct(vec![Field::new("d", DataType::Int64, true)]),
+                true,
+            ),
+            Field::new("e", list(list(DataType::Int64)), true),
+        ]);
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("b", b, true),
+            Field::new("f", DataType::Int64, true),
+        ]);
+        assert_eq!(schema, expected);
+
+        let options = InferenceOptions::new()
+            .with_max_depth(1)
+            .with_type_widening(TypeWidening::Utf8);
+        let schema =
+            infer_json_schema_from_iterator_with_options(records(), &options).unwrap();
+        let b = DataType::Struct(vec![
+            Field::new("c", DataType::Utf8, true),
+            Field::new("e", DataType::Utf8, true),
+        ]);
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("b", b, true),
+            Field::new("f", DataType::Utf8, true),
+        ]);
+        assert_eq!(schema, expected);
+
+        let options = InferenceOptions::new().with_max_depth(0);
+        let schema =
+            infer_json_schema_from_iterator_with_options(records().take(2), &options)
+                .unwrap();
+        let expected = Schema::new(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("f", DataType::Int64, true),
+        ]);
+        assert_eq!(schema, expected);
+
+        // mixed scalars are an error with strict widening
+        let options = InferenceOptions::new().with_type_widening(TypeWidening::Strict);
+        let err = infer_json_schema_from_iterator_with_options(
+            vec![
+                Ok(serde_json::json!({"a": 1})),
+                Ok(serde_json::json!({"a": 1.5})),
+                Ok(serde_json::json!({"a": "foo"})),
+            ]
+            .into_iter(),
+            &options,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Incompatible type"), "{err}");
+    }
+
+    #[test]
+    fn test_json_read_coerce_mixed() {
+        let buf = r#"
+        {"a": "foo"}
+        {"a": 1}
+        {"a": {"b": [true, null]}}
+        {"a": null}
+        "#;
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Utf8, true)]));
+
+        let read = |coerce_mixed| {
+            let options = DecoderOptions::new().with_coerce_mixed(coerce_mixed);
+            let mut reader =
+                Reader::new(Cursor::new(buf.as_bytes()), schema.clone(), options);
+            let batch = reader.next().unwrap().unwrap();
+            as_string_array(batch.column(0)).clone()
+        };
+
+        // Values of other types are read as null by the Value based decoder
+        let expected = StringArray::from(vec![Some("foo"), None, None, None]);
+        assert_eq!(read(false), expected);
+
+        // But are an error for ReaderBuilder
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema.clone())
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap();
+        let err = reader.next().unwrap_err();
+        assert_eq!(err.to_string(), "Json error: expected string got number");
+
+        let expected = StringArray::from(vec![
+            Some("foo"),
+            Some("1"),
+            Some(r#"{"b":[true,null]}"#),
+            None,
+        ]);
+        assert_eq!(read(true), expected);
+
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema)
+            .with_coerce_mixed(true)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(as_string_array(batch.column(0)), &expected);
+    }
+
+    #[test]
+    fn test_json_read_decimals() {
+        let buf = r#"
+        {"a": 1.25, "b": "-99999999999999999999.99"}
+        {"a": "3", "b": 0}
+        {"a": null}
+        {"a": 1e2, "b": "1.5e-1"}
+        "#;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Decimal128(5, 2), true),
+            Field::new("b", DataType::Decimal256(40, 2), true),
+        ]));
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let a = as_primitive_array::<Decimal128Type>(batch.column(0));
+        assert_eq!(a.data_type(), &DataType::Decimal128(5, 2));
+        assert_eq!(a.value(0), 125);
+        assert_eq!(a.value(1), 300);
+        assert!(a.is_null(2));
+        assert_eq!(a.value(3), 10000);
+
+        let b = as_primitive_array::<Decimal256Type>(batch.column(1));
+        assert_eq!(b.value_as_string(0), "-99999999999999999999.99");
+        assert_eq!(b.value_as_string(1), "0.00");
+        assert!(b.is_null(2));
+        assert_eq!(b.value_as_string(3), "0.15");
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::Decimal128(3, 2),
+            true,
+        )]));
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema)
+            .build(Cursor::new(r#"{"a": 10.5}"#.as_bytes()))
+            .unwrap();
+        let err = reader.next().unwrap_err();
+        assert!(err.to_string().contains("parse decimal overflow"), "{err}");
+
+        // Numbers are read from their text, keeping all significant digits
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::Decimal128(30, 10),
+            true,
+        )]));
+        let buf = r#"{"a": 12345678901234567890.1234567891}"#;
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema.clone())
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let a = as_primitive_array::<Decimal128Type>(batch.column(0));
+        assert_eq!(a.value(0), 123456789012345678901234567891);
+
+        // The Value based decoder only reads integers and strings exactly
+        let buf = r#"
+        {"a": "12345678901234567890.1234567891"}
+        {"a": 12345678901234567}
+        "#;
+        let options = DecoderOptions::new();
+        let mut reader =
+            Reader::new(Cursor::new(buf.as_bytes()), schema.clone(), options);
+        let batch = reader.next().unwrap().unwrap();
+        let a = as_primitive_array::<Decimal128Type>(batch.column(0));
+        assert_eq!(a.value(0), 123456789012345678901234567891);
+        assert_eq!(a.value(1), 123456789012345670000000000);
+
+        let buf = r#"{"a": 12345678901234567890.1234567891}"#;
+        let options = DecoderOptions::new();
+        let mut reader = Reader::new(Cursor::new(buf.as_bytes()), schema, options);
+        let err = reader.next().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: cannot read 1.2345678901234567e+19 for column a as a decimal \
+             without losing precision, quote it or use ReaderBuilder"
+        );
+    }
+
+    #[test]
+    fn test_json_read_widened_utf8() {
+        let buf = r#"
+        {"a": {"b": 1}, "c": "foo"}
+        {"a": "bar", "c": [1, 2]}
+        {"a": null}
+        "#;
+        let options = InferenceOptions::new().with_type_widening(TypeWidening::Utf8);
+        let mut reader = BufReader::new(Cursor::new(buf.as_bytes()));
+        let schema = infer_json_schema_with_options(&mut reader, &options).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+
+        let mut reader = ReaderBuilder::new()
+            .with_schema(Arc::new(schema))
+            .with_coerce_mixed(true)
+            .build(Cursor::new(buf.as_bytes()))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let a = as_string_array(batch.column(0));
+        assert_eq!(a.value(0), r#"{"b":1}"#);
+        assert_eq!(a.value(1), "bar");
+        assert!(a.is_null(2));
+
+        let c = as_string_array(batch.column(1));
+        assert_eq!(c.value(0), "foo");
+        assert_eq!(c.value(1), "[1,2]");
+        assert!(c.is_null(2));
+    }
+
     #[test]
     fn test_timestamp_from_json_seconds() {
         let schema = Schema::new(vec![Field::new(
@@ -3181,12 +3806,12 @@ mod tests {
     {
         let schema = Schema::new(vec![Field::new("d", T::DATA_TYPE, true)]);
 
-        let builder = ReaderBuilder::new()
-            .with_schema(Arc::new(schema))
-            .with_batch_size(64);
-        let mut reader: Reader<File> = builder
-            .build::<File>(File::open("test/data/basic_nulls.json").unwrap())
-            .unwrap();
+        // Strings that fail to parse are read as null by the Value based decoder
+        let mut reader: Reader<File> = Reader::new(
+            File::open("test/data/basic_nulls.json").unwrap(),
+            Arc::new(schema),
+            DecoderOptions::new().with_batch_size(64),
+        );
 
         let batch = reader.next().unwrap().unwrap();
         let dd = batch
@@ -104,8 +104,10 @@ use arrow_array::cast::*;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_schema::*;
+use bytes::Bytes;
 
 use arrow_cast::display::temporal_array_value_to_string;
+use base64::Engine;
 
 fn primitive_array_to_json<T>(array: &ArrayRef) -> Result<Vec<Value>, ArrowError>
 where
@@ -121,9 +123,21 @@ where
         .collect())
 }
 
+/// Converts a decimal array to exact string [`Value`]s, such as `"1.50"`
+fn decimal_array_to_json<T: DecimalType>(array: &ArrayRef) -> Vec<Value> {
+    let decimal_arr = as_primitive_array::<T>(array);
+    (0..decimal_arr.len())
+        .map(|i| match decimal_arr.is_null(i) {
+            true => Value::Null,
+            false => decimal_arr.value_as_string(i).into(),
+        })
+        .collect()
+}
+
 fn struct_array_to_jsonmap_array(
     array: &StructArray,
     row_count: usize,
+    options: &WriterBuilder,
 ) -> Result<Vec<JsonMap<String, Value>>, ArrowError> {
     let inner_col_names = array.column_names();
 
@@ -138,6 +152,7 @@ fn struct_array_to_jsonmap_array(
             struct_col,
             inner_col_names[j],
             j,
+            options,
         )?
     }
     Ok(inner_objs)
@@ -145,6 +160,13 @@ fn struct_array_to_jsonmap_array(
 
 /// Converts an arrow [`ArrayRef`] into a `Vec` of Serde JSON [`serde_json::Value`]'s
 pub fn array_to_json_array(array: &ArrayRef) -> Result<Vec<Value>, ArrowError> {
+    array_to_json_array_with_options(array, &WriterBuilder::default())
+}
+
+fn array_to_json_array_with_options(
+    array: &ArrayRef,
+    options: &WriterBuilder,
+) -> Result<Vec<Value>, ArrowError> {
     match array.data_type() {
         DataType::Null => Ok(iter::repeat(Value::Null).take(array.len()).collect()),
         DataType::Boolean => Ok(as_boolean_array(array)
@@ -179,24 +201,65 @@ pub fn array_to_json_array(array: &ArrayRef) -> Result<Vec<Value>, ArrowError> {
         DataType::UInt64 => primitive_array_to_json::<UInt64Type>(array),
         DataType::Float32 => primitive_array_to_json::<Float32Type>(array),
         DataType::Float64 => primitive_array_to_json::<Float64Type>(array),
+        DataType::Decimal128(_, _) => Ok(decimal_array_to_json::<Decimal128Type>(array)),
+        DataType::Decimal256(_, _) => Ok(decimal_array_to_json::<Decimal256Type>(array)),
         DataType::List(_) => as_list_array(array)
             .iter()
             .map(|maybe_value| match maybe_value {
-                Some(v) => Ok(Value::Array(array_to_json_array(&v)?)),
+                Some(v) => {
+                    Ok(Value::Array(array_to_json_array_with_options(&v, options)?))
+                }
                 None => Ok(Value::Null),
             })
             .collect(),
         DataType::LargeList(_) => as_large_list_array(array)
             .iter()
             .map(|maybe_value| match maybe_value {
-                Some(v) => Ok(Value::Array(array_to_json_array(&v)?)),
+                Some(v) => {
+                    Ok(Value::Array(array_to_json_array_with_options(&v, options)?))
+                }
                 None => Ok(Value::Null),
             })
             .collect(),
         DataType::Struct(_) => {
-            let jsonmaps =
-                struct_array_to_jsonmap_array(as_struct_array(array), array.len())?;
-            Ok(jsonmaps.into_iter().map(Value::Object).collect())
+            let jsonmaps = struct_array_to_jsonmap_array(
+                as_struct_array(array),
+                array.len(),
+                options,
+            )?;
+            Ok(jsonmaps
+                .into_iter()
+                .enumerate()
+                .map(
+                    |(i, obj)| match options.explicit_nulls && array.is_null(i) {
+                        true => Value::Null,
+                        false => Value::Object(obj),
+                    },
+                )
+                .collect())
+        }
+        DataType::Date32
+        | DataType::Date64
+        | DataType::Timestamp(_, _)
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Duration(_)
+        | DataType::Interval(_)
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_) => (0..array.len())
+            .map(|i| match array.is_null(i) {
+                true => Ok(Value::Null),
+                false => Ok(format_value(array, 0, i, options)?.into()),
+            })
+            .collect(),
+        DataType::Dictionary(_, value_type) => {
+            let hydrated = arrow_cast::cast::cast(array, value_type)?;
+            array_to_json_array_with_options(&hydrated, options)
+        }
+        DataType::RunEndEncoded(_, _) => {
+            let decoded = run_end_decode_array(array)?;
+            array_to_json_array_with_options(&decoded, options)
         }
         t => Err(ArrowError::JsonError(format!(
             "data type {t:?} not supported"
@@ -217,24 +280,59 @@ macro_rules! set_column_by_array_type {
     };
 }
 
-macro_rules! set_temporal_column_by_array_type {
-    ($col_name:ident, $col_idx:ident, $rows:ident, $array:ident, $row_count:ident) => {
-        $rows
-            .iter_mut()
-            .enumerate()
-            .take($row_count)
-            .for_each(|(i, row)| {
-                if !$array.is_null(i) {
-                    row.insert(
-                        $col_name.to_string(),
-                        temporal_array_value_to_string($array, $col_idx, i, None)
-                            .unwrap()
-                            .to_string()
-                            .into(),
-                    );
+/// Formats the temporal, interval or binary value at `row_idx` of `array` according
+/// to `options`
+fn format_value(
+    array: &ArrayRef,
+    col_idx: usize,
+    row_idx: usize,
+    options: &WriterBuilder,
+) -> Result<String, ArrowError> {
+    let bytes = match array.data_type() {
+        DataType::Binary => as_generic_binary_array::<i32>(array).value(row_idx),
+        DataType::LargeBinary => as_generic_binary_array::<i64>(array).value(row_idx),
+        DataType::FixedSizeBinary(_) => array
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap()
+            .value(row_idx),
+        data_type => {
+            let format = match data_type {
+                DataType::Date32 => options.date_format.as_deref(),
+                DataType::Date64 => options.datetime_format.as_deref(),
+                DataType::Time32(_) | DataType::Time64(_) => {
+                    options.time_format.as_deref()
                 }
-            });
+                DataType::Timestamp(_, Some(_)) => options.timestamp_tz_format.as_deref(),
+                DataType::Timestamp(_, None) => options.timestamp_format.as_deref(),
+                _ => None,
+            };
+            return temporal_array_value_to_string(array, col_idx, row_idx, format);
+        }
     };
+
+    Ok(match options.binary_encoding {
+        BinaryEncoding::Base64 => base64::engine::general_purpose::STANDARD.encode(bytes),
+        BinaryEncoding::Hex => bytes.iter().map(|b| format!("{b:02x}")).collect(),
+    })
+}
+
+fn set_column_by_formatted_value(
+    rows: &mut [JsonMap<String, Value>],
+    row_count: usize,
+    array: &ArrayRef,
+    col_name: &str,
+    col_idx: usize,
+    options: &WriterBuilder,
+) -> Result<(), ArrowError> {
+    for (i, row) in rows.iter_mut().enumerate().take(row_count) {
+        // when value is null, we simply skip setting the key
+        if array.is_valid(i) {
+            let value = format_value(array, col_idx, i, options)?;
+            row.insert(col_name.to_string(), value.into());
+        }
+    }
+    Ok(())
 }
 
 fn set_column_by_primitive_type<T>(
@@ -259,12 +357,32 @@ fn set_column_by_primitive_type<T>(
         });
 }
 
+fn set_column_by_decimal_type<T: DecimalType>(
+    rows: &mut [JsonMap<String, Value>],
+    row_count: usize,
+    array: &ArrayRef,
+    col_name: &str,
+) {
+    let decimal_arr = as_primitive_array::<T>(array);
+
+    rows.iter_mut()
+        .enumerate()
+        .take(row_count)
+        .for_each(|(i, row)| {
+            // when value is null, we simply skip setting the key
+            if decimal_arr.is_valid(i) {
+                row.insert(col_name.to_string(), decimal_arr.value_as_string(i).into());
+            }
+        });
+}
+
 fn set_column_for_json_rows(
     rows: &mut [JsonMap<String, Value>],
     row_count: usize,
     array: &ArrayRef,
     col_name: &str,
     col_idx: usize,
+    options: &WriterBuilder,
 ) -> Result<(), ArrowError> {
     match array.data_type() {
         DataType::Int8 => {
@@ -297,6 +415,16 @@ fn set_column_for_json_rows(
         DataType::Float64 => {
             set_column_by_primitive_type::<Float64Type>(rows, row_count, array, col_name);
         }
+        DataType::Decimal128(_, _) => {
+            set_column_by_decimal_type::<Decimal128Type>(
+                rows, row_count, array, col_name,
+            );
+        }
+        DataType::Decimal256(_, _) => {
+            set_column_by_decimal_type::<Decimal256Type>(
+                rows, row_count, array, col_name,
+            );
+        }
         DataType::Null => {
             // when value is null, we simply skip setting the key
         }
@@ -315,56 +443,36 @@ fn set_column_for_json_rows(
                 row_count
             );
         }
-        DataType::Date32 => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Date64 => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Timestamp(TimeUnit::Second, _) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Timestamp(TimeUnit::Millisecond, _) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Timestamp(TimeUnit::Microsecond, _) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Time32(TimeUnit::Second) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Time32(TimeUnit::Millisecond) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Time64(TimeUnit::Microsecond) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Time64(TimeUnit::Nanosecond) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Duration(TimeUnit::Second) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Duration(TimeUnit::Millisecond) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Duration(TimeUnit::Microsecond) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
-        }
-        DataType::Duration(TimeUnit::Nanosecond) => {
-            set_temporal_column_by_array_type!(col_name, col_idx, rows, array, row_count);
+        DataType::Date32
+        | DataType::Date64
+        | DataType::Timestamp(_, _)
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Duration(_)
+        | DataType::Interval(_)
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::FixedSizeBinary(_) => {
+            set_column_by_formatted_value(
+                rows, row_count, array, col_name, col_idx, options,
+            )?;
         }
         DataType::Struct(_) => {
-            let inner_objs =
-                struct_array_to_jsonmap_array(as_struct_array(array), row_count)?;
+            let inner_objs = struct_array_to_jsonmap_array(
+                as_struct_array(array),
+                row_count,
+                options,
+            )?;
             rows.iter_mut()
                 .take(row_count)
                 .zip(inner_objs.into_iter())
-                .for_each(|(row, obj)| {
-                    row.insert(col_name.to_string(), Value::Object(obj));
+                .enumerate()
+                .for_each(|(i, (row, obj))| {
+                    let value = match options.explicit_nulls && array.is_null(i) {
+                        true => Value::Null,
+                        false => Value::Object(obj),
+                    };
+                    row.insert(col_name.to_string(), value);
                 });
         }
         DataType::List(_) => {
@@ -376,7 +484,7 @@ fn set_column_for_json_rows(
                     if let Some(v) = maybe_value {
                         row.insert(
                             col_name.to_string(),
-                            Value::Array(array_to_json_array(&v)?),
+                            Value::Array(array_to_json_array_with_options(&v, options)?),
                         );
                     }
                     Ok(())
@@ -389,7 +497,7 @@ fn set_column_for_json_rows(
                 .take(row_count)
                 .try_for_each(|(row, maybe_value)| -> Result<(), ArrowError> {
                     if let Some(v) = maybe_value {
-                        let val = array_to_json_array(&v)?;
+                        let val = array_to_json_array_with_options(&v, options)?;
                         row.insert(col_name.to_string(), Value::Array(val));
                     }
                     Ok(())
@@ -397,9 +505,16 @@ fn set_column_for_json_rows(
         }
         DataType::Dictionary(_, value_type) => {
             let slice = array.slice(0, row_count);
-            let hydrated = arrow_cast::cast::cast(&slice, value_type)
-                .expect("cannot cast dictionary to underlying values");
-            set_column_for_json_rows(rows, row_count, &hydrated, col_name, col_idx)?;
+            let hydrated = arrow_cast::cast::cast(&slice, value_type)?;
+            set_column_for_json_rows(
+                rows, row_count, &hydrated, col_name, col_idx, options,
+            )?;
+        }
+        DataType::RunEndEncoded(_, _) => {
+            let decoded = run_end_decode_array(&array.slice(0, row_count))?;
+            set_column_for_json_rows(
+                rows, row_count, &decoded, col_name, col_idx, options,
+            )?;
         }
         DataType::Map(_, _) => {
             let maparr = as_map_array(array);
@@ -416,7 +531,7 @@ fn set_column_for_json_rows(
             }
 
             let keys = as_string_array(keys);
-            let values = array_to_json_array(values)?;
+            let values = array_to_json_array_with_options(values, options)?;
 
             let mut kv = keys.iter().zip(values.into_iter());
 
@@ -453,6 +568,13 @@ fn set_column_for_json_rows(
 /// [`JsonMap`]s (objects)
 pub fn record_batches_to_json_rows(
     batches: &[RecordBatch],
+) -> Result<Vec<JsonMap<String, Value>>, ArrowError> {
+    record_batches_to_json_rows_with_options(batches, &WriterBuilder::default())
+}
+
+fn record_batches_to_json_rows_with_options(
+    batches: &[RecordBatch],
+    options: &WriterBuilder,
 ) -> Result<Vec<JsonMap<String, Value>>, ArrowError> {
     let mut rows: Vec<JsonMap<String, Value>> = iter::repeat(JsonMap::new())
         .take(batches.iter().map(|b| b.num_rows()).sum())
@@ -465,7 +587,14 @@ pub fn record_batches_to_json_rows(
             let row_count = batch.num_rows();
             for (j, col) in batch.columns().iter().enumerate() {
                 let col_name = schema.field(j).name();
-                set_column_for_json_rows(&mut rows[base..], row_count, col, col_name, j)?
+                set_column_for_json_rows(
+                    &mut rows[base..],
+                    row_count,
+                    col,
+                    col_name,
+                    j,
+                    options,
+                )?
             }
             base += row_count;
         }
@@ -559,6 +688,427 @@ pub type LineDelimitedWriter<W> = Writer<W, LineDelimited>;
 /// A JSON writer which serializes [`RecordBatch`]es to JSON arrays
 pub type ArrayWriter<W> = Writer<W, JsonArray>;
 
+/// Fills in the fields of `value` missing from `data_type` with [`Value::Null`]
+fn fill_nulls(value: &mut Value, data_type: &DataType) {
+    match (value, data_type) {
+        (Value::Object(map), DataType::Struct(fields)) => {
+            for field in fields {
+                let value = map.entry(field.name().clone()).or_insert(Value::Null);
+                fill_nulls(value, field.data_type());
+            }
+        }
+        (Value::Array(values), DataType::List(f) | DataType::LargeList(f)) => {
+            values.iter_mut().for_each(|v| fill_nulls(v, f.data_type()))
+        }
+        (Value::Object(map), DataType::Map(f, _)) => {
+            let value_type = map_value_type(f);
+            map.values_mut().for_each(|v| fill_nulls(v, value_type))
+        }
+        (value, DataType::Dictionary(_, value_type)) => fill_nulls(value, value_type),
+        (value, DataType::RunEndEncoded(_, values)) => {
+            fill_nulls(value, values.data_type())
+        }
+        _ => {}
+    }
+}
+
+/// Converts the decimals in `value` of `data_type` from exact strings to JSON numbers,
+/// integers are converted exactly, other values are converted to the nearest `f64`
+fn decimals_to_numbers(value: &mut Value, data_type: &DataType) {
+    match (value, data_type) {
+        (Value::Object(map), DataType::Struct(fields)) => {
+            for field in fields {
+                if let Some(value) = map.get_mut(field.name()) {
+                    decimals_to_numbers(value, field.data_type());
+                }
+            }
+        }
+        (Value::Array(values), DataType::List(f) | DataType::LargeList(f)) => values
+            .iter_mut()
+            .for_each(|v| decimals_to_numbers(v, f.data_type())),
+        (Value::Object(map), DataType::Map(f, _)) => {
+            let value_type = map_value_type(f);
+            map.values_mut()
+                .for_each(|v| decimals_to_numbers(v, value_type))
+        }
+        (value, DataType::Decimal128(_, _) | DataType::Decimal256(_, _)) => {
+            let number = match &*value {
+                Value::String(s) => match (s.parse::<i64>(), s.parse::<u64>()) {
+                    (Ok(i), _) => Some(i.into()),
+                    (_, Ok(u)) => Some(u.into()),
+                    _ => s.parse::<f64>().ok().and_then(serde_json::Number::from_f64),
+                },
+                _ => None,
+            };
+            if let Some(number) = number {
+                *value = Value::Number(number);
+            }
+        }
+        (value, DataType::Dictionary(_, value_type)) => {
+            decimals_to_numbers(value, value_type)
+        }
+        (value, DataType::RunEndEncoded(_, values)) => {
+            decimals_to_numbers(value, values.data_type())
+        }
+        _ => {}
+    }
+}
+
+/// Writes `value` of `data_type` to `out` according to `options`
+///
+/// Decimals are converted to exact strings by [`array_to_json_array`], these are
+/// written as JSON numbers unless [`WriterBuilder::with_decimals_as_strings`]
+fn write_typed(
+    out: &mut Vec<u8>,
+    value: &Value,
+    data_type: &DataType,
+    options: &WriterBuilder,
+) -> Result<(), ArrowError> {
+    match (value, data_type) {
+        (Value::Object(map), DataType::Struct(fields)) => {
+            out.push(b'{');
+            let mut write_field = |idx: usize, name: &String, value, data_type| {
+                if idx != 0 {
+                    out.push(b',');
+                }
+                write_json(out, &Value::String(name.clone()))?;
+                out.push(b':');
+                write_typed(out, value, data_type, options)
+            };
+
+            if options.schema_order {
+                let present = fields.iter().filter_map(|f| {
+                    map.get(f.name()).map(|v| (f.name(), v, f.data_type()))
+                });
+                for (idx, (name, value, data_type)) in present.enumerate() {
+                    write_field(idx, name, value, data_type)?;
+                }
+            } else {
+                for (idx, (name, value)) in map.iter().enumerate() {
+                    let data_type = fields
+                        .iter()
+                        .find(|f| f.name() == name)
+                        .map(|f| f.data_type())
+                        .unwrap_or(&DataType::Null);
+                    write_field(idx, name, value, data_type)?;
+                }
+            }
+            out.push(b'}');
+        }
+        (Value::Array(values), DataType::List(f) | DataType::LargeList(f)) => {
+            out.push(b'[');
+            for (idx, value) in values.iter().enumerate() {
+                if idx != 0 {
+                    out.push(b',');
+                }
+                write_typed(out, value, f.data_type(), options)?;
+            }
+            out.push(b']');
+        }
+        (Value::Object(map), DataType::Map(f, _)) => {
+            let value_type = map_value_type(f);
+            out.push(b'{');
+            for (idx, (key, value)) in map.iter().enumerate() {
+                if idx != 0 {
+                    out.push(b',');
+                }
+                write_json(out, &Value::String(key.clone()))?;
+                out.push(b':');
+                write_typed(out, value, value_type, options)?;
+            }
+            out.push(b'}');
+        }
+        (Value::String(s), DataType::Decimal128(_, _) | DataType::Decimal256(_, _))
+            if !options.decimals_as_strings =>
+        {
+            out.extend_from_slice(s.as_bytes())
+        }
+        (value, DataType::Dictionary(_, value_type)) => {
+            write_typed(out, value, value_type, options)?
+        }
+        (value, DataType::RunEndEncoded(_, values)) => {
+            write_typed(out, value, values.data_type(), options)?
+        }
+        (value, _) => write_json(out, value)?,
+    }
+    Ok(())
+}
+
+fn write_json(out: &mut Vec<u8>, value: &Value) -> Result<(), ArrowError> {
+    serde_json::to_writer(out, value)
+        .map_err(|error| ArrowError::JsonError(error.to_string()))
+}
+
+/// Decodes a [`RunArray`] into an array of its logical values, by casting it to a
+/// dictionary and then to its value type
+fn run_end_decode_array(array: &ArrayRef) -> Result<ArrayRef, ArrowError> {
+    let values_type = match array.data_type() {
+        DataType::RunEndEncoded(_, values) => values.data_type(),
+        t => {
+            return Err(ArrowError::JsonError(format!(
+                "expected run end encoded array got {t:?}"
+            )))
+        }
+    };
+    let dictionary_type =
+        DataType::Dictionary(Box::new(DataType::Int64), Box::new(values_type.clone()));
+    let dictionary = arrow_cast::cast::cast(array, &dictionary_type)?;
+    arrow_cast::cast::cast(&dictionary, values_type)
+}
+
+/// Returns the type of the values of a map with entries `field`
+fn map_value_type(field: &Field) -> &DataType {
+    match field.data_type() {
+        DataType::Struct(fields) if fields.len() == 2 => fields[1].data_type(),
+        _ => &DataType::Null,
+    }
+}
+
+/// How binary values are encoded as JSON strings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BinaryEncoding {
+    /// Standard base64 with padding, e.g. `"AQL/"`
+    #[default]
+    Base64,
+    /// Lowercase hexadecimal, e.g. `"0102ff"`
+    Hex,
+}
+
+/// Encodes the rows of [`RecordBatch`]es as JSON objects, either as [`Value`]s or
+/// as serialized JSON bytes, configured by [`WriterBuilder::build_encoder`]
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// use arrow_json::writer::WriterBuilder;
+///
+/// let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+/// let a = Int32Array::from(vec![Some(1), None]);
+/// let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a)]).unwrap();
+///
+/// let encoder = WriterBuilder::new().with_explicit_nulls(true).build_encoder();
+///
+/// let values: Vec<_> = encoder.encode_values(&batch).unwrap().collect();
+/// assert_eq!(values[1], serde_json::json!({"a": null}));
+///
+/// let rows = encoder
+///     .encode_bytes(&batch)
+///     .unwrap()
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert_eq!(rows[0], r#"{"a":1}"#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    options: WriterBuilder,
+}
+
+impl Encoder {
+    /// Encodes the rows of `batch` as JSON object [`Value`]s
+    ///
+    /// Decimals are encoded as JSON numbers, unless
+    /// [`WriterBuilder::with_decimals_as_strings`] is set. As a [`Value`] stores
+    /// fractional numbers as `f64`, decimals that cannot be represented exactly
+    /// lose precision, write decimals as strings to preserve their exact value
+    pub fn encode_values(
+        &self,
+        batch: &RecordBatch,
+    ) -> Result<impl Iterator<Item = Value>, ArrowError> {
+        let data_type = DataType::Struct(batch.schema().fields().clone());
+        let decimals_as_strings = self.options.decimals_as_strings;
+
+        Ok(self.encode_rows(batch)?.map(move |mut row| {
+            if !decimals_as_strings {
+                decimals_to_numbers(&mut row, &data_type);
+            }
+            row
+        }))
+    }
+
+    /// Encodes the rows of `batch` as serialized JSON objects
+    ///
+    /// Decimals are written as exact JSON numbers, unless
+    /// [`WriterBuilder::with_decimals_as_strings`] is set
+    pub fn encode_bytes(
+        &self,
+        batch: &RecordBatch,
+    ) -> Result<impl Iterator<Item = Result<Bytes, ArrowError>>, ArrowError> {
+        let data_type = DataType::Struct(batch.schema().fields().clone());
+        let options = self.options.clone();
+
+        Ok(self.encode_rows(batch)?.map(move |row| {
+            let mut buf = Vec::new();
+            write_typed(&mut buf, &row, &data_type, &options)?;
+            Ok(buf.into())
+        }))
+    }
+
+    /// Encodes the rows of `batch` as [`Value`]s with decimals as exact strings
+    fn encode_rows(
+        &self,
+        batch: &RecordBatch,
+    ) -> Result<impl Iterator<Item = Value>, ArrowError> {
+        let data_type = DataType::Struct(batch.schema().fields().clone());
+        let explicit_nulls = self.options.explicit_nulls;
+
+        let rows = record_batches_to_json_rows_with_options(
+            std::slice::from_ref(batch),
+            &self.options,
+        )?;
+        Ok(rows.into_iter().map(move |row| {
+            let mut row = Value::Object(row);
+            if explicit_nulls {
+                fill_nulls(&mut row, &data_type);
+            }
+            row
+        }))
+    }
+}
+
+/// JSON writer builder, used to configure a [`Writer`]
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{Int32Array, RecordBatch};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// use arrow_json::writer::{LineDelimited, WriterBuilder};
+///
+/// let schema = Schema::new(vec![
+///     Field::new("b", DataType::Int32, true),
+///     Field::new("a", DataType::Int32, true),
+/// ]);
+/// let b = Int32Array::from(vec![Some(1), None]);
+/// let a = Int32Array::from(vec![Some(2), Some(3)]);
+/// let batch =
+///     RecordBatch::try_new(Arc::new(schema), vec![Arc::new(b), Arc::new(a)]).unwrap();
+///
+/// let mut writer = WriterBuilder::new()
+///     .with_explicit_nulls(true)
+///     .with_schema_order(true)
+///     .build::<_, LineDelimited>(vec![]);
+/// writer.write(batch).unwrap();
+/// writer.finish().unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(writer.into_inner()).unwrap(),
+///     "{\"b\":1,\"a\":2}\n{\"b\":null,\"a\":3}\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct WriterBuilder {
+    /// Write `null` for null values instead of omitting the key, defaults to `false`
+    explicit_nulls: bool,
+    /// Write the fields of objects in schema order instead of sorted by name,
+    /// defaults to `false`
+    schema_order: bool,
+    /// Write decimals as JSON strings instead of numbers, defaults to `false`
+    decimals_as_strings: bool,
+    /// Optional format for date arrays
+    date_format: Option<String>,
+    /// Optional format for datetime arrays
+    datetime_format: Option<String>,
+    /// Optional format for time arrays
+    time_format: Option<String>,
+    /// Optional format for timestamp arrays
+    timestamp_format: Option<String>,
+    /// Optional format for timestamp with timezone arrays
+    timestamp_tz_format: Option<String>,
+    /// How to encode binary arrays, defaults to [`BinaryEncoding::Base64`]
+    binary_encoding: BinaryEncoding,
+}
+
+impl WriterBuilder {
+    /// Create a new builder for configuring JSON writing options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether to write null values as `"field": null` instead of omitting
+    /// the field, including the fields of nested structs. Null structs are
+    /// written as `null`
+    pub fn with_explicit_nulls(mut self, explicit_nulls: bool) -> Self {
+        self.explicit_nulls = explicit_nulls;
+        self
+    }
+
+    /// Set whether to write the fields of objects in the order of the fields of
+    /// the schema, instead of sorted by name
+    pub fn with_schema_order(mut self, schema_order: bool) -> Self {
+        self.schema_order = schema_order;
+        self
+    }
+
+    /// Set whether to write decimals as JSON strings, such as `"1.50"`, instead of
+    /// JSON numbers, such as `1.50`
+    ///
+    /// In both cases the exact decimal value is written, however, some JSON
+    /// parsers read numbers as floating point, losing precision
+    pub fn with_decimals_as_strings(mut self, decimals_as_strings: bool) -> Self {
+        self.decimals_as_strings = decimals_as_strings;
+        self
+    }
+
+    /// Set the format for `Date32` values, such as `%Y-%m-%d`
+    ///
+    /// Temporal values without a format are written in ISO 8601 format
+    pub fn with_date_format(mut self, format: String) -> Self {
+        self.date_format = Some(format);
+        self
+    }
+
+    /// Set the format for `Date64` values, such as `%Y-%m-%dT%H:%M:%S`
+    pub fn with_datetime_format(mut self, format: String) -> Self {
+        self.datetime_format = Some(format);
+        self
+    }
+
+    /// Set the format for `Time32` and `Time64` values, such as `%H:%M:%S`
+    pub fn with_time_format(mut self, format: String) -> Self {
+        self.time_format = Some(format);
+        self
+    }
+
+    /// Set the format for `Timestamp` values without a timezone
+    pub fn with_timestamp_format(mut self, format: String) -> Self {
+        self.timestamp_format = Some(format);
+        self
+    }
+
+    /// Set the format for `Timestamp` values with a timezone, such as `%Y-%m-%dT%H:%M:%S%:z`
+    pub fn with_timestamp_tz_format(mut self, format: String) -> Self {
+        self.timestamp_tz_format = Some(format);
+        self
+    }
+
+    /// Set how to encode `Binary`, `LargeBinary` and `FixedSizeBinary` values as
+    /// JSON strings
+    pub fn with_binary_encoding(mut self, binary_encoding: BinaryEncoding) -> Self {
+        self.binary_encoding = binary_encoding;
+        self
+    }
+
+    /// Create a new [`Encoder`] with the configured options
+    pub fn build_encoder(self) -> Encoder {
+        Encoder { options: self }
+    }
+
+    /// Create a new [`Writer`] with the configured options
+    pub fn build<W: Write, F: JsonFormat>(self, writer: W) -> Writer<W, F> {
+        Writer {
+            writer,
+            started: false,
+            finished: false,
+            format: F::default(),
+            options: self,
+        }
+    }
+}
+
 /// A JSON writer which serializes [`RecordBatch`]es to a stream of
 /// `u8` encoded JSON objects. See the module level documentation for
 /// detailed usage and examples. The specific format of the stream is
@@ -580,6 +1130,9 @@ where
 
     /// Determines how the byte stream is formatted
     format: F,
+
+    /// The options configured by [`WriterBuilder`]
+    options: WriterBuilder,
 }
 
 impl<W, F> Writer<W, F>
@@ -588,17 +1141,22 @@ where
     F: JsonFormat,
 {
     /// Construct a new writer
+    ///
+    /// See [`WriterBuilder`] to configure the writer
     pub fn new(writer: W) -> Self {
-        Self {
-            writer,
-            started: false,
-            finished: false,
-            format: F::default(),
-        }
+        WriterBuilder::new().build(writer)
     }
 
     /// Write a single JSON row to the output writer
+    ///
+    /// The row is written as provided, regardless of the [`WriterBuilder`] options
     pub fn write_row(&mut self, row: &Value) -> Result<(), ArrowError> {
+        let buf = serde_json::to_vec(row)
+            .map_err(|error| ArrowError::JsonError(error.to_string()))?;
+        self.write_encoded_row(&buf)
+    }
+
+    fn write_encoded_row(&mut self, row: &[u8]) -> Result<(), ArrowError> {
         let is_first_row = !self.started;
         if !self.started {
             self.format.start_stream(&mut self.writer)?;
@@ -606,26 +1164,23 @@ where
         }
 
         self.format.start_row(&mut self.writer, is_first_row)?;
-        self.writer.write_all(
-            &serde_json::to_vec(row)
-                .map_err(|error| ArrowError::JsonError(error.to_string()))?,
-        )?;
+        self.writer.write_all(row)?;
         self.format.end_row(&mut self.writer)?;
         Ok(())
     }
 
     /// Convert the `RecordBatch` into JSON rows, and write them to the output
     pub fn write(&mut self, batch: RecordBatch) -> Result<(), ArrowError> {
-        for row in record_batches_to_json_rows(&[batch])? {
-            self.write_row(&Value::Object(row))?;
-        }
-        Ok(())
+        self.write_batches(&[batch])
     }
 
     /// Convert the [`RecordBatch`] into JSON rows, and write them to the output
     pub fn write_batches(&mut self, batches: &[RecordBatch]) -> Result<(), ArrowError> {
-        for row in record_batches_to_json_rows(batches)? {
-            self.write_row(&Value::Object(row))?;
+        let encoder = self.options.clone().build_encoder();
+        for batch in batches {
+            for row in encoder.encode_bytes(batch)? {
+                self.write_encoded_row(&row?)?;
+            }
         }
         Ok(())
     }
@@ -653,7 +1208,7 @@ mod tests {
     use std::sync::Arc;
 
     use crate::reader::*;
-    use arrow_buffer::{Buffer, ToByteSlice};
+    use arrow_buffer::{i256, Buffer, ToByteSlice};
     use arrow_data::ArrayData;
     use serde_json::json;
 
@@ -1481,4 +2036,389 @@ mod tests {
             assert_eq!(serde_json::from_str::<Value>(r).unwrap(), expected_json,);
         }
     }
+
+    #[test]
+    fn test_write_explicit_nulls_schema_order() {
+        let struct_type = DataType::Struct(vec![
+            Field::new("z", DataType::Int32, true),
+            Field::new("y", DataType::Utf8, true),
+        ]);
+        let schema = Schema::new(vec![
+            Field::new("c", DataType::Utf8, true),
+            Field::new("b", struct_type, true),
+            Field::new(
+                "a",
+                DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+                true,
+            ),
+        ]);
+
+        let c = StringArray::from(vec![Some("foo"), None]);
+        let z = Int32Array::from(vec![Some(1), None]);
+        let y = StringArray::from(vec![None, Some("bar")]);
+        let b = StructArray::from(vec![
+            (
+                Field::new("z", DataType::Int32, true),
+                Arc::new(z) as ArrayRef,
+            ),
+            (
+                Field::new("y", DataType::Utf8, true),
+                Arc::new(y) as ArrayRef,
+            ),
+        ]);
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None,
+            Some(vec![Some(1), None]),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(c), Arc::new(b), Arc::new(a)],
+        )
+        .unwrap();
+
+        let write = |builder: WriterBuilder| {
+            let mut writer = builder.build::<_, JsonArray>(Vec::new());
+            writer.write_batches(&[batch.clone()]).unwrap();
+            writer.finish().unwrap();
+            String::from_utf8(writer.into_inner()).unwrap()
+        };
+
+        assert_eq!(
+            write(WriterBuilder::new()),
+            r#"[{"b":{"z":1},"c":"foo"},{"a":[1,null],"b":{"y":"bar"}}]"#
+        );
+        assert_eq!(
+            write(WriterBuilder::new().with_explicit_nulls(true)),
+            r#"[{"a":null,"b":{"y":null,"z":1},"c":"foo"},{"a":[1,null],"b":{"y":"bar","z":null},"c":null}]"#
+        );
+        assert_eq!(
+            write(WriterBuilder::new().with_schema_order(true)),
+            r#"[{"c":"foo","b":{"z":1}},{"b":{"y":"bar"},"a":[1,null]}]"#
+        );
+        assert_eq!(
+            write(
+                WriterBuilder::new()
+                    .with_explicit_nulls(true)
+                    .with_schema_order(true)
+            ),
+            r#"[{"c":"foo","b":{"z":1,"y":null},"a":null},{"c":null,"b":{"z":null,"y":"bar"},"a":[1,null]}]"#
+        );
+    }
+
+    #[test]
+    fn test_write_explicit_nulls_null_struct() {
+        let field = Field::new("c", DataType::Int32, true);
+        let c = Int32Array::from(vec![Some(1), Some(2), None]);
+        // The second struct is null
+        let b = StructArray::from((
+            vec![(field.clone(), Arc::new(c) as ArrayRef)],
+            Buffer::from([0b00000101]),
+        ));
+        let list_type =
+            DataType::List(Box::new(Field::new("item", b.data_type().clone(), true)));
+        let a = ListArray::from(
+            ArrayData::builder(list_type.clone())
+                .len(2)
+                .add_buffer(Buffer::from_slice_ref([0_i32, 2, 3]))
+                .add_child_data(b.data().clone())
+                .build()
+                .unwrap(),
+        );
+        let b = b.slice(0, 2);
+        let schema = Schema::new(vec![
+            Field::new("a", list_type, true),
+            Field::new("b", b.data_type().clone(), true),
+        ]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), b]).unwrap();
+
+        let write = |builder: WriterBuilder| {
+            let mut writer = builder.build::<_, LineDelimited>(Vec::new());
+            writer.write(batch.clone()).unwrap();
+            String::from_utf8(writer.into_inner()).unwrap()
+        };
+
+        assert_eq!(
+            write(WriterBuilder::new().with_explicit_nulls(true)),
+            "{\"a\":[{\"c\":1},null],\"b\":{\"c\":1}}\n\
+             {\"a\":[{\"c\":null}],\"b\":null}\n"
+        );
+    }
+
+    #[test]
+    fn test_write_decimals() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Decimal128(20, 2), true),
+            Field::new(
+                "b",
+                DataType::List(Box::new(Field::new(
+                    "item",
+                    DataType::Decimal256(40, 3),
+                    true,
+                ))),
+                true,
+            ),
+        ]);
+
+        let a = Decimal128Array::from(vec![Some(123456789012345678), None, Some(-5)])
+            .with_precision_and_scale(20, 2)
+            .unwrap();
+        let values = Decimal256Array::from(vec![
+            Some(i256::from_i128(1500)),
+            None,
+            Some(i256::from_i128(-1)),
+        ])
+        .with_precision_and_scale(40, 3)
+        .unwrap();
+        let b = ListArray::from(
+            ArrayData::builder(schema.field(1).data_type().clone())
+                .len(3)
+                .add_buffer(Buffer::from_slice_ref([0_i32, 2, 2, 3]))
+                .add_child_data(values.into_data())
+                .build()
+                .unwrap(),
+        );
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), Arc::new(b)])
+                .unwrap();
+
+        let rows = record_batches_to_json_rows(&[batch.clone()]).unwrap();
+        assert_eq!(
+            Value::Object(rows[0].clone()),
+            json!({"a": "1234567890123456.78", "b": ["1.500", null]})
+        );
+
+        let write = |builder: WriterBuilder| {
+            let mut writer = builder.build::<_, LineDelimited>(Vec::new());
+            writer.write(batch.clone()).unwrap();
+            String::from_utf8(writer.into_inner()).unwrap()
+        };
+
+        assert_eq!(
+            write(WriterBuilder::new()),
+            "{\"a\":1234567890123456.78,\"b\":[1.500,null]}\n\
+             {\"b\":[]}\n\
+             {\"a\":-0.05,\"b\":[-0.001]}\n"
+        );
+        assert_eq!(
+            write(WriterBuilder::new().with_decimals_as_strings(true)),
+            "{\"a\":\"1234567890123456.78\",\"b\":[\"1.500\",null]}\n\
+             {\"b\":[]}\n\
+             {\"a\":\"-0.05\",\"b\":[\"-0.001\"]}\n"
+        );
+
+        // The encoder uses the same representation for values and bytes
+        let encoder = WriterBuilder::new().build_encoder();
+        let values: Vec<_> = encoder.encode_values(&batch).unwrap().collect();
+        assert_eq!(values[2], json!({"a": -0.05, "b": [-0.001]}));
+        let bytes = encoder
+            .encode_bytes(&batch)
+            .unwrap()
+            .nth(2)
+            .unwrap()
+            .unwrap();
+        assert_eq!(bytes, r#"{"a":-0.05,"b":[-0.001]}"#);
+
+        let encoder = WriterBuilder::new()
+            .with_decimals_as_strings(true)
+            .build_encoder();
+        let values: Vec<_> = encoder.encode_values(&batch).unwrap().collect();
+        assert_eq!(values[2], json!({"a": "-0.05", "b": ["-0.001"]}));
+        let bytes = encoder
+            .encode_bytes(&batch)
+            .unwrap()
+            .nth(2)
+            .unwrap()
+            .unwrap();
+        assert_eq!(bytes, r#"{"a":"-0.05","b":["-0.001"]}"#);
+    }
+
+    #[test]
+    fn test_encoder() {
+        let ts_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+        let schema = Schema::new(vec![
+            Field::new(
+                "a",
+                DataType::List(Box::new(Field::new("item", ts_type.clone(), true))),
+                true,
+            ),
+            Field::new(
+                "b",
+                DataType::Struct(vec![Field::new("c", DataType::Date32, true)]),
+                true,
+            ),
+        ]);
+
+        let values = TimestampMillisecondArray::from(vec![Some(1_000), None, Some(0)]);
+        let a = ListArray::from(
+            ArrayData::builder(schema.field(0).data_type().clone())
+                .len(2)
+                .add_buffer(Buffer::from_slice_ref([0_i32, 2, 3]))
+                .add_child_data(values.into_data())
+                .build()
+                .unwrap(),
+        );
+        let c = Date32Array::from(vec![Some(1), None]);
+        let b = StructArray::from(vec![(
+            Field::new("c", DataType::Date32, true),
+            Arc::new(c) as ArrayRef,
+        )]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), Arc::new(b)])
+                .unwrap();
+
+        let encoder = WriterBuilder::new().build_encoder();
+        let values: Vec<_> = encoder.encode_values(&batch).unwrap().collect();
+        assert_eq!(
+            values,
+            vec![
+                json!({"a": ["1970-01-01T00:00:01", null], "b": {"c": "1970-01-02"}}),
+                json!({"a": ["1970-01-01T00:00:00"], "b": {}}),
+            ]
+        );
+
+        let encoder = WriterBuilder::new()
+            .with_explicit_nulls(true)
+            .with_schema_order(true)
+            .build_encoder();
+        let rows = encoder
+            .encode_bytes(&batch)
+            .unwrap()
+            .map(|row| String::from_utf8(row.unwrap().to_vec()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                r#"{"a":["1970-01-01T00:00:01",null],"b":{"c":"1970-01-02"}}"#,
+                r#"{"a":["1970-01-01T00:00:00"],"b":{"c":null}}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_temporal_interval_binary() {
+        let schema = Schema::new(vec![
+            Field::new("date", DataType::Date32, true),
+            Field::new("time", DataType::Time32(TimeUnit::Second), true),
+            Field::new("ts", DataType::Timestamp(TimeUnit::Second, None), true),
+            Field::new(
+                "ts_tz",
+                DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string())),
+                true,
+            ),
+            Field::new("duration", DataType::Duration(TimeUnit::Second), true),
+            Field::new(
+                "interval",
+                DataType::Interval(IntervalUnit::YearMonth),
+                true,
+            ),
+            Field::new("binary", DataType::Binary, true),
+            Field::new("fixed", DataType::FixedSizeBinary(2), true),
+        ]);
+
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Date32Array::from(vec![Some(1), None])),
+                Arc::new(Time32SecondArray::from(vec![Some(3661), None])),
+                Arc::new(TimestampSecondArray::from(vec![Some(86_400), None])),
+                Arc::new(
+                    TimestampSecondArray::from(vec![Some(0), None])
+                        .with_timezone("+01:00".to_string()),
+                ),
+                Arc::new(DurationSecondArray::from(vec![Some(90), None])),
+                Arc::new(IntervalYearMonthArray::from(vec![Some(14), None])),
+                Arc::new(BinaryArray::from_opt_vec(vec![Some(b"\x01\x02\xff"), None])),
+                Arc::new(
+                    FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                        vec![Some(b"ab"), None].into_iter(),
+                        2,
+                    )
+                    .unwrap(),
+                ),
+            ],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = LineDelimitedWriter::new(&mut buf);
+            writer.write_batches(&[batch.clone()]).unwrap();
+        }
+        assert_json_eq(
+            &buf,
+            r#"{"date":"1970-01-02","time":"01:01:01","ts":"1970-01-02T00:00:00","ts_tz":"1970-01-01T01:00:00+01:00","duration":"PT90S","interval":"1 years 2 mons 0 days 0 hours 0 mins 0.00 secs","binary":"AQL/","fixed":"YWI="}
+{}
+"#,
+        );
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = WriterBuilder::new()
+                .with_date_format("%d/%m/%Y".to_string())
+                .with_time_format("%H-%M".to_string())
+                .with_timestamp_format("%Y%m%d %H%M%S".to_string())
+                .with_timestamp_tz_format("%H:%M %:z".to_string())
+                .with_binary_encoding(BinaryEncoding::Hex)
+                .build::<_, LineDelimited>(&mut buf);
+            writer.write_batches(&[batch]).unwrap();
+        }
+        assert_json_eq(
+            &buf,
+            r#"{"date":"02/01/1970","time":"01-01","ts":"19700102 000000","ts_tz":"01:00 +01:00","duration":"PT90S","interval":"1 years 2 mons 0 days 0 hours 0 mins 0.00 secs","binary":"0102ff","fixed":"6162"}
+{}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_write_run_end_encoded() {
+        let a: Int32RunArray = vec![Some("a"), Some("a"), None, Some("b")]
+            .into_iter()
+            .collect();
+        let b: DictionaryArray<Int16Type> = vec![Some("x"), None, Some("y"), Some("x")]
+            .into_iter()
+            .collect();
+        let c = StructArray::from(vec![(
+            Field::new("d", b.data_type().clone(), true),
+            Arc::new(b) as ArrayRef,
+        )]);
+        let schema = Schema::new(vec![
+            Field::new("a", a.data_type().clone(), true),
+            Field::new("c", c.data_type().clone(), true),
+        ]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), Arc::new(c)])
+                .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = LineDelimitedWriter::new(&mut buf);
+            writer.write_batches(&[batch.slice(1, 3)]).unwrap();
+        }
+        assert_json_eq(
+            &buf,
+            r#"{"a":"a","c":{}}
+{"c":{"d":"y"}}
+{"a":"b","c":{"d":"x"}}
+"#,
+        );
+
+        let encoder = WriterBuilder::new()
+            .with_explicit_nulls(true)
+            .build_encoder();
+        let rows = encoder
+            .encode_bytes(&batch)
+            .unwrap()
+            .map(|row| String::from_utf8(row.unwrap().to_vec()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                r#"{"a":"a","c":{"d":"x"}}"#,
+                r#"{"a":"a","c":{"d":null}}"#,
+                r#"{"a":null,"c":{"d":"y"}}"#,
+                r#"{"a":"b","c":{"d":"x"}}"#,
+            ]
+        );
+    }
 }
@@ -0,0 +1,371 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Comparison kernels for [`Datum`]
+//!
+//! Unlike the kernels in [`comparison`](crate::comparison), these accept either argument
+//! as a [`Scalar`](arrow_array::Scalar), removing the need for separate `*_scalar` kernels.
+//! The `*_dyn_scalar`, `*_dyn_utf8_scalar`, `*_dyn_binary_scalar` and `*_dyn_bool_scalar`
+//! kernels are deprecated in favour of these
+//!
+//! ```
+//! # use arrow_array::{BooleanArray, Int32Array, Scalar};
+//! # use arrow_ord::cmp::lt;
+//! let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+//! let scalar = Scalar::new(Int32Array::from(vec![2]));
+//! let result = lt(&array, &scalar).unwrap();
+//! assert_eq!(result, BooleanArray::from(vec![Some(true), None, Some(false)]));
+//! ```
+
+use crate::comparison::{eq_dyn, gt_dyn, gt_eq_dyn, lt_dyn, lt_eq_dyn, neq_dyn};
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+
+#[derive(Debug, Copy, Clone)]
+enum Op {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl Op {
+    /// Returns the equivalent operation with the operands swapped
+    fn swap(self) -> Self {
+        match self {
+            Op::Equal => Op::Equal,
+            Op::NotEqual => Op::NotEqual,
+            Op::Less => Op::Greater,
+            Op::LessEqual => Op::GreaterEqual,
+            Op::Greater => Op::Less,
+            Op::GreaterEqual => Op::LessEqual,
+        }
+    }
+
+    /// Compares native values using the totalOrder predicate for floats
+    fn native<T: ArrowNativeTypeOp>(self, l: T, r: T) -> bool {
+        match self {
+            Op::Equal => l.is_eq(r),
+            Op::NotEqual => l.is_ne(r),
+            Op::Less => l.is_lt(r),
+            Op::LessEqual => l.is_le(r),
+            Op::Greater => l.is_gt(r),
+            Op::GreaterEqual => l.is_ge(r),
+        }
+    }
+
+    fn ord<T: PartialOrd + ?Sized>(self, l: &T, r: &T) -> bool {
+        match self {
+            Op::Equal => l == r,
+            Op::NotEqual => l != r,
+            Op::Less => l < r,
+            Op::LessEqual => l <= r,
+            Op::Greater => l > r,
+            Op::GreaterEqual => l >= r,
+        }
+    }
+
+    fn dyn_op(self, l: &dyn Array, r: &dyn Array) -> Result<BooleanArray, ArrowError> {
+        match self {
+            Op::Equal => eq_dyn(l, r),
+            Op::NotEqual => neq_dyn(l, r),
+            Op::Less => lt_dyn(l, r),
+            Op::LessEqual => lt_eq_dyn(l, r),
+            Op::Greater => gt_dyn(l, r),
+            Op::GreaterEqual => gt_eq_dyn(l, r),
+        }
+    }
+}
+
+/// Perform `left == right` operation on two [`Datum`]
+///
+/// For floating values like f32 and f64, this comparison produces an ordering in accordance to
+/// the totalOrder predicate as defined in the IEEE 754 (2008 revision) floating point standard.
+/// Note that totalOrder treats positive and negative zeros are different. If it is necessary
+/// to treat them as equal, please normalize zeros before calling this kernel.
+/// Please refer to `f32::total_cmp` and `f64::total_cmp`.
+pub fn eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::Equal, lhs, rhs)
+}
+
+/// Perform `left != right` operation on two [`Datum`]
+///
+/// See [`eq`] for the handling of floating point values
+pub fn neq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::NotEqual, lhs, rhs)
+}
+
+/// Perform `left < right` operation on two [`Datum`]
+///
+/// See [`eq`] for the handling of floating point values
+pub fn lt(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::Less, lhs, rhs)
+}
+
+/// Perform `left <= right` operation on two [`Datum`]
+///
+/// See [`eq`] for the handling of floating point values
+pub fn lt_eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::LessEqual, lhs, rhs)
+}
+
+/// Perform `left > right` operation on two [`Datum`]
+///
+/// See [`eq`] for the handling of floating point values
+pub fn gt(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::Greater, lhs, rhs)
+}
+
+/// Perform `left >= right` operation on two [`Datum`]
+///
+/// See [`eq`] for the handling of floating point values
+pub fn gt_eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+    compare_op(Op::GreaterEqual, lhs, rhs)
+}
+
+fn compare_op(
+    op: Op,
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+) -> Result<BooleanArray, ArrowError> {
+    let (l, l_scalar) = lhs.get();
+    let (r, r_scalar) = rhs.get();
+
+    // Normalize so that the scalar, if any, is on the right
+    let (array, scalar, op) = match (l_scalar, r_scalar) {
+        (false, true) => (l, r, op),
+        (true, false) => (r, l, op.swap()),
+        _ => return op.dyn_op(l, r),
+    };
+
+    scalar_op(op, array, scalar)
+}
+
+/// Compares `array` with the single value in `scalar`
+fn scalar_op(
+    op: Op,
+    array: &dyn Array,
+    scalar: &dyn Array,
+) -> Result<BooleanArray, ArrowError> {
+    if scalar.is_null(0) && array.data_type() == scalar.data_type() {
+        return Ok(BooleanArray::from(vec![None; array.len()]));
+    }
+
+    match (array.data_type(), scalar.data_type()) {
+        (DataType::Boolean, DataType::Boolean) => {
+            let s = scalar.as_boolean().value(0);
+            Ok(BooleanArray::from_unary(array.as_boolean(), |v| {
+                op.ord(&v, &s)
+            }))
+        }
+        (DataType::Utf8, DataType::Utf8) => Ok(bytes_op::<Utf8Type>(op, array, scalar)),
+        (DataType::LargeUtf8, DataType::LargeUtf8) => {
+            Ok(bytes_op::<LargeUtf8Type>(op, array, scalar))
+        }
+        (DataType::Binary, DataType::Binary) => {
+            Ok(bytes_op::<BinaryType>(op, array, scalar))
+        }
+        (DataType::LargeBinary, DataType::LargeBinary) => {
+            Ok(bytes_op::<LargeBinaryType>(op, array, scalar))
+        }
+        (DataType::Dictionary(_, v), t) if v.as_ref() == t => {
+            // Compare the dictionary values once and then expand by the keys
+            downcast_dictionary_array!(
+                array => {
+                    let values = scalar_op(op, array.values().as_ref(), scalar)?;
+                    let result = take(&values, array.keys(), None)?;
+                    Ok(result.as_boolean().clone())
+                }
+                t => unreachable!("unexpected dictionary type {}", t)
+            )
+        }
+        (DataType::Decimal128(_, l), DataType::Decimal128(_, r))
+        | (DataType::Decimal256(_, l), DataType::Decimal256(_, r))
+            if l != r =>
+        {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot compare {} with {} as they have different scales",
+                array.data_type(),
+                scalar.data_type()
+            )))
+        }
+        _ => downcast_primitive_array!(
+            (array, scalar) => {
+                let s = scalar.value(0);
+                Ok(BooleanArray::from_unary(array, |v| op.native(v, s)))
+            }
+            _ => {
+                // Fallback to broadcasting the scalar to the length of the array
+                let indices = UInt32Array::from(vec![0; array.len()]);
+                let scalar = take(scalar, &indices, None)?;
+                op.dyn_op(array, scalar.as_ref())
+            }
+        ),
+    }
+}
+
+fn bytes_op<T: ByteArrayType>(
+    op: Op,
+    array: &dyn Array,
+    scalar: &dyn Array,
+) -> BooleanArray
+where
+    T::Native: PartialOrd,
+{
+    let s = scalar.as_bytes::<T>().value(0);
+    BooleanArray::from_unary(array.as_bytes::<T>(), |v| op.ord(v, s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_primitive_scalar() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(2)]);
+        let s = Scalar::new(Int32Array::from(vec![2]));
+
+        let r = lt(&a, &s).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(false)])
+        );
+
+        // Scalar on the left swaps the operation
+        let r = lt(&s, &a).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(false), None, Some(true), Some(false)])
+        );
+
+        let r = eq(&a, &s).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(false), None, Some(false), Some(true)])
+        );
+
+        let r = gt_eq(&s, &a).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)])
+        );
+
+        let null = Scalar::new(Int32Array::from(vec![None]));
+        let r = neq(&a, &null).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![None; 4]));
+
+        let r = gt(&s, &s).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false]));
+    }
+
+    #[test]
+    fn test_float_scalar() {
+        let a = Float64Array::from(vec![f64::NAN, -0.0, 0.0, 1.0]);
+        let s = Scalar::new(Float64Array::from(vec![0.0]));
+        let r = lt_eq(&a, &s).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, true, false]));
+
+        let s = Scalar::new(Float64Array::from(vec![f64::NAN]));
+        let r = eq(&a, &s).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, false, false]));
+    }
+
+    #[test]
+    fn test_bytes_scalar() {
+        let a = StringArray::from(vec![Some("a"), Some("b"), None, Some("c")]);
+        let s = Scalar::new(StringArray::from(vec!["b"]));
+        let r = gt(&a, &s).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(false), Some(false), None, Some(true)])
+        );
+
+        let a = LargeBinaryArray::from(vec![b"a".as_ref(), b"b"]);
+        let s = Scalar::new(LargeBinaryArray::from(vec![b"b".as_ref()]));
+        let r = neq(&s, &a).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false]));
+
+        let a = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        let s = Scalar::new(BooleanArray::from(vec![true]));
+        let r = lt(&a, &s).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(false), None, Some(true)]));
+    }
+
+    #[test]
+    fn test_dictionary_scalar() {
+        let a: DictionaryArray<Int8Type> = vec![Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+        let s = Scalar::new(StringArray::from(vec!["a"]));
+        let r = eq(&a, &s).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)])
+        );
+
+        let a: ArrayRef = Arc::new(a);
+        let r = gt(&s, &a).unwrap();
+        assert_eq!(
+            r,
+            BooleanArray::from(vec![Some(false), None, Some(false), Some(false)])
+        );
+    }
+
+    #[test]
+    fn test_arrays() {
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let b = Int32Array::from(vec![3, 2, 1]);
+        let r = lt(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![true, false, false]));
+
+        let b: &dyn Array = &b;
+        let r = gt_eq(&a, &b).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![false, true, true]));
+
+        let c = StringArray::from(vec!["a"]);
+        let s = Scalar::new(&c);
+        let err = eq(&a, &s).unwrap_err().to_string();
+        assert!(err.contains("Int32"), "{err}");
+    }
+
+    #[test]
+    fn test_decimal_scalar() {
+        let a = Decimal128Array::from(vec![Some(100), None, Some(150)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let s = Decimal128Array::from(vec![100])
+            .with_precision_and_scale(12, 2)
+            .unwrap();
+        let r = eq(&a, &Scalar::new(s)).unwrap();
+        assert_eq!(r, BooleanArray::from(vec![Some(true), None, Some(false)]));
+
+        let s = Decimal128Array::from(vec![10])
+            .with_precision_and_scale(5, 1)
+            .unwrap();
+        let err = eq(&a, &Scalar::new(s)).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Cannot compare Decimal128(10, 2) with Decimal128(5, 1) as they have different scales"
+        );
+    }
+}
@@ -23,15 +23,17 @@
 //! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
 //!
 
+use crate::run_end::run_end_decode;
 use arrow_array::cast::*;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::buffer::buffer_unary_not;
-use arrow_buffer::{bit_util, Buffer, MutableBuffer};
+use arrow_buffer::{bit_util, ArrowNativeType, Buffer, MutableBuffer};
 use arrow_data::bit_mask::combine_option_bitmap;
 use arrow_data::ArrayData;
 use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
 use arrow_select::take::take;
+use half::f16;
 
 /// Helper function to perform boolean lambda function on values from two array accessors, this
 /// version does not attempt to use SIMD.
@@ -508,6 +510,11 @@ macro_rules! dyn_compare_scalar {
                 let left = as_primitive_array::<UInt64Type>($LEFT);
                 $OP::<UInt64Type>(left, right)
             }
+            DataType::Float16 => {
+                let right = try_to_type!($RIGHT, to_f32)?;
+                let left = as_primitive_array::<Float16Type>($LEFT);
+                $OP::<Float16Type>(left, f16::from_f32(right))
+            }
             DataType::Float32 => {
                 let right = try_to_type!($RIGHT, to_f32)?;
                 let left = as_primitive_array::<Float32Type>($LEFT);
@@ -518,6 +525,26 @@ macro_rules! dyn_compare_scalar {
                 let left = as_primitive_array::<Float64Type>($LEFT);
                 $OP::<Float64Type>(left, right)
             }
+            DataType::Duration(TimeUnit::Second) => {
+                let right = try_to_type!($RIGHT, to_i64)?;
+                let left = as_primitive_array::<DurationSecondType>($LEFT);
+                $OP::<DurationSecondType>(left, right)
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                let right = try_to_type!($RIGHT, to_i64)?;
+                let left = as_primitive_array::<DurationMillisecondType>($LEFT);
+                $OP::<DurationMillisecondType>(left, right)
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                let right = try_to_type!($RIGHT, to_i64)?;
+                let left = as_primitive_array::<DurationMicrosecondType>($LEFT);
+                $OP::<DurationMicrosecondType>(left, right)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                let right = try_to_type!($RIGHT, to_i64)?;
+                let left = as_primitive_array::<DurationNanosecondType>($LEFT);
+                $OP::<DurationNanosecondType>(left, right)
+            }
             DataType::Decimal128(_, _) => {
                 let right = try_to_type!($RIGHT, to_i128)?;
                 let left = as_primitive_array::<Decimal128Type>($LEFT);
@@ -574,56 +601,38 @@ macro_rules! dyn_compare_scalar {
     }};
 }
 
-macro_rules! dyn_compare_utf8_scalar {
-    ($LEFT: expr, $RIGHT: expr, $KT: ident, $OP: ident) => {{
-        match $KT.as_ref() {
-            DataType::UInt8 => {
-                let left = as_dictionary_array::<UInt8Type>($LEFT);
-                let values = as_string_array(left.values());
-                unpack_dict_comparison(left, $OP(values, $RIGHT)?)
-            }
-            DataType::UInt16 => {
-                let left = as_dictionary_array::<UInt16Type>($LEFT);
-                let values = as_string_array(left.values());
-                unpack_dict_comparison(left, $OP(values, $RIGHT)?)
-            }
-            DataType::UInt32 => {
-                let left = as_dictionary_array::<UInt32Type>($LEFT);
-                let values = as_string_array(left.values());
-                unpack_dict_comparison(left, $OP(values, $RIGHT)?)
-            }
-            DataType::UInt64 => {
-                let left = as_dictionary_array::<UInt64Type>($LEFT);
-                let values = as_string_array(left.values());
-                unpack_dict_comparison(left, $OP(values, $RIGHT)?)
-            }
-            DataType::Int8 => {
-                let left = as_dictionary_array::<Int8Type>($LEFT);
-                let values = as_string_array(left.values());
-                unpack_dict_comparison(left, $OP(values, $RIGHT)?)
-            }
+/// Applies `$OP` to the values of the run end encoded `$LEFT` with run ends of type `$RT`,
+/// expanding the result to a `BooleanArray` of the logical length of `$LEFT`
+macro_rules! dyn_compare_run_scalar {
+    ($LEFT: expr, $RIGHT: expr, $RT: ident, $OP: ident) => {{
+        match $RT.data_type() {
             DataType::Int16 => {
-                let left = as_dictionary_array::<Int16Type>($LEFT);
-                let values = as_string_array(left.values());
-                unpack_dict_comparison(left, $OP(values, $RIGHT)?)
+                let left = as_run_array::<Int16Type>($LEFT);
+                unpack_run_comparison(&cmp_run_scalar(left, |v| $OP(v, $RIGHT))?)
             }
             DataType::Int32 => {
-                let left = as_dictionary_array::<Int32Type>($LEFT);
-                let values = as_string_array(left.values());
-                unpack_dict_comparison(left, $OP(values, $RIGHT)?)
+                let left = as_run_array::<Int32Type>($LEFT);
+                unpack_run_comparison(&cmp_run_scalar(left, |v| $OP(v, $RIGHT))?)
             }
             DataType::Int64 => {
-                let left = as_dictionary_array::<Int64Type>($LEFT);
-                let values = as_string_array(left.values());
-                unpack_dict_comparison(left, $OP(values, $RIGHT)?)
+                let left = as_run_array::<Int64Type>($LEFT);
+                unpack_run_comparison(&cmp_run_scalar(left, |v| $OP(v, $RIGHT))?)
             }
-            _ => Err(ArrowError::ComputeError(String::from("Unknown key type"))),
+            _ => Err(ArrowError::ComputeError(format!(
+                "Unsupported run end type {:?}",
+                $RT.data_type()
+            ))),
         }
     }};
 }
 
 /// Perform `left == right` operation on an array and a numeric scalar
-/// value. Supports PrimitiveArrays, and DictionaryArrays that have primitive values.
+/// value. Supports PrimitiveArrays, and DictionaryArrays and RunArrays that have primitive values.
+///
+/// For DictionaryArrays the scalar is only compared against the dictionary values, with
+/// the results then mapped through the keys, avoiding unpacking the dictionary. The same
+/// applies to the other `*_dyn_scalar`, `*_dyn_utf8_scalar`, `*_dyn_binary_scalar` and
+/// `*_dyn_bool_scalar` kernels.
 ///
 /// If `simd` feature flag is not enabled:
 /// For floating values like f32 and f64, this comparison produces an ordering in accordance to
@@ -631,11 +640,15 @@ macro_rules! dyn_compare_utf8_scalar {
 /// Note that totalOrder treats positive and negative zeros are different. If it is necessary
 /// to treat them as equal, please normalize zeros before calling this kernel.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
+#[deprecated(note = "Use arrow_ord::cmp::eq")]
 pub fn eq_dyn_scalar<T>(left: &dyn Array, right: T) -> Result<BooleanArray, ArrowError>
 where
     T: num::ToPrimitive + std::fmt::Debug,
 {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, eq_dyn_scalar)
+        }
         DataType::Dictionary(key_type, _value_type) => {
             dyn_compare_scalar!(left, right, key_type, eq_dyn_scalar)
         }
@@ -644,7 +657,7 @@ where
 }
 
 /// Perform `left < right` operation on an array and a numeric scalar
-/// value. Supports PrimitiveArrays, and DictionaryArrays that have primitive values.
+/// value. Supports PrimitiveArrays, and DictionaryArrays and RunArrays that have primitive values.
 ///
 /// If `simd` feature flag is not enabled:
 /// For floating values like f32 and f64, this comparison produces an ordering in accordance to
@@ -652,11 +665,15 @@ where
 /// Note that totalOrder treats positive and negative zeros are different. If it is necessary
 /// to treat them as equal, please normalize zeros before calling this kernel.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
+#[deprecated(note = "Use arrow_ord::cmp::lt")]
 pub fn lt_dyn_scalar<T>(left: &dyn Array, right: T) -> Result<BooleanArray, ArrowError>
 where
     T: num::ToPrimitive + std::fmt::Debug,
 {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, lt_dyn_scalar)
+        }
         DataType::Dictionary(key_type, _value_type) => {
             dyn_compare_scalar!(left, right, key_type, lt_dyn_scalar)
         }
@@ -665,7 +682,7 @@ where
 }
 
 /// Perform `left <= right` operation on an array and a numeric scalar
-/// value. Supports PrimitiveArrays, and DictionaryArrays that have primitive values.
+/// value. Supports PrimitiveArrays, and DictionaryArrays and RunArrays that have primitive values.
 ///
 /// If `simd` feature flag is not enabled:
 /// For floating values like f32 and f64, this comparison produces an ordering in accordance to
@@ -673,11 +690,15 @@ where
 /// Note that totalOrder treats positive and negative zeros are different. If it is necessary
 /// to treat them as equal, please normalize zeros before calling this kernel.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
+#[deprecated(note = "Use arrow_ord::cmp::lt_eq")]
 pub fn lt_eq_dyn_scalar<T>(left: &dyn Array, right: T) -> Result<BooleanArray, ArrowError>
 where
     T: num::ToPrimitive + std::fmt::Debug,
 {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, lt_eq_dyn_scalar)
+        }
         DataType::Dictionary(key_type, _value_type) => {
             dyn_compare_scalar!(left, right, key_type, lt_eq_dyn_scalar)
         }
@@ -686,7 +707,7 @@ where
 }
 
 /// Perform `left > right` operation on an array and a numeric scalar
-/// value. Supports PrimitiveArrays, and DictionaryArrays that have primitive values.
+/// value. Supports PrimitiveArrays, and DictionaryArrays and RunArrays that have primitive values.
 ///
 /// If `simd` feature flag is not enabled:
 /// For floating values like f32 and f64, this comparison produces an ordering in accordance to
@@ -694,11 +715,15 @@ where
 /// Note that totalOrder treats positive and negative zeros are different. If it is necessary
 /// to treat them as equal, please normalize zeros before calling this kernel.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
+#[deprecated(note = "Use arrow_ord::cmp::gt")]
 pub fn gt_dyn_scalar<T>(left: &dyn Array, right: T) -> Result<BooleanArray, ArrowError>
 where
     T: num::ToPrimitive + std::fmt::Debug,
 {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, gt_dyn_scalar)
+        }
         DataType::Dictionary(key_type, _value_type) => {
             dyn_compare_scalar!(left, right, key_type, gt_dyn_scalar)
         }
@@ -707,7 +732,7 @@ where
 }
 
 /// Perform `left >= right` operation on an array and a numeric scalar
-/// value. Supports PrimitiveArrays, and DictionaryArrays that have primitive values.
+/// value. Supports PrimitiveArrays, and DictionaryArrays and RunArrays that have primitive values.
 ///
 /// If `simd` feature flag is not enabled:
 /// For floating values like f32 and f64, this comparison produces an ordering in accordance to
@@ -715,11 +740,15 @@ where
 /// Note that totalOrder treats positive and negative zeros are different. If it is necessary
 /// to treat them as equal, please normalize zeros before calling this kernel.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
+#[deprecated(note = "Use arrow_ord::cmp::gt_eq")]
 pub fn gt_eq_dyn_scalar<T>(left: &dyn Array, right: T) -> Result<BooleanArray, ArrowError>
 where
     T: num::ToPrimitive + std::fmt::Debug,
 {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, gt_eq_dyn_scalar)
+        }
         DataType::Dictionary(key_type, _value_type) => {
             dyn_compare_scalar!(left, right, key_type, gt_eq_dyn_scalar)
         }
@@ -728,7 +757,7 @@ where
 }
 
 /// Perform `left != right` operation on an array and a numeric scalar
-/// value. Supports PrimitiveArrays, and DictionaryArrays that have primitive values.
+/// value. Supports PrimitiveArrays, and DictionaryArrays and RunArrays that have primitive values.
 ///
 /// If `simd` feature flag is not enabled:
 /// For floating values like f32 and f64, this comparison produces an ordering in accordance to
@@ -736,11 +765,15 @@ where
 /// Note that totalOrder treats positive and negative zeros are different. If it is necessary
 /// to treat them as equal, please normalize zeros before calling this kernel.
 /// Please refer to `f32::total_cmp` and `f64::total_cmp`.
+#[deprecated(note = "Use arrow_ord::cmp::neq")]
 pub fn neq_dyn_scalar<T>(left: &dyn Array, right: T) -> Result<BooleanArray, ArrowError>
 where
     T: num::ToPrimitive + std::fmt::Debug,
 {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, neq_dyn_scalar)
+        }
         DataType::Dictionary(key_type, _value_type) => {
             dyn_compare_scalar!(left, right, key_type, neq_dyn_scalar)
         }
@@ -749,12 +782,25 @@ where
 }
 
 /// Perform `left == right` operation on an array and a numeric scalar
-/// value. Supports BinaryArray and LargeBinaryArray
+/// value. Supports BinaryArray and LargeBinaryArray, and DictionaryArrays and RunArrays that
+/// have binary values
+#[deprecated(note = "Use arrow_ord::cmp::eq")]
 pub fn eq_dyn_binary_scalar(
     left: &dyn Array,
     right: &[u8],
 ) -> Result<BooleanArray, ArrowError> {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, eq_dyn_binary_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if matches!(
+                value_type.as_ref(),
+                DataType::Binary | DataType::LargeBinary
+            ) =>
+        {
+            dyn_compare_scalar!(left, right, key_type, eq_dyn_binary_scalar)
+        }
         DataType::Binary => {
             let left = as_generic_binary_array::<i32>(left);
             eq_binary_scalar(left, right)
@@ -770,12 +816,25 @@ pub fn eq_dyn_binary_scalar(
 }
 
 /// Perform `left != right` operation on an array and a numeric scalar
-/// value. Supports BinaryArray and LargeBinaryArray
+/// value. Supports BinaryArray and LargeBinaryArray, and DictionaryArrays and RunArrays that
+/// have binary values
+#[deprecated(note = "Use arrow_ord::cmp::neq")]
 pub fn neq_dyn_binary_scalar(
     left: &dyn Array,
     right: &[u8],
 ) -> Result<BooleanArray, ArrowError> {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, neq_dyn_binary_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if matches!(
+                value_type.as_ref(),
+                DataType::Binary | DataType::LargeBinary
+            ) =>
+        {
+            dyn_compare_scalar!(left, right, key_type, neq_dyn_binary_scalar)
+        }
         DataType::Binary => {
             let left = as_generic_binary_array::<i32>(left);
             neq_binary_scalar(left, right)
@@ -792,12 +851,25 @@ pub fn neq_dyn_binary_scalar(
 }
 
 /// Perform `left < right` operation on an array and a numeric scalar
-/// value. Supports BinaryArray and LargeBinaryArray
+/// value. Supports BinaryArray and LargeBinaryArray, and DictionaryArrays and RunArrays that
+/// have binary values
+#[deprecated(note = "Use arrow_ord::cmp::lt")]
 pub fn lt_dyn_binary_scalar(
     left: &dyn Array,
     right: &[u8],
 ) -> Result<BooleanArray, ArrowError> {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, lt_dyn_binary_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if matches!(
+                value_type.as_ref(),
+                DataType::Binary | DataType::LargeBinary
+            ) =>
+        {
+            dyn_compare_scalar!(left, right, key_type, lt_dyn_binary_scalar)
+        }
         DataType::Binary => {
             let left = as_generic_binary_array::<i32>(left);
             lt_binary_scalar(left, right)
@@ -813,12 +885,25 @@ pub fn lt_dyn_binary_scalar(
 }
 
 /// Perform `left <= right` operation on an array and a numeric scalar
-/// value. Supports BinaryArray and LargeBinaryArray
+/// value. Supports BinaryArray and LargeBinaryArray, and DictionaryArrays and RunArrays that
+/// have binary values
+#[deprecated(note = "Use arrow_ord::cmp::lt_eq")]
 pub fn lt_eq_dyn_binary_scalar(
     left: &dyn Array,
     right: &[u8],
 ) -> Result<BooleanArray, ArrowError> {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, lt_eq_dyn_binary_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if matches!(
+                value_type.as_ref(),
+                DataType::Binary | DataType::LargeBinary
+            ) =>
+        {
+            dyn_compare_scalar!(left, right, key_type, lt_eq_dyn_binary_scalar)
+        }
         DataType::Binary => {
             let left = as_generic_binary_array::<i32>(left);
             lt_eq_binary_scalar(left, right)
@@ -835,12 +920,25 @@ pub fn lt_eq_dyn_binary_scalar(
 }
 
 /// Perform `left > right` operation on an array and a numeric scalar
-/// value. Supports BinaryArray and LargeBinaryArray
+/// value. Supports BinaryArray and LargeBinaryArray, and DictionaryArrays and RunArrays that
+/// have binary values
+#[deprecated(note = "Use arrow_ord::cmp::gt")]
 pub fn gt_dyn_binary_scalar(
     left: &dyn Array,
     right: &[u8],
 ) -> Result<BooleanArray, ArrowError> {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, gt_dyn_binary_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if matches!(
+                value_type.as_ref(),
+                DataType::Binary | DataType::LargeBinary
+            ) =>
+        {
+            dyn_compare_scalar!(left, right, key_type, gt_dyn_binary_scalar)
+        }
         DataType::Binary => {
             let left = as_generic_binary_array::<i32>(left);
             gt_binary_scalar(left, right)
@@ -856,12 +954,25 @@ pub fn gt_dyn_binary_scalar(
 }
 
 /// Perform `left >= right` operation on an array and a numeric scalar
-/// value. Supports BinaryArray and LargeBinaryArray
+/// value. Supports BinaryArray and LargeBinaryArray, and DictionaryArrays and RunArrays that
+/// have binary values
+#[deprecated(note = "Use arrow_ord::cmp::gt_eq")]
 pub fn gt_eq_dyn_binary_scalar(
     left: &dyn Array,
     right: &[u8],
 ) -> Result<BooleanArray, ArrowError> {
     match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, gt_eq_dyn_binary_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if matches!(
+                value_type.as_ref(),
+                DataType::Binary | DataType::LargeBinary
+            ) =>
+        {
+            dyn_compare_scalar!(left, right, key_type, gt_eq_dyn_binary_scalar)
+        }
         DataType::Binary => {
             let left = as_generic_binary_array::<i32>(left);
             gt_eq_binary_scalar(left, right)
@@ -878,15 +989,19 @@ pub fn gt_eq_dyn_binary_scalar(
 }
 
 /// Perform `left == right` operation on an array and a numeric scalar
-/// value. Supports StringArrays, and DictionaryArrays that have string values
+/// value. Supports StringArrays, and DictionaryArrays and RunArrays that have string values
+#[deprecated(note = "Use arrow_ord::cmp::eq")]
 pub fn eq_dyn_utf8_scalar(
     left: &dyn Array,
     right: &str,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, eq_dyn_utf8_scalar)
+        }
         DataType::Dictionary(key_type, value_type) => match value_type.as_ref() {
             DataType::Utf8 | DataType::LargeUtf8 => {
-                dyn_compare_utf8_scalar!(left, right, key_type, eq_utf8_scalar)
+                dyn_compare_scalar!(left, right, key_type, eq_dyn_utf8_scalar)
             }
             _ => Err(ArrowError::ComputeError(
                 "eq_dyn_utf8_scalar only supports Utf8 or LargeUtf8 arrays or DictionaryArray with Utf8 or LargeUtf8 values".to_string(),
@@ -908,15 +1023,19 @@ pub fn eq_dyn_utf8_scalar(
 }
 
 /// Perform `left < right` operation on an array and a numeric scalar
-/// value. Supports StringArrays, and DictionaryArrays that have string values
+/// value. Supports StringArrays, and DictionaryArrays and RunArrays that have string values
+#[deprecated(note = "Use arrow_ord::cmp::lt")]
 pub fn lt_dyn_utf8_scalar(
     left: &dyn Array,
     right: &str,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, lt_dyn_utf8_scalar)
+        }
         DataType::Dictionary(key_type, value_type) => match value_type.as_ref() {
             DataType::Utf8 | DataType::LargeUtf8 => {
-                dyn_compare_utf8_scalar!(left, right, key_type, lt_utf8_scalar)
+                dyn_compare_scalar!(left, right, key_type, lt_dyn_utf8_scalar)
             }
             _ => Err(ArrowError::ComputeError(
                 "lt_dyn_utf8_scalar only supports Utf8 or LargeUtf8 arrays or DictionaryArray with Utf8 or LargeUtf8 values".to_string(),
@@ -938,15 +1057,19 @@ pub fn lt_dyn_utf8_scalar(
 }
 
 /// Perform `left >= right` operation on an array and a numeric scalar
-/// value. Supports StringArrays, and DictionaryArrays that have string values
+/// value. Supports StringArrays, and DictionaryArrays and RunArrays that have string values
+#[deprecated(note = "Use arrow_ord::cmp::gt_eq")]
 pub fn gt_eq_dyn_utf8_scalar(
     left: &dyn Array,
     right: &str,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, gt_eq_dyn_utf8_scalar)
+        }
         DataType::Dictionary(key_type, value_type) => match value_type.as_ref() {
             DataType::Utf8 | DataType::LargeUtf8 => {
-                dyn_compare_utf8_scalar!(left, right, key_type, gt_eq_utf8_scalar)
+                dyn_compare_scalar!(left, right, key_type, gt_eq_dyn_utf8_scalar)
             }
             _ => Err(ArrowError::ComputeError(
                 "gt_eq_dyn_utf8_scalar only supports Utf8 or LargeUtf8 arrays or DictionaryArray with Utf8 or LargeUtf8 values".to_string(),
@@ -968,15 +1091,19 @@ pub fn gt_eq_dyn_utf8_scalar(
 }
 
 /// Perform `left <= right` operation on an array and a numeric scalar
-/// value. Supports StringArrays, and DictionaryArrays that have string values
+/// value. Supports StringArrays, and DictionaryArrays and RunArrays that have string values
+#[deprecated(note = "Use arrow_ord::cmp::lt_eq")]
 pub fn lt_eq_dyn_utf8_scalar(
     left: &dyn Array,
     right: &str,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, lt_eq_dyn_utf8_scalar)
+        }
         DataType::Dictionary(key_type, value_type) => match value_type.as_ref() {
             DataType::Utf8 | DataType::LargeUtf8 => {
-                dyn_compare_utf8_scalar!(left, right, key_type, lt_eq_utf8_scalar)
+                dyn_compare_scalar!(left, right, key_type, lt_eq_dyn_utf8_scalar)
             }
             _ => Err(ArrowError::ComputeError(
                 "lt_eq_dyn_utf8_scalar only supports Utf8 or LargeUtf8 arrays or DictionaryArray with Utf8 or LargeUtf8 values".to_string(),
@@ -998,15 +1125,19 @@ pub fn lt_eq_dyn_utf8_scalar(
 }
 
 /// Perform `left > right` operation on an array and a numeric scalar
-/// value. Supports StringArrays, and DictionaryArrays that have string values
+/// value. Supports StringArrays, and DictionaryArrays and RunArrays that have string values
+#[deprecated(note = "Use arrow_ord::cmp::gt")]
 pub fn gt_dyn_utf8_scalar(
     left: &dyn Array,
     right: &str,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, gt_dyn_utf8_scalar)
+        }
         DataType::Dictionary(key_type, value_type) => match value_type.as_ref() {
             DataType::Utf8 | DataType::LargeUtf8 => {
-                dyn_compare_utf8_scalar!(left, right, key_type, gt_utf8_scalar)
+                dyn_compare_scalar!(left, right, key_type, gt_dyn_utf8_scalar)
             }
             _ => Err(ArrowError::ComputeError(
                 "gt_dyn_utf8_scalar only supports Utf8 or LargeUtf8 arrays or DictionaryArray with Utf8 or LargeUtf8 values".to_string(),
@@ -1028,15 +1159,19 @@ pub fn gt_dyn_utf8_scalar(
 }
 
 /// Perform `left != right` operation on an array and a numeric scalar
-/// value. Supports StringArrays, and DictionaryArrays that have string values
+/// value. Supports StringArrays, and DictionaryArrays and RunArrays that have string values
+#[deprecated(note = "Use arrow_ord::cmp::neq")]
 pub fn neq_dyn_utf8_scalar(
     left: &dyn Array,
     right: &str,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, neq_dyn_utf8_scalar)
+        }
         DataType::Dictionary(key_type, value_type) => match value_type.as_ref() {
             DataType::Utf8 | DataType::LargeUtf8 => {
-                dyn_compare_utf8_scalar!(left, right, key_type, neq_utf8_scalar)
+                dyn_compare_scalar!(left, right, key_type, neq_dyn_utf8_scalar)
             }
             _ => Err(ArrowError::ComputeError(
                 "neq_dyn_utf8_scalar only supports Utf8 or LargeUtf8 arrays or DictionaryArray with Utf8 or LargeUtf8 values".to_string(),
@@ -1058,12 +1193,21 @@ pub fn neq_dyn_utf8_scalar(
 }
 
 /// Perform `left == right` operation on an array and a numeric scalar
-/// value.
+/// value. Supports BooleanArrays, and DictionaryArrays and RunArrays that have boolean values.
+#[deprecated(note = "Use arrow_ord::cmp::eq")]
 pub fn eq_dyn_bool_scalar(
     left: &dyn Array,
     right: bool,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, eq_dyn_bool_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if value_type.as_ref() == &DataType::Boolean =>
+        {
+            dyn_compare_scalar!(left, right, key_type, eq_dyn_bool_scalar)
+        }
         DataType::Boolean => {
             let left = as_boolean_array(left);
             eq_bool_scalar(left, right)
@@ -1076,12 +1220,21 @@ pub fn eq_dyn_bool_scalar(
 }
 
 /// Perform `left < right` operation on an array and a numeric scalar
-/// value. Supports BooleanArrays.
+/// value. Supports BooleanArrays, and DictionaryArrays and RunArrays that have boolean values.
+#[deprecated(note = "Use arrow_ord::cmp::lt")]
 pub fn lt_dyn_bool_scalar(
     left: &dyn Array,
     right: bool,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, lt_dyn_bool_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if value_type.as_ref() == &DataType::Boolean =>
+        {
+            dyn_compare_scalar!(left, right, key_type, lt_dyn_bool_scalar)
+        }
         DataType::Boolean => {
             let left = as_boolean_array(left);
             lt_bool_scalar(left, right)
@@ -1094,12 +1247,21 @@ pub fn lt_dyn_bool_scalar(
 }
 
 /// Perform `left > right` operation on an array and a numeric scalar
-/// value. Supports BooleanArrays.
+/// value. Supports BooleanArrays, and DictionaryArrays and RunArrays that have boolean values.
+#[deprecated(note = "Use arrow_ord::cmp::gt")]
 pub fn gt_dyn_bool_scalar(
     left: &dyn Array,
     right: bool,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, gt_dyn_bool_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if value_type.as_ref() == &DataType::Boolean =>
+        {
+            dyn_compare_scalar!(left, right, key_type, gt_dyn_bool_scalar)
+        }
         DataType::Boolean => {
             let left = as_boolean_array(left);
             gt_bool_scalar(left, right)
@@ -1112,12 +1274,21 @@ pub fn gt_dyn_bool_scalar(
 }
 
 /// Perform `left <= right` operation on an array and a numeric scalar
-/// value. Supports BooleanArrays.
+/// value. Supports BooleanArrays, and DictionaryArrays and RunArrays that have boolean values.
+#[deprecated(note = "Use arrow_ord::cmp::lt_eq")]
 pub fn lt_eq_dyn_bool_scalar(
     left: &dyn Array,
     right: bool,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, lt_eq_dyn_bool_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if value_type.as_ref() == &DataType::Boolean =>
+        {
+            dyn_compare_scalar!(left, right, key_type, lt_eq_dyn_bool_scalar)
+        }
         DataType::Boolean => {
             let left = as_boolean_array(left);
             lt_eq_bool_scalar(left, right)
@@ -1130,12 +1301,21 @@ pub fn lt_eq_dyn_bool_scalar(
 }
 
 /// Perform `left >= right` operation on an array and a numeric scalar
-/// value. Supports BooleanArrays.
+/// value. Supports BooleanArrays, and DictionaryArrays and RunArrays that have boolean values.
+#[deprecated(note = "Use arrow_ord::cmp::gt_eq")]
 pub fn gt_eq_dyn_bool_scalar(
     left: &dyn Array,
     right: bool,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, gt_eq_dyn_bool_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if value_type.as_ref() == &DataType::Boolean =>
+        {
+            dyn_compare_scalar!(left, right, key_type, gt_eq_dyn_bool_scalar)
+        }
         DataType::Boolean => {
             let left = as_boolean_array(left);
             gt_eq_bool_scalar(left, right)
@@ -1148,12 +1328,21 @@ pub fn gt_eq_dyn_bool_scalar(
 }
 
 /// Perform `left != right` operation on an array and a numeric scalar
-/// value. Supports BooleanArrays.
+/// value. Supports BooleanArrays, and DictionaryArrays and RunArrays that have boolean values.
+#[deprecated(note = "Use arrow_ord::cmp::neq")]
 pub fn neq_dyn_bool_scalar(
     left: &dyn Array,
     right: bool,
 ) -> Result<BooleanArray, ArrowError> {
     let result = match left.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => {
+            dyn_compare_run_scalar!(left, right, run_ends, neq_dyn_bool_scalar)
+        }
+        DataType::Dictionary(key_type, value_type)
+            if value_type.as_ref() == &DataType::Boolean =>
+        {
+            dyn_compare_scalar!(left, right, key_type, neq_dyn_bool_scalar)
+        }
         DataType::Boolean => {
             let left = as_boolean_array(left);
             neq_bool_scalar(left, right)
@@ -1165,6 +1354,55 @@ pub fn neq_dyn_bool_scalar(
     result
 }
 
+/// Compares the values of a, possibly sliced, [`RunArray`] using `op`, returning
+/// the result as a run end encoded boolean array with the same runs as `left`.
+///
+/// As `op` is only evaluated once per run, this avoids work proportional to the
+/// logical length of `left`.
+///
+/// ```
+/// # use arrow_array::{Array, BooleanArray, Int32RunArray};
+/// # use arrow_ord::comparison::{cmp_run_scalar, eq_dyn_utf8_scalar};
+/// let array: Int32RunArray = vec!["a", "a", "b", "b", "a"].into_iter().collect();
+/// let result = cmp_run_scalar(&array, |values| eq_dyn_utf8_scalar(values, "a")).unwrap();
+/// assert_eq!(result.run_ends().values(), &[2, 4, 5]);
+/// let values = result.values().as_any().downcast_ref::<BooleanArray>().unwrap();
+/// assert_eq!(values, &BooleanArray::from(vec![true, false, true]));
+/// ```
+pub fn cmp_run_scalar<R, F>(left: &RunArray<R>, op: F) -> Result<RunArray<R>, ArrowError>
+where
+    R: RunEndIndexType,
+    F: FnOnce(&dyn Array) -> Result<BooleanArray, ArrowError>,
+{
+    if left.is_empty() {
+        let run_ends = PrimitiveArray::<R>::from_iter_values(std::iter::empty());
+        return RunArray::try_new(&run_ends, &op(left.values().slice(0, 0).as_ref())?);
+    }
+
+    let start = left.get_start_physical_index();
+    let end = left.get_end_physical_index();
+    let offset = left.offset();
+    let len = left.len();
+
+    let values = left.values().slice(start, end - start + 1);
+    let values = op(values.as_ref())?;
+    let run_ends = PrimitiveArray::<R>::from_iter_values(
+        left.run_ends().values()[start..=end].iter().map(|run_end| {
+            let run_end = (run_end.as_usize() - offset).min(len);
+            R::Native::from_usize(run_end).unwrap()
+        }),
+    );
+    RunArray::try_new(&run_ends, &values)
+}
+
+/// unpacks the results of comparing the values of a run end encoded array
+fn unpack_run_comparison<R: RunEndIndexType>(
+    run_comparison: &RunArray<R>,
+) -> Result<BooleanArray, ArrowError> {
+    let array = run_end_decode(run_comparison)?;
+    Ok(BooleanArray::from(array.data().clone()))
+}
+
 /// unpacks the results of comparing left.values (as a boolean)
 ///
 /// TODO add example
@@ -1519,6 +1757,9 @@ macro_rules! typed_cmp_dict_non_dict {
                 (DataType::UInt64, DataType::UInt64) => {
                     typed_dict_non_dict_cmp!($LEFT, $RIGHT, left_key_type.as_ref(), UInt64Type, $OP_BOOL, $OP)
                 }
+                (DataType::Float16, DataType::Float16) => {
+                    typed_dict_non_dict_cmp!($LEFT, $RIGHT, left_key_type.as_ref(), Float16Type, $OP_BOOL, $OP_FLOAT)
+                }
                 (DataType::Float32, DataType::Float32) => {
                     typed_dict_non_dict_cmp!($LEFT, $RIGHT, left_key_type.as_ref(), Float32Type, $OP_BOOL, $OP_FLOAT)
                 }
@@ -1616,6 +1857,9 @@ macro_rules! typed_compares {
             (DataType::UInt64, DataType::UInt64) => {
                 cmp_primitive_array::<UInt64Type, _>($LEFT, $RIGHT, $OP)
             }
+            (DataType::Float16, DataType::Float16) => {
+                cmp_primitive_array::<Float16Type, _>($LEFT, $RIGHT, $OP_FLOAT)
+            }
             (DataType::Float32, DataType::Float32) => {
                 cmp_primitive_array::<Float32Type, _>($LEFT, $RIGHT, $OP_FLOAT)
             }
@@ -1707,6 +1951,22 @@ macro_rules! typed_compares {
                 DataType::Interval(IntervalUnit::MonthDayNano),
                 DataType::Interval(IntervalUnit::MonthDayNano),
             ) => cmp_primitive_array::<IntervalMonthDayNanoType, _>($LEFT, $RIGHT, $OP),
+            (
+                DataType::Duration(TimeUnit::Second),
+                DataType::Duration(TimeUnit::Second),
+            ) => cmp_primitive_array::<DurationSecondType, _>($LEFT, $RIGHT, $OP),
+            (
+                DataType::Duration(TimeUnit::Millisecond),
+                DataType::Duration(TimeUnit::Millisecond),
+            ) => cmp_primitive_array::<DurationMillisecondType, _>($LEFT, $RIGHT, $OP),
+            (
+                DataType::Duration(TimeUnit::Microsecond),
+                DataType::Duration(TimeUnit::Microsecond),
+            ) => cmp_primitive_array::<DurationMicrosecondType, _>($LEFT, $RIGHT, $OP),
+            (
+                DataType::Duration(TimeUnit::Nanosecond),
+                DataType::Duration(TimeUnit::Nanosecond),
+            ) => cmp_primitive_array::<DurationNanosecondType, _>($LEFT, $RIGHT, $OP),
             (t1, t2) if t1 == t2 => Err(ArrowError::NotYetImplemented(format!(
                 "Comparing arrays of type {} is not yet implemented",
                 t1
@@ -1751,6 +2011,9 @@ macro_rules! typed_dict_cmp {
             (DataType::UInt64, DataType::UInt64) => {
                 cmp_dict::<$KT, UInt64Type, _>($LEFT, $RIGHT, $OP)
             }
+            (DataType::Float16, DataType::Float16) => {
+                cmp_dict::<$KT, Float16Type, _>($LEFT, $RIGHT, $OP_FLOAT)
+            }
             (DataType::Float32, DataType::Float32) => {
                 cmp_dict::<$KT, Float32Type, _>($LEFT, $RIGHT, $OP_FLOAT)
             }
@@ -2799,6 +3062,7 @@ fn new_all_set_buffer(len: usize) -> Buffer {
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
 #[cfg(test)]
+#[allow(deprecated)]
 mod tests {
     use super::*;
     use arrow_array::builder::{
@@ -3464,6 +3728,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_duration_array() {
+        let a = DurationSecondArray::from(vec![Some(1), Some(6), None, Some(-3)]);
+        let b = DurationSecondArray::from(vec![Some(2), Some(6), Some(4), Some(-4)]);
+        assert_eq!(
+            eq_dyn(&a, &b).unwrap(),
+            BooleanArray::from(vec![Some(false), Some(true), None, Some(false)])
+        );
+        assert_eq!(
+            lt_dyn(&a, &b).unwrap(),
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(false)])
+        );
+        assert_eq!(
+            gt_dyn_scalar(&a, 1).unwrap(),
+            BooleanArray::from(vec![Some(false), Some(true), None, Some(false)])
+        );
+
+        let b = DurationMillisecondArray::from(vec![1, 6, 4, -3]);
+        let err = eq_dyn(&a, &b).unwrap_err();
+        assert!(err.to_string().contains("different types"), "{}", err);
+    }
+
     #[test]
     fn test_interval_array() {
         let a = IntervalDayTimeArray::from(
@@ -3950,6 +4236,38 @@ mod tests {
 
         let array = array.unary::<_, Float64Type>(|x| x as f64);
         assert_eq!(eq_dyn_scalar(&array, 8).unwrap(), expected);
+
+        let array = array.unary::<_, Float16Type>(f16::from_f64);
+        assert_eq!(eq_dyn_scalar(&array, 8).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_cmp_dyn_f16() {
+        let a = Float16Array::from_iter([
+            Some(f16::from_f32(1.5)),
+            None,
+            Some(f16::NAN),
+            Some(f16::from_f32(-2.0)),
+        ]);
+        let b = Float16Array::from_iter([
+            Some(f16::from_f32(1.5)),
+            Some(f16::ONE),
+            Some(f16::NAN),
+            Some(f16::ZERO),
+        ]);
+
+        assert_eq!(
+            eq_dyn(&a, &b).unwrap(),
+            BooleanArray::from(vec![Some(true), None, Some(true), Some(false)])
+        );
+        assert_eq!(
+            lt_dyn(&a, &b).unwrap(),
+            BooleanArray::from(vec![Some(false), None, Some(false), Some(true)])
+        );
+        assert_eq!(
+            gt_eq_dyn_scalar(&a, 1.5).unwrap(),
+            BooleanArray::from(vec![Some(true), None, Some(true), Some(false)])
+        );
     }
 
     #[test]
@@ -4432,6 +4750,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dyn_scalar_with_dict_values() {
+        // Dictionary values are compared without unpacking, including null values
+        // and values not referenced by any key
+        let keys = Int8Array::from(vec![Some(2), None, Some(0), Some(2), Some(1)]);
+
+        let values =
+            LargeStringArray::from(vec![Some("abc"), None, Some("def"), Some("x")]);
+        let array = DictionaryArray::try_new(&keys, &values).unwrap();
+        let a_eq = eq_dyn_utf8_scalar(&array, "def").unwrap();
+        assert_eq!(
+            a_eq,
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true), None])
+        );
+        let a_lt = lt_dyn_utf8_scalar(&array, "b").unwrap();
+        assert_eq!(
+            a_lt,
+            BooleanArray::from(vec![Some(false), None, Some(true), Some(false), None])
+        );
+
+        let values =
+            BinaryArray::from_opt_vec(vec![Some(b"abc"), None, Some(b"def"), Some(b"x")]);
+        let array = DictionaryArray::try_new(&keys, &values).unwrap();
+        let a_neq = neq_dyn_binary_scalar(&array, b"def").unwrap();
+        assert_eq!(
+            a_neq,
+            BooleanArray::from(vec![Some(false), None, Some(true), Some(false), None])
+        );
+        let a_gt_eq = gt_eq_dyn_binary_scalar(&array, b"b").unwrap();
+        assert_eq!(
+            a_gt_eq,
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true), None])
+        );
+
+        let values = BooleanArray::from(vec![Some(false), None, Some(true)]);
+        let array = DictionaryArray::try_new(&keys, &values).unwrap();
+        let a_eq = eq_dyn_bool_scalar(&array, true).unwrap();
+        assert_eq!(
+            a_eq,
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true), None])
+        );
+        let a_gt = gt_dyn_bool_scalar(&array, false).unwrap();
+        assert_eq!(
+            a_gt,
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true), None])
+        );
+
+        let values = Int32Array::from(vec![1, 2, 3]);
+        let array = DictionaryArray::try_new(&keys, &values).unwrap();
+        let err = eq_dyn_bool_scalar(&array, true).unwrap_err().to_string();
+        assert!(err.contains("only supports BooleanArray"), "{err}");
+    }
+
     #[test]
     fn test_eq_dyn_bool_scalar() {
         let array = BooleanArray::from(vec![true, false, true]);
@@ -4492,6 +4863,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dyn_scalar_with_run_array() {
+        let run_ends = Int32Array::from(vec![2, 3, 6, 7]);
+        let values = Int64Array::from(vec![Some(1), None, Some(3), Some(1)]);
+        let array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+
+        let t = Some(true);
+        let f = Some(false);
+        let expected = BooleanArray::from(vec![t, t, None, f, f, f, t]);
+        assert_eq!(eq_dyn_scalar(&array, 1).unwrap(), expected);
+        let expected = BooleanArray::from(vec![f, f, None, t, t, t, f]);
+        assert_eq!(neq_dyn_scalar(&array, 1).unwrap(), expected);
+        let expected = BooleanArray::from(vec![t, t, None, f, f, f, t]);
+        assert_eq!(lt_dyn_scalar(&array, 3).unwrap(), expected);
+        let expected = BooleanArray::from(vec![t, t, None, t, t, t, t]);
+        assert_eq!(lt_eq_dyn_scalar(&array, 3).unwrap(), expected);
+        let expected = BooleanArray::from(vec![f, f, None, t, t, t, f]);
+        assert_eq!(gt_dyn_scalar(&array, 1).unwrap(), expected);
+        let expected = BooleanArray::from(vec![f, f, None, t, t, t, f]);
+        assert_eq!(gt_eq_dyn_scalar(&array, 3).unwrap(), expected);
+
+        // Sliced arrays only compare the logical values within the slice
+        let sliced = array.slice(1, 4);
+        let expected = BooleanArray::from(vec![t, None, f, f]);
+        assert_eq!(eq_dyn_scalar(sliced.as_ref(), 1).unwrap(), expected);
+        assert_eq!(eq_dyn_scalar(&array.slice(7, 0), 1).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_dyn_utf8_scalar_with_run_array() {
+        let array: Int16RunArray = vec![Some("a"), Some("a"), None, Some("b"), Some("c")]
+            .into_iter()
+            .collect();
+        let t = Some(true);
+        let f = Some(false);
+        let expected = BooleanArray::from(vec![f, f, None, t, f]);
+        assert_eq!(eq_dyn_utf8_scalar(&array, "b").unwrap(), expected);
+        let expected = BooleanArray::from(vec![t, t, None, f, t]);
+        assert_eq!(neq_dyn_utf8_scalar(&array, "b").unwrap(), expected);
+        let expected = BooleanArray::from(vec![t, t, None, f, f]);
+        assert_eq!(lt_dyn_utf8_scalar(&array, "b").unwrap(), expected);
+        let expected = BooleanArray::from(vec![t, t, None, t, f]);
+        assert_eq!(lt_eq_dyn_utf8_scalar(&array, "b").unwrap(), expected);
+        let expected = BooleanArray::from(vec![f, f, None, f, t]);
+        assert_eq!(gt_dyn_utf8_scalar(&array, "b").unwrap(), expected);
+        let expected = BooleanArray::from(vec![f, f, None, t, t]);
+        assert_eq!(gt_eq_dyn_utf8_scalar(&array, "b").unwrap(), expected);
+
+        // Run array with dictionary values
+        let values: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        let run_ends = Int64Array::from(vec![1, 3, 4]);
+        let array = RunArray::<Int64Type>::try_new(&run_ends, &values).unwrap();
+        let expected = BooleanArray::from(vec![t, f, f, t]);
+        assert_eq!(eq_dyn_utf8_scalar(&array, "a").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_dyn_bool_and_binary_scalar_with_run_array() {
+        let run_ends = Int32Array::from(vec![1, 3, 4]);
+        let values = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(false), Some(false), None]);
+        assert_eq!(eq_dyn_bool_scalar(&array, true).unwrap(), expected);
+        assert_eq!(gt_dyn_bool_scalar(&array, false).unwrap(), expected);
+
+        let values =
+            BinaryArray::from(vec![Some(b"a".as_ref()), Some(b"b".as_ref()), None]);
+        let array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(false), Some(true), Some(true), None]);
+        assert_eq!(eq_dyn_binary_scalar(&array, b"b").unwrap(), expected);
+        assert_eq!(gt_dyn_binary_scalar(&array, b"a").unwrap(), expected);
+    }
+
+    #[test]
+    fn test_cmp_run_scalar() {
+        let array: Int32RunArray = vec!["a", "a", "b", "b", "b", "a", "c"]
+            .into_iter()
+            .collect();
+        let sliced = array.slice(1, 5);
+        let sliced = as_run_array::<Int32Type>(sliced.as_ref());
+        let result =
+            cmp_run_scalar(sliced, |values| eq_dyn_utf8_scalar(values, "b")).unwrap();
+        assert_eq!(result.len(), 5);
+        assert_eq!(result.run_ends().values(), &[1, 4, 5]);
+        let values = as_boolean_array(result.values());
+        assert_eq!(values, &BooleanArray::from(vec![false, true, false]));
+
+        let err = cmp_run_scalar(&array, |values| eq_dyn_scalar(values, 1)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Unsupported data type Utf8 for comparison eq_scalar with 1"
+        );
+    }
+
     #[test]
     fn test_eq_dyn_neq_dyn_fixed_size_binary() {
         let values1: Vec<Option<&[u8]>> =
@@ -17,7 +17,10 @@
 
 //! Arrow ordering kernels
 
+pub mod cmp;
 pub mod comparison;
 pub mod ord;
 pub mod partition;
+pub mod rank;
+pub mod run_end;
 pub mod sort;
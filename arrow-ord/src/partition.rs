@@ -15,13 +15,112 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines partition kernel for `ArrayRef`
+//! Defines partition kernels for `ArrayRef`
 
+use crate::ord::build_compare;
 use crate::sort::{LexicographicalComparator, SortColumn};
+use arrow_array::{Array, ArrayRef};
 use arrow_schema::ArrowError;
 use std::cmp::Ordering;
 use std::ops::Range;
 
+/// The partitions of a set of columns computed by [`partition`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partitions {
+    /// The index of the first row of each partition, excluding the first partition
+    boundaries: Vec<usize>,
+    num_rows: usize,
+}
+
+impl Partitions {
+    /// Returns the range of rows covered by each partition, in order
+    pub fn ranges(&self) -> Vec<Range<usize>> {
+        if self.num_rows == 0 {
+            return vec![];
+        }
+        let mut start = 0;
+        let mut ranges = Vec::with_capacity(self.len());
+        for end in self.boundaries.iter().copied().chain(Some(self.num_rows)) {
+            ranges.push(start..end);
+            start = end;
+        }
+        ranges
+    }
+
+    /// Returns the number of partitions
+    pub fn len(&self) -> usize {
+        match self.num_rows {
+            0 => 0,
+            _ => self.boundaries.len() + 1,
+        }
+    }
+
+    /// Returns true if there are no partitions, i.e. the columns have no rows
+    pub fn is_empty(&self) -> bool {
+        self.num_rows == 0
+    }
+}
+
+/// Given a list of columns in which equal rows are contiguous, such as columns sorted
+/// in any order, returns the [`Partitions`] of consecutive equal rows.
+///
+/// Unlike [`lexicographical_partition_ranges`] this does not require the sort order of
+/// the columns, and visits each row once, making it suitable for streaming group by
+/// over pre-sorted data. Null values are considered equal to each other, as are NaN
+/// values.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StringArray};
+/// # use arrow_ord::partition::partition;
+/// let columns: Vec<ArrayRef> = vec![
+///     Arc::new(Int32Array::from(vec![Some(1), Some(1), Some(2), Some(2), None])),
+///     Arc::new(StringArray::from(vec!["a", "a", "a", "b", "b"])),
+/// ];
+/// let partitions = partition(&columns).unwrap();
+/// assert_eq!(partitions.len(), 4);
+/// assert_eq!(partitions.ranges(), vec![0..2, 2..3, 3..4, 4..5]);
+/// ```
+pub fn partition(columns: &[ArrayRef]) -> Result<Partitions, ArrowError> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Partition requires at least one column".to_string(),
+        ));
+    }
+    let num_rows = columns[0].len();
+    if columns.iter().any(|item| item.len() != num_rows) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Partition columns have different row counts".to_string(),
+        ));
+    };
+
+    // is_boundary[i] is true if row i + 1 starts a new partition
+    let mut is_boundary = vec![false; num_rows.saturating_sub(1)];
+    for column in columns {
+        let comparator = build_compare(column.as_ref(), column.as_ref())?;
+        for (idx, boundary) in is_boundary.iter_mut().enumerate() {
+            if *boundary {
+                continue;
+            }
+            *boundary = match (column.is_valid(idx), column.is_valid(idx + 1)) {
+                (true, true) => comparator(idx, idx + 1).is_ne(),
+                (false, false) => false,
+                _ => true,
+            };
+        }
+    }
+
+    let boundaries = is_boundary
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, boundary)| boundary.then_some(idx + 1))
+        .collect();
+    Ok(Partitions {
+        boundaries,
+        num_rows,
+    })
+}
+
 /// Given a list of already sorted columns, find partition ranges that would partition
 /// lexicographically equal values across columns.
 ///
@@ -375,4 +474,52 @@ mod tests {
             results.collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn test_partition() {
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Float64Array::from(vec![
+                Some(f64::NAN),
+                Some(f64::NAN),
+                None,
+                None,
+                Some(1.0),
+                Some(1.0),
+            ])),
+            // Sorted descending
+            Arc::new(Int64Array::from(vec![3, 3, 3, 2, 1, 1])),
+        ];
+        let partitions = partition(&columns).unwrap();
+        assert_eq!(partitions.len(), 4);
+        assert!(!partitions.is_empty());
+        assert_eq!(partitions.ranges(), vec![0..2, 2..3, 3..4, 4..6]);
+
+        let partitions = partition(&columns[..1]).unwrap();
+        assert_eq!(partitions.ranges(), vec![0..2, 2..4, 4..6]);
+
+        let array: DictionaryArray<arrow_array::types::Int8Type> =
+            vec!["a", "a", "b"].into_iter().collect();
+        let partitions = partition(&[Arc::new(array) as ArrayRef]).unwrap();
+        assert_eq!(partitions.ranges(), vec![0..2, 2..3]);
+    }
+
+    #[test]
+    fn test_partition_edge_cases() {
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(Vec::<i32>::new())),
+            Arc::new(Int32Array::from(vec![1])),
+        ];
+        let partitions = partition(&columns[..1]).unwrap();
+        assert!(partitions.is_empty());
+        assert_eq!(partitions.len(), 0);
+        assert!(partitions.ranges().is_empty());
+
+        let partitions = partition(&columns[1..]).unwrap();
+        assert_eq!(partitions.ranges(), vec![0..1]);
+
+        let err = partition(&[]).unwrap_err().to_string();
+        assert!(err.contains("at least one column"), "{err}");
+        let err = partition(&columns).unwrap_err().to_string();
+        assert!(err.contains("different row counts"), "{err}");
+    }
 }
@@ -0,0 +1,204 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines rank kernel for [`Array`]
+
+use crate::ord::build_compare;
+use crate::sort::{build_struct_compare, sort_to_indices, SortOptions};
+use arrow_array::{Array, ArrayRef, UInt32Array};
+use arrow_schema::{ArrowError, DataType};
+
+/// The method used by [`rank`] to assign ranks to equal values
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum RankMethod {
+    /// Equal values are assigned the lowest rank of the group, e.g. `[1, 2, 2, 4]`
+    #[default]
+    Min,
+    /// Equal values are assigned the highest rank of the group, e.g. `[1, 3, 3, 4]`
+    Max,
+    /// Like [`RankMethod::Min`], but the rank of each group is one more than the
+    /// previous group, e.g. `[1, 2, 2, 3]`
+    Dense,
+    /// Equal values are assigned distinct ranks in the order they appear in the
+    /// input, e.g. `[1, 2, 3, 4]`
+    Ordinal,
+}
+
+/// Assigns a rank, starting from 1, to each value of `values` according to its position
+/// when sorted with `options`, using `method` to assign ranks to equal values.
+///
+/// Null values are considered equal to each other and are ranked before or after all
+/// other values according to [`SortOptions::nulls_first`]. Struct arrays are ranked
+/// by comparing their fields in order, as for [`sort_to_indices`]
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, UInt32Array};
+/// # use arrow_ord::rank::{rank, RankMethod};
+/// let values: ArrayRef = Arc::new(Int32Array::from(vec![Some(5), None, Some(2), Some(5)]));
+///
+/// let ranks = rank(&values, None, RankMethod::Min).unwrap();
+/// assert_eq!(ranks, UInt32Array::from(vec![3, 1, 2, 3]));
+///
+/// let ranks = rank(&values, None, RankMethod::Dense).unwrap();
+/// assert_eq!(ranks, UInt32Array::from(vec![3, 1, 2, 3]));
+///
+/// let ranks = rank(&values, None, RankMethod::Max).unwrap();
+/// assert_eq!(ranks, UInt32Array::from(vec![4, 1, 2, 4]));
+/// ```
+pub fn rank(
+    values: &ArrayRef,
+    options: Option<SortOptions>,
+    method: RankMethod,
+) -> Result<UInt32Array, ArrowError> {
+    let len = values.len();
+    let sorted = sort_to_indices(values, options, None)?;
+    let sorted = sorted.values();
+
+    let comparator = match values.data_type() {
+        DataType::Struct(_) => {
+            build_struct_compare(values.as_ref(), options.unwrap_or_default())?
+        }
+        _ => build_compare(values.as_ref(), values.as_ref())?,
+    };
+    let is_equal = |a: usize, b: usize| match (values.is_valid(a), values.is_valid(b)) {
+        (true, true) => comparator(a, b).is_eq(),
+        (false, false) => true,
+        _ => false,
+    };
+
+    let mut ranks = vec![0_u32; len];
+    let mut group_start = 0;
+    let mut dense_rank = 0;
+    while group_start < len {
+        let first = sorted[group_start] as usize;
+        let group_end = (group_start + 1..len)
+            .find(|idx| !is_equal(first, sorted[*idx] as usize))
+            .unwrap_or(len);
+        let group = &sorted[group_start..group_end];
+        dense_rank += 1;
+
+        match method {
+            RankMethod::Min => assign(&mut ranks, group, group_start as u32 + 1),
+            RankMethod::Max => assign(&mut ranks, group, group_end as u32),
+            RankMethod::Dense => assign(&mut ranks, group, dense_rank),
+            RankMethod::Ordinal => {
+                // The sort is unstable, so order equal values by their position
+                let mut group = group.to_vec();
+                group.sort_unstable();
+                for (offset, idx) in group.into_iter().enumerate() {
+                    ranks[idx as usize] = (group_start + offset) as u32 + 1;
+                }
+            }
+        }
+        group_start = group_end;
+    }
+
+    Ok(UInt32Array::from(ranks))
+}
+
+fn assign(ranks: &mut [u32], indices: &[u32], rank: u32) {
+    indices.iter().for_each(|idx| ranks[*idx as usize] = rank);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Float64Array, Int32Array, StringArray, StructArray};
+    use arrow_schema::Field;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_rank() {
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(3),
+            None,
+            Some(1),
+            Some(3),
+            None,
+            Some(2),
+            Some(3),
+        ]));
+
+        let ranks = rank(&values, None, RankMethod::Min).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![5, 1, 3, 5, 1, 4, 5]));
+
+        let ranks = rank(&values, None, RankMethod::Max).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![7, 2, 3, 7, 2, 4, 7]));
+
+        let ranks = rank(&values, None, RankMethod::Dense).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![4, 1, 2, 4, 1, 3, 4]));
+
+        let ranks = rank(&values, None, RankMethod::Ordinal).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![5, 1, 3, 6, 2, 4, 7]));
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let ranks = rank(&values, Some(options), RankMethod::Min).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![1, 6, 5, 1, 6, 4, 1]));
+
+        let ranks = rank(&values, Some(options), RankMethod::Ordinal).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![1, 6, 5, 2, 7, 4, 3]));
+    }
+
+    #[test]
+    fn test_rank_types() {
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["b", "a", "c", "a", "b"]));
+        let ranks = rank(&values, None, RankMethod::Dense).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![2, 1, 3, 1, 2]));
+
+        let values: ArrayRef =
+            Arc::new(Float64Array::from(vec![f64::NAN, 1.0, f64::NAN, -1.0]));
+        let ranks = rank(&values, None, RankMethod::Min).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![3, 2, 3, 1]));
+
+        let values: ArrayRef = Arc::new(Int32Array::from(Vec::<i32>::new()));
+        let ranks = rank(&values, None, RankMethod::Min).unwrap();
+        assert!(ranks.is_empty());
+    }
+
+    #[test]
+    fn test_rank_struct() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(2),
+            Some(1),
+            Some(2),
+            None,
+            Some(1),
+        ]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "x", "z", "x"]));
+        let values: ArrayRef = Arc::new(StructArray::from(vec![
+            (Field::new("a", a.data_type().clone(), true), a),
+            (Field::new("b", b.data_type().clone(), false), b),
+        ]));
+
+        let ranks = rank(&values, None, RankMethod::Min).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![4, 3, 4, 1, 2]));
+
+        let ranks = rank(&values, None, RankMethod::Dense).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![4, 3, 4, 1, 2]));
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let ranks = rank(&values, Some(options), RankMethod::Max).unwrap();
+        assert_eq!(ranks, UInt32Array::from(vec![2, 3, 2, 5, 4]));
+    }
+}
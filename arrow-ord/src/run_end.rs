@@ -0,0 +1,249 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to convert arrays to and from run-end encoding, see [`RunArray`]
+
+use crate::ord::build_compare;
+use arrow_array::builder::UInt64Builder;
+use arrow_array::types::RunEndIndexType;
+use arrow_array::*;
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::ArrowError;
+use arrow_select::take::take;
+use std::cmp::Ordering;
+
+/// Run-end encodes `array`, producing a [`RunArray`] with a run for each sequence of
+/// consecutive equal values. Consecutive nulls form a single run of null values.
+///
+/// Returns an error if the length of `array` cannot be represented by the run end type `R`.
+///
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_array::types::Int16Type;
+/// # use arrow_ord::run_end::run_end_encode;
+/// let array = Int32Array::from(vec![Some(1), Some(1), None, None, Some(2)]);
+/// let run_array = run_end_encode::<Int16Type>(&array).unwrap();
+/// assert_eq!(run_array.run_ends().values(), &[2, 4, 5]);
+/// let values = run_array.values().as_any().downcast_ref::<Int32Array>().unwrap();
+/// assert_eq!(values, &Int32Array::from(vec![Some(1), None, Some(2)]));
+/// ```
+pub fn run_end_encode<R: RunEndIndexType>(
+    array: &dyn Array,
+) -> Result<RunArray<R>, ArrowError> {
+    let len = array.len();
+    let run_end_err = || {
+        ArrowError::ComputeError(format!(
+            "Cannot represent array of length {} with run end type {:?}",
+            len,
+            R::DATA_TYPE
+        ))
+    };
+
+    let is_equal = build_equal(array);
+
+    let mut run_ends = Vec::<R::Native>::new();
+    let mut run_values = UInt64Builder::new();
+    for idx in 0..len {
+        if idx > 0 && is_equal(idx - 1, idx) {
+            continue;
+        }
+        if idx > 0 {
+            run_ends.push(R::Native::from_usize(idx).ok_or_else(run_end_err)?);
+        }
+        run_values.append_value(idx as u64);
+    }
+    if len > 0 {
+        run_ends.push(R::Native::from_usize(len).ok_or_else(run_end_err)?);
+    }
+
+    let run_ends = PrimitiveArray::<R>::from_iter_values(run_ends);
+    let values = take(array, &run_values.finish(), None)?;
+    RunArray::try_new(&run_ends, &values)
+}
+
+/// Returns a function that returns true if the values at two indices of `array` are
+/// equal, where two nulls are considered equal
+pub(crate) fn build_equal(array: &dyn Array) -> impl Fn(usize, usize) -> bool + '_ {
+    // Types not supported by `build_compare`, such as nested types,
+    // fall back to comparing slices of the underlying data
+    let compare = build_compare(array, array).ok();
+    let data = array.data();
+    move |i: usize, j: usize| match (array.is_null(i), array.is_null(j)) {
+        (true, true) => true,
+        (false, false) => match &compare {
+            Some(compare) => compare(i, j) == Ordering::Equal,
+            None => data.slice(i, 1) == data.slice(j, 1),
+        },
+        _ => false,
+    }
+}
+
+/// Decodes a, possibly sliced, [`RunArray`] into an array of its values type containing
+/// the logical values of `array`
+///
+/// ```
+/// # use arrow_array::{Array, Int32RunArray, StringArray};
+/// # use arrow_ord::run_end::run_end_decode;
+/// let array: Int32RunArray = vec!["a", "a", "b", "c", "c"].into_iter().collect();
+/// let decoded = run_end_decode(&array).unwrap();
+/// let decoded = decoded.as_any().downcast_ref::<StringArray>().unwrap();
+/// assert_eq!(decoded, &StringArray::from(vec!["a", "a", "b", "c", "c"]));
+/// ```
+pub fn run_end_decode<R: RunEndIndexType>(
+    array: &RunArray<R>,
+) -> Result<ArrayRef, ArrowError> {
+    if array.is_empty() {
+        return Ok(new_empty_array(array.values().data_type()));
+    }
+
+    let start = array.get_start_physical_index();
+    let end = array.get_end_physical_index();
+    let logical_end = array.offset() + array.len();
+
+    let mut indices = UInt64Builder::with_capacity(array.len());
+    let mut run_start = array.offset();
+    for (idx, run_end) in array.run_ends().values()[start..=end].iter().enumerate() {
+        let run_end = run_end.as_usize().min(logical_end);
+        let physical_idx = (start + idx) as u64;
+        (run_start..run_end).for_each(|_| indices.append_value(physical_idx));
+        run_start = run_end;
+    }
+    take(array.values(), &indices.finish(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::builder::{Int32Builder, ListBuilder};
+    use arrow_array::types::{Int16Type, Int32Type, Int64Type};
+    use arrow_schema::DataType;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_run_end_encode_primitive() {
+        let array = Float64Array::from(vec![
+            Some(1.0),
+            Some(1.0),
+            None,
+            None,
+            Some(2.0),
+            Some(1.0),
+        ]);
+        let run_array = run_end_encode::<Int32Type>(&array).unwrap();
+        assert_eq!(run_array.len(), 6);
+        assert_eq!(run_array.run_ends().values(), &[2, 4, 5, 6]);
+        let values = run_array
+            .values()
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(
+            values,
+            &Float64Array::from(vec![Some(1.0), None, Some(2.0), Some(1.0)])
+        );
+
+        let decoded = run_end_decode(&run_array).unwrap();
+        assert_eq!(decoded.as_ref(), &array as &dyn Array);
+    }
+
+    #[test]
+    fn test_run_end_encode_string() {
+        let array = StringArray::from(vec!["a", "a", "a", "b", "c", "c"]);
+        let run_array = run_end_encode::<Int16Type>(&array).unwrap();
+        assert_eq!(run_array.run_ends().values(), &[3, 4, 6]);
+        assert_eq!(run_array.values().len(), 3);
+
+        let decoded = run_end_decode(&run_array).unwrap();
+        assert_eq!(decoded.as_ref(), &array as &dyn Array);
+
+        // Encoding a sliced array only considers the values within the slice
+        let sliced = array.slice(2, 3);
+        let run_array = run_end_encode::<Int64Type>(sliced.as_ref()).unwrap();
+        assert_eq!(run_array.run_ends().values(), &[1, 2, 3]);
+        let decoded = run_end_decode(&run_array).unwrap();
+        assert_eq!(decoded.as_ref(), sliced.as_ref());
+    }
+
+    #[test]
+    fn test_run_end_encode_nested() {
+        let mut builder = ListBuilder::new(Int32Builder::new());
+        for list in [vec![1, 2], vec![1, 2], vec![3], vec![3], vec![1, 2]] {
+            builder.values().append_slice(&list);
+            builder.append(true);
+        }
+        builder.append(false);
+        builder.append(false);
+        let array = builder.finish();
+
+        let run_array = run_end_encode::<Int32Type>(&array).unwrap();
+        assert_eq!(run_array.run_ends().values(), &[2, 4, 5, 7]);
+        assert_eq!(run_array.values().null_count(), 1);
+
+        let decoded = run_end_decode(&run_array).unwrap();
+        assert_eq!(decoded.as_ref(), &array as &dyn Array);
+    }
+
+    #[test]
+    fn test_run_end_encode_empty() {
+        let array = Int32Array::from(Vec::<i32>::new());
+        let run_array = run_end_encode::<Int32Type>(&array).unwrap();
+        assert_eq!(run_array.len(), 0);
+        assert_eq!(run_array.run_ends().len(), 0);
+
+        let decoded = run_end_decode(&run_array).unwrap();
+        assert_eq!(decoded.len(), 0);
+        assert_eq!(decoded.data_type(), &DataType::Int32);
+    }
+
+    #[test]
+    fn test_run_end_encode_overflow() {
+        let array = Int32Array::from(vec![1; i16::MAX as usize + 1]);
+        let err = run_end_encode::<Int16Type>(&array).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot represent array of length 32768 with run end type Int16"
+        );
+        assert!(run_end_encode::<Int32Type>(&array).is_ok());
+    }
+
+    #[test]
+    fn test_run_end_decode_sliced() {
+        let run_ends = Int32Array::from(vec![2, 3, 6, 7]);
+        let values: ArrayRef =
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)]));
+        let run_array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+
+        let sliced = run_array.slice(1, 4);
+        let sliced = sliced
+            .as_any()
+            .downcast_ref::<RunArray<Int32Type>>()
+            .unwrap();
+        let decoded = run_end_decode(sliced).unwrap();
+        assert_eq!(
+            decoded.as_ref(),
+            &Int32Array::from(vec![Some(1), None, Some(3), Some(3)]) as &dyn Array
+        );
+
+        let sliced = run_array.slice(6, 1);
+        let sliced = sliced
+            .as_any()
+            .downcast_ref::<RunArray<Int32Type>>()
+            .unwrap();
+        let decoded = run_end_decode(sliced).unwrap();
+        assert_eq!(decoded.as_ref(), &Int32Array::from(vec![4]) as &dyn Array);
+    }
+}
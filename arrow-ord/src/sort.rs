@@ -18,14 +18,18 @@
 //! Defines sort kernel for `ArrayRef`
 
 use crate::ord::{build_compare, DynComparator};
+use crate::run_end::build_equal;
 use arrow_array::cast::*;
 use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, MutableBuffer};
 use arrow_data::ArrayData;
+use arrow_row::{RowConverter, SortField};
 use arrow_schema::{ArrowError, DataType, IntervalUnit, TimeUnit};
 use arrow_select::take::take;
 use std::cmp::Ordering;
+use std::ops::Range;
+use std::sync::Arc;
 
 pub use arrow_schema::SortOptions;
 
@@ -55,6 +59,9 @@ pub fn sort(
     values: &ArrayRef,
     options: Option<SortOptions>,
 ) -> Result<ArrayRef, ArrowError> {
+    if let DataType::RunEndEncoded(_, _) = values.data_type() {
+        return sort_run(values, options, None);
+    }
     let indices = sort_to_indices(values, options, None)?;
     take(values.as_ref(), &indices, None)
 }
@@ -94,6 +101,9 @@ pub fn sort_limit(
     options: Option<SortOptions>,
     limit: Option<usize>,
 ) -> Result<ArrayRef, ArrowError> {
+    if let DataType::RunEndEncoded(_, _) = values.data_type() {
+        return sort_run(values, options, limit);
+    }
     let indices = sort_to_indices(values, options, limit)?;
     take(values.as_ref(), &indices, None)
 }
@@ -138,6 +148,25 @@ pub fn sort_to_indices(
     options: Option<SortOptions>,
     limit: Option<usize>,
 ) -> Result<UInt32Array, ArrowError> {
+    if let DataType::RunEndEncoded(run_ends, _) = values.data_type() {
+        return match run_ends.data_type() {
+            DataType::Int16 => sort_run_to_indices::<Int16Type>(values, options, limit),
+            DataType::Int32 => sort_run_to_indices::<Int32Type>(values, options, limit),
+            DataType::Int64 => sort_run_to_indices::<Int64Type>(values, options, limit),
+            t => Err(ArrowError::ComputeError(format!(
+                "Unsupported run end type {t:?}"
+            ))),
+        };
+    }
+
+    if let DataType::Struct(_) = values.data_type() {
+        let column = SortColumn {
+            values: values.clone(),
+            options,
+        };
+        return lexsort_to_indices(&[column], limit);
+    }
+
     let options = options.unwrap_or_default();
 
     let (v, n) = partition_validity(values);
@@ -365,6 +394,129 @@ pub fn sort_to_indices(
     })
 }
 
+/// Sorts the values of the runs of a, possibly sliced, [`RunArray`]
+///
+/// Returns the sorted physical indices into the returned values, along with the
+/// logical range of each run relative to the start of the array
+fn sort_run_values<R: RunEndIndexType>(
+    run_array: &RunArray<R>,
+    options: Option<SortOptions>,
+) -> Result<(UInt32Array, ArrayRef, Vec<Range<usize>>), ArrowError> {
+    if run_array.is_empty() {
+        let values = run_array.values().slice(0, 0);
+        return Ok((UInt32Array::from(Vec::<u32>::new()), values, vec![]));
+    }
+
+    let start = run_array.get_start_physical_index();
+    let end = run_array.get_end_physical_index();
+    let offset = run_array.offset();
+    let len = run_array.len();
+
+    let mut run_start = 0;
+    let run_ranges = run_array.run_ends().values()[start..=end]
+        .iter()
+        .map(|run_end| {
+            let run_end = (run_end.as_usize() - offset).min(len);
+            let range = run_start..run_end;
+            run_start = run_end;
+            range
+        })
+        .collect();
+
+    // Only the values of the runs need to be sorted
+    let values = run_array.values().slice(start, end - start + 1);
+    let sorted = sort_to_indices(&values, options, None)?;
+    Ok((sorted, values, run_ranges))
+}
+
+/// Sorts a run end encoded array, producing a run end encoded array with a run for
+/// each run of `values` in sorted order, merging adjacent runs with equal values
+fn sort_run(
+    values: &ArrayRef,
+    options: Option<SortOptions>,
+    limit: Option<usize>,
+) -> Result<ArrayRef, ArrowError> {
+    match values.data_type() {
+        DataType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+            DataType::Int16 => sort_run_inner::<Int16Type>(values, options, limit),
+            DataType::Int32 => sort_run_inner::<Int32Type>(values, options, limit),
+            DataType::Int64 => sort_run_inner::<Int64Type>(values, options, limit),
+            t => Err(ArrowError::ComputeError(format!(
+                "Unsupported run end type {t:?}"
+            ))),
+        },
+        t => Err(ArrowError::ComputeError(format!(
+            "Expected run end encoded array, found {t:?}"
+        ))),
+    }
+}
+
+fn sort_run_inner<R: RunEndIndexType>(
+    values: &ArrayRef,
+    options: Option<SortOptions>,
+    limit: Option<usize>,
+) -> Result<ArrayRef, ArrowError> {
+    let run_array = as_run_array::<R>(values);
+    let (sorted, run_values, run_ranges) = sort_run_values(run_array, options)?;
+
+    let is_equal = build_equal(run_values.as_ref());
+    let mut remaining = limit.unwrap_or(run_array.len()).min(run_array.len());
+    let mut run_end = 0;
+    let mut new_run_ends = Vec::with_capacity(sorted.len());
+    let mut physical_indices: Vec<u32> = Vec::with_capacity(sorted.len());
+    for physical in sorted.values() {
+        if remaining == 0 {
+            break;
+        }
+        let run_len = run_ranges[*physical as usize].len().min(remaining);
+        remaining -= run_len;
+        run_end += run_len;
+        // The logical length cannot be larger than that of the input array
+        let new_run_end = R::Native::from_usize(run_end).unwrap();
+        match physical_indices.last() {
+            // Sorting may place runs with equal values next to each other, merge them
+            Some(last) if is_equal(*last as usize, *physical as usize) => {
+                *new_run_ends.last_mut().unwrap() = new_run_end;
+            }
+            _ => {
+                new_run_ends.push(new_run_end);
+                physical_indices.push(*physical);
+            }
+        }
+    }
+
+    let new_run_ends = PrimitiveArray::<R>::from_iter_values(new_run_ends);
+    let physical_indices = UInt32Array::from(physical_indices);
+    let new_values = take(run_values.as_ref(), &physical_indices, None)?;
+    Ok(Arc::new(RunArray::try_new(
+        &new_run_ends,
+        new_values.as_ref(),
+    )?))
+}
+
+/// Sorts a run end encoded array to the logical indices of its values in sorted order
+fn sort_run_to_indices<R: RunEndIndexType>(
+    values: &ArrayRef,
+    options: Option<SortOptions>,
+    limit: Option<usize>,
+) -> Result<UInt32Array, ArrowError> {
+    let run_array = as_run_array::<R>(values);
+    let (sorted, _, run_ranges) = sort_run_values(run_array, options)?;
+
+    let len = limit.unwrap_or(run_array.len()).min(run_array.len());
+    let mut indices = Vec::with_capacity(len);
+    for physical in sorted.values() {
+        let remaining = len - indices.len();
+        if remaining == 0 {
+            break;
+        }
+        let range = &run_ranges[*physical as usize];
+        let run_len = range.len().min(remaining);
+        indices.extend((range.start..range.start + run_len).map(|idx| idx as u32));
+    }
+    Ok(UInt32Array::from(indices))
+}
+
 /// Sort boolean values
 ///
 /// when a limit is present, the sort is pair-comparison based as k-select might be more efficient,
@@ -884,11 +1036,16 @@ pub fn lexsort(
         .collect()
 }
 
+/// The number of columns from which [`lexsort_to_indices`] converts the
+/// columns to the row format instead of using a [`LexicographicalComparator`]
+const ROW_FORMAT_MIN_COLUMNS: usize = 4;
+
 /// Sort elements lexicographically from a list of `ArrayRef` into an unsigned integer
 /// (`UInt32Array`) of indices.
 ///
-/// Note: for multi-column sorts without a limit, using the [row format](https://docs.rs/arrow/latest/arrow/row/)
-/// may be significantly faster
+/// When sorting by many columns, the columns are converted to the
+/// [row format](https://docs.rs/arrow/latest/arrow/row/) if supported, which is
+/// significantly faster than comparing each column in turn
 pub fn lexsort_to_indices(
     columns: &[SortColumn],
     limit: Option<usize>,
@@ -898,7 +1055,8 @@ pub fn lexsort_to_indices(
             "Sort requires at least one column".to_string(),
         ));
     }
-    if columns.len() == 1 {
+    if columns.len() == 1 && !matches!(columns[0].values.data_type(), DataType::Struct(_))
+    {
         // fallback to non-lexical sort
         let column = &columns[0];
         return sort_to_indices(&column.values, column.options, limit);
@@ -918,6 +1076,30 @@ pub fn lexsort_to_indices(
         len = limit.min(len);
     }
 
+    if columns.len() >= ROW_FORMAT_MIN_COLUMNS {
+        let fields: Vec<_> = columns
+            .iter()
+            .map(|c| {
+                let options = c.options.unwrap_or_default();
+                SortField::new_with_options(c.values.data_type().clone(), options)
+            })
+            .collect();
+
+        if RowConverter::supports_fields(&fields) {
+            // Comparing rows is a single memcmp, avoiding a dynamic comparator per column
+            let mut converter = RowConverter::new(fields)?;
+            let arrays: Vec<_> = columns.iter().map(|c| c.values.clone()).collect();
+            let rows = converter.convert_columns(&arrays)?;
+            sort_unstable_by(&mut value_indices, len, |a, b| {
+                rows.row(*a).cmp(&rows.row(*b))
+            });
+
+            return Ok(UInt32Array::from_iter_values(
+                value_indices.iter().take(len).map(|i| *i as u32),
+            ));
+        }
+    }
+
     let lexicographical_comparator = LexicographicalComparator::try_new(columns)?;
     // uint32 can be sorted unstably
     sort_unstable_by(&mut value_indices, len, |a, b| {
@@ -929,6 +1111,85 @@ pub fn lexsort_to_indices(
     ))
 }
 
+/// Returns the indices of the first `k` rows of `columns` when sorted lexicographically,
+/// i.e. the `k` smallest rows, or the `k` largest if sorted with
+/// [`SortOptions::descending`].
+///
+/// Rather than sorting all rows, this maintains a bounded heap of the `k` rows found so
+/// far, making it well suited to a small `k`, such as for `ORDER BY ... LIMIT k`. Unlike
+/// [`lexsort_to_indices`] the selection is stable, equal rows are returned in the order
+/// they appear in `columns`.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, UInt32Array};
+/// # use arrow_ord::sort::{select_k, SortColumn, SortOptions};
+/// let values: ArrayRef = Arc::new(Int32Array::from(vec![Some(5), None, Some(1), Some(9), Some(5)]));
+/// let column = SortColumn { values, options: None };
+/// let indices = select_k(&[column.clone()], 3).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![1, 2, 0]));
+///
+/// let options = SortOptions { descending: true, nulls_first: false };
+/// let column = SortColumn { options: Some(options), ..column };
+/// let indices = select_k(&[column], 2).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![3, 0]));
+/// ```
+pub fn select_k(columns: &[SortColumn], k: usize) -> Result<UInt32Array, ArrowError> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Sort requires at least one column".to_string(),
+        ));
+    }
+    let row_count = columns[0].values.len();
+    if columns.iter().any(|item| item.values.len() != row_count) {
+        return Err(ArrowError::ComputeError(
+            "lexical sort columns have different row counts".to_string(),
+        ));
+    };
+
+    let comparator = LexicographicalComparator::try_new(columns)?;
+    // Break ties by position so that the selection is stable
+    let cmp = |a: usize, b: usize| comparator.compare(a, b).then(a.cmp(&b));
+
+    // A max-heap of the smallest rows seen so far, with the largest at the root
+    let k = k.min(row_count);
+    let mut heap: Vec<usize> = (0..k).collect();
+    for pos in (0..k / 2).rev() {
+        sift_down(&mut heap, pos, &cmp);
+    }
+    for idx in k..row_count {
+        if k > 0 && cmp(idx, heap[0]).is_lt() {
+            heap[0] = idx;
+            sift_down(&mut heap, 0, &cmp);
+        }
+    }
+
+    heap.sort_unstable_by(|a, b| cmp(*a, *b));
+    Ok(UInt32Array::from_iter_values(
+        heap.into_iter().map(|i| i as u32),
+    ))
+}
+
+/// Restores the max-heap property of `heap` for the subtree rooted at `pos`
+fn sift_down<F>(heap: &mut [usize], mut pos: usize, cmp: &F)
+where
+    F: Fn(usize, usize) -> Ordering,
+{
+    loop {
+        let mut largest = pos;
+        for child in [2 * pos + 1, 2 * pos + 2] {
+            if child < heap.len() && cmp(heap[child], heap[largest]).is_gt() {
+                largest = child;
+            }
+        }
+        if largest == pos {
+            return;
+        }
+        heap.swap(pos, largest);
+        pos = largest;
+    }
+}
+
 /// It's unstable_sort, may not preserve the order of equal elements
 pub fn partial_sort<T, F>(v: &mut [T], limit: usize, mut is_less: F)
 where
@@ -1002,17 +1263,59 @@ impl LexicographicalComparator<'_> {
                 // use ArrayData for is_valid checks later to avoid dynamic call
                 let values = column.values.as_ref();
                 let data = values.data_ref();
-                Ok((
-                    data,
-                    build_compare(values, values)?,
-                    column.options.unwrap_or_default(),
-                ))
+                let options = column.options.unwrap_or_default();
+                let comparator = match values.data_type() {
+                    DataType::Struct(_) => build_struct_compare(values, options)?,
+                    _ => build_compare(values, values)?,
+                };
+                Ok((data, comparator, options))
             })
             .collect::<Result<Vec<_>, ArrowError>>()?;
         Ok(LexicographicalComparator { compare_items })
     }
 }
 
+/// Builds a comparator for a [`StructArray`] that compares its fields in order,
+/// recursing into nested structs and ordering null field values according to `options`
+pub(crate) fn build_struct_compare(
+    array: &dyn Array,
+    options: SortOptions,
+) -> Result<DynComparator, ArrowError> {
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    // The result is reversed for descending sorts, so the null ordering is too
+    let null_ordering = match options.nulls_first != options.descending {
+        true => Ordering::Less,
+        false => Ordering::Greater,
+    };
+
+    let comparators = array
+        .columns()
+        .iter()
+        .map(|column| {
+            let comparator = match column.data_type() {
+                DataType::Struct(_) => build_struct_compare(column.as_ref(), options)?,
+                _ => build_compare(column.as_ref(), column.as_ref())?,
+            };
+            Ok((column.data().clone(), comparator))
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    Ok(Box::new(move |i, j| {
+        for (data, comparator) in &comparators {
+            let ordering = match (data.is_valid(i), data.is_valid(j)) {
+                (true, true) => comparator(i, j),
+                (false, true) => null_ordering,
+                (true, false) => null_ordering.reverse(),
+                (false, false) => Ordering::Equal,
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }))
+}
+
 fn sort_valids<T, U>(
     descending: bool,
     valids: &mut [(u32, T)],
@@ -1055,7 +1358,8 @@ fn sort_valids_array<T>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow_buffer::i256;
+    use arrow_buffer::{i256, Buffer};
+    use arrow_schema::Field;
     use rand::rngs::StdRng;
     use rand::{Rng, RngCore, SeedableRng};
     use std::convert::TryFrom;
@@ -4004,4 +4308,246 @@ mod tests {
             vec![None, None, None, Some(5.1), Some(5.1), Some(3.0), Some(1.2)],
         );
     }
+
+    #[test]
+    fn test_sort_run_to_indices() {
+        let run_ends = Int32Array::from(vec![2, 3, 6, 7]);
+        let values = Int64Array::from(vec![Some(3), None, Some(1), Some(2)]);
+        let array: ArrayRef =
+            Arc::new(RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap());
+
+        let indices = sort_to_indices(&array, None, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![2, 3, 4, 5, 6, 0, 1]));
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let indices = sort_to_indices(&array, Some(options), None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![2, 0, 1, 6, 3, 4, 5]));
+
+        let indices = sort_to_indices(&array, None, Some(4)).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![2, 3, 4, 5]));
+
+        // Indices are relative to the slice
+        let sliced = array.slice(1, 4);
+        let indices = sort_to_indices(&sliced, None, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![1, 2, 3, 0]));
+
+        let empty = array.slice(7, 0);
+        assert_eq!(sort_to_indices(&empty, None, None).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_sort_run() {
+        let array: Int16RunArray = vec!["b", "b", "c", "a", "a", "a", "b"]
+            .into_iter()
+            .collect();
+        let array: ArrayRef = Arc::new(array);
+
+        let sorted = sort(&array, None).unwrap();
+        let sorted = as_run_array::<Int16Type>(&sorted);
+        assert_eq!(sorted.len(), 7);
+        // The runs of "b" are adjacent once sorted and are merged
+        assert_eq!(sorted.run_ends().values(), &[3, 6, 7]);
+        let values = as_string_array(sorted.values());
+        assert_eq!(values, &StringArray::from(vec!["a", "b", "c"]));
+
+        let sorted = sort_limit(&array.slice(1, 5), None, Some(4)).unwrap();
+        let sorted = as_run_array::<Int16Type>(&sorted);
+        assert_eq!(sorted.len(), 4);
+        assert_eq!(sorted.run_ends().values(), &[3, 4]);
+        let values = as_string_array(sorted.values());
+        assert_eq!(values, &StringArray::from(vec!["a", "b"]));
+
+        // Sorting the run array is consistent with sorting the decoded array
+        let options = Some(SortOptions {
+            descending: true,
+            nulls_first: false,
+        });
+        let sorted = sort(&array, options).unwrap();
+        let decoded =
+            crate::run_end::run_end_decode(as_run_array::<Int16Type>(&sorted)).unwrap();
+        let decoded_array =
+            crate::run_end::run_end_decode(as_run_array::<Int16Type>(&array)).unwrap();
+        assert_eq!(&decoded, &sort(&decoded_array, options).unwrap());
+    }
+
+    fn struct_array() -> ArrayRef {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(2),
+            Some(1),
+            None,
+            Some(2),
+            Some(1),
+            Some(0),
+        ]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["b", "b", "a", "a", "c", "z"]));
+        let inner = StructArray::from(vec![
+            (Field::new("a", DataType::Int32, true), a),
+            (Field::new("b", DataType::Utf8, false), b),
+        ]);
+        // The last row is null
+        let outer = StructArray::from((
+            vec![(
+                Field::new("inner", inner.data_type().clone(), false),
+                Arc::new(inner) as ArrayRef,
+            )],
+            Buffer::from([0b00011111]),
+        ));
+        Arc::new(outer)
+    }
+
+    #[test]
+    fn test_sort_struct() {
+        let array = struct_array();
+
+        let indices = sort_to_indices(&array, None, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![5, 2, 1, 4, 3, 0]));
+
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        let indices = sort_to_indices(&array, Some(options), None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![1, 4, 3, 0, 2, 5]));
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let indices = sort_to_indices(&array, Some(options), Some(3)).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![5, 2, 0]));
+
+        let sorted = sort(&array.slice(1, 4), None).unwrap();
+        let expected = take(&array, &UInt32Array::from(vec![2, 1, 4, 3]), None).unwrap();
+        assert_eq!(&sorted, &expected);
+    }
+
+    #[test]
+    fn test_lex_sort_struct() {
+        let input = vec![
+            SortColumn {
+                values: struct_array(),
+                options: None,
+            },
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![0, 1, 2, 3, 4, 5])) as ArrayRef,
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: true,
+                }),
+            },
+        ];
+        let indices = lexsort_to_indices(&input, None).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![5, 2, 1, 4, 3, 0]));
+    }
+
+    #[test]
+    fn test_lex_sort_many_columns() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let len = 200;
+        let ints: Int32Array = (0..len)
+            .map(|_| rng.gen_bool(0.8).then(|| rng.gen_range(0..3)))
+            .collect();
+        let strings: StringArray = (0..len)
+            .map(|_| {
+                rng.gen_bool(0.8)
+                    .then(|| ["a", "b", "c"][rng.gen_range(0..3)])
+            })
+            .collect();
+        let floats: Float64Array = (0..len)
+            .map(|_| rng.gen_bool(0.8).then(|| rng.gen_range(0..3) as f64 - 1.0))
+            .collect();
+        let bools: BooleanArray = (0..len)
+            .map(|_| rng.gen_bool(0.8).then(|| rng.gen_bool(0.5)))
+            .collect();
+        // Ensure no two rows compare equal so the result is deterministic
+        let unique = Int32Array::from_iter_values(0..len);
+
+        let options = [
+            None,
+            Some(SortOptions {
+                descending: true,
+                nulls_first: false,
+            }),
+            Some(SortOptions {
+                descending: false,
+                nulls_first: false,
+            }),
+            Some(SortOptions {
+                descending: true,
+                nulls_first: true,
+            }),
+            None,
+        ];
+        let arrays: [ArrayRef; 5] = [
+            Arc::new(ints),
+            Arc::new(strings),
+            Arc::new(floats),
+            Arc::new(bools),
+            Arc::new(unique),
+        ];
+        let columns: Vec<_> = arrays
+            .into_iter()
+            .zip(options)
+            .map(|(values, options)| SortColumn { values, options })
+            .collect();
+
+        let comparator = LexicographicalComparator::try_new(&columns).unwrap();
+        let mut expected: Vec<u32> = (0..len as u32).collect();
+        expected.sort_unstable_by(|a, b| comparator.compare(*a as usize, *b as usize));
+
+        let indices = lexsort_to_indices(&columns, None).unwrap();
+        assert_eq!(indices.values(), expected.as_slice());
+
+        let indices = lexsort_to_indices(&columns, Some(10)).unwrap();
+        assert_eq!(indices.values(), &expected[..10]);
+    }
+
+    #[test]
+    fn test_select_k() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let len = 100;
+        let ints: Int32Array = (0..len)
+            .map(|_| rng.gen_bool(0.9).then(|| rng.gen_range(0..10)))
+            .collect();
+        let strings: StringArray = (0..len)
+            .map(|_| rng.gen_bool(0.9).then(|| ["a", "b"][rng.gen_range(0..2)]))
+            .collect();
+        let columns = vec![
+            SortColumn {
+                values: Arc::new(ints),
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                }),
+            },
+            SortColumn {
+                values: Arc::new(strings),
+                options: None,
+            },
+        ];
+
+        // A stable sort of all rows
+        let comparator = LexicographicalComparator::try_new(&columns).unwrap();
+        let mut expected: Vec<u32> = (0..len as u32).collect();
+        expected.sort_by(|a, b| comparator.compare(*a as usize, *b as usize));
+
+        for k in [0, 1, 7, 50, len, len + 5] {
+            let indices = select_k(&columns, k).unwrap();
+            assert_eq!(indices.values(), &expected[..k.min(len)], "k = {k}");
+        }
+
+        let err = select_k(&[], 1).unwrap_err().to_string();
+        assert!(err.contains("at least one column"), "{err}");
+
+        let short = SortColumn {
+            values: Arc::new(Int32Array::from(vec![1])),
+            options: None,
+        };
+        let columns = vec![columns[0].clone(), short];
+        let err = select_k(&columns, 1).unwrap_err().to_string();
+        assert!(err.contains("different row counts"), "{err}");
+    }
 }
@@ -0,0 +1,181 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the coalesce kernel, selecting the first non-null value of each row
+
+use crate::zip::check_dictionary_keys;
+use arrow_array::*;
+use arrow_data::transform::MutableArrayData;
+use arrow_schema::{ArrowError, DataType};
+
+/// Returns an array containing, for each row, the first non-null value of `arrays`
+///
+/// A row is null only if it is null in all of `arrays`. Arrays following the first
+/// array without nulls can never be selected, and are ignored
+///
+/// ```
+/// # use arrow_array::{Array, Int32Array};
+/// # use arrow_array::cast::as_primitive_array;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_select::coalesce::coalesce;
+/// let a = Int32Array::from(vec![Some(1), None, None]);
+/// let b = Int32Array::from(vec![Some(4), Some(5), None]);
+/// let result = coalesce(&[&a, &b]).unwrap();
+/// let expected = Int32Array::from(vec![Some(1), Some(5), None]);
+/// assert_eq!(as_primitive_array::<Int32Type>(&result), &expected);
+/// ```
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
+    let first = arrays.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "coalesce requires input of at least one array".to_string(),
+        )
+    })?;
+    for array in arrays.iter().skip(1) {
+        if array.data_type() != first.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "It is not possible to coalesce arrays of different data types ({} and {})",
+                first.data_type(),
+                array.data_type()
+            )));
+        }
+        if array.len() != first.len() {
+            return Err(ArrowError::InvalidArgumentError(
+                "all arrays should have the same length".to_string(),
+            ));
+        }
+    }
+
+    let end = arrays
+        .iter()
+        .position(|a| a.null_count() == 0)
+        .map(|idx| idx + 1)
+        .unwrap_or(arrays.len());
+    let arrays = &arrays[..end];
+    if arrays.len() == 1 {
+        return Ok(make_array(first.data().clone()));
+    }
+
+    if let DataType::Dictionary(key_type, _) = first.data_type() {
+        check_dictionary_keys(key_type, arrays)?;
+    }
+
+    let len = first.len();
+    let data: Vec<_> = arrays.iter().map(|a| a.data()).collect();
+    let mut mutable = MutableArrayData::new(data, true, len);
+
+    // Contiguous rows taken from the same array are copied in a single call, with
+    // `None` denoting rows that are null in all arrays
+    let mut current: Option<usize> = None;
+    let mut start = 0;
+    for row in 0..len {
+        let source = arrays.iter().position(|a| a.is_valid(row));
+        if source != current {
+            extend(&mut mutable, current, start, row);
+            current = source;
+            start = row;
+        }
+    }
+    extend(&mut mutable, current, start, len);
+
+    Ok(make_array(mutable.freeze()))
+}
+
+fn extend(
+    mutable: &mut MutableArrayData,
+    source: Option<usize>,
+    start: usize,
+    end: usize,
+) {
+    match source {
+        Some(idx) => mutable.extend(idx, start, end),
+        None => mutable.extend_nulls(end - start),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::{as_primitive_array, as_string_array};
+    use arrow_array::types::{Int32Type, Int8Type};
+
+    #[test]
+    fn test_coalesce() {
+        let a = Int32Array::from(vec![Some(1), None, None, None, Some(5)]);
+        let b = Int32Array::from(vec![Some(6), Some(7), None, None, None]);
+        let c = Int32Array::from(vec![None, Some(8), Some(9), None, Some(10)]);
+        let result = coalesce(&[&a, &b, &c]).unwrap();
+        let expected = Int32Array::from(vec![Some(1), Some(7), Some(9), None, Some(5)]);
+        assert_eq!(as_primitive_array::<Int32Type>(&result), &expected);
+
+        // Sliced inputs
+        let a = StringArray::from(vec![Some("a"), None, Some("b"), None]);
+        let b = StringArray::from(vec![Some("c"), Some("d"), None, Some("e")]);
+        let a = a.slice(1, 3);
+        let b = b.slice(1, 3);
+        let result = coalesce(&[a.as_ref(), b.as_ref()]).unwrap();
+        let expected = StringArray::from(vec![Some("d"), Some("b"), Some("e")]);
+        assert_eq!(as_string_array(&result), &expected);
+    }
+
+    #[test]
+    fn test_coalesce_no_nulls() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = Int32Array::from(vec![4, 5, 6]);
+        let c = StringArray::from(vec!["x", "y", "z"]);
+
+        let result = coalesce(&[&b, &a]).unwrap();
+        assert_eq!(as_primitive_array::<Int32Type>(&result), &b);
+
+        let result = coalesce(&[&a, &b]).unwrap();
+        let expected = Int32Array::from(vec![1, 5, 3]);
+        assert_eq!(as_primitive_array::<Int32Type>(&result), &expected);
+
+        let err = coalesce(&[&a, &c]).unwrap_err().to_string();
+        assert!(err.contains("different data types"), "{err}");
+
+        let err = coalesce(&[&a, &Int32Array::from(vec![1])])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("same length"), "{err}");
+
+        let err = coalesce(&[]).unwrap_err().to_string();
+        assert!(err.contains("at least one array"), "{err}");
+    }
+
+    #[test]
+    fn test_coalesce_dictionary_overflow() {
+        let keys = Int8Array::from(vec![None, Some(1), Some(2)]);
+        let values = StringArray::from_iter_values((0..100).map(|i| i.to_string()));
+        let a = DictionaryArray::try_new(&keys, &values).unwrap();
+        let values = StringArray::from_iter_values((100..200).map(|i| i.to_string()));
+        let b = DictionaryArray::try_new(&keys, &values).unwrap();
+        let err = coalesce(&[&a, &b]).unwrap_err();
+        assert!(
+            matches!(err, ArrowError::DictionaryKeyOverflowError),
+            "{err}"
+        );
+
+        // Dictionaries sharing the same values are not concatenated
+        let b = DictionaryArray::try_new(&Int8Array::from(vec![0, 0, 0]), a.values())
+            .unwrap();
+        let result = coalesce(&[&a, &b]).unwrap();
+        let result = result.as_any().downcast_ref::<DictionaryArray<Int8Type>>();
+        let result = result.unwrap();
+        assert_eq!(result.values().len(), 100);
+        assert_eq!(result.keys(), &Int8Array::from(vec![0, 1, 2]));
+    }
+}
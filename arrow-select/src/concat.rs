@@ -35,6 +35,7 @@ use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
 use arrow_data::transform::{Capacities, MutableArrayData};
 use arrow_schema::{ArrowError, DataType, SchemaRef};
+use std::sync::Arc;
 
 fn binary_capacity<T: ByteArrayType>(arrays: &[&dyn Array]) -> Capacities {
     let mut item_capacity = 0;
@@ -73,6 +74,17 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
         ));
     }
 
+    if let DataType::RunEndEncoded(run_ends, _) = d {
+        return match run_ends.data_type() {
+            DataType::Int16 => concat_run_arrays::<Int16Type>(arrays),
+            DataType::Int32 => concat_run_arrays::<Int32Type>(arrays),
+            DataType::Int64 => concat_run_arrays::<Int64Type>(arrays),
+            t => Err(ArrowError::InvalidArgumentError(format!(
+                "Unsupported run end type {t:?}"
+            ))),
+        };
+    }
+
     let capacity = match d {
         DataType::Utf8 => binary_capacity::<Utf8Type>(arrays),
         DataType::LargeUtf8 => binary_capacity::<LargeUtf8Type>(arrays),
@@ -91,6 +103,73 @@ pub fn concat(arrays: &[&dyn Array]) -> Result<ArrayRef, ArrowError> {
     Ok(make_array(mutable.freeze()))
 }
 
+/// Concatenates run end encoded arrays by offsetting the run ends of each array,
+/// merging the runs at the boundaries of arrays that have equal values
+fn concat_run_arrays<R: RunEndIndexType>(
+    arrays: &[&dyn Array],
+) -> Result<ArrayRef, ArrowError> {
+    let total_len: usize = arrays.iter().map(|a| a.len()).sum();
+    let run_end_err = || {
+        ArrowError::ComputeError(format!(
+            "Cannot represent concatenated array of length {} with run end type {:?}",
+            total_len,
+            R::DATA_TYPE
+        ))
+    };
+
+    let mut run_ends: Vec<R::Native> = Vec::new();
+    let mut values: Vec<ArrayRef> = Vec::with_capacity(arrays.len());
+    // The value of the last run written
+    let mut last_value: Option<ArrayRef> = None;
+    let mut len_offset = 0;
+    for array in arrays.iter().filter(|a| !a.is_empty()) {
+        let array = array.as_any().downcast_ref::<RunArray<R>>().unwrap();
+        let start = array.get_start_physical_index();
+        let end = array.get_end_physical_index();
+        let offset = array.offset();
+        let len = array.len();
+
+        let array_run_ends = array.run_ends().values()[start..=end]
+            .iter()
+            .map(|run_end| (run_end.as_usize() - offset).min(len) + len_offset);
+
+        let first_value = array.values().slice(start, 1);
+        let merge = matches!(&last_value, Some(v) if v.data() == first_value.data());
+        let values_start = match merge {
+            true => {
+                // Extend the last run into this array
+                let mut array_run_ends = array_run_ends;
+                let first = array_run_ends.next().unwrap();
+                *run_ends.last_mut().unwrap() =
+                    R::Native::from_usize(first).ok_or_else(run_end_err)?;
+                for run_end in array_run_ends {
+                    run_ends
+                        .push(R::Native::from_usize(run_end).ok_or_else(run_end_err)?);
+                }
+                start + 1
+            }
+            false => {
+                for run_end in array_run_ends {
+                    run_ends
+                        .push(R::Native::from_usize(run_end).ok_or_else(run_end_err)?);
+                }
+                start
+            }
+        };
+
+        values.push(array.values().slice(values_start, end + 1 - values_start));
+        last_value = Some(array.values().slice(end, 1));
+        len_offset += len;
+    }
+
+    if values.is_empty() {
+        return Ok(new_empty_array(arrays[0].data_type()));
+    }
+    let values = concat(&values.iter().map(|v| v.as_ref()).collect::<Vec<_>>())?;
+    let run_ends = PrimitiveArray::<R>::from_iter_values(run_ends);
+    Ok(Arc::new(RunArray::try_new(&run_ends, values.as_ref())?))
+}
+
 /// Concatenates `batches` together into a single record batch.
 pub fn concat_batches<'a>(
     schema: &SchemaRef,
@@ -131,6 +210,7 @@ pub fn concat_batches<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow_array::builder::StringRunBuilder;
     use arrow_schema::{Field, Schema};
     use std::sync::Arc;
 
@@ -717,4 +797,105 @@ mod tests {
         assert_eq!(data.buffers()[1].len(), 200);
         assert_eq!(data.buffers()[1].capacity(), 256); // Nearest multiple of 64
     }
+
+    fn run_array_values<R: RunEndIndexType>(array: &dyn Array) -> Vec<Option<String>> {
+        let array = array.as_any().downcast_ref::<RunArray<R>>().unwrap();
+        let typed = array.downcast::<StringArray>().unwrap();
+        typed
+            .into_iter()
+            .map(|v| v.map(ToString::to_string))
+            .collect()
+    }
+
+    #[test]
+    fn test_concat_run_arrays() {
+        let a: Int32RunArray = vec![Some("a"), Some("a"), None, Some("b")]
+            .into_iter()
+            .collect();
+        let b: Int32RunArray =
+            vec![Some("b"), Some("c"), Some("c")].into_iter().collect();
+        let c: Int32RunArray = vec![Some("d")].into_iter().collect();
+
+        let array = concat(&[&a, &b, &c]).unwrap();
+        let run_array = array.as_any().downcast_ref::<Int32RunArray>().unwrap();
+        assert_eq!(run_array.len(), 8);
+        // The boundary runs of "b" are merged
+        assert_eq!(run_array.run_ends().values(), &[2, 3, 5, 7, 8]);
+        assert_eq!(run_array.values().len(), 5);
+
+        let expected: Int32RunArray = vec![
+            Some("a"),
+            Some("a"),
+            None,
+            Some("b"),
+            Some("b"),
+            Some("c"),
+            Some("c"),
+            Some("d"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            run_array_values::<Int32Type>(run_array),
+            run_array_values::<Int32Type>(&expected)
+        );
+    }
+
+    #[test]
+    fn test_concat_run_array_slices() {
+        let a: Int16RunArray = vec!["a", "a", "b", "b", "c"].into_iter().collect();
+        let b: Int16RunArray = vec!["c", "c", "d"].into_iter().collect();
+
+        let array = concat(&[
+            a.slice(1, 2).as_ref(),
+            a.slice(5, 0).as_ref(),
+            b.slice(1, 2).as_ref(),
+            a.slice(3, 2).as_ref(),
+        ])
+        .unwrap();
+        let run_array = array.as_any().downcast_ref::<Int16RunArray>().unwrap();
+        assert_eq!(run_array.run_ends().values(), &[1, 2, 3, 4, 5, 6]);
+        let expected: Vec<_> = ["a", "b", "c", "d", "b", "c"]
+            .into_iter()
+            .map(|v| Some(v.to_string()))
+            .collect();
+        assert_eq!(run_array_values::<Int16Type>(run_array), expected);
+
+        let array = concat(&[a.slice(0, 0).as_ref(), b.slice(3, 0).as_ref()]).unwrap();
+        assert_eq!(array.len(), 0);
+        assert_eq!(array.data_type(), a.data_type());
+    }
+
+    #[test]
+    fn test_concat_run_arrays_overflow() {
+        let mut builder = StringRunBuilder::<Int16Type>::new();
+        for i in 0..i16::MAX / 2 + 1 {
+            builder.append_value(if i % 2 == 0 { "a" } else { "b" });
+        }
+        let a = builder.finish();
+        let err = concat(&[&a, &a]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot represent concatenated array of length 32768 with run end type Int16"
+        );
+    }
+
+    #[test]
+    fn test_concat_batches_run_arrays() {
+        let a: Int32RunArray = vec!["a", "a", "b"].into_iter().collect();
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            a.data_type().clone(),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a)]).unwrap();
+        let batch = concat_batches(&schema, [&batch, &batch]).unwrap();
+        assert_eq!(batch.num_rows(), 6);
+        let run_array = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32RunArray>()
+            .unwrap();
+        assert_eq!(run_array.run_ends().values(), &[2, 3, 5, 6]);
+    }
 }
@@ -21,15 +21,19 @@ use std::sync::Arc;
 
 use arrow_array::builder::BooleanBufferBuilder;
 use arrow_array::cast::{as_generic_binary_array, as_largestring_array, as_string_array};
-use arrow_array::types::ByteArrayType;
+use arrow_array::types::{
+    ByteArrayType, Int16Type, Int32Type, Int64Type, RunEndIndexType,
+};
 use arrow_array::*;
-use arrow_buffer::bit_util;
+use arrow_buffer::{bit_util, ArrowNativeType};
 use arrow_buffer::{buffer::buffer_bin_and, Buffer, MutableBuffer};
 use arrow_data::bit_iterator::{BitIndexIterator, BitSliceIterator};
 use arrow_data::transform::MutableArrayData;
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::*;
 
+use crate::take::take;
+
 /// If the filter selects more than this fraction of rows, use
 /// [`SlicesIterator`] to copy ranges of values. Otherwise iterate
 /// over individual rows using [`IndexIterator`]
@@ -369,6 +373,16 @@ fn filter_array(
                 values => Ok(Arc::new(filter_dict(values, predicate))),
                 t => unimplemented!("Filter not supported for dictionary type {:?}", t)
             }
+            DataType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+                DataType::Int16 => filter_run_end_array::<Int16Type>(values, predicate),
+                DataType::Int32 => filter_run_end_array::<Int32Type>(values, predicate),
+                DataType::Int64 => filter_run_end_array::<Int64Type>(values, predicate),
+                t => unimplemented!("Filter not supported for run end type {:?}", t)
+            }
+            DataType::Union(_, _, UnionMode::Dense) => {
+                let values = values.as_any().downcast_ref::<UnionArray>().unwrap();
+                filter_dense_union(values, predicate)
+            }
             _ => {
                 // fallback to using MutableArrayData
                 let mut mutable = MutableArrayData::new(
@@ -683,6 +697,129 @@ where
     DictionaryArray::from(unsafe { builder.build_unchecked() })
 }
 
+/// `filter` implementation for run end encoded arrays
+///
+/// Each run is kept if the predicate selects any of its rows, with its length
+/// reduced to the number of selected rows, avoiding expansion of the values
+fn filter_run_end_array<R: RunEndIndexType>(
+    values: &dyn Array,
+    predicate: &FilterPredicate,
+) -> Result<ArrayRef, ArrowError> {
+    let array = values.as_any().downcast_ref::<RunArray<R>>().unwrap();
+    let selection = &predicate.filter;
+    let start_physical = array.get_start_physical_index();
+    let end_physical = array.get_end_physical_index();
+    let offset = array.offset();
+
+    let run_ends = &array.run_ends().values()[start_physical..=end_physical];
+    let mut new_run_ends = Vec::with_capacity(run_ends.len());
+    let mut keep_runs = Vec::with_capacity(run_ends.len());
+    let mut run_start = 0;
+    let mut count = 0;
+    for run_end in run_ends {
+        // The predicate may be shorter than the array
+        let run_end = (run_end.as_usize() - offset).min(selection.len());
+        let selected = match run_start < run_end {
+            true => selection.values().count_set_bits_offset(
+                selection.offset() + run_start,
+                run_end - run_start,
+            ),
+            false => 0,
+        };
+        if selected > 0 {
+            count += selected;
+            new_run_ends.push(R::Native::from_usize(count).unwrap());
+        }
+        keep_runs.push(selected > 0);
+        run_start = run_end;
+    }
+
+    let run_values = array
+        .values()
+        .slice(start_physical, end_physical - start_physical + 1);
+    let run_values = filter(run_values.as_ref(), &BooleanArray::from(keep_runs))?;
+    let new_run_ends = PrimitiveArray::<R>::from_iter_values(new_run_ends);
+
+    // Keep the field names and nullability of the input type
+    let builder = ArrayDataBuilder::new(array.data_type().clone())
+        .len(count)
+        .add_child_data(new_run_ends.into_data())
+        .add_child_data(run_values.into_data());
+    // SAFETY: the children are validated by `validate_data` below
+    let data = unsafe { builder.build_unchecked() };
+    data.validate_data()?;
+    Ok(Arc::new(RunArray::<R>::from(data)))
+}
+
+/// `filter` implementation for dense [`UnionArray`]
+///
+/// Rebuilds the type ids and offsets, and takes only the values referenced by
+/// the selected slots from each child
+fn filter_dense_union(
+    array: &UnionArray,
+    predicate: &FilterPredicate,
+) -> Result<ArrayRef, ArrowError> {
+    let type_ids = match array.data_type() {
+        DataType::Union(_, type_ids, _) => type_ids,
+        _ => unreachable!(),
+    };
+    // Maps a type id to the index of its child
+    let mut child_idx = [usize::MAX; 128];
+    for (idx, type_id) in type_ids.iter().enumerate() {
+        child_idx[*type_id as usize] = idx;
+    }
+
+    let data = array.data();
+    let src_type_ids = data.buffer::<i8>(0);
+    let src_offsets = data.buffer::<i32>(1);
+
+    let mut child_indices: Vec<Vec<u32>> = vec![vec![]; type_ids.len()];
+    let mut dst_type_ids = MutableBuffer::with_capacity(predicate.count);
+    let mut dst_offsets =
+        MutableBuffer::with_capacity(predicate.count * std::mem::size_of::<i32>());
+
+    let mut push = |idx: usize| {
+        let type_id = src_type_ids[idx];
+        let indices = &mut child_indices[child_idx[type_id as usize]];
+        dst_type_ids.push(type_id);
+        dst_offsets.push(indices.len() as i32);
+        indices.push(src_offsets[idx] as u32);
+    };
+
+    match &predicate.strategy {
+        IterationStrategy::SlicesIterator => SlicesIterator::new(&predicate.filter)
+            .for_each(|(start, end)| (start..end).for_each(&mut push)),
+        IterationStrategy::Slices(slices) => slices
+            .iter()
+            .for_each(|(start, end)| (*start..*end).for_each(&mut push)),
+        IterationStrategy::IndexIterator => {
+            IndexIterator::new(&predicate.filter, predicate.count).for_each(push)
+        }
+        IterationStrategy::Indices(indices) => indices.iter().for_each(|x| push(*x)),
+        IterationStrategy::All | IterationStrategy::None => unreachable!(),
+    }
+
+    let children = child_indices
+        .into_iter()
+        .zip(data.child_data())
+        .map(|(indices, child)| {
+            let indices = UInt32Array::from(indices);
+            let child = take(&make_array(child.clone()), &indices, None)?;
+            Ok(child.into_data())
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    let builder = ArrayDataBuilder::new(array.data_type().clone())
+        .len(predicate.count)
+        .add_buffer(dst_type_ids.into())
+        .add_buffer(dst_offsets.into())
+        .child_data(children);
+
+    // SAFETY: each offset is within the bounds of the child taken for its type id
+    let data = unsafe { builder.build_unchecked() };
+    Ok(make_array(data))
+}
+
 #[cfg(test)]
 mod tests {
     use arrow_array::builder::*;
@@ -920,6 +1057,100 @@ mod tests {
         assert_eq!("world", values.value(d.keys().value(1) as usize));
     }
 
+    #[test]
+    fn test_filter_run_end_encoded_array() {
+        let run_ends = Int32Array::from(vec![2, 3, 6, 7]);
+        let values = Int64Array::from(vec![Some(1), None, Some(3), Some(4)]);
+        let a = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+        let b = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+            Some(false),
+        ]);
+        let c = filter(&a, &b).unwrap();
+        let d = c.as_any().downcast_ref::<RunArray<Int32Type>>().unwrap();
+        assert_eq!(d.len(), 3);
+        assert_eq!(d.run_ends().values(), &[1, 2, 3]);
+        let values = d.values().as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(values, &Int64Array::from(vec![Some(1), None, Some(3)]));
+
+        // Runs with no selected rows are removed
+        let b = BooleanArray::from(vec![true, true, false, false, false, false, true]);
+        let c = filter(&a, &b).unwrap();
+        let d = c.as_any().downcast_ref::<RunArray<Int32Type>>().unwrap();
+        assert_eq!(d.run_ends().values(), &[2, 3]);
+        let values = d.values().as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(values, &Int64Array::from(vec![1, 4]));
+
+        // Filter shorter than the array
+        let b = BooleanArray::from(vec![false, false, true, true]);
+        let c = filter(&a, &b).unwrap();
+        let d = c.as_any().downcast_ref::<RunArray<Int32Type>>().unwrap();
+        assert_eq!(d.run_ends().values(), &[1, 2]);
+        let values = d.values().as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(values, &Int64Array::from(vec![None, Some(3)]));
+
+        // The field names and nullability of the input type are kept
+        let data_type = DataType::RunEndEncoded(
+            Box::new(Field::new("ends", DataType::Int32, false)),
+            Box::new(Field::new("vals", DataType::Int64, true)),
+        );
+        let data = a.data().clone().into_builder().data_type(data_type.clone());
+        let a = RunArray::<Int32Type>::from(data.build().unwrap());
+        let c = filter(&a, &b).unwrap();
+        assert_eq!(c.data_type(), &data_type);
+    }
+
+    #[test]
+    fn test_filter_run_end_encoded_array_slice() {
+        let a: Int16RunArray = vec!["a", "a", "b", "c", "c", "c", "d"]
+            .into_iter()
+            .collect();
+        let a = a.slice(1, 5);
+        let b = BooleanArray::from(vec![true, false, true, false, true]);
+        let c = filter(a.as_ref(), &b).unwrap();
+        let d = c.as_any().downcast_ref::<Int16RunArray>().unwrap();
+        assert_eq!(d.run_ends().values(), &[1, 3]);
+        let values = d.values().as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(values, &StringArray::from(vec!["a", "c"]));
+
+        // All rows selected
+        let b = BooleanArray::from(vec![true; 5]);
+        let c = filter(a.as_ref(), &b).unwrap();
+        let d = c.as_any().downcast_ref::<Int16RunArray>().unwrap();
+        assert_eq!(d.len(), 5);
+        let values = d.downcast::<StringArray>().unwrap();
+        let values: Vec<_> = values.into_iter().flatten().collect();
+        assert_eq!(values, vec!["a", "b", "c", "c", "c"]);
+
+        // No rows selected
+        let b = BooleanArray::from(vec![false; 5]);
+        let c = filter(a.as_ref(), &b).unwrap();
+        assert_eq!(c.len(), 0);
+        assert_eq!(c.data_type(), a.data_type());
+    }
+
+    #[test]
+    fn test_filter_record_batch_run_end_encoded() {
+        let a: Int32RunArray = vec!["a", "a", "b", "b"].into_iter().collect();
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            a.data_type().clone(),
+            true,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(a)]).unwrap();
+        let b = BooleanArray::from(vec![false, true, true, false]);
+        let filtered = filter_record_batch(&batch, &b).unwrap();
+        assert_eq!(filtered.num_rows(), 2);
+        let d = filtered.column(0);
+        let d = d.as_any().downcast_ref::<Int32RunArray>().unwrap();
+        assert_eq!(d.run_ends().values(), &[1, 2]);
+    }
+
     #[test]
     fn test_filter_list_array() {
         let value_data = ArrayData::builder(DataType::Int32)
@@ -1496,6 +1727,45 @@ mod tests {
         compare_union_arrays(filtered, &expected_array);
     }
 
+    #[test]
+    fn test_filter_union_array_dense_children() {
+        let mut builder = UnionBuilder::new_dense();
+        for i in 0..20 {
+            match i % 3 {
+                0 => builder.append::<Int32Type>("A", i).unwrap(),
+                1 => builder.append::<Float64Type>("B", i as f64).unwrap(),
+                _ => builder.append_null::<Int32Type>("A").unwrap(),
+            }
+        }
+        let array = builder.build().unwrap();
+        let sliced = array.slice(2, 15);
+        let sliced = sliced.as_any().downcast_ref::<UnionArray>().unwrap();
+
+        // Exercise both the index and slice iteration strategies
+        for filter_array in [
+            BooleanArray::from_iter((0..15).map(|i| Some(i % 4 == 0))),
+            BooleanArray::from_iter((0..15).map(|i| Some(i != 7))),
+        ] {
+            let c = filter(sliced, &filter_array).unwrap();
+            let filtered = c.as_any().downcast_ref::<UnionArray>().unwrap();
+
+            let selected: Vec<_> = (0..15).filter(|i| filter_array.value(*i)).collect();
+            assert_eq!(filtered.len(), selected.len());
+
+            // Children only contain the referenced values
+            let child_len: usize =
+                filtered.data().child_data().iter().map(|c| c.len()).sum();
+            assert_eq!(child_len, selected.len());
+
+            for (out_idx, in_idx) in selected.into_iter().enumerate() {
+                assert_eq!(filtered.type_id(out_idx), sliced.type_id(in_idx));
+                let expected = sliced.value(in_idx);
+                let actual = filtered.value(out_idx);
+                assert_eq!(actual.as_ref(), expected.as_ref());
+            }
+        }
+    }
+
     #[test]
     fn test_filter_union_array_sparse() {
         let mut builder = UnionBuilder::new_sparse();
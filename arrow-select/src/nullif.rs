@@ -20,12 +20,18 @@ use arrow_array::{make_array, Array, ArrayRef, BooleanArray};
 use arrow_buffer::buffer::{
     bitwise_bin_op_helper, bitwise_unary_op_helper, buffer_bin_and,
 };
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, DataType};
 
 /// Copies original array, setting validity bit to false if a secondary comparison
 /// boolean array is set to true
 ///
 /// Typically used to implement NULLIF.
+///
+/// Supports arrays of any type with a validity buffer, including strings, booleans,
+/// nested types such as lists and structs, and dictionaries, for which the keys are
+/// nulled. The values of the array, and the children of nested arrays, are reused
+/// without being copied. Returns an error for unions and run end encoded arrays, which
+/// have no validity buffer of their own.
 pub fn nullif(left: &dyn Array, right: &BooleanArray) -> Result<ArrayRef, ArrowError> {
     let left_data = left.data();
     let right_data = right.data();
@@ -39,6 +45,18 @@ pub fn nullif(left: &dyn Array, right: &BooleanArray) -> Result<ArrayRef, ArrowE
     let len = left_data.len();
     let l_offset = left_data.offset();
 
+    match left_data.data_type() {
+        // Every value is already null
+        DataType::Null => return Ok(make_array(left_data.clone())),
+        DataType::Union(_, _, _) | DataType::RunEndEncoded(_, _) => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "nullif is not supported for {}",
+                left_data.data_type()
+            )))
+        }
+        _ => {}
+    }
+
     if len == 0 {
         return Ok(make_array(left_data.clone()));
     }
@@ -118,9 +136,15 @@ pub fn nullif(left: &dyn Array, right: &BooleanArray) -> Result<ArrayRef, ArrowE
 mod tests {
     use super::*;
     use arrow_array::builder::{BooleanBuilder, Int32Builder, StructBuilder};
-    use arrow_array::cast::{as_boolean_array, as_primitive_array, as_string_array};
-    use arrow_array::types::Int32Type;
-    use arrow_array::{Int32Array, StringArray, StructArray};
+    use arrow_array::cast::{
+        as_boolean_array, as_dictionary_array, as_list_array, as_primitive_array,
+        as_string_array,
+    };
+    use arrow_array::types::{Int32Type, Int8Type};
+    use arrow_array::{
+        DictionaryArray, Int32Array, Int8Array, ListArray, NullArray, RunArray,
+        StringArray, StructArray,
+    };
     use arrow_data::ArrayData;
     use arrow_schema::{DataType, Field};
     use rand::{thread_rng, Rng};
@@ -456,6 +480,59 @@ mod tests {
         assert_eq!(res, &expected);
     }
 
+    #[test]
+    fn test_nullif_dictionary() {
+        let a: DictionaryArray<Int8Type> = vec![Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+        let comp = BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]);
+        let res = nullif(&a, &comp).unwrap();
+        res.data().validate_full().unwrap();
+
+        let res = as_dictionary_array::<Int8Type>(res.as_ref());
+        assert_eq!(res.values(), a.values());
+        let expected = Int8Array::from(vec![None, None, Some(1), Some(0)]);
+        assert_eq!(res.keys(), &expected);
+    }
+
+    #[test]
+    fn test_nullif_list() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![Some(2), None]),
+            Some(vec![]),
+        ]);
+        let a = a.slice(1, 3);
+        let comp = BooleanArray::from(vec![Some(true), Some(true), Some(false)]);
+        let res = nullif(a.as_ref(), &comp).unwrap();
+        res.data().validate_full().unwrap();
+
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None,
+            None,
+            Some(vec![]),
+        ]);
+        assert_eq!(as_list_array(res.as_ref()), &expected);
+    }
+
+    #[test]
+    fn test_nullif_unsupported() {
+        let comp = BooleanArray::from(vec![true, false]);
+
+        let a = NullArray::new(2);
+        let res = nullif(&a, &comp).unwrap();
+        assert_eq!(res.data_type(), &DataType::Null);
+        assert_eq!(res.len(), 2);
+
+        let a: RunArray<Int32Type> = vec!["a", "b"].into_iter().collect();
+        let err = nullif(&a, &comp).unwrap_err().to_string();
+        assert!(
+            err.contains("nullif is not supported for RunEndEncoded"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn nullif_empty() {
         let a = Int32Array::from(ArrayData::new_empty(&DataType::Int32));
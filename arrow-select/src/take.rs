@@ -23,7 +23,7 @@ use arrow_array::types::*;
 use arrow_array::*;
 use arrow_buffer::{bit_util, ArrowNativeType, Buffer, MutableBuffer};
 use arrow_data::{ArrayData, ArrayDataBuilder};
-use arrow_schema::{ArrowError, DataType, Field};
+use arrow_schema::{ArrowError, DataType, Field, UnionMode};
 
 use arrow_array::cast::{as_generic_binary_array, as_largestring_array, as_string_array};
 use num::{ToPrimitive, Zero};
@@ -197,10 +197,36 @@ where
 
             Ok(Arc::new(StructArray::from((fields, is_valid))) as ArrayRef)
         }
+        DataType::Map(field, _) => {
+            let values = values.as_any().downcast_ref::<MapArray>().unwrap();
+            // A map has the same layout as a list of its entries
+            let list_data = values
+                .data()
+                .clone()
+                .into_builder()
+                .data_type(DataType::List(field.clone()));
+            let list = ListArray::from(unsafe { list_data.build_unchecked() });
+            let taken = take_list::<_, Int32Type>(&list, indices)?;
+            let map_data = taken
+                .into_data()
+                .into_builder()
+                .data_type(values.data_type().clone());
+            Ok(Arc::new(MapArray::from(unsafe { map_data.build_unchecked() })))
+        }
+        DataType::Union(_, _, _) => {
+            let values = values.as_any().downcast_ref::<UnionArray>().unwrap();
+            Ok(Arc::new(take_union(values, indices)?))
+        }
         DataType::Dictionary(_, _) => downcast_dictionary_array! {
             values => Ok(Arc::new(take_dict(values, indices)?)),
             t => unimplemented!("Take not supported for dictionary type {:?}", t)
         }
+        DataType::RunEndEncoded(run_ends, _) => match run_ends.data_type() {
+            DataType::Int16 => Ok(Arc::new(take_run::<Int16Type, _>(values, indices)?)),
+            DataType::Int32 => Ok(Arc::new(take_run::<Int32Type, _>(values, indices)?)),
+            DataType::Int64 => Ok(Arc::new(take_run::<Int64Type, _>(values, indices)?)),
+            t => unimplemented!("Take not supported for run end type {:?}", t)
+        }
         DataType::Binary => {
             Ok(Arc::new(take_bytes(as_generic_binary_array::<i32>(values), indices)?))
         }
@@ -709,6 +735,107 @@ where
     Ok(GenericListArray::<OffsetType::Native>::from(list_data))
 }
 
+/// `take` implementation for `UnionArray`
+///
+/// Takes the type ids of the selected slots and the values they reference from each
+/// child. A null index results in a null value of the first child
+fn take_union<IndexType>(
+    values: &UnionArray,
+    indices: &PrimitiveArray<IndexType>,
+) -> Result<UnionArray, ArrowError>
+where
+    IndexType: ArrowPrimitiveType,
+    IndexType::Native: ToPrimitive,
+{
+    let (type_ids, mode) = match values.data_type() {
+        DataType::Union(_, type_ids, mode) => (type_ids, mode),
+        _ => unreachable!(),
+    };
+    let null_type_id = type_ids[0];
+    let len = values.len();
+    let to_index = |index: IndexType::Native| {
+        let index = ToPrimitive::to_usize(&index).ok_or_else(|| {
+            ArrowError::ComputeError("Cast to usize failed".to_string())
+        })?;
+        if index >= len {
+            return Err(ArrowError::ComputeError(format!(
+                "Array index out of bounds, cannot get item at index {index} from {len} entries"
+            )));
+        }
+        Ok(index)
+    };
+
+    let mut dst_type_ids = MutableBuffer::with_capacity(indices.len());
+    let mut buffers = vec![];
+    let child_indices = match mode {
+        UnionMode::Sparse => {
+            let child_indices = indices
+                .iter()
+                .map(|index| match index {
+                    Some(index) => {
+                        let index = to_index(index)?;
+                        dst_type_ids.push(values.type_id(index));
+                        Ok(Some(values.value_offset(index) as u32))
+                    }
+                    None => {
+                        dst_type_ids.push(null_type_id);
+                        Ok(None)
+                    }
+                })
+                .collect::<Result<UInt32Array, ArrowError>>()?;
+            vec![child_indices; type_ids.len()]
+        }
+        UnionMode::Dense => {
+            // Maps a type id to the index of its child
+            let mut child_idx = [usize::MAX; 128];
+            for (idx, type_id) in type_ids.iter().enumerate() {
+                child_idx[*type_id as usize] = idx;
+            }
+
+            let mut child_indices: Vec<Vec<Option<u32>>> = vec![vec![]; type_ids.len()];
+            let mut dst_offsets =
+                MutableBuffer::with_capacity(indices.len() * std::mem::size_of::<i32>());
+            for index in indices.iter() {
+                let (type_id, offset) = match index {
+                    Some(index) => {
+                        let index = to_index(index)?;
+                        let offset = values.value_offset(index) as u32;
+                        (values.type_id(index), Some(offset))
+                    }
+                    None => (null_type_id, None),
+                };
+                let child = &mut child_indices[child_idx[type_id as usize]];
+                dst_type_ids.push(type_id);
+                dst_offsets.push(child.len() as i32);
+                child.push(offset);
+            }
+            buffers.push(dst_offsets.into());
+            child_indices.into_iter().map(UInt32Array::from).collect()
+        }
+    };
+    buffers.insert(0, dst_type_ids.into());
+
+    let children = values
+        .data()
+        .child_data()
+        .iter()
+        .zip(child_indices)
+        .map(|(child, indices)| {
+            let taken = take_impl(make_array(child.clone()).as_ref(), &indices, None)?;
+            Ok(taken.into_data())
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    let data = ArrayDataBuilder::new(values.data_type().clone())
+        .len(indices.len())
+        .buffers(buffers)
+        .child_data(children);
+
+    // SAFETY: each type id and offset references a value in the taken children
+    let data = unsafe { data.build_unchecked() };
+    Ok(UnionArray::from(data))
+}
+
 /// `take` implementation for `FixedSizeListArray`
 ///
 /// Calculates the index and indexed offset for the inner array,
@@ -810,6 +937,69 @@ where
     Ok(DictionaryArray::<T>::from(data))
 }
 
+/// `take` implementation for run end encoded arrays
+///
+/// The valid indices are mapped to physical indices of the values in a single pass
+/// using [`RunArray::get_physical_indices`]. Consecutive indices into the same run
+/// produce a single run in the output.
+fn take_run<R, I>(
+    values: &dyn Array,
+    indices: &PrimitiveArray<I>,
+) -> Result<RunArray<R>, ArrowError>
+where
+    R: RunEndIndexType,
+    I: ArrowPrimitiveType,
+    I::Native: ToPrimitive,
+{
+    let values = values.as_any().downcast_ref::<RunArray<R>>().unwrap();
+    let len = values.len();
+    let run_end_err = || {
+        ArrowError::ComputeError(format!(
+            "Cannot represent {} indices with run end type {:?}",
+            indices.len(),
+            R::DATA_TYPE
+        ))
+    };
+
+    let logical_indices = indices
+        .iter()
+        .flatten()
+        .map(|index| {
+            let index = maybe_usize::<I::Native>(index)?;
+            if index >= len {
+                return Err(ArrowError::ComputeError(format!(
+                    "Array index out of bounds, cannot get item at index {index} from {len} entries"
+                )));
+            }
+            Ok(index)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut physical = values.get_physical_indices(&logical_indices)?.into_iter();
+
+    let mut new_run_ends = Vec::new();
+    let mut physical_indices = Vec::new();
+    for (idx, index) in indices.iter().enumerate() {
+        let physical = index.and_then(|_| physical.next());
+        match physical_indices.last() {
+            Some(last) if *last == physical => {
+                *new_run_ends.last_mut().unwrap() =
+                    R::Native::from_usize(idx + 1).ok_or_else(run_end_err)?;
+            }
+            _ => {
+                new_run_ends
+                    .push(R::Native::from_usize(idx + 1).ok_or_else(run_end_err)?);
+                physical_indices.push(physical);
+            }
+        }
+    }
+
+    let new_run_ends = PrimitiveArray::<R>::from_iter_values(new_run_ends);
+    let physical_indices =
+        UInt64Array::from_iter(physical_indices.into_iter().map(|x| x.map(|x| x as u64)));
+    let new_values = take_impl(values.values().as_ref(), &physical_indices, None)?;
+    RunArray::try_new(&new_run_ends, new_values.as_ref())
+}
+
 /// Takes/filters a list array's inner data using the offsets of the list array.
 ///
 /// Where a list array has indices `[0,2,5,10]`, taking indices of `[2,0]` returns
@@ -1927,6 +2117,100 @@ mod tests {
         assert_eq!(&expected, actual);
     }
 
+    #[test]
+    fn test_take_map() {
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.keys().append_value("c");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        let array = builder.finish();
+
+        let index = UInt32Array::from(vec![Some(2), None, Some(0), Some(1)]);
+        let actual = take(&array, &index, None).unwrap();
+        let actual = actual.as_any().downcast_ref::<MapArray>().unwrap();
+        assert_eq!(actual.data_type(), array.data_type());
+        assert_eq!(actual.len(), 4);
+        assert_eq!(actual.null_count(), 2);
+        assert_eq!(actual.value_offsets(), &[0, 2, 2, 3, 3]);
+        assert_eq!(actual.value(0).as_ref(), array.value(2).as_ref());
+        assert_eq!(actual.value(2).as_ref(), array.value(0).as_ref());
+    }
+
+    fn test_take_union(array: UnionArray) {
+        let index = UInt32Array::from(vec![Some(3), None, Some(0), Some(3), Some(2)]);
+        let actual = take(&array, &index, None).unwrap();
+        let actual = actual.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(actual.data_type(), array.data_type());
+        assert_eq!(actual.len(), index.len());
+
+        for (i, idx) in index.iter().enumerate() {
+            let value = actual.value(i);
+            match idx {
+                Some(idx) => {
+                    let idx = idx as usize;
+                    assert_eq!(actual.type_id(i), array.type_id(idx));
+                    assert_eq!(value.as_ref(), array.value(idx).as_ref());
+                }
+                None => assert!(value.is_null(0)),
+            }
+        }
+
+        let index = UInt32Array::from(vec![Some(0), Some(array.len() as u32)]);
+        let err = take(&array, &index, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Compute error: Array index out of bounds, cannot get item at index {} from {} entries",
+                array.len(),
+                array.len()
+            )
+        );
+    }
+
+    #[test]
+    fn test_take_union_dense() {
+        let mut builder = UnionBuilder::new_dense();
+        builder.append::<Int32Type>("A", 1).unwrap();
+        builder.append::<Float64Type>("B", 3.2).unwrap();
+        builder.append_null::<Float64Type>("B").unwrap();
+        builder.append::<Int32Type>("A", 34).unwrap();
+        let array = builder.build().unwrap();
+        test_take_union(array.clone());
+
+        // Offsets are rebuilt to reference the taken children
+        let index = UInt32Array::from(vec![3, 1, 0]);
+        let actual = take(&array, &index, None).unwrap();
+        let actual = actual.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(actual.value_offset(0), 0);
+        assert_eq!(actual.value_offset(1), 0);
+        assert_eq!(actual.value_offset(2), 1);
+        assert_eq!(actual.child(0).len(), 2);
+        assert_eq!(actual.child(1).len(), 1);
+    }
+
+    #[test]
+    fn test_take_union_sparse() {
+        let mut builder = UnionBuilder::new_sparse();
+        builder.append::<Int32Type>("A", 1).unwrap();
+        builder.append::<Float64Type>("B", 3.2).unwrap();
+        builder.append_null::<Float64Type>("B").unwrap();
+        builder.append::<Int32Type>("A", 34).unwrap();
+        builder.append::<Int32Type>("A", 5).unwrap();
+        let array = builder.build().unwrap();
+        test_take_union(array.clone());
+
+        let sliced = array.slice(1, 4);
+        let sliced = sliced.as_any().downcast_ref::<UnionArray>().unwrap();
+        test_take_union(sliced.clone());
+    }
+
     #[test]
     fn test_take_out_of_bounds() {
         let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(3), Some(6)]);
@@ -2040,6 +2324,90 @@ mod tests {
         assert_eq!(result.keys(), &expected_keys);
     }
 
+    #[test]
+    fn test_take_run() {
+        let run_ends = Int32Array::from(vec![2, 3, 6, 7]);
+        let values = StringArray::from(vec![Some("a"), None, Some("b"), Some("c")]);
+        let array = RunArray::<Int32Type>::try_new(&run_ends, &values).unwrap();
+
+        let indices = UInt32Array::from(vec![
+            Some(0),
+            Some(1),
+            Some(3),
+            Some(4),
+            None,
+            Some(2),
+            Some(6),
+            Some(0),
+        ]);
+        let result = take(&array, &indices, None).unwrap();
+        let result = result
+            .as_any()
+            .downcast_ref::<RunArray<Int32Type>>()
+            .unwrap();
+        assert_eq!(result.len(), 8);
+        assert_eq!(result.run_ends().values(), &[2, 4, 5, 6, 7, 8]);
+        let result_values = result
+            .values()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let expected_values = StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            None,
+            Some("c"),
+            Some("a"),
+        ]);
+        assert_eq!(result_values, &expected_values);
+
+        let indices = UInt32Array::from(vec![7]);
+        let options = TakeOptions { check_bounds: true };
+        let err = take(&array, &indices, Some(options)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 7 from 7 entries"
+        );
+    }
+
+    #[test]
+    fn test_take_run_sliced() {
+        let array: Int16RunArray = vec!["a", "a", "b", "c", "c", "c", "d"]
+            .into_iter()
+            .collect();
+        let array = array.slice(2, 4);
+
+        let indices = Int64Array::from(vec![3, 0, 1, 2]);
+        let result = take(array.as_ref(), &indices, None).unwrap();
+        let result = result.as_any().downcast_ref::<Int16RunArray>().unwrap();
+        assert_eq!(result.run_ends().values(), &[1, 2, 4]);
+        let result_values = result
+            .values()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(result_values, &StringArray::from(vec!["c", "b", "c"]));
+
+        let indices = Int64Array::from(vec![4]);
+        let err = take(array.as_ref(), &indices, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Array index out of bounds, cannot get item at index 4 from 4 entries"
+        );
+    }
+
+    #[test]
+    fn test_take_run_overflow() {
+        let array: Int16RunArray = vec!["a"].into_iter().collect();
+        let indices = UInt32Array::from(vec![0; i16::MAX as usize + 1]);
+        let err = take(&array, &indices, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: Cannot represent 32768 indices with run end type Int16"
+        );
+    }
+
     fn build_generic_list<S, T>(data: Vec<Option<Vec<T::Native>>>) -> GenericListArray<S>
     where
         S: OffsetSizeTrait + 'static,
@@ -16,13 +16,24 @@
 // under the License.
 
 use crate::filter::SlicesIterator;
+use arrow_array::cast::as_run_array;
+use arrow_array::types::RunEndIndexType;
 use arrow_array::*;
+use arrow_buffer::ArrowNativeType;
 use arrow_data::transform::MutableArrayData;
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, DataType};
+use std::sync::Arc;
 
 /// Zip two arrays by some boolean mask. Where the mask evaluates `true` values of `truthy`
 /// are taken, where the mask evaluates `false` values of `falsy` are taken.
 ///
+/// Supports arrays of any type, including nested types such as lists and structs. If
+/// `truthy` and `falsy` are [`DictionaryArray`]s with different values, the output
+/// dictionary contains the values of both, returning an error if the keys would overflow.
+///
+/// If `truthy` and `falsy` are [`RunArray`]s, the output is a [`RunArray`] whose runs
+/// are computed from the intersection of the input runs and the mask
+///
 /// # Arguments
 /// * `mask` - Boolean values used to determine from which array to take the values.
 /// * `truthy` - Values of this array are taken if mask evaluates `true`
@@ -32,6 +43,60 @@ pub fn zip(
     truthy: &dyn Array,
     falsy: &dyn Array,
 ) -> Result<ArrayRef, ArrowError> {
+    check_arguments(mask, truthy, falsy)?;
+    zip_slices(SlicesIterator::new(mask), truthy, falsy)
+}
+
+/// Zip two arrays by a run-end encoded boolean mask, see [`zip`]
+///
+/// The mask is processed a run at a time, with null mask values treated as `false`
+///
+/// # Arguments
+/// * `mask` - Run-end encoded boolean values used to determine from which array to take the values.
+/// * `truthy` - Values of this array are taken if mask evaluates `true`
+/// * `falsy` - Values of this array are taken if mask evaluates `false`
+pub fn zip_run<R: RunEndIndexType>(
+    mask: &RunArray<R>,
+    truthy: &dyn Array,
+    falsy: &dyn Array,
+) -> Result<ArrayRef, ArrowError> {
+    check_arguments(mask, truthy, falsy)?;
+    let mask_values = mask
+        .values()
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "mask should have boolean values, got {}",
+                mask.values().data_type()
+            ))
+        })?;
+
+    let len = mask.len();
+    let offset = mask.offset();
+    let mut slices: Vec<(usize, usize)> = vec![];
+    let mut run_start = 0;
+    if len > 0 {
+        let run_ends = mask.run_ends();
+        for physical in mask.get_start_physical_index()..=mask.get_end_physical_index() {
+            let run_end = (run_ends.value(physical).as_usize() - offset).min(len);
+            if mask_values.is_valid(physical) && mask_values.value(physical) {
+                match slices.last_mut() {
+                    Some((_, end)) if *end == run_start => *end = run_end,
+                    _ => slices.push((run_start, run_end)),
+                }
+            }
+            run_start = run_end;
+        }
+    }
+    zip_slices(slices.into_iter(), truthy, falsy)
+}
+
+fn check_arguments(
+    mask: &dyn Array,
+    truthy: &dyn Array,
+    falsy: &dyn Array,
+) -> Result<(), ArrowError> {
     if truthy.data_type() != falsy.data_type() {
         return Err(ArrowError::InvalidArgumentError(
             "arguments need to have the same data type".into(),
@@ -42,6 +107,55 @@ pub fn zip(
             "all arrays should have the same length".into(),
         ));
     }
+    if let DataType::Dictionary(key_type, _) = truthy.data_type() {
+        check_dictionary_keys(key_type, &[truthy, falsy])?;
+    }
+    Ok(())
+}
+
+/// Returns an error if the values of the dictionaries `arrays` differ, and their
+/// combined length would overflow `key_type`
+pub(crate) fn check_dictionary_keys(
+    key_type: &DataType,
+    arrays: &[&dyn Array],
+) -> Result<(), ArrowError> {
+    let values: Vec<_> = arrays.iter().map(|a| &a.data().child_data()[0]).collect();
+    if values.windows(2).all(|v| v[0].ptr_eq(v[1])) {
+        return Ok(());
+    }
+
+    // the number of distinct values that can be represented by `key_type`
+    let max = match key_type {
+        DataType::Int8 => i8::MAX as usize + 1,
+        DataType::Int16 => i16::MAX as usize + 1,
+        DataType::Int32 => (i32::MAX as usize).saturating_add(1),
+        DataType::UInt8 => u8::MAX as usize + 1,
+        DataType::UInt16 => u16::MAX as usize + 1,
+        DataType::UInt32 => (u32::MAX as usize).saturating_add(1),
+        _ => usize::MAX,
+    };
+    match values.iter().map(|v| v.len()).sum::<usize>() > max {
+        true => Err(ArrowError::DictionaryKeyOverflowError),
+        false => Ok(()),
+    }
+}
+
+/// Zips `truthy` and `falsy` given the sorted, non-overlapping `(start, end)` slices
+/// of the mask that are `true`
+fn zip_slices(
+    slices: impl Iterator<Item = (usize, usize)>,
+    truthy: &dyn Array,
+    falsy: &dyn Array,
+) -> Result<ArrayRef, ArrowError> {
+    if let DataType::RunEndEncoded(run_ends, _) = truthy.data_type() {
+        return Ok(match run_ends.data_type() {
+            DataType::Int16 => zip_run_values::<types::Int16Type>(slices, truthy, falsy),
+            DataType::Int32 => zip_run_values::<types::Int32Type>(slices, truthy, falsy),
+            DataType::Int64 => zip_run_values::<types::Int64Type>(slices, truthy, falsy),
+            t => unreachable!("Invalid run end type {}", t),
+        });
+    }
+
     let falsy = falsy.data();
     let truthy = truthy.data();
 
@@ -53,7 +167,7 @@ pub fn zip(
     // keep track of how much is filled
     let mut filled = 0;
 
-    SlicesIterator::new(mask).for_each(|(start, end)| {
+    slices.for_each(|(start, end)| {
         // the gap needs to be filled with falsy values
         if start > filled {
             mutable.extend(1, filled, start);
@@ -71,9 +185,86 @@ pub fn zip(
     Ok(make_array(data))
 }
 
+/// Zips two [`RunArray`]s, computing the output runs from the intersection of
+/// the runs of the selected input and the slices of the mask
+fn zip_run_values<R: RunEndIndexType>(
+    slices: impl Iterator<Item = (usize, usize)>,
+    truthy: &dyn Array,
+    falsy: &dyn Array,
+) -> ArrayRef {
+    let inputs = [as_run_array::<R>(truthy), as_run_array::<R>(falsy)];
+    let len = truthy.len();
+
+    let mut run_ends: Vec<R::Native> = vec![];
+    // the (input, physical index) of the value of each output run
+    let mut run_values: Vec<(usize, usize)> = vec![];
+
+    let mut push_runs = |input: usize, start: usize, end: usize| {
+        let array = inputs[input];
+        let offset = array.offset();
+        let array_run_ends = array.run_ends();
+        let mut physical = array.get_physical_index(start).unwrap();
+        let mut pos = start;
+        while pos < end {
+            let run_end = (array_run_ends.value(physical).as_usize() - offset).min(end);
+            match run_values.last() {
+                // extend the previous output run if it refers to the same input value
+                Some(last) if *last == (input, physical) => {
+                    *run_ends.last_mut().unwrap() =
+                        R::Native::from_usize(run_end).unwrap()
+                }
+                _ => {
+                    run_ends.push(R::Native::from_usize(run_end).unwrap());
+                    run_values.push((input, physical));
+                }
+            }
+            pos = run_end;
+            physical += 1;
+        }
+    };
+
+    let mut filled = 0;
+    for (start, end) in slices {
+        if start > filled {
+            push_runs(1, filled, start);
+        }
+        push_runs(0, start, end);
+        filled = end;
+    }
+    if filled < len {
+        push_runs(1, filled, len);
+    }
+
+    let truthy_values = inputs[0].values().data();
+    let falsy_values = inputs[1].values().data();
+    let mut mutable =
+        MutableArrayData::new(vec![truthy_values, falsy_values], false, run_values.len());
+    // copy contiguous physical values of the same input at once
+    let mut idx = 0;
+    while idx < run_values.len() {
+        let (input, start) = run_values[idx];
+        let mut end = start + 1;
+        idx += 1;
+        while idx < run_values.len() && run_values[idx] == (input, end) {
+            end += 1;
+            idx += 1;
+        }
+        mutable.extend(input, start, end);
+    }
+
+    let run_ends = PrimitiveArray::<R>::from_iter_values(run_ends);
+    let values = make_array(mutable.freeze());
+    Arc::new(RunArray::try_new(&run_ends, values.as_ref()).unwrap())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use arrow_array::cast::{
+        as_boolean_array, as_dictionary_array, as_list_array, as_struct_array,
+    };
+    use arrow_array::types::{Int16Type, Int32Type, Int8Type};
+    use arrow_schema::Field;
 
     #[test]
     fn test_zip_kernel() {
@@ -85,4 +276,182 @@ mod test {
         let expected = Int32Array::from(vec![Some(5), None, Some(6), Some(7), Some(1)]);
         assert_eq!(actual, &expected);
     }
+
+    #[test]
+    fn test_zip_nested() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![Some(2), None]),
+        ]);
+        let b = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            None,
+            Some(vec![]),
+            Some(vec![Some(3)]),
+        ]);
+        let mask = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let out = zip(&mask, &a, &b).unwrap();
+        let expected = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            Some(vec![]),
+            Some(vec![Some(3)]),
+        ]);
+        assert_eq!(as_list_array(out.as_ref()), &expected);
+
+        let struct_array = |ints: Vec<Option<i32>>, strings: Vec<&str>| {
+            StructArray::from(vec![
+                (
+                    Field::new("i", DataType::Int32, true),
+                    Arc::new(Int32Array::from(ints)) as ArrayRef,
+                ),
+                (
+                    Field::new("s", DataType::Utf8, false),
+                    Arc::new(StringArray::from(strings)) as ArrayRef,
+                ),
+            ])
+        };
+        let a = struct_array(vec![Some(1), None, Some(3)], vec!["a", "b", "c"]);
+        let b = struct_array(vec![Some(4), Some(5), None], vec!["d", "e", "f"]);
+        let mask = BooleanArray::from(vec![false, true, false]);
+        let out = zip(&mask, &a, &b).unwrap();
+        let expected = struct_array(vec![Some(4), None, None], vec!["d", "b", "f"]);
+        assert_eq!(as_struct_array(out.as_ref()), &expected);
+
+        let a =
+            FixedSizeBinaryArray::try_from_iter(vec![b"ab", b"cd"].into_iter()).unwrap();
+        let b = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![None, Some(b"ef")].into_iter(),
+            2,
+        )
+        .unwrap();
+        let mask = BooleanArray::from(vec![false, true]);
+        let out = zip(&mask, &a, &b).unwrap();
+        let expected = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![None, Some(b"cd")].into_iter(),
+            2,
+        )
+        .unwrap();
+        assert_eq!(
+            out.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap(),
+            &expected
+        );
+    }
+
+    #[test]
+    fn test_zip_dictionary() {
+        let a: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("b")].into_iter().collect();
+        let b: DictionaryArray<Int8Type> =
+            vec![Some("c"), Some("c"), Some("a")].into_iter().collect();
+        let mask = BooleanArray::from(vec![true, false, false]);
+        let out = zip(&mask, &a, &b).unwrap();
+        out.data().validate_full().unwrap();
+        let out = as_dictionary_array::<Int8Type>(out.as_ref());
+        let values: Vec<_> = out
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some("a"), Some("c"), Some("a")]);
+
+        // Dictionaries with the same values are not concatenated
+        let keys = Int8Array::from(vec![1, 0, 0]);
+        let b = DictionaryArray::try_new(&keys, a.values()).unwrap();
+        let out = zip(&mask, &a, &b).unwrap();
+        let out = as_dictionary_array::<Int8Type>(out.as_ref());
+        assert_eq!(out.values().len(), a.values().len());
+        assert_eq!(
+            out.keys(),
+            &Int8Array::from(vec![Some(0), Some(0), Some(0)])
+        );
+
+        // Int8 keys can represent 128 distinct values
+        let values = StringArray::from_iter_values((0..100).map(|i| i.to_string()));
+        let keys = Int8Array::from(vec![0, 1, 2]);
+        let a = DictionaryArray::try_new(&keys, &values).unwrap();
+        let values = StringArray::from_iter_values((100..128).map(|i| i.to_string()));
+        let b = DictionaryArray::try_new(&keys, &values).unwrap();
+        let out = zip(&mask, &a, &b).unwrap();
+        out.data().validate_full().unwrap();
+        let out = as_dictionary_array::<Int8Type>(out.as_ref());
+        assert_eq!(out.values().len(), 128);
+        assert_eq!(out.keys(), &Int8Array::from(vec![0, 101, 102]));
+
+        let values = StringArray::from_iter_values((100..129).map(|i| i.to_string()));
+        let b = DictionaryArray::try_new(&keys, &values).unwrap();
+        let err = zip(&mask, &a, &b).unwrap_err();
+        assert!(
+            matches!(err, ArrowError::DictionaryKeyOverflowError),
+            "{err}"
+        );
+    }
+
+    fn run_array(run_ends: Vec<i32>, values: Vec<Option<i32>>) -> Int32RunArray {
+        let run_ends = Int32Array::from(run_ends);
+        let values = Int32Array::from(values);
+        RunArray::try_new(&run_ends, &values).unwrap()
+    }
+
+    #[test]
+    fn test_zip_run_inputs() {
+        // 1, 1, 1, 2, 2, 2
+        let a = run_array(vec![3, 6], vec![Some(1), Some(2)]);
+        // 3, 3, NULL, NULL, NULL, NULL
+        let b = run_array(vec![2, 6], vec![Some(3), None]);
+        let mask = BooleanArray::from(vec![true, true, false, false, true, true]);
+
+        let out = zip(&mask, &a, &b).unwrap();
+        let out = as_run_array::<Int32Type>(out.as_ref());
+        assert_eq!(out.run_ends().values(), &[2, 4, 6]);
+        assert_eq!(
+            out.values().as_ref(),
+            &Int32Array::from(vec![Some(1), None, Some(2)])
+        );
+        assert_eq!(
+            out,
+            &Int32Array::from(vec![Some(1), Some(1), None, None, Some(2), Some(2)])
+        );
+
+        let mask = mask.slice(1, 4);
+        let mask = as_boolean_array(mask.as_ref());
+        let out = zip(mask, &a.slice(2, 4), &b.slice(2, 4)).unwrap();
+        assert_eq!(
+            as_run_array::<Int32Type>(out.as_ref()),
+            &Int32Array::from(vec![Some(1), None, None, Some(2)])
+        );
+    }
+
+    #[test]
+    fn test_zip_run_mask() {
+        // true, true, NULL, false, false, true
+        let run_ends = Int16Array::from(vec![2, 3, 5, 6]);
+        let values = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let mask = RunArray::<Int16Type>::try_new(&run_ends, &values).unwrap();
+
+        let a = Int32Array::from(vec![0, 1, 2, 3, 4, 5]);
+        let b = Int32Array::from(vec![10, 11, 12, 13, 14, 15]);
+        let out = zip_run(&mask, &a, &b).unwrap();
+        assert_eq!(out.as_ref(), &Int32Array::from(vec![0, 1, 12, 13, 14, 5]));
+
+        let sliced = mask.slice(1, 4);
+        let sliced = as_run_array::<Int16Type>(sliced.as_ref());
+        let out = zip_run(sliced, &a.slice(0, 4), &b.slice(0, 4)).unwrap();
+        assert_eq!(out.as_ref(), &Int32Array::from(vec![0, 11, 12, 13]));
+
+        // run-end encoded mask and inputs
+        let a = run_array(vec![3, 6], vec![Some(1), Some(2)]);
+        let b = run_array(vec![1, 6], vec![Some(3), Some(4)]);
+        let out = zip_run(&mask, &a, &b).unwrap();
+        let out = as_run_array::<Int32Type>(out.as_ref());
+        assert_eq!(out.run_ends().values(), &[2, 5, 6]);
+        assert_eq!(out, &Int32Array::from(vec![1, 1, 4, 4, 4, 2]));
+
+        let values = Int32Array::from(vec![1, 2, 3, 4]);
+        let mask = RunArray::<Int16Type>::try_new(&run_ends, &values).unwrap();
+        let err = zip_run(&mask, &a, &b).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: mask should have boolean values, got Int32"
+        );
+    }
 }
@@ -0,0 +1,201 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to convert the case of string arrays, and to test their values
+//! for a prefix, suffix or substring
+
+use crate::like::{
+    contains_utf8_scalar_dyn, ends_with_utf8_scalar_dyn, starts_with_utf8_scalar_dyn,
+};
+use crate::string_unary_dyn;
+use arrow_array::{downcast_dictionary_array, Array, ArrayRef, BooleanArray};
+use arrow_schema::{ArrowError, DataType};
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// Returns a new array with the values of `array` converted to upper case
+///
+/// Supports [`StringArray`](arrow_array::StringArray),
+/// [`LargeStringArray`](arrow_array::LargeStringArray), and
+/// [`DictionaryArray`](arrow_array::DictionaryArray) with values of either type, in
+/// which case only the dictionary values are converted and the result is a
+/// [`DictionaryArray`](arrow_array::DictionaryArray) with the same keys
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::as_string_array;
+/// # use arrow_string::case::upper;
+/// let array = StringArray::from(vec![Some("arrow"), None, Some("straße")]);
+/// let result = upper(&array).unwrap();
+/// let expected = StringArray::from(vec![Some("ARROW"), None, Some("STRASSE")]);
+/// assert_eq!(as_string_array(&result), &expected);
+/// ```
+pub fn upper(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    string_unary_dyn(array, "upper", &|v| Cow::Owned(v.to_uppercase()))
+}
+
+/// Returns a new array with the values of `array` converted to lower case
+///
+/// See [`upper`] for the supported types
+pub fn lower(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    string_unary_dyn(array, "lower", &|v| Cow::Owned(v.to_lowercase()))
+}
+
+/// Returns for each value of `array` if it starts with `prefix`
+///
+/// Supports the same types as [`upper`], returning a
+/// [`BooleanArray`](arrow_array::BooleanArray) for string arrays. For a
+/// [`DictionaryArray`](arrow_array::DictionaryArray) only the dictionary values are
+/// tested, and the result is a [`DictionaryArray`](arrow_array::DictionaryArray) of
+/// boolean values with the same keys
+///
+/// ```
+/// # use arrow_array::{BooleanArray, StringArray};
+/// # use arrow_array::cast::as_boolean_array;
+/// # use arrow_string::case::starts_with;
+/// let array = StringArray::from(vec![Some("arrow"), None, Some("parquet")]);
+/// let result = starts_with(&array, "ar").unwrap();
+/// let expected = BooleanArray::from(vec![Some(true), None, Some(false)]);
+/// assert_eq!(as_boolean_array(&result), &expected);
+/// ```
+pub fn starts_with(array: &dyn Array, prefix: &str) -> Result<ArrayRef, ArrowError> {
+    predicate_dyn(array, prefix, starts_with_utf8_scalar_dyn)
+}
+
+/// Returns for each value of `array` if it ends with `suffix`
+///
+/// See [`starts_with`] for the supported types
+pub fn ends_with(array: &dyn Array, suffix: &str) -> Result<ArrayRef, ArrowError> {
+    predicate_dyn(array, suffix, ends_with_utf8_scalar_dyn)
+}
+
+/// Returns for each value of `array` if it contains `pattern`
+///
+/// See [`starts_with`] for the supported types
+pub fn contains(array: &dyn Array, pattern: &str) -> Result<ArrayRef, ArrowError> {
+    predicate_dyn(array, pattern, contains_utf8_scalar_dyn)
+}
+
+/// Evaluates the [`like`](crate::like) kernel `op` for the values of `array`, or for
+/// only the dictionary values of a [`DictionaryArray`](arrow_array::DictionaryArray),
+/// preserving the keys
+fn predicate_dyn(
+    array: &dyn Array,
+    pattern: &str,
+    op: fn(&dyn Array, &str) -> Result<BooleanArray, ArrowError>,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Dictionary(_, _) => downcast_dictionary_array!(
+            array => {
+                let values = predicate_dyn(array.values().as_ref(), pattern, op)?;
+                Ok(Arc::new(array.with_values(values.as_ref())))
+            }
+            t => unreachable!("unexpected dictionary type {}", t)
+        ),
+        _ => Ok(Arc::new(op(array, pattern)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::*;
+    use arrow_array::types::Int32Type;
+    use arrow_array::*;
+
+    #[test]
+    fn test_case() {
+        let array = LargeStringArray::from(vec![Some("aRrOw"), None, Some("ΑΒΓ")]);
+        let result = lower(&array).unwrap();
+        let expected = LargeStringArray::from(vec![Some("arrow"), None, Some("αβγ")]);
+        assert_eq!(as_largestring_array(&result), &expected);
+
+        let array = StringArray::from(vec![Some("aRrOw"), None, Some("ß"), Some("xyz")]);
+        let array = array.slice(1, 3);
+        let result = upper(array.as_ref()).unwrap();
+        let expected = StringArray::from(vec![None, Some("SS"), Some("XYZ")]);
+        assert_eq!(as_string_array(&result), &expected);
+
+        let err = upper(&Int32Array::from(vec![1])).unwrap_err().to_string();
+        assert!(err.contains("upper only supports Utf8"), "{err}");
+    }
+
+    #[test]
+    fn test_case_ascii_sliced() {
+        let array =
+            StringArray::from(vec![Some("Hello"), None, Some("World"), Some("!")]);
+        let sliced = array.slice(1, 2);
+        let result = lower(sliced.as_ref()).unwrap();
+        let expected = StringArray::from(vec![None, Some("world")]);
+        assert_eq!(as_string_array(&result), &expected);
+    }
+
+    #[test]
+    fn test_case_dictionary() {
+        let array: DictionaryArray<Int32Type> =
+            vec![Some("a"), Some("B"), None, Some("a")]
+                .into_iter()
+                .collect();
+        let result = upper(&array).unwrap();
+        let result = as_dictionary_array::<Int32Type>(&result);
+        assert_eq!(result.keys(), array.keys());
+        let values: Vec<_> = result
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some("A"), Some("B"), None, Some("A")]);
+    }
+
+    #[test]
+    fn test_predicates() {
+        let array = LargeStringArray::from(vec![Some("arrow"), None, Some("narrow")]);
+        let result = starts_with(&array, "ar").unwrap();
+        let expected = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        assert_eq!(as_boolean_array(&result), &expected);
+
+        let result = ends_with(&array, "row").unwrap();
+        let expected = BooleanArray::from(vec![Some(true), None, Some(true)]);
+        assert_eq!(as_boolean_array(&result), &expected);
+
+        let err = contains(&Int32Array::from(vec![1]), "a").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("contains_utf8_scalar_dyn only supports Utf8"));
+    }
+
+    #[test]
+    fn test_predicates_dictionary() {
+        let array: DictionaryArray<Int32Type> =
+            vec![Some("arrow"), Some("parquet"), None, Some("arrow")]
+                .into_iter()
+                .collect();
+        let result = contains(&array, "rq").unwrap();
+        let result = as_dictionary_array::<Int32Type>(&result);
+        assert_eq!(result.keys(), array.keys());
+        assert_eq!(
+            as_boolean_array(result.values()),
+            &BooleanArray::from(vec![false, true])
+        );
+        let values: Vec<_> = result
+            .downcast_dict::<BooleanArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some(false), Some(true), None, Some(false)]);
+    }
+}
@@ -15,11 +15,16 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow_array::builder::BufferBuilder;
+use arrow_array::builder::{BooleanBufferBuilder, BufferBuilder};
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
 use arrow_array::*;
+use arrow_buffer::ArrowNativeType;
 use arrow_data::bit_mask::combine_option_bitmap;
 use arrow_data::ArrayDataBuilder;
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, DataType};
+use arrow_select::take::take;
+use std::sync::Arc;
 
 /// Returns the elementwise concatenation of a [`StringArray`].
 ///
@@ -156,6 +161,133 @@ pub fn concat_elements_utf8_many<Offset: OffsetSizeTrait>(
     Ok(unsafe { builder.build_unchecked() }.into())
 }
 
+/// Returns the elementwise concatenation of `arrays`, which must all be of the same
+/// string or binary type, or dictionaries with values of that type, with `separator`
+/// between each value.
+///
+/// If `skip_nulls` is `false`, an index of the result is null if any of `arrays` are null
+/// at that location. Otherwise null values are skipped, along with their separator, and
+/// the result contains no nulls.
+///
+/// ```text
+/// e.g:
+///   concat(["a", "b"], [None, "c"], sep: "-", skip_nulls: false) = [None, "b-c"]
+///
+///   concat(["a", "b"], [None, "c"], sep: "-", skip_nulls: true) = ["a", "b-c"]
+/// ```
+///
+/// An error will be returned if the arrays are of different lengths or types
+pub fn concat_elements_dyn_many(
+    arrays: &[&dyn Array],
+    separator: Option<&str>,
+    skip_nulls: bool,
+) -> Result<ArrayRef, ArrowError> {
+    if arrays.is_empty() {
+        return Err(ArrowError::ComputeError(
+            "concat requires input of at least one array".to_string(),
+        ));
+    }
+
+    let size = arrays[0].len();
+    if !arrays.iter().all(|array| array.len() == size) {
+        return Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same length of {size}",
+        )));
+    }
+
+    // Expand any dictionaries to their values
+    let arrays = arrays
+        .iter()
+        .map(|&array| match array.data_type() {
+            DataType::Dictionary(_, _) => downcast_dictionary_array!(
+                array => take(array.values().as_ref(), array.keys(), None),
+                t => unreachable!("unexpected dictionary type {}", t)
+            ),
+            _ => Ok(make_array(array.data().clone())),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let data_type = arrays[0].data_type();
+    if let Some(array) = arrays.iter().find(|a| a.data_type() != data_type) {
+        return Err(ArrowError::ComputeError(format!(
+            "Arrays must have the same type, got {} and {}",
+            data_type,
+            array.data_type()
+        )));
+    }
+
+    let separator = separator.unwrap_or_default().as_bytes();
+    match data_type {
+        DataType::Utf8 => concat_bytes_many::<Utf8Type>(&arrays, separator, skip_nulls),
+        DataType::LargeUtf8 => {
+            concat_bytes_many::<LargeUtf8Type>(&arrays, separator, skip_nulls)
+        }
+        DataType::Binary => {
+            concat_bytes_many::<BinaryType>(&arrays, separator, skip_nulls)
+        }
+        DataType::LargeBinary => {
+            concat_bytes_many::<LargeBinaryType>(&arrays, separator, skip_nulls)
+        }
+        t => Err(ArrowError::NotYetImplemented(format!(
+            "concat_elements not supported for {t}"
+        ))),
+    }
+}
+
+fn concat_bytes_many<T: ByteArrayType>(
+    arrays: &[ArrayRef],
+    separator: &[u8],
+    skip_nulls: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let arrays: Vec<_> = arrays.iter().map(|a| a.as_bytes::<T>()).collect();
+    let size = arrays[0].len();
+
+    let mut output_values = BufferBuilder::<u8>::new(
+        arrays.iter().map(|a| a.value_data().len()).sum::<usize>()
+            + separator.len() * (arrays.len() - 1) * size,
+    );
+    let mut output_offsets = BufferBuilder::<T::Offset>::new(size + 1);
+    let mut output_nulls = BooleanBufferBuilder::new(size);
+    output_offsets.append(T::Offset::usize_as(0));
+
+    for idx in 0..size {
+        let valid = skip_nulls || arrays.iter().all(|a| a.is_valid(idx));
+        if valid {
+            let mut values = arrays.iter().filter(|a| a.is_valid(idx));
+            if let Some(first) = values.next() {
+                let value: &[u8] = first.value(idx).as_ref();
+                output_values.append_slice(value);
+            }
+            for array in values {
+                let value: &[u8] = array.value(idx).as_ref();
+                output_values.append_slice(separator);
+                output_values.append_slice(value);
+            }
+        }
+        output_nulls.append(valid);
+
+        let offset = T::Offset::from_usize(output_values.len()).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "{}Array offset overflow concatenating elements",
+                T::PREFIX
+            ))
+        })?;
+        output_offsets.append(offset);
+    }
+
+    let builder = ArrayDataBuilder::new(T::DATA_TYPE)
+        .len(size)
+        .add_buffer(output_offsets.finish())
+        .add_buffer(output_values.finish())
+        .null_bit_buffer((!skip_nulls).then(|| output_nulls.finish()));
+
+    // SAFETY - offsets valid by construction, and concatenating valid UTF-8
+    // with a UTF-8 separator produces valid UTF-8
+    Ok(Arc::new(GenericByteArray::<T>::from(unsafe {
+        builder.build_unchecked()
+    })))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,4 +433,62 @@ mod tests {
 
         assert_eq!(output, expected);
     }
+
+    #[test]
+    fn test_concat_dyn_many() {
+        let foo = StringArray::from(vec![Some("f"), Some("o"), Some("o"), None]);
+        let bar = StringArray::from(vec![None, Some("b"), Some("a"), None]);
+        let baz = StringArray::from(vec![Some("b"), None, Some("a"), None]);
+
+        let output = concat_elements_dyn_many(&[&foo, &bar, &baz], None, false).unwrap();
+        let expected = StringArray::from(vec![None, None, Some("oaa"), None]);
+        assert_eq!(output.as_string::<i32>(), &expected);
+
+        let output =
+            concat_elements_dyn_many(&[&foo, &bar, &baz], Some(", "), false).unwrap();
+        let expected = StringArray::from(vec![None, None, Some("o, a, a"), None]);
+        assert_eq!(output.as_string::<i32>(), &expected);
+
+        let output =
+            concat_elements_dyn_many(&[&foo, &bar, &baz], Some("-"), true).unwrap();
+        let expected = StringArray::from(vec!["f-b", "o-b", "o-a-a", ""]);
+        assert_eq!(output.as_string::<i32>(), &expected);
+        assert_eq!(output.null_count(), 0);
+    }
+
+    #[test]
+    fn test_concat_dyn_many_types() {
+        let a = LargeBinaryArray::from(vec![b"a".as_ref(), b"b"]);
+        let b = LargeBinaryArray::from_opt_vec(vec![Some(b"c"), None]);
+        let output = concat_elements_dyn_many(&[&a, &b], Some("/"), false).unwrap();
+        let expected = LargeBinaryArray::from_opt_vec(vec![Some(b"a/c"), None]);
+        assert_eq!(output.as_binary::<i64>(), &expected);
+
+        let dict: DictionaryArray<Int32Type> =
+            vec![Some("x"), None, Some("y")].into_iter().collect();
+        let strings = StringArray::from(vec!["1", "2", "3"]);
+        let output = concat_elements_dyn_many(&[&dict, &strings], None, true).unwrap();
+        let expected = StringArray::from(vec!["x1", "2", "y3"]);
+        assert_eq!(output.as_string::<i32>(), &expected);
+
+        let sliced = strings.slice(1, 2);
+        let large = LargeStringArray::from(vec!["a", "b"]);
+        let err = concat_elements_dyn_many(&[sliced.as_ref(), &large], None, true)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Compute error: Arrays must have the same type, got Utf8 and LargeUtf8"
+        );
+
+        let sliced = concat_elements_dyn_many(&[sliced.as_ref()], None, false).unwrap();
+        assert_eq!(
+            sliced.as_string::<i32>(),
+            &StringArray::from(vec!["2", "3"])
+        );
+
+        let ints = Int32Array::from(vec![1]);
+        let err = concat_elements_dyn_many(&[&ints], None, true).unwrap_err();
+        assert!(matches!(err, ArrowError::NotYetImplemented(_)));
+    }
 }
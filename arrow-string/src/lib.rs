@@ -17,8 +17,56 @@
 
 //! Arrow string kernels
 
+pub mod case;
 pub mod concat_elements;
 pub mod length;
 pub mod like;
+pub mod pad;
 pub mod regexp;
 pub mod substring;
+pub mod trim;
+
+use arrow_array::cast::{as_largestring_array, as_string_array};
+use arrow_array::{
+    downcast_dictionary_array, Array, ArrayRef, GenericStringArray, OffsetSizeTrait,
+};
+use arrow_schema::{ArrowError, DataType};
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// A function from a string value to its transformed value
+type StringOp<'a> = &'a dyn for<'b> Fn(&'b str) -> Cow<'b, str>;
+
+/// Applies `op` to each value of a [`StringArray`](arrow_array::StringArray) or
+/// [`LargeStringArray`](arrow_array::LargeStringArray), or to the values of a
+/// [`DictionaryArray`](arrow_array::DictionaryArray) with values of either type,
+/// preserving the keys
+pub(crate) fn string_unary_dyn(
+    array: &dyn Array,
+    name: &str,
+    op: StringOp,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Utf8 => Ok(Arc::new(string_unary(as_string_array(array), op))),
+        DataType::LargeUtf8 => {
+            Ok(Arc::new(string_unary(as_largestring_array(array), op)))
+        }
+        DataType::Dictionary(_, _) => downcast_dictionary_array!(
+            array => {
+                let values = string_unary_dyn(array.values().as_ref(), name, op)?;
+                Ok(Arc::new(array.with_values(values.as_ref())))
+            }
+            t => unreachable!("unexpected dictionary type {}", t)
+        ),
+        t => Err(ArrowError::ComputeError(format!(
+            "{name} only supports Utf8, LargeUtf8 or DictionaryArray with Utf8 or LargeUtf8 values, got {t}"
+        ))),
+    }
+}
+
+fn string_unary<O: OffsetSizeTrait>(
+    array: &GenericStringArray<O>,
+    op: StringOp,
+) -> GenericStringArray<O> {
+    array.iter().map(|v| v.map(op)).collect()
+}
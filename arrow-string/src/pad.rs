@@ -0,0 +1,151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to pad string arrays to a given length
+
+use crate::string_unary_dyn;
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::ArrowError;
+use std::borrow::Cow;
+
+/// Pads each value of `array` on the left to `length` characters by repeating `fill`,
+/// which defaults to a single space. Values longer than `length` characters are
+/// truncated to their first `length` characters.
+///
+/// Supports [`StringArray`](arrow_array::StringArray),
+/// [`LargeStringArray`](arrow_array::LargeStringArray), and
+/// [`DictionaryArray`](arrow_array::DictionaryArray) with values of either type, in
+/// which case only the dictionary values are padded
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::as_string_array;
+/// # use arrow_string::pad::lpad;
+/// let array = StringArray::from(vec![Some("hi"), None, Some("hello")]);
+/// let result = lpad(&array, 5, Some("xy")).unwrap();
+/// let expected = StringArray::from(vec![Some("xyxhi"), None, Some("hello")]);
+/// assert_eq!(as_string_array(&result), &expected);
+/// ```
+pub fn lpad(
+    array: &dyn Array,
+    length: usize,
+    fill: Option<&str>,
+) -> Result<ArrayRef, ArrowError> {
+    let fill = fill.unwrap_or(" ");
+    string_unary_dyn(array, "lpad", &|v| pad(v, length, fill, true))
+}
+
+/// Pads each value of `array` on the right to `length` characters by repeating `fill`,
+/// which defaults to a single space. Values longer than `length` characters are
+/// truncated to their first `length` characters.
+///
+/// See [`lpad`] for the supported types
+pub fn rpad(
+    array: &dyn Array,
+    length: usize,
+    fill: Option<&str>,
+) -> Result<ArrayRef, ArrowError> {
+    let fill = fill.unwrap_or(" ");
+    string_unary_dyn(array, "rpad", &|v| pad(v, length, fill, false))
+}
+
+fn pad<'a>(value: &'a str, length: usize, fill: &str, left: bool) -> Cow<'a, str> {
+    let mut chars = value.char_indices();
+    let char_count = match chars.nth(length) {
+        Some((idx, _)) => return Cow::Borrowed(&value[..idx]),
+        None => value.chars().count(),
+    };
+    if char_count == length || fill.is_empty() {
+        return Cow::Borrowed(value);
+    }
+
+    let padding = fill.chars().cycle().take(length - char_count);
+    let mut padded = String::with_capacity(value.len() + (length - char_count) * 4);
+    match left {
+        true => padded.extend(padding.chain(value.chars())),
+        false => padded.extend(value.chars().chain(padding)),
+    }
+    Cow::Owned(padded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::*;
+    use arrow_array::types::Int8Type;
+    use arrow_array::{DictionaryArray, Int32Array, LargeStringArray, StringArray};
+
+    #[test]
+    fn test_pad() {
+        let array = LargeStringArray::from(vec![
+            Some("ab"),
+            None,
+            Some("äöü"),
+            Some("hello"),
+            Some(""),
+        ]);
+        let result = rpad(&array, 4, Some("€")).unwrap();
+        let expected = LargeStringArray::from(vec![
+            Some("ab€€"),
+            None,
+            Some("äöü€"),
+            Some("hell"),
+            Some("€€€€"),
+        ]);
+        assert_eq!(as_largestring_array(&result), &expected);
+
+        let result = lpad(&array, 3, None).unwrap();
+        let expected = LargeStringArray::from(vec![
+            Some(" ab"),
+            None,
+            Some("äöü"),
+            Some("hel"),
+            Some("   "),
+        ]);
+        assert_eq!(as_largestring_array(&result), &expected);
+
+        let result = lpad(&array, 4, Some("")).unwrap();
+        let expected = LargeStringArray::from(vec![
+            Some("ab"),
+            None,
+            Some("äöü"),
+            Some("hell"),
+            Some(""),
+        ]);
+        assert_eq!(as_largestring_array(&result), &expected);
+
+        let err = lpad(&Int32Array::from(vec![1]), 1, None).unwrap_err();
+        assert!(err.to_string().contains("lpad only supports Utf8"), "{err}");
+    }
+
+    #[test]
+    fn test_pad_dictionary() {
+        let array: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("bcd"), Some("a")]
+                .into_iter()
+                .collect();
+        let result = lpad(&array, 2, Some("-")).unwrap();
+        let result = as_dictionary_array::<Int8Type>(&result);
+        assert_eq!(result.keys(), array.keys());
+        let values: Vec<_> = result
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some("-a"), None, Some("bc"), Some("-a")]);
+    }
+}
@@ -157,6 +157,11 @@ pub fn regexp_is_match_utf8_scalar<OffsetSize: OffsetSizeTrait>(
 }
 
 /// Extract all groups matched by a regular expression for a given String array.
+///
+/// Returns a [`ListArray`] with the captured groups of each row, which is null if the
+/// value or pattern is null, or if the pattern does not match. Each pattern may be
+/// combined with the corresponding `flags_array` value, such as `i` for case-insensitive
+/// or `m` for multi-line matching.
 pub fn regexp_match<OffsetSize: OffsetSizeTrait>(
     array: &GenericStringArray<OffsetSize>,
     regex_array: &GenericStringArray<OffsetSize>,
@@ -225,10 +230,66 @@ pub fn regexp_match<OffsetSize: OffsetSizeTrait>(
     Ok(Arc::new(list_builder.finish()))
 }
 
+/// Extract all groups matched by a scalar regular expression for a given String array.
+///
+/// See the documentation on [`regexp_match`] for more details.
+///
+/// ```
+/// # use arrow_array::{Array, StringArray};
+/// # use arrow_array::cast::{as_list_array, AsArray};
+/// # use arrow_string::regexp::regexp_match_scalar;
+/// let array = StringArray::from(vec![Some("Foo-1"), Some("bar"), None]);
+/// let result = regexp_match_scalar(&array, r"(foo)-(\d)", Some("i")).unwrap();
+/// let result = as_list_array(&result);
+/// assert_eq!(result.value(0).as_string::<i32>(), &StringArray::from(vec!["Foo", "1"]));
+/// assert!(result.is_null(1));
+/// assert!(result.is_null(2));
+/// ```
+pub fn regexp_match_scalar<OffsetSize: OffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex: &str,
+    flag: Option<&str>,
+) -> Result<ArrayRef, ArrowError> {
+    let builder: GenericStringBuilder<OffsetSize> =
+        GenericStringBuilder::with_capacity(0, 0);
+    let mut list_builder = ListBuilder::with_capacity(builder, array.len());
+
+    let pattern = match flag {
+        Some(flag) => format!("(?{flag}){regex}"),
+        None => regex.to_string(),
+    };
+    if pattern.is_empty() {
+        // Required for Postgres compatibility, see regexp_match
+        for value in array.iter() {
+            if value.is_some() {
+                list_builder.values().append_value("");
+            }
+            list_builder.append(value.is_some());
+        }
+        return Ok(Arc::new(list_builder.finish()));
+    }
+
+    let re = Regex::new(pattern.as_str()).map_err(|e| {
+        ArrowError::ComputeError(format!("Regular expression did not compile: {e:?}"))
+    })?;
+    for value in array.iter() {
+        match value.and_then(|value| re.captures(value)) {
+            Some(caps) => {
+                for m in caps.iter().skip(1).flatten() {
+                    list_builder.values().append_value(m.as_str());
+                }
+                list_builder.append(true);
+            }
+            None => list_builder.append(false),
+        }
+    }
+    Ok(Arc::new(list_builder.finish()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow_array::{ListArray, StringArray};
+    use arrow_array::{LargeStringArray, ListArray, StringArray};
 
     #[test]
     fn match_single_group() {
@@ -282,6 +343,36 @@ mod tests {
         assert_eq!(&expected, result);
     }
 
+    #[test]
+    fn match_scalar() {
+        let array = LargeStringArray::from(vec![
+            Some("abc-005-def"),
+            Some("X-7-5"),
+            Some("X545"),
+            None,
+        ]);
+        let actual = regexp_match_scalar(&array, r"x.*-(\d*)-(.*)", Some("i")).unwrap();
+        let elem_builder: GenericStringBuilder<i64> = GenericStringBuilder::new();
+        let mut expected_builder = ListBuilder::new(elem_builder);
+        expected_builder.append(false);
+        expected_builder.values().append_value("7");
+        expected_builder.values().append_value("5");
+        expected_builder.append(true);
+        expected_builder.append(false);
+        expected_builder.append(false);
+        let expected = expected_builder.finish();
+        let result = actual.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(&expected, result);
+
+        let actual = regexp_match_scalar(&array, "", None).unwrap();
+        let result = actual.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(result.null_count(), 1);
+        assert_eq!(result.value_length(0), 1);
+
+        let err = regexp_match_scalar(&array, "(", None).unwrap_err();
+        assert!(err.to_string().contains("did not compile"), "{err}");
+    }
+
     macro_rules! test_flag_utf8 {
         ($test_name:ident, $left:expr, $right:expr, $op:expr, $expected:expr) => {
             #[test]
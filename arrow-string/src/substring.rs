@@ -222,6 +222,60 @@ pub fn substring_by_char<OffsetSize: OffsetSizeTrait>(
     Ok(GenericStringArray::<OffsetSize>::from(data))
 }
 
+/// Returns an [`ArrayRef`] with substrings of all the elements in `array`, with `start`
+/// and `length` counted by char as in [`substring_by_char`].
+///
+/// Supports [`GenericStringArray`] and [`DictionaryArray`] with a \[Large\]String value
+/// type, in which case only the dictionary values are sliced.
+///
+/// ```
+/// # use arrow_array::{DictionaryArray, StringArray};
+/// # use arrow_array::cast::as_string_array;
+/// # use arrow_array::types::Int32Type;
+/// # use arrow_string::substring::substring_by_char_dyn;
+/// let array: DictionaryArray<Int32Type> =
+///     vec![Some("E=mc²"), None, Some("E=mc²"), Some("π")].into_iter().collect();
+/// let result = substring_by_char_dyn(&array, -2, None).unwrap();
+/// let result = result.as_any().downcast_ref::<DictionaryArray<Int32Type>>().unwrap();
+/// assert_eq!(result.keys(), array.keys());
+/// assert_eq!(as_string_array(result.values()), &StringArray::from(vec!["c²", "π"]));
+/// ```
+pub fn substring_by_char_dyn(
+    array: &dyn Array,
+    start: i64,
+    length: Option<u64>,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Dictionary(_, _) => downcast_dictionary_array!(
+            array => {
+                let values = substring_by_char_dyn(array.values().as_ref(), start, length)?;
+                Ok(Arc::new(DictionaryArray::try_new(array.keys(), &values)?))
+            }
+            t => unreachable!("unexpected dictionary type {}", t)
+        ),
+        DataType::Utf8 => Ok(Arc::new(substring_by_char(
+            array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("A string is expected"),
+            start,
+            length,
+        )?)),
+        DataType::LargeUtf8 => Ok(Arc::new(substring_by_char(
+            array
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .expect("A large string is expected"),
+            start,
+            length,
+        )?)),
+        _ => Err(ArrowError::ComputeError(format!(
+            "substring_by_char does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
 /// * `val` - string
 /// * `start` - the start char index of the substring
 /// * `length` - the char length of the substring
@@ -964,6 +1018,35 @@ mod tests {
         generic_string_by_char_with_non_zero_offset::<i64>()
     }
 
+    #[test]
+    fn by_char_dyn() {
+        let array = LargeStringArray::from(vec![Some("Γ ⊢x:T"), None, Some("ab")]);
+        let result = substring_by_char_dyn(&array, -3, Some(2)).unwrap();
+        let result = result.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        let expected = LargeStringArray::from(vec![Some("x:"), None, Some("ab")]);
+        assert_eq!(result, &expected);
+
+        let dict: DictionaryArray<Int8Type> =
+            vec![Some("→T"), Some("S→T"), None, Some("→T")]
+                .into_iter()
+                .collect();
+        let result = substring_by_char_dyn(&dict, 1, Some(1)).unwrap();
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        let values = result.downcast_dict::<StringArray>().unwrap();
+        let actual: Vec<_> = values.into_iter().collect();
+        assert_eq!(actual, vec![Some("T"), Some("→"), None, Some("T")]);
+
+        let err = substring_by_char_dyn(&BinaryArray::from(vec![b"a".as_ref()]), 0, None)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("does not support type Binary"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn dictionary() {
         _dictionary::<Int8Type>();
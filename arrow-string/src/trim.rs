@@ -0,0 +1,145 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to trim characters from the start and end of string arrays
+
+use crate::string_unary_dyn;
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::ArrowError;
+use std::borrow::Cow;
+
+/// Removes the longest prefix and suffix of each value of `array` consisting only of
+/// characters in `characters`, or of whitespace if `characters` is `None`
+///
+/// Supports [`StringArray`](arrow_array::StringArray),
+/// [`LargeStringArray`](arrow_array::LargeStringArray), and
+/// [`DictionaryArray`](arrow_array::DictionaryArray) with values of either type, in
+/// which case only the dictionary values are trimmed
+///
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_array::cast::as_string_array;
+/// # use arrow_string::trim::trim;
+/// let array = StringArray::from(vec![Some("  arrow "), None, Some("xxrustyx")]);
+/// let result = trim(&array, None).unwrap();
+/// let expected = StringArray::from(vec![Some("arrow"), None, Some("xxrustyx")]);
+/// assert_eq!(as_string_array(&result), &expected);
+///
+/// let result = trim(&array, Some("xy ")).unwrap();
+/// let expected = StringArray::from(vec![Some("arrow"), None, Some("rust")]);
+/// assert_eq!(as_string_array(&result), &expected);
+/// ```
+pub fn trim(array: &dyn Array, characters: Option<&str>) -> Result<ArrayRef, ArrowError> {
+    trim_impl(array, "trim", characters, true, true)
+}
+
+/// Removes the longest prefix of each value of `array` consisting only of characters
+/// in `characters`, or of whitespace if `characters` is `None`
+///
+/// See [`trim`] for the supported types
+pub fn ltrim(
+    array: &dyn Array,
+    characters: Option<&str>,
+) -> Result<ArrayRef, ArrowError> {
+    trim_impl(array, "ltrim", characters, true, false)
+}
+
+/// Removes the longest suffix of each value of `array` consisting only of characters
+/// in `characters`, or of whitespace if `characters` is `None`
+///
+/// See [`trim`] for the supported types
+pub fn rtrim(
+    array: &dyn Array,
+    characters: Option<&str>,
+) -> Result<ArrayRef, ArrowError> {
+    trim_impl(array, "rtrim", characters, false, true)
+}
+
+fn trim_impl(
+    array: &dyn Array,
+    name: &str,
+    characters: Option<&str>,
+    start: bool,
+    end: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let matches = |c: char| match characters {
+        Some(characters) => characters.contains(c),
+        None => c.is_whitespace(),
+    };
+    string_unary_dyn(array, name, &|v| {
+        let v = if start {
+            v.trim_start_matches(matches)
+        } else {
+            v
+        };
+        let v = if end { v.trim_end_matches(matches) } else { v };
+        Cow::Borrowed(v)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::*;
+    use arrow_array::types::Int16Type;
+    use arrow_array::{DictionaryArray, LargeStringArray, StringArray};
+
+    #[test]
+    fn test_trim() {
+        let array =
+            LargeStringArray::from(vec![Some("\t a b \n"), None, Some("--a-"), Some("")]);
+
+        let result = ltrim(&array, None).unwrap();
+        let expected =
+            LargeStringArray::from(vec![Some("a b \n"), None, Some("--a-"), Some("")]);
+        assert_eq!(as_largestring_array(&result), &expected);
+
+        let result = rtrim(&array, None).unwrap();
+        let expected =
+            LargeStringArray::from(vec![Some("\t a b"), None, Some("--a-"), Some("")]);
+        assert_eq!(as_largestring_array(&result), &expected);
+
+        let result = ltrim(&array, Some("-")).unwrap();
+        let expected =
+            LargeStringArray::from(vec![Some("\t a b \n"), None, Some("a-"), Some("")]);
+        assert_eq!(as_largestring_array(&result), &expected);
+
+        let result = trim(&array, Some("-\t\n ab")).unwrap();
+        let expected = LargeStringArray::from(vec![Some(""), None, Some(""), Some("")]);
+        assert_eq!(as_largestring_array(&result), &expected);
+
+        let result = trim(&array, Some("")).unwrap();
+        assert_eq!(as_largestring_array(&result), &array);
+    }
+
+    #[test]
+    fn test_trim_dictionary() {
+        let array: DictionaryArray<Int16Type> =
+            vec![Some("ö a ö"), None, Some(" a"), Some("ö a ö")]
+                .into_iter()
+                .collect();
+        let result = trim(&array, Some("ö ")).unwrap();
+        let result = as_dictionary_array::<Int16Type>(&result);
+        assert_eq!(result.keys(), array.keys());
+        let values: Vec<_> = result
+            .downcast_dict::<StringArray>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some("a"), None, Some("a"), Some("a")]);
+    }
+}
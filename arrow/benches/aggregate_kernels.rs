@@ -23,12 +23,19 @@ extern crate arrow;
 
 use arrow::compute::kernels::aggregate::*;
 use arrow::util::bench_util::*;
-use arrow::{array::*, datatypes::Float32Type};
+use arrow::{
+    array::*,
+    datatypes::{Float32Type, Int32Type},
+};
 
 fn bench_sum(arr_a: &Float32Array) {
     criterion::black_box(sum(arr_a).unwrap());
 }
 
+fn bench_sum_int32(arr_a: &Int32Array) {
+    criterion::black_box(sum(arr_a).unwrap());
+}
+
 fn bench_min(arr_a: &Float32Array) {
     criterion::black_box(min(arr_a).unwrap());
 }
@@ -54,6 +61,12 @@ fn add_benchmark(c: &mut Criterion) {
     c.bench_function("min nulls 512", |b| b.iter(|| bench_min(&arr_a)));
     c.bench_function("max nulls 512", |b| b.iter(|| bench_max(&arr_a)));
 
+    let arr_a = create_primitive_array::<Int32Type>(512, 0.5);
+
+    c.bench_function("sum nulls int32 512", |b| {
+        b.iter(|| bench_sum_int32(&arr_a))
+    });
+
     let arr_b = create_string_array::<i32>(512, 0.0);
     c.bench_function("min string 512", |b| b.iter(|| bench_min_string(&arr_b)));
 
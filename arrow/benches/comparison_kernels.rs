@@ -21,6 +21,7 @@ use criterion::Criterion;
 
 extern crate arrow;
 
+use arrow::compute::kernels::cmp;
 use arrow::compute::*;
 use arrow::datatypes::{ArrowNativeTypeOp, ArrowNumericType, IntervalMonthDayNanoType};
 use arrow::util::bench_util::*;
@@ -322,14 +323,14 @@ fn add_benchmark(c: &mut Criterion) {
         b.iter(|| bench_dict_eq(&dict_arr_a, &dict_arr_b))
     });
 
-    c.bench_function("eq_dyn_utf8_scalar dictionary[10] string[4])", |b| {
-        b.iter(|| eq_dyn_utf8_scalar(&dict_arr_a, "test"))
+    let scalar = Scalar::new(StringArray::from(vec!["test"]));
+    c.bench_function("eq scalar dictionary[10] string[4])", |b| {
+        b.iter(|| cmp::eq(&dict_arr_a, &scalar))
     });
 
-    c.bench_function(
-        "gt_eq_dyn_utf8_scalar scalar dictionary[10] string[4])",
-        |b| b.iter(|| gt_eq_dyn_utf8_scalar(&dict_arr_a, "test")),
-    );
+    c.bench_function("gt_eq scalar dictionary[10] string[4])", |b| {
+        b.iter(|| cmp::gt_eq(&dict_arr_a, &scalar))
+    });
 
     c.bench_function("like_utf8_scalar_dyn dictionary[10] string[4])", |b| {
         b.iter(|| like_utf8_scalar_dyn(&dict_arr_a, "test"))
@@ -22,8 +22,9 @@ use arrow::util::bench_util::{
     create_primitive_array, create_string_array, create_string_array_with_len,
 };
 use arrow_array::RecordBatch;
+use arrow_json::reader::DecoderOptions;
 use arrow_json::RawReaderBuilder;
-use arrow_json::{LineDelimitedWriter, ReaderBuilder};
+use arrow_json::{LineDelimitedWriter, Reader};
 use std::io::Cursor;
 use std::sync::Arc;
 
@@ -31,11 +32,8 @@ fn do_bench(c: &mut Criterion, name: &str, json: &str, schema: SchemaRef) {
     c.bench_function(&format!("{name} (basic)"), |b| {
         b.iter(|| {
             let cursor = Cursor::new(black_box(json));
-            let builder = ReaderBuilder::new()
-                .with_schema(schema.clone())
-                .with_batch_size(64);
-
-            let mut reader = builder.build(cursor).unwrap();
+            let options = DecoderOptions::new().with_batch_size(64);
+            let mut reader = Reader::new(cursor, schema.clone(), options);
             while let Some(next) = reader.next().transpose() {
                 next.unwrap();
             }
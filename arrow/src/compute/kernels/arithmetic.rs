@@ -0,0 +1,225 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines basic arithmetic kernels for `PrimitiveArrays`
+//!
+//! In addition to the kernels of [`arrow_arith::arithmetic`], this provides the
+//! `*_dyn_coerced` kernels, that cast their inputs to a common type before applying
+//! the operation
+
+pub use arrow_arith::arithmetic::*;
+
+use crate::array::*;
+use crate::compute::kernels::cast::{cast_with_options, CastOptions};
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use std::sync::Arc;
+
+/// The operation applied by [`coerced_op`]
+#[derive(Debug, Copy, Clone)]
+enum CoercedOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+/// Casts `array` to `to_type`, returning an error if a value cannot be represented
+/// in `to_type` instead of replacing it with null
+fn coerce_array(array: &dyn Array, to_type: &DataType) -> Result<ArrayRef> {
+    let array = make_array(array.data().clone());
+    if array.data_type() == to_type {
+        return Ok(array);
+    }
+    let options = CastOptions::default().with_safe(false);
+    cast_with_options(&array, to_type, &options)
+}
+
+/// Casts `left` and `right` to the types returned by [`arithmetic_coercion`] and
+/// applies `op`, returning an error on overflow or if a value cannot be represented
+/// in the coerced type
+fn coerced_op(op: CoercedOp, left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    let (l_type, r_type) = arithmetic_coercion(left.data_type(), right.data_type())
+        .ok_or_else(|| {
+            ArrowError::CastError(format!(
+                "Cannot coerce arithmetic operation between array of type {} and array of type {}",
+                left.data_type(),
+                right.data_type()
+            ))
+        })?;
+    let left = coerce_array(left, &l_type)?;
+    let right = coerce_array(right, &r_type)?;
+
+    match (&l_type, &r_type) {
+        (DataType::Decimal128(_, _), DataType::Decimal128(_, _)) => {
+            decimal_op::<Decimal128Type>(op, &left, &right)
+        }
+        (DataType::Decimal256(_, _), DataType::Decimal256(_, _)) => {
+            decimal_op::<Decimal256Type>(op, &left, &right)
+        }
+        _ => match op {
+            CoercedOp::Add => add_dyn_checked(&left, &right),
+            CoercedOp::Subtract => subtract_dyn_checked(&left, &right),
+            CoercedOp::Multiply => multiply_dyn_checked(&left, &right),
+            CoercedOp::Divide => divide_dyn_checked(&left, &right),
+        },
+    }
+}
+
+fn decimal_op<T>(op: CoercedOp, left: &dyn Array, right: &dyn Array) -> Result<ArrayRef>
+where
+    T: DecimalType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let (left, right) = (left.as_primitive::<T>(), right.as_primitive::<T>());
+    let result = match op {
+        CoercedOp::Add => add_decimal_checked(left, right),
+        CoercedOp::Subtract => subtract_decimal_checked(left, right),
+        CoercedOp::Multiply => multiply_decimal_checked(left, right),
+        CoercedOp::Divide => divide_decimal_checked(left, right),
+    }?;
+    Ok(Arc::new(result))
+}
+
+/// Perform `left + right` operation on two arrays of possibly different numeric types,
+/// after promoting them to a common type as described in [`arithmetic_coercion`]. If
+/// either left or right value is null then the result is also null.
+///
+/// This detects overflow and returns an `Err` for that.
+///
+/// ```
+/// # use arrow::array::{AsArray, Int32Array, Int64Array};
+/// # use arrow::datatypes::Int64Type;
+/// # use arrow::compute::kernels::arithmetic::add_dyn_coerced;
+/// let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+/// let b = Int64Array::from(vec![Some(i64::MAX - 1), Some(2), Some(3)]);
+/// let result = add_dyn_coerced(&a, &b).unwrap();
+/// let expected = Int64Array::from(vec![Some(i64::MAX), None, Some(6)]);
+/// assert_eq!(result.as_primitive::<Int64Type>(), &expected);
+/// ```
+pub fn add_dyn_coerced(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    coerced_op(CoercedOp::Add, left, right)
+}
+
+/// Perform `left - right` operation on two arrays of possibly different numeric types,
+/// after promoting them to a common type as described in [`arithmetic_coercion`]. If
+/// either left or right value is null then the result is also null.
+///
+/// This detects overflow and returns an `Err` for that.
+pub fn subtract_dyn_coerced(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    coerced_op(CoercedOp::Subtract, left, right)
+}
+
+/// Perform `left * right` operation on two arrays of possibly different numeric types,
+/// after promoting them to a common type as described in [`arithmetic_coercion`]. If
+/// either left or right value is null then the result is also null.
+///
+/// This detects overflow and returns an `Err` for that.
+pub fn multiply_dyn_coerced(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    coerced_op(CoercedOp::Multiply, left, right)
+}
+
+/// Perform `left / right` operation on two arrays of possibly different numeric types,
+/// after promoting them to a common type as described in [`arithmetic_coercion`]. If
+/// either left or right value is null then the result is also null. If any right hand
+/// value is zero then the result of this operation will be `Err(ArrowError::DivideByZero)`.
+///
+/// This detects overflow and returns an `Err` for that.
+pub fn divide_dyn_coerced(left: &dyn Array, right: &dyn Array) -> Result<ArrayRef> {
+    coerced_op(CoercedOp::Divide, left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_buffer::i256;
+
+    #[test]
+    fn test_dyn_coerced() {
+        let a = UInt8Array::from(vec![Some(200), None, Some(3)]);
+        let b = Int8Array::from(vec![Some(100), Some(1), Some(-4)]);
+        let r = add_dyn_coerced(&a, &b).unwrap();
+        let expected = Int16Array::from(vec![Some(300), None, Some(-1)]);
+        assert_eq!(r.as_primitive::<Int16Type>(), &expected);
+
+        let r = subtract_dyn_coerced(&b, &a).unwrap();
+        let expected = Int16Array::from(vec![Some(-100), None, Some(-7)]);
+        assert_eq!(r.as_primitive::<Int16Type>(), &expected);
+
+        let c = Float32Array::from(vec![0.5, 1.0, 2.0]);
+        let r = multiply_dyn_coerced(&a, &c).unwrap();
+        let expected = Float64Array::from(vec![Some(100.0), None, Some(6.0)]);
+        assert_eq!(r.as_primitive::<Float64Type>(), &expected);
+
+        let err = divide_dyn_coerced(&b, &Int32Array::from(vec![1, 0, 1])).unwrap_err();
+        assert!(matches!(err, ArrowError::DivideByZero));
+
+        let err = add_dyn_coerced(&a, &StringArray::from(vec!["a", "b", "c"]))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Cannot coerce"), "{err}");
+
+        // UInt64 values that do not fit in Int64 must error rather than become null
+        let a = UInt64Array::from(vec![u64::MAX, 1]);
+        let b = Int64Array::from(vec![0, 1]);
+        let err = add_dyn_coerced(&a, &b).unwrap_err().to_string();
+        assert!(err.contains("Can't cast value"), "{err}");
+
+        let a = UInt64Array::from(vec![i64::MAX as u64, 1]);
+        let r = add_dyn_coerced(&a, &b).unwrap();
+        let expected = Int64Array::from(vec![i64::MAX, 2]);
+        assert_eq!(r.as_primitive::<Int64Type>(), &expected);
+    }
+
+    #[test]
+    fn test_dyn_coerced_decimal() {
+        let a = Decimal128Array::from(vec![Some(1025), None, Some(-50)])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let b = Decimal128Array::from(vec![Some(12345), Some(1), Some(5)])
+            .with_precision_and_scale(12, 4)
+            .unwrap();
+        let r = add_dyn_coerced(&a, &b).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(13, 4));
+        let expected = Decimal128Array::from(vec![Some(114845), None, Some(-4995)])
+            .with_precision_and_scale(13, 4)
+            .unwrap();
+        assert_eq!(r.as_primitive::<Decimal128Type>(), &expected);
+
+        let c = Int32Array::from(vec![2, 3, 4]);
+        let r = multiply_dyn_coerced(&c, &a).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal128(21, 2));
+        let expected = Decimal128Array::from(vec![Some(2050), None, Some(-200)])
+            .with_precision_and_scale(21, 2)
+            .unwrap();
+        assert_eq!(r.as_primitive::<Decimal128Type>(), &expected);
+
+        let d = Decimal256Array::from(vec![i256::from_i128(1); 3])
+            .with_precision_and_scale(20, 0)
+            .unwrap();
+        let r = subtract_dyn_coerced(&a, &d).unwrap();
+        assert_eq!(r.data_type(), &DataType::Decimal256(23, 2));
+        let expected = Decimal256Array::from(vec![
+            Some(i256::from_i128(925)),
+            None,
+            Some(i256::from_i128(-150)),
+        ])
+        .with_precision_and_scale(23, 2)
+        .unwrap();
+        assert_eq!(r.as_primitive::<Decimal256Type>(), &expected);
+    }
+}
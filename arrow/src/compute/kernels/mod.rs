@@ -17,14 +17,15 @@
 
 //! Computation kernels on Arrow Arrays
 
+pub mod arithmetic;
 pub mod limit;
 
-pub use arrow_arith::{aggregate, arithmetic, arity, bitwise, boolean, temporal};
+pub use arrow_arith::{aggregate, arity, bitwise, boolean, hash, numeric, temporal};
 pub use arrow_cast::cast;
 pub use arrow_cast::parse as cast_utils;
-pub use arrow_ord::{partition, sort};
-pub use arrow_select::{concat, filter, interleave, nullif, take, window, zip};
-pub use arrow_string::{concat_elements, length, regexp, substring};
+pub use arrow_ord::{cmp, partition, rank, run_end, sort};
+pub use arrow_select::{coalesce, concat, filter, interleave, nullif, take, window, zip};
+pub use arrow_string::{case, concat_elements, length, pad, regexp, substring, trim};
 
 /// Comparison kernels for `Array`s.
 pub mod comparison {
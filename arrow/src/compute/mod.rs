@@ -32,6 +32,7 @@ pub use self::kernels::limit::*;
 pub use self::kernels::nullif::*;
 pub use self::kernels::partition::*;
 pub use self::kernels::regexp::*;
+pub use self::kernels::run_end::*;
 pub use self::kernels::sort::*;
 pub use self::kernels::take::*;
 pub use self::kernels::temporal::*;
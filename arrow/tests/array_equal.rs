@@ -19,7 +19,8 @@ use arrow::array::{
     make_array, Array, ArrayRef, BooleanArray, Decimal128Array, FixedSizeBinaryArray,
     FixedSizeBinaryBuilder, FixedSizeListBuilder, GenericBinaryArray, GenericStringArray,
     Int32Array, Int32Builder, Int64Builder, ListArray, ListBuilder, NullArray,
-    OffsetSizeTrait, StringArray, StringDictionaryBuilder, StructArray, UnionBuilder,
+    OffsetSizeTrait, RunArray, StringArray, StringDictionaryBuilder, StructArray,
+    UnionBuilder,
 };
 use arrow::datatypes::{Int16Type, Int32Type};
 use arrow_array::builder::{StringBuilder, StructBuilder};
@@ -1083,6 +1084,43 @@ fn test_dictionary_equal_null() {
     test_equal(&a, &b, false);
 }
 
+fn create_run_array(run_ends: &[i32], values: &[Option<&str>]) -> ArrayData {
+    let run_ends = Int32Array::from(run_ends.to_vec());
+    let values = StringArray::from(values.to_vec());
+    RunArray::<Int32Type>::try_new(&run_ends, &values)
+        .unwrap()
+        .into_data()
+}
+
+#[test]
+fn test_run_equal() {
+    // (a, a, b, NULL, c)
+    let a = create_run_array(&[2, 3, 4, 5], &[Some("a"), Some("b"), None, Some("c")]);
+    // different run boundaries, same logical values
+    let b = create_run_array(
+        &[1, 2, 3, 4, 5],
+        &[Some("a"), Some("a"), Some("b"), None, Some("c")],
+    );
+    test_equal(&a, &b, true);
+
+    // different len
+    let b = create_run_array(&[2, 3, 4], &[Some("a"), Some("b"), None]);
+    test_equal(&a, &b, false);
+
+    // different values
+    let b = create_run_array(&[2, 3, 4, 5], &[Some("a"), Some("b"), None, Some("d")]);
+    test_equal(&a, &b, false);
+
+    // different nulls
+    let b = create_run_array(&[2, 3, 5], &[Some("a"), Some("b"), Some("c")]);
+    test_equal(&a, &b, false);
+
+    // slices with different offsets
+    let b = create_run_array(&[3, 4, 5, 6], &[Some("a"), Some("b"), None, Some("c")]);
+    test_equal(&a.slice(1, 4), &b.slice(2, 4), true);
+    test_equal(&a.slice(1, 4), &b.slice(1, 4), false);
+}
+
 #[test]
 fn test_non_null_empty_strings() {
     let s = StringArray::from(vec![Some(""), Some(""), Some("")]);
@@ -107,3 +107,42 @@ fn test_export_csv_timestamps_using_rfc3339() {
     let right = String::from_utf8(sw).unwrap();
     assert_eq!(left, right);
 }
+
+#[test]
+fn test_read_csv_default_timezone_dst_gap() {
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Timestamp(TimeUnit::Second, None),
+        true,
+    )]));
+    let reader = |csv: &'static str| {
+        arrow_csv::ReaderBuilder::new()
+            .with_schema(schema.clone())
+            .with_default_timezone("America/New_York".parse().unwrap())
+            .build(std::io::Cursor::new(csv))
+            .unwrap()
+    };
+
+    // clocks moved forward from 02:00 to 03:00 on 2023-03-12
+    let batch = reader("2023-03-12 01:30:00\n2023-03-12 03:30:00\n")
+        .next()
+        .unwrap()
+        .unwrap();
+    let a = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<TimestampSecondArray>()
+        .unwrap();
+    assert_eq!(a.values(), &[1678602600, 1678606200]);
+
+    let err = reader("2023-03-12 02:30:00\n")
+        .next()
+        .unwrap()
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        err,
+        "Parser error: Error while parsing value 2023-03-12 02:30:00 for column 0 at line 0: \
+         2023-03-12 02:30:00 does not exist in the timezone"
+    );
+}
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use arrow::array::{Int32Array, Scalar};
+use arrow::compute::kernels::cmp::eq;
 use arrow::util::pretty::print_batches;
 use futures::TryStreamExt;
 use parquet::arrow::arrow_reader::{ArrowPredicateFn, RowFilter};
@@ -46,7 +48,10 @@ async fn main() -> Result<()> {
     // For more specific usage: please refer to https://github.com/apache/arrow-datafusion/blob/master/datafusion/core/src/physical_plan/file_format/parquet/row_filter.rs.
     let filter = ArrowPredicateFn::new(
         ProjectionMask::roots(file_metadata.schema_descr(), [0]),
-        |record_batch| arrow::compute::eq_dyn_scalar(record_batch.column(0), 1),
+        |record_batch| {
+            let scalar = Scalar::new(Int32Array::from(vec![1]));
+            eq(record_batch.column(0), &scalar)
+        },
     );
     let row_filter = RowFilter::new(vec![Box::new(filter)]);
     builder = builder.with_row_filter(row_filter);
@@ -1138,10 +1138,9 @@ mod tests {
             false,
         );
         let schema = Arc::new(Schema::new(vec![stocks_field]));
-        let builder = arrow::json::ReaderBuilder::new()
-            .with_schema(schema)
-            .with_batch_size(64);
-        let mut reader = builder.build(std::io::Cursor::new(json_content)).unwrap();
+        let options = arrow::json::reader::DecoderOptions::new().with_batch_size(64);
+        let mut reader =
+            arrow::json::Reader::new(std::io::Cursor::new(json_content), schema, options);
 
         let batch = reader.next().unwrap().unwrap();
 
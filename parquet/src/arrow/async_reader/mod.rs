@@ -825,8 +825,9 @@ mod tests {
     use crate::file::footer::parse_metadata;
     use crate::file::page_index::index_reader;
     use crate::file::properties::WriterProperties;
+    use arrow::compute::kernels::cmp::eq;
     use arrow::error::Result as ArrowResult;
-    use arrow_array::{Array, ArrayRef, Int32Array, StringArray};
+    use arrow_array::{Array, ArrayRef, BooleanArray, Int32Array, Scalar, StringArray};
     use futures::TryStreamExt;
     use rand::{thread_rng, Rng};
     use std::sync::Mutex;
@@ -1251,12 +1252,12 @@ mod tests {
 
         let a_filter = ArrowPredicateFn::new(
             ProjectionMask::leaves(&parquet_schema, vec![0]),
-            |batch| arrow::compute::eq_dyn_utf8_scalar(batch.column(0), "b"),
+            |batch| eq(batch.column(0), &Scalar::new(StringArray::from(vec!["b"]))),
         );
 
         let b_filter = ArrowPredicateFn::new(
             ProjectionMask::leaves(&parquet_schema, vec![1]),
-            |batch| arrow::compute::eq_dyn_utf8_scalar(batch.column(0), "4"),
+            |batch| eq(batch.column(0), &Scalar::new(StringArray::from(vec!["4"]))),
         );
 
         let filter = RowFilter::new(vec![Box::new(a_filter), Box::new(b_filter)]);
@@ -1365,12 +1366,17 @@ mod tests {
 
         let a_filter = ArrowPredicateFn::new(
             ProjectionMask::leaves(&parquet_schema, vec![1]),
-            |batch| arrow::compute::eq_dyn_bool_scalar(batch.column(0), true),
+            |batch| {
+                eq(
+                    batch.column(0),
+                    &Scalar::new(BooleanArray::from(vec![true])),
+                )
+            },
         );
 
         let b_filter = ArrowPredicateFn::new(
             ProjectionMask::leaves(&parquet_schema, vec![2]),
-            |batch| arrow::compute::eq_dyn_scalar(batch.column(0), 2_i32),
+            |batch| eq(batch.column(0), &Scalar::new(Int32Array::from(vec![2]))),
         );
 
         let filter = RowFilter::new(vec![Box::new(a_filter), Box::new(b_filter)]);
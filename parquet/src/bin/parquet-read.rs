@@ -91,7 +91,7 @@ fn main() {
 
     while all_records || start < end {
         match iter.next() {
-            Some(row) => print_row(&row, json),
+            Some(row) => print_row(&row.expect("Failed to read row"), json),
             None => break,
         }
         start += 1;
@@ -70,6 +70,20 @@ impl Int96 {
             .wrapping_add(nanoseconds)
     }
 
+    /// Converts this INT96 into an i64 representing the number of NANOSECONDS since EPOCH
+    ///
+    /// Returns an error if the value is outside the range representable by an `i64`
+    /// of nanoseconds, roughly the years 1677 to 2262
+    pub fn try_to_nanos(&self) -> Result<i64> {
+        let (seconds, nanoseconds) = self.to_seconds_and_nanos();
+        seconds
+            .checked_mul(1_000_000_000)
+            .and_then(|nanos| nanos.checked_add(nanoseconds))
+            .ok_or_else(|| {
+                general_err!("INT96 value {} cannot be represented as nanoseconds", self)
+            })
+    }
+
     /// Converts this INT96 to a number of seconds and nanoseconds since EPOCH
     pub fn to_seconds_and_nanos(&self) -> (i64, i64) {
         const JULIAN_DAY_OF_EPOCH: i64 = 2_440_588;
@@ -405,6 +419,27 @@ impl Decimal {
             Decimal::Bytes { scale, .. } => scale,
         }
     }
+
+    /// Returns the unscaled value as an `i128`.
+    ///
+    /// Returns an error if the unscaled value does not fit into 16 bytes.
+    pub fn to_i128(&self) -> Result<i128> {
+        let data = self.data();
+        if data.len() > 16 {
+            return Err(general_err!(
+                "Decimal of {} bytes cannot be represented as i128",
+                data.len()
+            ));
+        }
+        // Sign extend the big-endian two's complement representation
+        let fill = match data.first() {
+            Some(b) if *b & 0x80 != 0 => 0xFF,
+            _ => 0,
+        };
+        let mut bytes = [fill; 16];
+        bytes[16 - data.len()..].copy_from_slice(data);
+        Ok(i128::from_be_bytes(bytes))
+    }
 }
 
 impl Default for Decimal {
@@ -421,6 +456,70 @@ impl PartialEq for Decimal {
     }
 }
 
+/// Rust representation for the INTERVAL logical type.
+///
+/// INTERVAL is stored as a FIXED_LEN_BYTE_ARRAY of length 12 containing three
+/// little-endian unsigned integers: the number of months, days and milliseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Interval {
+    months: u32,
+    days: u32,
+    millis: u32,
+}
+
+impl Interval {
+    /// Creates new interval from the number of months, days and milliseconds.
+    pub fn new(months: u32, days: u32, millis: u32) -> Self {
+        Self {
+            months,
+            days,
+            millis,
+        }
+    }
+
+    /// Creates new interval from its 12 byte little-endian encoding.
+    pub fn from_bytes(value: &[u8]) -> Result<Self> {
+        if value.len() != 12 {
+            return Err(general_err!(
+                "Expected 12 bytes for INTERVAL, got {}",
+                value.len()
+            ));
+        }
+        let read = |i: usize| u32::from_le_bytes(value[i..i + 4].try_into().unwrap());
+        Ok(Self::new(read(0), read(4), read(8)))
+    }
+
+    /// Returns the number of months.
+    pub fn months(&self) -> u32 {
+        self.months
+    }
+
+    /// Returns the number of days.
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// Returns the number of milliseconds.
+    pub fn millis(&self) -> u32 {
+        self.millis
+    }
+
+    /// Returns the number of milliseconds converted to nanoseconds.
+    pub fn nanoseconds(&self) -> i64 {
+        self.millis as i64 * 1_000_000
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} months {} days {} millis",
+            self.months, self.days, self.millis
+        )
+    }
+}
+
 /// Converts an instance of data type to a slice of bytes as `u8`.
 pub trait AsBytes {
     /// Returns slice of bytes for this data type.
@@ -1264,6 +1363,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_int96_try_to_nanos() {
+        let value = Int96::from(vec![4165425152, 13, 2454923]);
+        assert_eq!(value.try_to_nanos().unwrap(), 1238544060000000000);
+        assert_eq!(value.try_to_nanos().unwrap(), value.to_nanos());
+
+        // Julian day 0 is in 4713 BC
+        let value = Int96::from(vec![0, 0, 0]);
+        assert!(value.try_to_nanos().is_err());
+    }
+
     #[test]
     fn test_byte_array_from() {
         assert_eq!(
@@ -1295,6 +1405,41 @@ mod tests {
         assert!(Decimal::from_i64(222, 5, 2) != Decimal::from_i32(222, 5, 2));
     }
 
+    #[test]
+    fn test_decimal_to_i128() {
+        assert_eq!(Decimal::from_i32(-222, 5, 2).to_i128().unwrap(), -222);
+        assert_eq!(
+            Decimal::from_i64(i64::MAX, 18, 2).to_i128().unwrap(),
+            i64::MAX as i128
+        );
+        assert_eq!(
+            Decimal::from_bytes(ByteArray::from(vec![0xFF, 0x85]), 5, 2)
+                .to_i128()
+                .unwrap(),
+            -123
+        );
+        assert_eq!(
+            Decimal::from_bytes(ByteArray::from(vec![0x01, 0x00]), 5, 2)
+                .to_i128()
+                .unwrap(),
+            256
+        );
+        assert!(Decimal::from_bytes(ByteArray::from(vec![0; 17]), 40, 2)
+            .to_i128()
+            .is_err());
+    }
+
+    #[test]
+    fn test_interval_from_bytes() {
+        let bytes = [1, 0, 0, 0, 2, 0, 0, 0, 3, 1, 0, 0];
+        let interval = Interval::from_bytes(&bytes).unwrap();
+        assert_eq!(interval, Interval::new(1, 2, 259));
+        assert_eq!(interval.nanoseconds(), 259_000_000);
+        assert_eq!(interval.to_string(), "1 months 2 days 259 millis");
+
+        assert!(Interval::from_bytes(&bytes[..8]).is_err());
+    }
+
     #[test]
     fn test_byte_array_ord() {
         let ba1 = ByteArray::from(vec![1, 2, 3]);
@@ -15,17 +15,26 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use std::{io::Read, sync::Arc};
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom, Write},
+    sync::Arc,
+};
 
-use crate::format::{ColumnOrder as TColumnOrder, FileMetaData as TFileMetaData};
-use thrift::protocol::{TCompactInputProtocol, TSerializable};
+use bytes::Bytes;
+
+use crate::format::{
+    ColumnOrder as TColumnOrder, FileMetaData as TFileMetaData, SortingColumn,
+};
+use thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol, TSerializable};
 
 use crate::basic::ColumnOrder;
 
 use crate::errors::{ParquetError, Result};
+use crate::file::statistics::{self, Statistics};
 use crate::file::{metadata::*, reader::ChunkReader, FOOTER_SIZE, PARQUET_MAGIC};
 
-use crate::schema::types::{self, SchemaDescriptor};
+use crate::schema::types::{self, SchemaDescPtr, SchemaDescriptor};
 
 /// Layout of Parquet file
 /// +---------------------------+-----+---+
@@ -36,6 +45,13 @@ use crate::schema::types::{self, SchemaDescriptor};
 /// The reader first reads DEFAULT_FOOTER_SIZE bytes from the end of the file.
 /// If it is not enough according to the length indicated in the footer, it reads more bytes.
 pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaData> {
+    let (_, metadata) = read_metadata_bytes(chunk_reader)?;
+    decode_metadata(&metadata)
+}
+
+/// Reads the encoded metadata from the end of the file, returning it along with the
+/// offset in the file at which it starts
+fn read_metadata_bytes<R: ChunkReader>(chunk_reader: &R) -> Result<(u64, Bytes)> {
     // check file is large enough to hold footer
     let file_size = chunk_reader.len();
     if file_size < (FOOTER_SIZE as u64) {
@@ -61,18 +77,27 @@ pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaDat
         ));
     }
 
-    let metadata =
-        chunk_reader.get_bytes(file_size - footer_metadata_len as u64, metadata_len)?;
+    let metadata_offset = file_size - footer_metadata_len as u64;
+    let metadata = chunk_reader.get_bytes(metadata_offset, metadata_len)?;
 
-    decode_metadata(&metadata)
+    Ok((metadata_offset, metadata))
 }
 
 /// Decodes [`ParquetMetaData`] from the provided bytes
 pub fn decode_metadata(metadata_read: &[u8]) -> Result<ParquetMetaData> {
     // TODO: row group filtering
+    from_thrift_metadata(decode_thrift_metadata(metadata_read)?)
+}
+
+/// Decodes the thrift [`TFileMetaData`] from the provided bytes
+fn decode_thrift_metadata(metadata_read: &[u8]) -> Result<TFileMetaData> {
     let mut prot = TCompactInputProtocol::new(metadata_read);
-    let t_file_metadata: TFileMetaData = TFileMetaData::read_from_in_protocol(&mut prot)
-        .map_err(|e| ParquetError::General(format!("Could not parse metadata: {e}")))?;
+    TFileMetaData::read_from_in_protocol(&mut prot)
+        .map_err(|e| ParquetError::General(format!("Could not parse metadata: {e}")))
+}
+
+/// Converts the thrift [`TFileMetaData`] into [`ParquetMetaData`]
+fn from_thrift_metadata(t_file_metadata: TFileMetaData) -> Result<ParquetMetaData> {
     let schema = types::from_thrift(&t_file_metadata.schema)?;
     let schema_descr = Arc::new(SchemaDescriptor::new(schema));
     let mut row_groups = Vec::new();
@@ -109,6 +134,195 @@ pub fn decode_footer(slice: &[u8; FOOTER_SIZE]) -> Result<usize> {
     })
 }
 
+/// Modifies the metadata of an existing parquet file without rewriting its data pages.
+///
+/// The footer is kept in its thrift representation, so any fields not exposed by
+/// [`ParquetMetaData`], such as page index offsets, are preserved when re-encoded.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::OpenOptions;
+/// use parquet::file::footer::FooterEditor;
+/// use parquet::file::metadata::KeyValue;
+///
+/// let mut file = OpenOptions::new()
+///     .read(true)
+///     .write(true)
+///     .open("/path/to/file")
+///     .unwrap();
+/// let mut editor = FooterEditor::try_new(&file).unwrap();
+/// editor.append_key_value_metadata(KeyValue::new("key".to_string(), "value".to_string()));
+/// editor.rewrite(&mut file).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct FooterEditor {
+    metadata: TFileMetaData,
+    schema_descr: SchemaDescPtr,
+    metadata_offset: u64,
+}
+
+impl FooterEditor {
+    /// Reads and decodes the footer of the file provided by `chunk_reader`
+    pub fn try_new<R: ChunkReader>(chunk_reader: &R) -> Result<Self> {
+        let (metadata_offset, metadata) = read_metadata_bytes(chunk_reader)?;
+        let metadata = decode_thrift_metadata(&metadata)?;
+        let schema = types::from_thrift(&metadata.schema)?;
+        Ok(Self {
+            metadata,
+            schema_descr: Arc::new(SchemaDescriptor::new(schema)),
+            metadata_offset,
+        })
+    }
+
+    /// Returns the offset in the file at which the encoded metadata starts, this is
+    /// the position from which [`Self::rewrite`] overwrites the file
+    pub fn metadata_offset(&self) -> u64 {
+        self.metadata_offset
+    }
+
+    /// Returns the schema descriptor of the file
+    pub fn schema_descr(&self) -> &SchemaDescriptor {
+        &self.schema_descr
+    }
+
+    /// Returns the current state of the metadata as [`ParquetMetaData`]
+    pub fn metadata(&self) -> Result<ParquetMetaData> {
+        from_thrift_metadata(self.metadata.clone())
+    }
+
+    /// Returns the key-value metadata of the file
+    pub fn key_value_metadata(&self) -> Option<&Vec<KeyValue>> {
+        self.metadata.key_value_metadata.as_ref()
+    }
+
+    /// Replaces the key-value metadata of the file
+    pub fn set_key_value_metadata(&mut self, key_value_metadata: Option<Vec<KeyValue>>) {
+        self.metadata.key_value_metadata = key_value_metadata;
+    }
+
+    /// Appends an entry to the key-value metadata of the file
+    pub fn append_key_value_metadata(&mut self, key_value: KeyValue) {
+        self.metadata
+            .key_value_metadata
+            .get_or_insert_with(Vec::new)
+            .push(key_value);
+    }
+
+    /// Replaces the sorting columns of the row group at `row_group_idx`
+    pub fn set_sorting_columns(
+        &mut self,
+        row_group_idx: usize,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> Result<()> {
+        let num_columns = self.schema_descr.num_columns();
+        if let Some(column) = sorting_columns
+            .iter()
+            .flatten()
+            .find(|c| c.column_idx < 0 || c.column_idx as usize >= num_columns)
+        {
+            return Err(general_err!(
+                "Invalid sorting column index {}, file has {} columns",
+                column.column_idx,
+                num_columns
+            ));
+        }
+
+        let num_row_groups = self.metadata.row_groups.len();
+        let row_group =
+            self.metadata
+                .row_groups
+                .get_mut(row_group_idx)
+                .ok_or_else(|| {
+                    general_err!(
+                        "Invalid row group index {}, file has {} row groups",
+                        row_group_idx,
+                        num_row_groups
+                    )
+                })?;
+        row_group.sorting_columns = sorting_columns;
+        Ok(())
+    }
+
+    /// Replaces the statistics of the column chunk at `column_idx` of the row group at
+    /// `row_group_idx`
+    ///
+    /// Returns an error if the physical type of `statistics` does not match that of
+    /// the column.
+    pub fn set_column_statistics(
+        &mut self,
+        row_group_idx: usize,
+        column_idx: usize,
+        statistics: Option<Statistics>,
+    ) -> Result<()> {
+        let num_row_groups = self.metadata.row_groups.len();
+        let row_group =
+            self.metadata
+                .row_groups
+                .get_mut(row_group_idx)
+                .ok_or_else(|| {
+                    general_err!(
+                        "Invalid row group index {}, file has {} row groups",
+                        row_group_idx,
+                        num_row_groups
+                    )
+                })?;
+
+        let num_columns = row_group.columns.len();
+        let column_meta = row_group
+            .columns
+            .get_mut(column_idx)
+            .and_then(|c| c.meta_data.as_mut())
+            .ok_or_else(|| {
+                general_err!(
+                    "Invalid column index {}, row group has {} columns",
+                    column_idx,
+                    num_columns
+                )
+            })?;
+
+        if let Some(stats) = &statistics {
+            let expected = self.schema_descr.column(column_idx).physical_type();
+            if stats.physical_type() != expected {
+                return Err(general_err!(
+                    "Statistics of type {} do not match column of type {}",
+                    stats.physical_type(),
+                    expected
+                ));
+            }
+        }
+        column_meta.statistics = statistics::to_thrift(statistics.as_ref());
+        Ok(())
+    }
+
+    /// Encodes the metadata followed by the 8 byte footer, this is the full content
+    /// that follows [`Self::metadata_offset`] in the rewritten file
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        {
+            let mut protocol = TCompactOutputProtocol::new(&mut buf);
+            self.metadata.write_to_out_protocol(&mut protocol)?;
+        }
+        let metadata_len = buf.len() as i32;
+        buf.extend_from_slice(&metadata_len.to_le_bytes());
+        buf.extend_from_slice(&PARQUET_MAGIC);
+        Ok(buf)
+    }
+
+    /// Overwrites the footer region of `file` with the encoded metadata, truncating
+    /// the file if the new footer is shorter than the original
+    ///
+    /// `file` must be the same file this editor was created from, opened for writing.
+    pub fn rewrite(&self, file: &mut File) -> Result<()> {
+        let encoded = self.encode()?;
+        file.seek(SeekFrom::Start(self.metadata_offset))?;
+        file.write_all(&encoded)?;
+        file.set_len(self.metadata_offset + encoded.len() as u64)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
 /// Parses column orders from Thrift definition.
 /// If no column orders are defined, returns `None`.
 fn parse_column_orders(
@@ -241,4 +455,112 @@ mod tests {
 
         parse_column_orders(t_column_orders, &schema_descr);
     }
+
+    #[test]
+    fn test_footer_editor() {
+        use crate::data_type::Int32Type;
+        use crate::file::properties::WriterProperties;
+        use crate::file::reader::{FileReader, SerializedFileReader};
+        use crate::file::writer::SerializedFileWriter;
+        use crate::schema::parser::parse_message_type;
+
+        let schema = Arc::new(
+            parse_message_type("message schema { REQUIRED INT32 a; REQUIRED INT32 b; }")
+                .unwrap(),
+        );
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer =
+            SerializedFileWriter::new(file.try_clone().unwrap(), schema, props).unwrap();
+        for values in [[1, 2, 3], [4, 5, 6]] {
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            while let Some(mut col_writer) = row_group_writer.next_column().unwrap() {
+                col_writer
+                    .typed::<Int32Type>()
+                    .write_batch(&values, None, None)
+                    .unwrap();
+                col_writer.close().unwrap();
+            }
+            row_group_writer.close().unwrap();
+        }
+        writer.close().unwrap();
+
+        let original_len = file.metadata().unwrap().len();
+        let mut editor = FooterEditor::try_new(&file).unwrap();
+        assert!(editor.metadata_offset() < original_len);
+        assert_eq!(editor.metadata().unwrap().num_row_groups(), 2);
+        assert!(editor.key_value_metadata().is_none());
+
+        let sorting_columns = vec![SortingColumn::new(1, true, false)];
+        editor.append_key_value_metadata(KeyValue::new(
+            "key".to_string(),
+            "value".to_string(),
+        ));
+        editor
+            .set_sorting_columns(1, Some(sorting_columns.clone()))
+            .unwrap();
+        let stats = Statistics::int32(Some(-10), Some(10), None, 0, false);
+        editor
+            .set_column_statistics(0, 1, Some(stats.clone()))
+            .unwrap();
+
+        // Invalid edits are rejected
+        let err = editor.set_sorting_columns(2, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid row group index 2, file has 2 row groups"
+        );
+        let err = editor
+            .set_sorting_columns(0, Some(vec![SortingColumn::new(2, true, false)]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid sorting column index 2, file has 2 columns"
+        );
+        let err = editor.set_column_statistics(0, 3, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid column index 3, row group has 2 columns"
+        );
+        let err = editor
+            .set_column_statistics(
+                0,
+                0,
+                Some(Statistics::boolean(None, None, None, 0, false)),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Statistics of type BOOLEAN do not match column of type INT32"
+        );
+
+        editor.rewrite(&mut file).unwrap();
+        assert_eq!(
+            file.metadata().unwrap().len(),
+            editor.metadata_offset() + editor.encode().unwrap().len() as u64
+        );
+
+        let reader = SerializedFileReader::new(file).unwrap();
+        let metadata = reader.metadata();
+        let kv = metadata.file_metadata().key_value_metadata().unwrap();
+        assert_eq!(kv.len(), 1);
+        assert_eq!(kv[0].key, "key");
+        assert_eq!(kv[0].value.as_deref(), Some("value"));
+        assert!(metadata.row_group(0).sorting_columns().is_none());
+        assert_eq!(
+            metadata.row_group(1).sorting_columns(),
+            Some(&sorting_columns)
+        );
+        assert_eq!(metadata.row_group(0).column(1).statistics(), Some(&stats));
+        assert!(metadata.row_group(1).column(1).statistics().is_some());
+
+        // Data pages are untouched
+        let values: Vec<_> = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| row.unwrap().to_string())
+            .collect();
+        assert_eq!(values[0], "{a: 1, b: 1}");
+        assert_eq!(values[5], "{a: 6, b: 6}");
+    }
 }
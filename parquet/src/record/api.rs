@@ -22,8 +22,8 @@ use std::fmt;
 use chrono::{TimeZone, Utc};
 use num_bigint::{BigInt, Sign};
 
-use crate::basic::{ConvertedType, Type as PhysicalType};
-use crate::data_type::{ByteArray, Decimal, Int96};
+use crate::basic::{ConvertedType, LogicalType, TimeUnit, Type as PhysicalType};
+use crate::data_type::{ByteArray, Decimal, Int96, Interval};
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
 
@@ -66,7 +66,7 @@ impl Row {
     ///
     /// let file = File::open("/path/to/file").unwrap();
     /// let reader = SerializedFileReader::new(file).unwrap();
-    /// let row: Row = reader.get_row_iter(None).unwrap().next().unwrap();
+    /// let row: Row = reader.get_row_iter(None).unwrap().next().unwrap().unwrap();
     /// for (idx, (name, field)) in row.get_column_iter().enumerate() {
     ///     println!("column index: {}, column name: {}, column value: {}", idx, name, field);
     /// }
@@ -125,6 +125,8 @@ pub trait RowAccessor {
     fn get_double(&self, i: usize) -> Result<f64>;
     fn get_timestamp_millis(&self, i: usize) -> Result<i64>;
     fn get_timestamp_micros(&self, i: usize) -> Result<i64>;
+    fn get_timestamp_nanos(&self, i: usize) -> Result<i64>;
+    fn get_interval(&self, i: usize) -> Result<Interval>;
     fn get_decimal(&self, i: usize) -> Result<&Decimal>;
     fn get_string(&self, i: usize) -> Result<&String>;
     fn get_bytes(&self, i: usize) -> Result<&ByteArray>;
@@ -146,7 +148,7 @@ pub trait RowAccessor {
 ///
 /// if let Ok(file) = File::open(&Path::new("test.parquet")) {
 ///     let reader = SerializedFileReader::new(file).unwrap();
-///     let row = reader.get_row_iter(None).unwrap().next().unwrap();
+///     let row = reader.get_row_iter(None).unwrap().next().unwrap().unwrap();
 ///     println!("column 0: {}, column 1: {}", row.fmt(0), row.fmt(1));
 /// }
 /// ```
@@ -223,6 +225,10 @@ impl RowAccessor for Row {
 
     row_primitive_accessor!(get_timestamp_micros, TimestampMicros, i64);
 
+    row_primitive_accessor!(get_timestamp_nanos, TimestampNanos, i64);
+
+    row_primitive_accessor!(get_interval, Interval, Interval);
+
     row_complex_accessor!(get_decimal, Decimal, Decimal);
 
     row_complex_accessor!(get_string, Str, String);
@@ -297,6 +303,8 @@ pub trait ListAccessor {
     fn get_double(&self, i: usize) -> Result<f64>;
     fn get_timestamp_millis(&self, i: usize) -> Result<i64>;
     fn get_timestamp_micros(&self, i: usize) -> Result<i64>;
+    fn get_timestamp_nanos(&self, i: usize) -> Result<i64>;
+    fn get_interval(&self, i: usize) -> Result<Interval>;
     fn get_decimal(&self, i: usize) -> Result<&Decimal>;
     fn get_string(&self, i: usize) -> Result<&String>;
     fn get_bytes(&self, i: usize) -> Result<&ByteArray>;
@@ -366,6 +374,10 @@ impl ListAccessor for List {
 
     list_primitive_accessor!(get_timestamp_micros, TimestampMicros, i64);
 
+    list_primitive_accessor!(get_timestamp_nanos, TimestampNanos, i64);
+
+    list_primitive_accessor!(get_interval, Interval, Interval);
+
     list_complex_accessor!(get_decimal, Decimal, Decimal);
 
     list_complex_accessor!(get_string, Str, String);
@@ -457,6 +469,10 @@ impl<'a> ListAccessor for MapList<'a> {
 
     map_list_primitive_accessor!(get_timestamp_micros, TimestampMicros, i64);
 
+    map_list_primitive_accessor!(get_timestamp_nanos, TimestampNanos, i64);
+
+    map_list_primitive_accessor!(get_interval, Interval, Interval);
+
     list_complex_accessor!(get_decimal, Decimal, Decimal);
 
     list_complex_accessor!(get_string, Str, String);
@@ -527,6 +543,10 @@ pub enum Field {
     TimestampMillis(i64),
     /// Microseconds from the Unix epoch, 1 January 1970.
     TimestampMicros(i64),
+    /// Nanoseconds from the Unix epoch, 1 January 1970.
+    TimestampNanos(i64),
+    /// Interval of months, days and milliseconds.
+    Interval(Interval),
 
     // ----------------------------------------------------------------------
     // Complex types
@@ -560,6 +580,8 @@ impl Field {
             Field::Bytes(_) => "Bytes",
             Field::TimestampMillis(_) => "TimestampMillis",
             Field::TimestampMicros(_) => "TimestampMicros",
+            Field::TimestampNanos(_) => "TimestampNanos",
+            Field::Interval(_) => "Interval",
             Field::Group(_) => "Group",
             Field::ListInternal(_) => "ListInternal",
             Field::MapInternal(_) => "MapInternal",
@@ -604,7 +626,7 @@ impl Field {
     #[inline]
     pub fn convert_int64(descr: &ColumnDescPtr, value: i64) -> Self {
         match descr.converted_type() {
-            ConvertedType::INT_64 | ConvertedType::NONE => Field::Long(value),
+            ConvertedType::INT_64 => Field::Long(value),
             ConvertedType::UINT_64 => Field::ULong(value as u64),
             ConvertedType::TIMESTAMP_MILLIS => Field::TimestampMillis(value),
             ConvertedType::TIMESTAMP_MICROS => Field::TimestampMicros(value),
@@ -613,15 +635,26 @@ impl Field {
                 descr.type_precision(),
                 descr.type_scale(),
             )),
+            // Nanosecond timestamps have no corresponding converted type
+            ConvertedType::NONE => match descr.logical_type() {
+                Some(LogicalType::Timestamp {
+                    unit: TimeUnit::NANOS(_),
+                    ..
+                }) => Field::TimestampNanos(value),
+                _ => Field::Long(value),
+            },
             _ => nyi!(descr, value),
         }
     }
 
     /// Converts Parquet INT96 (nanosecond timestamps) type and logical type into
     /// `Timestamp` value.
+    ///
+    /// Returns an error if `value` is outside the range representable by an `i64` of
+    /// nanoseconds.
     #[inline]
-    pub fn convert_int96(_descr: &ColumnDescPtr, value: Int96) -> Self {
-        Field::TimestampMillis(value.to_i64())
+    pub fn convert_int96(_descr: &ColumnDescPtr, value: Int96) -> Result<Self> {
+        Ok(Field::TimestampNanos(value.try_to_nanos()?))
     }
 
     /// Converts Parquet FLOAT type with logical type into `f32` value.
@@ -638,12 +671,22 @@ impl Field {
 
     /// Converts Parquet BYTE_ARRAY type with converted type into either UTF8 string or
     /// array of bytes.
+    ///
+    /// Returns an error if `value` is not valid for the converted type, e.g. a UTF8
+    /// value that is not valid UTF-8 or an INTERVAL value that is not 12 bytes long.
     #[inline]
-    pub fn convert_byte_array(descr: &ColumnDescPtr, value: ByteArray) -> Self {
-        match descr.physical_type() {
+    pub fn convert_byte_array(descr: &ColumnDescPtr, value: ByteArray) -> Result<Self> {
+        let field = match descr.physical_type() {
             PhysicalType::BYTE_ARRAY => match descr.converted_type() {
                 ConvertedType::UTF8 | ConvertedType::ENUM | ConvertedType::JSON => {
-                    let value = String::from_utf8(value.data().to_vec()).unwrap();
+                    let value =
+                        String::from_utf8(value.data().to_vec()).map_err(|e| {
+                            general_err!(
+                            "Error reading BYTE_ARRAY as String. Bytes: {:?} Error: {:?}",
+                            value.data(),
+                            e
+                        )
+                        })?;
                     Field::Str(value)
                 }
                 ConvertedType::BSON | ConvertedType::NONE => Field::Bytes(value),
@@ -660,11 +703,15 @@ impl Field {
                     descr.type_precision(),
                     descr.type_scale(),
                 )),
+                ConvertedType::INTERVAL => {
+                    Field::Interval(Interval::from_bytes(value.data())?)
+                }
                 ConvertedType::NONE => Field::Bytes(value),
                 _ => nyi!(descr, value),
             },
             _ => nyi!(descr, value),
-        }
+        };
+        Ok(field)
     }
 
     #[cfg(any(feature = "json", test))]
@@ -699,6 +746,10 @@ impl Field {
             Field::TimestampMicros(ts) => {
                 Value::String(convert_timestamp_micros_to_string(*ts))
             }
+            Field::TimestampNanos(ts) => {
+                Value::String(convert_timestamp_nanos_to_string(*ts))
+            }
+            Field::Interval(interval) => Value::String(interval.to_string()),
             Field::Group(row) => row.to_json_value(),
             Field::ListInternal(fields) => {
                 Value::Array(fields.elements.iter().map(|f| f.to_json_value()).collect())
@@ -763,6 +814,10 @@ impl fmt::Display for Field {
             Field::TimestampMicros(value) => {
                 write!(f, "{}", convert_timestamp_micros_to_string(value))
             }
+            Field::TimestampNanos(value) => {
+                write!(f, "{}", convert_timestamp_nanos_to_string(value))
+            }
+            Field::Interval(value) => write!(f, "{value}"),
             Field::Group(ref fields) => write!(f, "{fields}"),
             Field::ListInternal(ref list) => {
                 let elems = &list.elements;
@@ -829,6 +884,14 @@ fn convert_timestamp_micros_to_string(value: i64) -> String {
     convert_timestamp_secs_to_string(value / 1000000)
 }
 
+/// Helper method to convert Parquet timestamp into a string.
+/// Input `value` is a number of nanoseconds since the epoch in UTC.
+/// Datetime is displayed in local timezone.
+#[inline]
+fn convert_timestamp_nanos_to_string(value: i64) -> String {
+    convert_timestamp_secs_to_string(value / 1000000000)
+}
+
 /// Helper method to convert Parquet decimal into a string.
 /// We assert that `scale >= 0` and `precision > scale`, but this will be enforced
 /// when constructing Parquet schema.
@@ -982,6 +1045,22 @@ mod tests {
             make_column_descr![PhysicalType::INT64, ConvertedType::DECIMAL, 0, 8, 2];
         let row = Field::convert_int64(&descr, 3333);
         assert_eq!(row, Field::Decimal(Decimal::from_i64(3333, 8, 2)));
+
+        let tpe = PrimitiveTypeBuilder::new("col", PhysicalType::INT64)
+            .with_logical_type(Some(LogicalType::Timestamp {
+                is_adjusted_to_u_t_c: true,
+                unit: TimeUnit::NANOS(Default::default()),
+            }))
+            .build()
+            .unwrap();
+        let descr = Arc::new(ColumnDescriptor::new(
+            Arc::new(tpe),
+            0,
+            0,
+            ColumnPath::from("col"),
+        ));
+        let row = Field::convert_int64(&descr, 1541186529153123456);
+        assert_eq!(row, Field::TimestampNanos(1541186529153123456));
     }
 
     #[test]
@@ -990,12 +1069,15 @@ mod tests {
         let descr = make_column_descr![PhysicalType::INT96, ConvertedType::NONE];
 
         let value = Int96::from(vec![0, 0, 2454923]);
-        let row = Field::convert_int96(&descr, value);
-        assert_eq!(row, Field::TimestampMillis(1238544000000));
+        let row = Field::convert_int96(&descr, value).unwrap();
+        assert_eq!(row, Field::TimestampNanos(1238544000000000000));
 
         let value = Int96::from(vec![4165425152, 13, 2454923]);
-        let row = Field::convert_int96(&descr, value);
-        assert_eq!(row, Field::TimestampMillis(1238544060000));
+        let row = Field::convert_int96(&descr, value).unwrap();
+        assert_eq!(row, Field::TimestampNanos(1238544060000000000));
+
+        let value = Int96::from(vec![0, 0, 0]);
+        assert!(Field::convert_int96(&descr, value).is_err());
     }
 
     #[test]
@@ -1019,38 +1101,38 @@ mod tests {
         // UTF8
         let descr = make_column_descr![PhysicalType::BYTE_ARRAY, ConvertedType::UTF8];
         let value = ByteArray::from(vec![b'A', b'B', b'C', b'D']);
-        let row = Field::convert_byte_array(&descr, value);
+        let row = Field::convert_byte_array(&descr, value).unwrap();
         assert_eq!(row, Field::Str("ABCD".to_string()));
 
         // ENUM
         let descr = make_column_descr![PhysicalType::BYTE_ARRAY, ConvertedType::ENUM];
         let value = ByteArray::from(vec![b'1', b'2', b'3']);
-        let row = Field::convert_byte_array(&descr, value);
+        let row = Field::convert_byte_array(&descr, value).unwrap();
         assert_eq!(row, Field::Str("123".to_string()));
 
         // JSON
         let descr = make_column_descr![PhysicalType::BYTE_ARRAY, ConvertedType::JSON];
         let value = ByteArray::from(vec![b'{', b'"', b'a', b'"', b':', b'1', b'}']);
-        let row = Field::convert_byte_array(&descr, value);
+        let row = Field::convert_byte_array(&descr, value).unwrap();
         assert_eq!(row, Field::Str("{\"a\":1}".to_string()));
 
         // NONE
         let descr = make_column_descr![PhysicalType::BYTE_ARRAY, ConvertedType::NONE];
         let value = ByteArray::from(vec![1, 2, 3, 4, 5]);
-        let row = Field::convert_byte_array(&descr, value.clone());
+        let row = Field::convert_byte_array(&descr, value.clone()).unwrap();
         assert_eq!(row, Field::Bytes(value));
 
         // BSON
         let descr = make_column_descr![PhysicalType::BYTE_ARRAY, ConvertedType::BSON];
         let value = ByteArray::from(vec![1, 2, 3, 4, 5]);
-        let row = Field::convert_byte_array(&descr, value.clone());
+        let row = Field::convert_byte_array(&descr, value.clone()).unwrap();
         assert_eq!(row, Field::Bytes(value));
 
         // DECIMAL
         let descr =
             make_column_descr![PhysicalType::BYTE_ARRAY, ConvertedType::DECIMAL, 0, 8, 2];
         let value = ByteArray::from(vec![207, 200]);
-        let row = Field::convert_byte_array(&descr, value.clone());
+        let row = Field::convert_byte_array(&descr, value.clone()).unwrap();
         assert_eq!(row, Field::Decimal(Decimal::from_bytes(value, 8, 2)));
 
         // DECIMAL (FIXED_LEN_BYTE_ARRAY)
@@ -1062,7 +1144,7 @@ mod tests {
             5
         ];
         let value = ByteArray::from(vec![0, 0, 0, 0, 0, 4, 147, 224]);
-        let row = Field::convert_byte_array(&descr, value.clone());
+        let row = Field::convert_byte_array(&descr, value.clone()).unwrap();
         assert_eq!(row, Field::Decimal(Decimal::from_bytes(value, 17, 5)));
 
         // NONE (FIXED_LEN_BYTE_ARRAY)
@@ -1074,8 +1156,27 @@ mod tests {
             0
         ];
         let value = ByteArray::from(vec![1, 2, 3, 4, 5, 6]);
-        let row = Field::convert_byte_array(&descr, value.clone());
+        let row = Field::convert_byte_array(&descr, value.clone()).unwrap();
         assert_eq!(row, Field::Bytes(value));
+
+        // INTERVAL
+        let descr = make_column_descr![
+            PhysicalType::FIXED_LEN_BYTE_ARRAY,
+            ConvertedType::INTERVAL,
+            12,
+            0,
+            0
+        ];
+        let value = ByteArray::from(vec![1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+        let row = Field::convert_byte_array(&descr, value).unwrap();
+        assert_eq!(row, Field::Interval(Interval::new(1, 2, 3)));
+
+        let value = ByteArray::from(vec![1, 0, 0, 0, 2, 0, 0, 0]);
+        let err = Field::convert_byte_array(&descr, value).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Expected 12 bytes for INTERVAL, got 8"
+        );
     }
 
     #[test]
@@ -1340,6 +1441,8 @@ mod tests {
             ("15".to_string(), Field::TimestampMillis(1262391174000)),
             ("16".to_string(), Field::TimestampMicros(1262391174000000)),
             ("17".to_string(), Field::Decimal(Decimal::from_i32(4, 7, 2))),
+            ("18".to_string(), Field::TimestampNanos(1262391174000000000)),
+            ("19".to_string(), Field::Interval(Interval::new(1, 2, 3))),
         ]);
 
         assert_eq!("null", format!("{}", row.fmt(0)));
@@ -1366,6 +1469,11 @@ mod tests {
             format!("{}", row.fmt(16))
         );
         assert_eq!("0.04", format!("{}", row.fmt(17)));
+        assert_eq!(
+            convert_timestamp_nanos_to_string(1262391174000000000),
+            format!("{}", row.fmt(18))
+        );
+        assert_eq!("1 months 2 days 3 millis", format!("{}", row.fmt(19)));
     }
 
     #[test]
@@ -1425,6 +1533,8 @@ mod tests {
                 Field::Bytes(ByteArray::from(vec![1, 2, 3, 4, 5])),
             ),
             ("o".to_string(), Field::Decimal(Decimal::from_i32(4, 7, 2))),
+            ("p".to_string(), Field::TimestampNanos(1262391174000000001)),
+            ("q".to_string(), Field::Interval(Interval::new(1, 2, 3))),
         ]);
 
         assert!(!row.get_bool(1).unwrap());
@@ -1441,6 +1551,10 @@ mod tests {
         assert_eq!("abc", row.get_string(12).unwrap());
         assert_eq!(5, row.get_bytes(13).unwrap().len());
         assert_eq!(7, row.get_decimal(14).unwrap().precision());
+        assert_eq!(4, row.get_decimal(14).unwrap().to_i128().unwrap());
+        assert_eq!(1262391174000000001, row.get_timestamp_nanos(15).unwrap());
+        assert_eq!(Interval::new(1, 2, 3), row.get_interval(16).unwrap());
+        assert!(row.get_timestamp_micros(15).is_err());
     }
 
     #[test]
@@ -395,14 +395,15 @@ impl Reader {
     /// Automatically advances all necessary readers.
     /// This must be called on the root level reader (i.e., for Message type).
     /// Otherwise, it will panic.
-    fn read(&mut self) -> Row {
+    fn read(&mut self) -> Result<Row> {
         match *self {
             Reader::GroupReader(_, _, ref mut readers) => {
                 let mut fields = Vec::new();
                 for reader in readers {
-                    fields.push((String::from(reader.field_name()), reader.read_field()));
+                    fields
+                        .push((String::from(reader.field_name()), reader.read_field()?));
                 }
-                make_row(fields)
+                Ok(make_row(fields))
             }
             _ => panic!("Cannot call read() on {self}"),
         }
@@ -410,16 +411,16 @@ impl Reader {
 
     /// Reads current record as `Field` from the reader tree.
     /// Automatically advances all necessary readers.
-    fn read_field(&mut self) -> Field {
-        match *self {
+    fn read_field(&mut self) -> Result<Field> {
+        let field = match *self {
             Reader::PrimitiveReader(_, ref mut column) => {
-                let value = column.current_value();
-                column.read_next().unwrap();
+                let value = column.current_value()?;
+                column.read_next()?;
                 value
             }
             Reader::OptionReader(def_level, ref mut reader) => {
                 if reader.current_def_level() > def_level {
-                    reader.read_field()
+                    reader.read_field()?
                 } else {
                     reader.advance_columns();
                     Field::Null
@@ -433,7 +434,7 @@ impl Reader {
                     {
                         fields.push((
                             String::from(reader.field_name()),
-                            reader.read_field(),
+                            reader.read_field()?,
                         ));
                     } else {
                         reader.advance_columns();
@@ -447,7 +448,7 @@ impl Reader {
                 let mut elements = Vec::new();
                 loop {
                     if reader.current_def_level() > def_level {
-                        elements.push(reader.read_field());
+                        elements.push(reader.read_field()?);
                     } else {
                         reader.advance_columns();
                         // If the current definition level is equal to the definition
@@ -476,7 +477,7 @@ impl Reader {
                 let mut pairs = Vec::new();
                 loop {
                     if keys.current_def_level() > def_level {
-                        pairs.push((keys.read_field(), values.read_field()));
+                        pairs.push((keys.read_field()?, values.read_field()?));
                     } else {
                         keys.advance_columns();
                         values.advance_columns();
@@ -497,7 +498,8 @@ impl Reader {
 
                 Field::MapInternal(make_map(pairs))
             }
-        }
+        };
+        Ok(field)
     }
 
     /// Returns field name for the current reader.
@@ -630,6 +632,9 @@ impl<'a> Either<'a> {
 /// Iterator of [`Row`](crate::record::Row)s.
 /// It is used either for a single row group to iterate over data in that row group, or
 /// an entire file with auto buffering of all row groups.
+///
+/// Returns an error for a row containing a value that cannot be converted to a
+/// [`Field`](crate::record::Field), such as invalid UTF-8 in a UTF8 column.
 pub struct RowIter<'a> {
     descr: SchemaDescPtr,
     tree_builder: TreeBuilder,
@@ -637,6 +642,10 @@ pub struct RowIter<'a> {
     current_row_group: usize,
     num_row_groups: usize,
     row_iter: Option<ReaderIter>,
+    /// Number of rows still to be skipped before rows are returned
+    offset: usize,
+    /// Number of rows still to be returned, `None` if unbounded
+    limit: Option<usize>,
 }
 
 impl<'a> RowIter<'a> {
@@ -659,6 +668,8 @@ impl<'a> RowIter<'a> {
             num_row_groups,
             row_iter,
             current_row_group: 0,
+            offset: 0,
+            limit: None,
         }
     }
 
@@ -716,12 +727,36 @@ impl<'a> RowIter<'a> {
                     .schema_descr_ptr();
                 let descr = Self::get_proj_descr(proj, schema)?;
 
-                Ok(Self::new(self.file_reader, None, descr))
+                Ok(Self::new(self.file_reader, None, descr)
+                    .with_offset(self.offset)
+                    .with_limit_opt(self.limit))
             }
             None => Err(general_err!("File reader is required to use projections")),
         }
     }
 
+    /// Skips the first `offset` rows of this iterator.
+    ///
+    /// When reading from a file, row groups that lie entirely within the offset are
+    /// skipped using their row counts, without decoding any of their data.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Limits this iterator to return at most `limit` rows.
+    ///
+    /// Row groups are only read once a row is requested from them, so row groups
+    /// past the limit are never decoded.
+    pub fn with_limit(self, limit: usize) -> Self {
+        self.with_limit_opt(Some(limit))
+    }
+
+    fn with_limit_opt(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+
     /// Helper method to get schema descriptor for projected schema.
     /// If projection is None, then full schema is returned.
     #[inline]
@@ -751,12 +786,16 @@ impl<'a> RowIter<'a> {
 }
 
 impl<'a> Iterator for RowIter<'a> {
-    type Item = Row;
+    type Item = Result<Row>;
+
+    fn next(&mut self) -> Option<Result<Row>> {
+        if self.limit == Some(0) {
+            return None;
+        }
 
-    fn next(&mut self) -> Option<Row> {
         let mut row = None;
         if let Some(ref mut iter) = self.row_iter {
-            row = iter.next();
+            row = skip_and_next(iter, &mut self.offset);
         }
 
         while row.is_none() && self.current_row_group < self.num_row_groups {
@@ -764,6 +803,18 @@ impl<'a> Iterator for RowIter<'a> {
             // must be set for selecting next row group.
             if let Some(ref either) = self.file_reader {
                 let file_reader = either.reader();
+
+                // Skip row groups entirely covered by the offset without decoding them
+                let num_rows = file_reader
+                    .metadata()
+                    .row_group(self.current_row_group)
+                    .num_rows() as usize;
+                if self.offset >= num_rows {
+                    self.offset -= num_rows;
+                    self.current_row_group += 1;
+                    continue;
+                }
+
                 let row_group_reader = &*file_reader
                     .get_row_group(self.current_row_group)
                     .expect("Row group is required to advance");
@@ -772,17 +823,35 @@ impl<'a> Iterator for RowIter<'a> {
                     .tree_builder
                     .as_iter(self.descr.clone(), row_group_reader);
 
-                row = iter.next();
+                row = skip_and_next(&mut iter, &mut self.offset);
 
                 self.current_row_group += 1;
                 self.row_iter = Some(iter);
             }
         }
 
+        if row.is_some() {
+            if let Some(limit) = self.limit.as_mut() {
+                *limit -= 1;
+            }
+        }
         row
     }
 }
 
+/// Discards up to `offset` rows from `iter`, decrementing `offset` for each discarded
+/// row, and returns the next row if any.
+#[inline]
+fn skip_and_next(iter: &mut ReaderIter, offset: &mut usize) -> Option<Result<Row>> {
+    while *offset > 0 {
+        if let Err(e) = iter.next()? {
+            return Some(Err(e));
+        }
+        *offset -= 1;
+    }
+    iter.next()
+}
+
 /// Internal iterator of [`Row`](crate::record::Row)s for a reader.
 pub struct ReaderIter {
     root_reader: Reader,
@@ -801,9 +870,9 @@ impl ReaderIter {
 }
 
 impl Iterator for ReaderIter {
-    type Item = Row;
+    type Item = Result<Row>;
 
-    fn next(&mut self) -> Option<Row> {
+    fn next(&mut self) -> Option<Result<Row>> {
         if self.records_left > 0 {
             self.records_left -= 1;
             Some(self.root_reader.read())
@@ -817,8 +886,13 @@ impl Iterator for ReaderIter {
 mod tests {
     use super::*;
 
+    use bytes::Bytes;
+
+    use crate::data_type::{ByteArray, ByteArrayType, Int32Type};
     use crate::errors::Result;
+    use crate::file::properties::WriterProperties;
     use crate::file::reader::{FileReader, SerializedFileReader};
+    use crate::file::writer::SerializedFileWriter;
     use crate::record::api::{Field, Row, RowAccessor, RowFormatter};
     use crate::schema::parser::parse_message_type;
     use crate::util::test_common::file_util::{get_test_file, get_test_path};
@@ -1495,7 +1569,7 @@ mod tests {
             .iter()
             .map(|p| SerializedFileReader::try_from(p.as_path()).unwrap())
             .flat_map(|r| RowIter::from_file_into(Box::new(r)))
-            .flat_map(|r| r.get_int(0))
+            .flat_map(|r| r.unwrap().get_int(0))
             .collect::<Vec<_>>();
 
         assert_eq!(vec, vec![4, 5, 6, 7, 2, 3, 0, 1]);
@@ -1513,7 +1587,7 @@ mod tests {
 
                 RowIter::from_file_into(Box::new(r)).project(proj).unwrap()
             })
-            .map(|r| format!("id:{}", r.fmt(0)))
+            .map(|r| format!("id:{}", r.unwrap().fmt(0)))
             .collect::<Vec<_>>()
             .join(", ");
 
@@ -1614,11 +1688,116 @@ mod tests {
         assert_eq!(rows, expected_rows);
     }
 
+    #[test]
+    fn test_file_reader_iter_offset_limit() {
+        let reader = SerializedFileReader::new(write_row_groups(&[3, 4, 3])).unwrap();
+        let read = |offset: usize, limit: Option<usize>| {
+            let mut iter = RowIter::from_file(None, &reader)
+                .unwrap()
+                .with_offset(offset);
+            if let Some(limit) = limit {
+                iter = iter.with_limit(limit);
+            }
+            iter.map(|r| r.unwrap().get_int(0).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(read(0, None), (0..10).collect::<Vec<_>>());
+        assert_eq!(read(3, None), (3..10).collect::<Vec<_>>());
+        assert_eq!(read(5, None), (5..10).collect::<Vec<_>>());
+        assert_eq!(read(7, Some(2)), vec![7, 8]);
+        assert_eq!(read(2, Some(5)), vec![2, 3, 4, 5, 6]);
+        assert_eq!(read(0, Some(0)), Vec::<i32>::new());
+        assert_eq!(read(10, None), Vec::<i32>::new());
+        assert_eq!(read(12, Some(3)), Vec::<i32>::new());
+
+        // Offset and limit are preserved across projection
+        let proj = parse_message_type("message schema { REQUIRED INT32 a; }").ok();
+        let values = RowIter::from_file(None, &reader)
+            .unwrap()
+            .with_offset(4)
+            .with_limit(3)
+            .project(proj)
+            .unwrap()
+            .map(|r| r.unwrap().get_int(0).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn test_row_group_iter_offset_limit() {
+        let reader = SerializedFileReader::new(write_row_groups(&[3, 4])).unwrap();
+        let row_group_reader = reader.get_row_group(1).unwrap();
+        let values = row_group_reader
+            .get_row_iter(None)
+            .unwrap()
+            .with_offset(1)
+            .with_limit(2)
+            .map(|r| r.unwrap().get_int(0).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_file_reader_iter_invalid_utf8() {
+        let schema = "message schema { REQUIRED BYTE_ARRAY a (UTF8); }";
+        let schema = Arc::new(parse_message_type(schema).unwrap());
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut buf = Vec::new();
+        let mut writer = SerializedFileWriter::new(&mut buf, schema, props).unwrap();
+
+        let values = vec![ByteArray::from("a"), ByteArray::from(vec![0xff, 0xfe])];
+        let mut row_group_writer = writer.next_row_group().unwrap();
+        let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+        col_writer
+            .typed::<ByteArrayType>()
+            .write_batch(&values, None, None)
+            .unwrap();
+        col_writer.close().unwrap();
+        row_group_writer.close().unwrap();
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(Bytes::from(buf)).unwrap();
+        let mut iter = reader.get_row_iter(None).unwrap();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get_string(0).unwrap(), "a");
+        let err = iter.next().unwrap().unwrap_err().to_string();
+        assert!(err.contains("Error reading BYTE_ARRAY as String"), "{err}");
+        assert!(iter.next().is_none());
+    }
+
+    /// Writes a file with a single required INT32 column `a` containing consecutive
+    /// values, split into row groups of the given sizes.
+    fn write_row_groups(row_group_sizes: &[usize]) -> Bytes {
+        let schema =
+            Arc::new(parse_message_type("message schema { REQUIRED INT32 a; }").unwrap());
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut buf = Vec::new();
+        let mut writer = SerializedFileWriter::new(&mut buf, schema, props).unwrap();
+
+        let mut next = 0;
+        for size in row_group_sizes {
+            let values: Vec<i32> = (next..next + *size as i32).collect();
+            next += *size as i32;
+
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+            col_writer
+                .typed::<Int32Type>()
+                .write_batch(&values, None, None)
+                .unwrap();
+            col_writer.close().unwrap();
+            row_group_writer.close().unwrap();
+        }
+        writer.close().unwrap();
+        Bytes::from(buf)
+    }
+
     fn test_file_reader_rows(file_name: &str, schema: Option<Type>) -> Result<Vec<Row>> {
         let file = get_test_file(file_name);
         let file_reader: Box<dyn FileReader> = Box::new(SerializedFileReader::new(file)?);
         let iter = file_reader.get_row_iter(schema)?;
-        Ok(iter.collect())
+        iter.collect()
     }
 
     fn test_row_group_rows(file_name: &str, schema: Option<Type>) -> Result<Vec<Row>> {
@@ -1628,6 +1807,6 @@ mod tests {
         // group
         let row_group_reader = file_reader.get_row_group(0).unwrap();
         let iter = row_group_reader.get_row_iter(schema)?;
-        Ok(iter.collect())
+        iter.collect()
     }
 }
@@ -136,11 +136,11 @@ impl TripletIter {
     }
 
     /// Updates non-null value for current row.
-    pub fn current_value(&self) -> Field {
+    pub fn current_value(&self) -> Result<Field> {
         if self.is_null() {
-            return Field::Null;
+            return Ok(Field::Null);
         }
-        match *self {
+        let field = match *self {
             TripletIter::BoolTripletIter(ref typed) => {
                 Field::convert_bool(typed.column_descr(), *typed.current_value())
             }
@@ -151,7 +151,7 @@ impl TripletIter {
                 Field::convert_int64(typed.column_descr(), *typed.current_value())
             }
             TripletIter::Int96TripletIter(ref typed) => {
-                Field::convert_int96(typed.column_descr(), *typed.current_value())
+                Field::convert_int96(typed.column_descr(), *typed.current_value())?
             }
             TripletIter::FloatTripletIter(ref typed) => {
                 Field::convert_float(typed.column_descr(), *typed.current_value())
@@ -162,14 +162,15 @@ impl TripletIter {
             TripletIter::ByteArrayTripletIter(ref typed) => Field::convert_byte_array(
                 typed.column_descr(),
                 typed.current_value().clone(),
-            ),
+            )?,
             TripletIter::FixedLenByteArrayTripletIter(ref typed) => {
                 Field::convert_byte_array(
                     typed.column_descr(),
                     typed.current_value().clone().into(),
-                )
+                )?
             }
-        }
+        };
+        Ok(field)
     }
 }
 
@@ -550,7 +551,7 @@ mod tests {
         while let Ok(true) = iter.read_next() {
             assert!(iter.has_next());
             if !iter.is_null() {
-                values.push(iter.current_value());
+                values.push(iter.current_value().unwrap());
             }
             def_levels.push(iter.current_def_level());
             rep_levels.push(iter.current_rep_level());